@@ -9,6 +9,7 @@ import sys
 import threading
 import types
 import weakref
+from typing import Any, Dict, List, Optional
 
 # Import settings from helpers module
 from .helpers import Settings, get_settings, _settings, _settings_lock
@@ -31,7 +32,19 @@ from .exceptions import (
     InternalError,
     ProgrammingError,
     NotSupportedError,
+    SchemaDriftError,
+    TooManyParametersError,
+    StatementTooLargeError,
+    PingError,
+    LoginError,
+    InvalidCredentialsError,
+    LoginDisabledError,
+    DatabaseUnavailableError,
+    ServerUnreachableError,
+    CertificateTrustError,
     ConnectionStringParseError,
+    register_error_class,
+    unregister_error_class,
 )
 
 # Type Objects
@@ -52,6 +65,7 @@ from .type import (
 
 # Connection Objects
 from .db_connection import connect, Connection
+from .connect_attempt import ConnectAttempt
 
 # Connection String Handling
 from .connection_string_parser import _ConnectionStringParser
@@ -64,7 +78,7 @@ from .cursor import Cursor
 from .row import Row
 
 # Logging Configuration (Simplified single-level DEBUG system)
-from .logging import logger, setup_logging, driver_logger
+from .logging import logger, setup_logging, driver_logger, configure_redaction, RedactionPolicy
 
 # Constants
 from .constants import ConstantsDDBC, GetInfoConstants, get_info_constants
@@ -72,6 +86,68 @@ from .constants import ConstantsDDBC, GetInfoConstants, get_info_constants
 # Pooling
 from .pooling import PoolingManager
 
+# Bulk Load Helpers
+from .bulk_load import BulkCopyOptions, TableLoad
+
+# Progress reporting
+from .progress import ProgressEvent
+
+# Retry of transient errors
+from .retry import RetryPolicy
+
+# Savepoint-scoped nested transactions
+from .savepoint import NestedTransaction
+
+# Read-write splitting
+from .routing import RoutingPool
+
+# Extended Events consumer
+from .xevents import XEventSession
+
+# Index maintenance
+from .maintenance import IndexMaintenance
+
+# Ephemeral test databases
+from .testing import EphemeralDatabase
+
+# Local SQL Server instance discovery
+from .discovery import local_instances
+
+# Waiting for a server to accept logins
+from .readiness import wait_for_server
+
+# Audit event stream of executed statements
+from .audit import AuditEvent, AuditStream, enable_audit, disable_audit
+
+# Driver metrics
+from .driver_metrics import metrics, metrics_text
+
+# Per-server circuit breakers
+from .circuit_breaker import (
+    circuit_breaker_stats,
+    disable_circuit_breaker,
+    enable_circuit_breaker,
+)
+
+# Client-side checks of read-only connections
+from .read_only import check_read_only
+
+# Per-connection statement policies
+from .statement_policy import StatementPolicy, check_statement_policy
+
+# Connection defaults from environment variables
+from .environment import environment_defaults
+
+# asyncio API
+from . import aio
+from .aio import AsyncConnection, AsyncCursor
+
+# Stored procedure OUTPUT parameters
+from .procedure import Output, InOut
+
+# Streaming comparison of two result sets
+from .result_diff import RowDifference, diff_results
+
 # Global registry for tracking active connections (using weak references)
 _active_connections = weakref.WeakSet()
 _connections_lock = threading.Lock()
@@ -161,6 +237,8 @@ from .constants import (  # noqa: F401
     SQL_TYPE_TIMESTAMP,
     SQL_GUID,
     SQL_XML,
+    SQL_MONEY,
+    SQL_SMALLMONEY,
     # Connection attribute constants
     SQL_ATTR_ACCESS_MODE,
     SQL_ATTR_CONNECTION_TIMEOUT,
@@ -173,6 +251,7 @@ from .constants import (  # noqa: F401
     SQL_TXN_READ_COMMITTED,
     SQL_TXN_REPEATABLE_READ,
     SQL_TXN_SERIALIZABLE,
+    SQL_TXN_SS_SNAPSHOT,
     # Access modes
     SQL_MODE_READ_WRITE,
     SQL_MODE_READ_ONLY,
@@ -305,7 +384,19 @@ __all__ = [
     "InternalError",
     "ProgrammingError",
     "NotSupportedError",
+    "SchemaDriftError",
+    "TooManyParametersError",
+    "StatementTooLargeError",
+    "PingError",
+    "LoginError",
+    "InvalidCredentialsError",
+    "LoginDisabledError",
+    "DatabaseUnavailableError",
+    "ServerUnreachableError",
+    "CertificateTrustError",
     "ConnectionStringParseError",
+    "register_error_class",
+    "unregister_error_class",
     # Type objects and functions
     "Date",
     "Time",
@@ -322,6 +413,7 @@ __all__ = [
     # Connection and cursor classes
     "connect",
     "Connection",
+    "ConnectAttempt",
     "Cursor",
     "Row",
     # Settings
@@ -331,12 +423,65 @@ __all__ = [
     "logger",
     "setup_logging",
     "driver_logger",
+    "configure_redaction",
+    "RedactionPolicy",
     # Decimal functions
     "setDecimalSeparator",
     "getDecimalSeparator",
     # Pooling
     "pooling",
+    "pool_stats",
     "PoolingManager",
+    # Bulk load helpers
+    "BulkCopyOptions",
+    "TableLoad",
+    # Progress reporting
+    "ProgressEvent",
+    # Retry of transient errors
+    "RetryPolicy",
+    # Savepoint-scoped nested transactions
+    "NestedTransaction",
+    # Read-write splitting
+    "RoutingPool",
+    # Extended Events consumer
+    "XEventSession",
+    # Index maintenance
+    "IndexMaintenance",
+    # Ephemeral test databases
+    "EphemeralDatabase",
+    # Local SQL Server instance discovery
+    "local_instances",
+    # Waiting for a server to accept logins
+    "wait_for_server",
+    # Audit event stream
+    "AuditEvent",
+    "AuditStream",
+    "enable_audit",
+    "disable_audit",
+    # Driver metrics
+    "metrics",
+    "metrics_text",
+    # Per-server circuit breakers
+    "enable_circuit_breaker",
+    "disable_circuit_breaker",
+    "circuit_breaker_stats",
+    # Client-side checks of read-only connections
+    "check_read_only",
+    # Per-connection statement policies
+    "StatementPolicy",
+    "check_statement_policy",
+    # Connection defaults from environment variables
+    "environment_defaults",
+    # asyncio API
+    "aio",
+    "AsyncConnection",
+    "AsyncCursor",
+    # Stored procedure OUTPUT parameters
+    "Output",
+    "InOut",
+    # Streaming comparison of two result sets
+    "RowDifference",
+    "diff_results",
     # Constants - Enum classes
     "AuthType",
     "SQLTypes",
@@ -369,6 +514,8 @@ __all__ = [
     "SQL_TYPE_TIMESTAMP",
     "SQL_GUID",
     "SQL_XML",
+    "SQL_MONEY",
+    "SQL_SMALLMONEY",
     # Connection attribute constants
     "SQL_ATTR_ACCESS_MODE",
     "SQL_ATTR_CONNECTION_TIMEOUT",
@@ -381,6 +528,7 @@ __all__ = [
     "SQL_TXN_READ_COMMITTED",
     "SQL_TXN_REPEATABLE_READ",
     "SQL_TXN_SERIALIZABLE",
+    "SQL_TXN_SS_SNAPSHOT",
     # Access modes
     "SQL_MODE_READ_WRITE",
     "SQL_MODE_READ_ONLY",
@@ -506,10 +654,23 @@ __all__ = [
     # Module properties
     "lowercase",
     "native_uuid",
+    "duplicate_column_names",
+    "case_insensitive_columns",
+    "fips_mode",
 ]
 
 
-def pooling(max_size: int = 100, idle_timeout: int = 600, enabled: bool = True) -> None:
+def pooling(
+    max_size: int = 100,
+    idle_timeout: int = 600,
+    enabled: bool = True,
+    min_size: int = 0,
+    max_lifetime: int = 0,
+    validation_query: Optional[str] = None,
+    max_concurrent_statements: int = 0,
+    statement_queue_policy: str = "wait",
+    statement_queue_timeout: Optional[float] = None,
+) -> None:
     """
     Enable connection pooling with the specified parameters.
     By default:
@@ -519,6 +680,18 @@ def pooling(max_size: int = 100, idle_timeout: int = 600, enabled: bool = True)
         max_size (int): Maximum number of connections in the pool.
         idle_timeout (int): Time in seconds before idle connections are closed.
         enabled (bool): Whether to enable or disable pooling.
+        min_size (int): Connections of a pool kept open past idle_timeout once opened.
+        max_lifetime (int): Seconds after which a connection is closed instead of
+            being reused; 0 means no limit.
+        validation_query (str, optional): Statement run on a pooled connection
+            before it is handed out, e.g. "SELECT 1"; connections it fails on are
+            replaced.
+        max_concurrent_statements (int): Statements executing at once on the
+            connections of a pool; 0 means no limit.
+        statement_queue_policy (str): "wait" to queue an execute beyond the limit,
+            or "fail" to raise OperationalError instead.
+        statement_queue_timeout (float, optional): Seconds an execute queues before
+            failing with OperationalError; None means no limit.
 
     Returns:
         None
@@ -526,7 +699,26 @@ def pooling(max_size: int = 100, idle_timeout: int = 600, enabled: bool = True)
     if not enabled:
         PoolingManager.disable()
     else:
-        PoolingManager.enable(max_size, idle_timeout)
+        PoolingManager.enable(
+            max_size,
+            idle_timeout,
+            min_size,
+            max_lifetime,
+            validation_query,
+            max_concurrent_statements,
+            statement_queue_policy,
+            statement_queue_timeout,
+        )
+
+
+def pool_stats() -> List[Dict[str, Any]]:
+    """
+    Return the size and counters of every connection pool (see PoolingManager.stats).
+
+    Returns:
+        list of dict: One entry per connection string; empty when pooling is disabled.
+    """
+    return PoolingManager.stats()
 
 
 _original_module_setattr = sys.modules[__name__].__setattr__
@@ -579,6 +771,95 @@ class _MSSQLModule(types.ModuleType):
         with _settings_lock:
             _settings.native_uuid = value
 
+    @property
+    def duplicate_column_names(self) -> str:
+        """Get the duplicate_column_names setting.
+
+        Controls which column a name shared by several result columns refers to in
+        row["name"] / row.name access:
+        - "last" (default): the last column with that name.
+        - "first": the first column with that name.
+        - "suffix": later duplicates are renamed in cursor.description with a
+          numeric suffix ("id", "id_1", "id_2"), so every column is addressable.
+        """
+        return _settings.duplicate_column_names
+
+    @duplicate_column_names.setter
+    def duplicate_column_names(self, value: str) -> None:
+        """Set the duplicate_column_names setting."""
+        if value not in ("last", "first", "suffix"):
+            raise ValueError("duplicate_column_names must be 'last', 'first' or 'suffix'")
+        with _settings_lock:
+            _settings.duplicate_column_names = value
+
+    @property
+    def case_insensitive_columns(self) -> bool:
+        """Get the case_insensitive_columns setting.
+
+        When True, row["Name"] / row.name lookups ignore case while cursor.description
+        keeps the original column names (unlike lowercase, which rewrites them).
+        Default is False.
+        """
+        return _settings.case_insensitive_columns
+
+    @case_insensitive_columns.setter
+    def case_insensitive_columns(self, value: bool) -> None:
+        """Set the case_insensitive_columns setting."""
+        if not isinstance(value, bool):
+            raise ValueError("case_insensitive_columns must be a boolean value")
+        with _settings_lock:
+            _settings.case_insensitive_columns = value
+
+    @property
+    def temporal_precision(self) -> str:
+        """Get the temporal_precision setting.
+
+        "microseconds" (default) returns DATETIME2, DATETIMEOFFSET and TIME values
+        as datetime.datetime / datetime.time, truncating the 7th fractional digit.
+        "nanoseconds" returns them as mssql_python.temporal.PreciseDateTime /
+        PreciseTime, subclasses that keep it in their nanosecond attribute.
+        """
+        return _settings.temporal_precision
+
+    @temporal_precision.setter
+    def temporal_precision(self, value: str) -> None:
+        """Set the temporal_precision setting."""
+        from mssql_python.temporal import TEMPORAL_PRECISIONS
+
+        if value not in TEMPORAL_PRECISIONS:
+            raise ValueError("temporal_precision must be 'microseconds' or 'nanoseconds'")
+        with _settings_lock:
+            try:
+                from mssql_python.ddbc_bindings import DDBCSetTemporalNanoseconds
+            except ImportError:
+                DDBCSetTemporalNanoseconds = None
+            if DDBCSetTemporalNanoseconds is not None:
+                DDBCSetTemporalNanoseconds(value == "nanoseconds")
+            _settings.temporal_precision = value
+
+    @property
+    def fips_mode(self) -> bool:
+        """Get the fips_mode setting.
+
+        When True, connections need FIPS-validated crypto providers and encryption,
+        and only FIPS-approved TLS cipher suites and hashes are used (see
+        mssql_python.fips). Defaults to the MSSQL_PYTHON_FIPS_MODE environment
+        variable, off when unset.
+        """
+        return _settings.fips_mode
+
+    @fips_mode.setter
+    def fips_mode(self, value: bool) -> None:
+        """Set the fips_mode setting; turning it on checks the crypto providers."""
+        if not isinstance(value, bool):
+            raise ValueError("fips_mode must be a boolean value")
+        if value:
+            from mssql_python.fips import require_fips_providers
+
+            require_fips_providers()
+        with _settings_lock:
+            _settings.fips_mode = value
+
 
 # Replace the current module with our custom module class
 old_module: types.ModuleType = sys.modules[__name__]
@@ -598,3 +879,7 @@ sys.modules[__name__] = new_module
 # Initialize property values
 lowercase: bool = _settings.lowercase
 native_uuid: bool = _settings.native_uuid
+duplicate_column_names: str = _settings.duplicate_column_names
+case_insensitive_columns: bool = _settings.case_insensitive_columns
+temporal_precision: str = _settings.temporal_precision
+fips_mode: bool = _settings.fips_mode
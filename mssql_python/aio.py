@@ -0,0 +1,271 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module provides an asyncio API over connections and cursors.
+
+ODBC calls block, so each awaitable runs its call on a thread pool reserved for
+the driver, leaving the event loop free meanwhile, instead of every FastAPI or
+aiohttp handler wrapping calls in run_in_executor. Calls on one connection (and
+its cursors) run one at a time, in the order they were awaited. A task cancelled
+while a statement runs cancels the statement on the server; the connection is
+usable again once the driver has returned.
+
+    conn = await mssql_python.aio.connect(conn_str)
+    async with conn.cursor() as cursor:
+        await cursor.execute("SELECT name FROM sys.databases WHERE database_id > ?", 4)
+        async for row in cursor:
+            print(row.name)
+    await conn.close()
+"""
+
+import asyncio
+import functools
+import threading
+from concurrent.futures import ThreadPoolExecutor
+from typing import Any, AsyncIterator, Callable, List, Optional, Sequence
+
+from mssql_python import ddbc_bindings
+from mssql_python.connect_attempt import ConnectAttempt
+from mssql_python.connection import Connection
+from mssql_python.cursor import Cursor
+from mssql_python.logging import logger
+from mssql_python.row import Row
+from mssql_python.scalar import NO_DEFAULT, typed_scalar
+
+# Threads of the pool ODBC calls run on, and so the number of calls that can run at
+# once across all connections
+MAX_WORKERS = 32
+# Rows fetched per call when iterating a cursor whose arraysize is 1
+_ITERATION_BATCH_SIZE = 1000
+
+_executor: Optional[ThreadPoolExecutor] = None
+_executor_lock = threading.Lock()
+
+
+def _pool() -> ThreadPoolExecutor:
+    global _executor  # pylint: disable=global-statement
+    with _executor_lock:
+        if _executor is None:
+            _executor = ThreadPoolExecutor(MAX_WORKERS, thread_name_prefix="mssql-python-aio")
+        return _executor
+
+
+async def _run_blocking(
+    call: Callable[[], Any], on_cancel: Optional[Callable[[], None]] = None
+) -> Any:
+    """
+    Return call() run on the pool. If the awaiting task is cancelled, on_cancel is
+    invoked and the call still waited for, since a running ODBC call cannot be
+    abandoned, before CancelledError propagates.
+    """
+    future = asyncio.get_running_loop().run_in_executor(_pool(), call)
+    try:
+        return await asyncio.shield(future)
+    except asyncio.CancelledError:
+        if on_cancel is not None:
+            on_cancel()
+        await asyncio.wait([future])
+        raise
+
+
+async def connect(
+    connection_str: str = "", attempt: Optional[ConnectAttempt] = None, **kwargs: Any
+) -> "AsyncConnection":
+    """
+    Open a connection without blocking the event loop; see mssql_python.connect()
+    for the arguments. Cancelling the awaiting task cancels the connect.
+
+    Returns:
+        AsyncConnection: The open connection.
+    """
+    # pylint: disable=import-outside-toplevel
+    from mssql_python.db_connection import connect as connect_blocking
+
+    attempt = attempt or ConnectAttempt()
+    connection = await _run_blocking(
+        functools.partial(connect_blocking, connection_str, attempt=attempt, **kwargs),
+        attempt.cancel_connect,
+    )
+    return AsyncConnection(connection)
+
+
+class AsyncConnection:
+    """
+    An asyncio wrapper of a Connection, created by mssql_python.aio.connect().
+
+    Methods that talk to the server are coroutines; the wrapped Connection is
+    available as ``connection`` for everything else.
+    """
+
+    def __init__(self, connection: Connection) -> None:
+        self.connection = connection
+        # Created on first use, in the running event loop
+        self._lock: Optional[asyncio.Lock] = None
+
+    async def _run(
+        self, call: Callable[..., Any], *args: Any, on_cancel: Optional[Callable[[], None]] = None
+    ) -> Any:
+        """Return call(*args) run on the pool once earlier calls of this connection ended."""
+        if self._lock is None:
+            self._lock = asyncio.Lock()
+        async with self._lock:
+            return await _run_blocking(functools.partial(call, *args), on_cancel)
+
+    @property
+    def closed(self) -> bool:
+        """Whether the connection is closed."""
+        return self.connection.closed
+
+    @property
+    def autocommit(self) -> bool:
+        """Whether each statement is committed as it runs."""
+        return self.connection.autocommit
+
+    async def setautocommit(self, value: bool) -> None:
+        """Turn autocommit on or off; see Connection.setautocommit()."""
+        await self._run(self.connection.setautocommit, value)
+
+    def cursor(self) -> "AsyncCursor":
+        """Return a new AsyncCursor of this connection."""
+        return AsyncCursor(self, self.connection.cursor())
+
+    async def execute(self, sql: str, *params: Any) -> "AsyncCursor":
+        """Execute sql on a new cursor and return it; see Connection.execute()."""
+        cursor = self.cursor()
+        await cursor.execute(sql, *params)
+        return cursor
+
+    async def _execute_and_fetch(self, fetch: str, sql: str, params: Any) -> Any:
+        async with self.cursor() as cursor:
+            await cursor.execute(sql, *params)
+            return await getattr(cursor, fetch)()
+
+    async def fetch_val(
+        self, sql: str, *params: Any, as_type: Optional[type] = None, default: Any = NO_DEFAULT
+    ) -> Any:
+        """Return the first value of a query's first row; see Connection.fetch_val()."""
+        value = await self._execute_and_fetch("fetchval", sql, params)
+        return value if as_type is None else typed_scalar(value, as_type, default, sql)
+
+    async def fetch_row(self, sql: str, *params: Any) -> Optional[Row]:
+        """Return the first row of a query, None if it has none."""
+        return await self._execute_and_fetch("fetchone", sql, params)
+
+    async def fetch_all(self, sql: str, *params: Any) -> List[Row]:
+        """Return all rows of a query."""
+        return await self._execute_and_fetch("fetchall", sql, params)
+
+    async def commit(self) -> None:
+        """Commit the current transaction."""
+        await self._run(self.connection.commit)
+
+    async def rollback(self) -> None:
+        """Roll back the current transaction."""
+        await self._run(self.connection.rollback)
+
+    async def close(self) -> None:
+        """Close the connection and its cursors."""
+        await self._run(self.connection.close)
+
+    async def __aenter__(self) -> "AsyncConnection":
+        return self
+
+    async def __aexit__(self, *exc_info: Any) -> None:
+        if not self.closed:
+            await self.close()
+
+    def __repr__(self) -> str:
+        return f"<AsyncConnection {self.connection!r}>"
+
+
+class AsyncCursor:
+    """
+    An asyncio wrapper of a Cursor, created by AsyncConnection.cursor().
+
+    Supports ``async for`` over the rows of the current result set and
+    ``async with``. The wrapped Cursor is available as ``cursor``.
+    """
+
+    def __init__(self, connection: AsyncConnection, cursor: Cursor) -> None:
+        self.connection = connection
+        self.cursor = cursor
+
+    def _cancel(self) -> None:
+        """Send an attention for the statement running on this cursor."""
+        try:
+            ddbc_bindings.DDBCSQLCancel(self.cursor.hstmt)
+        except Exception as e:  # pylint: disable=broad-exception-caught
+            logger.warning("AsyncCursor: Could not cancel the statement: %s", e)
+
+    @property
+    def description(self) -> Any:
+        """The description of the current result set's columns; see Cursor.description."""
+        return self.cursor.description
+
+    @property
+    def rowcount(self) -> int:
+        """Rows affected by the last statement, -1 if unknown."""
+        return self.cursor.rowcount
+
+    @property
+    def arraysize(self) -> int:
+        """Rows fetched at a time by fetchmany() and by iteration."""
+        return self.cursor.arraysize
+
+    @arraysize.setter
+    def arraysize(self, value: int) -> None:
+        self.cursor.arraysize = value
+
+    async def execute(self, operation: Any, *parameters: Any, **kwargs: Any) -> "AsyncCursor":
+        """Execute a statement; see Cursor.execute() for the arguments."""
+        await self.connection._run(
+            functools.partial(self.cursor.execute, operation, *parameters, **kwargs),
+            on_cancel=self._cancel,
+        )
+        return self
+
+    async def executemany(self, operation: str, seq_of_parameters: Sequence[Any]) -> None:
+        """Execute a statement once per parameter set; see Cursor.executemany()."""
+        await self.connection._run(
+            self.cursor.executemany, operation, seq_of_parameters, on_cancel=self._cancel
+        )
+
+    async def fetchval(self) -> Any:
+        """Return the first column of the next row; see Cursor.fetchval()."""
+        return await self.connection._run(self.cursor.fetchval)
+
+    async def fetchone(self) -> Optional[Row]:
+        """Return the next row, None when no rows are left."""
+        return await self.connection._run(self.cursor.fetchone)
+
+    async def fetchmany(self, size: Optional[int] = None) -> List[Row]:
+        """Return up to size (default arraysize) next rows."""
+        return await self.connection._run(self.cursor.fetchmany, size)
+
+    async def fetchall(self) -> List[Row]:
+        """Return the remaining rows."""
+        return await self.connection._run(self.cursor.fetchall)
+
+    async def nextset(self) -> Optional[bool]:
+        """Move to the next result set; True if there is one."""
+        return await self.connection._run(self.cursor.nextset)
+
+    async def close(self) -> None:
+        """Close the cursor."""
+        await self.connection._run(self.cursor.close)
+
+    async def __aiter__(self) -> AsyncIterator[Row]:
+        size = self.arraysize if self.arraysize > 1 else _ITERATION_BATCH_SIZE
+        while True:
+            rows = await self.fetchmany(size)
+            if not rows:
+                return
+            for row in rows:
+                yield row
+
+    async def __aenter__(self) -> "AsyncCursor":
+        return self
+
+    async def __aexit__(self, *exc_info: Any) -> None:
+        if not self.cursor.closed:
+            await self.close()
@@ -0,0 +1,376 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module implements the audit event stream (mssql_python.enable_audit): every
+statement run by Cursor.execute() and Cursor.executemany() is reported, once it has
+run, as an AuditEvent to a callback or a JSON Lines file. Events are handed to the
+target by a background thread through a bounded queue, so a slow target does not
+slow down statements; while the queue is full, events are dropped and counted.
+"""
+
+import atexit
+import datetime
+import getpass
+import json
+import os
+import queue
+import re
+import socket
+import sys
+import threading
+import time
+from typing import TYPE_CHECKING, Any, Callable, Dict, Optional, Tuple, Union
+
+from mssql_python.logging import logger
+
+if TYPE_CHECKING:
+    from mssql_python.cursor import Cursor
+
+DEFAULT_MAX_QUEUE = 10000
+
+# Comments, string literals, quoted identifiers, numeric literals and whitespace runs;
+# identifiers come before numbers so [t1] and "2024" stay as they are
+_SQL_TOKEN_RE = re.compile(
+    r"""
+    (?P<comment>--[^\n]*|/\*.*?\*/)
+    | (?P<string>N?'(?:[^']|'')*'?)
+    | (?P<identifier>\[(?:[^\]]|\]\])*\]|"(?:[^"]|"")*")
+    | (?P<number>(?<![\w@#$])(?:0x[0-9A-Fa-f]*|\d+(?:\.\d*)?(?:[eE][+-]?\d+)?))
+    | (?P<space>\s+)
+    """,
+    re.VERBOSE | re.DOTALL,
+)
+
+
+def normalize_sql(sql: str) -> str:
+    """
+    Return sql with comments removed, literals replaced by ? and whitespace collapsed.
+
+    Statements that differ only in literal values normalize to the same text, and
+    values embedded in the SQL (not passed as parameters) stay out of the audit trail.
+
+    Examples:
+        normalize_sql("SELECT * FROM t -- all\\nWHERE id = 42 AND n = N'x'")
+        # 'SELECT * FROM t WHERE id = ? AND n = ?'
+    """
+    parts = []
+    position = 0
+    for match in _SQL_TOKEN_RE.finditer(sql):
+        if match.start() > position:
+            parts.append(sql[position : match.start()])
+        position = match.end()
+        kind = match.lastgroup
+        if kind == "identifier":
+            parts.append(match.group())
+        elif kind not in ("comment", "space"):
+            parts.append("?")
+        elif parts and parts[-1] != " ":
+            parts.append(" ")
+    parts.append(sql[position:])
+    return "".join(parts).strip()
+
+
+class AuditEvent:
+    """
+    An executed statement, as passed to the audit target.
+
+    Attributes:
+        timestamp: When the statement started, as an aware UTC datetime.
+        operation: "execute" or "executemany".
+        sql: The statement as sent to the server, normalized (see normalize_sql)
+            unless enable_audit() was called with normalize=False.
+        principal: The login: UID of the connection string, the operating system
+            user for Trusted_Connection, or None (e.g. for access tokens).
+        server: The Server of the connection string.
+        database: The Database of the connection string, or None for the default.
+        application: The application name the server sees (APP_NAME()).
+        host: This machine's host name.
+        process_id: This process's id.
+        session_id: The session's @@SPID if the connection has already read it
+            (Connection.session_id), else None; reading it would cost a round trip.
+        duration: Execution time in seconds, without fetching the results.
+        rowcount: Cursor.rowcount after the execution (-1 for result sets), or None
+            if the statement failed.
+        parameter_sets: Number of parameter sets (1 for execute()).
+        error: Exception class name if the statement failed, else None.
+        sqlstate: SQLSTATE of the failure, if any.
+    """
+
+    __slots__ = (
+        "timestamp",
+        "operation",
+        "sql",
+        "principal",
+        "server",
+        "database",
+        "application",
+        "host",
+        "process_id",
+        "session_id",
+        "duration",
+        "rowcount",
+        "parameter_sets",
+        "error",
+        "sqlstate",
+    )
+
+    def __init__(self, **fields: Any) -> None:
+        for name in self.__slots__:
+            setattr(self, name, fields.get(name))
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Return the event as a plain dictionary."""
+        return {name: getattr(self, name) for name in self.__slots__}
+
+    def __repr__(self) -> str:
+        return (
+            f"AuditEvent(operation={self.operation!r}, sql={self.sql!r}, "
+            f"principal={self.principal!r}, duration={self.duration!r}, "
+            f"rowcount={self.rowcount!r}, error={self.error!r})"
+        )
+
+
+def _client_info(connection: Any) -> Dict[str, Any]:
+    """Return the client fields of connection's events, computed once per connection."""
+    info = getattr(connection, "_audit_client", None)
+    if info is not None:
+        return info
+    # pylint: disable=import-outside-toplevel
+    from mssql_python.connection_string_parser import _ConnectionStringParser
+
+    try:
+        params = _ConnectionStringParser(validate_keywords=False)._parse(
+            getattr(connection, "connection_str", "") or ""
+        )
+    except Exception:  # pylint: disable=broad-exception-caught
+        params = {}
+    principal = params.get("uid")
+    if not principal and params.get("trusted_connection", "").lower() == "yes":
+        principal = getpass.getuser()
+        if sys.platform == "win32" and os.environ.get("USERDOMAIN"):
+            principal = f"{os.environ['USERDOMAIN']}\\{principal}"
+    info = {
+        "principal": principal or None,
+        "server": params.get("server"),
+        "database": params.get("database"),
+        "application": params.get("app"),
+        "host": socket.gethostname(),
+        "process_id": os.getpid(),
+    }
+    try:
+        connection._audit_client = info
+    except AttributeError:
+        pass
+    return info
+
+
+class AuditStream:
+    """
+    The active audit target and its delivery thread, returned by enable_audit().
+
+    Attributes:
+        delivered: Number of events handed to the target.
+        dropped: Number of events dropped because the queue was full.
+        failed: Number of events the target raised an exception for.
+    """
+
+    def __init__(
+        self,
+        target: Union[Callable[[AuditEvent], Any], str, "os.PathLike[str]", Any],
+        normalize: bool = True,
+        max_queue: int = DEFAULT_MAX_QUEUE,
+    ) -> None:
+        if not isinstance(max_queue, int) or max_queue < 1:
+            raise ValueError("max_queue must be a positive integer")
+        self._file = None
+        self._owns_file = False
+        if isinstance(target, (str, os.PathLike)):
+            self._file = open(target, "a", encoding="utf-8")  # pylint: disable=consider-using-with
+            self._owns_file = True
+        elif hasattr(target, "write"):
+            self._file = target
+        elif not callable(target):
+            raise TypeError("target must be a callable, a file path or a writable file")
+        self._callback = None if self._file is not None else target
+        self.normalize = normalize
+        self.max_queue = max_queue
+        self.delivered = 0
+        self.dropped = 0
+        self.failed = 0
+        self._closed = False
+        self._lock = threading.Lock()
+        self._queue: "queue.Queue[Optional[Tuple[Any, ...]]]" = queue.Queue(max_queue)
+        self._thread = threading.Thread(target=self._run, name="mssql-python-audit", daemon=True)
+        self._thread.start()
+
+    def submit(self, entry: Tuple[Any, ...]) -> None:
+        """Queue the fields of an event without blocking; drop it if the queue is full."""
+        if self._closed:
+            return
+        try:
+            self._queue.put_nowait(entry)
+        except queue.Full:
+            with self._lock:
+                self.dropped += 1
+
+    def _event(self, entry: Tuple[Any, ...]) -> AuditEvent:
+        started, operation, sql, client, duration, rowcount, parameter_sets, error = entry
+        if isinstance(sql, bytes):
+            sql = sql.decode("utf-8", "replace")
+        return AuditEvent(
+            timestamp=datetime.datetime.fromtimestamp(started, datetime.timezone.utc),
+            operation=operation,
+            sql=normalize_sql(sql) if self.normalize else sql,
+            session_id=client.pop("session_id"),
+            duration=duration,
+            rowcount=None if error is not None else rowcount,
+            parameter_sets=parameter_sets,
+            error=type(error).__name__ if error is not None else None,
+            sqlstate=getattr(error, "sqlstate", None),
+            **client,
+        )
+
+    def _deliver(self, event: AuditEvent) -> None:
+        if self._file is None:
+            self._callback(event)
+            return
+        record = event.to_dict()
+        record["timestamp"] = event.timestamp.isoformat()
+        self._file.write(json.dumps(record, default=str) + "\n")
+        self._file.flush()
+
+    def _run(self) -> None:
+        while True:
+            entry = self._queue.get()
+            try:
+                if entry is None:
+                    return
+                self._deliver(self._event(entry))
+                self.delivered += 1
+            except Exception:  # pylint: disable=broad-exception-caught
+                self.failed += 1
+                logger.debug("audit: Target raised an exception", exc_info=True)
+            finally:
+                self._queue.task_done()
+
+    def flush(self, timeout: Optional[float] = None) -> bool:
+        """
+        Wait until every queued event has been handed to the target.
+
+        Returns:
+            bool: False if timeout seconds passed first.
+        """
+        deadline = None if timeout is None else time.monotonic() + timeout
+        with self._queue.all_tasks_done:
+            while self._queue.unfinished_tasks:
+                remaining = None if deadline is None else deadline - time.monotonic()
+                if remaining is not None and remaining <= 0:
+                    return False
+                self._queue.all_tasks_done.wait(remaining)
+        return True
+
+    def close(self, timeout: Optional[float] = 5.0) -> None:
+        """Deliver the queued events (waiting up to timeout seconds) and stop the stream."""
+        if self._closed:
+            return
+        self._closed = True
+        try:
+            self._queue.put(None, timeout=timeout)
+        except queue.Full:
+            logger.warning("audit: Closed with %d events undelivered", self._queue.qsize())
+        self._thread.join(timeout)
+        if self._owns_file and not self._thread.is_alive():
+            self._file.close()
+
+    def stats(self) -> Dict[str, int]:
+        """Return the delivered, dropped, failed and queued event counts."""
+        return {
+            "delivered": self.delivered,
+            "dropped": self.dropped,
+            "failed": self.failed,
+            "queued": self._queue.qsize(),
+        }
+
+
+_stream: Optional[AuditStream] = None
+_stream_lock = threading.Lock()
+
+
+def enable_audit(
+    target: Union[Callable[[AuditEvent], Any], str, "os.PathLike[str]", Any],
+    normalize: bool = True,
+    max_queue: int = DEFAULT_MAX_QUEUE,
+) -> AuditStream:
+    """
+    Report every executed statement to target, for application-side audit trails.
+
+    The statement's caller only queues the event; a background thread passes it to
+    target. Replaces the stream of an earlier call, which is closed first.
+
+    Args:
+        target: A callable receiving AuditEvent objects (on the delivery thread), a
+            file path to append JSON Lines to, or a writable text file.
+        normalize: Record normalized SQL (see normalize_sql) instead of the text as
+            executed, which may hold literal values.
+        max_queue: Events that may wait for delivery; more are dropped, counted in
+            AuditStream.dropped.
+
+    Returns:
+        AuditStream: The new stream.
+
+    Examples:
+        import mssql_python
+
+        stream = mssql_python.enable_audit("/var/log/app/sql-audit.jsonl")
+        ...
+        mssql_python.disable_audit()
+    """
+    global _stream  # pylint: disable=global-statement
+    stream = AuditStream(target, normalize=normalize, max_queue=max_queue)
+    with _stream_lock:
+        previous, _stream = _stream, stream
+    if previous is not None:
+        previous.close()
+    return stream
+
+
+def disable_audit(timeout: Optional[float] = 5.0) -> None:
+    """Stop auditing, delivering the queued events first (waiting up to timeout seconds)."""
+    global _stream  # pylint: disable=global-statement
+    with _stream_lock:
+        previous, _stream = _stream, None
+    if previous is not None:
+        previous.close(timeout)
+
+
+def record(
+    cursor: "Cursor",
+    operation: str,
+    sql: str,
+    started: float,
+    error: Optional[BaseException] = None,
+    parameter_sets: int = 1,
+) -> None:
+    """Queue an event for a statement cursor ran from time.perf_counter() value started."""
+    stream = _stream
+    if stream is None:
+        return
+    duration = time.perf_counter() - started
+    connection = getattr(cursor, "_connection", None)
+    client = dict(_client_info(connection))
+    client["session_id"] = getattr(connection, "_session_id", None)
+    stream.submit(
+        (
+            time.time() - duration,
+            operation,
+            sql,
+            client,
+            duration,
+            getattr(cursor, "rowcount", None),
+            parameter_sets,
+            error,
+        )
+    )
+
+
+atexit.register(disable_audit, 2.0)
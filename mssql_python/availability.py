@@ -0,0 +1,144 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module reports the health of Always On availability groups from the HADR
+DMVs: the role, connection and synchronization state of every replica, whether
+secondaries are readable, and the synchronization of each availability database.
+"""
+
+from typing import TYPE_CHECKING, Any, Dict, List, Optional
+
+from mssql_python.exceptions import OperationalError, ProgrammingError
+
+if TYPE_CHECKING:
+    from mssql_python.connection import Connection
+
+_HADR_ENABLED_QUERY = "SELECT CAST(ISNULL(SERVERPROPERTY('IsHadrEnabled'), 0) AS int)"
+_GROUPS_QUERY = (
+    "SELECT ag.name, gs.primary_replica, gs.synchronization_health_desc "
+    "FROM sys.availability_groups AS ag "
+    "LEFT JOIN sys.dm_hadr_availability_group_states AS gs ON gs.group_id = ag.group_id "
+    "ORDER BY ag.name"
+)
+_REPLICAS_QUERY = (
+    "SELECT ag.name, ar.replica_server_name, rs.is_local, rs.role_desc, "
+    "rs.operational_state_desc, rs.connected_state_desc, rs.synchronization_health_desc, "
+    "ar.availability_mode_desc, ar.failover_mode_desc, ar.secondary_role_allow_connections_desc "
+    "FROM sys.availability_groups AS ag "
+    "JOIN sys.availability_replicas AS ar ON ar.group_id = ag.group_id "
+    "LEFT JOIN sys.dm_hadr_availability_replica_states AS rs ON rs.replica_id = ar.replica_id "
+    "ORDER BY ag.name, ar.replica_server_name"
+)
+_REPLICA_COLUMNS = (
+    "group",
+    "server",
+    "is_local",
+    "role",
+    "operational_state",
+    "connected_state",
+    "synchronization_health",
+    "availability_mode",
+    "failover_mode",
+    "secondary_allow_connections",
+)
+_DATABASES_QUERY = (
+    "SELECT ag.name, ar.replica_server_name, DB_NAME(drs.database_id), "
+    "drs.synchronization_state_desc, drs.synchronization_health_desc, drs.is_suspended, "
+    "drs.suspend_reason_desc, drs.log_send_queue_size, drs.redo_queue_size, "
+    "drs.last_commit_time "
+    "FROM sys.dm_hadr_database_replica_states AS drs "
+    "JOIN sys.availability_replicas AS ar ON ar.replica_id = drs.replica_id "
+    "JOIN sys.availability_groups AS ag ON ag.group_id = drs.group_id "
+    "ORDER BY ag.name, ar.replica_server_name, DB_NAME(drs.database_id)"
+)
+_DATABASE_COLUMNS = (
+    "group",
+    "server",
+    "database",
+    "synchronization_state",
+    "synchronization_health",
+    "is_suspended",
+    "suspend_reason",
+    "log_send_queue_kb",
+    "redo_queue_kb",
+    "last_commit_time",
+)
+# secondary_role_allow_connections_desc values that let clients read a secondary
+_READABLE = ("ALL", "READ_ONLY")
+
+
+def _fetchall(connection: "Connection", sql: str) -> List[tuple]:
+    cursor = connection.cursor()
+    try:
+        return [tuple(row) for row in cursor.execute(sql).fetchall()]
+    finally:
+        cursor.close()
+
+
+def availability_groups(connection: "Connection") -> List[Dict[str, Any]]:
+    """Return the availability groups of the server; see Connection."""
+    if not _fetchall(connection, _HADR_ENABLED_QUERY)[0][0]:
+        return []
+    groups = {
+        name: {
+            "name": name,
+            "primary_replica": primary,
+            "synchronization_health": health,
+            "local_role": None,
+            "replicas": [],
+        }
+        for name, primary, health in _fetchall(connection, _GROUPS_QUERY)
+    }
+    replicas = {}
+    for row in _fetchall(connection, _REPLICAS_QUERY):
+        replica = dict(zip(_REPLICA_COLUMNS, row))
+        group = groups[replica.pop("group")]
+        replica["is_local"] = bool(replica["is_local"])
+        replica["readable_secondary"] = replica["secondary_allow_connections"] in _READABLE
+        replica["databases"] = []
+        if replica["is_local"]:
+            group["local_role"] = replica["role"]
+        group["replicas"].append(replica)
+        replicas[(group["name"], replica["server"])] = replica
+    for row in _fetchall(connection, _DATABASES_QUERY):
+        database = dict(zip(_DATABASE_COLUMNS, row))
+        replica = replicas.get((database.pop("group"), database.pop("server")))
+        if replica is not None:
+            database["is_suspended"] = bool(database["is_suspended"])
+            replica["databases"].append(database)
+    return list(groups.values())
+
+
+def require_primary(
+    connection: "Connection", group: Optional[str] = None, require_healthy: bool = True
+) -> Dict[str, Any]:
+    """Raise unless this server is a healthy primary; see Connection.require_primary."""
+    groups = availability_groups(connection)
+    if group is not None:
+        groups = [entry for entry in groups if entry["name"].lower() == group.lower()]
+    if not groups:
+        raise ProgrammingError(
+            driver_error=(
+                f"Availability group {group!r} does not exist on this server"
+                if group is not None
+                else "This server is not part of an availability group"
+            ),
+            ddbc_error="No matching row in sys.availability_groups",
+        )
+    if len(groups) > 1:
+        raise ProgrammingError(
+            driver_error="The server has several availability groups: pass group",
+            ddbc_error=", ".join(entry["name"] for entry in groups),
+        )
+    entry = groups[0]
+    if entry["local_role"] != "PRIMARY":
+        raise OperationalError(
+            driver_error=f"This server is not the primary replica of {entry['name']!r}",
+            ddbc_error=f"Local role: {entry['local_role']}; primary: {entry['primary_replica']}",
+        )
+    if require_healthy and entry["synchronization_health"] != "HEALTHY":
+        raise OperationalError(
+            driver_error=f"Availability group {entry['name']!r} is not healthy",
+            ddbc_error=f"Synchronization health: {entry['synchronization_health']}",
+        )
+    return entry
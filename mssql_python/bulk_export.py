@@ -0,0 +1,343 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module implements parallel table export: a table is split into key ranges (or
+partitions) and each range is streamed to its own file on a separate connection.
+"""
+
+import csv
+import datetime
+import os
+import threading
+from concurrent.futures import ThreadPoolExecutor
+from typing import Any, Dict, List, Optional, Sequence, Tuple, TYPE_CHECKING
+
+from mssql_python.exceptions import ProgrammingError
+from mssql_python.helpers import quote_identifier, quote_multipart_name, split_multipart_name
+from mssql_python.logging import logger
+
+if TYPE_CHECKING:
+    from mssql_python.connection import Connection
+    from mssql_python.cursor import Cursor
+
+_SUPPORTED_FORMATS = ("csv", "parquet")
+
+# First key column of the clustered index, falling back to the primary key
+_SPLIT_COLUMN_QUERY = (
+    "SELECT TOP 1 c.name "
+    "FROM sys.indexes AS i "
+    "JOIN sys.index_columns AS ic "
+    "ON ic.object_id = i.object_id AND ic.index_id = i.index_id AND ic.key_ordinal = 1 "
+    "JOIN sys.columns AS c ON c.object_id = ic.object_id AND c.column_id = ic.column_id "
+    "WHERE i.object_id = OBJECT_ID(?) AND (i.index_id = 1 OR i.is_primary_key = 1) "
+    "ORDER BY i.index_id"
+)
+
+# Partition function, partitioning column and partition count of a partitioned table
+_PARTITION_SCHEME_QUERY = (
+    "SELECT pf.name, c.name, pf.fanout "
+    "FROM sys.indexes AS i "
+    "JOIN sys.partition_schemes AS ps ON ps.data_space_id = i.data_space_id "
+    "JOIN sys.partition_functions AS pf ON pf.function_id = ps.function_id "
+    "JOIN sys.index_columns AS ic "
+    "ON ic.object_id = i.object_id AND ic.index_id = i.index_id AND ic.partition_ordinal = 1 "
+    "JOIN sys.columns AS c ON c.object_id = ic.object_id AND c.column_id = ic.column_id "
+    "WHERE i.object_id = OBJECT_ID(?) AND i.index_id IN (0, 1)"
+)
+
+
+class ExportStream:
+    """One stream of a parallel export: a WHERE clause, its parameters and output file."""
+
+    def __init__(self, index: int, predicate: str, params: Sequence[Any], path: str) -> None:
+        self.index = index
+        self.predicate = predicate
+        self.params = list(params)
+        self.path = path
+        self.rows = 0
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Return the stream result as plain Python data."""
+        return {"stream": self.index, "path": self.path, "rows": self.rows}
+
+    def __repr__(self) -> str:
+        return f"ExportStream({self.index}, {self.predicate!r}, rows={self.rows})"
+
+
+def range_predicates(column: str, boundaries: Sequence[Any]) -> List[Tuple[str, List[Any]]]:
+    """
+    Build non-overlapping WHERE clauses that together cover every value of a column.
+
+    ``boundaries`` are the lower bounds of each range after the first (sorted,
+    duplicates allowed). The first range is open below and also takes NULL keys,
+    the last range is open above, so rows inserted outside the sampled key range
+    are never lost.
+
+    Returns:
+        A list of (predicate, params) pairs, one per range.
+    """
+    quoted = quote_identifier(column)
+    bounds: List[Any] = []
+    for value in boundaries:
+        if value is not None and (not bounds or value != bounds[-1]):
+            bounds.append(value)
+
+    if not bounds:
+        return [("1 = 1", [])]
+
+    predicates = [(f"({quoted} < ? OR {quoted} IS NULL)", [bounds[0]])]
+    for low, high in zip(bounds, bounds[1:]):
+        predicates.append((f"{quoted} >= ? AND {quoted} < ?", [low, high]))
+    predicates.append((f"{quoted} >= ?", [bounds[-1]]))
+    return predicates
+
+
+def partition_predicates(
+    function_name: str, column: str, partition_count: int, num_streams: int
+) -> List[Tuple[str, List[Any]]]:
+    """
+    Distribute the partitions of a table round-robin over at most num_streams streams.
+
+    Returns:
+        A list of (predicate, params) pairs using ``$PARTITION.<function>(<column>)``.
+    """
+    expression = f"$PARTITION.{quote_identifier(function_name)}({quote_identifier(column)})"
+    groups: List[List[int]] = [[] for _ in range(min(num_streams, partition_count))]
+    for number in range(1, partition_count + 1):
+        groups[(number - 1) % len(groups)].append(number)
+    return [
+        (f"{expression} IN ({', '.join('?' * len(group))})", list(group)) for group in groups
+    ]
+
+
+def _plan_streams(
+    cursor: "Cursor",
+    table_name: str,
+    num_streams: int,
+    split_column: Optional[str],
+    split: str,
+) -> List[Tuple[str, List[Any]]]:
+    """Choose partitions or key ranges for the table and return the stream predicates."""
+    quoted_table = quote_multipart_name(table_name)
+
+    if split in ("auto", "partitions") and split_column is None:
+        cursor.execute(_PARTITION_SCHEME_QUERY, table_name)
+        scheme = cursor.fetchone()
+        if scheme is not None and scheme[2] > 1:
+            logger.debug("bulk_export_parallel: Splitting %s by partition", table_name)
+            return partition_predicates(scheme[0], scheme[1], scheme[2], num_streams)
+        if split == "partitions":
+            raise ProgrammingError(
+                driver_error=f"Table '{table_name}' is not partitioned",
+                ddbc_error="split='partitions' requires a partitioned table",
+            )
+
+    if split_column is None:
+        cursor.execute(_SPLIT_COLUMN_QUERY, table_name)
+        split_column = cursor.fetchval()
+        if split_column is None:
+            raise ProgrammingError(
+                driver_error=f"Table '{table_name}' has no clustered index or primary key",
+                ddbc_error="Pass split_column to choose the column used to split the export",
+            )
+
+    if num_streams == 1:
+        return [("1 = 1", [])]
+
+    # The lowest key of each NTILE bucket (after the first) is a range boundary
+    quoted_column = quote_identifier(split_column)
+    cursor.execute(
+        f"SELECT MIN(k) FROM (SELECT {quoted_column} AS k, "
+        f"NTILE({int(num_streams)}) OVER (ORDER BY {quoted_column}) AS tile "
+        f"FROM {quoted_table} WHERE {quoted_column} IS NOT NULL) AS t "
+        "GROUP BY tile HAVING tile > 1 ORDER BY tile"
+    )
+    boundaries = [row[0] for row in cursor.fetchall()]
+    logger.debug(
+        "bulk_export_parallel: Splitting %s on %s into %d ranges",
+        table_name,
+        split_column,
+        len(boundaries) + 1,
+    )
+    return range_predicates(split_column, boundaries)
+
+
+def _csv_value(value: Any) -> Any:
+    """Convert a fetched value to its CSV text form."""
+    if value is None:
+        return ""
+    if isinstance(value, (bytes, bytearray)):
+        return value.hex()
+    if isinstance(value, (datetime.datetime, datetime.date, datetime.time)):
+        return value.isoformat()
+    return value
+
+
+def _write_csv(cursor: "Cursor", path: str, batch_size: int, cancelled: threading.Event) -> int:
+    rows = 0
+    with open(path, "w", newline="", encoding="utf-8") as handle:
+        writer = csv.writer(handle)
+        writer.writerow([column[0] for column in cursor.description])
+        while not cancelled.is_set():
+            batch = cursor.fetchmany(batch_size)
+            if not batch:
+                break
+            writer.writerows([_csv_value(v) for v in row] for row in batch)
+            rows += len(batch)
+    return rows
+
+
+def _write_parquet(
+    cursor: "Cursor", path: str, batch_size: int, cancelled: threading.Event
+) -> int:
+    import pyarrow.parquet  # pylint: disable=import-outside-toplevel
+
+    rows = 0
+    reader = cursor.arrow_reader(batch_size)
+    with pyarrow.parquet.ParquetWriter(path, reader.schema) as writer:
+        for batch in reader:
+            if cancelled.is_set():
+                break
+            writer.write_batch(batch)
+            rows += batch.num_rows
+    return rows
+
+
+def bulk_export_parallel(
+    connection: "Connection",
+    table_name: str,
+    num_streams: int,
+    output_dir: str = ".",
+    file_format: str = "csv",
+    split_column: Optional[str] = None,
+    split: str = "auto",
+    batch_size: int = 10000,
+) -> Dict[str, Any]:
+    """
+    Export a table to several files concurrently, one connection per stream.
+
+    The table is split by partition (when partitioned) or by ranges of the first
+    clustered index key, and every stream reads its slice inside a SNAPSHOT
+    isolation transaction on its own connection, so each file is transactionally
+    consistent in itself. The streams' snapshots are established one after the
+    other before any rows are read, not in one instant: a transaction committing
+    meanwhile can be seen by some streams and not others. For a single consistent
+    view of the whole table, export from a database snapshot
+    (Connection.create_snapshot) instead. The database must have
+    ALLOW_SNAPSHOT_ISOLATION ON.
+
+    Args:
+        connection: The connection used to plan the export; streams open new
+            connections with the same settings.
+        table_name: Table to export (can include schema, e.g., 'dbo.Sales').
+        num_streams: Number of concurrent streams (and output files).
+        output_dir: Directory for the output files, created if needed.
+        file_format: "csv" (with a header row) or "parquet" (requires pyarrow).
+        split_column: Column used to split the table into ranges. Defaults to the
+            first clustered index (or primary key) column.
+        split: "auto" (partitions if partitioned, otherwise key ranges),
+            "partitions", or "ranges".
+        batch_size: Rows fetched per round trip in each stream.
+
+    Returns:
+        Dictionary with:
+            - files: Paths of the written files, in stream order
+            - rows_exported: Total number of rows written
+            - streams: Per-stream dictionaries with stream, path and rows
+
+    Raises:
+        ProgrammingError: If the table cannot be split.
+        DatabaseError: If a stream fails. Partially written files are removed.
+    """
+    if not table_name or not isinstance(table_name, str):
+        raise ValueError("table_name must be a non-empty string")
+    if not isinstance(num_streams, int) or isinstance(num_streams, bool) or num_streams < 1:
+        raise ValueError(f"num_streams must be a positive integer, got {num_streams!r}")
+    if file_format not in _SUPPORTED_FORMATS:
+        raise ValueError(f"file_format must be one of {_SUPPORTED_FORMATS}, got {file_format!r}")
+    if split not in ("auto", "partitions", "ranges"):
+        raise ValueError("split must be 'auto', 'partitions' or 'ranges'")
+    if not isinstance(batch_size, int) or batch_size <= 0:
+        raise ValueError(f"batch_size must be a positive integer, got {batch_size!r}")
+    if file_format == "parquet":
+        try:
+            import pyarrow.parquet  # pylint: disable=import-outside-toplevel,unused-import
+        except ImportError as e:
+            raise ImportError("file_format='parquet' requires pyarrow") from e
+
+    planner = connection.cursor()
+    try:
+        predicates = _plan_streams(planner, table_name, num_streams, split_column, split)
+    finally:
+        planner.close()
+
+    os.makedirs(output_dir, exist_ok=True)
+    base_name = split_multipart_name(table_name)[-1]
+    streams = [
+        ExportStream(
+            index,
+            predicate,
+            params,
+            os.path.join(output_dir, f"{base_name}_{index:04d}.{file_format}"),
+        )
+        for index, (predicate, params) in enumerate(predicates)
+    ]
+    quoted_table = quote_multipart_name(table_name)
+    writer = _write_parquet if file_format == "parquet" else _write_csv
+    barrier = threading.Barrier(len(streams))
+    cancelled = threading.Event()
+
+    def _run(stream: ExportStream) -> None:
+        stream_conn = None
+        try:
+            stream_conn = connection._spawn_connection(autocommit=False)
+            cursor = stream_conn.cursor()
+            cursor.execute("SET TRANSACTION ISOLATION LEVEL SNAPSHOT")
+            # The snapshot is established by the first data access in the transaction
+            cursor.execute(f"SELECT TOP 1 1 FROM {quoted_table}")
+            cursor.fetchall()
+            # Reading starts once every stream has its snapshot, keeping them close
+            barrier.wait()
+            cursor.execute(
+                f"SELECT * FROM {quoted_table} WHERE {stream.predicate}", *stream.params
+            )
+            stream.rows = writer(cursor, stream.path, batch_size, cancelled)
+            stream_conn.commit()
+            logger.debug(
+                "bulk_export_parallel: Stream %d wrote %d rows", stream.index, stream.rows
+            )
+        except BaseException:
+            cancelled.set()
+            barrier.abort()
+            raise
+        finally:
+            if stream_conn is not None:
+                try:
+                    stream_conn.close()
+                except Exception:  # pylint: disable=broad-exception-caught
+                    logger.debug("bulk_export_parallel: Failed to close stream connection")
+
+    with ThreadPoolExecutor(max_workers=len(streams)) as executor:
+        futures = [executor.submit(_run, stream) for stream in streams]
+    errors = [f.exception() for f in futures if f.exception() is not None]
+    # A barrier abort in the other streams is a consequence, not the cause
+    errors.sort(key=lambda e: isinstance(e, threading.BrokenBarrierError))
+
+    if errors:
+        for stream in streams:
+            if os.path.exists(stream.path):
+                os.remove(stream.path)
+        raise errors[0]
+
+    total = sum(stream.rows for stream in streams)
+    logger.info(
+        "bulk_export_parallel: Exported %d rows from %s in %d streams",
+        total,
+        table_name,
+        len(streams),
+    )
+    return {
+        "files": [stream.path for stream in streams],
+        "rows_exported": total,
+        "streams": [stream.to_dict() for stream in streams],
+    }
@@ -0,0 +1,1080 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module contains helpers that sit around Cursor.bulkcopy(), such as comparing
+the schema of the source data with the target table before a load starts, loading
+several related tables in one transaction, and multi-row INSERT statements for
+loads without bulk copy permissions.
+"""
+
+import datetime
+import decimal
+import re
+import time
+import uuid
+from itertools import chain
+from typing import (
+    Any,
+    Callable,
+    Dict,
+    Iterable,
+    Iterator,
+    List,
+    Mapping,
+    Optional,
+    Sequence,
+    Tuple,
+    Union,
+    TYPE_CHECKING,
+)
+
+from mssql_python.exceptions import ProgrammingError, SchemaDriftError
+from mssql_python.helpers import quote_identifier, quote_multipart_name
+from mssql_python.logging import logger
+from mssql_python.parameter_helper import MAX_PARAMETERS, MAX_VALUES_ROWS
+
+if TYPE_CHECKING:
+    from mssql_python.connection import Connection
+    from mssql_python.cursor import Cursor
+
+# SQL Server type name -> type family used for compatibility checks
+_SQL_TYPE_FAMILIES: Dict[str, str] = {
+    "char": "string",
+    "varchar": "string",
+    "text": "string",
+    "nchar": "string",
+    "nvarchar": "string",
+    "ntext": "string",
+    "sysname": "string",
+    "binary": "binary",
+    "varbinary": "binary",
+    "image": "binary",
+    "timestamp": "binary",
+    "rowversion": "binary",
+    "tinyint": "integer",
+    "smallint": "integer",
+    "int": "integer",
+    "bigint": "integer",
+    "bit": "bit",
+    "decimal": "decimal",
+    "numeric": "decimal",
+    "money": "decimal",
+    "smallmoney": "decimal",
+    "float": "float",
+    "real": "float",
+    "date": "date",
+    "time": "time",
+    "datetime": "datetime",
+    "datetime2": "datetime",
+    "smalldatetime": "datetime",
+    "datetimeoffset": "datetimeoffset",
+    "uniqueidentifier": "uuid",
+    "xml": "xml",
+}
+
+# Python type -> target type families that accept its values without loss of meaning
+_PYTHON_TYPE_FAMILIES: Dict[type, Tuple[str, ...]] = {
+    bool: ("bit", "integer"),
+    int: ("integer", "decimal", "float", "bit"),
+    float: ("float", "decimal"),
+    decimal.Decimal: ("decimal", "float"),
+    str: ("string", "xml", "uuid"),
+    bytes: ("binary",),
+    bytearray: ("binary",),
+    datetime.datetime: ("datetime", "datetimeoffset", "date"),
+    datetime.date: ("date", "datetime"),
+    datetime.time: ("time",),
+    uuid.UUID: ("uuid", "string"),
+}
+
+# Python type -> column definition used when auto-adding a missing column
+_PYTHON_TYPE_DDL: Dict[type, str] = {
+    bool: "BIT",
+    int: "BIGINT",
+    float: "FLOAT",
+    decimal.Decimal: "DECIMAL(38, 10)",
+    str: "NVARCHAR(MAX)",
+    bytes: "VARBINARY(MAX)",
+    bytearray: "VARBINARY(MAX)",
+    datetime.datetime: "DATETIME2(7)",
+    datetime.date: "DATE",
+    datetime.time: "TIME(7)",
+    uuid.UUID: "UNIQUEIDENTIFIER",
+}
+
+# A conservative whitelist for user-supplied SQL type names (e.g. "NVARCHAR(50)")
+_SQL_TYPE_NAME_RE = re.compile(r"^[A-Za-z][A-Za-z0-9_ ]*(\(\s*(\d+|max)\s*(,\s*\d+\s*)?\))?$", re.I)
+
+# Limits of one INSERT ... VALUES statement: rows of a table value constructor, and
+# parameters of a request
+MAX_INSERT_ROWS = MAX_VALUES_ROWS
+MAX_INSERT_PARAMETERS = MAX_PARAMETERS
+
+# Rows bound per executemany() call by the ODBC bulk copy engine
+_ODBC_BULK_CHUNK_ROWS = 1000
+
+_TARGET_COLUMNS_QUERY = (
+    "SELECT c.name, t.name, c.max_length, c.precision, c.scale, c.is_nullable, "
+    "c.is_identity, c.is_computed, "
+    "CASE WHEN c.default_object_id <> 0 THEN 1 ELSE 0 END "
+    "FROM sys.columns AS c "
+    "JOIN sys.types AS t ON c.user_type_id = t.user_type_id "
+    "WHERE c.object_id = OBJECT_ID(?) "
+    "ORDER BY c.column_id"
+)
+
+SourceColumns = Union[
+    Mapping[str, Any],
+    Sequence[Union[str, Tuple[Any, ...]]],
+]
+
+
+class TargetColumn:
+    """Metadata for one column of the bulk copy target table."""
+
+    def __init__(
+        self,
+        name: str,
+        type_name: str,
+        is_nullable: bool = True,
+        has_default: bool = False,
+        is_identity: bool = False,
+        is_computed: bool = False,
+    ) -> None:
+        self.name = name
+        self.type_name = type_name
+        self.is_nullable = is_nullable
+        self.has_default = has_default
+        self.is_identity = is_identity
+        self.is_computed = is_computed
+
+    @property
+    def requires_value(self) -> bool:
+        """True if a load that omits this column will fail (NOT NULL, no default)."""
+        return not (self.is_nullable or self.has_default or self.is_identity or self.is_computed)
+
+    def __repr__(self) -> str:
+        return f"TargetColumn({self.name!r}, {self.type_name!r}, nullable={self.is_nullable})"
+
+
+class ColumnTypeMismatch:
+    """A source column whose type cannot be stored in the matching target column."""
+
+    def __init__(self, name: str, source_type: Any, target_type: str) -> None:
+        self.name = name
+        self.source_type = source_type
+        self.target_type = target_type
+
+    def __repr__(self) -> str:
+        source = getattr(self.source_type, "__name__", self.source_type)
+        return f"ColumnTypeMismatch({self.name!r}, source={source}, target={self.target_type})"
+
+    def __eq__(self, other: Any) -> bool:
+        if not isinstance(other, ColumnTypeMismatch):
+            return NotImplemented
+        return (self.name, self.source_type, self.target_type) == (
+            other.name,
+            other.source_type,
+            other.target_type,
+        )
+
+
+class SchemaDriftReport:
+    """
+    Structured result of comparing a bulk load source schema with its target table.
+
+    Attributes:
+        table_name: The target table that was inspected.
+        missing_columns: Source columns that do not exist in the target table.
+        extra_columns: Target columns that the source does not supply.
+        unfillable_columns: Subset of extra_columns that are NOT NULL without a default,
+            identity, or computed value; a load that omits them will fail.
+        type_mismatches: Source columns whose type is incompatible with the target column.
+        added_columns: Columns that were added to the target as nullable columns
+            (only populated when add_missing_columns=True).
+    """
+
+    def __init__(self, table_name: str) -> None:
+        self.table_name = table_name
+        self.missing_columns: List[str] = []
+        self.extra_columns: List[str] = []
+        self.unfillable_columns: List[str] = []
+        self.type_mismatches: List[ColumnTypeMismatch] = []
+        self.added_columns: List[str] = []
+
+    @property
+    def has_drift(self) -> bool:
+        """True if the source and target schemas differ in any way."""
+        return bool(self.missing_columns or self.extra_columns or self.type_mismatches)
+
+    @property
+    def is_compatible(self) -> bool:
+        """True if a load of the source into the target is expected to succeed."""
+        return not (self.missing_columns or self.unfillable_columns or self.type_mismatches)
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Return the report as plain Python data, e.g. for logging or JSON output."""
+        return {
+            "table_name": self.table_name,
+            "missing_columns": list(self.missing_columns),
+            "extra_columns": list(self.extra_columns),
+            "unfillable_columns": list(self.unfillable_columns),
+            "type_mismatches": [
+                {
+                    "name": m.name,
+                    "source_type": getattr(m.source_type, "__name__", str(m.source_type)),
+                    "target_type": m.target_type,
+                }
+                for m in self.type_mismatches
+            ],
+            "added_columns": list(self.added_columns),
+        }
+
+    def summary(self) -> str:
+        """Return a one-line human-readable description of the drift."""
+        parts = []
+        if self.missing_columns:
+            parts.append(f"missing in target: {', '.join(self.missing_columns)}")
+        if self.unfillable_columns:
+            parts.append(
+                f"required target columns not supplied: {', '.join(self.unfillable_columns)}"
+            )
+        if self.type_mismatches:
+            parts.append(
+                "type mismatches: "
+                + ", ".join(
+                    f"{m.name} ({getattr(m.source_type, '__name__', m.source_type)} -> "
+                    f"{m.target_type})"
+                    for m in self.type_mismatches
+                )
+            )
+        if not parts:
+            return "no incompatible drift"
+        return "; ".join(parts)
+
+    def raise_if_incompatible(self) -> None:
+        """
+        Raise SchemaDriftError if the load is expected to fail.
+
+        Raises:
+            SchemaDriftError: With this report attached as ``exc.report``.
+        """
+        if not self.is_compatible:
+            raise SchemaDriftError(
+                driver_error=f"Source schema does not match target table '{self.table_name}'",
+                ddbc_error=self.summary(),
+                report=self,
+            )
+
+    def __repr__(self) -> str:
+        return f"SchemaDriftReport({self.table_name!r}, {self.summary()})"
+
+
+def _normalize_source_columns(source_columns: SourceColumns) -> List[Tuple[str, Any]]:
+    """
+    Normalize the accepted source column specifications to (name, type) pairs.
+
+    Accepts a mapping of name -> type, a sequence of names, a sequence of
+    (name, type) pairs, or a cursor.description. The type may be a Python type,
+    a SQL Server type name string, or None when unknown.
+    """
+    if source_columns is None or isinstance(source_columns, (str, bytes)):
+        raise TypeError("source_columns must be a mapping or a sequence of column specs")
+
+    if isinstance(source_columns, Mapping):
+        items: Iterable[Any] = source_columns.items()
+    else:
+        items = source_columns
+
+    normalized = []
+    for spec in items:
+        if isinstance(spec, str):
+            normalized.append((spec, None))
+        elif isinstance(spec, (tuple, list)) and spec and isinstance(spec[0], str):
+            normalized.append((spec[0], spec[1] if len(spec) > 1 else None))
+        else:
+            raise TypeError(f"Invalid source column specification: {spec!r}")
+
+    seen = set()
+    for name, _ in normalized:
+        key = name.casefold()
+        if key in seen:
+            raise ValueError(f"Duplicate source column name: {name}")
+        seen.add(key)
+    return normalized
+
+
+def _source_type_families(source_type: Any) -> Optional[Tuple[str, ...]]:
+    """Return the target families compatible with a source type, or None if unknown."""
+    if source_type is None:
+        return None
+    if isinstance(source_type, str):
+        family = _SQL_TYPE_FAMILIES.get(source_type.split("(")[0].strip().lower())
+        return (family,) if family else None
+    if isinstance(source_type, type):
+        # Walk the MRO so subclasses (e.g. IntEnum) resolve to their base type
+        for base in source_type.__mro__:
+            if base in _PYTHON_TYPE_FAMILIES:
+                return _PYTHON_TYPE_FAMILIES[base]
+    return None
+
+
+def _column_definition(source_type: Any) -> Optional[str]:
+    """Return the column definition used when auto-adding a source column."""
+    if isinstance(source_type, str):
+        return source_type.strip() if _SQL_TYPE_NAME_RE.match(source_type.strip()) else None
+    if isinstance(source_type, type):
+        for base in source_type.__mro__:
+            if base in _PYTHON_TYPE_DDL:
+                return _PYTHON_TYPE_DDL[base]
+    return None
+
+
+def compare_schemas(
+    table_name: str,
+    source_columns: SourceColumns,
+    target_columns: Sequence[TargetColumn],
+) -> SchemaDriftReport:
+    """
+    Compare source column specifications with already-fetched target metadata.
+
+    Column names are matched case-insensitively, matching SQL Server's default
+    catalog collation. Columns with an unknown source type are only checked by name.
+
+    Args:
+        table_name: The target table name (used in the report only).
+        source_columns: The source column specification (see bulkcopy source_columns).
+        target_columns: Metadata for the target table's columns.
+
+    Returns:
+        SchemaDriftReport: The structured comparison.
+    """
+    source = _normalize_source_columns(source_columns)
+    target_by_name = {col.name.casefold(): col for col in target_columns}
+    source_names = {name.casefold() for name, _ in source}
+
+    report = SchemaDriftReport(table_name)
+    for name, source_type in source:
+        target = target_by_name.get(name.casefold())
+        if target is None:
+            report.missing_columns.append(name)
+            continue
+        families = _source_type_families(source_type)
+        target_family = _SQL_TYPE_FAMILIES.get(target.type_name.lower(), "other")
+        if families is not None and target_family != "other" and target_family not in families:
+            report.type_mismatches.append(ColumnTypeMismatch(name, source_type, target.type_name))
+
+    for col in target_columns:
+        if col.name.casefold() not in source_names:
+            report.extra_columns.append(col.name)
+            if col.requires_value:
+                report.unfillable_columns.append(col.name)
+    return report
+
+
+def fetch_target_columns(cursor: "Cursor", table_name: str) -> List[TargetColumn]:
+    """
+    Read column metadata for a table from sys.columns.
+
+    Raises:
+        ProgrammingError: If the table does not exist or is not visible to the login.
+    """
+    cursor.execute(_TARGET_COLUMNS_QUERY, table_name)
+    rows = cursor.fetchall()
+    if not rows:
+        raise ProgrammingError(
+            driver_error=f"Target table '{table_name}' was not found",
+            ddbc_error="OBJECT_ID() returned no columns for the table",
+        )
+    return [
+        TargetColumn(
+            name=row[0],
+            type_name=row[1],
+            is_nullable=bool(row[5]),
+            has_default=bool(row[8]),
+            is_identity=bool(row[6]),
+            is_computed=bool(row[7]),
+        )
+        for row in rows
+    ]
+
+
+def detect_schema_drift(
+    cursor: "Cursor",
+    table_name: str,
+    source_columns: SourceColumns,
+    add_missing_columns: bool = False,
+) -> SchemaDriftReport:
+    """
+    Compare the source schema with the target table and optionally add missing columns.
+
+    When add_missing_columns is True, every source column that is absent from the
+    target is added with ``ALTER TABLE ... ADD <column> <type> NULL`` and moved from
+    ``missing_columns`` to ``added_columns``. The type is inferred from the source
+    type (Python type or SQL type name); columns with an unknown type are left in
+    ``missing_columns``.
+
+    Args:
+        cursor: Cursor used to read metadata and run any ALTER TABLE statements.
+        table_name: Target table name (may include schema, e.g. 'dbo.MyTable').
+        source_columns: The source column specification.
+        add_missing_columns: Whether to add source columns missing from the target.
+
+    Returns:
+        SchemaDriftReport: The structured comparison.
+    """
+    report = compare_schemas(table_name, source_columns, fetch_target_columns(cursor, table_name))
+
+    if add_missing_columns and report.missing_columns:
+        source_types = dict(
+            (name.casefold(), source_type)
+            for name, source_type in _normalize_source_columns(source_columns)
+        )
+        quoted_table = quote_multipart_name(table_name)
+        for name in list(report.missing_columns):
+            definition = _column_definition(source_types[name.casefold()])
+            if definition is None:
+                logger.warning(
+                    "detect_schema_drift: Cannot infer a type for missing column %s", name
+                )
+                continue
+            cursor.execute(
+                f"ALTER TABLE {quoted_table} ADD {quote_identifier(name)} {definition} NULL"
+            )
+            report.missing_columns.remove(name)
+            report.added_columns.append(name)
+            logger.info("detect_schema_drift: Added nullable column %s to %s", name, table_name)
+
+    logger.debug("detect_schema_drift: %r", report)
+    return report
+
+
+_FOREIGN_KEYS_QUERY = (
+    "SELECT DISTINCT fk.parent_object_id, fk.referenced_object_id "
+    "FROM sys.foreign_keys AS fk "
+    "WHERE fk.parent_object_id <> fk.referenced_object_id"
+)
+
+
+class TableLoad:
+    """
+    One table's share of a multi-table load.
+
+    Args:
+        table_name: Target table name (can include schema, e.g., 'dbo.Orders').
+        rows: Iterable of row tuples (or Row objects) to insert.
+        columns: Optional target column names, in the order the row values appear.
+            When omitted, values are inserted by ordinal position.
+        keep_identity: Insert explicit values into the table's identity column
+            (wraps the load in SET IDENTITY_INSERT ON/OFF).
+    """
+
+    def __init__(
+        self,
+        table_name: str,
+        rows: Iterable[Sequence[Any]],
+        columns: Optional[Sequence[str]] = None,
+        keep_identity: bool = False,
+    ) -> None:
+        if not table_name or not isinstance(table_name, str):
+            raise ValueError("table_name must be a non-empty string")
+        if rows is None or isinstance(rows, (str, bytes)):
+            raise TypeError("rows must be an iterable of row sequences")
+        self.table_name = table_name
+        self.rows = rows
+        self.columns = list(columns) if columns is not None else None
+        self.keep_identity = keep_identity
+
+    def __repr__(self) -> str:
+        return f"TableLoad({self.table_name!r}, columns={self.columns!r})"
+
+
+class BulkCopyOptions:
+    """
+    The load options of Cursor.bulkcopy(), to build once and pass as options=.
+
+    Args:
+        batch_size: Rows per batch; 0 lets the server decide (a single batch).
+        timeout: Operation timeout in seconds.
+        keep_identity: Insert the source values into the identity column.
+        check_constraints: Check constraints while loading.
+        table_lock: Take a table lock instead of row locks.
+        keep_nulls: Keep NULL source values instead of applying column defaults.
+        fire_triggers: Fire the target's insert triggers.
+        use_internal_transaction: Run each batch in its own transaction.
+        order: Sort order of the source rows, as column names or (column,
+            "ASC" | "DESC") pairs. The server skips sorting when loading into a
+            table clustered on those columns; the ODBC engine, which sends plain
+            INSERTs, does not use it.
+    """
+
+    def __init__(
+        self,
+        batch_size: int = 0,
+        timeout: int = 30,
+        keep_identity: bool = False,
+        check_constraints: bool = False,
+        table_lock: bool = False,
+        keep_nulls: bool = False,
+        fire_triggers: bool = False,
+        use_internal_transaction: bool = False,
+        order: Optional[Sequence[Union[str, Tuple[str, str]]]] = None,
+    ) -> None:
+        self.batch_size = batch_size
+        self.timeout = timeout
+        self.keep_identity = keep_identity
+        self.check_constraints = check_constraints
+        self.table_lock = table_lock
+        self.keep_nulls = keep_nulls
+        self.fire_triggers = fire_triggers
+        self.use_internal_transaction = use_internal_transaction
+        self.order = normalize_order_hints(order)
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Return the options as keyword arguments of Cursor.bulkcopy()."""
+        return dict(vars(self))
+
+    def __repr__(self) -> str:
+        changed = {
+            name: value for name, value in self.to_dict().items() if value != _DEFAULT_OPTIONS[name]
+        }
+        return "BulkCopyOptions(" + ", ".join(f"{k}={v!r}" for k, v in changed.items()) + ")"
+
+
+def normalize_order_hints(
+    order: Optional[Sequence[Union[str, Tuple[str, str]]]],
+) -> Optional[List[Tuple[str, str]]]:
+    """
+    Return bulk copy order hints as (column, "ASC" | "DESC") pairs, or None if empty.
+
+    Raises:
+        ValueError: If a column name is empty or a direction is not ASC or DESC.
+    """
+    if not order:
+        return None
+    if isinstance(order, str):
+        order = [order]
+    hints = []
+    for hint in order:
+        column, direction = (hint, "ASC") if isinstance(hint, str) else tuple(hint)
+        if not column or not isinstance(column, str):
+            raise ValueError(f"order hint columns must be non-empty strings, got {column!r}")
+        direction = str(direction).upper()
+        if direction not in ("ASC", "DESC"):
+            raise ValueError(f"order hint direction must be 'ASC' or 'DESC', got {direction!r}")
+        hints.append((column, direction))
+    return hints
+
+
+# Option values of a plain BulkCopyOptions(), i.e. the bulkcopy() defaults
+_DEFAULT_OPTIONS = BulkCopyOptions().to_dict()
+
+
+def order_table_loads(
+    loads: Sequence[TableLoad], dependencies: Iterable[Tuple[int, int]]
+) -> List[TableLoad]:
+    """
+    Order loads so that referenced (parent) tables are loaded before referencing ones.
+
+    Loads with no ordering constraint between them keep their relative input order.
+
+    Args:
+        loads: The table loads, in caller order.
+        dependencies: (child_index, parent_index) pairs into ``loads``.
+
+    Returns:
+        The loads in a dependency-respecting order.
+
+    Raises:
+        ProgrammingError: If the dependencies contain a cycle.
+    """
+    parents: Dict[int, set] = {i: set() for i in range(len(loads))}
+    for child, parent in dependencies:
+        if child != parent:
+            parents[child].add(parent)
+
+    ordered: List[int] = []
+    remaining = list(range(len(loads)))
+    while remaining:
+        ready = next((i for i in remaining if not parents[i] - set(ordered)), None)
+        if ready is None:
+            cycle = ", ".join(loads[i].table_name for i in remaining)
+            raise ProgrammingError(
+                driver_error="Cannot order multi-table load: foreign keys form a cycle",
+                ddbc_error=f"Tables involved: {cycle}",
+            )
+        ordered.append(ready)
+        remaining.remove(ready)
+    return [loads[i] for i in ordered]
+
+
+def _foreign_key_dependencies(
+    cursor: "Cursor", loads: Sequence[TableLoad]
+) -> List[Tuple[int, int]]:
+    """Return (child_index, parent_index) pairs for foreign keys between the load targets."""
+    index_by_object_id: Dict[int, int] = {}
+    for index, load in enumerate(loads):
+        cursor.execute("SELECT OBJECT_ID(?)", load.table_name)
+        object_id = cursor.fetchval()
+        if object_id is None:
+            raise ProgrammingError(
+                driver_error=f"Target table '{load.table_name}' was not found",
+                ddbc_error="OBJECT_ID() returned NULL for the table",
+            )
+        if object_id in index_by_object_id:
+            raise ValueError(f"Table '{load.table_name}' appears more than once in the load")
+        index_by_object_id[object_id] = index
+
+    cursor.execute(_FOREIGN_KEYS_QUERY)
+    return [
+        (index_by_object_id[child], index_by_object_id[parent])
+        for child, parent in cursor.fetchall()
+        if child in index_by_object_id and parent in index_by_object_id
+    ]
+
+
+def _insert_rows(cursor: "Cursor", load: TableLoad, batch_size: int) -> int:
+    """Insert one table's rows with executemany in batches; return the row count."""
+    quoted_table = quote_multipart_name(load.table_name)
+    column_list = ""
+    if load.columns:
+        column_list = " (" + ", ".join(quote_identifier(c) for c in load.columns) + ")"
+
+    if load.keep_identity:
+        cursor.execute(f"SET IDENTITY_INSERT {quoted_table} ON")
+    inserted = 0
+    try:
+        sql = None
+        batch: List[Tuple[Any, ...]] = []
+        for row in load.rows:
+            values = tuple(row)
+            if sql is None:
+                placeholders = ", ".join("?" * len(values))
+                sql = f"INSERT INTO {quoted_table}{column_list} VALUES ({placeholders})"
+            batch.append(values)
+            if len(batch) >= batch_size:
+                cursor.executemany(sql, batch)
+                inserted += len(batch)
+                batch = []
+        if batch:
+            cursor.executemany(sql, batch)
+            inserted += len(batch)
+    except BaseException:
+        if load.keep_identity:
+            try:
+                cursor.execute(f"SET IDENTITY_INSERT {quoted_table} OFF")
+            except Exception:  # pylint: disable=broad-exception-caught
+                # The insert failure is the error to report, not this one
+                logger.warning(
+                    "load_tables: Could not turn IDENTITY_INSERT off for %s",
+                    load.table_name,
+                    exc_info=True,
+                )
+        raise
+    if load.keep_identity:
+        cursor.execute(f"SET IDENTITY_INSERT {quoted_table} OFF")
+    return inserted
+
+
+def load_tables(
+    cursor: "Cursor",
+    loads: Sequence[TableLoad],
+    order: str = "dependencies",
+    batch_size: int = 1000,
+) -> Dict[str, int]:
+    """
+    Insert rows into several tables as a single all-or-nothing transaction.
+
+    Rows are inserted through ``cursor`` (and therefore on its connection), so every
+    table shares one transaction. On success the transaction is committed; on any
+    failure it is rolled back and the error is re-raised, leaving every table as it
+    was. If autocommit is enabled it is switched off for the duration of the load
+    and restored afterwards. The connection must not have a transaction open, since
+    committing or rolling back the load would also end the caller's pending work.
+
+    Args:
+        cursor: Cursor whose connection runs the load.
+        loads: The table loads.
+        order: "dependencies" to load parent tables before child tables using the
+            foreign keys between the targets, or "given" to keep the caller's order.
+        batch_size: Maximum number of rows per executemany() call.
+
+    Returns:
+        Dict mapping each table name to the number of rows inserted, in load order.
+
+    Raises:
+        ProgrammingError: If a transaction is open on the connection, a table is
+            missing or the foreign keys form a cycle.
+    """
+    if order not in ("dependencies", "given"):
+        raise ValueError("order must be 'dependencies' or 'given'")
+    if not isinstance(batch_size, int) or isinstance(batch_size, bool) or batch_size <= 0:
+        raise ValueError(f"batch_size must be a positive integer, got {batch_size!r}")
+    loads = list(loads)
+    if not all(isinstance(load, TableLoad) for load in loads):
+        raise TypeError("loads must be a sequence of TableLoad objects")
+
+    connection = cursor.connection
+    if not connection.autocommit and connection.in_transaction:
+        raise ProgrammingError(
+            driver_error="load_tables() cannot run with a transaction open",
+            ddbc_error="Commit or roll back the connection's transaction first",
+        )
+    restore_autocommit = connection.autocommit
+    if restore_autocommit:
+        connection.autocommit = False
+
+    results: Dict[str, int] = {}
+    try:
+        if order == "dependencies" and len(loads) > 1:
+            loads = order_table_loads(loads, _foreign_key_dependencies(cursor, loads))
+        for load in loads:
+            results[load.table_name] = _insert_rows(cursor, load, batch_size)
+            logger.debug(
+                "load_tables: Inserted %d rows into %s", results[load.table_name], load.table_name
+            )
+        connection.commit()
+    except BaseException:
+        try:
+            connection.rollback()
+        except Exception:  # pylint: disable=broad-exception-caught
+            logger.warning("load_tables: Rollback after failed load also failed", exc_info=True)
+        raise
+    finally:
+        if restore_autocommit:
+            try:
+                connection.autocommit = True
+            except Exception:  # pylint: disable=broad-exception-caught
+                logger.warning("load_tables: Failed to restore autocommit", exc_info=True)
+
+    logger.info(
+        "load_tables: Committed %d rows across %d tables", sum(results.values()), len(results)
+    )
+    return results
+
+
+def insert_statements(
+    table_name: str,
+    columns: Sequence[str],
+    rows: Iterable[Sequence[Any]],
+    max_rows: int = MAX_INSERT_ROWS,
+) -> Iterator[Tuple[str, List[Any]]]:
+    """
+    Turn rows into parameterized multi-row INSERT ... VALUES statements.
+
+    Each statement inserts as many rows as fit in both the 1000-row limit of a
+    VALUES list (or max_rows, if lower) and the server's 2100-parameter limit, so
+    wide tables get fewer rows per statement. Every full chunk has the same SQL
+    text and reuses one cached plan; only the last statement may be shorter.
+    Rows are read lazily, one chunk at a time.
+
+    Args:
+        table_name: Target table name (can include schema, e.g., 'dbo.Orders').
+        columns: Target column names, in the order the row values appear.
+        rows: Iterable of row tuples (or Row objects), one value per column.
+        max_rows: Upper bound on rows per statement, at most 1000.
+
+    Yields:
+        (sql, parameters): A statement and its flattened parameter values.
+
+    Raises:
+        ValueError: If columns is empty or too wide for one parameterized row,
+            max_rows is out of range, or a row has the wrong number of values.
+    """
+    if not table_name or not isinstance(table_name, str):
+        raise ValueError("table_name must be a non-empty string")
+    if isinstance(columns, str) or not columns:
+        raise ValueError("columns must be a non-empty sequence of column names")
+    if len(columns) > MAX_INSERT_PARAMETERS:
+        raise ValueError(
+            f"{len(columns)} columns exceed the {MAX_INSERT_PARAMETERS} parameters of a request"
+        )
+    if not isinstance(max_rows, int) or isinstance(max_rows, bool):
+        raise ValueError(f"max_rows must be an integer, got {max_rows!r}")
+    if not 1 <= max_rows <= MAX_INSERT_ROWS:
+        raise ValueError(f"max_rows must be between 1 and {MAX_INSERT_ROWS}, got {max_rows}")
+
+    width = len(columns)
+    rows_per_statement = min(max_rows, MAX_INSERT_PARAMETERS // width)
+    prefix = (
+        f"INSERT INTO {quote_multipart_name(table_name)} ("
+        + ", ".join(quote_identifier(c) for c in columns)
+        + ") VALUES "
+    )
+    row_placeholder = "(" + ", ".join("?" * width) + ")"
+
+    def statement(row_count: int) -> str:
+        return prefix + ", ".join([row_placeholder] * row_count)
+
+    full_statement = statement(rows_per_statement)
+    parameters: List[Any] = []
+    row_count = 0
+    for index, row in enumerate(rows):
+        values = tuple(row)
+        if len(values) != width:
+            raise ValueError(f"Row {index} has {len(values)} values, expected {width}")
+        parameters.extend(values)
+        row_count += 1
+        if row_count == rows_per_statement:
+            yield full_statement, parameters
+            parameters, row_count = [], 0
+    if row_count:
+        yield statement(row_count), parameters
+
+
+def insert_rows(
+    cursor: "Cursor",
+    table_name: str,
+    columns: Sequence[str],
+    rows: Iterable[Sequence[Any]],
+    max_rows: int = MAX_INSERT_ROWS,
+) -> int:
+    """Run the statements of insert_statements() on cursor; return the rows inserted."""
+    inserted = 0
+    statements = 0
+    for sql, parameters in insert_statements(table_name, columns, rows, max_rows):
+        cursor.execute(sql, parameters)
+        inserted += len(parameters) // len(columns)
+        statements += 1
+    logger.debug(
+        "insert_rows: Inserted %d rows into %s in %d statements", inserted, table_name, statements
+    )
+    return inserted
+
+
+def report_row_progress(
+    rows: Iterable[Any], callback: Callable[[int], Any], every: int
+) -> Iterator[Any]:
+    """
+    Yield rows lazily, calling callback with the count read so far every `every` rows.
+
+    The callback is also called once the rows are exhausted, with the total, unless
+    the last call already reported it.
+    """
+    count = 0
+    for row in rows:
+        yield row
+        count += 1
+        if count % every == 0:
+            callback(count)
+    if count % every or not count:
+        callback(count)
+
+
+def odbc_bulk_copy(
+    connection: "Connection",
+    table_name: str,
+    rows: Iterable[Any],
+    batch_size: int = 0,
+    timeout: int = 30,
+    column_mappings: Optional[Sequence[Any]] = None,
+    keep_identity: bool = False,
+    table_lock: bool = False,
+) -> Dict[str, Any]:
+    """
+    Bulk insert rows through the ODBC driver, for Cursor.bulkcopy(engine="odbc").
+
+    Rows are bound as parameter arrays (executemany) on a separate session of
+    connection, so the load does not join the caller's transaction. Every batch of
+    batch_size rows, or the whole load when batch_size is 0, is committed as it
+    completes; a failure rolls back the batch in progress only, as with the bulk
+    load protocol. The rows are ordinary INSERTs: constraints are checked,
+    triggers fire and NULL values are inserted as NULL.
+
+    Args:
+        connection: Connection whose server, database and credentials are used.
+        table_name: Target table name (can include schema, e.g., 'dbo.Orders').
+        rows: Iterable of tuples or Row objects.
+        batch_size: Rows per committed batch; 0 for a single batch.
+        timeout: Query timeout in seconds for each statement.
+        column_mappings: As for Cursor.bulkcopy(): target column names by source
+            position, or (source_index, target_column) pairs.
+        keep_identity: Insert the source values into the identity column.
+        table_lock: Take a table lock (TABLOCK) for the inserts.
+
+    Returns:
+        dict: rows_copied, batch_count and elapsed_time (seconds).
+    """
+    from mssql_python.row import Row  # pylint: disable=import-outside-toplevel
+
+    quoted_table = quote_multipart_name(table_name)
+    indexes: Optional[List[int]] = None
+    column_list = ""
+    if column_mappings:
+        if isinstance(column_mappings[0], (tuple, list)):
+            indexes = [int(index) for index, _ in column_mappings]
+            names = [name for _, name in column_mappings]
+        else:
+            names = list(column_mappings)
+        column_list = " (" + ", ".join(quote_identifier(name) for name in names) + ")"
+    hint = " WITH (TABLOCK)" if table_lock else ""
+
+    started = time.perf_counter()
+    session = connection._spawn_connection(autocommit=False)
+    session.timeout = timeout
+    cursor = session.cursor()
+    rows_copied = 0
+    batch_count = 0
+    uncommitted = 0
+    sql = None
+    try:
+        if keep_identity:
+            cursor.execute(f"SET IDENTITY_INSERT {quoted_table} ON")
+        chunk: List[Tuple[Any, ...]] = []
+
+        def send() -> None:
+            nonlocal uncommitted, batch_count
+            cursor.executemany(sql, chunk)
+            uncommitted += len(chunk)
+            chunk.clear()
+            if batch_size and uncommitted >= batch_size:
+                session.commit()
+                batch_count += 1
+                uncommitted = 0
+
+        for row in rows:
+            if isinstance(row, Row):
+                values = tuple(row._values)
+            elif isinstance(row, tuple):
+                values = row
+            else:
+                raise TypeError(
+                    f"bulkcopy data rows must be tuples or Row objects, got {type(row).__name__}"
+                )
+            if indexes is not None:
+                values = tuple(values[index] for index in indexes)
+            if sql is None:
+                placeholders = ", ".join("?" * len(values))
+                sql = f"INSERT INTO {quoted_table}{hint}{column_list} VALUES ({placeholders})"
+            chunk.append(values)
+            rows_copied += 1
+            limit = _ODBC_BULK_CHUNK_ROWS
+            if batch_size:
+                limit = min(limit, batch_size - uncommitted)
+            if len(chunk) >= limit:
+                send()
+        if chunk:
+            send()
+        if uncommitted or not batch_count:
+            session.commit()
+            batch_count += 1 if rows_copied else 0
+    except BaseException:
+        try:
+            session.rollback()
+        except Exception:  # pylint: disable=broad-exception-caught
+            logger.debug("bulkcopy: Rollback of the failed batch failed", exc_info=True)
+        raise
+    finally:
+        try:
+            session.close()
+        except Exception:  # pylint: disable=broad-exception-caught
+            logger.debug("bulkcopy: Closing the bulk copy session failed", exc_info=True)
+    elapsed = time.perf_counter() - started
+    logger.debug(
+        "bulkcopy: Copied %d rows into %s in %d batches through the driver",
+        rows_copied,
+        table_name,
+        batch_count,
+    )
+    return {"rows_copied": rows_copied, "batch_count": batch_count, "elapsed_time": elapsed}
+
+
+def prepare_idempotent_load(
+    cursor: "Cursor",
+    table_name: str,
+    data: Iterable[Any],
+    column_mappings: Optional[Sequence[Any]],
+    load_id: Any,
+    load_id_column: str = "load_id",
+) -> Tuple[Iterable[Tuple[Any, ...]], List[Any], int]:
+    """
+    Prepare a bulk copy that can be retried without duplicating rows.
+
+    Every row is stamped with ``load_id`` in ``load_id_column``. Rows left behind by
+    an earlier (possibly interrupted) attempt with the same load ID are deleted and
+    committed first, so re-running a load with the same ID always leaves exactly one
+    copy of its rows in the target. The DELETE is committed before the copy starts,
+    which would commit the caller's pending work too, so the load is refused while
+    a transaction is open on the connection.
+
+    Args:
+        cursor: Cursor used for the metadata query and the cleanup DELETE.
+        table_name: Target table name.
+        data: The bulk copy source rows (tuples or Row objects).
+        column_mappings: The caller's column mappings (None, List[str] or
+            List[Tuple[int, str]]).
+        load_id: The load identifier to stamp (e.g. a UUID or batch number).
+        load_id_column: Target column that stores the load identifier.
+
+    Returns:
+        (rows, column_mappings, rows_removed): the stamped row iterator, mappings that
+        include the load ID column, and how many stale rows were deleted.
+
+    Raises:
+        ProgrammingError: If the table or the load ID column does not exist, or a
+            transaction is open on the cursor's connection.
+    """
+    if load_id is None:
+        raise ValueError("load_id must not be None")
+    connection = cursor.connection
+    if not connection.autocommit and connection.in_transaction:
+        raise ProgrammingError(
+            driver_error="bulkcopy(load_id=...) cannot run with a transaction open",
+            ddbc_error="Commit or roll back the connection's transaction first",
+        )
+    target_columns = fetch_target_columns(cursor, table_name)
+    column_key = load_id_column.casefold()
+    if not any(col.name.casefold() == column_key for col in target_columns):
+        raise ProgrammingError(
+            driver_error=f"Load ID column '{load_id_column}' not found in '{table_name}'",
+            ddbc_error="Add the column to the target table or pass load_id_column",
+        )
+    if column_mappings and any(
+        (m[1] if isinstance(m, (tuple, list)) else m).casefold() == column_key
+        for m in column_mappings
+    ):
+        raise ValueError(f"column_mappings must not include the load ID column '{load_id_column}'")
+
+    from mssql_python.row import Row
+
+    iterator = iter(data)
+    first = next(iterator, None)
+    if first is not None and not isinstance(first, (tuple, Row)):
+        raise TypeError(
+            f"bulkcopy data rows must be tuples or Row objects, got {type(first).__name__}"
+        )
+
+    if not column_mappings:
+        # Ordinal mapping over every target column except the load ID column
+        mappings: List[Any] = [c.name for c in target_columns if c.name.casefold() != column_key]
+        mappings.append(load_id_column)
+    elif isinstance(column_mappings[0], (tuple, list)):
+        width = len(first) if first is not None else 0
+        mappings = [tuple(m) for m in column_mappings] + [(width, load_id_column)]
+    else:
+        mappings = list(column_mappings) + [load_id_column]
+
+    delete_sql = (
+        f"DELETE FROM {quote_multipart_name(table_name)} "
+        f"WHERE {quote_identifier(load_id_column)} = ?"
+    )
+    cursor.execute(delete_sql, load_id)
+    rows_removed = max(cursor.rowcount, 0)
+    if not connection.autocommit:
+        # Only the DELETE is pending: no transaction was open before it
+        connection.commit()
+    if rows_removed:
+        logger.info(
+            "prepare_idempotent_load: Removed %d rows from an earlier attempt of load %s",
+            rows_removed,
+            load_id,
+        )
+
+    def _stamped_rows():
+        if first is None:
+            return
+        for row in chain((first,), iterator):
+            values = tuple(row._values) if isinstance(row, Row) else tuple(row)
+            yield values + (load_id,)
+
+    return _stamped_rows(), mappings, rows_removed
@@ -0,0 +1,222 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module enumerates the databases of a server and the metadata of objects in
+many databases at once, for inventory and cataloging tools: tables and views,
+their columns, and routines, read with three-part names so that one batch covers
+several databases without sp_MSforeachdb or a USE per database.
+"""
+
+from typing import TYPE_CHECKING, Any, Dict, Iterable, List, Optional, Sequence
+
+from mssql_python.exceptions import ProgrammingError
+from mssql_python.helpers import quote_identifier
+from mssql_python.logging import logger
+
+if TYPE_CHECKING:
+    from mssql_python.connection import Connection
+
+# Databases read per round trip by catalog()
+CATALOG_BATCH_SIZE = 20
+
+_DATABASES_QUERY = (
+    "SELECT d.name, d.database_id, d.state_desc, HAS_DBACCESS(d.name), d.is_read_only, "
+    "d.compatibility_level, d.collation_name, d.recovery_model_desc, "
+    "DB_NAME(d.source_database_id), d.create_date "
+    "FROM sys.databases AS d ORDER BY d.name"
+)
+_DATABASE_COLUMNS = (
+    "name",
+    "database_id",
+    "state",
+    "accessible",
+    "is_read_only",
+    "compatibility_level",
+    "collation",
+    "recovery_model",
+    "snapshot_of",
+    "created",
+)
+# master, tempdb, model and msdb
+_SYSTEM_DATABASE_IDS = range(1, 5)
+# Azure SQL Database, where three-part names only reach the current database
+_AZURE_SQL_DATABASE = 5
+_ENGINE_EDITION_QUERY = "SELECT CAST(SERVERPROPERTY('EngineEdition') AS int), DB_NAME()"
+
+# Per kind: the SELECT for one database ({db} the quoted name, {name} its literal)
+# and the keys of its rows after "database". Names are collated to the current
+# database's collation so that the UNION ALL over databases does not conflict.
+_KIND_QUERIES = {
+    "tables": (
+        "SELECT {name}, s.name COLLATE DATABASE_DEFAULT, o.name COLLATE DATABASE_DEFAULT, "
+        "o.type_desc, (SELECT SUM(p.rows) FROM {db}.sys.partitions AS p "
+        "WHERE p.object_id = o.object_id AND p.index_id IN (0, 1)), "
+        "o.create_date, o.modify_date "
+        "FROM {db}.sys.objects AS o JOIN {db}.sys.schemas AS s ON s.schema_id = o.schema_id "
+        "WHERE o.type IN ('U', 'V') AND o.is_ms_shipped = 0",
+        ("schema", "name", "type", "row_count", "created", "modified"),
+    ),
+    "columns": (
+        "SELECT {name}, s.name COLLATE DATABASE_DEFAULT, o.name COLLATE DATABASE_DEFAULT, "
+        "c.name COLLATE DATABASE_DEFAULT, c.column_id, t.name COLLATE DATABASE_DEFAULT, "
+        "c.max_length, c.precision, c.scale, c.is_nullable, c.is_identity, c.is_computed "
+        "FROM {db}.sys.columns AS c "
+        "JOIN {db}.sys.objects AS o ON o.object_id = c.object_id "
+        "JOIN {db}.sys.schemas AS s ON s.schema_id = o.schema_id "
+        "JOIN {db}.sys.types AS t ON t.user_type_id = c.user_type_id "
+        "WHERE o.type IN ('U', 'V') AND o.is_ms_shipped = 0",
+        (
+            "schema",
+            "table",
+            "name",
+            "ordinal",
+            "type",
+            "max_length",
+            "precision",
+            "scale",
+            "nullable",
+            "is_identity",
+            "is_computed",
+        ),
+    ),
+    "routines": (
+        "SELECT {name}, s.name COLLATE DATABASE_DEFAULT, o.name COLLATE DATABASE_DEFAULT, "
+        "o.type_desc, o.create_date, o.modify_date "
+        "FROM {db}.sys.objects AS o JOIN {db}.sys.schemas AS s ON s.schema_id = o.schema_id "
+        "WHERE o.type IN ('P', 'PC', 'FN', 'IF', 'TF', 'FS', 'FT') AND o.is_ms_shipped = 0",
+        ("schema", "name", "type", "created", "modified"),
+    ),
+}
+# Sort keys of each kind's rows
+_KIND_ORDER = {
+    "tables": (1, 2),
+    "columns": (1, 2, 4),
+    "routines": (1, 2),
+}
+
+
+def _literal(text: str) -> str:
+    return "N'" + text.replace("'", "''") + "'"
+
+
+def _fetchall(connection: "Connection", sql: str) -> List[tuple]:
+    cursor = connection.cursor()
+    try:
+        return [tuple(row) for row in cursor.execute(sql).fetchall()]
+    finally:
+        cursor.close()
+
+
+def list_databases(
+    connection: "Connection", include_system: bool = False
+) -> List[Dict[str, Any]]:
+    """List the databases of the server; see Connection.databases."""
+    databases = []
+    for row in _fetchall(connection, _DATABASES_QUERY):
+        entry = dict(zip(_DATABASE_COLUMNS, row))
+        if not include_system and entry["database_id"] in _SYSTEM_DATABASE_IDS:
+            continue
+        entry["accessible"] = bool(entry["accessible"]) and entry["state"] == "ONLINE"
+        entry["is_read_only"] = bool(entry["is_read_only"])
+        databases.append(entry)
+    return databases
+
+
+def catalog_sql(databases: Sequence[str], kinds: Sequence[str]) -> str:
+    """Return the batch reading kinds from databases, one result set per kind."""
+    statements = []
+    for kind in kinds:
+        template = _KIND_QUERIES[kind][0]
+        statements.append(
+            "\nUNION ALL\n".join(
+                template.format(db=quote_identifier(database), name=_literal(database))
+                for database in databases
+            )
+        )
+    return ";\n".join(statements) + ";"
+
+
+def _validate_kinds(kinds: Iterable[str]) -> List[str]:
+    kinds = list(kinds)
+    unknown = [kind for kind in kinds if kind not in _KIND_QUERIES]
+    if unknown or not kinds:
+        raise ProgrammingError(
+            driver_error=f"Unknown catalog kinds: {', '.join(unknown) or '(none given)'}",
+            ddbc_error=f"Catalog kinds are {', '.join(_KIND_QUERIES)}",
+        )
+    return kinds
+
+
+def _catalogued_databases(
+    connection: "Connection", databases: Optional[Iterable[str]]
+) -> Dict[str, List[str]]:
+    """Split the databases to catalog into readable ones and skipped ones."""
+    edition, current = _fetchall(connection, _ENGINE_EDITION_QUERY)[0]
+    if edition == _AZURE_SQL_DATABASE:
+        names = [current]
+        readable = set(names)
+        if databases is not None and list(databases) != [current]:
+            raise ProgrammingError(
+                driver_error="Azure SQL Database can only catalog the current database",
+                ddbc_error="Three-part names cannot refer to other databases",
+            )
+    else:
+        # System databases are only catalogued when asked for by name
+        entries = {
+            entry["name"]: entry
+            for entry in list_databases(connection, include_system=databases is not None)
+        }
+        if databases is None:
+            names = sorted(entries)
+        else:
+            names = list(dict.fromkeys(databases))
+            missing = [name for name in names if name not in entries]
+            if missing:
+                raise ProgrammingError(
+                    driver_error=f"Databases do not exist: {', '.join(missing)}",
+                    ddbc_error="No databases with these names in sys.databases",
+                )
+        readable = {name for name in names if entries[name]["accessible"]}
+    return {
+        "readable": [name for name in names if name in readable],
+        "skipped": [name for name in names if name not in readable],
+    }
+
+
+def catalog(
+    connection: "Connection",
+    databases: Optional[Iterable[str]] = None,
+    kinds: Iterable[str] = ("tables", "columns", "routines"),
+    batch_size: int = CATALOG_BATCH_SIZE,
+) -> Dict[str, List[Any]]:
+    """Read object metadata of many databases; see Connection.catalog."""
+    kinds = _validate_kinds(kinds)
+    if batch_size < 1:
+        raise ValueError("batch_size must be at least 1")
+    split = _catalogued_databases(connection, databases)
+    result: Dict[str, List[Any]] = {kind: [] for kind in kinds}
+    rows: Dict[str, List[tuple]] = {kind: [] for kind in kinds}
+    readable = split["readable"]
+    for start in range(0, len(readable), batch_size):
+        batch = readable[start : start + batch_size]
+        logger.info("catalog: reading %s from %d databases", ", ".join(kinds), len(batch))
+        cursor = connection.cursor()
+        try:
+            cursor.execute(catalog_sql(batch, kinds), use_prepare=False)
+            for position, kind in enumerate(kinds):
+                if position and not cursor.nextset():
+                    break
+                rows[kind].extend(tuple(row) for row in cursor.fetchall())
+        finally:
+            cursor.close()
+    for kind in kinds:
+        order = _KIND_ORDER[kind]
+        keys = ("database",) + _KIND_QUERIES[kind][1]
+        for row in sorted(rows[kind], key=lambda row: (row[0],) + tuple(row[i] for i in order)):
+            entry = dict(zip(keys, row))
+            for flag in ("nullable", "is_identity", "is_computed"):
+                if flag in entry:
+                    entry[flag] = bool(entry[flag])
+            result[kind].append(entry)
+    result["skipped"] = split["skipped"]
+    return result
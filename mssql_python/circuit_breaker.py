@@ -0,0 +1,243 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module provides per-server circuit breakers (enable_circuit_breaker()): after
+a number of consecutive connection failures to a server, connects and statements
+to it fail at once instead of each waiting for a login or network timeout, until
+a probe finds the server reachable again.
+"""
+
+import threading
+import time
+from typing import Any, Dict, List, Optional
+
+from mssql_python.exceptions import OperationalError
+from mssql_python.logging import logger
+
+CIRCUIT_STATES = ("closed", "open", "half_open")
+
+# Connect failures meaning the server could not be reached: connection exceptions
+# (class 08), login and connection timeouts
+_CONNECT_FAILURE_SQLSTATES = ("HYT00", "HYT01")
+# Statement failures meaning the session to the server was lost; query timeouts
+# (HYT00) are left out, a slow or blocked query does not mean the server is down
+_STATEMENT_FAILURE_SQLSTATES = ("HYT01",)
+
+
+def is_connection_failure(sqlstate: Optional[str], connecting: bool = False) -> bool:
+    """Return True if a failure with sqlstate counts against the server's circuit."""
+    if not sqlstate:
+        return False
+    if sqlstate.startswith("08"):
+        return True
+    return sqlstate in (_CONNECT_FAILURE_SQLSTATES if connecting else _STATEMENT_FAILURE_SQLSTATES)
+
+
+class CircuitBreaker:
+    """
+    The circuit of one server.
+
+    Closed, every request goes through. After failure_threshold consecutive
+    connection failures the circuit opens: requests raise OperationalError
+    without reaching the server. reset_timeout seconds later it is half-open:
+    up to half_open_probes requests go through as probes, the others still fail
+    at once. A probe that succeeds closes the circuit, one that fails opens it
+    again. Any answer of the server, errors included, is a success.
+    """
+
+    def __init__(
+        self,
+        endpoint: str,
+        failure_threshold: int = 5,
+        reset_timeout: float = 30.0,
+        half_open_probes: int = 1,
+    ) -> None:
+        if failure_threshold < 1:
+            raise ValueError(f"failure_threshold must be positive, got {failure_threshold}")
+        if reset_timeout < 0:
+            raise ValueError(f"reset_timeout must be non-negative, got {reset_timeout}")
+        if half_open_probes < 1:
+            raise ValueError(f"half_open_probes must be positive, got {half_open_probes}")
+        self.endpoint = endpoint
+        self.failure_threshold = failure_threshold
+        self.reset_timeout = reset_timeout
+        self.half_open_probes = half_open_probes
+        self._lock = threading.Lock()
+        self._state = "closed"
+        self._consecutive_failures = 0
+        self._opened_at = 0.0
+        self._probes_in_flight = 0
+        self._times_opened = 0
+        self._rejected = 0
+        self._probes = 0
+
+    @property
+    def state(self) -> str:
+        """"closed", "open" or "half_open" (once reset_timeout has passed)."""
+        with self._lock:
+            return self._current_state()
+
+    def _current_state(self) -> str:
+        if self._state == "open" and time.monotonic() - self._opened_at >= self.reset_timeout:
+            self._state = "half_open"
+            self._probes_in_flight = 0
+        return self._state
+
+    def allow(self) -> None:
+        """
+        Let one request through, as a probe when the circuit is half-open.
+
+        Raises:
+            OperationalError: If the circuit is open, or half-open with all
+                probes in flight.
+        """
+        with self._lock:
+            state = self._current_state()
+            if state == "closed":
+                return
+            if state == "half_open" and self._probes_in_flight < self.half_open_probes:
+                self._probes_in_flight += 1
+                self._probes += 1
+                return
+            self._rejected += 1
+            retry_in = max(self.reset_timeout - (time.monotonic() - self._opened_at), 0.0)
+            failures = self._consecutive_failures
+        raise OperationalError(
+            driver_error=f"Circuit breaker open for server '{self.endpoint}'",
+            ddbc_error=(
+                f"{failures} consecutive connection failures; "
+                f"the server is probed again in {retry_in:.1f} seconds"
+            ),
+        )
+
+    def record_success(self) -> None:
+        """Record that a request reached the server, closing a half-open circuit."""
+        with self._lock:
+            self._consecutive_failures = 0
+            if self._state != "closed":
+                logger.info("Circuit breaker for %s closed", self.endpoint)
+            self._state = "closed"
+            self._probes_in_flight = 0
+
+    def record_failure(self) -> None:
+        """Record a connection failure, opening the circuit at the threshold or on a probe."""
+        with self._lock:
+            self._consecutive_failures += 1
+            probing = self._state == "half_open"
+            if probing or (
+                self._state == "closed" and self._consecutive_failures >= self.failure_threshold
+            ):
+                self._state = "open"
+                self._opened_at = time.monotonic()
+                self._probes_in_flight = 0
+                self._times_opened += 1
+                logger.warning(
+                    "Circuit breaker for %s opened after %d consecutive connection failures",
+                    self.endpoint,
+                    self._consecutive_failures,
+                )
+
+    def abandon(self) -> None:
+        """Give back the probe of a request let through by allow() that never ran."""
+        with self._lock:
+            if self._probes_in_flight > 0:
+                self._probes_in_flight -= 1
+
+    def stats(self) -> Dict[str, Any]:
+        """
+        Return the state of the circuit with its counters: consecutive connection
+        failures, times opened, requests rejected without reaching the server and
+        probes let through, and the seconds until an open circuit is probed again.
+        """
+        with self._lock:
+            state = self._current_state()
+            retry_in = 0.0
+            if state == "open":
+                retry_in = max(self.reset_timeout - (time.monotonic() - self._opened_at), 0.0)
+            return {
+                "endpoint": self.endpoint,
+                "state": state,
+                "consecutive_failures": self._consecutive_failures,
+                "failure_threshold": self.failure_threshold,
+                "times_opened": self._times_opened,
+                "requests_rejected": self._rejected,
+                "probes": self._probes,
+                "seconds_until_probe": retry_in,
+            }
+
+
+_settings: Optional[Dict[str, Any]] = None
+_breakers: Dict[str, CircuitBreaker] = {}
+_breakers_lock = threading.Lock()
+
+
+def _endpoint_key(server: str) -> str:
+    return server.strip().lower()
+
+
+def enable_circuit_breaker(
+    failure_threshold: int = 5, reset_timeout: float = 30.0, half_open_probes: int = 1
+) -> None:
+    """
+    Give every server a circuit breaker (see CircuitBreaker), so a server that is
+    down fails connects and statements fast instead of stacking up timeouts.
+
+    Servers are told apart by the Server value connected to; each server of a
+    Server=a|b failover list has its own circuit, and one whose circuit is open
+    is skipped. Replaces the settings and circuits of an earlier call.
+
+    Args:
+        failure_threshold: Consecutive connection failures that open a circuit.
+        reset_timeout: Seconds an open circuit rejects requests before probing.
+        half_open_probes: Requests let through at once to probe a server.
+
+    Examples:
+        import mssql_python
+
+        mssql_python.enable_circuit_breaker(failure_threshold=3, reset_timeout=10)
+        ...
+        mssql_python.circuit_breaker_stats()
+    """
+    global _settings  # pylint: disable=global-statement
+    # Validates the settings before replacing the current ones
+    CircuitBreaker("", failure_threshold, reset_timeout, half_open_probes)
+    with _breakers_lock:
+        _settings = {
+            "failure_threshold": failure_threshold,
+            "reset_timeout": reset_timeout,
+            "half_open_probes": half_open_probes,
+        }
+        _breakers.clear()
+
+
+def disable_circuit_breaker() -> None:
+    """Stop using circuit breakers, discarding their state."""
+    global _settings  # pylint: disable=global-statement
+    with _breakers_lock:
+        _settings = None
+        _breakers.clear()
+
+
+def endpoint_breaker(server: Optional[str]) -> Optional[CircuitBreaker]:
+    """Return the circuit breaker of server, or None when circuit breakers are disabled."""
+    if _settings is None or server is None:
+        return None
+    key = _endpoint_key(server)
+    with _breakers_lock:
+        if _settings is None:
+            return None
+        breaker = _breakers.get(key)
+        if breaker is None:
+            breaker = CircuitBreaker(server.strip(), **_settings)
+            _breakers[key] = breaker
+        return breaker
+
+
+def circuit_breaker_stats() -> List[Dict[str, Any]]:
+    """
+    Return the state and counters of the circuit of every server connected to
+    (see CircuitBreaker.stats); empty when circuit breakers are disabled.
+    """
+    with _breakers_lock:
+        breakers = list(_breakers.values())
+    return [breaker.stats() for breaker in breakers]
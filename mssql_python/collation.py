@@ -0,0 +1,122 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module maps SQL Server collation names to the code page of their non-Unicode
+(CHAR, VARCHAR, TEXT) data and the Python codec that decodes it, so VARCHAR bytes
+can be decoded per column, e.g. cp1252 for Latin1_General_CI_AS and cp1251 for
+Cyrillic_General_CI_AS.
+"""
+
+import codecs
+import re
+from functools import lru_cache
+from typing import Any, Callable, Optional
+
+# Code pages of the Windows collation designators (the part before _CI_AS, _BIN etc.)
+_DESIGNATOR_CODE_PAGES = {
+    874: ("Thai",),
+    932: ("Japanese",),
+    936: ("Chinese_PRC", "Chinese_Simplified"),
+    949: ("Korean",),
+    950: ("Chinese_Taiwan", "Chinese_Traditional", "Chinese_Hong_Kong"),
+    1250: (
+        "Albanian",
+        "Bosnian_Latin",
+        "Croatian",
+        "Czech",
+        "Hungarian",
+        "Polish",
+        "Romanian",
+        "Serbian_Latin",
+        "Slovak",
+        "Slovenian",
+        "Turkmen",
+        "Upper_Sorbian",
+    ),
+    1251: (
+        "Bashkir",
+        "Bosnian_Cyrillic",
+        "Cyrillic_General",
+        "Kazakh",
+        "Macedonian_FYROM",
+        "Serbian_Cyrillic",
+        "Tatar",
+        "Ukrainian",
+        "Yakut",
+    ),
+    1253: ("Greek",),
+    1254: ("Azeri_Latin", "Turkish", "Uzbek_Latin"),
+    1255: ("Hebrew",),
+    1256: ("Arabic", "Dari", "Pashto", "Persian", "Urdu", "Uighur"),
+    1257: ("Estonian", "Latvian", "Lithuanian"),
+    1258: ("Vietnamese",),
+}
+_CODE_PAGES = {
+    designator.lower(): code_page
+    for code_page, designators in _DESIGNATOR_CODE_PAGES.items()
+    for designator in designators
+}
+# SQL collations name their code page, e.g. SQL_Latin1_General_CP1_CI_AS (1252)
+# or SQL_Latin1_General_CP850_BIN
+_SQL_COLLATION_RE = re.compile(r"^SQL_.*?_(?:Pref_)?CP(\d+)(?:_|$)", re.I)
+_SQL_CODE_PAGE_ALIASES = {1: 1252}
+_UTF8_RE = re.compile(r"_UTF8(?:_|$)", re.I)
+
+
+@lru_cache(maxsize=256)
+def collation_code_page(collation: Optional[str]) -> Optional[int]:
+    """
+    Return the code page of non-Unicode data in a collation, or None if unknown.
+
+    UTF-8 collations (SQL Server 2019+) give 65001; Windows collations not listed
+    here, such as Latin1_General or French, use 1252.
+    """
+    if not collation:
+        return None
+    if _UTF8_RE.search(collation):
+        return 65001
+    match = _SQL_COLLATION_RE.match(collation)
+    if match:
+        code_page = int(match.group(1))
+        return _SQL_CODE_PAGE_ALIASES.get(code_page, code_page)
+    name = collation.lower()
+    for designator, code_page in _CODE_PAGES.items():
+        if name == designator or name.startswith(designator + "_"):
+            return code_page
+    return 1252
+
+
+def collation_codec(collation: Optional[str]) -> Optional[str]:
+    """Return the Python codec for non-Unicode data in a collation, or None if unknown."""
+    code_page = collation_code_page(collation)
+    if code_page is None:
+        return None
+    codec = "utf-8" if code_page == 65001 else f"cp{code_page}"
+    try:
+        return codecs.lookup(codec).name
+    except LookupError:
+        return None
+
+
+def narrow_char_decoder(collation: Optional[str], fallback: Optional[str]) -> Callable[[Any], Any]:
+    """
+    Return a column transform that decodes VARCHAR values fetched as bytes, which
+    the SQL_CHAR encoding could not decode: with the code page of the column's
+    collation, then with fallback (replacing undecodable bytes). Without either,
+    the bytes are kept.
+    """
+    codec = collation_codec(collation)
+
+    def decode(value: Any) -> Any:
+        if not isinstance(value, bytes):
+            return value
+        if codec:
+            try:
+                return value.decode(codec)
+            except UnicodeDecodeError:
+                pass
+        if fallback:
+            return value.decode(fallback, "replace")
+        return value
+
+    return decode
@@ -0,0 +1,111 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module bounds and cancels connection opens. With a login timeout or a
+ConnectAttempt, the driver connect runs on a helper thread while the caller waits
+for it, the deadline or cancel_connect() from another thread, whichever comes
+first; a connect given up on is closed by the helper thread once the driver
+returns, so a hung handshake no longer blocks the caller indefinitely.
+"""
+
+import threading
+import time
+from typing import Any, Callable, Optional
+
+from mssql_python.exceptions import OperationalError
+from mssql_python.logging import logger
+
+# Seconds past the login timeout before a connect the driver has not given up on
+# is abandoned (the driver's own login timeout normally fires first)
+LOGIN_TIMEOUT_GRACE = 5.0
+
+
+class ConnectAttempt:
+    """
+    A handle to cancel a connect() in progress from another thread.
+
+    Pass it as connect(..., attempt=...) and call cancel_connect() to make that
+    connect() raise OperationalError (SQLSTATE HY008). Cancelling before the
+    connect starts makes it fail right away; an attempt cannot be reused once
+    cancelled.
+
+    Examples:
+        attempt = mssql_python.ConnectAttempt()
+        threading.Timer(10, attempt.cancel_connect).start()
+        conn = mssql_python.connect(conn_str, attempt=attempt)
+    """
+
+    def __init__(self) -> None:
+        self._condition = threading.Condition()
+        self._cancelled = False
+
+    @property
+    def cancelled(self) -> bool:
+        """Whether cancel_connect() was called."""
+        return self._cancelled
+
+    def cancel_connect(self) -> None:
+        """Abort the connect waiting on this attempt (or the next one to start)."""
+        with self._condition:
+            self._cancelled = True
+            self._condition.notify_all()
+        logger.info("cancel_connect: Connect cancelled")
+
+    def check(self) -> None:
+        """Raise OperationalError (HY008) if the attempt was cancelled."""
+        if self._cancelled:
+            error = OperationalError(
+                driver_error="Operation canceled",
+                ddbc_error="The connection attempt was cancelled with cancel_connect()",
+            )
+            error.sqlstate = "HY008"
+            raise error
+
+    def run(self, connect: Callable[[], Any], timeout: Optional[float] = None) -> Any:
+        """
+        Return connect() run on a helper thread, giving up on cancel or after timeout.
+
+        Raises:
+            OperationalError: If cancel_connect() was called first (HY008).
+            RuntimeError: "SQLSTATE:HYT00:..." if timeout seconds passed first, like
+                a login timeout reported by the driver.
+        """
+        self.check()
+        outcome: dict = {}
+
+        def target() -> None:
+            try:
+                result = connect()
+            except BaseException as e:  # pylint: disable=broad-exception-caught
+                result, outcome["error"] = None, e
+            with self._condition:
+                abandoned = outcome.get("abandoned", False)
+                outcome["result"] = result
+                outcome["done"] = True
+                self._condition.notify_all()
+            if abandoned and result is not None:
+                logger.debug("connect: Closing a connection opened after it was given up")
+                try:
+                    result.close()
+                except Exception:  # pylint: disable=broad-exception-caught
+                    logger.debug("connect: Could not close abandoned connection", exc_info=True)
+
+        deadline = None if timeout is None else time.monotonic() + timeout
+        threading.Thread(target=target, name="mssql-python-connect", daemon=True).start()
+        with self._condition:
+            while not outcome.get("done") and not self._cancelled:
+                remaining = None if deadline is None else deadline - time.monotonic()
+                if remaining is not None and remaining <= 0:
+                    break
+                self._condition.wait(remaining)
+            if not outcome.get("done"):
+                outcome["abandoned"] = True
+        if not outcome.get("done"):
+            self.check()
+            raise RuntimeError(
+                "SQLSTATE:HYT00:[mssql-python] Login timeout expired: the driver did not "
+                f"return within {timeout:.0f} seconds"
+            )
+        if "error" in outcome:
+            raise outcome["error"]
+        return outcome["result"]
@@ -0,0 +1,94 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module breaks the time a connection takes to open into phases.
+
+The ODBC driver opens a session in one call -- name resolution, TCP, the TDS
+pre-login, TLS and the login -- so the connection measures what it can around
+that call (token acquisition, the driver's connect, the session settings applied
+after it), and probe_network_phases() measures the network phases separately by
+repeating them against the server. What remains of the driver's connect is the
+login: authentication and session setup on the server.
+"""
+
+import socket
+import ssl
+import time
+from typing import Dict, Optional
+
+from mssql_python.logging import logger
+
+# Phases of Connection.connect_timings, in the order they run
+CONNECT_PHASES = ("token", "connect", "session", "total")
+# Phases of probe_network_phases(), in the order the driver runs them (TDS 8 runs
+# TLS before the pre-login)
+NETWORK_PHASES = ("dns", "tcp", "prelogin", "tls")
+# Seconds each probed phase may take
+PROBE_TIMEOUT = 5.0
+
+
+def total_seconds(timings: Dict[str, Optional[float]]) -> float:
+    """Return the sum of the phases of timings that were measured."""
+    return sum(seconds for phase, seconds in timings.items() if phase != "total" and seconds)
+
+
+def probe_network_phases(
+    server: str, strict: bool = False, timeout: float = PROBE_TIMEOUT
+) -> Dict[str, Optional[float]]:
+    """
+    Time name resolution, the TCP handshake, the TDS pre-login and the TLS handshake
+    to server, without logging in.
+
+    With strict (Encrypt=strict, TDS 8) TLS comes first and the pre-login runs
+    inside it. Phases that were not reached are None: all of them for a server
+    that is not a TCP address with a port (named pipes, LocalDB, named instances
+    resolved by SQL Browser), the ones after a failure, and tls for a server that
+    does not support encryption.
+    """
+    # pylint: disable=import-outside-toplevel
+    # readiness imports connection, which imports this module
+    from mssql_python.readiness import server_endpoint
+    from mssql_python.tls import _ENCRYPT_NOT_SUP, _prelogin_encryption, _tls_context, _tls_in_tds
+
+    phases: Dict[str, Optional[float]] = dict.fromkeys(NETWORK_PHASES)
+    endpoint = server_endpoint(server)
+    if endpoint is None:
+        return phases
+    host, port = endpoint
+    sock = None
+    try:
+        started = time.perf_counter()
+        family, socktype, proto, _, address = socket.getaddrinfo(
+            host, port, type=socket.SOCK_STREAM
+        )[0]
+        phases["dns"] = time.perf_counter() - started
+
+        started = time.perf_counter()
+        sock = socket.socket(family, socktype, proto)
+        sock.settimeout(timeout)
+        sock.connect(address)
+        phases["tcp"] = time.perf_counter() - started
+
+        if strict:
+            context = _tls_context()
+            context.set_alpn_protocols(["tds/8.0"])
+            started = time.perf_counter()
+            sock = context.wrap_socket(sock, server_hostname=host)
+            phases["tls"] = time.perf_counter() - started
+            started = time.perf_counter()
+            _prelogin_encryption(sock)
+            phases["prelogin"] = time.perf_counter() - started
+        else:
+            started = time.perf_counter()
+            encryption = _prelogin_encryption(sock)
+            phases["prelogin"] = time.perf_counter() - started
+            if encryption != _ENCRYPT_NOT_SUP:
+                started = time.perf_counter()
+                _tls_in_tds(sock, host)
+                phases["tls"] = time.perf_counter() - started
+    except (OSError, ssl.SSLError, ValueError) as e:
+        logger.debug("probe_network_phases: Probing %s stopped: %s", server, e)
+    finally:
+        if sock is not None:
+            sock.close()
+    return phases
@@ -11,15 +11,31 @@ Resource Management:
 - Cursors are also cleaned up automatically when no longer referenced, to prevent memory leaks.
 """
 
+import math
+import random
+import time
 import weakref
 import re
+from collections import OrderedDict
 import codecs
-from typing import Any, Dict, Optional, Union, List, Tuple, Callable, TYPE_CHECKING
+from typing import (
+    Any,
+    Dict,
+    Iterable,
+    Optional,
+    Union,
+    List,
+    Sequence,
+    Tuple,
+    Callable,
+    TYPE_CHECKING,
+)
 import threading
 
 import mssql_python
 from mssql_python.cursor import Cursor
 from mssql_python.helpers import (
+    quote_identifier,
     sanitize_user_input,
     validate_attribute_value,
 )
@@ -27,6 +43,23 @@ from mssql_python.connection_string_parser import sanitize_connection_string
 from mssql_python.logging import logger
 from mssql_python import ddbc_bindings
 from mssql_python.pooling import PoolingManager
+from mssql_python.statement_limit import StatementLimiter, check_priority
+from mssql_python.retry import DEADLOCK_VICTIM
+from mssql_python.circuit_breaker import endpoint_breaker, is_connection_failure
+from mssql_python.fips import require_fips_connection
+from mssql_python.connect_attempt import LOGIN_TIMEOUT_GRACE, ConnectAttempt
+from mssql_python.discovery import forget_localdb_pipe, localdb_instance, resolve_localdb
+from mssql_python.sql_script import configured_packet_size
+from mssql_python.environment import environment_defaults
+from mssql_python.secret_file import SecretFile, SecretSource
+from mssql_python.scalar import NO_DEFAULT, typed_scalar
+from mssql_python.connect_timing import (
+    CONNECT_PHASES,
+    NETWORK_PHASES,
+    PROBE_TIMEOUT,
+    probe_network_phases,
+    total_seconds,
+)
 from mssql_python.exceptions import (
     Warning,  # pylint: disable=redefined-builtin
     Error,
@@ -38,9 +71,13 @@ from mssql_python.exceptions import (
     InternalError,
     ProgrammingError,
     NotSupportedError,
+    PingError,
+    CertificateTrustError,
+    login_error,
     sqlstate_to_exception,
 )
 from mssql_python.auth import (
+    AADAuth,
     extract_auth_type,
     process_auth_parameters,
     remove_sensitive_params,
@@ -52,12 +89,21 @@ from mssql_python.connection_string_builder import _ConnectionStringBuilder
 from mssql_python.constants import (
     _RESERVED_PARAMETERS,
     _KEY_AUTHENTICATION,
+    _KEY_PWD,
+    _KEY_SERVER_CERTIFICATE_HASH,
+    _KEY_SERVER_ORDER,
+    _KEY_TRUSTED_CONNECTION,
     _KEY_UID,
     _AuthInternal,
 )
 
 if TYPE_CHECKING:
     from mssql_python.row import Row
+    from mssql_python.bulk_load import TableLoad
+    from mssql_python.progress import ProgressEvent
+    from mssql_python.savepoint import NestedTransaction
+    from mssql_python.xevents import XEventSession
+    from mssql_python.maintenance import IndexMaintenance
 
 # Add SQL_WMETADATA constant for metadata decoding configuration
 SQL_WMETADATA: int = -99  # Special flag for column name decoding
@@ -70,6 +116,21 @@ UTF16_ENCODINGS: frozenset[str] = frozenset(["utf-16le", "utf-16be"])
 
 _SQLSTATE_RE = re.compile(r"^SQLSTATE:([A-Z0-9]{0,5}):(.*)", re.DOTALL)
 
+# Seconds close() waits for the server to acknowledge cancelled statements
+_CANCEL_ACK_TIMEOUT = 5.0
+
+# Query timeout and cancellation, after which diagnose_blocking captures the blocking chain
+_BLOCKING_DIAGNOSTIC_SQLSTATES = ("HYT00", "HY008")
+
+# Counters returned by Connection.usage()
+_USAGE_COUNTERS = (
+    "statements_executed",
+    "rows_fetched",
+    "rows_affected",
+    "bytes_sent",
+    "bytes_received",
+)
+
 
 def _raise_connection_error(e: RuntimeError) -> None:
     """Map a RuntimeError from the C++ pybind layer to the correct DB-API 2.0 exception.
@@ -105,6 +166,22 @@ def _raise_connection_error(e: RuntimeError) -> None:
     ) from None
 
 
+def _raise_login_error(e: RuntimeError) -> None:
+    """
+    Raise the error of a failed connect: a LoginError subclass with a remediation
+    hint when the cause is recognized (see exceptions.login_error), otherwise the
+    exception _raise_connection_error() maps it to.
+    """
+    error_msg = str(e)
+    match = _SQLSTATE_RE.match(error_msg)
+    sqlstate, ddbc_error = (match.group(1), match.group(2)) if match else (None, error_msg)
+    exc = login_error(sqlstate, ddbc_error)
+    if exc is not None:
+        logger.error("Login failed (%s): %s", type(exc).__name__, ddbc_error)
+        raise exc from None
+    _raise_connection_error(e)
+
+
 def _validate_utf16_wchar_compatibility(
     encoding: str, wchar_type: int, context: str = "SQL_WCHAR"
 ) -> None:
@@ -189,6 +266,167 @@ def _validate_encoding(encoding: str) -> bool:
         return False
 
 
+_DRIVER_APP_NAME = "MSSQL-Python"
+# APP values are limited to 128 characters by SQL Server
+_MAX_WORKLOAD_LENGTH = 128 - len(_DRIVER_APP_NAME) - 3
+_WORKLOAD_RE = re.compile(r"^[A-Za-z0-9_.\- ]+$")
+
+
+def _validate_workload(workload: Optional[str]) -> Optional[str]:
+    """Check a workload tag is safe to embed in the APP connection keyword."""
+    if workload is None:
+        return None
+    if (
+        not isinstance(workload, str)
+        or not workload.strip()
+        or len(workload) > _MAX_WORKLOAD_LENGTH
+        or not _WORKLOAD_RE.match(workload)
+    ):
+        raise ValueError(
+            "workload must be a non-empty string of at most "
+            f"{_MAX_WORKLOAD_LENGTH} letters, digits, spaces, '.', '_' or '-'"
+        )
+    return workload.strip()
+
+
+# require_row_versioning values and the database options each one needs
+_ROW_VERSIONING_REQUIREMENTS = {
+    "read_committed_snapshot": ("read_committed_snapshot",),
+    "snapshot": ("snapshot_isolation",),
+    "both": ("read_committed_snapshot", "snapshot_isolation"),
+}
+
+_ROW_VERSIONING_OPTIONS = {
+    "read_committed_snapshot": "READ_COMMITTED_SNAPSHOT",
+    "snapshot_isolation": "ALLOW_SNAPSHOT_ISOLATION",
+}
+
+# set_isolation_level() levels: the connection attribute and value setting each one
+_ISOLATION_LEVELS = {
+    "READ UNCOMMITTED": (
+        ConstantsDDBC.SQL_ATTR_TXN_ISOLATION.value,
+        ConstantsDDBC.SQL_TXN_READ_UNCOMMITTED.value,
+    ),
+    "READ COMMITTED": (
+        ConstantsDDBC.SQL_ATTR_TXN_ISOLATION.value,
+        ConstantsDDBC.SQL_TXN_READ_COMMITTED.value,
+    ),
+    "REPEATABLE READ": (
+        ConstantsDDBC.SQL_ATTR_TXN_ISOLATION.value,
+        ConstantsDDBC.SQL_TXN_REPEATABLE_READ.value,
+    ),
+    "SERIALIZABLE": (
+        ConstantsDDBC.SQL_ATTR_TXN_ISOLATION.value,
+        ConstantsDDBC.SQL_TXN_SERIALIZABLE.value,
+    ),
+    "SNAPSHOT": (
+        ConstantsDDBC.SQL_COPT_SS_TXN_ISOLATION.value,
+        ConstantsDDBC.SQL_TXN_SS_SNAPSHOT.value,
+    ),
+}
+
+
+def _isolation_level_name(level: Union[str, int]) -> str:
+    """Return the set_isolation_level() name of level, a name or a SQL_TXN_* value."""
+    if isinstance(level, str):
+        name = " ".join(level.replace("_", " ").upper().split())
+        if name in _ISOLATION_LEVELS:
+            return name
+    elif isinstance(level, int) and not isinstance(level, bool):
+        for name, (_, value) in _ISOLATION_LEVELS.items():
+            if value == level:
+                return name
+    raise ValueError(
+        f"Unknown isolation level {level!r}; use one of {', '.join(_ISOLATION_LEVELS)} "
+        "or a SQL_TXN_* constant"
+    )
+
+
+def _validate_row_versioning(requirement: Optional[str]) -> Optional[str]:
+    """Check a require_row_versioning value."""
+    if requirement is not None and requirement not in _ROW_VERSIONING_REQUIREMENTS:
+        raise ValueError(
+            "require_row_versioning must be None, 'read_committed_snapshot', "
+            "'snapshot' or 'both'"
+        )
+    return requirement
+
+
+# Separates the servers of a Server=primary|secondary failover list
+_SERVER_LIST_SEPARATOR = "|"
+_SERVER_ORDERS = ("priority", "random")
+
+
+def _check_secret_files(
+    params: Dict[str, str],
+    attrs_before: Dict[int, Any],
+    password_file: Optional[SecretFile],
+    token_file: Optional[SecretFile],
+) -> None:
+    """Reject password_file and token_file combined with credentials they would replace."""
+    if password_file is not None and _KEY_PWD in params:
+        raise ValueError("Set PWD or password_file, not both")
+    if token_file is None:
+        return
+    if password_file is not None:
+        raise ValueError("Set password_file or token_file, not both")
+    keywords = [
+        key
+        for key in (_KEY_UID, _KEY_PWD, _KEY_AUTHENTICATION, _KEY_TRUSTED_CONNECTION)
+        if key in params
+    ]
+    if keywords:
+        raise ValueError(f"token_file cannot be combined with {', '.join(keywords)}")
+    if ConstantsDDBC.SQL_COPT_SS_ACCESS_TOKEN.value in attrs_before:
+        raise ValueError("Set an access token in attrs_before or token_file, not both")
+
+
+def _object_name(schema: Optional[str], name: str) -> str:
+    """
+    Return schema.name quoted for OBJECT_ID() and COL_LENGTH(), which resolve a
+    name without a schema in the default schema, and #name in tempdb.
+    """
+    quoted = quote_identifier(name)
+    if schema:
+        quoted = f"{quote_identifier(schema)}.{quoted}"
+    return f"tempdb.{'' if schema else '.'}{quoted}" if name.startswith("#") else quoted
+
+
+# sp_getapplock lock modes, by lowercased name
+_APP_LOCK_MODES = {
+    mode.lower(): mode
+    for mode in ("Shared", "Update", "IntentShared", "IntentExclusive", "Exclusive")
+}
+# sp_getapplock return codes: >= 0 granted, -1 timed out; the others are errors
+_APP_LOCK_ERRORS = {
+    -2: "the lock request was canceled",
+    -3: "the lock request was chosen as a deadlock victim",
+    -999: "invalid lock parameters or a call error",
+}
+
+
+def _server_candidates(params: Dict[str, str]) -> List[str]:
+    """
+    Return the servers to try, in order, for parsed connection string params.
+
+    Server may list several servers separated by "|"; ServerOrder=random shuffles
+    them on every connect instead of trying them in the listed order.
+    """
+    server = params.get("server", "")
+    servers = [s.strip() for s in server.split(_SERVER_LIST_SEPARATOR) if s.strip()]
+    order = params.get(_KEY_SERVER_ORDER.lower(), "priority").lower()
+    if order not in _SERVER_ORDERS:
+        raise ValueError("ServerOrder must be 'priority' or 'random'")
+    if order == "random":
+        random.shuffle(servers)
+    return servers or [server]
+
+
+def _application_name(workload: Optional[str]) -> str:
+    """Return the APP value sent to the server, tagged with the workload if any."""
+    return f"{_DRIVER_APP_NAME} ({workload})" if workload else _DRIVER_APP_NAME
+
+
 class Connection:
     """
     A class to manage a connection to a database, compliant with DB-API 2.0 specifications.
@@ -251,6 +489,14 @@ class Connection:
         attrs_before: Optional[Dict[int, Union[int, str, bytes]]] = None,
         timeout: int = 0,
         native_uuid: Optional[bool] = None,
+        rstrip_char: bool = False,
+        workload: Optional[str] = None,
+        require_row_versioning: Optional[str] = None,
+        login_timeout: Optional[int] = None,
+        attempt: Optional[ConnectAttempt] = None,
+        password_file: Optional[SecretSource] = None,
+        token_file: Optional[SecretSource] = None,
+        read_only: bool = False,
         **kwargs: Any,
     ) -> None:
         """
@@ -270,13 +516,44 @@ class Connection:
             native_uuid (bool, optional): Controls whether UNIQUEIDENTIFIER columns return
                 uuid.UUID objects (True) or str (False) for cursors created from this connection.
                 None (default) defers to the module-level ``mssql_python.native_uuid`` setting (True).
+            rstrip_char (bool): If True, trailing blank padding is removed from fixed-length
+                CHAR/NCHAR column values as rows are fetched. Default is False.
+            workload (str, optional): Workload tag added to the application name the
+                server sees (APP_NAME() returns "MSSQL-Python (<workload>)"), so a
+                Resource Governor classifier function can route these sessions to a
+                workload group. Connections with different tags are pooled separately.
+            require_row_versioning (str, optional): Fail fast unless the database
+                uses row versioning: "read_committed_snapshot" requires
+                READ_COMMITTED_SNAPSHOT (RCSI), "snapshot" requires
+                ALLOW_SNAPSHOT_ISOLATION and "both" requires both. Checked once
+                after connecting.
+            login_timeout (int, optional): Seconds each server may take to accept the
+                login, set as SQL_ATTR_LOGIN_TIMEOUT and SQL_ATTR_CONNECTION_TIMEOUT
+                unless attrs_before sets them. With a login timeout, the connect is
+                also given up on LOGIN_TIMEOUT_GRACE seconds later should the driver
+                itself not return, e.g. hung in a TLS handshake.
+            attempt (ConnectAttempt, optional): Handle whose cancel_connect(), called
+                from another thread, aborts this connect with OperationalError.
+            password_file (str, PathLike or int, optional): File, or open file
+                descriptor, holding the password (PWD). It is read again whenever
+                a session is opened, including reconnects, so the secret can be
+                rotated (e.g. a Kubernetes secret mount) without restarting.
+            token_file (str, PathLike or int, optional): File, or open file
+                descriptor, holding a Microsoft Entra access token, read like
+                password_file and sent as SQL_COPT_SS_ACCESS_TOKEN. It replaces
+                UID, PWD and Authentication.
+            read_only (bool): If True, connect with ApplicationIntent=ReadOnly and
+                reject statements that write before they are sent (see
+                mssql_python.read_only.check_read_only). Default is False.
             **kwargs: Additional key/value pairs for the connection string.
 
         Returns:
             None
 
         Raises:
-            ValueError: If the connection string is invalid or connection fails.
+            ValueError: If the connection string is invalid or connection fails,
+                or sets an ApplicationIntent other than ReadOnly with read_only.
+            OperationalError: If the database does not meet require_row_versioning.
 
         This method sets up the initial state for the connection object,
         preparing it for further operations such as connecting to the
@@ -296,11 +573,85 @@ class Connection:
         if native_uuid is not None and not isinstance(native_uuid, bool):
             raise ValueError("native_uuid must be a boolean value or None")
         self._native_uuid = native_uuid
+        if not isinstance(rstrip_char, bool):
+            raise ValueError("rstrip_char must be a boolean value")
+        self._rstrip_char = rstrip_char
+        self._workload = _validate_workload(workload)
+        self._require_row_versioning = _validate_row_versioning(require_row_versioning)
+        if not isinstance(read_only, bool):
+            raise ValueError("read_only must be a boolean value")
+        self._read_only = read_only
 
         self.connection_str, parsed_params = self._construct_connection_string(
             connection_str, **kwargs
         )
+        if read_only:
+            intent = parsed_params.get("ApplicationIntent", "ReadOnly")
+            if intent.strip().lower() != "readonly":
+                raise ValueError(
+                    f"ApplicationIntent={intent} conflicts with read_only=True, "
+                    "which connects with ApplicationIntent=ReadOnly"
+                )
+            parsed_params["ApplicationIntent"] = "ReadOnly"
+            self.connection_str = _ConnectionStringBuilder(parsed_params).build()
+        # In FIPS mode, refuse before connecting if encryption is off or the
+        # crypto providers are not FIPS-validated
+        require_fips_connection(parsed_params)
         self._attrs_before = attrs_before or {}
+        self._password_file = SecretFile.of(password_file, "password_file")
+        self._token_file = SecretFile.of(token_file, "token_file")
+        _check_secret_files(
+            parsed_params, self._attrs_before, self._password_file, self._token_file
+        )
+        # Connection string parameters PWD is read into on each connect, see _read_secrets
+        self._password_params: Optional[Dict[str, str]] = None
+        if self._password_file is not None:
+            self._password_params = parsed_params
+            parsed_params[_KEY_PWD] = self._password_file.read()
+            self.connection_str = _ConnectionStringBuilder(parsed_params).build()
+        if self._token_file is not None:
+            self._attrs_before = dict(self._attrs_before)
+            self._read_secrets()
+        if login_timeout is not None:
+            if not isinstance(login_timeout, int) or isinstance(login_timeout, bool):
+                raise TypeError("login_timeout must be an integer")
+            if login_timeout < 0:
+                raise ValueError("login_timeout cannot be negative")
+            self._attrs_before = dict(self._attrs_before)
+            self._attrs_before.setdefault(ConstantsDDBC.SQL_ATTR_LOGIN_TIMEOUT.value, login_timeout)
+            self._attrs_before.setdefault(
+                ConstantsDDBC.SQL_ATTR_CONNECTION_TIMEOUT.value, login_timeout
+            )
+        # Sets the batch size limit statements are checked against before they run
+        self._packet_size = configured_packet_size(parsed_params, self._attrs_before)
+        # Seconds each phase of opening the session took, see connect_timings
+        self._connect_timings: Dict[str, Optional[float]] = dict.fromkeys(CONNECT_PHASES)
+        self._strict_encryption = parsed_params.get("Encrypt", "").strip().lower() == "strict"
+        # Cancels the initial connect only, see _connect_driver
+        self._connect_attempt = attempt
+        # Without MARS only one cursor may have unread results at a time; cursors
+        # check this before executing to raise a clear error instead of a driver one.
+        self._mars_enabled = bool(
+            self._attrs_before.get(ConstantsDDBC.SQL_COPT_SS_MARS_ENABLED.value)
+        )
+        # Opt-in: discard other cursors' pending results instead of raising
+        self._auto_drain_results = False
+        # Opt-in: declare string/binary parameters with bucketed sizes
+        self._stable_parameter_sizes = False
+        # Opt-in: send the literals of statements without parameters as parameters
+        self._auto_parameterize = False
+        # Check run on every statement before it is sent (see statement_policy)
+        self._statement_policy: Optional[Callable[[str], Optional[bool]]] = None
+        # Opt-in: attach the blocking chain to timeout and cancellation errors
+        self._diagnose_blocking = False
+        # Opt-in: attach the deadlock graph to deadlock victim errors
+        self._capture_deadlock_graphs = False
+        # Priority class of executions queued for max_concurrent_statements
+        self._priority = "normal"
+        # Idle prepared statement handles, keyed by SQL text in LRU order, as
+        # (handle, query timeout) pairs. Disabled while the size is 0.
+        self._statement_cache: "OrderedDict[str, Tuple[Any, int]]" = OrderedDict()
+        self._statement_cache_size = 0
 
         # Initialize encoding settings with defaults for Python 3
         # Python 3 only has str (which is Unicode), so we use utf-16le by default
@@ -328,6 +679,8 @@ class Connection:
                 "ctype": ConstantsDDBC.SQL_WCHAR.value,
             },
         }
+        # Last resort for SQL_CHAR values the SQL_CHAR encoding cannot decode
+        self._char_fallback_encoding: Optional[str] = None
 
         # Auth type for acquiring fresh tokens at bulk copy time.
         # We intentionally do NOT cache the token — a fresh one is acquired
@@ -357,7 +710,11 @@ class Connection:
                 # Strip sensitive params and rebuild the connection string.
                 sanitized = remove_sensitive_params(parsed_params)
                 self.connection_str = _ConnectionStringBuilder(sanitized).build()
+                # The token is reused on reconnect, the password is not needed again
+                self._password_params = None
+                started = time.perf_counter()
                 token = get_auth_token(auth_type, credential_kwargs)
+                self._connect_timings["token"] = time.perf_counter() - started
                 if token:
                     self._attrs_before[ConstantsDDBC.SQL_COPT_SS_ACCESS_TOKEN.value] = token
                 self._credential_kwargs = credential_kwargs
@@ -370,6 +727,34 @@ class Connection:
         self._closed = False
         self._timeout = timeout
 
+        # Connection state tracking (see the state property)
+        self._connecting = True
+        self._broken = False
+        self._transaction_open = False
+        # Set after executions: the server's transaction count may have changed
+        self._transaction_state_stale = False
+        self._active_executions = 0
+        # Queue of the pool's max_concurrent_statements, set when a pooled session opens
+        self._statement_limiter: Optional[StatementLimiter] = None
+        # Cursors currently executing, cancelled by close(); notified as they finish
+        self._executing_cursors: "weakref.WeakSet[Cursor]" = weakref.WeakSet()
+        self._state_lock = threading.Condition()
+        self._closing = False
+        # @@SPID of the current session once read, see session_id
+        self._session_id: Optional[int] = None
+        # Usage of driver connections already closed (reconnects), see usage()
+        self._retired_usage: Dict[str, int] = dict.fromkeys(_USAGE_COUNTERS, 0)
+        # Autocommit connection that watches this one's statements, see Cursor.progress()
+        self._monitor: Optional["Connection"] = None
+        self._monitor_lock = threading.Lock()
+        self._maintenance: Optional["IndexMaintenance"] = None
+
+        # Active savepoints of begin_nested(), innermost last
+        self._savepoints: List["NestedTransaction"] = []
+        self._savepoint_counter = 0
+        # Isolation level of set_isolation_level(), None for the server default
+        self._isolation_level: Optional[str] = None
+
         # Using WeakSet which automatically removes cursors when they are no
         # longer in use
         # It is a set that holds weak references to its elements.
@@ -401,13 +786,19 @@ class Connection:
         if not PoolingManager.is_initialized():
             PoolingManager.enable()
         self._pooling = PoolingManager.is_enabled()
+        started = time.perf_counter()
         try:
-            self._conn = ddbc_bindings.Connection(
-                self.connection_str, self._pooling, self._attrs_before
-            )
-        except RuntimeError as e:
-            _raise_connection_error(e)
+            self._conn = self._open_session()
+        finally:
+            self._connect_attempt = None
+        self._connect_timings["connect"] = time.perf_counter() - started
+        started = time.perf_counter()
         self.setautocommit(autocommit)
+        if self._require_row_versioning is not None:
+            self._check_row_versioning()
+        self._connect_timings["session"] = time.perf_counter() - started
+        self._connect_timings["total"] = total_seconds(self._connect_timings)
+        self._connecting = False
 
         # Register this connection for cleanup before Python shutdown
         # This ensures ODBC handles are freed in correct order, preventing leaks
@@ -425,6 +816,180 @@ class Connection:
                 f"Unexpected error during connection registration: {type(e).__name__}: {e}"
             )
 
+    def _read_secrets(self) -> None:
+        """
+        Read the password of password_file into the connection string and the
+        token of token_file into attrs_before, before a session is opened.
+        """
+        if getattr(self, "_password_params", None) is not None:
+            self._password_params[_KEY_PWD] = self._password_file.read()
+            self.connection_str = _ConnectionStringBuilder(self._password_params).build()
+        if getattr(self, "_token_file", None) is not None:
+            token = self._token_file.read()
+            if not token:
+                raise ValueError(f"token_file {self._token_file.source} is empty")
+            self._attrs_before[ConstantsDDBC.SQL_COPT_SS_ACCESS_TOKEN.value] = (
+                AADAuth.get_token_struct(token)
+            )
+
+    def _open_session(self) -> Any:
+        """
+        Open the driver connection, failing over along a Server=a|b|c list.
+
+        Each server is tried in turn (see _server_candidates) until one accepts the
+        connection; the error of the last one is raised if none does. The server
+        connected to is kept in _active_server. LocalDB servers
+        ((localdb)\\<instance>) are connected to through the pipe of the instance,
+        and with ServerCertificateHash the certificate of each server is checked
+        against the pins before connecting (see mssql_python.tls). A server whose
+        circuit breaker is open is skipped (see mssql_python.circuit_breaker).
+        """
+        # pylint: disable=import-outside-toplevel
+        from mssql_python.tls import forget_pinned_certificate, pin_server_certificate
+
+        params = _ConnectionStringParser(validate_keywords=False)._parse(self.connection_str)
+        servers = _server_candidates(params)
+        localdb = any(localdb_instance(server) for server in servers)
+        rebuild = len(servers) > 1 or localdb
+        rebuild = rebuild or _KEY_SERVER_ORDER.lower() in params
+        rebuild = rebuild or _KEY_SERVER_CERTIFICATE_HASH.lower() in params
+        params.pop(_KEY_SERVER_ORDER.lower(), None)
+        last_error: Optional[Exception] = None
+        for index, server in enumerate(servers):
+            breaker = endpoint_breaker(server)
+            try:
+                if breaker is not None:
+                    breaker.allow()
+                if rebuild:
+                    server_params = dict(params, server=resolve_localdb(server))
+                    conn_str = _ConnectionStringBuilder(
+                        pin_server_certificate(server_params, server)
+                    ).build()
+                else:
+                    conn_str = self.connection_str
+                conn = self._connect_driver(conn_str)
+            except (RuntimeError, CertificateTrustError, OperationalError) as e:
+                last_error = e
+                if breaker is not None and not isinstance(e, OperationalError):
+                    match = _SQLSTATE_RE.match(str(e))
+                    if is_connection_failure(match and match.group(1), connecting=True):
+                        breaker.record_failure()
+                    elif isinstance(e, RuntimeError):
+                        # The server answered, e.g. rejected the login
+                        breaker.record_success()
+                    else:
+                        breaker.abandon()
+                # The instance may have restarted with a new pipe name, or the
+                # server may have renewed its certificate
+                forget_localdb_pipe(server)
+                forget_pinned_certificate(server)
+                if index + 1 < len(servers):
+                    logger.warning(
+                        "Connection to server %d of %d failed, trying the next: %s",
+                        index + 1,
+                        len(servers),
+                        e,
+                    )
+                continue
+            if breaker is not None:
+                breaker.record_success()
+            self._active_server = server
+            if self._pooling:
+                # The pool of the session is the one of the driver connection string
+                self._statement_limiter = PoolingManager.statement_limiter(conn_str)
+            return conn
+        if isinstance(last_error, (CertificateTrustError, OperationalError)):
+            raise last_error
+        _raise_login_error(last_error)
+
+    def _connect_driver(self, conn_str: str) -> Any:
+        """
+        Open one driver connection. With a login timeout or a ConnectAttempt, the
+        connect runs through ConnectAttempt.run so it is bounded and cancellable.
+        """
+        login_timeout = self._attrs_before.get(ConstantsDDBC.SQL_ATTR_LOGIN_TIMEOUT.value)
+        attempt = getattr(self, "_connect_attempt", None)
+        if not login_timeout and attempt is None:
+            return ddbc_bindings.Connection(conn_str, self._pooling, self._attrs_before)
+        return (attempt or ConnectAttempt()).run(
+            lambda: ddbc_bindings.Connection(conn_str, self._pooling, self._attrs_before),
+            timeout=login_timeout + LOGIN_TIMEOUT_GRACE if login_timeout else None,
+        )
+
+    @property
+    def server(self) -> Optional[str]:
+        """
+        Get the server this connection is connected to.
+
+        With a failover list (Server=primary|secondary), this is the server that
+        accepted the connection.
+
+        Returns:
+            str or None: The Server value used for the connection.
+        """
+        return getattr(self, "_active_server", None)
+
+    @property
+    def connect_timings(self) -> Dict[str, Optional[float]]:
+        """
+        Get the seconds each phase of opening the current session took.
+
+        token is the Entra ID token acquisition (None without one, and after a
+        reconnect, which reuses the token), connect the driver's connect call
+        (name resolution, TCP, pre-login, TLS and login together), session the
+        settings applied once connected (autocommit, require_row_versioning), and
+        total their sum. diagnose_connect() breaks connect down further.
+
+        Returns:
+            dict: Seconds by phase.
+        """
+        return dict(self._connect_timings)
+
+    def diagnose_connect(self, timeout: float = PROBE_TIMEOUT) -> Dict[str, Optional[float]]:
+        """
+        Break the time the current session took to open into network and login phases.
+
+        The driver connects in one call, so the network phases are measured by
+        probing the server again now, without logging in: dns (name resolution),
+        tcp (TCP handshake), prelogin (TDS pre-login exchange) and tls (TLS
+        handshake). login is the rest of the driver's connect time, spent on
+        authentication and session setup on the server; token, session and total
+        are those of connect_timings. Long network phases point at the network or
+        name resolution, a long login at authentication or the server.
+
+        Phases that cannot be probed are None, as is login then: named pipes,
+        LocalDB, named instances without a port, and a server that cannot be
+        reached now.
+
+        Args:
+            timeout: Seconds each probed phase may take.
+
+        Returns:
+            dict: Seconds by phase: token, dns, tcp, prelogin, tls, login, session
+                and total.
+        """
+        if self._closed:
+            raise InterfaceError(
+                driver_error="Cannot diagnose a closed connection",
+                ddbc_error="Cannot diagnose a closed connection",
+            )
+        timings = self.connect_timings
+        network: Dict[str, Optional[float]] = dict.fromkeys(NETWORK_PHASES)
+        if self.server:
+            network = probe_network_phases(self.server, self._strict_encryption, timeout)
+        login = None
+        if timings["connect"] is not None and all(
+            network[phase] is not None for phase in ("dns", "tcp", "prelogin")
+        ):
+            login = max(timings["connect"] - total_seconds(network), 0.0)
+        return {
+            "token": timings["token"],
+            **network,
+            "login": login,
+            "session": timings["session"],
+            "total": timings["total"],
+        }
+
     def _construct_connection_string(
         self, connection_str: str = "", **kwargs: Any
     ) -> Tuple[str, Dict[str, str]]:
@@ -433,7 +998,8 @@ class Connection:
 
         1. Parse and validate the base connection_str (validates against allowlist)
         2. Normalize parameter names (e.g., addr/address -> Server, uid -> UID)
-        3. Merge kwargs (which override connection_str params after normalization)
+        3. Merge kwargs (which override connection_str params after normalization),
+           then defaults from MSSQL_* environment variables for what is still unset
         4. Add Driver and APP (always controlled by the driver)
         5. Build and return the final connection string + parameter dictionary
 
@@ -468,14 +1034,21 @@ class Connection:
                         f"Connection parameter '{key}' is reserved and controlled by the driver. "
                         f"It cannot be set by the user."
                     )
-                # kwargs override any existing values from connection string
+                # kwargs override any existing values from connection string;
+                # booleans (e.g. trust_server_certificate=True) become yes/no
+                if isinstance(value, bool):
+                    value = "yes" if value else "no"
                 normalized_params[normalized_key] = str(value)
             else:
                 logger.warning(f"Ignoring unknown connection parameter from kwargs: {key}")
 
+        # Step 3b: Fill in what the connection string leaves out from MSSQL_*
+        # environment variables (see mssql_python.environment)
+        normalized_params.update(environment_defaults(normalized_params))
+
         # Step 4: Add Driver and APP (always controlled by the driver).
         normalized_params["Driver"] = "ODBC Driver 18 for SQL Server"
-        normalized_params["APP"] = "MSSQL-Python"
+        normalized_params["APP"] = _application_name(getattr(self, "_workload", None))
 
         # Step 5: Build final connection string
         conn_str = _ConnectionStringBuilder(normalized_params).build()
@@ -517,6 +1090,431 @@ class Connection:
         self._timeout = value
         logger.info(f"Query timeout set to {value} seconds")
 
+    @property
+    def workload(self) -> Optional[str]:
+        """
+        Get the workload tag this connection was opened with.
+
+        Returns:
+            str or None: The tag passed as ``workload`` to connect(), or None.
+        """
+        return self._workload
+
+    @property
+    def read_only(self) -> bool:
+        """
+        Whether this connection was opened with read_only=True: with
+        ApplicationIntent=ReadOnly, rejecting statements that write before they
+        are sent. A connection cannot be made read-only, or writable, after it
+        is opened, since the intent is part of the login.
+        """
+        return self._read_only
+
+    def workload_group(self) -> Optional[str]:
+        """
+        Return the Resource Governor workload group this session was classified into.
+
+        Useful to verify a classifier function routes ``workload``-tagged
+        connections as intended. Requires VIEW SERVER STATE permission.
+
+        Returns:
+            str or None: The workload group name (e.g. "default"), or None if the
+                session is not visible to the caller.
+        """
+        cursor = self.cursor()
+        try:
+            cursor.execute(
+                "SELECT g.name FROM sys.dm_exec_sessions AS s "
+                "JOIN sys.dm_resource_governor_workload_groups AS g ON g.group_id = s.group_id "
+                "WHERE s.session_id = @@SPID"
+            )
+            rows = cursor.fetchall()
+            return rows[0][0] if rows else None
+        finally:
+            cursor.close()
+
+    def _row_versioning_state(self) -> Dict[str, Any]:
+        """Read the current database's row versioning options from sys.databases."""
+        cursor = self.cursor()
+        try:
+            cursor.execute(
+                "SELECT name, snapshot_isolation_state, is_read_committed_snapshot_on "
+                "FROM sys.databases WHERE database_id = DB_ID()"
+            )
+            rows = cursor.fetchall()
+        finally:
+            cursor.close()
+        if not rows:
+            return {"database": None, "snapshot_isolation": False, "read_committed_snapshot": False}
+        name, snapshot_state, rcsi = rows[0]
+        # snapshot_isolation_state 1 is ON; 3 (IN_TRANSITION_TO_ON) cannot be used yet
+        return {
+            "database": name,
+            "snapshot_isolation": snapshot_state == 1,
+            "read_committed_snapshot": bool(rcsi),
+        }
+
+    def snapshot_isolation_enabled(self) -> bool:
+        """
+        Return whether the current database allows SNAPSHOT isolation.
+
+        True when ALLOW_SNAPSHOT_ISOLATION is ON, so transactions can run with
+        SET TRANSACTION ISOLATION LEVEL SNAPSHOT. Reflects the database in use at
+        the time of the call (one round trip).
+
+        Returns:
+            bool: True if snapshot isolation is enabled.
+        """
+        return self._row_versioning_state()["snapshot_isolation"]
+
+    def read_committed_snapshot_enabled(self) -> bool:
+        """
+        Return whether the current database uses read committed snapshot (RCSI).
+
+        With READ_COMMITTED_SNAPSHOT ON, READ COMMITTED reads see row versions
+        instead of waiting for writers' locks. Reflects the database in use at the
+        time of the call (one round trip).
+
+        Returns:
+            bool: True if READ_COMMITTED_SNAPSHOT is ON.
+        """
+        return self._row_versioning_state()["read_committed_snapshot"]
+
+    def _check_row_versioning(self) -> None:
+        """Close the connection and raise unless require_row_versioning is met."""
+        try:
+            state = self._row_versioning_state()
+        except Exception:
+            self.close()
+            raise
+        missing = [
+            _ROW_VERSIONING_OPTIONS[option]
+            for option in _ROW_VERSIONING_REQUIREMENTS[self._require_row_versioning]
+            if not state[option]
+        ]
+        if missing:
+            self.close()
+            raise OperationalError(
+                driver_error=(
+                    f"Database {state['database']!r} does not have "
+                    f"{' and '.join(missing)} enabled"
+                ),
+                ddbc_error=f"require_row_versioning={self._require_row_versioning!r} not met",
+            )
+
+    @property
+    def rstrip_char(self) -> bool:
+        """
+        Get whether CHAR/NCHAR blank padding is stripped on fetch.
+
+        Returns:
+            bool: True if trailing padding is removed. Default is False.
+        """
+        return self._rstrip_char
+
+    @rstrip_char.setter
+    def rstrip_char(self, value: bool) -> None:
+        """
+        Enable or disable stripping of CHAR/NCHAR blank padding.
+
+        SQL Server pads fixed-length CHAR(n)/NCHAR(n) values with trailing spaces up
+        to n characters. When enabled, the padding is removed while rows are built;
+        VARCHAR/NVARCHAR values are returned unchanged. Applies to result sets
+        produced after the change.
+
+        Args:
+            value (bool): True to strip trailing padding, False to keep it.
+        """
+        if not isinstance(value, bool):
+            raise TypeError("rstrip_char must be a boolean value")
+        self._rstrip_char = value
+        logger.info("rstrip_char set to %s", value)
+
+    @property
+    def stable_parameter_sizes(self) -> bool:
+        """
+        Get whether string/binary parameters are declared with bucketed sizes.
+
+        Returns:
+            bool: True if bucketed sizes are used. Default is False.
+        """
+        return self._stable_parameter_sizes
+
+    @stable_parameter_sizes.setter
+    def stable_parameter_sizes(self, value: bool) -> None:
+        """
+        Enable or disable bucketed parameter sizes.
+
+        The driver runs parameterized statements through sp_executesql /
+        sp_prepexec, declaring every string parameter with its value's length
+        (e.g. @P1 nvarchar(5) for "Smith", nvarchar(7) for "Johnson"). SQL Server
+        caches a separate plan for each distinct declaration, so the same query
+        with varying string lengths bloats the plan cache. When enabled, inferred
+        sizes are rounded up to 64, 256, 1024 or 4000 characters (8000 bytes for
+        binary), giving at most four declarations per parameter. Sizes set with
+        setinputsizes() and values too long for inline binding are not changed.
+
+        Args:
+            value (bool): True to use bucketed sizes, False to declare exact sizes.
+        """
+        if not isinstance(value, bool):
+            raise TypeError("stable_parameter_sizes must be a boolean value")
+        self._stable_parameter_sizes = value
+        logger.info("stable_parameter_sizes set to %s", value)
+
+    @property
+    def auto_parameterize(self) -> bool:
+        """
+        Get whether literals of statements executed without parameters are sent as parameters.
+
+        Returns:
+            bool: True if literals are parameterized. Default is False.
+        """
+        return self._auto_parameterize
+
+    @auto_parameterize.setter
+    def auto_parameterize(self, value: bool) -> None:
+        """
+        Enable or disable automatic parameterization of literals.
+
+        SQL Server caches one plan per distinct statement text, so code that
+        formats values into its SQL (WHERE id = 42, WHERE id = 43, ...) compiles
+        every statement and floods the plan cache. When enabled, execute()
+        without parameters replaces the integer and string literals of SELECT,
+        INSERT, UPDATE, DELETE and MERGE statements with parameters, so such
+        statements share a plan (see parameter_helper.parameterize_literals for
+        the literals that are kept). Other statements and batches are sent
+        unchanged. Together with stable_parameter_sizes, strings of different
+        lengths share the plan too.
+
+        Args:
+            value (bool): True to parameterize literals, False to send SQL as given.
+        """
+        if not isinstance(value, bool):
+            raise TypeError("auto_parameterize must be a boolean value")
+        self._auto_parameterize = value
+        logger.info("auto_parameterize set to %s", value)
+
+    @property
+    def statement_policy(self) -> Optional[Callable[[str], Optional[bool]]]:
+        """
+        Get the check run on every statement before it is sent.
+
+        Returns:
+            callable or None: The statement policy, None (the default) if there is none.
+        """
+        return self._statement_policy
+
+    @statement_policy.setter
+    def statement_policy(self, value: Optional[Callable[[str], Optional[bool]]]) -> None:
+        """
+        Set the check run on every statement before it is sent.
+
+        execute() and executemany() call the policy with the SQL text of each
+        statement; the statement is not sent when the policy raises or returns
+        False. mssql_python.StatementPolicy blocks statement words such as DROP,
+        regular expressions and references to other databases, e.g. to sandbox
+        analysts sharing a notebook environment. Connections opened from this
+        one (parallel export, blocking diagnosis) get the same policy.
+
+        Args:
+            value (callable or None): The policy, None to remove it.
+        """
+        if value is not None and not callable(value):
+            raise TypeError("statement_policy must be callable or None")
+        self._statement_policy = value
+        logger.info("statement_policy set to %r", value)
+
+    @property
+    def statement_cache_size(self) -> int:
+        """
+        Get the maximum number of prepared statement handles kept for reuse.
+
+        Returns:
+            int: The cache size. Default is 0 (no caching).
+        """
+        return self._statement_cache_size
+
+    @statement_cache_size.setter
+    def statement_cache_size(self, value: int) -> None:
+        """
+        Set the maximum number of prepared statement handles kept for reuse.
+
+        A cursor keeps its statement prepared while it re-executes the same SQL.
+        With a cache size above 0, a cursor switching to different SQL (or being
+        closed) hands its prepared handle to this connection instead of freeing it,
+        and any cursor executing that SQL text again takes the handle over without
+        another prepare/unprepare round trip. This suits ORM-style workloads that
+        issue the same parameterized statements from short-lived cursors. The least
+        recently used handles are freed beyond the limit, and the whole cache is
+        cleared when a statement fails with an error suggesting the schema changed.
+
+        Args:
+            value (int): Maximum number of cached handles; 0 disables caching.
+        """
+        if isinstance(value, bool) or not isinstance(value, int) or value < 0:
+            raise ValueError("statement_cache_size must be a non-negative integer")
+        self._statement_cache_size = value
+        self._trim_statement_cache(value)
+        logger.info("statement_cache_size set to %d", value)
+
+    def clear_statement_cache(self) -> None:
+        """Free all prepared statement handles cached for reuse."""
+        self._trim_statement_cache(0)
+
+    def _cache_statement(self, sql: str, hstmt: Any, timeout: int) -> bool:
+        """
+        Keep an idle statement handle prepared for sql for reuse.
+
+        Returns:
+            bool: False if caching is disabled; the caller keeps the handle.
+        """
+        if self._statement_cache_size <= 0 or self._closed:
+            return False
+        previous = self._statement_cache.pop(sql, None)
+        self._statement_cache[sql] = (hstmt, timeout)
+        if previous is not None:
+            # Another cursor's handle for the same SQL: keep the newer one
+            self._free_statement_handle(previous[0])
+        self._trim_statement_cache(self._statement_cache_size)
+        return True
+
+    def _take_cached_statement(self, sql: str) -> Optional[Tuple[Any, int]]:
+        """Remove and return the cached (handle, timeout) prepared for sql, if any."""
+        return self._statement_cache.pop(sql, None)
+
+    def _trim_statement_cache(self, size: int) -> None:
+        """Free the least recently cached handles until at most size remain."""
+        while len(self._statement_cache) > size:
+            _, (hstmt, _) = self._statement_cache.popitem(last=False)
+            self._free_statement_handle(hstmt)
+
+    @staticmethod
+    def _free_statement_handle(hstmt: Any) -> None:
+        try:
+            hstmt.free()
+        except Exception as e:  # pylint: disable=broad-exception-caught
+            logger.warning("Error freeing cached statement handle: %s", e)
+
+    @property
+    def auto_drain_results(self) -> bool:
+        """
+        Get whether pending results are discarded automatically on a new execute.
+
+        Returns:
+            bool: True if auto-draining is enabled. Default is False.
+        """
+        return self._auto_drain_results
+
+    @auto_drain_results.setter
+    def auto_drain_results(self, value: bool) -> None:
+        """
+        Enable or disable automatic draining of pending results.
+
+        Without MARS only one cursor may have unread results at a time. By default,
+        executing on a cursor while another cursor of this connection still has
+        unread rows raises InterfaceError. When enabled, the other cursor's pending
+        results are discarded instead (and a warning is issued), matching pyodbc's
+        forgiving behavior. Rows discarded this way can no longer be fetched.
+
+        Args:
+            value (bool): True to enable auto-draining, False to disable it.
+        """
+        if not isinstance(value, bool):
+            raise TypeError("auto_drain_results must be a boolean value")
+        self._auto_drain_results = value
+        logger.info("auto_drain_results set to %s", value)
+
+    @property
+    def diagnose_blocking(self) -> bool:
+        """
+        Get whether timeout and cancellation errors carry the server's blocking chain.
+
+        Returns:
+            bool: True if blocking diagnostics are enabled. Default is False.
+        """
+        return self._diagnose_blocking
+
+    @diagnose_blocking.setter
+    def diagnose_blocking(self, value: bool) -> None:
+        """
+        Enable or disable blocking diagnostics for failed statements.
+
+        When enabled, a statement failing with a query timeout (HYT00) or being
+        cancelled (HY008) is followed by a diagnostic query on the same session
+        that reads the blocking chain (blocked sessions with their
+        blocking_session_id, wait type and wait resource, and the sessions
+        blocking them). It is attached to the raised exception as
+        ``blocking_chain`` (see mssql_python.diagnostics.capture_blocking_chain);
+        it is None if the chain could not be read. Cancellations by close() are
+        not diagnosed. Other sessions are only visible with VIEW SERVER STATE.
+
+        Args:
+            value (bool): True to enable blocking diagnostics, False to disable them.
+        """
+        if not isinstance(value, bool):
+            raise TypeError("diagnose_blocking must be a boolean value")
+        self._diagnose_blocking = value
+        logger.info("diagnose_blocking set to %s", value)
+
+    @property
+    def capture_deadlock_graphs(self) -> bool:
+        """
+        Get whether deadlock errors carry the graph of the deadlock.
+
+        Returns:
+            bool: True if deadlock graphs are captured. Default is False.
+        """
+        return self._capture_deadlock_graphs
+
+    @capture_deadlock_graphs.setter
+    def capture_deadlock_graphs(self, value: bool) -> None:
+        """
+        Enable or disable capturing deadlock graphs for deadlock victims.
+
+        When enabled, a statement chosen as deadlock victim (error 1205) is followed
+        by a query on the same session that reads the deadlock's graph from the
+        system_health session. The <deadlock> XML is attached to the raised
+        exception as ``deadlock_graph``; it is None if it could not be read, e.g.
+        without VIEW SERVER STATE or on Azure SQL Database. The server reports the
+        graph asynchronously, so raising the error can be delayed by up to
+        mssql_python.diagnostics.DEADLOCK_GRAPH_WAIT seconds.
+
+        Args:
+            value (bool): True to capture deadlock graphs, False to stop.
+        """
+        if not isinstance(value, bool):
+            raise TypeError("capture_deadlock_graphs must be a boolean value")
+        self._capture_deadlock_graphs = value
+        logger.info("capture_deadlock_graphs set to %s", value)
+
+    @property
+    def priority(self) -> str:
+        """
+        Get the priority class of this connection's statements. Default is "normal".
+
+        Returns:
+            str: "high", "normal" or "low".
+        """
+        return self._priority
+
+    @priority.setter
+    def priority(self, value: str) -> None:
+        """
+        Set the priority class of this connection's statements.
+
+        With pooling(max_concurrent_statements=...), statements queued for the
+        pool's limit are admitted by priority class, then in arrival order, so
+        "high" (e.g. interactive) requests are not starved behind "low" (e.g.
+        batch) ones sharing the pool. A cursor's priority overrides it. Without
+        a statement limit there is no queue and the priority has no effect.
+
+        Args:
+            value (str): "high", "normal" or "low".
+        """
+        self._priority = check_priority(value)
+        logger.info("priority set to %s", value)
+
     @property
     def autocommit(self) -> bool:
         """
@@ -557,11 +1555,295 @@ class Connection:
         """
         return self._closed
 
-    def setautocommit(self, value: bool = False) -> None:
+    @property
+    def state(self) -> str:
         """
-        Set the autocommit mode of the connection.
-        Args:
-            value (bool): True to enable autocommit, False to disable it.
+        The current state of the connection.
+
+        One of:
+            "connecting": The connection is being established.
+            "idle": No statement is running and no transaction is open.
+            "executing": A statement is running on one of the connection's cursors.
+            "in_transaction": A transaction is open (see in_transaction).
+            "broken": A statement failed with a connection error, e.g. the network
+                link was lost; the connection should be closed and discarded.
+            "closed": close() has been called.
+
+        Returns:
+            str: The connection state.
+        """
+        if self._closed:
+            return "closed"
+        if self._connecting:
+            return "connecting"
+        if self._broken:
+            return "broken"
+        if self._active_executions:
+            return "executing"
+        if self.in_transaction:
+            return "in_transaction"
+        return "idle"
+
+    @property
+    def session_id(self) -> int:
+        """
+        The server process id (@@SPID) of this connection's session.
+
+        Read with one round trip on first use and cached until the session is
+        replaced. Monitoring a statement from another connection (e.g.
+        Cursor.progress()) needs it, so read it before executing the statement.
+
+        Returns:
+            int: The session id.
+        """
+        if self._session_id is None:
+            cursor = self.cursor()
+            try:
+                self._session_id = cursor.execute("SELECT @@SPID").fetchall()[0][0]
+            finally:
+                cursor.close()
+        return self._session_id
+
+    def usage(self, reset: bool = False) -> Dict[str, int]:
+        """
+        Return the work done on this connection so far, for usage metering.
+
+        The driver counts it as statements run, over every cursor of the connection
+        and across reconnects: statements executed, rows fetched, rows affected (the
+        row counts reported for INSERT, UPDATE, DELETE and MERGE), bytes sent (SQL
+        text and string or binary parameters) and bytes received (string or binary
+        column values), text counted as UTF-16. The counts stay readable after
+        close().
+
+        Args:
+            reset: Zero the counters after reading them, so that the next call
+                returns the usage since this one.
+
+        Returns:
+            dict: The counters, keyed statements_executed, rows_fetched,
+            rows_affected, bytes_sent and bytes_received.
+
+        Examples:
+            usage = conn.usage(reset=True)
+            meter.record(tenant, usage["rows_fetched"], usage["bytes_received"])
+        """
+        result = dict(self._retired_usage)
+        if reset:
+            self._retired_usage = dict.fromkeys(_USAGE_COUNTERS, 0)
+        if not self._closed and self._conn is not None:
+            for name, value in self._conn.get_usage(reset).items():
+                result[name] = result.get(name, 0) + value
+        return result
+
+    def _retire_usage(self) -> None:
+        """Keep the usage counters of the driver connection about to be closed."""
+        if not hasattr(self, "_retired_usage"):
+            return
+        try:
+            live = self._conn.get_usage(False)
+            for name in _USAGE_COUNTERS:
+                self._retired_usage[name] += live.get(name, 0)
+        except Exception as e:  # pylint: disable=broad-exception-caught
+            logger.debug("usage: Could not read the usage of the closed session: %s", e)
+
+    @property
+    def maintenance(self) -> "IndexMaintenance":
+        """
+        Index maintenance helpers for this connection.
+
+        Example:
+            conn.autocommit = True
+            conn.maintenance.rebuild_index("dbo.orders", "ix_orders_date", max_duration=60)
+            # From another thread while it runs:
+            conn.maintenance.resumable_operations("dbo.orders")[0]["percent_complete"]
+
+        Returns:
+            IndexMaintenance: See mssql_python.maintenance.
+        """
+        if self._maintenance is None:
+            from mssql_python.maintenance import IndexMaintenance
+
+            self._maintenance = IndexMaintenance(self)
+        return self._maintenance
+
+    def _monitoring_connection(self) -> "Connection":
+        """Return the connection that watches this one's statements, opening it once."""
+        if self._monitor is None or self._monitor.closed:
+            self._monitor = self._spawn_connection(autocommit=True)
+        return self._monitor
+
+    @property
+    def in_transaction(self) -> bool:
+        """
+        Whether a transaction is open on this connection.
+
+        With autocommit off, the first statement executed after connecting or after
+        commit()/rollback() starts a transaction, which stays open until the next
+        commit() or rollback() (or autocommit is enabled). Transactions opened or
+        closed on the server are reflected as well, e.g. a stored procedure that
+        left a transaction open or an explicit BEGIN TRANSACTION in autocommit mode:
+        after statements have run, the first read checks the server's @@TRANCOUNT
+        (one round trip). If that is not possible because a statement is running or
+        a cursor has unread results, the last known state is returned. Frameworks
+        can use this to decide whether a commit or rollback is needed.
+
+        Returns:
+            bool: True if a transaction is open.
+        """
+        if self._closed:
+            return False
+        if self._transaction_state_stale and not self._broken:
+            self._refresh_transaction_state()
+        return self._transaction_open
+
+    def _refresh_transaction_state(self) -> None:
+        """Read the server's transaction count into the tracked transaction state."""
+        if self._active_executions:
+            return
+        # The query would be refused or would disturb a result set still being read
+        if any(
+            getattr(cursor, "_results_pending", False) and not cursor.closed
+            for cursor in list(self._cursors)
+        ):
+            return
+        cursor = self.cursor()
+        try:
+            count = cursor.execute("SELECT @@TRANCOUNT").fetchall()[0][0]
+        except Exception as e:  # pylint: disable=broad-exception-caught
+            logger.debug("Could not read the server transaction count: %s", e)
+            return
+        finally:
+            cursor.close()
+        self._transaction_open = count > 0
+        self._transaction_state_stale = False
+
+    def _reconnect(self) -> None:
+        """
+        Replace a lost session with a new one opened with the same settings.
+
+        Session state (temporary tables, SET options, the open transaction) does not
+        carry over, and statement handles of the old session become unusable.
+        """
+        if self._closed:
+            raise InterfaceError(
+                driver_error="Cannot reconnect a closed connection",
+                ddbc_error="Cannot reconnect a closed connection",
+            )
+        try:
+            autocommit = self._conn.get_autocommit()
+        except RuntimeError:
+            autocommit = False
+        self.clear_statement_cache()
+        self._retire_usage()
+        try:
+            self._conn.close()
+        except Exception as e:  # pylint: disable=broad-exception-caught
+            logger.debug("_reconnect: Closing the lost session failed: %s", e)
+        # The token of the lost session is reused; secret files are read again
+        timings: Dict[str, Optional[float]] = dict.fromkeys(CONNECT_PHASES)
+        self._read_secrets()
+        started = time.perf_counter()
+        try:
+            self._conn = self._open_session()
+        finally:
+            self._connect_attempt = None
+        timings["connect"] = time.perf_counter() - started
+        started = time.perf_counter()
+        self.setautocommit(autocommit)
+        if self._isolation_level is not None:
+            self._apply_isolation_level(self._isolation_level)
+        timings["session"] = time.perf_counter() - started
+        timings["total"] = total_seconds(timings)
+        self._connect_timings = timings
+        self._broken = False
+        self._transaction_open = False
+        self._transaction_state_stale = False
+        self._discard_savepoints()
+        self._session_id = None
+        logger.info("Reconnected after connection loss")
+
+    def _begin_execution(self, cursor: Optional[Cursor] = None) -> None:
+        """
+        Record that a cursor of this connection started executing a statement,
+        after queuing for the pool's max_concurrent_statements if set. Fails at
+        once while the circuit breaker of the server is open.
+        """
+        breaker = endpoint_breaker(getattr(self, "_active_server", None))
+        if breaker is not None:
+            breaker.allow()
+        limiter = getattr(self, "_statement_limiter", None)
+        if limiter is not None:
+            priority = getattr(cursor, "priority", None) or getattr(self, "_priority", "normal")
+            try:
+                limiter.acquire(priority)
+            except BaseException:
+                if breaker is not None:
+                    breaker.abandon()
+                raise
+        with self._state_lock:
+            self._active_executions += 1
+            if cursor is not None:
+                cursor._executing_thread = threading.get_ident()
+                self._executing_cursors.add(cursor)
+
+    def _end_execution(
+        self, error: Optional[BaseException] = None, cursor: Optional[Cursor] = None
+    ) -> None:
+        """Record the end of an execution started with _begin_execution."""
+        limiter = getattr(self, "_statement_limiter", None)
+        if limiter is not None:
+            limiter.release()
+        breaker = endpoint_breaker(getattr(self, "_active_server", None))
+        if breaker is not None:
+            if error is not None and is_connection_failure(getattr(error, "sqlstate", None)):
+                breaker.record_failure()
+            else:
+                breaker.record_success()
+        with self._state_lock:
+            self._active_executions -= 1
+        if error is not None and (getattr(error, "sqlstate", None) or "").startswith("08"):
+            # SQLSTATE class 08: connection exception (link failure, ...)
+            self._broken = True
+            logger.warning("Connection marked broken after error: %s", error)
+        elif not self._closed and self._conn is not None:
+            self._transaction_state_stale = True
+            try:
+                if not self._conn.get_autocommit():
+                    self._transaction_open = True
+            except RuntimeError:
+                logger.debug("_end_execution: Could not read autocommit mode")
+        if cursor is not None:
+            # Last: a close() waiting in _cancel_executions() frees the handles next
+            with self._state_lock:
+                self._executing_cursors.discard(cursor)
+                self._state_lock.notify_all()
+        if error is not None:
+            self._attach_diagnostics(error)
+
+    def _attach_diagnostics(self, error: BaseException) -> None:
+        """Attach the server-side diagnostics enabled for failures like error to it."""
+        blocking = (
+            getattr(error, "sqlstate", None) in _BLOCKING_DIAGNOSTIC_SQLSTATES
+            and self._diagnose_blocking
+        )
+        deadlock = (
+            getattr(error, "native_error", None) == DEADLOCK_VICTIM
+            and self._capture_deadlock_graphs
+        )
+        if not (blocking or deadlock) or self._closing or self._closed:
+            return
+        from mssql_python import diagnostics
+
+        if blocking:
+            error.blocking_chain = diagnostics.capture_blocking_chain(self)
+        if deadlock:
+            error.deadlock_graph = diagnostics.capture_deadlock_graph(self)
+
+    def setautocommit(self, value: bool = False) -> None:
+        """
+        Set the autocommit mode of the connection.
+        Args:
+            value (bool): True to enable autocommit, False to disable it.
         Returns:
             None
         Raises:
@@ -571,6 +1853,11 @@ class Connection:
             self._conn.set_autocommit(value)
         except RuntimeError as e:
             _raise_connection_error(e)
+        if value:
+            # Enabling autocommit commits any open transaction
+            self._transaction_open = False
+            self._transaction_state_stale = False
+            self._discard_savepoints()
 
     def setencoding(self, encoding: Optional[str] = None, ctype: Optional[int] = None) -> None:
         """
@@ -708,7 +1995,11 @@ class Connection:
             return self._encoding_settings.copy()
 
     def setdecoding(
-        self, sqltype: int, encoding: Optional[str] = None, ctype: Optional[int] = None
+        self,
+        sqltype: int,
+        encoding: Optional[str] = None,
+        ctype: Optional[int] = None,
+        fallback: Optional[str] = None,
     ) -> None:
         """
         Sets the text decoding used when reading SQL_CHAR and SQL_WCHAR from the database.
@@ -728,12 +2019,18 @@ class Connection:
             ctype (int, optional): The C data type to request from SQLGetData:
                 SQL_CHAR or SQL_WCHAR. If None, uses default based on encoding
                 (SQL_WCHAR for UTF-16 variants, SQL_CHAR otherwise).
+            fallback (str, optional): SQL_CHAR only. With ctype SQL_CHAR, a value
+                that does not decode with encoding is decoded with the code page of
+                its column's collation (e.g. cp1252 for Latin1_General_CI_AS) and
+                then with fallback, replacing undecodable bytes, instead of being
+                returned as bytes. Calling setdecoding(SQL_CHAR) again without it
+                removes it.
 
         Returns:
             None
 
         Raises:
-            ProgrammingError: If the sqltype, encoding, or ctype is invalid.
+            ProgrammingError: If the sqltype, encoding, ctype or fallback is invalid.
             InterfaceError: If the connection is closed.
 
         Example:
@@ -749,6 +2046,10 @@ class Connection:
             # Use explicit ctype
             cnxn.setdecoding(mssql_python.SQL_WCHAR, encoding='utf-16le',
                            ctype=mssql_python.SQL_WCHAR)
+
+            # Narrow VARCHAR fetches that never come back as bytes
+            cnxn.setdecoding(mssql_python.SQL_CHAR, encoding='utf-8',
+                           ctype=mssql_python.SQL_CHAR, fallback='cp1252')
         """
         if self._closed:
             raise InterfaceError(
@@ -833,9 +2134,24 @@ class Connection:
         if ctype == ConstantsDDBC.SQL_WCHAR.value:
             _validate_utf16_wchar_compatibility(encoding, ctype, "SQL_WCHAR ctype")
 
+        if fallback is not None:
+            if sqltype != ConstantsDDBC.SQL_CHAR.value:
+                raise ProgrammingError(
+                    driver_error="fallback applies to SQL_CHAR only",
+                    ddbc_error=f"Invalid sqltype for a fallback encoding: {sqltype}",
+                )
+            if not _validate_encoding(fallback):
+                raise ProgrammingError(
+                    driver_error=f"Unsupported fallback encoding: {fallback}",
+                    ddbc_error=f"The encoding '{fallback}' is not supported by Python",
+                )
+            fallback = fallback.lower()
+
         # Store the decoding settings for the specified sqltype (thread-safe with lock)
         with self._encoding_lock:
             self._decoding_settings[sqltype] = {"encoding": encoding, "ctype": ctype}
+            if sqltype == ConstantsDDBC.SQL_CHAR.value:
+                self._char_fallback_encoding = fallback
 
         # Log with sanitized values for security
         sqltype_name = {
@@ -859,7 +2175,8 @@ class Connection:
             sqltype (int): The SQL type to get settings for: SQL_CHAR, SQL_WCHAR, or SQL_WMETADATA.
 
         Returns:
-            dict: A dictionary containing 'encoding' and 'ctype' keys for the specified sqltype.
+            dict: A dictionary containing 'encoding' and 'ctype' keys for the specified
+            sqltype, and 'fallback' for SQL_CHAR with a fallback encoding.
 
         Raises:
             ProgrammingError: If the sqltype is invalid.
@@ -898,7 +2215,10 @@ class Connection:
 
         # Thread-safe read with lock to prevent race conditions
         with self._encoding_lock:
-            return self._decoding_settings[sqltype].copy()
+            settings = self._decoding_settings[sqltype].copy()
+            if sqltype == ConstantsDDBC.SQL_CHAR.value and self._char_fallback_encoding:
+                settings["fallback"] = self._char_fallback_encoding
+            return settings
 
     def set_attr(self, attribute: int, value: Union[int, str, bytes, bytearray]) -> None:
         """
@@ -1033,6 +2353,66 @@ class Connection:
         logger.debug("cursor: Cursor created successfully - total_cursors=%d", len(self._cursors))
         return cursor
 
+    def _spawn_connection_string(self, database: Optional[str] = None) -> str:
+        """
+        Return the connection string of this connection without the Driver and APP
+        keywords, which are reserved and re-added by the constructor, optionally
+        bound to another database.
+        """
+        params = _ConnectionStringParser(validate_keywords=False)._parse(self.connection_str)
+        reserved = {key.lower() for key in _RESERVED_PARAMETERS}
+        if database is not None:
+            reserved.add("database")
+        if self._password_params is not None:
+            # The new connection reads password_file itself
+            reserved.add(_KEY_PWD.lower())
+        params = {key: value for key, value in params.items() if key.lower() not in reserved}
+        if database is not None:
+            params["database"] = database
+        return _ConnectionStringBuilder(params).build()
+
+    def _spawn_connection(
+        self, autocommit: bool = False, database: Optional[str] = None
+    ) -> "Connection":
+        """
+        Open a new connection to the same server with the same settings.
+
+        Used by helpers that need additional sessions (e.g. parallel export), and
+        connected to database instead of this connection's database if given.
+        Access tokens are carried over through attrs_before, and password_file
+        and token_file are read again by the new connection.
+        """
+        if self._closed:
+            raise InterfaceError(
+                driver_error="Cannot open a new session from a closed connection",
+                ddbc_error="Cannot open a new session from a closed connection",
+            )
+        attrs_before = dict(self._attrs_before)
+        if self._token_file is not None:
+            del attrs_before[ConstantsDDBC.SQL_COPT_SS_ACCESS_TOKEN.value]
+        conn = Connection(
+            self._spawn_connection_string(database),
+            autocommit=autocommit,
+            attrs_before=attrs_before,
+            timeout=self._timeout,
+            native_uuid=self._native_uuid,
+            rstrip_char=self._rstrip_char,
+            workload=self._workload,
+            require_row_versioning=self._require_row_versioning,
+            password_file=self._password_file if self._password_params is not None else None,
+            token_file=self._token_file,
+            read_only=self._read_only,
+        )
+        conn._auth_type = self._auth_type
+        conn._credential_kwargs = self._credential_kwargs
+        conn._auto_drain_results = self._auto_drain_results
+        conn._diagnose_blocking = self._diagnose_blocking
+        conn._capture_deadlock_graphs = self._capture_deadlock_graphs
+        conn._stable_parameter_sizes = self._stable_parameter_sizes
+        conn._statement_cache_size = self._statement_cache_size
+        conn._statement_policy = self._statement_policy
+        return conn
+
     def add_output_converter(self, sqltype: int, func: Callable[[Any], Any]) -> None:
         """
         Register an output converter function that will be called whenever a value
@@ -1054,6 +2434,13 @@ class Connection:
                             then the parameter passed to the function will be None, otherwise it
                             will be a bytes object.
 
+        Converters are looked up by the SQL type code of each column (as reported by
+        SQLDescribeCol), then by the Python type in cursor.description, then the
+        SQL_WVARCHAR converter is used as a fallback. Columns of types the driver
+        has no mapping for, such as CLR user-defined types (geography, geometry,
+        hierarchyid, -151), arrive as their raw bytes; sql_variant (-150) values
+        arrive as the Python value of their base type.
+
         Returns:
             None
         """
@@ -1170,6 +2557,256 @@ class Connection:
             cursor.close()
             raise
 
+    def _execute_and_fetch(self, fetch: str, sql: str, args: Any) -> Any:
+        """Execute sql on a new cursor, return the result of its fetch method and close it."""
+        cursor = self.cursor()
+        try:
+            cursor.execute(sql, *args)
+            return getattr(cursor, fetch)()
+        finally:
+            cursor.close()
+
+    def fetch_val(
+        self, sql: str, *args: Any, as_type: Optional[type] = None, default: Any = NO_DEFAULT
+    ) -> Any:
+        """
+        Execute a query and return the first column of its first row.
+
+        Unlike execute(), the cursor is closed before returning, so nothing is
+        left to clean up.
+
+        Args:
+            sql (str): The SQL query to execute.
+            *args: Parameters to be passed to the query.
+            as_type (type, optional): Type the value must have, e.g. int, str or
+                decimal.Decimal. Values are converted only where nothing is lost
+                (Decimal("3") to int, int to Decimal, a UNIQUEIDENTIFIER to str).
+            default: Returned instead of raising DataError when, with as_type,
+                the value is NULL or the query returns no rows.
+
+        Returns:
+            The value, or None if the query returns no rows, the value is NULL,
+            or the statement produces no result set.
+
+        Raises:
+            DataError: With as_type, if the value is not of that type, or is
+                NULL or missing and no default is given.
+
+        Example:
+            count = connection.fetch_val("SELECT COUNT(*) FROM users WHERE active = ?", 1)
+            name = connection.fetch_val("SELECT name FROM users WHERE id = ?", 7, as_type=str)
+        """
+        value = self._execute_and_fetch("fetchval", sql, args)
+        return value if as_type is None else typed_scalar(value, as_type, default, sql)
+
+    def fetch_row(self, sql: str, *args: Any) -> Optional["Row"]:
+        """
+        Execute a query and return its first row, None if it returns no rows.
+
+        Further rows are discarded and the cursor is closed before returning.
+
+        Example:
+            user = connection.fetch_row("SELECT id, name FROM users WHERE id = ?", 123)
+        """
+        return self._execute_and_fetch("fetchone", sql, args)
+
+    def fetch_all(self, sql: str, *args: Any) -> List["Row"]:
+        """
+        Execute a query and return all of its rows.
+
+        The cursor is closed before returning.
+
+        Example:
+            for user in connection.fetch_all("SELECT id, name FROM users"):
+                print(user.id, user.name)
+        """
+        return self._execute_and_fetch("fetchall", sql, args)
+
+    def object_id(
+        self, schema: Optional[str], name: str, object_type: Optional[str] = None
+    ) -> Optional[int]:
+        """
+        Return the object ID of a database object, None if there is none.
+
+        The names are quoted here, so they may contain dots, spaces or brackets,
+        and the lookup is a single parameterized OBJECT_ID() call.
+
+        Args:
+            schema (str): Schema of the object, None for the default schema.
+            name (str): Name of the object; temporary objects (#name) are found in
+                tempdb.
+            object_type (str, optional): Only match objects of this sys.objects
+                type, e.g. "U" (table), "V" (view) or "P" (procedure).
+
+        Example:
+            if connection.object_id("dbo", "orders", "U") is None: ...
+        """
+        if object_type is not None and not re.fullmatch(r"[A-Za-z0-9]{1,2}", object_type):
+            raise ValueError(f"object_type must be a sys.objects type code, got {object_type!r}")
+        if object_type is None:
+            return self.fetch_val("SELECT OBJECT_ID(?)", _object_name(schema, name))
+        return self.fetch_val("SELECT OBJECT_ID(?, ?)", _object_name(schema, name), object_type)
+
+    def table_exists(self, schema: Optional[str], name: str) -> bool:
+        """
+        Return whether a table exists (see object_id()); views are not tables.
+
+        Example:
+            connection.table_exists("dbo", "orders")
+        """
+        return self.object_id(schema, name, "U") is not None
+
+    def column_exists(self, schema: Optional[str], table: str, column: str) -> bool:
+        """
+        Return whether a table or view has a column, with one COL_LENGTH() call.
+
+        Example:
+            connection.column_exists("dbo", "orders", "shipped_at")
+        """
+        if not isinstance(column, str) or not column:
+            raise ValueError("column must be a non-empty string")
+        length = self.fetch_val("SELECT COL_LENGTH(?, ?)", _object_name(schema, table), column)
+        return length is not None
+
+    def acquire_app_lock(
+        self, name: str, mode: str = "Exclusive", timeout: Optional[float] = None
+    ) -> bool:
+        """
+        Acquire an application lock (sp_getapplock) on a name of your choosing.
+
+        Application locks let processes coordinate on anything, e.g. only one
+        migration runner or cron job at a time, without a table to lock. The
+        lock is owned by the session, so it is held across transactions and in
+        autocommit mode until release_app_lock() or the connection is closed.
+        A lock acquired several times must be released as many times.
+
+        Args:
+            name (str): The lock resource, at most 255 characters.
+            mode (str): "Exclusive" (default), "Update", "Shared", "IntentShared"
+                or "IntentExclusive".
+            timeout (float, optional): Seconds to wait for the lock; 0 returns at
+                once, None (default) waits as long as it takes.
+
+        Returns:
+            bool: True if the lock was granted, False if the timeout elapsed first.
+
+        Raises:
+            OperationalError: If the request was canceled or chosen as a deadlock
+                victim (native_error 1205, so it can be retried).
+            ValueError: If name, mode or timeout is invalid.
+
+        Example:
+            if connection.acquire_app_lock("migrations", timeout=30):
+                try:
+                    run_migrations()
+                finally:
+                    connection.release_app_lock("migrations")
+        """
+        if not isinstance(name, str) or not name or len(name) > 255:
+            raise ValueError("name must be a string of 1 to 255 characters")
+        lock_mode = _APP_LOCK_MODES.get(mode.lower()) if isinstance(mode, str) else None
+        if lock_mode is None:
+            raise ValueError(f"mode must be one of {', '.join(_APP_LOCK_MODES.values())}")
+        if timeout is None:
+            timeout_ms = -1
+        elif isinstance(timeout, bool) or not isinstance(timeout, (int, float)) or timeout < 0:
+            raise ValueError("timeout must be a non-negative number of seconds or None")
+        else:
+            timeout_ms = math.ceil(timeout * 1000)
+        result = self._call_app_lock_procedure(
+            "sp_getapplock",
+            {
+                "Resource": name,
+                "LockMode": lock_mode,
+                "LockOwner": "Session",
+                "LockTimeout": timeout_ms,
+            },
+        )
+        if result >= 0:
+            return True
+        if result == -1:
+            return False
+        error = OperationalError(
+            driver_error=f"Could not acquire application lock {name!r}: "
+            + _APP_LOCK_ERRORS.get(result, f"sp_getapplock returned {result}"),
+            ddbc_error="",
+        )
+        if result == -3:
+            error.native_error = DEADLOCK_VICTIM
+        raise error
+
+    def release_app_lock(self, name: str) -> None:
+        """
+        Release an application lock acquired with acquire_app_lock().
+
+        Raises:
+            DatabaseError: If this session does not hold the lock.
+        """
+        if not isinstance(name, str) or not name:
+            raise ValueError("name must be a non-empty string")
+        result = self._call_app_lock_procedure(
+            "sp_releaseapplock", {"Resource": name, "LockOwner": "Session"}
+        )
+        if result < 0:
+            raise ProgrammingError(
+                driver_error=f"Application lock {name!r} is not held by this connection",
+                ddbc_error=f"sp_releaseapplock returned {result}",
+            )
+
+    def _call_app_lock_procedure(self, procname: str, parameters: Dict[str, Any]) -> int:
+        """Call sp_getapplock or sp_releaseapplock and return its return code."""
+        cursor = self.cursor()
+        try:
+            cursor.callproc(procname, parameters)
+            return cursor.return_value
+        finally:
+            cursor.close()
+
+    def ping(self, timeout: float = 5) -> float:
+        """
+        Check that the server answers on this connection.
+
+        Sends a minimal batch and waits for its response, which proves the session
+        is alive end to end, unlike checks of the driver's local connection state.
+        The probe opens no transaction. Suited to health endpoints and to
+        pre-flight checks before handing out a connection.
+
+        Args:
+            timeout (float): Seconds to wait for the response; rounded up to whole
+                seconds, the query timeout granularity. Default is 5.
+
+        Returns:
+            float: The round-trip time in seconds.
+
+        Raises:
+            PingError: If the server does not answer within timeout or the link is
+                broken. The connection is marked broken for the latter.
+            InterfaceError: If the connection is closed.
+            ValueError: If timeout is not a positive number.
+        """
+        if isinstance(timeout, bool) or not isinstance(timeout, (int, float)) or timeout <= 0:
+            raise ValueError("timeout must be a positive number of seconds")
+        cursor = self.cursor()
+        cursor._timeout = math.ceil(timeout)
+        cursor._set_timeout()
+        # SELECT 1 reads no table, so no implicit transaction is started on the server
+        transaction_state = (self._transaction_open, self._transaction_state_stale)
+        started = time.monotonic()
+        try:
+            cursor.execute("SELECT 1", use_prepare=False)
+            cursor.fetchall()
+        except (OperationalError, InternalError) as e:
+            raise PingError(
+                driver_error=f"Ping failed: {e.driver_error}",
+                ddbc_error=e.ddbc_error,
+            ) from e
+        finally:
+            cursor.close()
+            self._transaction_open, self._transaction_state_stale = transaction_state
+        elapsed = time.monotonic() - started
+        logger.debug("ping: Server answered in %.3fs", elapsed)
+        return elapsed
+
     def batch_execute(
         self,
         statements: List[str],
@@ -1302,6 +2939,173 @@ class Connection:
 
         return results, cursor
 
+    def load_tables(
+        self,
+        loads: List["TableLoad"],
+        order: str = "dependencies",
+        batch_size: int = 1000,
+    ) -> Dict[str, int]:
+        """
+        Load rows into several related tables in a single transaction.
+
+        Parent tables are loaded before the tables that reference them (based on the
+        foreign keys between the targets), and the whole load is committed only if
+        every table succeeds. Any failure rolls back all tables. The connection
+        must not have a transaction open.
+
+        Args:
+            loads (list): TableLoad objects describing each table's rows.
+            order (str): "dependencies" (default) to order by foreign keys, or "given"
+                to load tables in list order.
+            batch_size (int): Maximum number of rows per executemany() call.
+
+        Returns:
+            dict: Mapping of table name to rows inserted, in load order.
+
+        Raises:
+            ProgrammingError: If a transaction is open, a table does not exist or the
+                foreign keys form a cycle.
+            DatabaseError: If an insert fails (after rolling back every table).
+
+        Example:
+            conn.load_tables([
+                TableLoad("dbo.OrderLines", lines, columns=["OrderID", "Sku"]),
+                TableLoad("dbo.Orders", orders, columns=["OrderID", "Customer"]),
+            ])
+        """
+        from mssql_python.bulk_load import load_tables
+
+        cursor = self.cursor()
+        try:
+            return load_tables(cursor, loads, order=order, batch_size=batch_size)
+        finally:
+            cursor.close()
+
+    def bulk_export_parallel(
+        self,
+        table_name: str,
+        num_streams: int,
+        output_dir: str = ".",
+        file_format: str = "csv",
+        split_column: Optional[str] = None,
+        split: str = "auto",
+        batch_size: int = 10000,
+    ) -> Dict[str, Any]:
+        """
+        Export a table to one file per stream, reading the streams concurrently.
+
+        The table is split by partition or by clustered key ranges and each stream
+        reads its slice on a separate connection under SNAPSHOT isolation. See
+        mssql_python.bulk_export.bulk_export_parallel for details.
+
+        Args:
+            table_name (str): Table to export (can include schema).
+            num_streams (int): Number of concurrent streams and output files.
+            output_dir (str): Directory for the output files.
+            file_format (str): "csv" or "parquet" (requires pyarrow).
+            split_column (str, optional): Column to split on instead of the clustered key.
+            split (str): "auto", "partitions" or "ranges".
+            batch_size (int): Rows fetched per round trip in each stream.
+
+        Returns:
+            dict: files, rows_exported and per-stream results.
+
+        Example:
+            result = conn.bulk_export_parallel("dbo.Sales", 8, output_dir="/data/sales")
+        """
+        from mssql_python.bulk_export import bulk_export_parallel
+
+        return bulk_export_parallel(
+            self,
+            table_name,
+            num_streams,
+            output_dir=output_dir,
+            file_format=file_format,
+            split_column=split_column,
+            split=split,
+            batch_size=batch_size,
+        )
+
+    def clone_table(
+        self,
+        source: str,
+        dest: str,
+        with_data: bool = True,
+        replace: bool = True,
+        source_connection: Optional["Connection"] = None,
+    ) -> int:
+        """
+        Create a copy of a table, e.g. to seed test data or for a blue/green swap.
+
+        The structure of source (columns, types, nullability, collations, identity
+        and computed columns) is scripted from the catalog and its rows copied
+        server-side, all in one transaction, so the clone can simply be run again.
+        Indexes, constraints and triggers are not cloned. See
+        mssql_python.table_clone.clone_table for details.
+
+        Args:
+            source (str): Table to copy (can include schema and database).
+            dest (str): Table to create.
+            with_data (bool): Copy the rows as well as the structure. Default True.
+            replace (bool): Drop dest first if it exists. Default True.
+            source_connection (Connection, optional): Read source on this connection,
+                e.g. on another server, and bulk-copy its rows into dest.
+
+        Returns:
+            int: The number of rows copied.
+
+        Example:
+            conn.clone_table("dbo.Orders", "dbo.Orders_green")
+        """
+        from mssql_python.table_clone import clone_table
+
+        return clone_table(
+            self,
+            source,
+            dest,
+            with_data=with_data,
+            replace=replace,
+            source_connection=source_connection,
+        )
+
+    def execute_with_progress(
+        self,
+        sql: str,
+        callback: Callable[["ProgressEvent"], Any],
+        poll_interval: Optional[float] = 1.0,
+    ) -> List[Tuple[str, str]]:
+        """
+        Execute a long-running statement, reporting its progress to a callback.
+
+        Intended for BACKUP, RESTORE and DBCC statements. Progress comes from two
+        sources: the "N percent processed" messages of ``WITH STATS = n`` (delivered
+        as the statement proceeds), and sys.dm_exec_requests.percent_complete,
+        polled every poll_interval seconds on a separate connection. Polling needs
+        VIEW SERVER STATE and is skipped silently without it.
+
+        The callback receives ProgressEvent objects and may be invoked from the
+        polling thread; calls are serialized and percentages never decrease. A
+        final event with source "complete" is sent when the statement finishes.
+
+        Args:
+            sql (str): The statement to run. BACKUP and RESTORE require autocommit.
+            callback: Called with a ProgressEvent for every update.
+            poll_interval (float, optional): Seconds between polls, or None to rely on
+                informational messages only.
+
+        Returns:
+            list: All informational messages, as (sqlstate, message) tuples.
+
+        Example:
+            conn.execute_with_progress(
+                "BACKUP DATABASE Sales TO DISK = N'/backup/sales.bak' WITH STATS = 10",
+                lambda event: print(event.percent),
+            )
+        """
+        from mssql_python.progress import execute_with_progress
+
+        return execute_with_progress(self, sql, callback, poll_interval=poll_interval)
+
     def getinfo(self, info_type: int) -> Union[str, int, bool, None]:
         """
         Return general information about the driver and data source.
@@ -1569,6 +3373,9 @@ class Connection:
             self._conn.commit()
         except RuntimeError as e:
             _raise_connection_error(e)
+        self._transaction_open = False
+        self._transaction_state_stale = False
+        self._discard_savepoints()
         logger.info("Transaction committed successfully.")
 
     def rollback(self) -> None:
@@ -1595,8 +3402,485 @@ class Connection:
             self._conn.rollback()
         except RuntimeError as e:
             _raise_connection_error(e)
+        self._transaction_open = False
+        self._transaction_state_stale = False
+        self._discard_savepoints()
         logger.info("Transaction rolled back successfully.")
 
+    def begin_nested(self) -> "NestedTransaction":
+        """
+        Set a savepoint in the current transaction and return it.
+
+        The returned NestedTransaction scopes part of a transaction: its rollback()
+        undoes only the work done since the savepoint, and its commit() releases the
+        savepoint, keeping that work in the enclosing transaction. As a context
+        manager it is released when the block completes and rolled back when the
+        block raises, without ending the enclosing transaction. Savepoints can be
+        nested; connection.commit() and connection.rollback() end all of them.
+
+        Requires autocommit to be off. If no transaction is open yet, one is
+        started.
+
+        Returns:
+            NestedTransaction: The new savepoint.
+
+        Raises:
+            ProgrammingError: If autocommit is enabled.
+            InterfaceError: If the connection is closed.
+
+        Example:
+            with conn.begin_nested():
+                cursor.execute("INSERT INTO audit VALUES (?)", event)
+            conn.commit()
+        """
+        from mssql_python.savepoint import begin_nested
+
+        return begin_nested(self)
+
+    def savepoint(self, name: str) -> "NestedTransaction":
+        """
+        Set a named savepoint in the current transaction and return it.
+
+        Like begin_nested(), with the savepoint named so that rollback_to() can
+        return to it. Names follow the rules of SAVE TRANSACTION: at most 32
+        letters, digits and underscores, not starting with a digit. A name may
+        be reused; rollback_to() then goes back to the most recent savepoint.
+
+        Args:
+            name: The savepoint name.
+
+        Returns:
+            NestedTransaction: The new savepoint.
+
+        Raises:
+            ProgrammingError: If autocommit is enabled or the name is invalid.
+
+        Example:
+            conn.savepoint("before_import")
+            try:
+                import_rows(cursor)
+            except ValueError:
+                conn.rollback_to("before_import")
+            conn.commit()
+        """
+        from mssql_python.savepoint import begin_nested
+
+        return begin_nested(self, name)
+
+    def rollback_to(self, name: str) -> None:
+        """
+        Roll back the work done since the named savepoint.
+
+        The savepoint stays set, so the transaction can roll back to it again;
+        savepoints set after it are ended. The transaction remains open.
+
+        Args:
+            name: The name given to savepoint().
+
+        Raises:
+            ProgrammingError: If no active savepoint has this name.
+        """
+        from mssql_python.savepoint import rollback_to
+
+        rollback_to(self, name)
+
+    @property
+    def isolation_level(self) -> Optional[str]:
+        """
+        Get the isolation level set with set_isolation_level().
+
+        Returns:
+            str or None: The level, e.g. "SNAPSHOT", or None if it was not set
+                (the server default, READ COMMITTED).
+        """
+        return self._isolation_level
+
+    def set_isolation_level(self, level: Union[str, int]) -> None:
+        """
+        Set the isolation level of this connection's transactions.
+
+        The level is set through the ODBC connection attributes, so it applies
+        to the transactions started after the call, survives reconnects and is
+        reset to READ COMMITTED when a pooled connection is returned. SNAPSHOT
+        needs ALLOW_SNAPSHOT_ISOLATION on the database (see
+        snapshot_isolation_enabled()); otherwise the server fails the first
+        statement that reads data.
+
+        Args:
+            level: "READ UNCOMMITTED", "READ COMMITTED", "REPEATABLE READ",
+                "SERIALIZABLE" or "SNAPSHOT" (case-insensitive, "_" for spaces
+                allowed), or one of the SQL_TXN_* constants.
+
+        Raises:
+            ValueError: If the level is unknown.
+            ProgrammingError: If a transaction is open.
+            InterfaceError: If the connection is closed.
+        """
+        name = _isolation_level_name(level)
+        if self._closed or self._conn is None:
+            raise InterfaceError(
+                driver_error="Cannot set the isolation level on a closed connection",
+                ddbc_error="Cannot set the isolation level on a closed connection",
+            )
+        if self.in_transaction:
+            raise ProgrammingError(
+                driver_error="Cannot change the isolation level while a transaction is open",
+                ddbc_error="Commit or roll back the transaction first",
+            )
+        self._apply_isolation_level(name)
+        self._isolation_level = name
+        logger.info("Isolation level set to %s", name)
+
+    def _apply_isolation_level(self, name: str) -> None:
+        attribute, value = _ISOLATION_LEVELS[name]
+        try:
+            self._conn.set_attr(attribute, value)
+        except RuntimeError as e:
+            _raise_connection_error(e)
+
+    def create_snapshot(
+        self, snapshot: str, database: Optional[str] = None, directory: Optional[str] = None
+    ) -> str:
+        """
+        Create a database snapshot and return the statement run.
+
+        One sparse file is created per data file of the database, named
+        <snapshot>_<logical file name>.ss, next to the data file or in directory
+        (a path on the server). Needs autocommit and CREATE DATABASE permission;
+        not available on Azure SQL Database.
+
+        Args:
+            snapshot: Name of the new snapshot database.
+            database: Database to snapshot; defaults to the current database.
+            directory: Server directory for the sparse files.
+
+        Returns:
+            str: The CREATE DATABASE ... AS SNAPSHOT OF statement.
+
+        Example:
+            conn.create_snapshot("app_baseline")
+            ...  # run a test that modifies the database
+            conn.revert_to_snapshot("app_baseline")
+        """
+        from mssql_python.snapshots import create_snapshot
+
+        return create_snapshot(self, snapshot, database=database, directory=directory)
+
+    def revert_to_snapshot(
+        self, snapshot: str, disconnect_others: bool = True, timeout: float = 60.0
+    ) -> None:
+        """
+        Revert the snapshot's source database to the snapshot and wait until it is online.
+
+        The revert needs exclusive use of the database: this connection switches
+        to master for it (and back afterwards), and other sessions are
+        disconnected with their transactions rolled back unless
+        disconnect_others=False. Pooled connections to the database are broken by
+        this. The database must have no other snapshots.
+
+        Args:
+            snapshot: Name of the snapshot to revert to; it is kept.
+            disconnect_others: Disconnect other sessions using the database.
+            timeout: Seconds to wait for the database to come back online.
+
+        Raises:
+            ProgrammingError: If the snapshot does not exist, the database has other
+                snapshots, or autocommit is off.
+            OperationalError: If the database is not online after timeout seconds.
+        """
+        from mssql_python.snapshots import revert_to_snapshot
+
+        revert_to_snapshot(self, snapshot, disconnect_others=disconnect_others, timeout=timeout)
+
+    def drop_snapshot(self, snapshot: str) -> None:
+        """
+        Drop a database snapshot.
+
+        Raises:
+            ProgrammingError: If no snapshot has this name (other databases are
+                never dropped), or autocommit is off.
+        """
+        from mssql_python.snapshots import drop_snapshot
+
+        drop_snapshot(self, snapshot)
+
+    def snapshots(self, database: Optional[str] = None) -> List[Dict[str, Any]]:
+        """
+        List database snapshots, oldest first.
+
+        Args:
+            database: Only list snapshots of this database.
+
+        Returns:
+            list: One dict per snapshot with "name", "database" (its source) and
+                "created".
+        """
+        from mssql_python.snapshots import list_snapshots
+
+        return list_snapshots(self, database)
+
+    def databases(self, include_system: bool = False) -> List[Dict[str, Any]]:
+        """
+        List the databases of the server, by name.
+
+        Args:
+            include_system: Also list master, tempdb, model and msdb.
+
+        Returns:
+            list: One dict per database with "name", "database_id", "state"
+                (e.g. "ONLINE"), "accessible" (online and this login has access),
+                "is_read_only", "compatibility_level", "collation",
+                "recovery_model", "snapshot_of" (the source of a database
+                snapshot, else None) and "created".
+        """
+        from mssql_python.catalog import list_databases
+
+        return list_databases(self, include_system)
+
+    def catalog(
+        self,
+        databases: Optional[Iterable[str]] = None,
+        kinds: Iterable[str] = ("tables", "columns", "routines"),
+        batch_size: int = 20,
+    ) -> Dict[str, List[Any]]:
+        """
+        Read the metadata of the objects of many databases, for inventory tools.
+
+        Each round trip reads batch_size databases with one statement per kind,
+        using three-part names (db.sys.objects), instead of a USE or a
+        connection per database. Databases that are offline or that this login
+        cannot access are skipped rather than failing the whole catalog. On
+        Azure SQL Database only the current database can be read.
+
+        Args:
+            databases: The databases to read; all user databases by default.
+            kinds: Any of "tables" (tables and views), "columns" and "routines"
+                (procedures and functions).
+            batch_size: Databases read per round trip.
+
+        Returns:
+            dict: Per kind a list of dicts, each with "database", "schema" and
+                "name" (for columns "table", "name", "ordinal", "type",
+                "max_length", "precision", "scale", "nullable", "is_identity" and
+                "is_computed"; for tables and routines "type", "created" and
+                "modified", and for tables "row_count"), sorted by database,
+                schema and name; and "skipped", the names of databases not read.
+
+        Raises:
+            ProgrammingError: If a kind is unknown or a database does not exist.
+        """
+        from mssql_python.catalog import catalog
+
+        return catalog(self, databases, kinds, batch_size)
+
+    def availability_groups(self) -> List[Dict[str, Any]]:
+        """
+        Return the health of the Always On availability groups of the server.
+
+        Read from the HADR DMVs, which needs VIEW SERVER STATE. A secondary
+        replica only knows its own state: there, the other replicas' states are
+        None. Servers without Always On return [].
+
+        Returns:
+            list: One dict per group with "name", "primary_replica",
+                "synchronization_health" ("HEALTHY", "PARTIALLY_HEALTHY" or
+                "NOT_HEALTHY"), "local_role" (this server's "PRIMARY",
+                "SECONDARY" or "RESOLVING") and "replicas". Each replica has
+                "server", "is_local", "role", "operational_state",
+                "connected_state", "synchronization_health", "availability_mode",
+                "failover_mode", "secondary_allow_connections",
+                "readable_secondary" and "databases"; each database has
+                "database", "synchronization_state" (e.g. "SYNCHRONIZED"),
+                "synchronization_health", "is_suspended", "suspend_reason",
+                "log_send_queue_kb", "redo_queue_kb" and "last_commit_time".
+        """
+        from mssql_python.availability import availability_groups
+
+        return availability_groups(self)
+
+    def require_primary(
+        self, group: Optional[str] = None, require_healthy: bool = True
+    ) -> Dict[str, Any]:
+        """
+        Check that this server is the primary replica of an availability group.
+
+        For deployment tooling that must only write to a healthy primary.
+
+        Args:
+            group: The availability group; may be omitted when the server has
+                only one.
+            require_healthy: Also require the group's synchronization health to
+                be HEALTHY.
+
+        Returns:
+            dict: The group, as returned by availability_groups().
+
+        Raises:
+            ProgrammingError: If the server has no such availability group.
+            OperationalError: If this server is not the primary, or the group is
+                not healthy.
+        """
+        from mssql_python.availability import require_primary
+
+        return require_primary(self, group, require_healthy)
+
+    def fulltext_population_status(self, catalog: str) -> Dict[str, Any]:
+        """
+        Return the population status of a full-text catalog.
+
+        Args:
+            catalog: Name of the full-text catalog in the current database.
+
+        Returns:
+            dict: "catalog", "status" (FULLTEXTCATALOGPROPERTY PopulateStatus),
+                "description" (e.g. "idle", "incremental population in progress"),
+                "idle", "item_count" (indexed items) and "completion_age" (end of
+                the last population, in seconds since 1990-01-01).
+
+        Raises:
+            ProgrammingError: If the catalog does not exist.
+        """
+        from mssql_python.fulltext import population_status
+
+        return population_status(self, catalog)
+
+    def wait_for_fulltext_population(
+        self, catalog: str, timeout: Optional[float] = None, poll_interval: float = 1.0
+    ) -> Dict[str, Any]:
+        """
+        Wait until a full-text catalog has finished populating.
+
+        Useful after loading data that tests or jobs then search, since full-text
+        indexes are populated in the background.
+
+        Args:
+            catalog: Name of the full-text catalog in the current database.
+            timeout: Seconds to wait at most, or None to wait indefinitely.
+            poll_interval: Seconds between status checks.
+
+        Returns:
+            dict: The idle status, as returned by fulltext_population_status().
+
+        Raises:
+            OperationalError: If the catalog is still populating after timeout.
+            ProgrammingError: If the catalog does not exist.
+        """
+        from mssql_python.fulltext import wait_for_population
+
+        return wait_for_population(self, catalog, timeout=timeout, poll_interval=poll_interval)
+
+    def create_xevent_session(
+        self,
+        name: str,
+        events: Sequence[str],
+        actions: Sequence[str] = (),
+        max_memory_kb: int = 4096,
+        scope: str = "server",
+        keep: bool = False,
+    ) -> "XEventSession":
+        """
+        Create and start an Extended Events session and return a reader for it.
+
+        The session captures events into a ring_buffer target of max_memory_kb,
+        which the returned XEventSession polls on a connection of its own (see
+        mssql_python.xevents). Closing it drops the session unless keep=True.
+        Creating sessions needs ALTER ANY EVENT SESSION.
+
+        Args:
+            name: Name of the session.
+            events: Events to capture as package.name, e.g.
+                ["sqlserver.rpc_completed", "sqlserver.xml_deadlock_report"].
+            actions: Actions added to every event, e.g. ["sqlserver.sql_text"].
+            max_memory_kb: Size of the ring buffer.
+            scope: "server" (SQL Server) or "database" (Azure SQL Database).
+            keep: Leave the session on the server when the reader is closed.
+
+        Returns:
+            XEventSession: A reader for the new session.
+
+        Example:
+            with conn.create_xevent_session("app_rpc", ["sqlserver.rpc_completed"]) as xe:
+                for event in xe.stream(poll_interval=2):
+                    print(event["name"], event["data"]["duration"])
+        """
+        from mssql_python.xevents import XEventSession
+
+        return XEventSession.create(
+            self, name, events, actions, max_memory_kb=max_memory_kb, scope=scope, keep=keep
+        )
+
+    def attach_xevent_session(self, name: str, scope: str = "server") -> "XEventSession":
+        """
+        Return a reader for an existing, started Extended Events session.
+
+        The session must have a ring_buffer target, e.g. system_health on SQL
+        Server. It is left on the server when the reader is closed.
+
+        Args:
+            name: Name of the session.
+            scope: "server" (SQL Server) or "database" (Azure SQL Database).
+
+        Returns:
+            XEventSession: A reader for the session.
+        """
+        from mssql_python.xevents import XEventSession
+
+        return XEventSession(self, name, scope)
+
+    def _run_transaction_statement(self, sql: str) -> None:
+        """Run a transaction control statement on a short-lived cursor."""
+        cursor = self.cursor()
+        try:
+            cursor.execute(sql, use_prepare=False)
+        finally:
+            cursor.close()
+
+    def _end_savepoint(self, savepoint: "NestedTransaction") -> None:
+        """Deactivate savepoint and the savepoints nested inside it."""
+        index = self._savepoints.index(savepoint)
+        for ended in self._savepoints[index:]:
+            ended.is_active = False
+        del self._savepoints[index:]
+
+    def _discard_savepoints(self) -> None:
+        """Deactivate all savepoints once the enclosing transaction has ended."""
+        for savepoint in self._savepoints:
+            savepoint.is_active = False
+        self._savepoints.clear()
+
+    def _cancel_executions(self, timeout: float = _CANCEL_ACK_TIMEOUT) -> None:
+        """
+        Cancel statements still executing on other threads before handles are freed.
+
+        Sends an attention for each executing statement and waits up to timeout
+        seconds for the server to acknowledge it, which ends the batch on the server
+        instead of leaving it running after this client has gone away.
+        """
+        # A statement of the closing thread (close() called from a callback it runs)
+        # only ends after close() returns, so it is neither cancelled nor waited for
+        current = threading.get_ident()
+
+        def others():
+            return [c for c in self._executing_cursors if c._executing_thread != current]
+
+        with self._state_lock:
+            executing = others()
+            if not executing:
+                return
+            logger.info("close: Cancelling %d executing statement(s)", len(executing))
+            # Sent under the lock so no handle is freed or reused meanwhile, as in cancel()
+            for cursor in executing:
+                try:
+                    ddbc_bindings.DDBCSQLCancel(cursor.hstmt)
+                except Exception as e:  # pylint: disable=broad-exception-caught
+                    logger.warning("close: Could not cancel executing statement: %s", e)
+            if not self._state_lock.wait_for(lambda: not others(), timeout):
+                logger.warning(
+                    "close: %d statement(s) did not acknowledge cancellation within %.1fs",
+                    len(others()),
+                    timeout,
+                )
+
     def close(self) -> None:
         """
         Close the connection now (rather than whenever .__del__() is called).
@@ -1607,6 +3891,10 @@ class Connection:
         trying to use the connection. Note that closing a connection without committing
         the changes first will cause an implicit rollback to be performed.
 
+        Statements still executing on other threads are cancelled first, waiting
+        briefly for the server to acknowledge the cancellation, so their batches do
+        not keep running on the server.
+
         Raises:
             DatabaseError: If there is an error while closing the connection.
         """
@@ -1614,6 +3902,17 @@ class Connection:
         if self._closed:
             return
 
+        if hasattr(self, "_executing_cursors"):
+            self._closing = True
+            self._cancel_executions()
+
+        if getattr(self, "_monitor", None) is not None:
+            try:
+                self._monitor.close()
+            except Exception as e:  # pylint: disable=broad-exception-caught
+                logger.warning("close: Error closing the monitoring connection: %s", e)
+            self._monitor = None
+
         # Close all cursors first, but don't let one failure stop the others
         if hasattr(self, "_cursors"):
             # Convert to list to avoid modification during iteration
@@ -1641,6 +3940,10 @@ class Connection:
             # references
             self._cursors.clear()
 
+        # Closed cursors may have handed their statement handles to the cache
+        if hasattr(self, "_statement_cache"):
+            self.clear_statement_cache()
+
         # Close the connection even if cursor cleanup had issues
         try:
             if self._conn:
@@ -1657,6 +3960,7 @@ class Connection:
                         _raise_connection_error(e)
                 # TODO: Check potential race conditions in case of multithreaded scenarios
                 # Close the connection
+                self._retire_usage()
                 self._conn.close()
                 self._conn = None
         except Exception as e:
@@ -1666,6 +3970,8 @@ class Connection:
         finally:
             # Always mark as closed, even if there were errors
             self._closed = True
+            if hasattr(self, "_savepoints"):
+                self._discard_savepoints()
 
         logger.info("Connection closed successfully.")
 
@@ -15,8 +15,9 @@ Parser behavior:
 - Collects all errors and reports them together
 """
 
-from typing import Dict, Tuple, Optional
+from typing import Dict, List, Tuple, Optional
 from mssql_python.exceptions import ConnectionStringParseError
+from mssql_python.connection_url import is_connection_url, parse_connection_url
 from mssql_python.constants import _ALLOWED_CONNECTION_STRING_PARAMS, _RESERVED_PARAMETERS
 from mssql_python.helpers import sanitize_user_input
 from mssql_python.logging import logger
@@ -162,6 +163,14 @@ class _ConnectionStringParser:
         if not connection_str:
             return {}
 
+        if is_connection_url(connection_str):
+            params, errors = parse_connection_url(connection_str)
+            if self._validate_keywords:
+                errors.extend(self._keyword_errors(params))
+            if errors:
+                raise ConnectionStringParseError(errors)
+            return params
+
         # Collect all errors for batch reporting
         errors = []
 
@@ -244,29 +253,7 @@ class _ConnectionStringParser:
 
         # Validate keywords against allowlist if validation is enabled
         if self._validate_keywords:
-            unknown_keys = []
-            reserved_keys = []
-
-            for key in params.keys():
-                # Check if this key can be normalized (i.e., it's known)
-                normalized_key = _ConnectionStringParser.normalize_key(key)
-
-                if normalized_key is None:
-                    # Unknown keyword
-                    unknown_keys.append(key)
-                elif normalized_key in _RESERVED_PARAMETERS:
-                    # Reserved keyword - user cannot set these
-                    reserved_keys.append(key)
-
-            if reserved_keys:
-                for key in reserved_keys:
-                    errors.append(
-                        f"Reserved keyword '{key}' is controlled by the driver and cannot be specified by the user"
-                    )
-
-            if unknown_keys:
-                for key in unknown_keys:
-                    errors.append(f"Unknown keyword '{key}' is not recognized")
+            errors.extend(self._keyword_errors(params))
 
         # If we collected any errors, raise them all together
         if errors:
@@ -274,6 +261,33 @@ class _ConnectionStringParser:
 
         return params
 
+    @staticmethod
+    def _keyword_errors(params: Dict[str, str]) -> List[str]:
+        """Return an error for each unknown or reserved keyword of params."""
+        errors = []
+        unknown_keys = []
+        reserved_keys = []
+
+        for key in params.keys():
+            # Check if this key can be normalized (i.e., it's known)
+            normalized_key = _ConnectionStringParser.normalize_key(key)
+
+            if normalized_key is None:
+                # Unknown keyword
+                unknown_keys.append(key)
+            elif normalized_key in _RESERVED_PARAMETERS:
+                # Reserved keyword - user cannot set these
+                reserved_keys.append(key)
+
+        for key in reserved_keys:
+            errors.append(
+                f"Reserved keyword '{key}' is controlled by the driver and cannot be specified by the user"
+            )
+
+        for key in unknown_keys:
+            errors.append(f"Unknown keyword '{key}' is not recognized")
+        return errors
+
     def _parse_value(self, connection_str: str, start_pos: int) -> Tuple[str, int]:
         """
         Parse a parameter value from the connection string.
@@ -381,7 +395,8 @@ class _ConnectionStringParser:
 
 def sanitize_connection_string(conn_str: str) -> str:
     """
-    Sanitize a connection string by masking sensitive values (PWD, Password).
+    Sanitize a connection string by masking sensitive values (PWD, Password, and
+    the keys added with configure_redaction()).
 
     Uses _ConnectionStringParser to correctly handle ODBC braced values
     (e.g. PWD={Top;Secret}) rather than a simple regex, which would truncate
@@ -409,7 +424,7 @@ def sanitize_connection_string(conn_str: str) -> str:
         for key, value in params.items():
             canonical = _ConnectionStringParser.normalize_key(key)
             display_key = canonical if canonical else key
-            if key in _SENSITIVE_KEYS:
+            if key in _SENSITIVE_KEYS or logger.redaction.masks(key):
                 sanitized_params[display_key] = "***"
             else:
                 sanitized_params[display_key] = value
@@ -164,12 +164,16 @@ class ConstantsDDBC(Enum):
 
     # SQL Server-specific connection option constants
     SQL_COPT_SS_ACCESS_TOKEN = 1256
+    SQL_COPT_SS_MARS_ENABLED = 1224
+    SQL_COPT_SS_TXN_ISOLATION = 1227
 
     # Transaction Isolation Level Constants
     SQL_TXN_READ_UNCOMMITTED = 1
     SQL_TXN_READ_COMMITTED = 2
     SQL_TXN_REPEATABLE_READ = 4
     SQL_TXN_SERIALIZABLE = 8
+    # Set through SQL_COPT_SS_TXN_ISOLATION
+    SQL_TXN_SS_SNAPSHOT = 32
 
     # Access Mode Constants
     SQL_MODE_READ_WRITE = 0
@@ -395,6 +399,9 @@ class SQLTypes:
             ConstantsDDBC.SQL_GUID.value,
             ConstantsDDBC.SQL_SS_UDT.value,
             ConstantsDDBC.SQL_SS_VARIANT.value,
+            # Declared as DECIMAL(19, 4) and DECIMAL(10, 4)
+            ConstantsDDBC.SQL_MONEY.value,
+            ConstantsDDBC.SQL_SMALLMONEY.value,
         }
 
     # Could also add category methods for convenience
@@ -517,6 +524,7 @@ _ALLOWED_CONNECTION_STRING_PARAMS = {
     "trustservercertificate": "TrustServerCertificate",
     "trust_server_certificate": "TrustServerCertificate",  # Snake_case synonym
     "hostnameincertificate": "HostnameInCertificate",  # v18.0+
+    "hostname_in_certificate": "HostnameInCertificate",  # Snake_case synonym
     "servercertificate": "ServerCertificate",  # v18.1+
     "serverspn": "ServerSPN",
     # Connection behavior
@@ -533,6 +541,13 @@ _ALLOWED_CONNECTION_STRING_PARAMS = {
     # (with spaces) ODBC only honors "PacketSize" without spaces
     # internally.
     "packetsize": "PacketSize",
+    # Order in which a Server=a|b|c list is tried; handled by mssql-python and
+    # not passed to the ODBC driver
+    "serverorder": "ServerOrder",
+    # SHA-256 fingerprints the server certificate must match; handled by
+    # mssql-python, which passes the matching certificate as ServerCertificate
+    "servercertificatehash": "ServerCertificateHash",
+    "server_certificate_hash": "ServerCertificateHash",  # Snake_case synonym
 }
 
 # Canonical normalized key names produced by _ConnectionStringParser._normalize_params.
@@ -542,6 +557,8 @@ _KEY_AUTHENTICATION = "Authentication"
 _KEY_UID = "UID"
 _KEY_PWD = "PWD"
 _KEY_TRUSTED_CONNECTION = "Trusted_Connection"
+_KEY_SERVER_ORDER = "ServerOrder"
+_KEY_SERVER_CERTIFICATE_HASH = "ServerCertificateHash"
 
 
 def get_info_constants() -> Dict[str, int]:
@@ -592,6 +609,8 @@ _DDBC_PUBLIC_API = {
     "SQL_TYPE_TIMESTAMP",
     "SQL_GUID",
     "SQL_XML",
+    "SQL_MONEY",
+    "SQL_SMALLMONEY",
     # Connection attribute constants (ODBC-standard, driver-independent only)
     "SQL_ATTR_ACCESS_MODE",
     "SQL_ATTR_CONNECTION_TIMEOUT",
@@ -604,6 +623,7 @@ _DDBC_PUBLIC_API = {
     "SQL_TXN_READ_COMMITTED",
     "SQL_TXN_REPEATABLE_READ",
     "SQL_TXN_SERIALIZABLE",
+    "SQL_TXN_SS_SNAPSHOT",
     # Access modes
     "SQL_MODE_READ_WRITE",
     "SQL_MODE_READ_ONLY",
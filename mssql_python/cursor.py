@@ -11,37 +11,106 @@ Resource Management:
 
 # pylint: disable=too-many-lines  # Large file due to comprehensive DB-API 2.0 implementation
 
+import contextlib
 import decimal
+import io
+import math
 import logging
 import uuid
+import re
 import datetime
+import time
 import warnings
-from typing import List, Mapping, Union, Any, Optional, Tuple, Sequence, TYPE_CHECKING, Iterable
+from typing import (
+    List,
+    Mapping,
+    Union,
+    Any,
+    Optional,
+    Tuple,
+    Sequence,
+    TYPE_CHECKING,
+    Iterable,
+    Iterator,
+    Dict,
+    Callable,
+)
 from mssql_python.constants import ConstantsDDBC as ddbc_sql_const, SQLTypes
 from mssql_python.helpers import check_error, connstr_to_pycore_params
 from mssql_python.logging import logger
-from mssql_python import ddbc_bindings
+from mssql_python import audit, ddbc_bindings
 from mssql_python.exceptions import (
     InterfaceError,
     NotSupportedError,
     ProgrammingError,
     OperationalError,
     DatabaseError,
+    TooManyParametersError,
+    PENDING_RESULTS_MESSAGE,
 )
+from mssql_python.retry import RetryPolicy, call_with_reconnect
 from mssql_python.row import Row
 from mssql_python import get_settings
 from mssql_python.parameter_helper import (
+    apply_query_hints,
     detect_and_convert_parameters,
     parse_pyformat_params,
     convert_pyformat_to_qmark,
+    expand_in_clauses,
+    is_single_in_placeholder,
+    substitute_literals,
+    is_in_list,
+    fit_parameter_limit,
+    parameterize_literals,
+    _LiteralInt,
+    MAX_PARAMETERS,
+)
+from mssql_python.read_only import check_read_only
+from mssql_python.statement_policy import check_statement_policy
+from mssql_python.lineage import SOURCE_KEYS, column_sources
+from mssql_python.sql_script import check_statement_size, iter_batches
+from mssql_python.collation import narrow_char_decoder
+from mssql_python.statement_limit import check_priority
+from mssql_python.temporal import PreciseTime, time_parameter_text
+from mssql_python.lob_stream import (
+    DEFAULT_LOB_CHUNK_SIZE,
+    LobBinaryReader,
+    LobReader,
+    LobTextReader,
 )
 
 if TYPE_CHECKING:
+    import pandas  # type: ignore
     import pyarrow  # type: ignore
     from mssql_python.connection import Connection
+    from mssql_python.bulk_load import BulkCopyOptions, SchemaDriftReport
+    from mssql_python.row_hash import ResultChecksum
+    from mssql_python.dbcc import DbccResult
 else:
     pyarrow = None
 
+# Column name of the result sets produced by SHOWPLAN_XML / STATISTICS XML
+_SHOWPLAN_COLUMN = "microsoft sql server 2005 xml showplan"
+
+# Statement hashes in showplan XML, e.g. QueryHash="0x8A4B1AA1E9A7BD2C"
+_QUERY_HASH_RE = re.compile(r'\bQueryHash="(0x[0-9A-Fa-f]+)"')
+_QUERY_PLAN_HASH_RE = re.compile(r'\bQueryPlanHash="(0x[0-9A-Fa-f]+)"')
+
+# Most recently used cached plan with the given query and plan hashes
+_PLAN_HANDLE_QUERY = (
+    "SELECT TOP 1 CONVERT(varchar(130), plan_handle, 1) FROM sys.dm_exec_query_stats "
+    "WHERE query_hash = CONVERT(binary(8), ?, 1) AND query_plan_hash = CONVERT(binary(8), ?, 1) "
+    "ORDER BY last_execution_time DESC"
+)
+
+# Cumulative waits of the current session, snapshotted around execute() with
+# capture_wait_stats enabled
+_WAIT_STATS_QUERY = (
+    "SELECT wait_type, waiting_tasks_count, wait_time_ms, signal_wait_time_ms "
+    "FROM sys.dm_exec_session_wait_stats WHERE session_id = @@SPID"
+)
+_WAIT_STATS_COLUMNS = ("waiting_tasks_count", "wait_time_ms", "signal_wait_time_ms")
+
 # Constants for string handling
 MAX_INLINE_CHAR: int = (
     4000  # NVARCHAR/VARCHAR inline limit; this triggers NVARCHAR(MAX)/VARCHAR(MAX) + DAE
@@ -50,6 +119,129 @@ SMALLMONEY_MIN: decimal.Decimal = decimal.Decimal("-214748.3648")
 SMALLMONEY_MAX: decimal.Decimal = decimal.Decimal("214748.3647")
 MONEY_MIN: decimal.Decimal = decimal.Decimal("-922337203685477.5808")
 MONEY_MAX: decimal.Decimal = decimal.Decimal("922337203685477.5807")
+# setinputsizes() types that are not ODBC types: the driver describes MONEY and
+# SMALLMONEY as DECIMAL(19, 4) and DECIMAL(10, 4), so they are declared as such
+_MONEY_INPUT_SIZES = {
+    ddbc_sql_const.SQL_MONEY.value: (ddbc_sql_const.SQL_DECIMAL.value, 19, 4),
+    ddbc_sql_const.SQL_SMALLMONEY.value: (ddbc_sql_const.SQL_DECIMAL.value, 10, 4),
+}
+# Keys of the PEP-249 description fields, in order, for extended_description()
+_DESCRIPTION_FIELDS = (
+    "name",
+    "type_code",
+    "display_size",
+    "internal_size",
+    "precision",
+    "scale",
+    "null_ok",
+)
+
+
+def _text_transform(method):
+    def transform(value):
+        return method(value) if isinstance(value, str) else value
+
+    return transform
+
+
+# Named column transforms accepted by Cursor.add_column_transform. They only touch
+# str values, so they are safe to register on columns that may also hold other types.
+COLUMN_TRANSFORMS: Dict[str, Callable[[Any], Any]] = {
+    "rstrip": _text_transform(lambda v: v.rstrip(" ")),  # CHAR/NCHAR blank padding
+    "strip": _text_transform(str.strip),
+    "upper": _text_transform(str.upper),
+    "lower": _text_transform(str.lower),
+}
+
+# Native errors after which cached prepared statements may be stale: invalid
+# column/object name, prepared statement not found, table schema changed
+_STALE_STATEMENT_ERRORS = frozenset({207, 208, 8179, 16943})
+
+
+def _ends_batch(sqlstate: Optional[str]) -> bool:
+    """Return True for SQLSTATEs after which no further results of a batch can be read."""
+    # Class 08: connection exception; HY010: function sequence error
+    return bool(sqlstate) and (sqlstate.startswith("08") or sqlstate == "HY010")
+
+
+# Results of a failed batch read for their errors, at most: a driver that keeps
+# reporting an error without advancing would otherwise be polled forever
+_MAX_BATCH_RESULTS = 10000
+
+# Declared sizes used for string/binary parameters when the connection's
+# stable_parameter_sizes option is on. Each value is declared with the smallest
+# bucket that fits it, so a statement needs at most a handful of distinct
+# parameter declarations (and cached plans) instead of one per value length.
+_STRING_SIZE_BUCKETS: Tuple[int, ...] = (64, 256, 1024, MAX_INLINE_CHAR)
+_BINARY_SIZE_BUCKETS: Tuple[int, ...] = (64, 256, 1024, 8000)
+
+
+def _bucket_parameter_size(sql_type: int, column_size: int) -> int:
+    """Round an inferred string/binary parameter size up to its size bucket."""
+    if sql_type in (ddbc_sql_const.SQL_WVARCHAR.value, ddbc_sql_const.SQL_VARCHAR.value):
+        buckets = _STRING_SIZE_BUCKETS
+    elif sql_type == ddbc_sql_const.SQL_VARBINARY.value:
+        buckets = _BINARY_SIZE_BUCKETS
+    else:
+        return column_size
+    for bucket in buckets:
+        if column_size <= bucket:
+            return bucket
+    return column_size
+
+
+# SQL types of the narrow (code page) character columns
+_NARROW_CHAR_TYPES = (
+    ddbc_sql_const.SQL_CHAR.value,
+    ddbc_sql_const.SQL_VARCHAR.value,
+    ddbc_sql_const.SQL_LONGVARCHAR.value,
+)
+
+
+def _is_stream_param(value: Any) -> bool:
+    """True for file-like parameter values (anything with read()), sent in chunks."""
+    return not isinstance(value, (str, bytes, bytearray, memoryview)) and callable(
+        getattr(value, "read", None)
+    )
+
+
+def _numeric_column_shape(column: Sequence[Any]) -> Optional[Tuple[int, int]]:
+    """
+    Return the (precision, scale) that holds every value of an executemany column of
+    finite Decimals, or None if it has other values or needs more than 38 digits.
+    """
+    integer_digits = scale = 0
+    for value in column:
+        if value is None:
+            continue
+        if not isinstance(value, decimal.Decimal) or not value.is_finite():
+            return None
+        _, digits, exponent = value.as_tuple()
+        integer_digits = max(integer_digits, len(digits) + exponent)
+        scale = max(scale, -exponent)
+    precision = max(integer_digits + scale, 1)
+    return (precision, scale) if precision <= 38 else None
+
+
+def _guid_parameter(value: Any) -> Any:
+    """
+    Return a parameter declared SQL_GUID with setinputsizes() as the 16 bytes_le it
+    is bound as: a uuid.UUID, or a str in any form uuid.UUID accepts.
+
+    Raises:
+        ProgrammingError: If a str is not a UUID.
+    """
+    if isinstance(value, uuid.UUID):
+        return value.bytes_le
+    if isinstance(value, str):
+        try:
+            return uuid.UUID(value).bytes_le
+        except ValueError:
+            raise ProgrammingError(
+                driver_error=f"Invalid UNIQUEIDENTIFIER parameter: {value!r}",
+                ddbc_error="A SQL_GUID parameter must be a uuid.UUID, a UUID string or 16 bytes",
+            ) from None
+    return value
 
 
 def _normalize_time_param(value, c_type):
@@ -61,7 +253,7 @@ def _normalize_time_param(value, c_type):
         ddbc_sql_const.SQL_C_CHAR.value,
         ddbc_sql_const.SQL_C_WCHAR.value,
     ):
-        return value.isoformat(timespec="microseconds")
+        return time_parameter_text(value)
     return None
 
 
@@ -74,12 +266,14 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
         description: Sequence of 7-item sequences describing one result column.
         rowcount: Number of rows produced or affected by the last execute operation.
         arraysize: Number of rows to fetch at a time with fetchmany().
+        internal_fetch_rows: Rows pulled from the driver per fetch call (None = automatic).
         rownumber: Track the current row index in the result set.
 
     Methods:
         __init__(connection_str) -> None.
         callproc(procname, parameters=None) ->
-            Modified copy of the input sequence with output parameters.
+            Modified copy of the input sequence with output parameters; the
+            return code is in return_value and the result sets in result_sets.
         close() -> None.
         execute(operation, parameters=None) -> Cursor.
         executemany(operation, seq_of_parameters) -> None.
@@ -133,16 +327,35 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
         self.arraysize: int = (
             1  # Default number of rows to fetch at a time is 1, user can change it
         )
+        # Rows requested from the driver per SQLFetch; None derives it from
+        # arraysize/fetchmany(size) or, for fetchall(), from the row size
+        self._internal_fetch_rows: Optional[int] = None
+        # Opt-in retry of statements failing with transient errors
+        self._retry_policy: Optional[RetryPolicy] = None
+        # Priority class for the pool's statement queue; None uses the connection's
+        self._priority: Optional[str] = None
+        self._retrying: bool = False
+        self._replaying: bool = False
+        # Opt-in session wait statistics around execute(); see wait_stats()
+        self._capture_wait_stats: bool = False
+        self._measuring_waits: bool = False
+        self._wait_baseline: Optional[Dict[str, Tuple[int, int, int]]] = None
+        self._wait_delta: Optional[Dict[str, Dict[str, int]]] = None
         self.buffer_length: int = 1024  # Default buffer length for string data
         self.closed: bool = False
         self._result_set_empty: bool = False  # Add this initialization
         self.last_executed_stmt: str = ""  # Stores the last statement executed by this cursor
+        # Position of the current result set among those of the last statement
+        self._result_set_number = 0
         self.is_stmt_prepared: List[bool] = [
             False
         ]  # Indicates if last_executed_stmt was prepared by ddbc shim.
         # Is a list instead of a bool coz bools in Python are immutable.
         # Hence, we can't pass around bools by reference & modify them.
         # Therefore, it must be a list with exactly one bool element.
+        # SQL the statement handle is prepared for by execute(), which the handle
+        # can be cached under on the connection when the cursor moves on
+        self._prepared_sql: Optional[str] = None
 
         self._rownumber = -1  # DB-API extension: last returned row index, -1 before first
 
@@ -153,11 +366,24 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
         self._cached_column_map_lower = None
         self._cached_converter_map = None
         self._uuid_str_indices = None  # Pre-computed UUID column indices for str conversion
+        self._column_transforms = []  # (column name or index, callable) in registration order
+        self._cached_transform_map = None  # Per-result-set (index, callables) pairs
+        self._char_column_indices = ()  # Fixed-length CHAR/NCHAR columns of the result set
+        self._narrow_char_collations = ()  # (index, collation) of CHAR/VARCHAR columns
+        self._column_sql_types = ()  # SQL type code of each column, for output converters
         # Cache the effective native_uuid setting for this cursor's connection.
         # Resolution order: connection._native_uuid (if not None) → module-level setting.
         self._conn_native_uuid = getattr(self.connection, "_native_uuid", None)
         self._next_row_index = 0  # internal: index of the next row the driver will return (0-based)
         self._has_result_set = False  # Track if we have an active result set
+        self._results_pending = False  # Unread rows remain on the server for this cursor
+        self._executing_thread: Optional[int] = None  # Set while executing, see _begin_execution
+        self.plan: Optional[str] = None  # Showplan XML from execute(..., capture_plan=...)
+        self.return_value: Any = None  # Return code of the last callproc()
+        self.result_sets: List[List[Row]] = []  # Result sets of the last callproc()
+        self._lob_reader = None  # Raw stream of the last fetch_lob_stream()
+        self._row_fetched_ahead = False  # Positioned on a row by _probe_end_of_results()
+        self._capture_actual_plan = False  # Hide STATISTICS XML result sets from nextset()
         self._skip_increment_for_next_fetch = (
             False  # Track if we need to skip incrementing the row index
         )
@@ -242,12 +468,17 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
                 continue
         return None
 
-    def _get_numeric_data(self, param: decimal.Decimal) -> Any:
+    def _get_numeric_data(
+        self, param: decimal.Decimal, precision: Optional[int] = None, scale: Optional[int] = None
+    ) -> Any:
         """
         Get the data for a numeric parameter.
 
         Args:
             param: The numeric parameter.
+            precision: Precision to bind at, by default that of the value.
+            scale: Scale to bind at, by default that of the value. A value with more
+                decimal places is rounded half-even to it.
 
         Returns:
             numeric_data: A NumericData struct containing
@@ -262,25 +493,28 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
         if isinstance(exponent, str):
             # For special values like 'n' (NaN), 'N' (sNaN), 'F' (Infinity)
             # Return default precision and scale
-            precision = 38  # SQL Server default max precision
-            scale = 0
+            value_precision = 38  # SQL Server default max precision
+            value_scale = 0
+            exponent = 0
         else:
             # Calculate the SQL precision & scale
             #   precision = no. of significant digits
             #   scale     = no. digits after decimal point
             if exponent >= 0:
                 # digits=314, exp=2 ---> '31400' --> precision=5, scale=0
-                precision = num_digits + exponent
-                scale = 0
+                value_precision = num_digits + exponent
+                value_scale = 0
             elif (-1 * exponent) <= num_digits:
                 # digits=3140, exp=-3 ---> '3.140' --> precision=4, scale=3
-                precision = num_digits
-                scale = exponent * -1
+                value_precision = num_digits
+                value_scale = exponent * -1
             else:
                 # digits=3140, exp=-5 ---> '0.03140' --> precision=5, scale=5
                 # TODO: double check the precision calculation here with SQL documentation
-                precision = exponent * -1
-                scale = exponent * -1
+                value_precision = exponent * -1
+                value_scale = exponent * -1
+        precision = value_precision if precision is None else precision
+        scale = value_scale if scale is None else scale
 
         if precision > 38:
             raise ValueError(
@@ -288,33 +522,32 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
                 + str(param)
                 + ". Should be less than or equal to 38"
             )
+        # The unscaled integer of the digits at the bound scale, without formatting
+        # the value as text: 12.34 at scale 2 ---> 1234
+        unscaled = 0
+        for digit in digits_tuple:
+            unscaled = unscaled * 10 + digit
+        shift = exponent + scale
+        if shift >= 0:
+            unscaled *= 10**shift
+        else:
+            unscaled, remainder = divmod(unscaled, 10**-shift)
+            half = 10**-shift // 2
+            if remainder > half or (remainder == half and unscaled % 2):
+                unscaled += 1
+        # After rounding: 9.999 at precision 3, scale 2 becomes 10.00, four digits
+        if unscaled >= 10**precision:
+            raise ValueError(
+                f"Numeric value {param} does not fit in precision {precision}, scale {scale}"
+            )
+
         Numeric_Data = ddbc_bindings.NumericData
         numeric_data = Numeric_Data()
         numeric_data.scale = scale
         numeric_data.precision = precision
         numeric_data.sign = 1 if decimal_as_tuple.sign == 0 else 0
-        # strip decimal point from param & convert the significant digits to integer
-        # Ex: 12.34 ---> 1234
-        int_str = "".join(str(d) for d in digits_tuple)
-        if exponent > 0:
-            int_str = int_str + ("0" * exponent)
-        elif exponent < 0:
-            if -exponent > num_digits:
-                int_str = ("0" * (-exponent - num_digits)) + int_str
-
-        if int_str == "":
-            int_str = "0"
-
-        # Convert decimal base-10 string to python int, then to 16 little-endian bytes
-        big_int = int(int_str)
-        byte_array = bytearray(16)  # SQL_MAX_NUMERIC_LEN
-        for i in range(16):
-            byte_array[i] = big_int & 0xFF
-            big_int >>= 8
-            if big_int == 0:
-                break
-
-        numeric_data.val = bytes(byte_array)
+        # 16 little-endian bytes (SQL_MAX_NUMERIC_LEN)
+        numeric_data.val = unscaled.to_bytes(16, "little")
         return numeric_data
 
     def _get_encoding_settings(self):
@@ -437,6 +670,9 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
             # Use min_val/max_val if available
             value_to_check = max_val if max_val is not None else param
             min_to_check = min_val if min_val is not None else param
+            if isinstance(param, _LiteralInt):
+                # Bound as INT or BIGINT, the type of the literal it replaces
+                min_to_check = min(min_to_check, -2147483648)
             logger.debug(
                 "_map_sql_type: INT detected - index=%d, min=%s, max=%s",
                 i,
@@ -531,30 +767,7 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
                     f"The maximum precision supported by SQL Server is 38, but got {precision}."
                 )
 
-            # Detect MONEY / SMALLMONEY range
-            if SMALLMONEY_MIN <= param <= SMALLMONEY_MAX:
-                logger.debug("_map_sql_type: DECIMAL -> SMALLMONEY - index=%d", i)
-                # smallmoney
-                parameters_list[i] = format(param, "f")
-                return (
-                    ddbc_sql_const.SQL_VARCHAR.value,
-                    ddbc_sql_const.SQL_C_CHAR.value,
-                    len(parameters_list[i]),
-                    0,
-                    False,
-                )
-            if MONEY_MIN <= param <= MONEY_MAX:
-                logger.debug("_map_sql_type: DECIMAL -> MONEY - index=%d", i)
-                # money
-                parameters_list[i] = format(param, "f")
-                return (
-                    ddbc_sql_const.SQL_VARCHAR.value,
-                    ddbc_sql_const.SQL_C_CHAR.value,
-                    len(parameters_list[i]),
-                    0,
-                    False,
-                )
-            # fallback to generic numeric binding
+            # Bound as SQL_NUMERIC_STRUCT, MONEY and SMALLMONEY values included
             logger.debug("_map_sql_type: DECIMAL -> NUMERIC - index=%d", i)
             parameters_list[i] = self._get_numeric_data(param)
             logger.debug(
@@ -673,12 +886,14 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
                     7,
                     False,
                 )
-            # Naive datetime -> TIMESTAMP
+            # Naive datetime -> TIMESTAMP, with 7 digits for nanosecond precision
+            # (PreciseDateTime, pandas Timestamp)
+            precise = hasattr(param, "nanosecond")
             return (
                 ddbc_sql_const.SQL_TIMESTAMP.value,
                 ddbc_sql_const.SQL_C_TYPE_TIMESTAMP.value,
-                26,
-                6,
+                27 if precise else 26,
+                7 if precise else 6,
                 False,
             )
 
@@ -692,14 +907,35 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
             )
 
         if isinstance(param, datetime.time):
+            # Bound as text; a PreciseTime with the 7 fractional digits of TIME(7)
             return (
                 ddbc_sql_const.SQL_TYPE_TIME.value,
                 ddbc_sql_const.SQL_C_CHAR.value,
                 16,
-                6,
+                7 if isinstance(param, PreciseTime) else 6,
                 False,
             )
 
+        if _is_stream_param(param):
+            # File-like objects are read while executing, text streams as
+            # NVARCHAR(MAX) and others as VARBINARY(MAX)
+            logger.debug("_map_sql_type: Stream detected, using DAE - index=%d", i)
+            if isinstance(param, io.TextIOBase):
+                return (
+                    ddbc_sql_const.SQL_WVARCHAR.value,
+                    ddbc_sql_const.SQL_C_WCHAR.value,
+                    0,
+                    0,
+                    True,
+                )
+            return (
+                ddbc_sql_const.SQL_VARBINARY.value,
+                ddbc_sql_const.SQL_C_BINARY.value,
+                0,
+                0,
+                True,
+            )
+
         # For safety: unknown/unhandled Python types should not silently go to SQL
         raise TypeError(
             "Unsupported parameter type: The driver cannot safely convert it to a SQL type."
@@ -718,13 +954,17 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
         """
         self.hstmt = self._connection._conn.alloc_statement_handle()
 
-    def _set_timeout(self) -> None:
+    def _set_timeout(self, force: bool = False) -> None:
         """
         Set the query timeout attribute on the statement handle.
         This is called once when the cursor is created and after any handle reallocation.
         Following pyodbc's approach for better performance.
+
+        Args:
+            force: Also set a timeout of 0, e.g. on a handle taken over from another
+                cursor that may have used a different timeout.
         """
-        if self._timeout > 0:
+        if self._timeout > 0 or force:
             logger.debug("_set_timeout: Setting query timeout=%d seconds", self._timeout)
             try:
                 timeout_value = int(self._timeout)
@@ -738,13 +978,25 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
             except Exception as e:  # pylint: disable=broad-exception-caught
                 logger.warning("Failed to set query timeout: %s", str(e))
 
+    def _detach_statement_handle(self) -> Any:
+        """
+        Take the statement handle away from the cursor and return it.
+
+        Done under the connection's state lock, which cancel() holds while it uses
+        the handle: a handle freed or handed over after this call is no longer seen
+        by a cancel() from another thread.
+        """
+        lock = getattr(self._connection, "_state_lock", None)
+        with lock if lock is not None else contextlib.nullcontext():
+            hstmt, self.hstmt = self.hstmt, None
+        return hstmt
+
     def _reset_cursor(self) -> None:
         """
         Reset the DDBC statement handle.
         """
         if self.hstmt:
-            self.hstmt.free()
-            self.hstmt = None
+            self._detach_statement_handle().free()
             logger.debug("SQLFreeHandle succeeded")
 
         self._clear_rownumber()
@@ -752,6 +1004,105 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
         # Reinitialize the statement handle
         self._initialize_cursor()
         self.is_stmt_prepared = [False]
+        self._prepared_sql = None
+
+    def _park_statement_handle(self) -> bool:
+        """
+        Hand the prepared statement handle over to the connection's statement cache.
+
+        Returns:
+            bool: True if the handle was cached; the cursor is then left without one.
+        """
+        sql, self._prepared_sql = getattr(self, "_prepared_sql", None), None
+        cache_statement = getattr(self._connection, "_cache_statement", None)
+        if sql is None or not self.hstmt or not self.is_stmt_prepared[0] or not cache_statement:
+            return False
+        # Close any open result set; the prepared statement itself is kept
+        ret = ddbc_bindings.DDBCSQLResetStmt(self.hstmt)
+        if ret < 0 or not cache_statement(sql, self.hstmt, self._timeout):
+            return False
+        self._detach_statement_handle()
+        self.is_stmt_prepared = [False]
+        return True
+
+    def _swap_statement_handle(self, operation: str, reuse: bool) -> bool:
+        """
+        Cache the current prepared handle and, with reuse, take over a cached one.
+
+        Called before executing SQL other than the statement prepared last.
+
+        Args:
+            operation: The SQL about to be executed.
+            reuse: Whether a cached handle prepared for operation may be used.
+
+        Returns:
+            bool: True if the cursor now holds a handle already prepared for operation.
+        """
+        if getattr(self._connection, "_statement_cache_size", 0) <= 0:
+            return False
+        self._park_statement_handle()
+        cached = self._connection._take_cached_statement(operation) if reuse else None
+        if cached is None:
+            if not self.hstmt:
+                self._initialize_cursor()
+            return False
+        if self.hstmt:
+            self._detach_statement_handle().free()
+        self.hstmt, timeout = cached
+        if timeout != self._timeout:
+            self._set_timeout(force=True)
+        self.is_stmt_prepared = [True]
+        self._prepared_sql = operation
+        logger.debug("execute: Reusing cached prepared statement handle")
+        return True
+
+    def _collect_batch_errors(self, error: BaseException) -> None:
+        """
+        Add the errors of the remaining statements of a failed batch to error.errors.
+
+        A batch keeps running after an error that does not abort it, and the errors
+        of later statements are only reported as their results are reached. They are
+        read here, and the remaining results discarded, before the handle is reset.
+        """
+        errors = getattr(error, "errors", None)
+        if errors is None or not self.hstmt or _ends_batch(getattr(error, "sqlstate", None)):
+            return
+        handle_type = ddbc_sql_const.SQL_HANDLE_STMT.value
+        try:
+            for _ in range(_MAX_BATCH_RESULTS):
+                ret = ddbc_bindings.DDBCSQLMoreResults(self.hstmt)
+                if ret in (
+                    ddbc_sql_const.SQL_NO_DATA.value,
+                    ddbc_sql_const.SQL_INVALID_HANDLE.value,
+                ):
+                    break
+                if ret < 0:
+                    info = ddbc_bindings.DDBCSQLCheckError(handle_type, self.hstmt, ret)
+                    records = [tuple(record) for record in info.records]
+                    if not records or any(_ends_batch(record[0]) for record in records):
+                        # No further results can be reached (lost link, sequence error)
+                        break
+                    errors.extend(records)
+            else:
+                logger.debug(
+                    "execute: Stopped reading batch errors after %d results", _MAX_BATCH_RESULTS
+                )
+        except Exception:  # pylint: disable=broad-exception-caught
+            logger.debug("execute: Could not read the remaining batch errors", exc_info=True)
+
+    def _invalidate_stale_statements(self, error: BaseException) -> bool:
+        """
+        Clear the connection's statement cache if error suggests a schema change.
+
+        Returns:
+            bool: True if the cache was cleared.
+        """
+        if getattr(error, "native_error", None) not in _STALE_STATEMENT_ERRORS:
+            return False
+        clear_statement_cache = getattr(self._connection, "clear_statement_cache", None)
+        if clear_statement_cache is not None:
+            clear_statement_cache()
+        return True
 
     def _soft_reset_cursor(self) -> None:
         """Lightweight reset: close cursor and unbind params without freeing the HSTMT.
@@ -793,9 +1144,8 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
             except Exception as e:  # pylint: disable=broad-exception-caught
                 logger.warning("Error removing cursor from connection tracking: %s", e)
 
-        if self.hstmt:
-            self.hstmt.free()
-            self.hstmt = None
+        if self.hstmt and not self._park_statement_handle():
+            self._detach_statement_handle().free()
             logger.debug("SQLFreeHandle succeeded")
         self._clear_rownumber()
         self.closed = True
@@ -813,6 +1163,113 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
                 ddbc_error="",
             )
 
+    def _check_pending_results(self) -> None:
+        """
+        Raise InterfaceError if another cursor on this connection has unread results.
+
+        Without MARS a session can only have one active result set, so executing
+        here would otherwise fail with a driver "connection is busy" error. A cursor
+        whose result set turns out to have ended is not in the way. When the
+        connection has auto_drain_results enabled, the other cursor's results are
+        discarded with a warning instead.
+        """
+        connection = self._connection
+        if getattr(connection, "_mars_enabled", True):
+            return
+        for other in list(getattr(connection, "_cursors", ())):
+            if other is not self and not other.closed and other._results_pending:
+                if other._probe_end_of_results():
+                    continue
+                if getattr(connection, "_auto_drain_results", False):
+                    warnings.warn(
+                        "Discarding unread results of another cursor on this connection "
+                        "(auto_drain_results is enabled)",
+                        Warning,
+                    )
+                    other._discard_pending_results()
+                    continue
+                logger.error("execute: Another cursor on this connection has pending results")
+                raise InterfaceError(
+                    driver_error=PENDING_RESULTS_MESSAGE,
+                    ddbc_error="Multiple active result sets are not enabled on this connection",
+                )
+
+    def _probe_end_of_results(self) -> bool:
+        """
+        Move one row ahead to learn whether the current result set has ended.
+
+        After fetchone() or fetchval() has returned the last row, the driver has not
+        yet reported the end of the result set and the session is still busy. Returns
+        True when the fetch reports no more data. Otherwise the cursor stays on the
+        new row, whose data is read and converted by the next fetch. A cursor that is
+        streaming a LOB value is left alone.
+        """
+        if not getattr(self, "hstmt", None) or self._row_fetched_ahead:
+            return False
+        if self._lob_reader is not None:
+            return False
+        ret = ddbc_bindings.DDBCSQLFetch(self.hstmt)
+        self.messages.extend(ddbc_bindings.DDBCSQLGetAllDiagRecords(self.hstmt))
+        if ret == ddbc_sql_const.SQL_NO_DATA.value:
+            self._results_pending = False
+            if self._next_row_index == 0 and self.description is not None:
+                self.rowcount = 0
+            return True
+        if ret in (ddbc_sql_const.SQL_SUCCESS.value, ddbc_sql_const.SQL_SUCCESS_WITH_INFO.value):
+            self._row_fetched_ahead = True
+        return False
+
+    def _fetch_raw_row(self) -> Tuple[int, list]:
+        """Fetch the next row from the driver; clears _results_pending at the end."""
+        char_decoding = self._get_decoding_settings(ddbc_sql_const.SQL_CHAR.value)
+        wchar_decoding = self._get_decoding_settings(ddbc_sql_const.SQL_WCHAR.value)
+        row_data: list = []
+        args = (
+            self.hstmt,
+            row_data,
+            char_decoding.get("encoding", "utf-16le"),
+            wchar_decoding.get("encoding", "utf-16le"),
+            char_decoding.get("ctype", ddbc_sql_const.SQL_WCHAR.value),
+        )
+        if self._row_fetched_ahead:
+            # _probe_end_of_results() already moved to the row; only read its data
+            self._row_fetched_ahead = False
+            ret = ddbc_bindings.DDBCSQLFetchOne(*args, fetch=False)
+        else:
+            ret = ddbc_bindings.DDBCSQLFetchOne(*args)
+
+        if self.hstmt:
+            self.messages.extend(ddbc_bindings.DDBCSQLGetAllDiagRecords(self.hstmt))
+
+        if ret == ddbc_sql_const.SQL_NO_DATA.value:
+            # No more data available
+            self._results_pending = False
+            if self._next_row_index == 0 and self.description is not None:
+                # This is an empty result set, set rowcount to 0
+                self.rowcount = 0
+        return ret, row_data
+
+    def _take_fetched_ahead_rows(self) -> list:
+        """Read the row _probe_end_of_results() moved to, as a list of raw rows."""
+        if not self._row_fetched_ahead:
+            return []
+        ret, row_data = self._fetch_raw_row()
+        if ret in (ddbc_sql_const.SQL_SUCCESS.value, ddbc_sql_const.SQL_SUCCESS_WITH_INFO.value):
+            return [row_data]
+        return []
+
+    def _discard_pending_results(self) -> None:
+        """Close the ODBC cursor, dropping any unread rows and remaining result sets."""
+        logger.debug("_discard_pending_results: Discarding unread results")
+        self._soft_reset_cursor()
+        self.description = None
+        self.rowcount = -1
+        self._cached_column_map = None
+        self._cached_column_map_lower = None
+        self._cached_converter_map = None
+        self._uuid_str_indices = None
+        self._cached_transform_map = None
+
     def _capture_diagnostics(self, ret: int) -> None:
         """Append diagnostic messages to self.messages when the return code
         indicates records may be present.
@@ -927,6 +1384,8 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
 
                     self._inputsizes.append((sql_type, 0, 0))
 
+            self._inputsizes = [_MONEY_INPUT_SIZES.get(size[0], size) for size in self._inputsizes]
+
     def _reset_inputsizes(self) -> None:
         """Reset input sizes after execution"""
         self._inputsizes = None
@@ -1017,6 +1476,9 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
                     if isinstance(parameter, decimal.Decimal):
                         parameters_list[i] = format(parameter, "f")
                         parameter = parameters_list[i]
+                elif sql_type == ddbc_sql_const.SQL_GUID.value:
+                    parameters_list[i] = _guid_parameter(parameter)
+                    parameter = parameters_list[i]
 
                 # Check if this should be a DAE (data at execution) parameter
                 # For string types with large column sizes
@@ -1025,6 +1487,8 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
                 # For binary types with large column sizes
                 elif isinstance(parameter, (bytes, bytearray)) and column_size > 8000:
                     is_dae = True
+                elif _is_stream_param(parameter):
+                    is_dae = True
 
             # Sanitize precision/scale for numeric types
             if sql_type in (
@@ -1039,6 +1503,8 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
             sql_type, c_type, column_size, decimal_digits, is_dae = self._map_sql_type(
                 parameter, parameters_list, i, min_val=min_val, max_val=max_val
             )
+            if not is_dae and getattr(self._connection, "_stable_parameter_sizes", False):
+                column_size = _bucket_parameter_size(sql_type, column_size)
 
         # If TIME values are being bound via text C-types, normalize them to a
         # textual representation expected by SQL_C_CHAR/SQL_C_WCHAR binding.
@@ -1061,10 +1527,24 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
 
     def _initialize_description(self, column_metadata: Optional[Any] = None) -> None:
         """Initialize the description attribute from column metadata."""
+        self._char_column_indices = ()
+        self._narrow_char_collations = ()
+        self._column_sql_types = ()
         if not column_metadata:
             self.description = None
             return
 
+        self._char_column_indices = tuple(
+            i
+            for i, col in enumerate(column_metadata)
+            if col["DataType"] in (ddbc_sql_const.SQL_CHAR.value, ddbc_sql_const.SQL_WCHAR.value)
+        )
+        self._narrow_char_collations = tuple(
+            (i, col.get("Collation"))
+            for i, col in enumerate(column_metadata)
+            if col["DataType"] in _NARROW_CHAR_TYPES
+        )
+        self._column_sql_types = tuple(col["DataType"] for col in column_metadata)
         description = []
         for _, col in enumerate(column_metadata):
             # Get column name - lowercase it if the lowercase flag is set
@@ -1086,8 +1566,35 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
                     col["Nullable"] == ddbc_sql_const.SQL_NULLABLE.value,  # null_ok
                 )
             )
+        if get_settings().duplicate_column_names == "suffix":
+            description = self._suffix_duplicate_names(description)
         self.description = description
 
+    @staticmethod
+    def _suffix_duplicate_names(description):
+        """
+        Rename repeated column names to name_1, name_2, ... so each is addressable.
+
+        Names are compared case-insensitively (SQL Server identifiers usually are);
+        unnamed columns are left alone.
+        """
+        taken = {desc[0].lower() for desc in description}
+        seen = set()
+        renamed = []
+        for desc in description:
+            name = desc[0]
+            key = name.lower()
+            if name and key in seen:
+                n = 1
+                while f"{key}_{n}" in taken:
+                    n += 1
+                name = f"{name}_{n}"
+                taken.add(name.lower())
+                desc = (name,) + tuple(desc[1:])
+            seen.add(key)
+            renamed.append(desc)
+        return renamed
+
     def _build_converter_map(self):
         """
         Build a pre-computed converter map for output converters.
@@ -1103,12 +1610,11 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
 
         converter_map = []
 
-        for desc in self.description:
+        for i, desc in enumerate(self.description):
             if desc is None:
                 converter_map.append(None)
                 continue
-            sql_type = desc[1]
-            converter = self.connection.get_output_converter(sql_type)
+            converter = self._column_output_converter(i, desc)
             # If no converter found for the SQL type, try the WVARCHAR converter as a fallback
             if converter is None:
                 from mssql_python.constants import ConstantsDDBC
@@ -1119,6 +1625,18 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
 
         return converter_map
 
+    def _column_output_converter(self, index, desc):
+        """
+        Return the output converter registered for a column's SQL type code (e.g.
+        -151 for geography or hierarchyid), else for its Python type code.
+        """
+        converter = None
+        if index < len(self._column_sql_types):
+            converter = self.connection.get_output_converter(self._column_sql_types[index])
+        if converter is None:
+            converter = self.connection.get_output_converter(desc[1])
+        return converter
+
     def _compute_uuid_str_indices(self):
         """
         Compute the tuple of column indices whose uuid.UUID values should be
@@ -1145,6 +1663,30 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
             return indices if indices else None
         return None
 
+    def _build_column_maps(self):
+        """
+        Build the name -> index maps used for row["name"] / row.name access.
+
+        Duplicated names resolve according to the duplicate_column_names setting
+        ("first" keeps the first column, otherwise the last one wins). A lowercase
+        map for case-insensitive lookup is built when lowercase or
+        case_insensitive_columns is enabled.
+
+        Returns:
+            tuple: (column_map, column_map_lower or None)
+        """
+        settings = get_settings()
+        names = [col_desc[0] for col_desc in self.description or ()]
+        if settings.duplicate_column_names == "first":
+            ordered = list(enumerate(names))[::-1]
+        else:
+            ordered = list(enumerate(names))
+        column_map = {name: i for i, name in ordered}
+        column_map_lower = None
+        if settings.lowercase or settings.case_insensitive_columns:
+            column_map_lower = {name.lower(): i for i, name in ordered}
+        return column_map, column_map_lower
+
     def _get_column_and_converter_maps(self):
         """
         Get column map and converter map for Row construction (thread-safe).
@@ -1158,11 +1700,9 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
         column_map = self._cached_column_map
         if column_map is None and self.description:
             # Build column map locally first, then assign to cache
-            column_map = {col_desc[0]: i for i, col_desc in enumerate(self.description)}
+            column_map, column_map_lower = self._build_column_maps()
             self._cached_column_map = column_map
-            self._cached_column_map_lower = (
-                {k.lower(): v for k, v in column_map.items()} if get_settings().lowercase else None
-            )
+            self._cached_column_map_lower = column_map_lower
 
         # Fallback to legacy column name map if no cached map
         column_map = column_map or getattr(self, "_column_name_map", None)
@@ -1291,85 +1831,545 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
         """
         return self._connection
 
-    def _reset_rownumber(self) -> None:
-        """Reset the rownumber tracking when starting a new result set."""
-        self._rownumber = -1
-        self._next_row_index = 0
-        self._has_result_set = True
-        self._skip_increment_for_next_fetch = False
-
-    def _increment_rownumber(self):
+    @property
+    def internal_fetch_rows(self) -> Optional[int]:
         """
-        Called after a successful fetch from the driver. Keep both counters consistent.
+        Number of rows pulled from the driver per fetch call (row array size).
+
+        Larger values favor throughput (fewer round trips through the driver),
+        smaller values favor latency and memory. None (default) is automatic:
+        fetchmany(size) pulls ``size`` rows at once and fetchall() uses arraysize
+        when it has been raised above 1, otherwise a size derived from the row width.
+
+        This is a DB-API extension.
         """
-        if self._has_result_set:
-            # driver returned one row, so the next row index increments by 1
-            self._next_row_index += 1
-            # rownumber is last returned row index
-            self._rownumber = self._next_row_index - 1
-        else:
-            raise InterfaceError(
-                "Cannot increment rownumber: no active result set.",
-                "No active result set.",
-            )
+        return self._internal_fetch_rows
 
-    # Will be used when we add support for scrollable cursors
-    def _decrement_rownumber(self):
+    @internal_fetch_rows.setter
+    def internal_fetch_rows(self, value: Optional[int]) -> None:
+        if value is not None and (
+            not isinstance(value, int) or isinstance(value, bool) or value <= 0
+        ):
+            raise ValueError("internal_fetch_rows must be a positive integer or None")
+        self._internal_fetch_rows = value
+
+    @property
+    def retry_policy(self) -> Optional[RetryPolicy]:
         """
-        Decrement the rownumber by 1.
+        Retry policy for execute() and executemany(), or None (default) for no retries.
 
-        This could be used for error recovery or cursor positioning operations.
+        With a policy, a statement failing with one of its errors (by default
+        deadlocks, lock request timeouts and query timeouts) is run again after a
+        backoff delay, binding its parameters again, up to the policy's attempt
+        limit. See mssql_python.RetryPolicy.
+
+        This is a DB-API extension.
         """
-        if self._has_result_set and self._rownumber >= 0:
-            if self._rownumber > 0:
-                self._rownumber -= 1
-            else:
-                self._rownumber = -1
-        else:
-            raise InterfaceError(
-                "Cannot decrement rownumber: no active result set.",
-                "No active result set.",
-            )
+        return self._retry_policy
 
-    def _clear_rownumber(self):
+    @retry_policy.setter
+    def retry_policy(self, value: Optional[RetryPolicy]) -> None:
+        if value is not None and not isinstance(value, RetryPolicy):
+            raise TypeError("retry_policy must be a RetryPolicy or None")
+        self._retry_policy = value
+
+    @property
+    def priority(self) -> Optional[str]:
         """
-        Clear the rownumber tracking.
+        Priority class ("high", "normal" or "low") of this cursor's statements in
+        the pool's statement queue, or None (default) for the connection's
+        priority. See Connection.priority.
 
-        This should be called when the result set is cleared or when the cursor is reset.
+        This is a DB-API extension.
         """
-        self._rownumber = -1
-        self._has_result_set = False
-        self._skip_increment_for_next_fetch = False
+        return self._priority
 
-    def __iter__(self):
+    @priority.setter
+    def priority(self, value: Optional[str]) -> None:
+        self._priority = None if value is None else check_priority(value)
+
+    @property
+    def capture_wait_stats(self) -> bool:
         """
-        Return the cursor itself as an iterator.
+        Whether execute() snapshots the session's wait statistics, default False.
 
-        This allows direct iteration over the cursor after execute():
+        When enabled, sys.dm_exec_session_wait_stats is read before each execute()
+        and again when wait_stats() is called, on a separate statement of the same
+        session. This costs two extra round trips per statement and needs VIEW
+        SERVER STATE on SQL Server 2016+ (VIEW SERVER PERFORMANCE STATE on 2022+).
 
-        for row in cursor.execute("SELECT * FROM table"):
-            print(row)
+        This is a DB-API extension.
         """
+        return self._capture_wait_stats
+
+    @capture_wait_stats.setter
+    def capture_wait_stats(self, value: bool) -> None:
+        if not isinstance(value, bool):
+            raise TypeError("capture_wait_stats must be a bool")
+        self._capture_wait_stats = value
+
+    def _call_with_retry(self, func: Callable[[], Any]) -> Any:
+        """Run one execution through the retry policy; nested executions run once."""
+        self._retrying = True
+        try:
+            in_transaction = not getattr(self._connection, "autocommit", True)
+            return self._retry_policy.call(func, in_transaction=in_transaction)
+        finally:
+            self._retrying = False
+
+    def _call_with_reconnect(self, func: Callable[[], Any]) -> Any:
+        """Run an idempotent execution, replaying it on a new session after connection loss."""
+        self._replaying = True
+        try:
+            if self._connection._transaction_open:
+                # A new session would silently drop the work done in the open transaction
+                return func()
+            return call_with_reconnect(func, self._reconnect, self._retry_policy)
+        finally:
+            self._replaying = False
+
+    def _session_wait_stats(self) -> Optional[Dict[str, Tuple[int, int, int]]]:
+        """Read the session's cumulative waits by wait type, or None if unavailable."""
+        snapshot = self._connection.cursor()
+        try:
+            rows = snapshot.execute(_WAIT_STATS_QUERY, use_prepare=False).fetchall()
+        except Exception as e:  # pylint: disable=broad-exception-caught
+            logger.debug("wait_stats: Could not read session wait statistics: %s", e)
+            return None
+        finally:
+            snapshot.close()
+        return {row[0]: (row[1], row[2], row[3]) for row in rows}
+
+    def _execute_measuring_waits(self, func: Callable[[], Any]) -> "Cursor":
+        """Run one execution between snapshots of the session's wait statistics."""
         self._check_closed()
+        if self._results_pending:
+            # The snapshot needs the session, which unread rows keep busy without MARS
+            self._discard_pending_results()
+        baseline = self._session_wait_stats()
+        self._wait_baseline = None
+        self._wait_delta = None
+        self._measuring_waits = True
+        try:
+            func()
+        finally:
+            self._measuring_waits = False
+        self._wait_baseline = baseline
         return self
 
-    def __next__(self):
+    def wait_stats(self) -> Optional[Dict[str, Dict[str, int]]]:
         """
-        Fetch the next row when iterating over the cursor.
+        Return the waits the session incurred since the last execute() started.
+
+        Requires capture_wait_stats. The difference between the snapshot taken
+        before execute() and one taken on the first call is returned, so waits
+        while fetching (e.g. ASYNC_NETWORK_IO) are included; later calls return the
+        same result. Waits of the snapshot queries themselves may appear as well.
+
+        This is a DB-API extension.
 
         Returns:
-            The next Row object.
+            dict or None: Wait type mapped to the increase of its
+                "waiting_tasks_count", "wait_time_ms" and "signal_wait_time_ms",
+                longest wait first; only wait types that increased are included.
+                None if no statement was executed with capture_wait_stats or the
+                statistics could not be read.
 
         Raises:
-            StopIteration: When no more rows are available.
+            ProgrammingError: If this cursor still has unread rows and the
+                connection does not have MARS enabled.
         """
-        self._check_closed()
-        row = self.fetchone()
-        if row is None:
-            raise StopIteration
-        return row
-
-    def next(self):
+        if self._wait_delta is not None or self._wait_baseline is None:
+            return self._wait_delta
+        if self._results_pending and not getattr(self._connection, "_mars_enabled", True):
+            raise ProgrammingError(
+                driver_error="Read the remaining rows before calling wait_stats()",
+                ddbc_error="Multiple active result sets are not enabled on this connection",
+            )
+        current = self._session_wait_stats()
+        if current is None:
+            return None
+        delta = {}
+        for wait_type, counters in current.items():
+            before = self._wait_baseline.get(wait_type, (0, 0, 0))
+            increase = tuple(now - then for now, then in zip(counters, before))
+            if any(increase):
+                delta[wait_type] = dict(zip(_WAIT_STATS_COLUMNS, increase))
+        self._wait_delta = dict(
+            sorted(delta.items(), key=lambda item: item[1]["wait_time_ms"], reverse=True)
+        )
+        return self._wait_delta
+
+    def progress(self) -> List[Dict[str, Any]]:
+        """
+        Return the per-operator progress of the statement running on this cursor.
+
+        Meant to be called from another thread (e.g. a dashboard) while execute()
+        runs, or between fetches of a large result. The counters are read from
+        sys.dm_exec_query_profiles on a monitoring connection the connection opens
+        on first use and closes with itself; this needs VIEW SERVER STATE and
+        lightweight query profiling (on by default from SQL Server 2019). The
+        statement is found by its session, so read connection.session_id before
+        executing it. With MARS, statements of other cursors running at the same
+        time are included.
+
+        This is a DB-API extension.
+
+        Returns:
+            list: One dict per plan operator in node order, with "node_id",
+                "operator", "object_name", "row_count", "estimate_row_count",
+                "percent_complete" (row_count against the estimate, None without an
+                estimate), "elapsed_time_ms", "cpu_time_ms", "logical_read_count"
+                and "threads". Empty when no statement is running or profiling is
+                not available.
+
+        Raises:
+            ProgrammingError: If the session id was not read before the statement
+                started.
+        """
+        self._check_closed()
+        connection = self._connection
+        if self not in connection._executing_cursors and not self._results_pending:
+            return []
+        session_id = connection._session_id
+        if session_id is None:
+            raise ProgrammingError(
+                driver_error="The session running the statement is not known",
+                ddbc_error="Read connection.session_id before executing the statement",
+            )
+        from mssql_python.progress import operator_progress
+
+        with connection._monitor_lock:
+            return operator_progress(connection._monitoring_connection(), session_id)
+
+    def extended_description(self) -> List[Dict[str, Any]]:
+        """
+        Return the description of the current result set with the source of each
+        column, so that BI tools can map result columns back to source tables.
+
+        The sources are read from sys.dm_exec_describe_first_result_set in
+        browse information mode (one round trip), which follows views to their
+        base tables. Columns computed from expressions have no source. Only the
+        first result set of a statement can be described. While this
+        connection has unread results and MARS is off, the statement is
+        described on the connection's monitoring connection, which does not see
+        this session's temporary tables.
+
+        This is a DB-API extension.
+
+        Returns:
+            list: One dict per column with the description fields "name",
+                "type_code", "display_size", "internal_size", "precision",
+                "scale" and "null_ok", and "source_server" (for linked servers),
+                "source_database", "source_schema", "source_table",
+                "source_column", "is_computed" and "is_identity".
+
+        Raises:
+            ProgrammingError: If there is no result set, it is not the first one,
+                or the server cannot describe the statement.
+        """
+        self._check_closed()
+        if self.description is None:
+            raise ProgrammingError(
+                driver_error="No result set to describe",
+                ddbc_error="Execute a statement returning rows first",
+            )
+        if getattr(self, "_result_set_number", 0):
+            raise ProgrammingError(
+                driver_error="Only the first result set of a statement can be described",
+                ddbc_error="sys.dm_exec_describe_first_result_set describes the first one",
+            )
+        connection = self._connection
+        busy = not connection._mars_enabled and any(
+            getattr(cursor, "_results_pending", False) and not cursor.closed
+            for cursor in list(connection._cursors)
+        )
+        if busy:
+            with connection._monitor_lock:
+                sources = column_sources(
+                    connection._monitoring_connection(), self.last_executed_stmt
+                )
+        else:
+            sources = column_sources(connection, self.last_executed_stmt)
+        if len(sources) != len(self.description):
+            raise ProgrammingError(
+                driver_error="The described columns do not match the result set",
+                ddbc_error=f"{len(sources)} columns described, {len(self.description)} returned",
+            )
+        extended = []
+        for column, source in zip(self.description, sources):
+            entry = dict(zip(_DESCRIPTION_FIELDS, column))
+            entry.update((key, source[key]) for key in SOURCE_KEYS)
+            extended.append(entry)
+        return extended
+
+    @property
+    def timeout(self) -> int:
+        """The query timeout of this cursor's statements in seconds, 0 for none."""
+        return self._timeout
+
+    @timeout.setter
+    def timeout(self, value: Optional[float]) -> None:
+        self.settimeout(value)
+
+    def settimeout(self, seconds: Optional[float]) -> None:
+        """
+        Set the query timeout of this cursor's statements (SQL_ATTR_QUERY_TIMEOUT),
+        in place of the connection's timeout the cursor was created with.
+
+        A statement still running after the timeout is cancelled and raises
+        OperationalError (HYT00). The timeout has a resolution of one second, so
+        fractions are rounded up.
+
+        Args:
+            seconds: The timeout in seconds; 0 or None for no timeout.
+
+        Raises:
+            TypeError: If seconds is not a number.
+            ValueError: If seconds is negative.
+        """
+        if seconds is None:
+            seconds = 0
+        if not isinstance(seconds, (int, float)) or isinstance(seconds, bool):
+            raise TypeError("Timeout must be a number of seconds")
+        if seconds < 0:
+            raise ValueError("Timeout cannot be negative")
+        self._check_closed()
+        self._timeout = math.ceil(seconds)
+        if self.hstmt:
+            self._set_timeout(force=True)
+        logger.debug("settimeout: Query timeout set to %d seconds", self._timeout)
+
+    def cancel(self) -> None:
+        """
+        Cancel the statement this cursor is executing or fetching from.
+
+        Safe to call from any thread. The driver sends an attention to the server,
+        and the execute() or fetch call running the statement raises
+        OperationalError (HY008) once the server has stopped it. Does nothing
+        when the cursor is closed or no statement is running.
+        """
+        if self.closed:
+            return
+        connection = self._connection
+        # The lock is held until the attention is sent, so the handle cannot be
+        # freed or handed to another cursor in between (_detach_statement_handle)
+        with connection._state_lock:
+            running = self in connection._executing_cursors or self._results_pending
+            hstmt = self.hstmt
+            if not running or hstmt is None:
+                logger.debug("cancel: No statement running")
+                return
+            ret = ddbc_bindings.DDBCSQLCancel(hstmt)
+        # The statement finished and its handle was freed meanwhile
+        if ret == ddbc_sql_const.SQL_INVALID_HANDLE.value:
+            return
+        check_error(ddbc_sql_const.SQL_HANDLE_STMT.value, hstmt, ret)
+        logger.info("cancel: Cancellation sent for the running statement")
+
+    def _reconnect(self) -> None:
+        """Move this cursor to a new session of its connection."""
+        self._connection._reconnect()
+        self._reset_cursor()
+
+    def add_column_transform(
+        self, column: Union[str, int], transform: Union[str, Callable[[Any], Any]]
+    ) -> None:
+        """
+        Register a per-column transform applied to every fetched value of a column.
+
+        Transforms run while rows are built, in the same pass as output converters,
+        so common cleanup does not need a second loop over the fetched rows. They stay
+        registered for later result sets until clear_column_transforms() is called;
+        several transforms on the same column run in registration order. NULL values
+        are never passed to a transform.
+
+        Args:
+            column: Column name (as in cursor.description) or 0-based column index.
+                Result sets without a matching column are unaffected.
+            transform: One of "rstrip" (trailing blank padding of CHAR/NCHAR values),
+                "strip", "upper", "lower", or a callable taking the value and
+                returning the replacement. Named transforms leave non-str values
+                unchanged.
+
+        Raises:
+            ProgrammingError: If the column or transform is invalid.
+
+        Example:
+            >>> cursor.add_column_transform("code", "rstrip")
+            >>> cursor.add_column_transform(2, lambda v: v * 100)
+
+        Note:
+            This is a convenience extension beyond the DB-API 2.0 specification.
+            Exceptions raised by a callable propagate from the fetch call.
+        """
+        self._check_closed()
+        if isinstance(column, bool) or not isinstance(column, (str, int)):
+            raise ProgrammingError(
+                driver_error="Column must be a column name or index",
+                ddbc_error=f"Invalid column: {column!r}",
+            )
+        if isinstance(column, int) and column < 0:
+            raise ProgrammingError(
+                driver_error="Column index must be non-negative",
+                ddbc_error=f"Invalid column index: {column}",
+            )
+        if isinstance(transform, str):
+            if transform not in COLUMN_TRANSFORMS:
+                raise ProgrammingError(
+                    driver_error=f"Unknown column transform '{transform}'",
+                    ddbc_error="Valid transforms: " + ", ".join(sorted(COLUMN_TRANSFORMS)),
+                )
+            transform = COLUMN_TRANSFORMS[transform]
+        elif not callable(transform):
+            raise ProgrammingError(
+                driver_error="Column transform must be a name or a callable",
+                ddbc_error=f"Invalid transform: {transform!r}",
+            )
+
+        self._column_transforms.append((column, transform))
+        # Apply to the current result set as well
+        self._cached_transform_map = self._build_transform_map()
+
+    def clear_column_transforms(self) -> None:
+        """Remove all column transforms registered with add_column_transform()."""
+        self._column_transforms = []
+        self._cached_transform_map = self._build_transform_map()
+
+    def _build_transform_map(self):
+        """
+        Resolve registered column transforms against the current description.
+
+        Returns:
+            tuple of (column index, tuple of callables) pairs, or None when no
+            registered transform matches a column of the current result set.
+        """
+        rstrip_char = getattr(self.connection, "_rstrip_char", False)
+        narrow_char = self._narrow_char_collations and self._narrow_char_fetch()
+        if not self.description or not (self._column_transforms or rstrip_char or narrow_char):
+            return None
+
+        names = {desc[0]: i for i, desc in enumerate(self.description) if desc}
+        by_index: Dict[int, List[Callable[[Any], Any]]] = {}
+        if narrow_char:
+            # Values the SQL_CHAR encoding could not decode come back as bytes
+            fallback = getattr(self.connection, "_char_fallback_encoding", None)
+            for index, collation in self._narrow_char_collations:
+                by_index[index] = [narrow_char_decoder(collation, fallback)]
+        if rstrip_char:
+            # Connection-level padding removal runs before user transforms
+            for index in self._char_column_indices:
+                by_index.setdefault(index, []).append(COLUMN_TRANSFORMS["rstrip"])
+        for column, transform in self._column_transforms:
+            index = names.get(column) if isinstance(column, str) else column
+            if index is not None and index < len(self.description):
+                by_index.setdefault(index, []).append(transform)
+
+        if not by_index:
+            return None
+        return tuple((index, tuple(funcs)) for index, funcs in sorted(by_index.items()))
+
+    def _narrow_char_fetch(self) -> bool:
+        """True if CHAR/VARCHAR columns are fetched as SQL_C_CHAR bytes and decoded here."""
+        try:
+            decoding = self._get_decoding_settings(ddbc_sql_const.SQL_CHAR.value)
+        except Exception:  # pylint: disable=broad-exception-caught
+            return False
+        return decoding.get("ctype") == ddbc_sql_const.SQL_CHAR.value
+
+    def _fetchall_batch_rows(self) -> int:
+        """Rows per driver fetch for fetchall(); 0 lets the driver layer decide."""
+        if self._internal_fetch_rows:
+            return self._internal_fetch_rows
+        if isinstance(self.arraysize, int) and self.arraysize > 1:
+            return self.arraysize
+        return 0
+
+    def _reset_rownumber(self) -> None:
+        """Reset the rownumber tracking when starting a new result set."""
+        self._rownumber = -1
+        self._next_row_index = 0
+        self._has_result_set = True
+        self._results_pending = True
+        self._skip_increment_for_next_fetch = False
+        self._lob_reader = None
+        self._row_fetched_ahead = False
+
+    def _increment_rownumber(self):
+        """
+        Called after a successful fetch from the driver. Keep both counters consistent.
+        """
+        if self._has_result_set:
+            # driver returned one row, so the next row index increments by 1
+            self._next_row_index += 1
+            # rownumber is last returned row index
+            self._rownumber = self._next_row_index - 1
+        else:
+            raise InterfaceError(
+                "Cannot increment rownumber: no active result set.",
+                "No active result set.",
+            )
+
+    # Will be used when we add support for scrollable cursors
+    def _decrement_rownumber(self):
+        """
+        Decrement the rownumber by 1.
+
+        This could be used for error recovery or cursor positioning operations.
+        """
+        if self._has_result_set and self._rownumber >= 0:
+            if self._rownumber > 0:
+                self._rownumber -= 1
+            else:
+                self._rownumber = -1
+        else:
+            raise InterfaceError(
+                "Cannot decrement rownumber: no active result set.",
+                "No active result set.",
+            )
+
+    def _clear_rownumber(self):
+        """
+        Clear the rownumber tracking.
+
+        This should be called when the result set is cleared or when the cursor is reset.
+        """
+        self._rownumber = -1
+        self._has_result_set = False
+        self._results_pending = False
+        self._skip_increment_for_next_fetch = False
+        self._lob_reader = None
+        self._row_fetched_ahead = False
+
+    def __iter__(self):
+        """
+        Return the cursor itself as an iterator.
+
+        This allows direct iteration over the cursor after execute():
+
+        for row in cursor.execute("SELECT * FROM table"):
+            print(row)
+        """
+        self._check_closed()
+        return self
+
+    def __next__(self):
+        """
+        Fetch the next row when iterating over the cursor.
+
+        Returns:
+            The next Row object.
+
+        Raises:
+            StopIteration: When no more rows are available.
+        """
+        self._check_closed()
+        row = self.fetchone()
+        if row is None:
+            raise StopIteration
+        return row
+
+    def next(self):
         """
         Fetch the next row from the cursor.
 
@@ -1383,22 +2383,328 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
         """
         return next(self)
 
-    def execute(  # pylint: disable=too-many-locals,too-many-branches,too-many-statements
+    @staticmethod
+    def _unwrap_parameters(operation: str, parameters: tuple) -> Any:
+        """
+        Resolve execute()'s *parameters into the parameter list or dict to bind.
+
+        A single tuple/list/dict/Row argument is the parameter collection itself,
+        except for a lone ``IN ?`` placeholder, where a sequence is its value.
+        """
+        # Check if single parameter is a nested container that should be unwrapped
+        # e.g., execute("SELECT ?", (value,)) vs execute("SELECT ?, ?", ((1, 2),))
+        if isinstance(parameters, tuple) and len(parameters) == 1:
+            if (
+                isinstance(parameters[0], (tuple, list))
+                and not (len(parameters[0]) == 1 and is_in_list(parameters[0][0]))
+                and is_single_in_placeholder(operation)
+            ):
+                # execute("... WHERE id IN ?", [1, 2, 3]): the sequence is the
+                # value of the only placeholder, not the parameter list
+                return (parameters[0],)
+            if isinstance(parameters[0], (tuple, list, dict)):
+                return parameters[0]
+            if isinstance(parameters[0], Row):
+                # A Row (e.g. from fetchone()) is a sequence of column values.
+                # Normalize it to a tuple so the downstream binding logic, which
+                # only handles tuple/list/dict, can unwrap it into individual
+                # parameters instead of treating the whole Row as one value.
+                return tuple(parameters[0])
+        return parameters
+
+    def mogrify(self, operation: str, *parameters) -> str:
+        """
+        Return the statement with its parameters substituted as T-SQL literals.
+
+        Parameters are accepted exactly as by execute() (qmark or pyformat style,
+        ``IN ?`` sequences) and rendered as safely quoted literals: strings as
+        N'...' with quotes doubled, bytes as 0x..., dates, times and UUIDs as
+        CASTs of ISO strings. Nothing is sent to the server. Use this for logging
+        or to paste a statement into SSMS; always execute with real parameters.
+
+        Args:
+            operation: SQL query or command.
+            parameters: Parameters, as for execute().
+
+        Returns:
+            str: The SQL text with literals in place of the placeholders.
+
+        Raises:
+            ProgrammingError: If the parameter count does not match the placeholders
+                or a value has no literal form.
+
+        Example:
+            >>> cursor.mogrify("SELECT * FROM t WHERE name = ? AND id IN ?", "O'Neil", [1, 2])
+            "SELECT * FROM t WHERE name = N'O''Neil' AND id IN (1, 2)"
+
+        Note:
+            This is a convenience extension beyond the DB-API 2.0 specification.
+        """
+        self._check_closed()
+        if not parameters:
+            return operation
+        actual_params = self._unwrap_parameters(operation, parameters)
+        try:
+            sql, converted_params = detect_and_convert_parameters(operation, actual_params)
+            sql, params = expand_in_clauses(sql, list(converted_params or ()))
+            return substitute_literals(sql, params)
+        except (TypeError, ValueError, KeyError) as e:
+            raise ProgrammingError(
+                driver_error="Cannot render statement parameters as literals",
+                ddbc_error=str(e),
+            ) from e
+
+    def _execute_split_statement(
+        self, statements: List[Tuple[str, List[Any]]], use_prepare: bool
+    ) -> "Cursor":
+        """
+        Run the statements fit_parameter_limit() split a statement into, as one execute().
+
+        rowcount is the total of the statements and messages are those of all of
+        them. In autocommit mode each statement commits on its own.
+        """
+        logger.info(
+            "execute: Statement over the %d-parameter limit split into %d statements",
+            MAX_PARAMETERS,
+            len(statements),
+        )
+        rowcount = 0
+        messages = []
+        for sql, parameters in statements:
+            self.execute(sql, parameters, use_prepare=use_prepare)
+            rowcount += max(self.rowcount, 0)
+            messages.extend(self.messages)
+        self.rowcount = rowcount
+        self.messages = messages
+        return self
+
+    def _execute_capturing_plan(
         self,
         operation: str,
+        parameters: tuple,
+        capture_plan: str,
+        use_prepare: bool,
+        reset_cursor: bool,
+    ) -> "Cursor":
+        """Run execute() with SHOWPLAN_XML or STATISTICS XML enabled and keep the plan."""
+        if capture_plan == "estimated":
+            # SET SHOWPLAN_XML must be the only statement in its batch
+            self.execute("SET SHOWPLAN_XML ON", use_prepare=False)
+            plans = []
+            try:
+                # Prepared statements cannot be compiled under SHOWPLAN_XML
+                self._execute_once(operation, parameters, False, reset_cursor, None)
+                while True:
+                    if self._is_plan_result_set():
+                        plans.extend(row[0] for row in self.fetchall())
+                    if not self.nextset():
+                        break
+            finally:
+                self.execute("SET SHOWPLAN_XML OFF", use_prepare=False)
+            self.plan = plans[0] if plans else None
+            return self
+
+        batch = f"SET STATISTICS XML ON;\n{operation}\n;SET STATISTICS XML OFF;"
+        try:
+            self._execute_once(batch, parameters, use_prepare, reset_cursor, None)
+        except Exception:
+            # The batch may have failed before switching statistics off again
+            try:
+                self.execute("SET STATISTICS XML OFF", use_prepare=False)
+            except Exception:  # pylint: disable=broad-exception-caught
+                logger.debug("execute: Failed to reset STATISTICS XML after error")
+            raise
+        self._capture_actual_plan = True
+        if self.description is None:
+            # Statements without rows are followed directly by their plan
+            rowcount = self.rowcount
+            while self.nextset() and self.description is None:
+                pass
+            self.rowcount = rowcount
+        else:
+            self._skip_plan_result_sets()
+        return self
+
+    def _is_plan_result_set(self) -> bool:
+        """Return True if the current result set holds showplan XML."""
+        return bool(
+            self.description
+            and len(self.description) == 1
+            and self.description[0][0].lower() == _SHOWPLAN_COLUMN
+        )
+
+    def stats(self) -> Dict[str, Optional[str]]:
+        """
+        Return the identifiers of the last statement's plan, for joining with Query Store.
+
+        The hashes come from the plan captured with execute(..., capture_plan=...);
+        STATISTICS XML ("actual") reports them without a separate compile. For
+        row-returning statements under "actual", the plan is available once the
+        rows have been read and nextset() has been called. The plan handle is then
+        looked up in sys.dm_exec_query_stats, which needs VIEW SERVER STATE; it is
+        None if the plan is not cached (e.g. estimated plans, or evicted) or cannot
+        be read. For a batch, the first statement's identifiers are returned.
+
+        This is a DB-API extension.
+
+        Returns:
+            dict: "query_hash", "query_plan_hash" and "plan_handle" as "0x..."
+                strings (matching query_store_query.query_hash and
+                query_store_plan.query_plan_hash), each None when unavailable.
+        """
+        result: Dict[str, Optional[str]] = {
+            "query_hash": None,
+            "query_plan_hash": None,
+            "plan_handle": None,
+        }
+        if not self.plan:
+            return result
+        query_hash = _QUERY_HASH_RE.search(self.plan)
+        plan_hash = _QUERY_PLAN_HASH_RE.search(self.plan)
+        result["query_hash"] = query_hash.group(1) if query_hash else None
+        result["query_plan_hash"] = plan_hash.group(1) if plan_hash else None
+        if query_hash and plan_hash:
+            lookup = self._connection.cursor()
+            try:
+                rows = lookup.execute(
+                    _PLAN_HANDLE_QUERY, query_hash.group(1), plan_hash.group(1)
+                ).fetchall()
+                result["plan_handle"] = rows[0][0] if rows else None
+            except Exception as e:  # pylint: disable=broad-exception-caught
+                logger.debug("stats: Could not look up the plan handle: %s", e)
+            finally:
+                lookup.close()
+        return result
+
+    def _skip_plan_result_sets(self) -> bool:
+        """
+        Capture and step over STATISTICS XML result sets at the current position.
+
+        Returns:
+            True if a non-plan result set is current, False at the end of the results.
+        """
+        while self._is_plan_result_set():
+            rows = self.fetchall()
+            if self.plan is None and rows:
+                self.plan = rows[0][0]
+            if not self._next_result_set():
+                return False
+        return True
+
+    def execute(  # pylint: disable=too-many-locals,too-many-branches,too-many-statements
+        self,
+        operation: Union[str, bytes],
         *parameters,
         use_prepare: bool = True,
         reset_cursor: bool = True,
+        capture_plan: Optional[str] = None,
+        hints: Optional[Sequence[str]] = None,
+        idempotent: bool = False,
     ) -> "Cursor":
         """
         Prepare and execute a database operation (query or command).
 
         Args:
-            operation: SQL query or command.
-            parameters: Sequence of parameters to bind.
+            operation: SQL query or command. Without parameters, hints or capture_plan
+                it may be UTF-8 encoded bytes, e.g. a script read from a file, which
+                the driver converts to the UTF-16 the server expects without it
+                becoming a str first.
+            parameters: Sequence of parameters to bind. A list, tuple, set or range
+                bound to an ``IN ?`` placeholder is expanded into one parameter per
+                value (see parameter_helper.expand_in_clauses). A file-like object
+                (anything with read()) is read in chunks while the statement runs,
+                a text stream as NVARCHAR(MAX) and others as VARBINARY(MAX), so a
+                large value needs no bytes object; it is read once, from its
+                current position.
             use_prepare: Whether to use SQLPrepareW (default) or SQLExecDirectW.
             reset_cursor: Whether to reset the cursor before execution.
+            capture_plan: "estimated" or "actual" to store the execution plan XML on
+                cursor.plan. "estimated" compiles the statement under SHOWPLAN_XML
+                without running it (no result set is produced). "actual" runs it
+                under STATISTICS XML; the plan result sets are hidden from the
+                caller, and for row-returning statements cursor.plan is set once
+                the rows have been read and nextset() is called.
+            hints: Query hints appended as an OPTION (...) clause, e.g. ["RECOMPILE",
+                "MAXDOP 4", "USE HINT('DISABLE_OPTIMIZER_ROWGOAL')"]. Hints are
+                validated (see parameter_helper.apply_query_hints); an unsupported
+                hint raises ProgrammingError.
+            idempotent: Declares that running the statement twice is harmless. If the
+                connection is lost (SQLSTATE class 08) while it runs, the connection
+                reconnects and the statement is executed again on the new session,
+                with attempts and backoff following retry_policy (one replay without
+                a policy). Not done inside an open transaction, whose work would be
+                lost. Session state such as temporary tables and SET options does
+                not survive, and other cursors of the connection must be recreated.
         """
+        if capture_plan not in (None, "estimated", "actual"):
+            raise ProgrammingError(
+                driver_error="capture_plan must be 'estimated' or 'actual'",
+                ddbc_error=f"Invalid capture_plan: {capture_plan!r}",
+            )
+        if isinstance(operation, bytes) and (parameters or hints or capture_plan is not None):
+            raise ProgrammingError(
+                driver_error="SQL text given as bytes cannot have parameters, hints or a plan",
+                ddbc_error="Pass the statement as str to use parameters, hints or capture_plan",
+            )
+        # The policy sees the statement as written, before literals are parameterized
+        policy = self._connection._statement_policy
+        if policy is not None:
+            check_statement_policy(policy, operation)
+        if (
+            not parameters
+            and isinstance(operation, str)
+            and self._connection._auto_parameterize
+        ):
+            rewritten = parameterize_literals(operation)
+            if rewritten is not None:
+                operation, parameters = rewritten[0], tuple(rewritten[1])
+        if self._connection._read_only:
+            check_read_only(operation)
+        if hints:
+            try:
+                operation = apply_query_hints(operation, hints)
+            except ValueError as e:
+                raise ProgrammingError(driver_error="Invalid query hint", ddbc_error=str(e)) from e
+
+        # The checks above run once; retries, replays and wait measurement repeat only this
+        def attempt() -> "Cursor":
+            return self._execute_once(
+                operation, parameters, use_prepare, reset_cursor, capture_plan
+            )
+
+        def measured() -> "Cursor":
+            if self._capture_wait_stats and not self._measuring_waits:
+                return self._execute_measuring_waits(attempt)
+            return attempt()
+
+        def replayed() -> "Cursor":
+            if idempotent and not self._replaying:
+                return self._call_with_reconnect(measured)
+            return measured()
+
+        if self._retry_policy is not None and not self._retrying:
+            return self._call_with_retry(replayed)
+        return replayed()
+
+    def _execute_once(  # pylint: disable=too-many-locals,too-many-branches,too-many-statements
+        self,
+        operation: Union[str, bytes],
+        parameters: tuple,
+        use_prepare: bool,
+        reset_cursor: bool,
+        capture_plan: Optional[str],
+    ) -> "Cursor":
+        """Run one attempt of execute() for an operation that passed its checks."""
+        if not self._measuring_waits:
+            self._wait_baseline = None
+            self._wait_delta = None
+        if capture_plan is not None:
+            return self._execute_capturing_plan(
+                operation, parameters, capture_plan, use_prepare, reset_cursor
+            )
+        self.plan = None
+        self._capture_actual_plan = False
+
         logger.debug(
             "execute: Starting - operation_length=%d, param_count=%d, use_prepare=%s",
             len(operation),
@@ -1406,10 +2712,11 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
             str(use_prepare),
         )
 
-        # Log the actual query being executed
-        logger.debug("Executing query: %s", operation)
+        # Log the actual query being executed, as far as the redaction policy allows
+        logger.debug("Executing query: %s", logger.redaction.sql(operation))
 
         self._check_closed()  # Check if the cursor is closed
+        self._check_pending_results()
         if reset_cursor:
             if self.hstmt:
                 self._soft_reset_cursor()
@@ -1456,21 +2763,7 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
         # pass a tuple as a single parameter value (but SQL Server doesn't
         # support tuple types as parameter values anyway).
         if parameters:
-            # Check if single parameter is a nested container that should be unwrapped
-            # e.g., execute("SELECT ?", (value,)) vs execute("SELECT ?, ?", ((1, 2),))
-            if isinstance(parameters, tuple) and len(parameters) == 1:
-                if isinstance(parameters[0], (tuple, list, dict)):
-                    actual_params = parameters[0]
-                elif isinstance(parameters[0], Row):
-                    # A Row (e.g. from fetchone()) is a sequence of column values.
-                    # Normalize it to a tuple so the downstream binding logic, which
-                    # only handles tuple/list/dict, can unwrap it into individual
-                    # parameters instead of treating the whole Row as one value.
-                    actual_params = tuple(parameters[0])
-                else:
-                    actual_params = parameters
-            else:
-                actual_params = parameters
+            actual_params = self._unwrap_parameters(operation, parameters)
 
             # Skip detect_and_convert_parameters when re-executing the same SQL —
             # the parameter style (qmark vs pyformat) won't change between calls.
@@ -1481,8 +2774,22 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
                     operation, actual_params
                 )
                 parameters = list(converted_params)
+            # Expand "IN ?" placeholders bound to Python sequences
+            operation, parameters = expand_in_clauses(operation, parameters)
+            # Over the parameter limit of a request: rewrite or split the statement
+            if len(parameters) > MAX_PARAMETERS:
+                statements = fit_parameter_limit(operation, parameters)
+                if len(statements) > 1:
+                    return self._execute_split_statement(statements, use_prepare)
+                operation, parameters = statements[0]
+            if logger.is_debug_enabled:
+                parameters_text = logger.redaction.parameters_text(parameters)
+                if parameters_text is not None:
+                    logger.debug("execute: Parameters: %s", parameters_text)
         else:
             parameters = []
+        # Over the batch size limit the server drops the connection: fail first
+        check_statement_size(operation, self._connection._packet_size)
 
         # Getting encoding setting
         encoding_settings = self._get_encoding_settings()
@@ -1512,9 +2819,17 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
         # with parameters. The HSTMT is reused via _soft_reset_cursor, so the
         # server-side plan from the previous SQLPrepare is still valid.
         same_sql = parameters and operation == self.last_executed_stmt and self.is_stmt_prepared[0]
+        reused_handle = False
         if not same_sql:
-            self.is_stmt_prepared = [False]
-        effective_use_prepare = use_prepare and not same_sql
+            # With a connection statement cache, a handle another execution already
+            # prepared for this SQL is reused in the same way
+            reused_handle = self._swap_statement_handle(
+                operation, reuse=bool(parameters) and use_prepare
+            )
+            if not reused_handle:
+                self.is_stmt_prepared = [False]
+                self._prepared_sql = None
+        effective_use_prepare = use_prepare and not same_sql and not reused_handle
 
         if logger.isEnabledFor(logging.DEBUG):
             for i, param in enumerate(parameters):
@@ -1531,28 +2846,49 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
                     parameters_type[i].inputOutputType,
                 )
 
-        ret = ddbc_bindings.DDBCSQLExecute(
-            self.hstmt,
-            operation,
-            parameters,
-            parameters_type,
-            self.is_stmt_prepared,
-            effective_use_prepare,
-            encoding_settings,
-        )
-        # Check return code
+        execution_error = None
+        started = time.perf_counter()
+        self._connection._begin_execution(self)
         try:
+            while True:
+                ret = ddbc_bindings.DDBCSQLExecute(
+                    self.hstmt,
+                    operation,
+                    parameters,
+                    parameters_type,
+                    self.is_stmt_prepared,
+                    effective_use_prepare,
+                    encoding_settings,
+                )
+                # Check return code
+                try:
 
-            # Check for errors but don't raise exceptions for info/warning messages
-            check_error(ddbc_sql_const.SQL_HANDLE_STMT.value, self.hstmt, ret)
-        except Exception as e:  # pylint: disable=broad-exception-caught
-            logger.warning("Execute failed, resetting cursor: %s", e)
-            self._reset_cursor()
+                    # Check for errors but don't raise exceptions for info/warning messages
+                    check_error(ddbc_sql_const.SQL_HANDLE_STMT.value, self.hstmt, ret)
+                    break
+                except Exception as e:  # pylint: disable=broad-exception-caught
+                    logger.warning("Execute failed, resetting cursor: %s", e)
+                    self._collect_batch_errors(e)
+                    self._reset_cursor()
+                    if not self._invalidate_stale_statements(e) or not reused_handle:
+                        raise
+                    # The cached handle's prepared statement is stale: prepare it again
+                    logger.debug("execute: Cached statement handle stale, preparing again")
+                    reused_handle = False
+                    effective_use_prepare = use_prepare
+        except Exception as e:
+            execution_error = e
+            audit.record(self, "execute", operation, started, e)
             raise
+        finally:
+            self._connection._end_execution(execution_error, self)
 
+        if parameters and self.is_stmt_prepared[0]:
+            self._prepared_sql = operation
         self._capture_diagnostics(ret)
 
         self.last_executed_stmt = operation
+        self._result_set_number = 0
 
         # Update rowcount after execution
         # TODO: rowcount return code from SQL needs to be handled
@@ -1573,16 +2909,12 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
             self.rowcount = -1
             self._reset_rownumber()
             # Pre-build column map and converter map
-            self._cached_column_map = {
-                col_desc[0]: i for i, col_desc in enumerate(self.description)
-            }
-            self._cached_column_map_lower = (
-                {k.lower(): v for k, v in self._cached_column_map.items()}
-                if get_settings().lowercase
-                else None
+            self._cached_column_map, self._cached_column_map_lower = (
+                self._build_column_maps()
             )
             self._cached_converter_map = self._build_converter_map()
             self._uuid_str_indices = self._compute_uuid_str_indices()
+            self._cached_transform_map = self._build_transform_map()
         else:
             self.rowcount = ddbc_bindings.DDBCSQLRowCount(self.hstmt)
             self._clear_rownumber()
@@ -1590,8 +2922,10 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
             self._cached_column_map_lower = None
             self._cached_converter_map = None
             self._uuid_str_indices = None
+            self._cached_transform_map = None
 
         self._reset_inputsizes()  # Reset input sizes after execution
+        audit.record(self, "execute", operation, started)
         # Return self for method chaining
         return self
 
@@ -1668,6 +3002,9 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
             {k.lower(): v for k, v in column_map.items()} if get_settings().lowercase else None
         )
 
+        # Column transforms are meant for query results, not catalog rows
+        self._cached_transform_map = None
+
         # Initialize rownumber tracking so fetchone() and iteration work
         self._reset_rownumber()
 
@@ -2111,7 +3448,42 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
 
         return sample_value, None, None, max_decimal_formatted_len
 
-    def executemany(  # pylint: disable=too-many-locals,too-many-branches,too-many-statements
+    def execute_script(self, script: Union[str, bytes, Iterable[Union[str, bytes]]]) -> int:
+        """
+        Run a SQL script batch by batch, splitting it on GO separator lines.
+
+        For scripts too large to send as one statement, such as generated seed
+        scripts: each batch is executed on its own (see sql_script.iter_batches),
+        and script may be an open file, which is read one batch at a time. A
+        script in UTF-8 bytes, or a file opened in binary mode, is sent as bytes
+        and converted to UTF-16 once by the driver. The batches run in the
+        connection's current transaction; a failing batch raises and the batches
+        after it are not run.
+
+        Args:
+            script: The script text, as str or UTF-8 bytes, or an iterable of its lines.
+
+        Returns:
+            int: The number of batches executed. rowcount and messages cover all
+            batches, and the results of the last batch can be fetched.
+
+        Raises:
+            StatementTooLargeError: If one batch is larger than the server accepts.
+        """
+        self._check_closed()
+        batch_count = 0
+        rowcount = 0
+        messages = []
+        for batch in iter_batches(script):
+            self.execute(batch, use_prepare=False)
+            batch_count += 1
+            rowcount += max(self.rowcount, 0)
+            messages.extend(self.messages)
+        self.rowcount = rowcount
+        self.messages = messages
+        return batch_count
+
+    def executemany(
         self,
         operation: str,
         seq_of_parameters: Union[Sequence[Sequence[Any]], Sequence[Mapping[str, Any]]],
@@ -2125,6 +3497,23 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
         Raises:
             Error: If the operation fails.
         """
+        if self._connection._read_only:
+            check_read_only(operation)
+        policy = self._connection._statement_policy
+        if policy is not None:
+            check_statement_policy(policy, operation)
+        if self._retry_policy is not None and not self._retrying:
+            return self._call_with_retry(
+                lambda: self._executemany_once(operation, seq_of_parameters)
+            )
+        return self._executemany_once(operation, seq_of_parameters)
+
+    def _executemany_once(  # pylint: disable=too-many-locals,too-many-branches,too-many-statements
+        self,
+        operation: str,
+        seq_of_parameters: Union[Sequence[Sequence[Any]], Sequence[Mapping[str, Any]]],
+    ) -> None:
+        """Run one attempt of executemany() for an operation that passed its checks."""
         logger.debug(
             "executemany: Starting - operation_length=%d, batch_count=%d",
             len(operation),
@@ -2132,6 +3521,11 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
         )
 
         self._check_closed()
+        self._check_pending_results()
+        self._wait_baseline = None
+        self._wait_delta = None
+        # Keep a statement prepared by execute() for reuse instead of freeing it
+        self._park_statement_handle()
         self._reset_cursor()
         self.messages = []
         logger.debug("executemany: Cursor reset complete")
@@ -2195,6 +3589,9 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
             else next(iter(seq_of_parameters))
         )
         param_count = len(sample_row)
+        if param_count > MAX_PARAMETERS:
+            raise TooManyParametersError(param_count, MAX_PARAMETERS)
+        check_statement_size(operation, self._connection._packet_size)
         param_info = ddbc_bindings.ParamInfo
         parameters_type = []
         any_dae = False
@@ -2309,12 +3706,22 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
                 # SQLDescribeParam cache.  The previous SQL_VARCHAR hardcoded
                 # fallback was removed because it broke VARBINARY columns.
 
-                # Override DECIMAL/NUMERIC to use SQL_C_CHAR string binding.
+                # A column of Decimals is bound as SQL_NUMERIC_STRUCTs at one precision
+                # and scale that fits every row (the conversion loop below rescales
+                # each value to it)
+                numeric_shape = (
+                    _numeric_column_shape(column)
+                    if paraminfo.paramCType == ddbc_sql_const.SQL_C_NUMERIC.value
+                    else None
+                )
+                if numeric_shape is not None:
+                    paraminfo.columnSize, paraminfo.decimalDigits = numeric_shape
+                # Otherwise override DECIMAL/NUMERIC to use SQL_C_CHAR string binding.
                 # _map_sql_type may return SQL_C_NUMERIC (expecting NumericData structs)
-                # but the conversion loop below converts all Decimal values to strings.
+                # but the conversion loop below converts the values to strings.
                 # The C type must match the actual data to avoid:
                 #   RuntimeError: Parameter's object type does not match parameter's C type
-                if paraminfo.paramSQLType in (
+                elif paraminfo.paramSQLType in (
                     ddbc_sql_const.SQL_DECIMAL.value,
                     ddbc_sql_const.SQL_NUMERIC.value,
                 ):
@@ -2380,7 +3787,11 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
                 if time_text is not None:
                     processed_row[i] = time_text
                     continue
-                if (
+                if parameters_type[i].paramCType == ddbc_sql_const.SQL_C_NUMERIC.value:
+                    processed_row[i] = self._get_numeric_data(
+                        val, parameters_type[i].columnSize, parameters_type[i].decimalDigits
+                    )
+                elif (
                     isinstance(val, decimal.Decimal)
                     and parameters_type[i].paramSQLType == ddbc_sql_const.SQL_VARCHAR.value
                 ):
@@ -2417,18 +3828,27 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
             ),  # Limit to first 5 rows for large batches
         )
 
-        ret = ddbc_bindings.SQLExecuteMany(
-            self.hstmt, operation, columnwise_params, parameters_type, row_count, encoding_settings
-        )
+        execution_error = None
+        started = time.perf_counter()
+        self._connection._begin_execution(self)
+        try:
+            ret = ddbc_bindings.SQLExecuteMany(
+                self.hstmt,
+                operation,
+                columnwise_params,
+                parameters_type,
+                row_count,
+                encoding_settings,
+            )
 
-        # Capture any diagnostic messages after execution
-        if self.hstmt:
-            self.messages.extend(ddbc_bindings.DDBCSQLGetAllDiagRecords(self.hstmt))
+            # Capture any diagnostic messages after execution
+            if self.hstmt:
+                self.messages.extend(ddbc_bindings.DDBCSQLGetAllDiagRecords(self.hstmt))
 
-        try:
             check_error(ddbc_sql_const.SQL_HANDLE_STMT.value, self.hstmt, ret)
             self.rowcount = ddbc_bindings.DDBCSQLRowCount(self.hstmt)
             self.last_executed_stmt = operation
+            self._result_set_number = 0
 
             # Fetch column metadata (e.g. for INSERT … OUTPUT)
             column_metadata = []
@@ -2441,16 +3861,12 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
             if self.description:
                 self.rowcount = -1
                 self._reset_rownumber()
-                self._cached_column_map = {
-                    col_desc[0]: i for i, col_desc in enumerate(self.description)
-                }
-                self._cached_column_map_lower = (
-                    {k.lower(): v for k, v in self._cached_column_map.items()}
-                    if get_settings().lowercase
-                    else None
+                self._cached_column_map, self._cached_column_map_lower = (
+                    self._build_column_maps()
                 )
                 self._cached_converter_map = self._build_converter_map()
                 self._uuid_str_indices = self._compute_uuid_str_indices()
+                self._cached_transform_map = self._build_transform_map()
             else:
                 self.rowcount = ddbc_bindings.DDBCSQLRowCount(self.hstmt)
                 self._clear_rownumber()
@@ -2458,41 +3874,30 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
                 self._cached_column_map_lower = None
                 self._cached_converter_map = None
                 self._uuid_str_indices = None
+                self._cached_transform_map = None
+        except Exception as e:
+            execution_error = e
+            audit.record(self, "executemany", operation, started, e, row_count)
+            raise
         finally:
+            self._connection._end_execution(execution_error, self)
             # Reset input sizes after execution
             self._reset_inputsizes()
+        audit.record(self, "executemany", operation, started, parameter_sets=row_count)
 
     def fetchone(self) -> Union[None, Row]:
         """
         Fetch the next row of a query result set.
 
-        Returns:
-            Single Row object or None if no more data is available.
-        """
-        self._check_closed()  # Check if the cursor is closed
-
-        char_decoding = self._get_decoding_settings(ddbc_sql_const.SQL_CHAR.value)
-        wchar_decoding = self._get_decoding_settings(ddbc_sql_const.SQL_WCHAR.value)
-
-        # Fetch raw data
-        row_data = []
-        try:
-            ret = ddbc_bindings.DDBCSQLFetchOne(
-                self.hstmt,
-                row_data,
-                char_decoding.get("encoding", "utf-16le"),
-                wchar_decoding.get("encoding", "utf-16le"),
-                char_decoding.get("ctype", ddbc_sql_const.SQL_WCHAR.value),
-            )
-
-            if self.hstmt:
-                self.messages.extend(ddbc_bindings.DDBCSQLGetAllDiagRecords(self.hstmt))
-
+        Returns:
+            Single Row object or None if no more data is available.
+        """
+        self._check_closed()  # Check if the cursor is closed
+
+        # Fetch raw data
+        try:
+            ret, row_data = self._fetch_raw_row()
             if ret == ddbc_sql_const.SQL_NO_DATA.value:
-                # No more data available
-                if self._next_row_index == 0 and self.description is not None:
-                    # This is an empty result set, set rowcount to 0
-                    self.rowcount = 0
                 return None
 
             # Update internal position after successful fetch
@@ -2512,6 +3917,7 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
                 cursor=self,
                 converter_map=converter_map,
                 uuid_str_indices=self._uuid_str_indices,
+                transform_map=self._cached_transform_map,
                 column_map_lower=column_map_lower,
             )
         except Exception as e:
@@ -2541,21 +3947,31 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
         char_decoding = self._get_decoding_settings(ddbc_sql_const.SQL_CHAR.value)
         wchar_decoding = self._get_decoding_settings(ddbc_sql_const.SQL_WCHAR.value)
 
-        # Fetch raw data
-        rows_data = []
+        # Fetch raw data, at most internal_fetch_rows rows per driver call
+        rows_data = self._take_fetched_ahead_rows()
+        chunk = self._internal_fetch_rows or size
         try:
-            ret = ddbc_bindings.DDBCSQLFetchMany(
-                self.hstmt,
-                rows_data,
-                size,
-                char_decoding.get("encoding", "utf-16le"),
-                wchar_decoding.get("encoding", "utf-16le"),
-                char_decoding.get("ctype", ddbc_sql_const.SQL_WCHAR.value),
-            )
+            while len(rows_data) < size:
+                requested = min(chunk, size - len(rows_data))
+                fetched_before = len(rows_data)
+                ret = ddbc_bindings.DDBCSQLFetchMany(
+                    self.hstmt,
+                    rows_data,
+                    requested,
+                    char_decoding.get("encoding", "utf-16le"),
+                    wchar_decoding.get("encoding", "utf-16le"),
+                    char_decoding.get("ctype", ddbc_sql_const.SQL_WCHAR.value),
+                )
+                if len(rows_data) - fetched_before < requested:
+                    break
 
             if self.hstmt:
                 self.messages.extend(ddbc_bindings.DDBCSQLGetAllDiagRecords(self.hstmt))
 
+            # A short batch means the driver reached the end of the result set
+            if len(rows_data) < size:
+                self._results_pending = False
+
             # Update rownumber for the number of rows actually fetched
             if rows_data and self._has_result_set:
                 # advance counters by number of rows actually returned
@@ -2573,6 +3989,7 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
 
             # Convert raw data to Row objects
             uuid_idx = self._uuid_str_indices
+            transform_map = self._cached_transform_map
             return [
                 Row(
                     row_data,
@@ -2580,6 +3997,7 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
                     cursor=self,
                     converter_map=converter_map,
                     uuid_str_indices=uuid_idx,
+                    transform_map=transform_map,
                     column_map_lower=column_map_lower,
                 )
                 for row_data in rows_data
@@ -2603,7 +4021,7 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
         wchar_decoding = self._get_decoding_settings(ddbc_sql_const.SQL_WCHAR.value)
 
         # Fetch raw data
-        rows_data = []
+        rows_data = self._take_fetched_ahead_rows()
         try:
             ret = ddbc_bindings.DDBCSQLFetchAll(
                 self.hstmt,
@@ -2611,10 +4029,12 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
                 char_decoding.get("encoding", "utf-16le"),
                 wchar_decoding.get("encoding", "utf-16le"),
                 char_decoding.get("ctype", ddbc_sql_const.SQL_WCHAR.value),
+                self._fetchall_batch_rows(),
             )
 
             # Check for errors
             check_error(ddbc_sql_const.SQL_HANDLE_STMT.value, self.hstmt, ret)
+            self._results_pending = False
 
             if self.hstmt:
                 self.messages.extend(ddbc_bindings.DDBCSQLGetAllDiagRecords(self.hstmt))
@@ -2635,6 +4055,7 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
 
             # Convert raw data to Row objects
             uuid_idx = self._uuid_str_indices
+            transform_map = self._cached_transform_map
             return [
                 Row(
                     row_data,
@@ -2642,6 +4063,7 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
                     cursor=self,
                     converter_map=converter_map,
                     uuid_str_indices=uuid_idx,
+                    transform_map=transform_map,
                     column_map_lower=column_map_lower,
                 )
                 for row_data in rows_data
@@ -2650,6 +4072,78 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
             # On error, don't increment rownumber - rethrow the error
             raise e
 
+    def fetchbatches(
+        self, size: Optional[int] = None, prefetch: bool = True
+    ) -> Iterator[List[Row]]:
+        """
+        Iterate over the remaining rows of the result set in batches.
+
+        With prefetch=True (default) the fetch is double-buffered: while the caller
+        processes one batch, the next batch is fetched on a background thread. The
+        driver releases the GIL while waiting on the network, so network time and
+        Python-side processing overlap for large streaming reads.
+
+        The cursor must not be used for anything else until the iterator is
+        exhausted or closed; closing it early waits for an in-flight fetch.
+
+        Args:
+            size: Rows per batch. Defaults to arraysize, or 1000 when arraysize is 1.
+            prefetch: Fetch the next batch in the background while the current
+                one is consumed.
+
+        Yields:
+            Lists of Row objects, each with at most ``size`` rows.
+
+        Example:
+            >>> cursor.execute("SELECT * FROM big_table")
+            >>> for batch in cursor.fetchbatches(5000):
+            ...     process(batch)
+
+        Note:
+            This is a convenience extension beyond the DB-API 2.0 specification.
+        """
+        self._check_closed()
+        if size is None:
+            size = self.arraysize if self.arraysize > 1 else 1000
+        if not isinstance(size, int) or isinstance(size, bool) or size <= 0:
+            raise ValueError(f"size must be a positive integer, got {size!r}")
+        return self._iter_batches(size, prefetch)
+
+    def _iter_batches(self, size: int, prefetch: bool) -> Iterator[List[Row]]:
+        if not prefetch:
+            while True:
+                batch = self.fetchmany(size)
+                if not batch:
+                    return
+                yield batch
+
+        from concurrent.futures import ThreadPoolExecutor
+
+        executor = ThreadPoolExecutor(max_workers=1, thread_name_prefix="mssql-prefetch")
+        pending = None
+        try:
+            pending = executor.submit(self.fetchmany, size)
+            while True:
+                batch = pending.result()
+                pending = None
+                if not batch:
+                    return
+                if len(batch) == size:
+                    # Start the next round trip before handing this batch to the caller
+                    pending = executor.submit(self.fetchmany, size)
+                yield batch
+                if pending is None:
+                    return
+        finally:
+            if pending is not None:
+                # The consumer stopped early: let the in-flight fetch finish so the
+                # statement handle is no longer in use when control returns.
+                try:
+                    pending.result()
+                except Exception:  # pylint: disable=broad-exception-caught
+                    logger.debug("fetchbatches: In-flight fetch failed after early exit")
+            executor.shutdown(wait=True)
+
     def arrow_batch(self, batch_size: int = 8192) -> "pyarrow.RecordBatch":
         """
         Fetch a single pyarrow Record Batch of the specified size from the
@@ -2667,21 +4161,28 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
         if not self._has_result_set and self.description:
             self._reset_rownumber()
 
+        # The row _probe_end_of_results() moved to heads the batch
+        head = self._take_fetched_ahead_rows() if batch_size > 0 else []
+
         capsules = []
         char_decoding = self._get_decoding_settings(ddbc_sql_const.SQL_CHAR.value)
         char_c_type = char_decoding.get("ctype", ddbc_sql_const.SQL_WCHAR.value)
         ret = ddbc_bindings.DDBCSQLFetchArrowBatch(
-            self.hstmt, capsules, max(batch_size, 0), char_c_type
+            self.hstmt, capsules, max(batch_size - len(head), 0), char_c_type
         )
         check_error(ddbc_sql_const.SQL_HANDLE_STMT.value, self.hstmt, ret)
 
         batch = pyarrow.RecordBatch._import_from_c_capsule(*capsules)
+        if head:
+            batch = self._prepend_arrow_row(pyarrow, batch, head[0])
 
         if self.hstmt:
             self.messages.extend(ddbc_bindings.DDBCSQLGetAllDiagRecords(self.hstmt))
 
         # Update rownumber for the number of rows actually fetched
         num_fetched = batch.num_rows
+        if 0 < batch_size and num_fetched < batch_size:
+            self._results_pending = False
         if num_fetched > 0 and self._has_result_set:
             self._next_row_index += num_fetched
             self._rownumber = self._next_row_index - 1
@@ -2694,6 +4195,21 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
 
         return batch
 
+    @staticmethod
+    def _prepend_arrow_row(
+        pyarrow: Any, batch: "pyarrow.RecordBatch", row_data: list
+    ) -> "pyarrow.RecordBatch":
+        """Return batch with one raw fetched row converted to its schema in front."""
+        columns = []
+        for value, field in zip(row_data, batch.schema):
+            if isinstance(value, uuid.UUID):
+                # As the Arrow fetch formats uniqueidentifier columns
+                value = str(value).upper()
+            columns.append(pyarrow.array([value], type=field.type))
+        first = pyarrow.RecordBatch.from_arrays(columns, schema=batch.schema)
+        table = pyarrow.Table.from_batches([first, batch]).combine_chunks()
+        return table.to_batches()[0]
+
     def arrow(self, batch_size: int = 8192) -> "pyarrow.Table":
         """
         Fetch the entire result as a pyarrow Table.
@@ -2742,10 +4258,47 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
 
         return pyarrow.RecordBatchReader.from_batches(schema, batch_generator())
 
+    def fetch_arrow_batches(self, batch_size: int = 8192) -> Iterator["pyarrow.RecordBatch"]:
+        """
+        Return an iterator over the rest of the current result set as pyarrow
+        Record Batches.
+
+        Rows are written by the driver bindings straight into Arrow column buffers
+        and handed over through the Arrow C Data Interface, without creating a
+        Python object per value, which makes this the fastest way to feed pandas,
+        polars or any Arrow consumer. Every batch has batch_size rows except the
+        last; an empty result set yields nothing. batch_size is checked when this is
+        called, not when iteration starts.
+
+        Args:
+            batch_size: Number of rows per batch, at least 1.
+
+        Returns:
+            Iterator[pyarrow.RecordBatch]: The batches of rows.
+
+        Raises:
+            ValueError: If batch_size is not a positive integer.
+            ImportError: If pyarrow is not installed.
+
+        Examples:
+            for batch in cursor.execute(query).fetch_arrow_batches(50000):
+                frame = polars.from_arrow(batch)
+        """
+        if isinstance(batch_size, bool) or not isinstance(batch_size, int) or batch_size < 1:
+            raise ValueError("batch_size must be a positive integer")
+        return iter(self.arrow_reader(batch_size))
+
     def nextset(self) -> Optional[bool]:
         """
         Skip to the next available result set.
 
+        A batch or stored procedure returns one result per statement that
+        produces one: a result set for each SELECT, and a row count for each
+        INSERT, UPDATE or DELETE unless SET NOCOUNT is ON. After moving to a
+        result set, description describes its columns and rowcount is -1; after
+        moving to a row count, description is None and rowcount holds the count.
+        messages holds the messages (e.g. PRINT output) that came with the result.
+
         Returns:
             True if there is another result set, False otherwise.
             Note: PEP 249 specifies True/None; we return True/False
@@ -2759,6 +4312,15 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
         logger.debug("nextset: Moving to next result set")
         self._check_closed()  # Check if the cursor is closed
 
+        if not self._next_result_set():
+            return False
+        self._result_set_number += 1
+        if self._capture_actual_plan:
+            return self._skip_plan_result_sets()
+        return True
+
+    def _next_result_set(self) -> bool:
+        """Advance to the next result set; see nextset()."""
         # Clear messages per DBAPI
         self.messages = []
 
@@ -2767,6 +4329,7 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
         self._cached_column_map_lower = None
         self._cached_converter_map = None
         self._uuid_str_indices = None
+        self._cached_transform_map = None
 
         # Skip to the next result set
         ret = ddbc_bindings.DDBCSQLMoreResults(self.hstmt)
@@ -2784,8 +4347,6 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
             self.description = None
             return False
 
-        self._reset_rownumber()
-
         # Initialize description for the new result set
         column_metadata = []
         try:
@@ -2794,20 +4355,25 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
 
             # Pre-build column map and converter map for the new result set
             if self.description:
-                self._cached_column_map = {
-                    col_desc[0]: i for i, col_desc in enumerate(self.description)
-                }
-                self._cached_column_map_lower = (
-                    {k.lower(): v for k, v in self._cached_column_map.items()}
-                    if get_settings().lowercase
-                    else None
+                self._cached_column_map, self._cached_column_map_lower = (
+                    self._build_column_maps()
                 )
                 self._cached_converter_map = self._build_converter_map()
                 self._uuid_str_indices = self._compute_uuid_str_indices()
+                self._cached_transform_map = self._build_transform_map()
         except Exception as e:  # pylint: disable=broad-exception-caught
             # If describe fails, there might be no results in this result set
             self.description = None
 
+        # A batch interleaves result sets with the row counts of its INSERT,
+        # UPDATE and DELETE statements; a row count has no rows to fetch
+        if self.description:
+            self.rowcount = -1
+            self._reset_rownumber()
+        else:
+            self.rowcount = ddbc_bindings.DDBCSQLRowCount(self.hstmt)
+            self._clear_rownumber()
+
         logger.debug(
             "nextset: Moved to next result set - column_count=%d",
             len(self.description) if self.description else 0,
@@ -2828,6 +4394,15 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
         keep_nulls: bool = False,
         fire_triggers: bool = False,
         use_internal_transaction: bool = False,
+        source_columns: Optional[Union[Mapping[str, Any], Sequence[Any]]] = None,
+        add_missing_columns: bool = False,
+        load_id: Optional[Any] = None,
+        load_id_column: str = "load_id",
+        engine: str = "auto",
+        progress: Optional[Callable[[int], Any]] = None,
+        progress_every: int = 10000,
+        order: Optional[Sequence[Union[str, Tuple[str, str]]]] = None,
+        options: Optional["BulkCopyOptions"] = None,
     ):  # pragma: no cover
         """
         Perform bulk copy operation for high-performance data loading.
@@ -2839,6 +4414,8 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
             data: Iterable of tuples or Row objects containing row data to be inserted.
                 Row objects from fetchone/fetchmany/fetchall are automatically
                 converted to tuples. Lists and other types are not accepted.
+                Iterators and generators are consumed lazily as the rows are
+                sent, so a load never needs all its rows in memory at once.
 
                 Data Format Requirements:
                 - Each element in the iterable represents one row
@@ -2881,29 +4458,149 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
 
             use_internal_transaction: Use an internal transaction for each batch.
 
+            source_columns: Optional description of the source data columns, used to
+                detect schema drift before any rows are sent. Accepts a list of column
+                names, a list of (name, type) pairs, a mapping of name -> type, or a
+                cursor.description. Types may be Python types or SQL type names.
+                When given, the load is refused with SchemaDriftError if a source
+                column is missing from the target, a NOT NULL target column without
+                a default is not supplied, or a source type is incompatible.
+
+            add_missing_columns: When used with source_columns, add source columns
+                that are missing from the target as nullable columns instead of
+                failing the load.
+
+            load_id: Optional load identifier that makes the load safe to retry. Every
+                row is stamped with this value in load_id_column, and rows left by an
+                earlier attempt with the same load_id are deleted (and committed) before
+                the copy starts, so re-running an interrupted load never duplicates rows.
+                Refused while a transaction is open on the connection, whose work the
+                commit would include. The source rows must not include the load ID
+                column.
+
+            load_id_column: Target column that stores the load identifier. Default
+                is 'load_id'. Only used when load_id is given.
+
+            engine: How the rows are sent. "core" streams them with the TDS bulk
+                load protocol of the mssql_py_core library. "odbc" binds them as
+                parameter arrays through the ODBC driver on a separate session,
+                committing each batch; the rows are ordinary INSERTs, so constraints
+                are checked and triggers fire whatever check_constraints and
+                fire_triggers say, and NULLs stay NULL. "auto" (default) uses
+                "core" when mssql_py_core is installed and "odbc" otherwise.
+
+            progress: Optional callable receiving the number of rows read from
+                data so far, every progress_every rows and once when data is
+                exhausted. It runs on the calling thread, between rows.
+
+            progress_every: Rows between progress calls. Default is 10000.
+
+            order: Sort order of the source rows, as column names or (column,
+                "ASC" | "DESC") pairs, so the server can skip sorting them for a
+                target clustered on those columns. Only used by the "core" engine.
+
+            options: A BulkCopyOptions holding batch_size, timeout, the load option
+                flags and order, instead of passing them one by one. Passing both
+                options and any of those arguments raises ValueError.
+
         Returns:
             Dictionary with bulk copy results including:
                 - rows_copied: Number of rows successfully copied
                 - batch_count: Number of batches processed
                 - elapsed_time: Time taken for the operation
+                - rows_replaced: Rows removed from an earlier attempt (only with load_id)
 
         Raises:
-            ImportError: If mssql_py_core library is not installed
-            TypeError: If data is None, not iterable, or is a string/bytes
+            ImportError: If engine is "core" and mssql_py_core is not installed
+            TypeError: If data is None, not iterable, or is a string/bytes, or if
+                options is not a BulkCopyOptions
             ValueError: If table_name is empty or parameters are invalid
             RuntimeError: If connection string is not available
+            SchemaDriftError: If source_columns is given and does not match the target
+            NotSupportedError: If FIPS mode is on and mssql_py_core is not built for it
+            ProgrammingError: If the connection was opened with read_only=True, or
+                load_id is given while a transaction is open
         """
         # Fast check if logging is enabled to avoid overhead
         is_logging_enabled = logger.is_debug_enabled
 
-        try:
-            import mssql_py_core
-        except ImportError as exc:
-            logger.error("_bulkcopy: Failed to import mssql_py_core module")
-            raise ImportError(
-                "Bulk copy requires the mssql_py_core library which is not available. "
-                "This is an unexpected error. "
-            ) from exc
+        from mssql_python.bulk_load import (
+            BulkCopyOptions,
+            _DEFAULT_OPTIONS,
+            normalize_order_hints,
+        )
+
+        if options is not None:
+            if not isinstance(options, BulkCopyOptions):
+                raise TypeError(
+                    f"options must be a BulkCopyOptions, got {type(options).__name__}"
+                )
+            arguments = {
+                "batch_size": batch_size,
+                "timeout": timeout,
+                "keep_identity": keep_identity,
+                "check_constraints": check_constraints,
+                "table_lock": table_lock,
+                "keep_nulls": keep_nulls,
+                "fire_triggers": fire_triggers,
+                "use_internal_transaction": use_internal_transaction,
+                "order": normalize_order_hints(order),
+            }
+            conflicting = [
+                name for name, value in arguments.items() if value != _DEFAULT_OPTIONS[name]
+            ]
+            if conflicting:
+                raise ValueError(
+                    f"Pass {', '.join(conflicting)} in options or as arguments, not both"
+                )
+            batch_size = options.batch_size
+            timeout = options.timeout
+            keep_identity = options.keep_identity
+            check_constraints = options.check_constraints
+            table_lock = options.table_lock
+            keep_nulls = options.keep_nulls
+            fire_triggers = options.fire_triggers
+            use_internal_transaction = options.use_internal_transaction
+            order = options.order
+        else:
+            order = normalize_order_hints(order)
+
+        if engine not in ("auto", "core", "odbc"):
+            raise ValueError(f"engine must be 'auto', 'core' or 'odbc', got {engine!r}")
+        if self._connection._read_only:
+            raise ProgrammingError(
+                driver_error="Bulk copy is not allowed on a read-only connection",
+                ddbc_error="The connection was opened with read_only=True",
+            )
+        mssql_py_core = None
+        if engine != "odbc":
+            try:
+                import mssql_py_core
+            except ImportError as exc:
+                if engine == "core":
+                    logger.error("_bulkcopy: Failed to import mssql_py_core module")
+                    raise ImportError(
+                        "Bulk copy with engine='core' requires the mssql_py_core library, "
+                        "which is not available. Use engine='odbc' to load through the driver."
+                    ) from exc
+                logger.info("_bulkcopy: mssql_py_core is not available, using the ODBC engine")
+
+        # py-core opens its own TLS connection with its bundled crypto, which is
+        # only used in FIPS mode when py-core was built for it
+        from mssql_python.fips import fips_mode
+
+        if (
+            mssql_py_core is not None
+            and fips_mode()
+            and not getattr(mssql_py_core, "FIPS_ENABLED", False)
+        ):
+            if engine == "core":
+                raise NotSupportedError(
+                    driver_error="Bulk copy is not available in FIPS mode",
+                    ddbc_error="mssql_py_core was not built with FIPS-validated cryptography",
+                )
+            # The driver's own TLS stack honours FIPS mode
+            mssql_py_core = None
 
         # Validate inputs
         if not table_name or not isinstance(table_name, str):
@@ -2937,6 +4634,68 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
         if timeout <= 0:
             raise ValueError(f"timeout must be positive, got {timeout}")
 
+        if progress is not None:
+            if not callable(progress):
+                raise TypeError("progress must be callable")
+            if not isinstance(progress_every, int) or isinstance(progress_every, bool):
+                raise TypeError("progress_every must be a positive integer")
+            if progress_every <= 0:
+                raise ValueError(f"progress_every must be positive, got {progress_every}")
+
+        # Compare the source schema with the target before opening the bulk copy
+        # connection, so a drifted source fails fast with a structured report.
+        if source_columns is not None:
+            drift_report = self.check_schema_drift(
+                table_name, source_columns, add_missing_columns=add_missing_columns
+            )
+            drift_report.raise_if_incompatible()
+
+        # Stamp rows with the load ID and clear out any earlier attempt of this load
+        rows_replaced = None
+        if load_id is not None:
+            from mssql_python.bulk_load import prepare_idempotent_load
+
+            if not load_id_column or not isinstance(load_id_column, str):
+                raise ValueError("load_id_column must be a non-empty string")
+            load_cursor = self.connection.cursor()
+            try:
+                data, column_mappings, rows_replaced = prepare_idempotent_load(
+                    load_cursor, table_name, data, column_mappings, load_id, load_id_column
+                )
+            finally:
+                load_cursor.close()
+
+        if progress is not None:
+            from mssql_python.bulk_load import report_row_progress
+
+            data = report_row_progress(data, progress, progress_every)
+
+        if mssql_py_core is None:
+            from mssql_python.bulk_load import odbc_bulk_copy
+
+            self._check_closed()
+            if order and is_logging_enabled:
+                logger.debug("bulkcopy: ODBC engine ignores order hints %s", order)
+            result = odbc_bulk_copy(
+                self.connection,
+                table_name,
+                data,
+                batch_size=batch_size,
+                timeout=timeout,
+                column_mappings=column_mappings,
+                keep_identity=keep_identity,
+                table_lock=table_lock,
+            )
+            logger.info(
+                "_bulkcopy: Bulk copy through the driver completed - rows_copied=%s, "
+                "batch_count=%s",
+                result["rows_copied"],
+                result["batch_count"],
+            )
+            if rows_replaced is not None:
+                result["rows_replaced"] = rows_replaced
+            return result
+
         # Get and parse connection string
         if not hasattr(self.connection, "connection_str"):
             logger.error("_bulkcopy: Connection string not available")
@@ -2947,6 +4706,19 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
 
         parser = _ConnectionStringParser(validate_keywords=False)
         params = parser._parse(self.connection.connection_str)
+        # A password_file may have been rotated since the connection was opened
+        if getattr(self.connection, "_password_params", None) is not None:
+            params["pwd"] = self.connection._password_file.read()
+        # With a Server=a|b failover list, load into the server actually connected to
+        active_server = getattr(self.connection, "_active_server", None)
+        if active_server:
+            for key in ("addr", "address", "serverorder"):
+                params.pop(key, None)
+            params["server"] = active_server
+        # py-core verifies the pinned certificate as ServerCertificate too
+        from mssql_python.tls import pin_server_certificate
+
+        params = pin_server_certificate(params, params.get("server", ""))
 
         # Check for server parameter (accepts synonyms: server, addr, address)
         if not (params.get("server") or params.get("addr") or params.get("address")):
@@ -2969,6 +4741,12 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
             if connect_timeout > 0:
                 pycore_context["connect_timeout"] = int(connect_timeout)
 
+        token_file = getattr(self.connection, "_token_file", None)
+        if token_file is not None:
+            # token_file excludes UID, PWD and Authentication, see connect()
+            pycore_context["access_token"] = token_file.read()
+            logger.debug("Bulk copy: read the access token of token_file")
+
         # Token acquisition — only thing cursor must handle (needs azure-identity SDK)
         if self.connection._auth_type:
             # Fresh token acquisition for mssql-py-core connection
@@ -3077,6 +4855,7 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
                 fire_triggers=fire_triggers,
                 use_internal_transaction=use_internal_transaction,
                 python_logger=logger if is_logging_enabled else None,  # Only pass logger if enabled
+                **({"order_hints": order} if order else {}),
             )
 
             logger.info(
@@ -3086,6 +4865,9 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
                 result.get("elapsed_time", "N/A"),
             )
 
+            if rows_replaced is not None:
+                result["rows_replaced"] = rows_replaced
+
             return result
 
         except Exception as e:
@@ -3126,6 +4908,151 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
                             cleanup_error,
                         )
 
+    def insert_rows(
+        self,
+        table_name: str,
+        columns: Sequence[str],
+        rows: Iterable[Sequence[Any]],
+        max_rows: int = 1000,
+    ) -> int:
+        """
+        Insert rows with multi-row INSERT ... VALUES statements.
+
+        A fallback for bulkcopy() where bulk load permissions are not granted: rows
+        are sent in as few statements as the server's limits allow (1000 rows per
+        VALUES list, 2100 parameters per request), fewer rows per statement for
+        wide tables. The statements run in the connection's current transaction;
+        with autocommit on, a failure leaves the earlier statements' rows inserted.
+
+        Args:
+            table_name: Target table name (can include schema, e.g., 'dbo.Orders').
+            columns: Target column names, in the order the row values appear.
+            rows: Iterable of row tuples (or Row objects), read lazily.
+            max_rows: Upper bound on rows per statement, at most 1000.
+
+        Returns:
+            int: The number of rows inserted.
+
+        Example:
+            cursor.insert_rows("dbo.Orders", ["OrderID", "Customer"], orders)
+        """
+        from mssql_python.bulk_load import insert_rows
+
+        self._check_closed()
+        return insert_rows(self, table_name, columns, rows, max_rows)
+
+    def check_schema_drift(
+        self,
+        table_name: str,
+        source_columns: Union[Mapping[str, Any], Sequence[Any]],
+        add_missing_columns: bool = False,
+    ) -> "SchemaDriftReport":
+        """
+        Compare a bulk load source schema with the columns of the target table.
+
+        The metadata query runs on a separate cursor so this cursor's current
+        result set is left untouched.
+
+        Args:
+            table_name: Target table name (can include schema, e.g., 'dbo.MyTable').
+            source_columns: Column names, (name, type) pairs, a mapping of name -> type,
+                or a cursor.description describing the source data.
+            add_missing_columns: Add source columns missing from the target as
+                nullable columns (the target table is altered).
+
+        Returns:
+            SchemaDriftReport with missing, extra, unfillable and mismatched columns.
+
+        Raises:
+            ProgrammingError: If the target table does not exist.
+        """
+        from mssql_python.bulk_load import detect_schema_drift
+
+        self._check_closed()
+        if not table_name or not isinstance(table_name, str):
+            raise ValueError("table_name must be a non-empty string")
+
+        metadata_cursor = self.connection.cursor()
+        try:
+            return detect_schema_drift(
+                metadata_cursor, table_name, source_columns, add_missing_columns
+            )
+        finally:
+            metadata_cursor.close()
+
+    def write_dataframe(
+        self,
+        table_name: str,
+        df: "pandas.DataFrame",
+        chunk_size: int = 10000,
+        create_table: bool = False,
+        index: bool = False,
+        **bulkcopy_kwargs: Any,
+    ) -> Dict[str, Any]:
+        """
+        Bulk copy a pandas DataFrame into a table.
+
+        Column types are inferred from the dtypes: integers, floats and bools
+        (numpy or nullable, e.g. Int64 and boolean), datetime64 (DATETIMEOFFSET
+        when time zone aware), category (the type of its categories), and object
+        or string columns (the type of their values). The frame is converted a
+        chunk of rows at a time, each column in one call, rather than row by row;
+        NaN, NaT and NA are sent as NULL.
+
+        Args:
+            table_name: Target table (can include schema, e.g., 'dbo.MyTable').
+            df: The DataFrame. Its columns are mapped to table columns by name.
+            chunk_size: Rows converted at a time. Default is 10000.
+            create_table: Create the table, all columns nullable, if it does not
+                exist.
+            index: Also write the index, as a column named after it ("index"
+                when it has no name).
+            **bulkcopy_kwargs: Passed to bulkcopy(), e.g. batch_size or table_lock.
+                The inferred column types are passed as source_columns, so a
+                frame that does not fit the table raises SchemaDriftError before
+                any rows are sent.
+
+        Returns:
+            The result of bulkcopy().
+
+        Raises:
+            TypeError: If a column has a dtype or mixed values SQL Server cannot store.
+            ValueError: If chunk_size is not positive or column names are not unique.
+        """
+        from mssql_python.dataframe import (
+            create_table_statement,
+            dataframe_columns,
+            dataframe_rows,
+        )
+
+        self._check_closed()
+        if not table_name or not isinstance(table_name, str):
+            raise ValueError("table_name must be a non-empty string")
+        if not isinstance(chunk_size, int) or isinstance(chunk_size, bool) or chunk_size <= 0:
+            raise ValueError(f"chunk_size must be a positive integer, got {chunk_size!r}")
+        if index:
+            df = df.reset_index()
+
+        columns = dataframe_columns(df)
+        if create_table:
+            ddl_cursor = self.connection.cursor()
+            try:
+                ddl_cursor.execute("SELECT OBJECT_ID(?)", table_name)
+                if ddl_cursor.fetchval() is None:
+                    ddl_cursor.execute(create_table_statement(table_name, columns))
+                    if not self.connection.autocommit:
+                        self.connection.commit()
+            finally:
+                ddl_cursor.close()
+
+        bulkcopy_kwargs.setdefault("source_columns", columns)
+        return self.bulkcopy(
+            table_name,
+            dataframe_rows(df, chunk_size),
+            column_mappings=[name for name, _ in columns],
+            **bulkcopy_kwargs,
+        )
+
     def __enter__(self):
         """
         Enter the runtime context for the cursor.
@@ -3184,6 +5111,204 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
         logger.debug("fetchval: Value retrieved successfully")
         return row[0]
 
+    def fetch_lob_stream(
+        self, column_index: int, chunk_size: int = DEFAULT_LOB_CHUNK_SIZE
+    ) -> Optional[Union[LobBinaryReader, LobTextReader]]:
+        """
+        Fetch the next row and return one of its columns as a file-like object.
+
+        The value is read from the driver chunk by chunk as the stream is read,
+        so a VARBINARY(MAX), VARCHAR(MAX) or NVARCHAR(MAX) value never needs to
+        fit in memory. Binary columns give a binary stream, character columns a
+        text stream. Only this column of the row can be read, and only until the
+        cursor fetches another row or runs another statement. Streaming works
+        best with the LOB column last in the select list.
+
+        Args:
+            column_index: 0-based index of the column in description.
+            chunk_size: Bytes to request from the driver per read. Default 64 KiB.
+
+        Returns:
+            The stream, or None if there are no more rows. Its is_null attribute is
+            True for a NULL value, which reads as empty.
+
+        Raises:
+            ProgrammingError: If there is no result set, or the column is not a
+                character or binary column.
+
+        Example:
+            >>> cursor.execute("SELECT content FROM documents WHERE id = ?", doc_id)
+            >>> with cursor.fetch_lob_stream(0) as stream, open(path, "wb") as out:
+            ...     shutil.copyfileobj(stream, out)
+        """
+        self._check_closed()
+        if not self.description:
+            raise ProgrammingError(
+                driver_error="fetch_lob_stream() needs a result set",
+                ddbc_error="The last statement did not return rows",
+            )
+        if (
+            isinstance(column_index, bool)
+            or not isinstance(column_index, int)
+            or not 0 <= column_index < len(self.description)
+        ):
+            raise ProgrammingError(
+                driver_error=f"Invalid column index {column_index!r}",
+                ddbc_error=f"The result set has {len(self.description)} columns",
+            )
+        if isinstance(chunk_size, bool) or not isinstance(chunk_size, int) or chunk_size < 16:
+            raise ValueError("chunk_size must be an integer of at least 16 bytes")
+        type_code = self.description[column_index][1]
+        if type_code in (bytes, bytearray):
+            c_type = ddbc_sql_const.SQL_C_BINARY.value
+        elif type_code is str:
+            c_type = ddbc_sql_const.SQL_C_WCHAR.value
+        else:
+            type_name = getattr(type_code, "__name__", type_code)
+            raise ProgrammingError(
+                driver_error=f"Column {column_index} is not a character or binary column",
+                ddbc_error=f"Cannot stream values of type {type_name}",
+            )
+
+        if self._row_fetched_ahead:
+            # _probe_end_of_results() already moved to the row
+            self._row_fetched_ahead = False
+            ret = ddbc_sql_const.SQL_SUCCESS.value
+        else:
+            ret = ddbc_bindings.DDBCSQLFetch(self.hstmt)
+        if self.hstmt:
+            self.messages.extend(ddbc_bindings.DDBCSQLGetAllDiagRecords(self.hstmt))
+        if ret == ddbc_sql_const.SQL_NO_DATA.value:
+            self._results_pending = False
+            if self._next_row_index == 0:
+                self.rowcount = 0
+            return None
+        check_error(ddbc_sql_const.SQL_HANDLE_STMT.value, self.hstmt, ret)
+        if self._skip_increment_for_next_fetch:
+            self._skip_increment_for_next_fetch = False
+            self._next_row_index += 1
+        else:
+            self._increment_rownumber()
+        self.rowcount = self._next_row_index
+
+        hstmt, row = self.hstmt, self._rownumber
+
+        def read_chunk() -> Optional[bytes]:
+            return ddbc_bindings.DDBCSQLGetDataChunk(hstmt, column_index + 1, c_type, chunk_size)
+
+        def check() -> None:
+            current = self.hstmt is hstmt and self._rownumber == row
+            if self.closed or not current or self._lob_reader is not raw:
+                raise ProgrammingError(
+                    driver_error="The row of this LOB stream is no longer current",
+                    ddbc_error="Read the stream before the cursor fetches or executes again",
+                )
+
+        raw = LobReader(read_chunk, check, read_chunk())
+        self._lob_reader = raw
+        if c_type == ddbc_sql_const.SQL_C_BINARY.value:
+            return LobBinaryReader(raw, chunk_size)
+        return LobTextReader(raw, chunk_size)
+
+    def execute_dbcc(
+        self, command: str, *parameters: Any, tableresults: bool = True
+    ) -> List["DbccResult"]:
+        """
+        Run a DBCC command and return all of its output as rows.
+
+        DBCC commands report through a mix of result sets and informational
+        messages. This runs the command, reads every result set, and returns them
+        in order; messages that do not belong to a result set (e.g. DBCC CHECKDB
+        without TABLERESULTS, or "DBCC execution completed...") are returned as a
+        final result with a single "message" column. The cursor has no pending
+        results afterwards.
+
+        Args:
+            command: The DBCC statement, e.g. "DBCC SQLPERF(LOGSPACE)".
+            parameters: Parameters, as for execute().
+            tableresults: Add WITH TABLERESULTS to commands that support it
+                (CHECKDB, CHECKTABLE, CHECKALLOC, ...) so findings come back as
+                rows instead of message text.
+
+        Returns:
+            list of DbccResult, each with description, rows and messages.
+
+        Raises:
+            ProgrammingError: If the command is not a DBCC statement.
+
+        Example:
+            >>> for result in cursor.execute_dbcc("DBCC CHECKDB(N'Sales')"):
+            ...     for row in result.rows:
+            ...         print(row)
+
+        Note:
+            This is a convenience extension beyond the DB-API 2.0 specification.
+        """
+        from mssql_python.dbcc import execute_dbcc
+
+        self._check_closed()
+        return execute_dbcc(self, command, *parameters, tableresults=tableresults)
+
+    def checksum(
+        self,
+        algorithm: str = "sha256",
+        ordered: bool = True,
+        include_row_hashes: bool = False,
+        batch_size: int = 1000,
+    ) -> "ResultChecksum":
+        """
+        Consume the remaining rows of the result set and return their checksum.
+
+        Rows are fetched and hashed batch by batch, so the result set is never
+        held in memory. Values are hashed in a canonical, type-tagged form, which
+        makes the digest comparable across servers and column scales.
+
+        Args:
+            algorithm: Any hashlib algorithm name. Default is 'sha256'. In FIPS mode
+                only FIPS-approved hashes (SHA-2, SHA-3) are accepted.
+            ordered: If True (default), the digest depends on row order; if False,
+                the same rows in any order produce the same digest.
+            include_row_hashes: Also keep the hex digest of every row (memory grows
+                with the row count).
+            batch_size: Rows fetched per round trip.
+
+        Returns:
+            ResultChecksum with row_count, digest and optionally row_hashes.
+
+        Raises:
+            ProgrammingError: If there is no result set.
+            TypeError: If a value has no canonical encoding.
+            NotSupportedError: If FIPS mode is on and algorithm is not approved.
+
+        Example:
+            >>> src = src_cursor.execute('SELECT * FROM t ORDER BY id').checksum()
+            >>> dst = dst_cursor.execute('SELECT * FROM t ORDER BY id').checksum()
+            >>> src == dst
+            True
+
+        Note:
+            This is a convenience extension beyond the DB-API 2.0 specification.
+        """
+        from mssql_python.row_hash import ResultChecksum
+
+        self._check_closed()
+        if not self.description:
+            raise ProgrammingError(
+                driver_error="No result set to checksum",
+                ddbc_error="checksum() requires a statement that returns rows",
+            )
+        if not isinstance(batch_size, int) or isinstance(batch_size, bool) or batch_size <= 0:
+            raise ValueError(f"batch_size must be a positive integer, got {batch_size!r}")
+
+        result = ResultChecksum(algorithm, ordered=ordered, include_row_hashes=include_row_hashes)
+        while True:
+            rows = self.fetchmany(batch_size)
+            if not rows:
+                break
+            result.update(rows)
+        logger.debug("checksum: Hashed %d rows", result.row_count)
+        return result
+
     def commit(self):
         """
         Commit all SQL statements executed on the connection that created this cursor.
@@ -3328,8 +5453,11 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
                     return
 
                 # For forward-only cursors, use multiple SQL_FETCH_NEXT calls
-                # This matches pyodbc's approach for skip operations
-                for i in range(value):
+                # This matches pyodbc's approach for skip operations; a row moved
+                # to by _probe_end_of_results() is the first one skipped
+                skipped = 1 if self._row_fetched_ahead else 0
+                self._row_fetched_ahead = False
+                for i in range(value - skipped):
                     ret = ddbc_bindings.DDBCSQLFetchScroll(
                         self.hstmt, ddbc_sql_const.SQL_FETCH_NEXT.value, 0, row_data
                     )
@@ -3464,27 +5592,80 @@ class Cursor:  # pylint: disable=too-many-instance-attributes,too-many-public-me
             raise
 
     def callproc(
-        self, procname: str, parameters: Optional[Sequence[Any]] = None
-    ) -> Optional[Sequence[Any]]:
+        self,
+        procname: str,
+        parameters: Optional[Union[Sequence[Any], Mapping[str, Any]]] = None,
+    ) -> Optional[Union[Sequence[Any], Dict[str, Any]]]:
         """
         Call a stored database procedure with the given name.
 
+        Mark OUTPUT parameters with Output (passed in as NULL) or InOut (passed
+        in with a value). Their final values are known only once the procedure
+        has returned all of its results, so callproc() reads them all: the
+        result sets are kept in result_sets, as lists of rows, and the
+        procedure's return code in return_value. Row counts are skipped, and
+        the messages of the whole call are in messages.
+
         Args:
-            procname: Name of the stored procedure to call
-            parameters: Optional sequence of parameters to pass to the procedure
+            procname: Name of the stored procedure to call, of up to four parts.
+            parameters: Optional sequence of parameters in the order the procedure
+                declares them, or a mapping of parameter name to value.
 
         Returns:
-            A sequence containing the result parameters (input parameters unchanged,
-            output parameters with their new values)
+            A copy of parameters (a list, or a dict for a mapping) with input
+            parameters unchanged and output parameters replaced by their values.
 
         Raises:
-            NotSupportedError: This method is not yet implemented
+            ProgrammingError: If the type of an Output parameter is not given and
+                cannot be found in the procedure's metadata.
+
+        Example:
+            >>> from mssql_python import Output, InOut
+            >>> _, total, count = cursor.callproc("dbo.add", [5, Output("INT"), InOut(1)])
+            >>> cursor.return_value
+            0
         """
-        raise NotSupportedError(
-            driver_error="callproc() is not yet implemented",
-            ddbc_error="Stored procedure calls are not currently supported",
+        from mssql_python.procedure import (
+            call_batch,
+            call_results,
+            needs_parameter_types,
+            procedure_parameter_types,
         )
 
+        self._check_closed()
+        if not procname or not isinstance(procname, str):
+            raise ValueError("procname must be a non-empty string")
+        parameters = [] if parameters is None else parameters
+
+        parameter_types = None
+        if needs_parameter_types(parameters):
+            metadata_cursor = self.connection.cursor()
+            try:
+                parameter_types = procedure_parameter_types(metadata_cursor, procname)
+            finally:
+                metadata_cursor.close()
+        batch, values = call_batch(procname, parameters, parameter_types)
+
+        if values:
+            self.execute(batch, tuple(values), use_prepare=False)
+        else:
+            self.execute(batch, use_prepare=False)
+        result_sets: List[List[Row]] = []
+        messages = list(self.messages)
+        while True:
+            if self.description:
+                result_sets.append(self.fetchall())
+            more = self.nextset()
+            messages.extend(self.messages)
+            if not more:
+                break
+        # The last result set is the row of return code and OUTPUT values
+        final_row = result_sets.pop()[0]
+        self.result_sets = result_sets
+        self.messages = messages
+        self.return_value, result = call_results(parameters, final_row)
+        return result
+
     def setoutputsize(self, size: int, column: Optional[int] = None) -> None:
         """
         Set a column buffer size for fetches of large columns.
@@ -0,0 +1,140 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module feeds pandas DataFrames to Cursor.bulkcopy() (Cursor.write_dataframe):
+it infers the SQL type of each column from its dtype, and turns the frame into
+rows a chunk at a time, each column converted in one vectorized call, rather than
+cell by cell with iterrows() or itertuples().
+"""
+
+import datetime
+import decimal
+import re
+import uuid
+from typing import Any, Iterator, List, Optional, Sequence, Tuple
+
+from mssql_python.helpers import quote_identifier, quote_multipart_name
+
+# Integer dtypes (numpy and the nullable pandas ones, lowercased) -> SQL type
+_INTEGER_TYPES = {
+    "int8": "SMALLINT",
+    "uint8": "TINYINT",
+    "int16": "SMALLINT",
+    "uint16": "INT",
+    "int32": "INT",
+    "uint32": "BIGINT",
+    "int64": "BIGINT",
+    "uint64": "DECIMAL(20, 0)",
+}
+_FLOAT_TYPES = {"float16": "REAL", "float32": "REAL", "float64": "FLOAT"}
+_DATETIME_DTYPE = re.compile(r"^datetime64\[\w+(, .+)?\]$")
+# SQL types of the Python values found in object columns
+_OBJECT_TYPES = (
+    (bool, "BIT"),
+    (int, "BIGINT"),
+    (float, "FLOAT"),
+    (decimal.Decimal, "DECIMAL(38, 10)"),
+    (datetime.datetime, "DATETIME2(7)"),
+    (datetime.date, "DATE"),
+    (datetime.time, "TIME(7)"),
+    (uuid.UUID, "UNIQUEIDENTIFIER"),
+    ((bytes, bytearray), "VARBINARY(MAX)"),
+)
+# Longest string of an NVARCHAR(n) column; longer strings need NVARCHAR(MAX)
+_MAX_NVARCHAR_LENGTH = 4000
+DEFAULT_CHUNK_SIZE = 10000
+
+
+def sql_type_for_dtype(dtype: Any) -> Optional[str]:
+    """
+    Return the SQL type of a column with this pandas or numpy dtype, or None for
+    dtypes whose type depends on the values (object, string, category).
+
+    Raises:
+        TypeError: If the dtype has no SQL Server equivalent (e.g. timedelta64).
+    """
+    name = str(dtype)
+    lowered = name.lower()
+    if lowered in ("bool", "boolean"):
+        return "BIT"
+    if lowered in _INTEGER_TYPES:
+        return _INTEGER_TYPES[lowered]
+    if lowered in _FLOAT_TYPES:
+        return _FLOAT_TYPES[lowered]
+    match = _DATETIME_DTYPE.match(name)
+    if match:
+        return "DATETIMEOFFSET(7)" if match.group(1) else "DATETIME2(7)"
+    # pandas 3 names its default string dtype "str"
+    if lowered in ("object", "str", "category") or lowered.startswith("string"):
+        return None
+    raise TypeError(f"Columns of dtype {name} cannot be written to SQL Server")
+
+
+def _value_sql_type(values: List[Any]) -> str:
+    """Return the SQL type of the non-null Python values of an object column."""
+    if not values:
+        return "NVARCHAR(1)"
+    if all(isinstance(value, str) for value in values):
+        longest = max(len(value) for value in values)
+        if longest > _MAX_NVARCHAR_LENGTH:
+            return "NVARCHAR(MAX)"
+        return f"NVARCHAR({max(longest, 1)})"
+    first = values[0]
+    for python_type, sql_type in _OBJECT_TYPES:
+        if isinstance(first, python_type):
+            if all(isinstance(value, python_type) for value in values):
+                return sql_type
+            break
+    raise TypeError(
+        "Object columns must hold values of one type, got "
+        + ", ".join(sorted({type(value).__name__ for value in values}))
+    )
+
+
+def column_sql_type(series: Any) -> str:
+    """Return the SQL type of a DataFrame column."""
+    sql_type = sql_type_for_dtype(series.dtype)
+    if sql_type is not None:
+        return sql_type
+    if str(series.dtype) == "category":
+        categories = series.cat.categories
+        sql_type = sql_type_for_dtype(categories.dtype)
+        return sql_type or _value_sql_type(list(categories))
+    return _value_sql_type(series.dropna().tolist())
+
+
+def dataframe_columns(df: Any) -> List[Tuple[str, str]]:
+    """Return (name, SQL type) of each column of df."""
+    names = [str(name) for name in df.columns]
+    if len(set(name.casefold() for name in names)) != len(names):
+        raise ValueError("DataFrame column names must be unique (case-insensitively)")
+    return [(name, column_sql_type(df[column])) for name, column in zip(names, df.columns)]
+
+
+def create_table_statement(table_name: str, columns: Sequence[Tuple[str, str]]) -> str:
+    """Return a CREATE TABLE statement for columns, all nullable."""
+    definitions = ", ".join(
+        f"{quote_identifier(name)} {sql_type} NULL" for name, sql_type in columns
+    )
+    return f"CREATE TABLE {quote_multipart_name(table_name)} ({definitions})"
+
+
+def _column_values(series: Any) -> List[Any]:
+    """Return a column as Python values, NaN, NaT and NA as None."""
+    if str(series.dtype) == "category":
+        # Convert each category once and look the values up by code (-1 for missing)
+        categories = _column_values(series.cat.categories.to_series())
+        return [categories[code] if code >= 0 else None for code in series.cat.codes.tolist()]
+    # Numbers become int, float and bool, datetimes Timestamp (a datetime subclass)
+    values = series.astype(object).tolist()
+    missing = series.isna()
+    if not missing.any():
+        return values
+    return [None if is_missing else value for value, is_missing in zip(values, missing.tolist())]
+
+
+def dataframe_rows(df: Any, chunk_size: int = DEFAULT_CHUNK_SIZE) -> Iterator[Tuple[Any, ...]]:
+    """Yield the rows of df as tuples, converting chunk_size rows at a time."""
+    for start in range(0, len(df), chunk_size):
+        chunk = df.iloc[start : start + chunk_size]
+        yield from zip(*(_column_values(chunk[column]) for column in chunk.columns))
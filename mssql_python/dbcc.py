@@ -0,0 +1,143 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module runs DBCC commands and collects their output, whether it arrives as
+result sets (WITH TABLERESULTS, DBCC SQLPERF, ...) or as informational messages,
+as regular rows that health checks can inspect without parsing driver messages.
+"""
+
+import re
+from typing import TYPE_CHECKING, Any, Dict, List, Optional, Tuple
+
+from mssql_python.exceptions import ProgrammingError
+from mssql_python.row import Row
+
+if TYPE_CHECKING:
+    from mssql_python.cursor import Cursor
+
+_DBCC_RE = re.compile(r"^\s*DBCC\s+([A-Za-z_]+)", re.IGNORECASE)
+
+# DBCC commands that report through messages unless WITH TABLERESULTS is given
+_TABLERESULTS_COMMANDS = {
+    "CHECKALLOC",
+    "CHECKCATALOG",
+    "CHECKCONSTRAINTS",
+    "CHECKDB",
+    "CHECKFILEGROUP",
+    "CHECKTABLE",
+    "OPENTRAN",
+    "SHOWCONTIG",
+}
+
+# Every driver message is prefixed with the components that produced it
+_MESSAGE_PREFIX_RE = re.compile(r"^(\[[^\]]*\])+")
+
+MESSAGE_DESCRIPTION = [("message", str, None, None, None, None, True)]
+
+
+class DbccResult:
+    """
+    One result of a DBCC command.
+
+    Attributes:
+        description: Column descriptions, as cursor.description. Message output
+            has a single "message" column.
+        rows: The rows, as Row objects.
+        messages: Informational messages returned with this result set, as
+            (sqlstate, message) tuples.
+        is_messages: True if the rows were built from informational messages.
+    """
+
+    def __init__(
+        self,
+        description: List[Tuple[Any, ...]],
+        rows: List[Row],
+        messages: Optional[List[Tuple[str, str]]] = None,
+        is_messages: bool = False,
+    ) -> None:
+        self.description = description
+        self.rows = rows
+        self.messages = messages or []
+        self.is_messages = is_messages
+
+    @property
+    def columns(self) -> List[str]:
+        """The column names."""
+        return [desc[0] for desc in self.description]
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Return the result as a plain dictionary, with rows as column dicts."""
+        names = self.columns
+        return {
+            "columns": names,
+            "rows": [dict(zip(names, row)) for row in self.rows],
+            "messages": [message for _, message in self.messages],
+        }
+
+    def __len__(self) -> int:
+        return len(self.rows)
+
+    def __repr__(self) -> str:
+        return f"DbccResult(columns={self.columns!r}, rows={len(self.rows)})"
+
+
+def message_text(message: str) -> str:
+    """Strip the "[Microsoft][ODBC Driver ..][SQL Server]" prefix from a driver message."""
+    return _MESSAGE_PREFIX_RE.sub("", message).strip()
+
+
+def with_tableresults(command: str) -> str:
+    """
+    Add TABLERESULTS to DBCC commands that otherwise report only through messages.
+
+    Commands that already specify it, or do not support it, are returned unchanged.
+    """
+    match = _DBCC_RE.match(command)
+    if not match or match.group(1).upper() not in _TABLERESULTS_COMMANDS:
+        return command
+    if re.search(r"\bTABLERESULTS\b", command, re.IGNORECASE):
+        return command
+    statement = command.rstrip().rstrip(";")
+    if re.search(r"\bWITH\b", statement[match.end() :], re.IGNORECASE):
+        return statement + ", TABLERESULTS"
+    return statement + " WITH TABLERESULTS"
+
+
+def _messages_result(messages: List[Tuple[str, str]]) -> DbccResult:
+    column_map = {"message": 0}
+    rows = [Row([message_text(text)], column_map) for _, text in messages]
+    return DbccResult(MESSAGE_DESCRIPTION, rows, messages, is_messages=True)
+
+
+def execute_dbcc(
+    cursor: "Cursor", command: str, *parameters: Any, tableresults: bool = True
+) -> List[DbccResult]:
+    """
+    Run a DBCC command and return all of its output.
+
+    See Cursor.execute_dbcc.
+    """
+    if not _DBCC_RE.match(command):
+        raise ProgrammingError(
+            driver_error="execute_dbcc() expects a DBCC command",
+            ddbc_error=f"Not a DBCC command: {command[:50]!r}",
+        )
+    if tableresults:
+        command = with_tableresults(command)
+
+    results: List[DbccResult] = []
+    loose_messages: List[Tuple[str, str]] = []
+    cursor.execute(command, *parameters, use_prepare=False)
+    while True:
+        messages = list(cursor.messages)
+        if cursor.description:
+            results.append(DbccResult(list(cursor.description), cursor.fetchall(), messages))
+        else:
+            loose_messages.extend(messages)
+        if not cursor.nextset():
+            loose_messages.extend(cursor.messages)
+            break
+
+    if loose_messages:
+        results.append(_messages_result(loose_messages))
+    return results
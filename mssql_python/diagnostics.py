@@ -0,0 +1,140 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module provides server-side diagnostics the driver captures for failed
+statements, such as the blocking chain at the time a statement timed out or the
+graph of a deadlock the statement was chosen as victim of.
+"""
+
+import time
+from typing import TYPE_CHECKING, Any, Dict, List, Optional
+
+from mssql_python.logging import logger
+
+if TYPE_CHECKING:
+    from mssql_python.connection import Connection
+
+# Seconds a diagnostic query may run before it is abandoned
+DIAGNOSTIC_QUERY_TIMEOUT = 5
+
+# The server reports deadlocks to system_health asynchronously: seconds to wait for
+# the victim's graph to reach the ring buffer, and seconds between looks
+DEADLOCK_GRAPH_WAIT = 5.0
+_DEADLOCK_GRAPH_POLL_INTERVAL = 0.25
+
+# Every session that is blocked or blocks another one, with what it waits on and
+# its most recent statement. Head blockers are often idle sessions holding an open
+# transaction, which have no row in sys.dm_exec_requests.
+_BLOCKING_CHAIN_QUERY = """
+WITH blocked AS (
+    SELECT session_id, blocking_session_id, wait_type, wait_time, wait_resource
+    FROM sys.dm_exec_requests WHERE blocking_session_id <> 0
+)
+SELECT s.session_id, b.blocking_session_id, b.wait_type, b.wait_time, b.wait_resource,
+    s.status, s.login_name, s.host_name, s.program_name, s.open_transaction_count, t.text
+FROM sys.dm_exec_sessions AS s
+LEFT JOIN blocked AS b ON b.session_id = s.session_id
+LEFT JOIN sys.dm_exec_connections AS c ON c.session_id = s.session_id
+OUTER APPLY sys.dm_exec_sql_text(c.most_recent_sql_handle) AS t
+WHERE s.session_id IN (SELECT session_id FROM blocked UNION SELECT blocking_session_id FROM blocked)
+ORDER BY s.session_id
+"""
+_BLOCKING_CHAIN_COLUMNS = (
+    "session_id",
+    "blocking_session_id",
+    "wait_type",
+    "wait_time_ms",
+    "wait_resource",
+    "status",
+    "login_name",
+    "host_name",
+    "program_name",
+    "open_transaction_count",
+    "sql_text",
+)
+
+
+# The latest recent deadlock graph of system_health whose victim is session @spid
+_DEADLOCK_GRAPH_QUERY = """
+DECLARE @spid int = ?;
+SELECT TOP 1 CAST(x.ev.query('(data[@name="xml_report"]/value/deadlock)[1]') AS nvarchar(max))
+FROM (
+    SELECT CAST(t.target_data AS xml) AS target_data
+    FROM sys.dm_xe_session_targets AS t
+    JOIN sys.dm_xe_sessions AS s ON s.address = t.event_session_address
+    WHERE s.name = 'system_health' AND t.target_name = 'ring_buffer'
+) AS rb
+CROSS APPLY rb.target_data.nodes('RingBufferTarget/event[@name="xml_deadlock_report"]') AS x(ev)
+WHERE x.ev.value('@timestamp', 'datetime2') >= DATEADD(minute, -5, SYSUTCDATETIME())
+AND x.ev.exist('data[@name="xml_report"]/value/deadlock[victim-list/victimProcess/@id
+    = process-list/process[@spid = sql:variable("@spid")]/@id]') = 1
+ORDER BY x.ev.value('@timestamp', 'datetime2') DESC
+"""
+
+
+def capture_blocking_chain(connection: "Connection") -> Optional[List[Dict[str, Any]]]:
+    """
+    Read the server's current blocking chain on a short-lived cursor of connection.
+
+    Returns:
+        list or None: One dict per session in the chain, ordered by session id,
+            with the keys of _BLOCKING_CHAIN_COLUMNS plus "head_blocker" (True for
+            sessions that block others without being blocked themselves). Only
+            the caller's own session is visible without VIEW SERVER STATE. None
+            if the chain could not be read.
+    """
+    cursor = connection.cursor()
+    try:
+        cursor._timeout = DIAGNOSTIC_QUERY_TIMEOUT
+        cursor._set_timeout()
+        rows = cursor.execute(_BLOCKING_CHAIN_QUERY, use_prepare=False).fetchall()
+    except Exception as e:  # pylint: disable=broad-exception-caught
+        logger.debug("Could not read the blocking chain: %s", e)
+        return None
+    finally:
+        cursor.close()
+    chain = [dict(zip(_BLOCKING_CHAIN_COLUMNS, tuple(row))) for row in rows]
+    blocking = {entry["blocking_session_id"] for entry in chain}
+    for entry in chain:
+        entry["head_blocker"] = (
+            entry["session_id"] in blocking and entry["blocking_session_id"] is None
+        )
+    return chain
+
+
+def capture_deadlock_graph(
+    connection: "Connection", wait: float = DEADLOCK_GRAPH_WAIT
+) -> Optional[str]:
+    """
+    Read the graph of the deadlock the connection's session was just the victim of.
+
+    The graph is read from the ring buffer of the system_health session, waiting
+    up to wait seconds for it to arrive. This needs VIEW SERVER STATE; Azure SQL
+    Database does not report deadlocks to system_health.
+
+    Returns:
+        str or None: The <deadlock> XML, or None if it could not be read in time.
+    """
+    try:
+        session_id = connection.session_id
+    except Exception as e:  # pylint: disable=broad-exception-caught
+        logger.debug("Could not read the session id for the deadlock graph: %s", e)
+        return None
+    deadline = time.monotonic() + wait
+    cursor = connection.cursor()
+    try:
+        cursor._timeout = DIAGNOSTIC_QUERY_TIMEOUT
+        cursor._set_timeout()
+        while True:
+            rows = cursor.execute(_DEADLOCK_GRAPH_QUERY, session_id, use_prepare=False).fetchall()
+            if rows and rows[0][0]:
+                return rows[0][0]
+            if time.monotonic() >= deadline:
+                logger.debug("No deadlock graph for session %s in system_health", session_id)
+                return None
+            time.sleep(_DEADLOCK_GRAPH_POLL_INTERVAL)
+    except Exception as e:  # pylint: disable=broad-exception-caught
+        logger.debug("Could not read the deadlock graph: %s", e)
+        return None
+    finally:
+        cursor.close()
@@ -0,0 +1,219 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module finds SQL Server instances installed on the local Windows machine:
+LocalDB instances, whose (localdb)\\<instance> server names are resolved to the
+named pipe of the running instance (starting it if needed), and SQL Server
+Express and other instances registered with setup.
+"""
+
+import glob
+import os
+import platform
+import re
+import shutil
+import subprocess
+import threading
+from typing import Any, Dict, List, Optional
+
+from mssql_python.exceptions import InterfaceError, OperationalError
+from mssql_python.logging import logger
+
+# Server=(localdb)\MSSQLLocalDB, the default automatic instance
+_LOCALDB_RE = re.compile(r"^\(localdb\)\\(?P<instance>[^\\;]+)$", re.IGNORECASE)
+_PIPE_RE = re.compile(r"(np:\\\\\.\\pipe\\[^\s]+)")
+_INSTANCE_NAMES_KEY = r"SOFTWARE\Microsoft\Microsoft SQL Server\Instance Names\SQL"
+_SETUP_KEY = r"SOFTWARE\Microsoft\Microsoft SQL Server\{}\Setup"
+_DEFAULT_INSTANCE = "MSSQLSERVER"
+# Seconds SqlLocalDB.exe may take, including starting an instance
+_SQLLOCALDB_TIMEOUT = 60
+
+# Pipe names of running LocalDB instances, by lowercase instance name. A pipe name
+# changes when the instance restarts; forget_localdb_pipe() drops stale ones.
+_pipes: Dict[str, str] = {}
+_pipes_lock = threading.Lock()
+
+
+def _is_windows() -> bool:
+    return platform.system().lower() == "windows"
+
+
+def localdb_instance(server: str) -> Optional[str]:
+    """Return the instance name of a (localdb)\\<instance> server, else None."""
+    match = _LOCALDB_RE.match(server.strip()) if isinstance(server, str) else None
+    return match.group("instance") if match else None
+
+
+def _sqllocaldb() -> Optional[str]:
+    """Return the path of SqlLocalDB.exe, or None if LocalDB is not installed."""
+    found = shutil.which("SqlLocalDB")
+    if found:
+        return found
+    program_files = os.environ.get("ProgramFiles", r"C:\Program Files")
+    candidates = glob.glob(
+        os.path.join(program_files, "Microsoft SQL Server", "*", "Tools", "Binn", "SqlLocalDB.exe")
+    )
+    # The newest version, by the numeric version directory (e.g. 150, 160)
+    candidates.sort(key=lambda path: int(re.sub(r"\D", "", path.split(os.sep)[-4]) or 0))
+    return candidates[-1] if candidates else None
+
+
+def _run_sqllocaldb(*args: str) -> str:
+    tool = _sqllocaldb()
+    if tool is None:
+        raise InterfaceError(
+            driver_error="SQL Server Express LocalDB is not installed",
+            ddbc_error="SqlLocalDB.exe was not found",
+        )
+    result = subprocess.run(
+        [tool, *args],
+        capture_output=True,
+        text=True,
+        timeout=_SQLLOCALDB_TIMEOUT,
+        check=False,
+    )
+    if result.returncode != 0:
+        raise OperationalError(
+            driver_error=f"SqlLocalDB {' '.join(args)} failed",
+            ddbc_error=(result.stderr or result.stdout).strip(),
+        )
+    return result.stdout
+
+
+def parse_localdb_pipe(info_output: str) -> Optional[str]:
+    """Return the instance pipe name in SqlLocalDB info output (empty when stopped)."""
+    match = _PIPE_RE.search(info_output)
+    return match.group(1) if match else None
+
+
+def resolve_localdb(server: str) -> str:
+    """
+    Return the named pipe to connect to for a (localdb)\\<instance> server.
+
+    The instance is started, and automatic instances such as MSSQLLocalDB are
+    created, if needed. Other server names are returned unchanged.
+
+    Raises:
+        InterfaceError: If the server is a LocalDB instance but LocalDB is not
+            available (it only exists on Windows).
+        OperationalError: If the instance cannot be started.
+    """
+    instance = localdb_instance(server)
+    if instance is None:
+        return server
+    if not _is_windows():
+        raise InterfaceError(
+            driver_error=f"{server} is a LocalDB instance, which is only available on Windows",
+            ddbc_error="SQL Server Express LocalDB requires Windows",
+        )
+    key = instance.lower()
+    with _pipes_lock:
+        if key in _pipes:
+            return _pipes[key]
+        pipe = parse_localdb_pipe(_run_sqllocaldb("info", instance))
+        if pipe is None:
+            logger.info("discovery: Starting LocalDB instance %s", instance)
+            _run_sqllocaldb("start", instance)
+            pipe = parse_localdb_pipe(_run_sqllocaldb("info", instance))
+        if pipe is None:
+            raise OperationalError(
+                driver_error=f"LocalDB instance {instance!r} did not start",
+                ddbc_error="SqlLocalDB info reports no instance pipe name",
+            )
+        logger.debug("discovery: %s is %s", server, pipe)
+        _pipes[key] = pipe
+        return pipe
+
+
+def forget_localdb_pipe(server: str) -> None:
+    """Drop the remembered pipe of a LocalDB server, e.g. after it failed."""
+    instance = localdb_instance(server)
+    if instance is not None:
+        with _pipes_lock:
+            _pipes.pop(instance.lower(), None)
+
+
+def localdb_instances() -> List[str]:
+    """Return the names of the LocalDB instances of the current user ([] without LocalDB)."""
+    if not _is_windows() or _sqllocaldb() is None:
+        return []
+    return [line.strip() for line in _run_sqllocaldb("info").splitlines() if line.strip()]
+
+
+def _registry_instances() -> List[Dict[str, Any]]:
+    """Return the instances setup registered, from both registry views."""
+    import winreg  # pylint: disable=import-outside-toplevel,import-error
+
+    instances: Dict[str, Dict[str, Any]] = {}
+    for view in (winreg.KEY_WOW64_64KEY, winreg.KEY_WOW64_32KEY):
+        try:
+            names_key = winreg.OpenKey(
+                winreg.HKEY_LOCAL_MACHINE, _INSTANCE_NAMES_KEY, 0, winreg.KEY_READ | view
+            )
+        except OSError:
+            continue
+        with names_key:
+            index = 0
+            while True:
+                try:
+                    name, instance_id, _ = winreg.EnumValue(names_key, index)
+                except OSError:
+                    break
+                index += 1
+                edition = None
+                try:
+                    with winreg.OpenKey(
+                        winreg.HKEY_LOCAL_MACHINE,
+                        _SETUP_KEY.format(instance_id),
+                        0,
+                        winreg.KEY_READ | view,
+                    ) as setup_key:
+                        edition = winreg.QueryValueEx(setup_key, "Edition")[0]
+                except OSError:
+                    pass
+                instances.setdefault(name.upper(), _instance_entry(name, edition))
+    return sorted(instances.values(), key=lambda entry: entry["name"])
+
+
+def _instance_entry(name: str, edition: Optional[str]) -> Dict[str, Any]:
+    if name.upper() == _DEFAULT_INSTANCE:
+        kind, server = "default", "localhost"
+    else:
+        express = "express" in (edition or "").lower() or name.upper() == "SQLEXPRESS"
+        kind, server = ("express" if express else "named"), f".\\{name}"
+    return {"name": name, "server": server, "kind": kind, "edition": edition}
+
+
+def local_instances() -> List[Dict[str, Any]]:
+    """
+    Return the SQL Server instances installed on this machine.
+
+    Lists the LocalDB instances of the current user and the instances registered
+    by setup (SQL Server Express and full installations). Always [] on other
+    platforms than Windows.
+
+    Returns:
+        list: One dict per instance with "name", "server" (the value to use for
+            Server=, e.g. "(localdb)\\MSSQLLocalDB" or ".\\SQLEXPRESS"), "kind"
+            ("localdb", "express", "default" or "named") and "edition" (None for
+            LocalDB).
+
+    Example:
+        express = [i for i in mssql_python.local_instances() if i["kind"] != "named"]
+        conn = mssql_python.connect(f"Server={express[0]['server']};Trusted_Connection=yes")
+    """
+    if not _is_windows():
+        return []
+    instances = []
+    try:
+        instances.extend(
+            {"name": name, "server": f"(localdb)\\{name}", "kind": "localdb", "edition": None}
+            for name in localdb_instances()
+        )
+    except (OperationalError, OSError, subprocess.SubprocessError) as e:
+        logger.debug("discovery: Could not list the LocalDB instances: %s", e)
+    try:
+        instances.extend(_registry_instances())
+    except OSError as e:
+        logger.debug("discovery: Could not read the registered instances: %s", e)
+    return instances
@@ -0,0 +1,87 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module exposes the process-wide driver metrics kept by the bindings: counters
+and latency histograms updated with atomics on every connect, statement, fetch and
+pool acquire, so exporters read them instead of wrapping each call.
+"""
+
+import math
+from typing import Any, Dict, List
+
+from mssql_python import ddbc_bindings
+
+METRIC_PREFIX = "mssql_python_"
+
+_COUNTER_HELP = {
+    "connections_opened": "Connections opened to the server",
+    "connection_failures": "Connection attempts that failed",
+    "failed_logins": "Connection attempts rejected at login (SQLSTATE class 28)",
+    "statements_executed": "Statements executed",
+    "statement_errors": "Statements that failed",
+    "rows_fetched": "Rows fetched from result sets",
+    "bytes_sent": "Bytes of SQL text and string or binary parameters sent",
+    "bytes_received": "Bytes of string or binary column values received",
+    "pool_acquires": "Connections requested from the connection pool",
+    "pool_exhausted": "Pool acquires refused because the pool was full",
+}
+
+_HISTOGRAM_HELP = {
+    "connect_duration_seconds": "Time taken to open a connection",
+    "statement_duration_seconds": "Time taken to execute a statement, without fetching",
+    "pool_acquire_duration_seconds": "Time spent waiting for a pooled connection",
+}
+
+
+def metrics() -> Dict[str, Dict[str, Any]]:
+    """
+    Return a snapshot of the driver metrics of this process.
+
+    The counters only grow (they are not reset) and cover every connection and
+    cursor. Histogram buckets are cumulative, as in Prometheus: each is an
+    (upper_bound, count) pair, the last bound being float("inf").
+
+    Returns:
+        dict: {"counters": {name: int}, "histograms": {name: {"buckets": [...],
+        "sum": float, "count": int}}}; see metrics_text() for the names.
+
+    Examples:
+        snapshot = mssql_python.metrics()
+        snapshot["counters"]["statements_executed"]
+        snapshot["histograms"]["statement_duration_seconds"]["count"]
+    """
+    return ddbc_bindings.metrics()
+
+
+def _format_value(value: float) -> str:
+    if math.isinf(value):
+        return "+Inf"
+    if float(value).is_integer():
+        return str(int(value))
+    return repr(float(value))
+
+
+def metrics_text() -> str:
+    """
+    Return the driver metrics in the Prometheus text exposition format.
+
+    Counters are named mssql_python_<name>_total and histograms
+    mssql_python_<name> with _bucket, _sum and _count series; serve the result
+    with content type "text/plain; version=0.0.4".
+    """
+    snapshot = metrics()
+    lines: List[str] = []
+    for name, value in snapshot["counters"].items():
+        metric = f"{METRIC_PREFIX}{name}_total"
+        lines.append(f"# HELP {metric} {_COUNTER_HELP.get(name, name)}")
+        lines.append(f"# TYPE {metric} counter")
+        lines.append(f"{metric} {value}")
+    for name, histogram in snapshot["histograms"].items():
+        metric = f"{METRIC_PREFIX}{name}"
+        lines.append(f"# HELP {metric} {_HISTOGRAM_HELP.get(name, name)}")
+        lines.append(f"# TYPE {metric} histogram")
+        for bound, count in histogram["buckets"]:
+            lines.append(f'{metric}_bucket{{le="{_format_value(bound)}"}} {count}')
+        lines.append(f"{metric}_sum {_format_value(histogram['sum'])}")
+        lines.append(f"{metric}_count {histogram['count']}")
+    return "\n".join(lines) + "\n"
@@ -0,0 +1,118 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module supplies connection string defaults from environment variables, so a
+containerized deployment can configure its database endpoint and credentials
+(e.g. a mounted secret file) without templating connection strings:
+
+    MSSQL_HOST                       Server (MSSQL_PORT appended as ",port")
+    MSSQL_PORT                       Port of MSSQL_HOST
+    MSSQL_DATABASE                   Database
+    MSSQL_USER                       UID
+    MSSQL_PASSWORD                   PWD
+    MSSQL_PASSWORD_FILE              PWD, read from this file (trailing newline dropped)
+    MSSQL_AUTHENTICATION             Authentication
+    MSSQL_ENCRYPT                    Encrypt
+    MSSQL_TRUST_SERVER_CERTIFICATE   TrustServerCertificate
+    MSSQL_HOSTNAME_IN_CERTIFICATE    HostnameInCertificate
+    MSSQL_APPLICATION_INTENT         ApplicationIntent
+    MSSQL_PACKET_SIZE                PacketSize
+
+Keywords of the connection string and connect() arguments always win. The
+credentials (MSSQL_USER, MSSQL_PASSWORD*, MSSQL_AUTHENTICATION) apply together,
+and only to connection strings that set none of UID, PWD, Authentication and
+Trusted_Connection. Empty variables count as unset; true and false become yes
+and no.
+"""
+
+import os
+from typing import Dict, Mapping, Optional
+
+from mssql_python.logging import logger
+from mssql_python.secret_file import SecretFile
+
+HOST_VARIABLE = "MSSQL_HOST"
+PORT_VARIABLE = "MSSQL_PORT"
+PASSWORD_VARIABLE = "MSSQL_PASSWORD"
+PASSWORD_FILE_VARIABLE = "MSSQL_PASSWORD_FILE"
+
+# Variables that map to one connection string keyword each
+_SETTING_VARIABLES = {
+    "MSSQL_DATABASE": "Database",
+    "MSSQL_ENCRYPT": "Encrypt",
+    "MSSQL_TRUST_SERVER_CERTIFICATE": "TrustServerCertificate",
+    "MSSQL_HOSTNAME_IN_CERTIFICATE": "HostnameInCertificate",
+    "MSSQL_APPLICATION_INTENT": "ApplicationIntent",
+    "MSSQL_PACKET_SIZE": "PacketSize",
+}
+_CREDENTIAL_VARIABLES = {
+    "MSSQL_USER": "UID",
+    "MSSQL_AUTHENTICATION": "Authentication",
+}
+# Keywords any of which means the connection string brings its own credentials
+_CREDENTIAL_KEYWORDS = ("uid", "pwd", "authentication", "trusted_connection")
+_BOOLEANS = {"true": "yes", "false": "no"}
+
+
+def _variable(environ: Mapping[str, str], name: str) -> Optional[str]:
+    return environ.get(name, "").strip() or None
+
+
+def _password(environ: Mapping[str, str]) -> Optional[str]:
+    password = environ.get(PASSWORD_VARIABLE) or None
+    path = _variable(environ, PASSWORD_FILE_VARIABLE)
+    if path is None:
+        return password
+    if password is not None:
+        raise ValueError(f"Set {PASSWORD_VARIABLE} or {PASSWORD_FILE_VARIABLE}, not both")
+    return SecretFile(path, PASSWORD_FILE_VARIABLE).read()
+
+
+def environment_defaults(
+    params: Optional[Mapping[str, str]] = None, environ: Optional[Mapping[str, str]] = None
+) -> Dict[str, str]:
+    """
+    Return the connection string parameters the environment supplies that params
+    do not set.
+
+    Args:
+        params: Connection string parameters already set, keys in any case.
+        environ: Variables to read, os.environ by default.
+
+    Returns:
+        dict: Parameters by canonical keyword (Server, UID, ...).
+
+    Raises:
+        ValueError: If MSSQL_PORT is not a port number, both MSSQL_PASSWORD and
+            MSSQL_PASSWORD_FILE are set, or the password file cannot be read.
+    """
+    environ = os.environ if environ is None else environ
+    present = {key.lower() for key in params or {}}
+    defaults: Dict[str, str] = {}
+
+    host = _variable(environ, HOST_VARIABLE)
+    if host is not None and "server" not in present:
+        port = _variable(environ, PORT_VARIABLE)
+        if port is not None:
+            if not port.isdigit() or not 0 < int(port) < 65536:
+                raise ValueError(f"{PORT_VARIABLE} must be a port number, got {port!r}")
+            host = f"{host},{port}"
+        defaults["Server"] = host
+
+    for name, keyword in _SETTING_VARIABLES.items():
+        value = _variable(environ, name)
+        if value is not None and keyword.lower() not in present:
+            defaults[keyword] = _BOOLEANS.get(value.lower(), value)
+
+    if not present.intersection(_CREDENTIAL_KEYWORDS):
+        for name, keyword in _CREDENTIAL_VARIABLES.items():
+            value = _variable(environ, name)
+            if value is not None:
+                defaults[keyword] = value
+        password = _password(environ)
+        if password is not None:
+            defaults["PWD"] = password
+
+    if defaults:
+        logger.debug("environment_defaults: Using %s from the environment", ", ".join(defaults))
+    return defaults
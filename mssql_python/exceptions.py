@@ -5,7 +5,9 @@ This module contains custom exception classes for the mssql_python package.
 These classes are used to raise exceptions when an error occurs while executing a query.
 """
 
-from typing import Optional
+import re
+import threading
+from typing import Any, Dict, Iterable, List, Optional, Tuple, Type, Union
 from mssql_python.logging import logger
 import builtins
 
@@ -37,11 +39,25 @@ class ConnectionStringParseError(builtins.Exception):
 class Exception(builtins.Exception):
     """
     Base class for all DB API 2.0 exceptions.
+
+    Exceptions raised for driver or server errors carry every error the failing
+    call reported in ``errors``, as (sqlstate, native_error, message) tuples; the
+    first one is the error the exception describes.
     """
 
+    # SQL Server error number (e.g. 1205 for a deadlock) when raised for a server error
+    native_error: Optional[int] = None
+    # SQLSTATE of the diagnostic record the exception was raised for
+    sqlstate: Optional[str] = None
+    # Sessions blocking the statement, for timeouts with Connection.diagnose_blocking
+    blocking_chain: Optional[List[Dict[str, Any]]] = None
+    # <deadlock> XML for deadlock victims with Connection.capture_deadlock_graphs
+    deadlock_graph: Optional[str] = None
+
     def __init__(self, driver_error: str, ddbc_error: str) -> None:
         self.driver_error = driver_error
         self.ddbc_error = truncate_error_message(ddbc_error)
+        self.errors: List[Tuple[str, int, str]] = []
         if self.ddbc_error:
             # Both driver and DDBC errors are present
             self.message = f"Driver Error: {self.driver_error}; DDBC Error: {self.ddbc_error}"
@@ -56,6 +72,13 @@ class Exception(builtins.Exception):
         return (
             Exception._unpickle,
             (self.__class__, self.driver_error, self.ddbc_error, self.message),
+            {
+                "native_error": self.native_error,
+                "sqlstate": self.sqlstate,
+                "errors": getattr(self, "errors", []),
+                "blocking_chain": self.blocking_chain,
+                "deadlock_graph": self.deadlock_graph,
+            },
         )
 
     @staticmethod
@@ -167,6 +190,230 @@ class NotSupportedError(DatabaseError):
         super().__init__(driver_error, ddbc_error)
 
 
+class SchemaDriftError(ProgrammingError):
+    """
+    Exception raised when the source schema of a bulk load does not match the
+    target table (missing columns, unfillable NOT NULL columns, or type mismatches).
+    The structured comparison is available on the ``report`` attribute.
+    """
+
+    report = None
+
+    def __init__(self, driver_error: str, ddbc_error: str, report=None) -> None:
+        super().__init__(driver_error, ddbc_error)
+        self.report = report
+
+
+class TooManyParametersError(ProgrammingError):
+    """
+    Exception raised before executing a statement with more parameters than SQL
+    Server accepts in one request, when the driver cannot split or rewrite it.
+    The count and the limit are available as ``parameter_count`` and ``limit``.
+    """
+
+    parameter_count = None
+    limit = None
+
+    def __init__(self, parameter_count: int, limit: int) -> None:
+        super().__init__(
+            driver_error=(
+                f"The statement has {parameter_count} parameters; SQL Server accepts at "
+                f"most {limit} per request"
+            ),
+            ddbc_error=(
+                "Bind long IN lists as one sequence (IN ?), insert many rows with "
+                "executemany() or Cursor.insert_rows(), or pass the values as JSON "
+                "(OPENJSON) or a table-valued parameter"
+            ),
+        )
+        self.parameter_count = parameter_count
+        self.limit = limit
+
+    def __reduce__(self):
+        reconstruct, args, state = super().__reduce__()
+        state = dict(state, parameter_count=self.parameter_count, limit=self.limit)
+        return reconstruct, args, state
+
+
+class StatementTooLargeError(ProgrammingError):
+    """
+    Exception raised before sending a statement larger than the batch size SQL
+    Server accepts (65,536 network packets). The size of the statement and the
+    limit, both in bytes, are available as ``size`` and ``limit``.
+    """
+
+    size = None
+    limit = None
+
+    def __init__(self, size: int, limit: int) -> None:
+        super().__init__(
+            driver_error=(
+                f"The statement is {size} bytes; SQL Server accepts batches of at most "
+                f"{limit} bytes with this packet size"
+            ),
+            ddbc_error=(
+                "Split the script into batches separated by GO lines and run it with "
+                "Cursor.execute_script(), or raise the PacketSize connection setting"
+            ),
+        )
+        self.size = size
+        self.limit = limit
+
+    def __reduce__(self):
+        reconstruct, args, state = super().__reduce__()
+        state = dict(state, size=self.size, limit=self.limit)
+        return reconstruct, args, state
+
+
+class PingError(OperationalError):
+    """
+    Exception raised by connection.ping() when the server does not answer the
+    liveness probe, e.g. because the link is broken or the probe timed out. The
+    driver error that caused it, if any, is chained as ``__cause__``.
+    """
+
+    def __init__(self, driver_error: str, ddbc_error: str) -> None:
+        super().__init__(driver_error, ddbc_error)
+
+
+class LoginError(OperationalError):
+    """
+    Exception raised when connecting fails, with a remediation hint for the cause
+    in ``hint``. The subclasses tell the common causes apart; LoginError itself is
+    raised for other login failures.
+    """
+
+    hint: Optional[str] = None
+
+    def __init__(self, driver_error: str, ddbc_error: str, hint: Optional[str] = None) -> None:
+        super().__init__(driver_error, ddbc_error)
+        self.hint = hint
+        if hint:
+            self.message = f"{self.message}; Hint: {hint}"
+            self.args = (self.message,)
+
+    def __reduce__(self):
+        reconstruct, args, state = super().__reduce__()
+        return reconstruct, args, dict(state, hint=self.hint)
+
+
+class InvalidCredentialsError(LoginError):
+    """Login failed for the user name and password (or token) given."""
+
+
+class LoginDisabledError(LoginError):
+    """The login exists but may not connect: disabled, locked out or its password expired."""
+
+
+class DatabaseUnavailableError(LoginError):
+    """
+    The server or the requested database cannot take the login: the database does
+    not exist, is offline or recovering, or the server is still starting.
+    """
+
+
+class ServerUnreachableError(LoginError):
+    """The server could not be reached: name resolution, network, firewall or timeout."""
+
+
+class CertificateTrustError(LoginError):
+    """The TLS handshake failed because the server's certificate is not trusted."""
+
+
+# Login failure causes, tried in order against the driver message of a failed
+# connect: (pattern, exception class, driver error, hint)
+_LOGIN_FAILURES = [
+    (
+        r"certificate verify failed|certificate chain was issued by an authority that is"
+        r" not trusted|self[- ]signed certificate|unable to get local issuer certificate",
+        CertificateTrustError,
+        "Server certificate not trusted",
+        "install the CA that issued the server's certificate, pass its path as "
+        "ServerCertificate=<file>, or set TrustServerCertificate=yes (development only)",
+    ),
+    (
+        r"certificate.*(name|host).*(mismatch|does not match)|HostnameInCertificate",
+        CertificateTrustError,
+        "Server certificate name does not match the server",
+        "connect with the name in the certificate, or set HostnameInCertificate=<name>",
+    ),
+    (
+        r"account is disabled",
+        LoginDisabledError,
+        "Login is disabled",
+        "ask an administrator to run ALTER LOGIN ... ENABLE",
+    ),
+    (
+        r"account is currently locked out",
+        LoginDisabledError,
+        "Login is locked out",
+        "wait for the lockout period to end, or ask an administrator to unlock the login",
+    ),
+    (
+        r"password of the account has expired|password of the account must be changed",
+        LoginDisabledError,
+        "Password expired",
+        "change the login's password (e.g. ALTER LOGIN ... WITH PASSWORD)",
+    ),
+    (
+        r"Client with IP address '[^']*' is not allowed to access the server",
+        ServerUnreachableError,
+        "Client IP address is blocked by the server firewall",
+        "add a firewall rule for this client's IP address (e.g. sp_set_firewall_rule)",
+    ),
+    (
+        r"script upgrade mode",
+        DatabaseUnavailableError,
+        "Server is starting",
+        "retry once the server has finished running its upgrade scripts",
+    ),
+    (
+        r"Cannot open database|Failed to open the explicitly specified database",
+        DatabaseUnavailableError,
+        "Database unavailable",
+        "check that the database exists, is online and that the login has a user in it",
+    ),
+    (
+        r"Login failed for user|password|AADSTS50126",
+        InvalidCredentialsError,
+        "Login failed",
+        "check the user name and password (UID/PWD) or the Authentication setting",
+    ),
+    (
+        r"No such host is known|could not be resolved|Name or service not known"
+        r"|getaddrinfo failed",
+        ServerUnreachableError,
+        "Server name not found",
+        "check the Server name; use host,port for a port other than 1433",
+    ),
+    (
+        r"Login timeout expired|TCP Provider|network-related or instance-specific error"
+        r"|server was not found or was not accessible|Connection refused",
+        ServerUnreachableError,
+        "Server unreachable",
+        "check that the server runs and accepts TCP connections on its port, and that "
+        "no firewall blocks it (1433 by default)",
+    ),
+]
+
+
+def login_error(sqlstate: Optional[str], ddbc_error: str) -> Optional["LoginError"]:
+    """
+    Return the LoginError subclass instance for a failed connect, with a hint, or
+    None if the message matches no known login failure cause.
+    """
+    for pattern, exception_class, driver_error, hint in _LOGIN_FAILURES:
+        if re.search(pattern, ddbc_error, re.IGNORECASE):
+            exception = exception_class(driver_error, ddbc_error, hint)
+            exception.sqlstate = sqlstate
+            return exception
+    if sqlstate == "28000":
+        exception = LoginError("Login failed", ddbc_error)
+        exception.sqlstate = sqlstate
+        return exception
+    return None
+
+
 # Mapping SQLSTATE codes to custom exception classes
 def sqlstate_to_exception(sqlstate: str, ddbc_error: str) -> Optional[Exception]:
     """
@@ -557,6 +804,9 @@ def truncate_error_message(error_message: str) -> str:
             return error_message
         string_first = error_message[: error_message.index("]") + 1]
         string_second = error_message[error_message.index("]") + 1 :]
+        if not string_second.startswith("["):
+            # Already truncated, e.g. when re-raised as another exception class
+            return error_message
         string_third = string_second[string_second.index("]") + 1 :]
         return string_first + string_third
     except Exception as e:
@@ -564,7 +814,68 @@ def truncate_error_message(error_message: str) -> str:
         return error_message
 
 
-def raise_exception(sqlstate: str, ddbc_error: str) -> None:
+# Application exception classes registered for SQL Server error numbers
+_error_classes: Dict[int, Type["Error"]] = {}
+_error_classes_lock = threading.Lock()
+
+
+def _error_numbers(error_numbers: Union[int, Iterable[int]]) -> list:
+    numbers = [error_numbers] if isinstance(error_numbers, int) else list(error_numbers)
+    if not numbers or not all(isinstance(n, int) and not isinstance(n, bool) for n in numbers):
+        raise ValueError("error_numbers must be an error number or a sequence of them")
+    return numbers
+
+
+def register_error_class(
+    error_numbers: Union[int, Iterable[int]], exception_class: Type["Error"]
+) -> None:
+    """
+    Raise exception_class for server errors with the given native error numbers.
+
+    Lets applications handle specific failures by type instead of parsing message
+    text, e.g. register_error_class([2601, 2627], DuplicateKeyError). The class must
+    derive from mssql_python.Error and accept (driver_error, ddbc_error); deriving
+    from the exception the error maps to by default (IntegrityError for constraint
+    violations) keeps existing handlers working. The raised exception carries the
+    same message, native_error and sqlstate as the default one. A later
+    registration for a number replaces the earlier one.
+
+    Args:
+        error_numbers: A native error number or a sequence of them.
+        exception_class: The exception class to raise for them.
+    """
+    if not isinstance(exception_class, type) or not issubclass(exception_class, Error):
+        raise TypeError("exception_class must be a subclass of mssql_python.Error")
+    numbers = _error_numbers(error_numbers)
+    with _error_classes_lock:
+        for number in numbers:
+            _error_classes[number] = exception_class
+    logger.info("Registered %s for error numbers %s", exception_class.__name__, numbers)
+
+
+def unregister_error_class(error_numbers: Union[int, Iterable[int]]) -> None:
+    """Restore the default exception mapping for the given native error numbers."""
+    numbers = _error_numbers(error_numbers)
+    with _error_classes_lock:
+        for number in numbers:
+            _error_classes.pop(number, None)
+
+
+# Shown when a statement is issued while another cursor on a non-MARS connection
+# still has unread results (raised both by the cursor guard and for the driver's
+# "Connection is busy with results for another command" error).
+PENDING_RESULTS_MESSAGE = (
+    "Results pending on this connection: call fetchall() or nextset() on the other "
+    "cursor, close it, or enable MARS (SQL_COPT_SS_MARS_ENABLED in attrs_before)"
+)
+
+
+def raise_exception(
+    sqlstate: str,
+    ddbc_error: str,
+    native_error: Optional[int] = None,
+    errors: Optional[Iterable[Tuple[str, int, str]]] = None,
+) -> None:
     """
     Raise a custom exception based on the given SQLSTATE code.
     This function raises a custom exception based on the provided SQLSTATE code.
@@ -573,16 +884,36 @@ def raise_exception(sqlstate: str, ddbc_error: str) -> None:
     Args:
         sqlstate (str): The SQLSTATE code to map to a custom exception.
         ddbc_error (str): The DDBC error message.
+        native_error (int, optional): The SQL Server error number, stored on the
+            raised exception as native_error (sqlstate is stored as well). An
+            exception class registered for it with register_error_class() is
+            raised instead of the SQLSTATE-based one.
+        errors (iterable, optional): All diagnostic records of the failed call as
+            (sqlstate, native_error, message) tuples, stored as the exception's errors.
 
     Raises:
         DatabaseError: If the SQLSTATE code is not found in the mapping.
     """
-    exception_class = sqlstate_to_exception(sqlstate, ddbc_error)
-    if exception_class:
-        logger.error(f"Raising exception: {exception_class}")
-        raise exception_class
-    logger.error(f"Unknown SQLSTATE {sqlstate}, raising DatabaseError")
-    raise DatabaseError(
-        driver_error=f"An error occurred with SQLSTATE code: {sqlstate}",
-        ddbc_error=f"{ddbc_error}" if ddbc_error else "Unknown DDBC error",
-    )
+    if ddbc_error and "busy with results for another" in ddbc_error.lower():
+        exception = InterfaceError(driver_error=PENDING_RESULTS_MESSAGE, ddbc_error=ddbc_error)
+    else:
+        exception = sqlstate_to_exception(sqlstate, ddbc_error)
+        if exception:
+            logger.error(f"Raising exception: {exception}")
+        else:
+            logger.error(f"Unknown SQLSTATE {sqlstate}, raising DatabaseError")
+            exception = DatabaseError(
+                driver_error=f"An error occurred with SQLSTATE code: {sqlstate}",
+                ddbc_error=f"{ddbc_error}" if ddbc_error else "Unknown DDBC error",
+            )
+    if native_error:
+        exception_class = _error_classes.get(native_error)
+        if exception_class is not None:
+            exception = exception_class(driver_error=exception.driver_error, ddbc_error=ddbc_error)
+        exception.native_error = native_error
+    exception.sqlstate = sqlstate
+    if errors:
+        exception.errors = [tuple(record) for record in errors]
+    else:
+        exception.errors = [(sqlstate, native_error or 0, ddbc_error)]
+    raise exception
@@ -0,0 +1,140 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module implements FIPS mode (mssql_python.fips_mode, or MSSQL_PYTHON_FIPS_MODE=1
+in the environment): connecting requires the operating system crypto provider that
+the ODBC driver uses and Python's OpenSSL to run in FIPS mode, encryption cannot be
+turned off, TLS opened by mssql-python itself is limited to FIPS-approved cipher
+suites, and hashes, e.g. for result checksums, are limited to FIPS-approved ones.
+"""
+
+import sys
+from typing import Any, Dict
+
+from mssql_python.exceptions import NotSupportedError
+from mssql_python.helpers import _settings
+
+# FIPS 180-4 and FIPS 202 hash functions
+APPROVED_HASHES = (
+    "sha224",
+    "sha256",
+    "sha384",
+    "sha512",
+    "sha512_224",
+    "sha512_256",
+    "sha3_224",
+    "sha3_256",
+    "sha3_384",
+    "sha3_512",
+)
+# ECDHE/DHE key exchange with AES-GCM (SP 800-52r2); TLS 1.3 suites are all AES
+# or ChaCha20 and OpenSSL's FIPS provider drops ChaCha20 itself
+APPROVED_CIPHERS = "ECDHE+AESGCM:DHE+AESGCM:!aNULL:!eNULL"
+_LINUX_FIPS_FLAG = "/proc/sys/crypto/fips_enabled"
+_WINDOWS_FIPS_KEY = r"SYSTEM\CurrentControlSet\Control\Lsa\FipsAlgorithmPolicy"
+
+
+def fips_mode() -> bool:
+    """Return whether FIPS mode is on."""
+    return _settings.fips_mode
+
+
+def _system_fips_enabled() -> bool:
+    if sys.platform == "win32":
+        import winreg  # pylint: disable=import-outside-toplevel,import-error
+
+        try:
+            with winreg.OpenKey(winreg.HKEY_LOCAL_MACHINE, _WINDOWS_FIPS_KEY) as key:
+                return bool(winreg.QueryValueEx(key, "Enabled")[0])
+        except OSError:
+            return False
+    try:
+        with open(_LINUX_FIPS_FLAG, encoding="ascii") as flag:
+            return flag.read().strip() == "1"
+    except OSError:
+        return False
+
+
+def _openssl_fips_enabled() -> bool:
+    try:
+        import _hashlib  # pylint: disable=import-outside-toplevel
+    except ImportError:
+        return False
+    return bool(getattr(_hashlib, "get_fips_mode", lambda: 0)())
+
+
+def fips_status() -> Dict[str, Any]:
+    """
+    Return whether FIPS mode is on and whether the crypto providers run in FIPS mode.
+
+    "system" is the operating system policy (FipsAlgorithmPolicy on Windows,
+    fips_enabled on Linux), which the ODBC driver's TLS follows; "openssl" is the
+    OpenSSL of this Python, used for certificate pinning and hashing.
+    """
+    return {
+        "fips_mode": fips_mode(),
+        "system": _system_fips_enabled(),
+        "openssl": _openssl_fips_enabled(),
+    }
+
+
+def require_fips_providers() -> None:
+    """
+    Raise unless the operating system and OpenSSL crypto providers run in FIPS mode.
+
+    Raises:
+        NotSupportedError: If a provider is not in FIPS mode.
+    """
+    status = fips_status()
+    missing = [name for name in ("system", "openssl") if not status[name]]
+    if missing:
+        raise NotSupportedError(
+            driver_error="FIPS mode needs FIPS-validated crypto providers",
+            ddbc_error=(
+                f"Not in FIPS mode: {', '.join(missing)} (turn on the FIPS policy of the "
+                "operating system and use a Python built against a FIPS OpenSSL)"
+            ),
+        )
+
+
+def require_approved_hash(algorithm: str) -> None:
+    """
+    Raise in FIPS mode if algorithm is not a FIPS-approved hash.
+
+    Raises:
+        NotSupportedError: If FIPS mode is on and the algorithm is not approved.
+    """
+    if fips_mode() and algorithm.lower().replace("-", "_") not in APPROVED_HASHES:
+        raise NotSupportedError(
+            driver_error=f"Hash algorithm {algorithm!r} is not FIPS-approved",
+            ddbc_error=f"FIPS mode allows: {', '.join(APPROVED_HASHES)}",
+        )
+
+
+def require_fips_connection(params: Dict[str, str]) -> None:
+    """
+    Raise in FIPS mode unless a connection with normalized params is FIPS-compliant.
+
+    Raises:
+        NotSupportedError: If the crypto providers are not in FIPS mode, or
+            Encrypt turns encryption off or makes it optional.
+    """
+    if not fips_mode():
+        return
+    require_fips_providers()
+    encrypt = params.get("Encrypt", "yes").strip().lower()
+    if encrypt in ("no", "false", "optional"):
+        raise NotSupportedError(
+            driver_error=f"Encrypt={params['Encrypt']} is not allowed in FIPS mode",
+            ddbc_error="Use Encrypt=yes or Encrypt=strict",
+        )
+
+
+def restrict_tls_context(context: Any) -> Any:
+    """Limit an ssl.SSLContext to TLS 1.2+ and FIPS-approved cipher suites in FIPS mode."""
+    if fips_mode():
+        import ssl  # pylint: disable=import-outside-toplevel
+
+        context.minimum_version = ssl.TLSVersion.TLSv1_2
+        context.set_ciphers(APPROVED_CIPHERS)
+    return context
@@ -0,0 +1,225 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module helps with full-text search: it builds CONTAINS search conditions from
+user input, which are bound as parameters instead of being spliced into the SQL, and
+reports the population status of full-text catalogs.
+"""
+
+import time
+from typing import TYPE_CHECKING, Any, Dict, Optional, Sequence, Union
+
+from mssql_python.exceptions import OperationalError, ProgrammingError
+from mssql_python.helpers import quote_identifier
+from mssql_python.logging import logger
+
+if TYPE_CHECKING:
+    from mssql_python.connection import Connection
+
+# FULLTEXTCATALOGPROPERTY(catalog, 'PopulateStatus') values
+POPULATE_STATUSES = {
+    0: "idle",
+    1: "full population in progress",
+    2: "paused",
+    3: "throttled",
+    4: "recovering",
+    5: "shutdown",
+    6: "incremental population in progress",
+    7: "building index",
+    8: "disk is full, paused",
+    9: "change tracking",
+}
+
+_CATALOG_STATUS_QUERY = (
+    "SELECT FULLTEXTCATALOGPROPERTY(?, 'PopulateStatus'), "
+    "FULLTEXTCATALOGPROPERTY(?, 'ItemCount'), "
+    "FULLTEXTCATALOGPROPERTY(?, 'PopulateCompletionAge')"
+)
+
+_OPERATORS = ("AND", "OR")
+
+
+def phrase(text: str, prefix: bool = False) -> str:
+    """
+    Quote text as a single phrase of a CONTAINS search condition.
+
+    The text is matched literally: operators (AND, NEAR, ...), parentheses and
+    wildcards in it have no effect. Double quotes are doubled and asterisks are
+    dropped, so that only prefix=True makes a prefix term.
+
+    Args:
+        text: The words to match, e.g. from a search box.
+        prefix: Match words starting with the last word of text ("data*").
+
+    Returns:
+        str: The quoted phrase, e.g. '"blue ""sky"" data*"'.
+
+    Raises:
+        ValueError: If text is empty or has no words.
+    """
+    if not isinstance(text, str):
+        raise ValueError("Full-text search terms must be strings")
+    words = " ".join(text.replace("*", " ").split())
+    if not words:
+        raise ValueError("Full-text search term has no words")
+    return '"' + words.replace('"', '""') + ("*" if prefix else "") + '"'
+
+
+def contains_condition(
+    terms: Union[str, Sequence[str]],
+    operator: str = "AND",
+    prefix: bool = False,
+    exclude: Sequence[str] = (),
+) -> str:
+    """
+    Build a CONTAINS search condition matching rows with the given terms.
+
+    Bind the result as the parameter of CONTAINS(column, ?) or CONTAINSTABLE.
+
+    Args:
+        terms: One phrase, or several combined with operator.
+        operator: "AND" (all terms) or "OR" (any term).
+        prefix: Treat each term as a prefix term (see phrase()).
+        exclude: Phrases the rows must not contain (AND NOT).
+
+    Returns:
+        str: The search condition, e.g. '"sql" AND "server" AND NOT "beta"'.
+
+    Example:
+        condition = fulltext.contains_condition(user_input.split(), prefix=True)
+        cursor.execute("SELECT id FROM docs WHERE CONTAINS(body, ?)", condition)
+    """
+    if isinstance(terms, str):
+        terms = [terms]
+    operator = operator.upper() if isinstance(operator, str) else operator
+    if operator not in _OPERATORS:
+        raise ValueError(f"operator must be one of {_OPERATORS}, got {operator!r}")
+    quoted = [phrase(term, prefix) for term in terms]
+    if not quoted:
+        raise ValueError("At least one full-text search term is required")
+    if isinstance(exclude, str):
+        exclude = [exclude]
+    condition = f" {operator} ".join(quoted)
+    if exclude:
+        if operator == "OR" and len(quoted) > 1:
+            condition = f"({condition})"
+        condition += "".join(f" AND NOT {phrase(term)}" for term in exclude)
+    return condition
+
+
+def near_condition(
+    terms: Sequence[str], max_distance: Optional[int] = None, ordered: bool = False
+) -> str:
+    """
+    Build a CONTAINS proximity condition: all terms within max_distance words.
+
+    Args:
+        terms: Two or more phrases.
+        max_distance: Maximum number of non-search words between the first and
+            last terms, or None for any distance.
+        ordered: Whether the terms must appear in the given order.
+
+    Returns:
+        str: The search condition, e.g. 'NEAR(("sql", "server"), 3, TRUE)'.
+    """
+    if isinstance(terms, str) or len(terms) < 2:
+        raise ValueError("NEAR needs at least two search terms")
+    if max_distance is not None and (
+        isinstance(max_distance, bool) or not isinstance(max_distance, int) or max_distance < 0
+    ):
+        raise ValueError("max_distance must be a non-negative integer or None")
+    quoted = ", ".join(phrase(term) for term in terms)
+    distance = "MAX" if max_distance is None else str(max_distance)
+    return f"NEAR(({quoted}), {distance}, {'TRUE' if ordered else 'FALSE'})"
+
+
+def contains_predicate(
+    columns: Union[str, Sequence[str]] = "*",
+    function: str = "CONTAINS",
+    language: Optional[Union[int, str]] = None,
+) -> str:
+    """
+    Return a CONTAINS or FREETEXT predicate over columns with a ? placeholder.
+
+    The search condition (or, for FREETEXT, the free text) is bound to the
+    placeholder, so user input never becomes part of the SQL.
+
+    Args:
+        columns: A column, several columns, or "*" for all full-text columns.
+        function: "CONTAINS" or "FREETEXT".
+        language: LCID (e.g. 1033) or language alias (e.g. "English") used to
+            break words and stem, or None for the column's language.
+
+    Returns:
+        str: e.g. "CONTAINS(([title], [body]), ?, LANGUAGE 1033)".
+    """
+    function = function.upper() if isinstance(function, str) else function
+    if function not in ("CONTAINS", "FREETEXT"):
+        raise ValueError(f"function must be 'CONTAINS' or 'FREETEXT', got {function!r}")
+    if columns == "*":
+        column_list = "*"
+    else:
+        if isinstance(columns, str):
+            columns = [columns]
+        quoted = [quote_identifier(column) for column in columns]
+        if not quoted:
+            raise ValueError("At least one column is required")
+        column_list = quoted[0] if len(quoted) == 1 else f"({', '.join(quoted)})"
+    if language is None:
+        language_clause = ""
+    elif isinstance(language, int) and not isinstance(language, bool):
+        language_clause = f", LANGUAGE {language}"
+    elif isinstance(language, str) and language.replace(" ", "").isalpha():
+        language_clause = f", LANGUAGE '{language}'"
+    else:
+        raise ValueError(f"Invalid full-text language: {language!r}")
+    return f"{function}({column_list}, ?{language_clause})"
+
+
+def population_status(connection: "Connection", catalog: str) -> Dict[str, Any]:
+    """Return the population status of a full-text catalog; see Connection."""
+    cursor = connection.cursor()
+    try:
+        row = cursor.execute(_CATALOG_STATUS_QUERY, catalog, catalog, catalog).fetchall()[0]
+    finally:
+        cursor.close()
+    status, item_count, completion_age = tuple(row)
+    if status is None:
+        raise ProgrammingError(
+            driver_error=f"Full-text catalog {catalog!r} does not exist",
+            ddbc_error="FULLTEXTCATALOGPROPERTY returned NULL",
+        )
+    return {
+        "catalog": catalog,
+        "status": status,
+        "description": POPULATE_STATUSES.get(status, "unknown"),
+        "idle": status == 0,
+        "item_count": item_count,
+        # Seconds between 1990-01-01 and the end of the last population
+        "completion_age": completion_age,
+    }
+
+
+def wait_for_population(
+    connection: "Connection",
+    catalog: str,
+    timeout: Optional[float] = None,
+    poll_interval: float = 1.0,
+) -> Dict[str, Any]:
+    """Wait until a full-text catalog is idle; see Connection."""
+    if isinstance(poll_interval, bool) or not isinstance(poll_interval, (int, float)):
+        raise ValueError("poll_interval must be a positive number of seconds")
+    if poll_interval <= 0:
+        raise ValueError("poll_interval must be a positive number of seconds")
+    deadline = None if timeout is None else time.monotonic() + timeout
+    while True:
+        status = population_status(connection, catalog)
+        if status["idle"]:
+            return status
+        if deadline is not None and time.monotonic() >= deadline:
+            raise OperationalError(
+                driver_error=f"Full-text catalog {catalog!r} is still populating",
+                ddbc_error=f"Status after {timeout}s: {status['description']}",
+            )
+        logger.debug("wait_for_population: %s is %s", catalog, status["description"])
+        time.sleep(poll_interval)
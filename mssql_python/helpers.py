@@ -4,6 +4,7 @@ Licensed under the MIT license.
 This module provides helper functions for the mssql_python package.
 """
 
+import os
 import re
 import threading
 import locale
@@ -35,7 +36,12 @@ def check_error(handle_type: int, handle: Any, ret: int) -> None:
         error_info = ddbc_bindings.DDBCSQLCheckError(handle_type, handle, ret)
         logger.error("Error: %s", error_info.ddbcErrorMsg)
         logger.debug("check_error: SQL state=%s", error_info.sqlState)
-        raise_exception(error_info.sqlState, error_info.ddbcErrorMsg)
+        raise_exception(
+            error_info.sqlState,
+            error_info.ddbcErrorMsg,
+            error_info.nativeError,
+            error_info.records,
+        )
 
 
 def sanitize_connection_string(conn_str: str) -> str:
@@ -96,6 +102,125 @@ def sanitize_user_input(user_input: str, max_length: int = 50) -> str:
     return result
 
 
+def quote_identifier(name: str) -> str:
+    """
+    Quote a single T-SQL identifier part with square brackets.
+
+    Closing brackets inside the name are doubled, matching QUOTENAME() so that
+    the result is always a single, safe identifier regardless of its content.
+
+    Args:
+        name (str): The unquoted identifier (e.g. a column or table name).
+
+    Returns:
+        str: The bracket-quoted identifier.
+
+    Raises:
+        ValueError: If the name is empty, not a string, or longer than 128 characters.
+    """
+    if not isinstance(name, str) or not name:
+        raise ValueError("Identifier must be a non-empty string")
+    if len(name) > 128:
+        raise ValueError(f"Identifier exceeds 128 characters: {name[:20]}...")
+    return "[" + name.replace("]", "]]") + "]"
+
+
+def split_multipart_name(name: str) -> Tuple[str, ...]:
+    """
+    Split a multi-part T-SQL object name (e.g. ``db.[my schema].tbl``) into its parts.
+
+    Bracket-quoted (``[a.b]``) and double-quoted (``"a.b"``) parts may contain dots;
+    escaped closing delimiters (``]]`` / ``""``) are unescaped. At most four parts
+    (server.database.schema.object) are accepted.
+
+    Args:
+        name (str): The multi-part name as written in T-SQL.
+
+    Returns:
+        tuple: The unquoted name parts, in order.
+
+    Raises:
+        ValueError: If the name is empty, has unbalanced quoting, or has too many parts.
+    """
+    if not isinstance(name, str) or not name.strip():
+        raise ValueError("Object name must be a non-empty string")
+
+    parts = []
+    current = []
+    i = 0
+    text = name.strip()
+    length = len(text)
+    while i < length:
+        ch = text[i]
+        if ch in "[\"" and not current:
+            closing = "]" if ch == "[" else '"'
+            i += 1
+            while True:
+                if i >= length:
+                    raise ValueError(f"Unclosed quoted identifier in object name: {name}")
+                if text[i] == closing:
+                    if i + 1 < length and text[i + 1] == closing:
+                        current.append(closing)
+                        i += 2
+                        continue
+                    i += 1
+                    break
+                current.append(text[i])
+                i += 1
+            if i < length and text[i] != ".":
+                raise ValueError(f"Unexpected character after quoted identifier: {name}")
+            continue
+        if ch == ".":
+            parts.append("".join(current).strip())
+            current = []
+            i += 1
+            continue
+        current.append(ch)
+        i += 1
+    parts.append("".join(current).strip())
+
+    if len(parts) > 4 or not parts[-1]:
+        raise ValueError(f"Invalid multi-part object name: {name}")
+    return tuple(parts)
+
+
+def quote_multipart_name(name: str) -> str:
+    """
+    Re-quote every part of a multi-part object name with square brackets.
+
+    Empty middle parts are preserved (``db..tbl`` stays valid T-SQL).
+
+    Args:
+        name (str): The multi-part name as written in T-SQL.
+
+    Returns:
+        str: The name with every non-empty part bracket-quoted.
+    """
+    return ".".join(quote_identifier(part) if part else "" for part in split_multipart_name(name))
+
+
+def catalog_of(name: str) -> Tuple[str, str]:
+    """
+    Return where to look up an object in the catalog views.
+
+    Three-part names are looked up in the database they name and temporary
+    objects (#name) in tempdb; other names in the current database.
+
+    Args:
+        name (str): The multi-part object name as written in T-SQL.
+
+    Returns:
+        tuple: The prefix for the sys views ("" or e.g. "[db].") and the name to
+        pass to OBJECT_ID().
+    """
+    parts = split_multipart_name(name)
+    if len(parts) >= 3 and parts[-3]:
+        return quote_identifier(parts[-3]) + ".", name
+    if parts[-1].startswith("#"):
+        return "tempdb.", "tempdb.." + quote_identifier(parts[-1])
+    return "", name
+
+
 def validate_attribute_value(
     attribute: Union[int, str],
     value: Union[int, str, bytes, bytearray],
@@ -291,6 +416,7 @@ def connstr_to_pycore_params(params: dict) -> dict:
         "trustservercertificate": "trust_server_certificate",
         "trust_server_certificate": "trust_server_certificate",
         "hostnameincertificate": "host_name_in_certificate",
+        "hostname_in_certificate": "host_name_in_certificate",
         "servercertificate": "server_certificate",
         # Kerberos
         "serverspn": "server_spn",
@@ -355,13 +481,17 @@ try:
 except (AttributeError, KeyError, TypeError, ValueError):
     pass  # Keep the default "." if locale access fails
 
+# Environment variable that turns FIPS mode on for the process (see mssql_python.fips)
+FIPS_MODE_ENVIRONMENT_VARIABLE = "MSSQL_PYTHON_FIPS_MODE"
+
 
 class Settings:
     """
     Settings class for mssql_python package configuration.
 
     This class holds global settings that affect the behavior of the package,
-    including lowercase column names, decimal separator, and UUID handling.
+    including column-name handling, decimal separator, UUID handling, temporal
+    precision and FIPS mode.
     """
 
     def __init__(self) -> None:
@@ -372,6 +502,17 @@ class Settings:
         # or str (False). Default True returns native uuid.UUID objects.
         # Set to False to return str for pyodbc-compatible migration.
         self.native_uuid: bool = True
+        # Column-name access policy for Row objects: which column a duplicated name
+        # resolves to ("last", "first") or whether duplicates are renamed ("suffix"),
+        # and whether names are matched case-insensitively without lowercasing them.
+        self.duplicate_column_names: str = "last"
+        self.case_insensitive_columns: bool = False
+        # "nanoseconds" returns DATETIME2, DATETIMEOFFSET and TIME values as
+        # mssql_python.temporal.PreciseDateTime / PreciseTime, keeping 100 ns precision
+        self.temporal_precision: str = "microseconds"
+        # Restricts crypto to FIPS-approved algorithms and providers (mssql_python.fips)
+        fips_mode = os.environ.get(FIPS_MODE_ENVIRONMENT_VARIABLE, "").strip().lower()
+        self.fips_mode: bool = fips_mode in ("1", "true", "yes", "on")
 
 
 # Global settings instance
@@ -0,0 +1,89 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module reads the column lineage of a statement's result: for each result
+column the server, database, schema, table and column it was read from, as
+reported by sys.dm_exec_describe_first_result_set in browse information mode,
+for BI tools mapping result columns back to source tables
+(Cursor.extended_description).
+"""
+
+from typing import TYPE_CHECKING, Any, Dict, List, Optional, Tuple
+
+from mssql_python.exceptions import ProgrammingError
+from mssql_python.parameter_helper import _LITERAL_TOKEN_RE
+
+if TYPE_CHECKING:
+    from mssql_python.connection import Connection
+
+# Browse information mode 1 fills the source_* columns, resolving views to their
+# base tables; it also adds hidden key columns, which are left out
+_DESCRIBE_QUERY = (
+    "SELECT column_ordinal, name, source_server, source_database, source_schema, "
+    "source_table, source_column, is_computed_column, is_identity_column, error_number, "
+    "error_message "
+    "FROM sys.dm_exec_describe_first_result_set(?, ?, 1) "
+    "WHERE is_hidden = 0 OR is_hidden IS NULL ORDER BY column_ordinal"
+)
+SOURCE_KEYS = (
+    "source_server",
+    "source_database",
+    "source_schema",
+    "source_table",
+    "source_column",
+    "is_computed",
+    "is_identity",
+)
+# Declared type of the parameter markers of described statements: the type does
+# not change where a column comes from, and converts implicitly to most types
+_PARAMETER_TYPE = "nvarchar(4000)"
+
+
+def describe_statement(sql: str) -> Tuple[str, Optional[str]]:
+    """
+    Return sql with its ? parameter markers named @P1, @P2, ... and the
+    declaration of those parameters, None without markers.
+    """
+    parts = []
+    count = 0
+    for match in _LITERAL_TOKEN_RE.finditer(sql):
+        if match.lastgroup == "other" and match.group() == "?":
+            count += 1
+            parts.append(f"@P{count}")
+        else:
+            parts.append(match.group())
+    if not count:
+        return sql, None
+    declaration = ", ".join(f"@P{n} {_PARAMETER_TYPE}" for n in range(1, count + 1))
+    return "".join(parts), declaration
+
+
+def column_sources(connection: "Connection", sql: str) -> List[Dict[str, Any]]:
+    """
+    Return the source of each column of the first result set of sql, in column
+    order: one dict with "name" and SOURCE_KEYS, the source_* values None for
+    columns computed from expressions.
+
+    Raises:
+        ProgrammingError: If the server cannot describe the statement.
+    """
+    tsql, declaration = describe_statement(sql)
+    cursor = connection.cursor()
+    try:
+        rows = [tuple(row) for row in cursor.execute(_DESCRIBE_QUERY, tsql, declaration).fetchall()]
+    finally:
+        cursor.close()
+    sources = []
+    for row in rows:
+        error_number, error_message = row[9], row[10]
+        if error_number is not None:
+            raise ProgrammingError(
+                driver_error=f"The result columns cannot be described: {error_message}",
+                ddbc_error=f"sys.dm_exec_describe_first_result_set error {error_number}",
+            )
+        entry = {"name": row[1]}
+        entry.update(zip(SOURCE_KEYS, row[2:9]))
+        entry["is_computed"] = bool(entry["is_computed"])
+        entry["is_identity"] = bool(entry["is_identity"])
+        sources.append(entry)
+    return sources
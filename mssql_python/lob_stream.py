@@ -0,0 +1,77 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module implements the file-like objects of Cursor.fetch_lob_stream(): a
+VARBINARY(MAX), VARCHAR(MAX) or NVARCHAR(MAX) value that is read from the driver
+in chunks, with repeated SQLGetData calls as the caller reads, instead of being
+loaded into memory as one bytes or str value.
+"""
+
+import io
+from typing import Callable, Optional
+
+# Bytes requested from the driver per SQLGetData call
+DEFAULT_LOB_CHUNK_SIZE = 65536
+
+
+class LobReader(io.RawIOBase):
+    """
+    The raw binary stream of one column value.
+
+    read_chunk returns the next chunk of the value, empty bytes at its end;
+    check raises if the value can no longer be read (the cursor moved on).
+    """
+
+    def __init__(
+        self,
+        read_chunk: Callable[[], bytes],
+        check: Callable[[], None],
+        first_chunk: Optional[bytes],
+    ) -> None:
+        super().__init__()
+        self._read_chunk = read_chunk
+        self._check = check
+        self._pending = memoryview(first_chunk or b"")
+        self._at_end = not first_chunk
+        # NULL reads as an empty value
+        self.is_null = first_chunk is None
+
+    def readable(self) -> bool:
+        return True
+
+    def readinto(self, buffer) -> int:
+        if self.closed:
+            raise ValueError("I/O operation on a closed LOB stream")
+        while not self._pending and not self._at_end:
+            self._check()
+            chunk = self._read_chunk()
+            self._at_end = not chunk
+            self._pending = memoryview(chunk)
+        count = min(len(buffer), len(self._pending))
+        buffer[:count] = self._pending[:count]
+        self._pending = self._pending[count:]
+        return count
+
+
+class LobTextReader(io.TextIOWrapper):
+    """A character column value as a text stream, decoded from the driver's UTF-16LE."""
+
+    def __init__(self, raw: LobReader, chunk_size: int) -> None:
+        super().__init__(io.BufferedReader(raw, buffer_size=chunk_size), encoding="utf-16-le")
+
+    @property
+    def is_null(self) -> bool:
+        """True if the value is NULL."""
+        return self.buffer.raw.is_null
+
+
+class LobBinaryReader(io.BufferedReader):
+    """A binary column value as a binary stream."""
+
+    def __init__(self, raw: LobReader, chunk_size: int) -> None:
+        super().__init__(raw, buffer_size=chunk_size)
+
+    @property
+    def is_null(self) -> bool:
+        """True if the value is NULL."""
+        return self.raw.is_null
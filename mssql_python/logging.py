@@ -15,7 +15,7 @@ import datetime
 import re
 import platform
 import atexit
-from typing import Optional
+from typing import Any, Dict, Iterable, Optional, Sequence, Union
 
 # Single DEBUG level - all or nothing philosophy
 # If you need logging, you need to see everything
@@ -45,6 +45,97 @@ class ThreadIDFilter(logging.Filter):
         return True
 
 
+# How RedactionPolicy logs parameters
+PARAMETERS_OMIT = "omit"  # Nothing about parameters
+PARAMETERS_LENGTH = "length"  # Type and length only, e.g. <str len=5>
+PARAMETERS_VALUES = "values"  # The values themselves
+_PARAMETER_MODES = (PARAMETERS_OMIT, PARAMETERS_LENGTH, PARAMETERS_VALUES)
+# Connection string keys that are always masked
+ALWAYS_MASKED_KEYS = frozenset({"pwd", "password"})
+
+
+class RedactionPolicy:
+    """
+    What the driver's log records may contain.
+
+    Attributes:
+        masked_keys: Lowercase connection string keys whose values are logged as
+            "***"; always includes pwd and password.
+        log_sql: Whether SQL text is logged; if False only its length is.
+        parameters: How parameters are logged: "length" (default) as type and
+            length placeholders, "omit" not at all, "values" as their values.
+    """
+
+    def __init__(
+        self,
+        masked_keys: Iterable[str] = (),
+        log_sql: bool = True,
+        parameters: str = PARAMETERS_LENGTH,
+    ) -> None:
+        if isinstance(masked_keys, str):
+            raise TypeError("masked_keys must be a collection of key names, not a string")
+        if not isinstance(log_sql, bool):
+            raise ValueError("log_sql must be a boolean value")
+        if parameters not in _PARAMETER_MODES:
+            raise ValueError(f"parameters must be one of {', '.join(_PARAMETER_MODES)}")
+        self.masked_keys = ALWAYS_MASKED_KEYS | {key.strip().lower() for key in masked_keys}
+        self.log_sql = log_sql
+        self.parameters = parameters
+        keys = "|".join(re.escape(key) for key in sorted(self.masked_keys, key=len, reverse=True))
+        # key=value or key={braced;value} inside free text, e.g. py-core messages;
+        # an unbraced value is masked up to the next ";" (or the end)
+        self._masked_pairs = re.compile(
+            rf"(?i)(?<!\w)((?:{keys})\s*=\s*)(\{{(?:[^}}]|\}}\}})*\}}|[^;]*)"
+        )
+
+    def masks(self, key: str) -> bool:
+        """Return whether the value of connection string key is masked."""
+        return key.strip().lower() in self.masked_keys
+
+    def sql(self, sql: str) -> str:
+        """Return SQL text as it may be logged."""
+        if self.log_sql:
+            return sql
+        return f"<SQL redacted, {len(sql)} characters>"
+
+    def parameters_text(self, parameters: Union[Sequence[Any], Dict[str, Any]]) -> Optional[str]:
+        """Return parameters as they may be logged, None if they are not logged."""
+        if self.parameters == PARAMETERS_OMIT:
+            return None
+        if isinstance(parameters, dict):
+            items = [f"{name}={self._parameter(value)}" for name, value in parameters.items()]
+        else:
+            items = [self._parameter(value) for value in parameters]
+        return "(" + ", ".join(items) + ")"
+
+    def _parameter(self, value: Any) -> str:
+        if self.parameters == PARAMETERS_VALUES:
+            return repr(value)
+        if value is None:
+            return "<NULL>"
+        if isinstance(value, (str, bytes, bytearray)):
+            return f"<{type(value).__name__} len={len(value)}>"
+        return f"<{type(value).__name__}>"
+
+    def scrub(self, message: str) -> str:
+        """Return message with the values of masked key=value pairs replaced by ***."""
+        return self._masked_pairs.sub(r"\1***", message)
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Return the policy as a dictionary."""
+        return {
+            "masked_keys": sorted(self.masked_keys),
+            "log_sql": self.log_sql,
+            "parameters": self.parameters,
+        }
+
+    def __repr__(self) -> str:
+        return (
+            f"RedactionPolicy(masked_keys={sorted(self.masked_keys)!r}, "
+            f"log_sql={self.log_sql!r}, parameters={self.parameters!r})"
+        )
+
+
 class MSSQLLogger:
     """
     Singleton logger for mssql_python with single DEBUG level.
@@ -104,6 +195,9 @@ class MSSQLLogger:
         self._handler_lock = threading.RLock()  # Reentrant lock for handler operations
         self._cleanup_registered = False  # Track if atexit cleanup is registered
 
+        # What log records may contain (see configure_redaction)
+        self.redaction = RedactionPolicy()
+
         # Cached level for fast checks (avoid repeated isEnabledFor calls)
         self._cached_level = logging.WARNING
         self._is_debug_enabled = False
@@ -391,6 +485,9 @@ class MSSQLLogger:
             if level < self._cached_level and level < logging.WARNING:
                 return
 
+            # py-core formats its messages itself, so mask key=value pairs here
+            msg = self.redaction.scrub(msg)
+
             # Create a custom LogRecord with Rust source location
             import logging as log_module
 
@@ -712,3 +809,32 @@ def setup_logging(output: str = "file", log_file_path: Optional[str] = None):
     """
     logger._setLevel(logging.DEBUG, output, log_file_path)
     return logger
+
+
+def configure_redaction(
+    masked_keys: Iterable[str] = (),
+    log_sql: bool = True,
+    parameters: str = PARAMETERS_LENGTH,
+) -> RedactionPolicy:
+    """
+    Set what the driver's logs may contain, e.g. for compliance rules on log data.
+
+    Args:
+        masked_keys: Connection string keys whose values are masked in addition
+            to PWD and Password, e.g. ("UID", "Database").
+        log_sql: If False, SQL text is replaced by its length.
+        parameters: "length" (default) logs parameters as placeholders with their
+            type and length only, e.g. <str len=5>; "omit" logs nothing about them;
+            "values" logs the values themselves.
+
+    Returns:
+        RedactionPolicy: The policy now in effect.
+
+    Examples:
+        import mssql_python
+
+        # No SQL text and no parameter values in any log record
+        mssql_python.configure_redaction(log_sql=False, parameters="omit")
+    """
+    logger.redaction = RedactionPolicy(masked_keys, log_sql, parameters)
+    return logger.redaction
@@ -0,0 +1,333 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module provides index maintenance helpers: ALTER INDEX REBUILD/REORGANIZE
+statements with the options the server's edition and version support, and control
+of resumable index operations (progress, pause, resume, abort).
+"""
+
+import warnings
+from typing import TYPE_CHECKING, Any, Dict, List, Optional
+
+from mssql_python.exceptions import ProgrammingError
+from mssql_python.helpers import quote_identifier, quote_multipart_name
+from mssql_python.logging import logger
+
+if TYPE_CHECKING:
+    from mssql_python.connection import Connection
+
+# SERVERPROPERTY('EngineEdition'): 3 = Enterprise/Developer/Evaluation,
+# 5 = Azure SQL Database, 8 = Azure SQL Managed Instance
+_ONLINE_EDITIONS = (3, 5, 8)
+_AZURE_EDITIONS = (5, 8)
+# sys.indexes.type of clustered and nonclustered columnstore indexes
+_COLUMNSTORE_TYPES = (5, 6)
+
+_CAPABILITIES_QUERY = (
+    "SELECT CAST(SERVERPROPERTY('ProductMajorVersion') AS int), "
+    "CAST(SERVERPROPERTY('EngineEdition') AS int)"
+)
+_INDEX_TYPE_QUERY = "SELECT type FROM sys.indexes WHERE object_id = OBJECT_ID(?) AND name = ?"
+_RESUMABLE_QUERY = (
+    "SELECT OBJECT_SCHEMA_NAME(object_id) + '.' + OBJECT_NAME(object_id), name, state_desc, "
+    "percent_complete, start_time, last_pause_time, total_execution_time, page_count, sql_text "
+    "FROM sys.index_resumable_operations"
+)
+_RESUMABLE_COLUMNS = (
+    "table",
+    "index",
+    "state",
+    "percent_complete",
+    "start_time",
+    "last_pause_time",
+    "total_execution_minutes",
+    "page_count",
+    "sql_text",
+)
+
+
+def server_capabilities(major_version: int, engine_edition: int) -> Dict[str, Any]:
+    """Return which index operation options a server version and edition support."""
+    azure = engine_edition in _AZURE_EDITIONS
+    online = engine_edition in _ONLINE_EDITIONS
+    return {
+        "major_version": major_version,
+        "engine_edition": engine_edition,
+        "online": online,
+        # Resumable rebuilds arrived in SQL Server 2017, and require ONLINE = ON
+        "resumable": online and (azure or major_version >= 14),
+        # Online rebuilds of columnstore indexes arrived in SQL Server 2019
+        "online_columnstore": online and (azure or major_version >= 15),
+    }
+
+
+def _positive_int(value: Optional[int], name: str) -> None:
+    if value is not None and (isinstance(value, bool) or not isinstance(value, int) or value <= 0):
+        raise ValueError(f"{name} must be a positive integer or None")
+
+
+def _target(table: str, index: str) -> str:
+    """Return the "<index> ON <table>" part of ALTER INDEX."""
+    if not isinstance(index, str):
+        raise ValueError("index must be an index name or 'ALL'")
+    quoted_index = "ALL" if index.upper() == "ALL" else quote_identifier(index)
+    return f"{quoted_index} ON {quote_multipart_name(table)}"
+
+
+def rebuild_index_sql(
+    table: str,
+    index: str,
+    capabilities: Dict[str, Any],
+    online: bool = True,
+    resumable: bool = True,
+    columnstore: bool = False,
+    maxdop: Optional[int] = None,
+    max_duration: Optional[int] = None,
+) -> str:
+    """
+    Return the ALTER INDEX ... REBUILD statement for a server's capabilities.
+
+    ONLINE and RESUMABLE are requested on a best-effort basis: each is left out,
+    with a warning, when the server (or the index) does not support it.
+    RESUMABLE is not possible for columnstore indexes or for index "ALL".
+    """
+    _positive_int(maxdop, "maxdop")
+    _positive_int(max_duration, "max_duration")
+    options = []
+    online_supported = capabilities["online_columnstore" if columnstore else "online"]
+    if online and not online_supported:
+        warnings.warn(
+            f"Online index rebuild is not supported here; rebuilding {index} offline",
+            Warning,
+        )
+        online = False
+    if resumable and (not online or columnstore or index.upper() == "ALL"):
+        resumable = False
+    elif resumable and not capabilities["resumable"]:
+        warnings.warn("Resumable index rebuild is not supported by this server", Warning)
+        resumable = False
+    if online:
+        options.append("ONLINE = ON")
+    if resumable:
+        options.append("RESUMABLE = ON")
+        if max_duration is not None:
+            options.append(f"MAX_DURATION = {max_duration} MINUTES")
+    if maxdop is not None:
+        options.append(f"MAXDOP = {maxdop}")
+    sql = f"ALTER INDEX {_target(table, index)} REBUILD"
+    return f"{sql} WITH ({', '.join(options)})" if options else sql
+
+
+class IndexMaintenance:
+    """
+    Index maintenance for a connection, available as connection.maintenance.
+
+    Rebuilds and reorganizations run on the connection itself and block until
+    they finish. Resumable operations are monitored, paused and aborted through
+    the connection's monitoring connection (see Cursor.progress()), so they can be
+    controlled from another thread while the rebuild runs. Resumable operations
+    cannot run inside a transaction, so they need autocommit.
+    """
+
+    def __init__(self, connection: "Connection") -> None:
+        self._connection = connection
+        self._capabilities: Optional[Dict[str, Any]] = None
+
+    def capabilities(self) -> Dict[str, Any]:
+        """
+        Return the index operation options the server supports (read once).
+
+        Returns:
+            dict: "major_version", "engine_edition", and whether "online",
+                "resumable" and "online_columnstore" operations are supported.
+        """
+        if self._capabilities is None:
+            major_version, engine_edition = self._fetch_one(_CAPABILITIES_QUERY)
+            self._capabilities = server_capabilities(major_version, engine_edition)
+        return self._capabilities
+
+    def _fetch_one(self, sql: str, *params: Any) -> Optional[tuple]:
+        cursor = self._connection.cursor()
+        try:
+            rows = cursor.execute(sql, *params).fetchall()
+        finally:
+            cursor.close()
+        return tuple(rows[0]) if rows else None
+
+    def _run(self, sql: str) -> None:
+        logger.info("maintenance: %s", logger.redaction.sql(sql))
+        cursor = self._connection.cursor()
+        try:
+            cursor.execute(sql, use_prepare=False)
+        finally:
+            cursor.close()
+
+    def _is_columnstore(self, table: str, index: str) -> bool:
+        if index.upper() == "ALL":
+            return False
+        row = self._fetch_one(_INDEX_TYPE_QUERY, table, index)
+        if row is None:
+            raise ProgrammingError(
+                driver_error=f"Index {index!r} does not exist on {table!r}",
+                ddbc_error="No matching row in sys.indexes",
+            )
+        return row[0] in _COLUMNSTORE_TYPES
+
+    def _require_autocommit(self, operation: str) -> None:
+        if not self._connection.autocommit:
+            raise ProgrammingError(
+                driver_error=f"{operation} needs autocommit",
+                ddbc_error="Resumable index operations cannot run inside a transaction",
+            )
+
+    def rebuild_index(
+        self,
+        table: str,
+        index: str = "ALL",
+        online: bool = True,
+        resumable: bool = True,
+        maxdop: Optional[int] = None,
+        max_duration: Optional[int] = None,
+    ) -> str:
+        """
+        Rebuild an index (or all indexes of a table) and return the statement run.
+
+        Online and resumable operation are used where the server supports them
+        (Enterprise, Developer and Azure SQL; resumable from SQL Server 2017),
+        otherwise the rebuild runs without them after a warning. Resumable
+        rebuilds need autocommit, and are not possible for columnstore indexes
+        or index "ALL".
+
+        Args:
+            table: The table, optionally schema-qualified (e.g. "dbo.orders").
+            index: The index name, or "ALL".
+            online: Keep the table available during the rebuild.
+            resumable: Make the rebuild resumable, so it can be paused and resumed.
+            maxdop: Maximum degree of parallelism.
+            max_duration: Minutes after which a resumable rebuild pauses itself.
+
+        Returns:
+            str: The ALTER INDEX statement that was run.
+        """
+        sql = rebuild_index_sql(
+            table,
+            index,
+            self.capabilities(),
+            online=online,
+            resumable=resumable,
+            columnstore=self._is_columnstore(table, index),
+            maxdop=maxdop,
+            max_duration=max_duration,
+        )
+        if "RESUMABLE = ON" in sql:
+            self._require_autocommit("A resumable rebuild")
+        self._run(sql)
+        return sql
+
+    def reorganize_index(
+        self, table: str, index: str = "ALL", compress_all_row_groups: bool = False
+    ) -> str:
+        """
+        Reorganize an index and return the statement run.
+
+        Args:
+            table: The table, optionally schema-qualified.
+            index: The index name, or "ALL".
+            compress_all_row_groups: For columnstore indexes, also compress open
+                and closed delta row groups (SQL Server 2016+).
+
+        Returns:
+            str: The ALTER INDEX statement that was run.
+        """
+        sql = f"ALTER INDEX {_target(table, index)} REORGANIZE"
+        if compress_all_row_groups:
+            sql += " WITH (COMPRESS_ALL_ROW_GROUPS = ON)"
+        self._run(sql)
+        return sql
+
+    def resumable_operations(self, table: Optional[str] = None) -> List[Dict[str, Any]]:
+        """
+        Return the resumable index operations of the current database.
+
+        Read on the monitoring connection, so this can be called while a rebuild
+        runs on the connection.
+
+        Args:
+            table: Only return operations on this table.
+
+        Returns:
+            list: One dict per operation with "table" (schema.table), "index",
+                "state" ("RUNNING" or "PAUSED"), "percent_complete",
+                "start_time", "last_pause_time", "total_execution_minutes",
+                "page_count" and "sql_text".
+        """
+        sql = _RESUMABLE_QUERY
+        params: tuple = ()
+        if table is not None:
+            sql += " WHERE object_id = OBJECT_ID(?)"
+            params = (table,)
+        connection = self._connection
+        with connection._monitor_lock:
+            cursor = connection._monitoring_connection().cursor()
+            try:
+                rows = cursor.execute(sql, *params).fetchall()
+            finally:
+                cursor.close()
+        return [dict(zip(_RESUMABLE_COLUMNS, tuple(row))) for row in rows]
+
+    def _control(self, table: str, index: str, action: str) -> None:
+        connection = self._connection
+        sql = f"ALTER INDEX {_target(table, index)} {action}"
+        logger.info("maintenance: %s", logger.redaction.sql(sql))
+        with connection._monitor_lock:
+            cursor = connection._monitoring_connection().cursor()
+            try:
+                cursor.execute(sql, use_prepare=False)
+            finally:
+                cursor.close()
+
+    def pause(self, table: str, index: str) -> None:
+        """
+        Pause a running resumable rebuild, e.g. from another thread.
+
+        The statement running the rebuild ends with an error; the work done so
+        far is kept and the operation can be continued with resume().
+        """
+        self._control(table, index, "PAUSE")
+
+    def abort(self, table: str, index: str) -> None:
+        """Abort a running or paused resumable rebuild, discarding its work."""
+        self._control(table, index, "ABORT")
+
+    def resume(
+        self,
+        table: str,
+        index: str,
+        maxdop: Optional[int] = None,
+        max_duration: Optional[int] = None,
+    ) -> str:
+        """
+        Continue a paused resumable rebuild on the connection; return the statement.
+
+        Args:
+            table: The table, optionally schema-qualified.
+            index: The index name.
+            maxdop: Maximum degree of parallelism for the rest of the rebuild.
+            max_duration: Minutes after which the rebuild pauses itself again.
+        """
+        _positive_int(maxdop, "maxdop")
+        _positive_int(max_duration, "max_duration")
+        self._require_autocommit("Resuming a rebuild")
+        options = []
+        if maxdop is not None:
+            options.append(f"MAXDOP = {maxdop}")
+        if max_duration is not None:
+            options.append(f"MAX_DURATION = {max_duration} MINUTES")
+        sql = f"ALTER INDEX {_target(table, index)} RESUME"
+        if options:
+            sql += f" WITH ({', '.join(options)})"
+        self._run(sql)
+        return sql
+
+    def __repr__(self) -> str:
+        return f"IndexMaintenance(capabilities={self._capabilities!r})"
@@ -4,9 +4,30 @@ Licensed under the MIT license.
 Type stubs for mssql_python package - based on actual public API
 """
 
-from typing import Any, Dict, List, Mapping, Optional, Union, Tuple, Sequence, Callable, Iterator
+from typing import (
+    Any,
+    Dict,
+    List,
+    Mapping,
+    Optional,
+    Union,
+    Tuple,
+    Sequence,
+    Callable,
+    Iterator,
+    AsyncIterator,
+    Iterable,
+    FrozenSet,
+    Type,
+    ContextManager,
+    Pattern,
+    Set,
+)
 import datetime
+import io
 import logging
+import os
+import threading
 import pyarrow
 
 # GLOBALS - DB-API 2.0 Required Module Globals
@@ -18,25 +39,97 @@ threadsafety: int  # 1
 # Module Settings - Properties that can be get/set at module level
 lowercase: bool  # Controls column name case behavior
 native_uuid: bool  # Controls UUID type handling
+duplicate_column_names: str  # "last", "first" or "suffix" for repeated column names
+case_insensitive_columns: bool  # Case-insensitive row access without lowercasing names
+temporal_precision: str  # "microseconds" or "nanoseconds" for DATETIME2/DATETIMEOFFSET/TIME
+fips_mode: bool  # Restrict crypto to FIPS-validated providers and algorithms
 
 # Settings Class
 class Settings:
     lowercase: bool
     decimal_separator: str
     native_uuid: bool
+    duplicate_column_names: str
+    case_insensitive_columns: bool
+    temporal_precision: str
+    fips_mode: bool
     def __init__(self) -> None: ...
 
 # Module-level Configuration Functions
 def get_settings() -> Settings: ...
 def setDecimalSeparator(separator: str) -> None: ...
 def getDecimalSeparator() -> str: ...
-def pooling(max_size: int = 100, idle_timeout: int = 600, enabled: bool = True) -> None: ...
+def pooling(
+    max_size: int = 100,
+    idle_timeout: int = 600,
+    enabled: bool = True,
+    min_size: int = 0,
+    max_lifetime: int = 0,
+    validation_query: Optional[str] = None,
+    max_concurrent_statements: int = 0,
+    statement_queue_policy: str = "wait",
+    statement_queue_timeout: Optional[float] = None,
+) -> None: ...
+def pool_stats() -> List[Dict[str, Any]]: ...
+def enable_circuit_breaker(
+    failure_threshold: int = 5, reset_timeout: float = 30.0, half_open_probes: int = 1
+) -> None: ...
+def disable_circuit_breaker() -> None: ...
+def circuit_breaker_stats() -> List[Dict[str, Any]]: ...
+def check_read_only(sql: Union[str, bytes]) -> None: ...
+
+class StatementPolicy:
+    deny_statements: List[Tuple[str, ...]]
+    deny: List[Pattern[str]]
+    allow: List[Pattern[str]]
+    deny_cross_database: bool
+    databases: Set[str]
+    def __init__(
+        self,
+        deny_statements: Iterable[str] = ...,
+        deny: Sequence[Union[str, Pattern[str]]] = ...,
+        allow: Sequence[Union[str, Pattern[str]]] = ...,
+        deny_cross_database: bool = False,
+        databases: Iterable[str] = ...,
+    ) -> None: ...
+    def __call__(self, sql: str) -> None: ...
+    def check(self, sql: Union[str, bytes]) -> None: ...
+
+def check_statement_policy(
+    policy: Callable[[str], Optional[bool]], sql: Union[str, bytes]
+) -> None: ...
 def get_info_constants() -> Dict[str, int]: ...
+def local_instances() -> List[Dict[str, Any]]: ...
+def wait_for_server(
+    connection_str: str,
+    timeout: float = 60.0,
+    poll_interval: float = 1.0,
+    **connect_kwargs: Any,
+) -> Dict[str, Any]: ...
 
 # Logging Functions
 def setup_logging(mode: str = "file", log_level: int = logging.DEBUG) -> None: ...
 def get_logger() -> Optional[logging.Logger]: ...
 
+class RedactionPolicy:
+    masked_keys: FrozenSet[str]
+    log_sql: bool
+    parameters: str
+    def __init__(
+        self, masked_keys: Iterable[str] = (), log_sql: bool = True, parameters: str = "length"
+    ) -> None: ...
+    def masks(self, key: str) -> bool: ...
+    def sql(self, sql: str) -> str: ...
+    def parameters_text(
+        self, parameters: Union[Sequence[Any], Dict[str, Any]]
+    ) -> Optional[str]: ...
+    def scrub(self, message: str) -> str: ...
+    def to_dict(self) -> Dict[str, Any]: ...
+
+def configure_redaction(
+    masked_keys: Iterable[str] = (), log_sql: bool = True, parameters: str = "length"
+) -> RedactionPolicy: ...
+
 # DB-API 2.0 Type Objects
 # https://www.python.org/dev/peps/pep-0249/#type-objects
 class STRING:
@@ -89,12 +182,22 @@ class Warning(Exception):
     driver_error: str
     ddbc_error: str
     message: str
+    native_error: Optional[int]
+    sqlstate: Optional[str]
+    errors: List[Tuple[str, int, str]]
+    blocking_chain: Optional[List[Dict[str, Any]]]
+    deadlock_graph: Optional[str]
 
 class Error(Exception):
     def __init__(self, driver_error: str, ddbc_error: str) -> None: ...
     driver_error: str
     ddbc_error: str
     message: str
+    native_error: Optional[int]
+    sqlstate: Optional[str]
+    errors: List[Tuple[str, int, str]]
+    blocking_chain: Optional[List[Dict[str, Any]]]
+    deadlock_graph: Optional[str]
 
 class InterfaceError(Error):
     def __init__(self, driver_error: str, ddbc_error: str) -> None: ...
@@ -120,6 +223,388 @@ class ProgrammingError(DatabaseError):
 class NotSupportedError(DatabaseError):
     def __init__(self, driver_error: str, ddbc_error: str) -> None: ...
 
+class SchemaDriftError(ProgrammingError):
+    report: Optional["SchemaDriftReport"]
+    def __init__(
+        self, driver_error: str, ddbc_error: str, report: Optional["SchemaDriftReport"] = None
+    ) -> None: ...
+
+class TooManyParametersError(ProgrammingError):
+    parameter_count: int
+    limit: int
+    def __init__(self, parameter_count: int, limit: int) -> None: ...
+
+class StatementTooLargeError(ProgrammingError):
+    size: int
+    limit: int
+    def __init__(self, size: int, limit: int) -> None: ...
+
+class PingError(OperationalError):
+    def __init__(self, driver_error: str, ddbc_error: str) -> None: ...
+
+class LoginError(OperationalError):
+    hint: Optional[str]
+    def __init__(self, driver_error: str, ddbc_error: str, hint: Optional[str] = None) -> None: ...
+
+class InvalidCredentialsError(LoginError): ...
+class LoginDisabledError(LoginError): ...
+class DatabaseUnavailableError(LoginError): ...
+class ServerUnreachableError(LoginError): ...
+class CertificateTrustError(LoginError): ...
+
+# Native Error Number to Exception Class Mapping
+def register_error_class(
+    error_numbers: Union[int, Iterable[int]], exception_class: Type[Error]
+) -> None: ...
+def unregister_error_class(error_numbers: Union[int, Iterable[int]]) -> None: ...
+
+# Streaming Result Checksum
+class ResultChecksum:
+    algorithm: str
+    ordered: bool
+    row_count: int
+    row_hashes: Optional[List[str]]
+    def __init__(
+        self, algorithm: str = "sha256", ordered: bool = True, include_row_hashes: bool = False
+    ) -> None: ...
+    def update(self, rows: Any) -> None: ...
+    @property
+    def digest(self) -> str: ...
+
+# Streaming Result Set Comparison
+class RowDifference:
+    kind: str
+    key: Tuple[Any, ...]
+    columns: List[str]
+    row_a: Any
+    row_b: Any
+    def __init__(
+        self,
+        kind: str,
+        key: Tuple[Any, ...],
+        columns: Optional[List[str]] = None,
+        row_a: Any = None,
+        row_b: Any = None,
+    ) -> None: ...
+    def to_dict(self) -> Dict[str, Any]: ...
+
+def diff_results(
+    cursor_a: Cursor,
+    cursor_b: Cursor,
+    key_columns: Union[str, Sequence[str]],
+    batch_size: int = 10000,
+) -> Iterator[RowDifference]: ...
+
+# DBCC Command Output
+class DbccResult:
+    description: List[Tuple[Any, ...]]
+    rows: List[Row]
+    messages: List[Tuple[str, str]]
+    is_messages: bool
+    @property
+    def columns(self) -> List[str]: ...
+    def to_dict(self) -> Dict[str, Any]: ...
+    def __len__(self) -> int: ...
+
+# Long-running Statement Progress
+class ProgressEvent:
+    percent: Optional[float]
+    message: Optional[str]
+    source: str
+    def __init__(self, percent: Optional[float], message: Optional[str], source: str) -> None: ...
+    def to_dict(self) -> Dict[str, Any]: ...
+
+# Audit Event Stream
+class AuditEvent:
+    timestamp: datetime.datetime
+    operation: str
+    sql: str
+    principal: Optional[str]
+    server: Optional[str]
+    database: Optional[str]
+    application: Optional[str]
+    host: str
+    process_id: int
+    session_id: Optional[int]
+    duration: float
+    rowcount: Optional[int]
+    parameter_sets: int
+    error: Optional[str]
+    sqlstate: Optional[str]
+    def to_dict(self) -> Dict[str, Any]: ...
+
+class AuditStream:
+    normalize: bool
+    max_queue: int
+    delivered: int
+    dropped: int
+    failed: int
+    def __init__(
+        self,
+        target: Union[Callable[[AuditEvent], Any], str, Any],
+        normalize: bool = True,
+        max_queue: int = 10000,
+    ) -> None: ...
+    def flush(self, timeout: Optional[float] = None) -> bool: ...
+    def close(self, timeout: Optional[float] = 5.0) -> None: ...
+    def stats(self) -> Dict[str, int]: ...
+
+def enable_audit(
+    target: Union[Callable[[AuditEvent], Any], str, Any],
+    normalize: bool = True,
+    max_queue: int = 10000,
+) -> AuditStream: ...
+def disable_audit(timeout: Optional[float] = 5.0) -> None: ...
+
+# Driver Metrics
+def metrics() -> Dict[str, Dict[str, Any]]: ...
+def metrics_text() -> str: ...
+
+# Connection Defaults from Environment Variables
+def environment_defaults(
+    params: Optional[Mapping[str, str]] = None, environ: Optional[Mapping[str, str]] = None
+) -> Dict[str, str]: ...
+
+# asyncio API
+class AsyncConnection:
+    connection: "Connection"
+    def __init__(self, connection: "Connection") -> None: ...
+    @property
+    def closed(self) -> bool: ...
+    @property
+    def autocommit(self) -> bool: ...
+    async def setautocommit(self, value: bool) -> None: ...
+    def cursor(self) -> "AsyncCursor": ...
+    async def execute(self, sql: str, *params: Any) -> "AsyncCursor": ...
+    async def fetch_val(
+        self, sql: str, *params: Any, as_type: Optional[type] = None, default: Any = ...
+    ) -> Any: ...
+    async def fetch_row(self, sql: str, *params: Any) -> Optional["Row"]: ...
+    async def fetch_all(self, sql: str, *params: Any) -> List["Row"]: ...
+    async def commit(self) -> None: ...
+    async def rollback(self) -> None: ...
+    async def close(self) -> None: ...
+    async def __aenter__(self) -> "AsyncConnection": ...
+    async def __aexit__(self, *exc_info: Any) -> None: ...
+
+class AsyncCursor:
+    connection: AsyncConnection
+    cursor: "Cursor"
+    def __init__(self, connection: AsyncConnection, cursor: "Cursor") -> None: ...
+    @property
+    def description(self) -> Optional[List[Tuple[Any, ...]]]: ...
+    @property
+    def rowcount(self) -> int: ...
+    arraysize: int
+    async def execute(
+        self, operation: Union[str, bytes], *parameters: Any, **kwargs: Any
+    ) -> "AsyncCursor": ...
+    async def executemany(self, operation: str, seq_of_parameters: Sequence[Any]) -> None: ...
+    async def fetchval(self) -> Any: ...
+    async def fetchone(self) -> Optional["Row"]: ...
+    async def fetchmany(self, size: Optional[int] = None) -> List["Row"]: ...
+    async def fetchall(self) -> List["Row"]: ...
+    async def nextset(self) -> Optional[bool]: ...
+    async def close(self) -> None: ...
+    def __aiter__(self) -> AsyncIterator["Row"]: ...
+    async def __aenter__(self) -> "AsyncCursor": ...
+    async def __aexit__(self, *exc_info: Any) -> None: ...
+
+# Savepoint-scoped Nested Transaction
+class NestedTransaction:
+    connection: "Connection"
+    name: str
+    is_active: bool
+    def __init__(self, connection: "Connection", name: str) -> None: ...
+    def commit(self) -> None: ...
+    def rollback(self) -> None: ...
+    def __enter__(self) -> "NestedTransaction": ...
+    def __exit__(self, *args: Any) -> None: ...
+    def to_dict(self) -> Dict[str, Any]: ...
+
+# Read-write Splitting
+class RoutingPool:
+    writer: str
+    readers: List[str]
+    fallback_to_writer: bool
+    def __init__(
+        self,
+        writer: str,
+        readers: Sequence[str] = (),
+        fallback_to_writer: bool = True,
+        **connect_kwargs: Any,
+    ) -> None: ...
+    def reader(self) -> ContextManager["RoutingPool"]: ...
+    @staticmethod
+    def in_reader_scope() -> bool: ...
+    def connect(self, readonly: Optional[bool] = None) -> "Connection": ...
+    def execute(
+        self, sql: str, *params: Any, readonly: Optional[bool] = None
+    ) -> Union[List[Row], int]: ...
+
+# Extended Events Consumer
+class XEventSession:
+    name: str
+    scope: str
+    owned: bool
+    def __init__(
+        self, connection: "Connection", name: str, scope: str = "server", owned: bool = False
+    ) -> None: ...
+    @classmethod
+    def create(
+        cls,
+        connection: "Connection",
+        name: str,
+        events: Sequence[str],
+        actions: Sequence[str] = (),
+        max_memory_kb: int = 4096,
+        scope: str = "server",
+        keep: bool = False,
+    ) -> "XEventSession": ...
+    def read(self) -> List[Dict[str, Any]]: ...
+    def stream(
+        self, poll_interval: float = 1.0, stop: Optional[threading.Event] = None
+    ) -> Iterator[Dict[str, Any]]: ...
+    def close(self) -> None: ...
+    def __enter__(self) -> "XEventSession": ...
+    def __exit__(self, *args: Any) -> None: ...
+    def to_dict(self) -> Dict[str, Any]: ...
+
+# Index Maintenance
+class IndexMaintenance:
+    def __init__(self, connection: "Connection") -> None: ...
+    def capabilities(self) -> Dict[str, Any]: ...
+    def rebuild_index(
+        self,
+        table: str,
+        index: str = "ALL",
+        online: bool = True,
+        resumable: bool = True,
+        maxdop: Optional[int] = None,
+        max_duration: Optional[int] = None,
+    ) -> str: ...
+    def reorganize_index(
+        self, table: str, index: str = "ALL", compress_all_row_groups: bool = False
+    ) -> str: ...
+    def resumable_operations(self, table: Optional[str] = None) -> List[Dict[str, Any]]: ...
+    def pause(self, table: str, index: str) -> None: ...
+    def abort(self, table: str, index: str) -> None: ...
+    def resume(
+        self,
+        table: str,
+        index: str,
+        maxdop: Optional[int] = None,
+        max_duration: Optional[int] = None,
+    ) -> str: ...
+
+# Ephemeral Test Database
+class EphemeralDatabase:
+    name: Optional[str]
+    snapshot: Optional[str]
+    def __init__(
+        self,
+        connection: "Connection",
+        snapshot: Optional[str] = None,
+        prefix: str = "mssql_python_test_",
+        drop_orphans: bool = True,
+        max_orphan_age: float = 86400.0,
+    ) -> None: ...
+    def create(self) -> "EphemeralDatabase": ...
+    @property
+    def connection_string(self) -> str: ...
+    def connect(self, autocommit: bool = False) -> "Connection": ...
+    def pool(self, **connect_kwargs: Any) -> RoutingPool: ...
+    def close(self) -> None: ...
+    def __enter__(self) -> "EphemeralDatabase": ...
+    def __exit__(self, *args: Any) -> None: ...
+    def to_dict(self) -> Dict[str, Any]: ...
+
+# Retry Policy for Transient Errors
+class RetryPolicy:
+    max_attempts: int
+    errors: FrozenSet[int]
+    backoff: float
+    max_backoff: float
+    jitter: bool
+    def __init__(
+        self,
+        max_attempts: int = 3,
+        errors: Iterable[int] = ...,
+        backoff: float = 0.1,
+        max_backoff: float = 5.0,
+        jitter: bool = True,
+    ) -> None: ...
+    def is_retryable(self, error: BaseException, in_transaction: bool = False) -> bool: ...
+    def delay(self, attempt: int) -> float: ...
+    def call(self, func: Callable[[], Any], in_transaction: bool = False) -> Any: ...
+    def to_dict(self) -> Dict[str, Any]: ...
+
+# Bulk Copy Options
+class BulkCopyOptions:
+    batch_size: int
+    timeout: int
+    keep_identity: bool
+    check_constraints: bool
+    table_lock: bool
+    keep_nulls: bool
+    fire_triggers: bool
+    use_internal_transaction: bool
+    order: Optional[List[Tuple[str, str]]]
+    def __init__(
+        self,
+        batch_size: int = 0,
+        timeout: int = 30,
+        keep_identity: bool = False,
+        check_constraints: bool = False,
+        table_lock: bool = False,
+        keep_nulls: bool = False,
+        fire_triggers: bool = False,
+        use_internal_transaction: bool = False,
+        order: Optional[Sequence[Union[str, Tuple[str, str]]]] = None,
+    ) -> None: ...
+    def to_dict(self) -> Dict[str, Any]: ...
+
+# Multi-table Load Specification
+class TableLoad:
+    table_name: str
+    rows: Any
+    columns: Optional[List[str]]
+    keep_identity: bool
+    def __init__(
+        self,
+        table_name: str,
+        rows: Any,
+        columns: Optional[Sequence[str]] = None,
+        keep_identity: bool = False,
+    ) -> None: ...
+
+# Bulk Load Schema Drift Report
+class SchemaDriftReport:
+    table_name: str
+    missing_columns: List[str]
+    extra_columns: List[str]
+    unfillable_columns: List[str]
+    type_mismatches: List[Any]
+    added_columns: List[str]
+    @property
+    def has_drift(self) -> bool: ...
+    @property
+    def is_compatible(self) -> bool: ...
+    def to_dict(self) -> Dict[str, Any]: ...
+    def summary(self) -> str: ...
+    def raise_if_incompatible(self) -> None: ...
+
+# Stored procedure OUTPUT parameters
+class Output:
+    sql_type: Optional[str]
+    def __init__(self, sql_type: Optional[str] = None) -> None: ...
+    @property
+    def has_input(self) -> bool: ...
+
+class InOut(Output):
+    value: Any
+    def __init__(self, value: Any, sql_type: Optional[str] = None) -> None: ...
+
 # Row Object
 class Row:
     """
@@ -169,10 +654,17 @@ class Cursor:
     ]
     rowcount: int
     arraysize: int
+    internal_fetch_rows: Optional[int]
+    retry_policy: Optional[RetryPolicy]
+    priority: Optional[str]
+    capture_wait_stats: bool
 
     # Extension Attributes
     closed: bool
     messages: List[str]
+    plan: Optional[str]
+    return_value: Any
+    result_sets: List[List[Row]]
 
     @property
     def rownumber(self) -> int: ...
@@ -182,16 +674,23 @@ class Cursor:
 
     # DB-API 2.0 Required Methods
     def callproc(
-        self, procname: str, parameters: Optional[Sequence[Any]] = None
-    ) -> Optional[Sequence[Any]]: ...
+        self,
+        procname: str,
+        parameters: Optional[Union[Sequence[Any], Mapping[str, Any]]] = None,
+    ) -> Optional[Union[Sequence[Any], Dict[str, Any]]]: ...
     def close(self) -> None: ...
+    def mogrify(self, operation: str, *parameters: Any) -> str: ...
     def execute(
         self,
-        operation: str,
+        operation: Union[str, bytes],
         *parameters: Any,
         use_prepare: bool = True,
         reset_cursor: bool = True,
+        capture_plan: Optional[str] = None,
+        hints: Optional[Sequence[str]] = None,
+        idempotent: bool = False,
     ) -> "Cursor": ...
+    def execute_script(self, script: Union[str, bytes, Iterable[Union[str, bytes]]]) -> int: ...
     def executemany(
         self,
         operation: str,
@@ -200,7 +699,27 @@ class Cursor:
     def fetchone(self) -> Optional[Row]: ...
     def fetchmany(self, size: Optional[int] = None) -> List[Row]: ...
     def fetchall(self) -> List[Row]: ...
+    def fetch_lob_stream(
+        self, column_index: int, chunk_size: int = 65536
+    ) -> Optional[Union[io.BufferedReader, io.TextIOWrapper]]: ...
+    def add_column_transform(
+        self, column: Union[str, int], transform: Union[str, Callable[[Any], Any]]
+    ) -> None: ...
+    def clear_column_transforms(self) -> None: ...
+    def fetchbatches(
+        self, size: Optional[int] = None, prefetch: bool = True
+    ) -> Iterator[List[Row]]: ...
     def nextset(self) -> Optional[bool]: ...
+    def stats(self) -> Dict[str, Optional[str]]: ...
+    def wait_stats(self) -> Optional[Dict[str, Dict[str, int]]]: ...
+    def progress(self) -> List[Dict[str, Any]]: ...
+    def extended_description(self) -> List[Dict[str, Any]]: ...
+    @property
+    def timeout(self) -> int: ...
+    @timeout.setter
+    def timeout(self, value: Optional[float]) -> None: ...
+    def settimeout(self, seconds: Optional[float]) -> None: ...
+    def cancel(self) -> None: ...
     def setinputsizes(self, sizes: List[Union[int, Tuple[Any, ...]]]) -> None: ...
     def setoutputsize(self, size: int, column: Optional[int] = None) -> None: ...
 
@@ -208,6 +727,43 @@ class Cursor:
     def arrow_batch(self, batch_size: int = 8192) -> pyarrow.RecordBatch: ...
     def arrow(self, batch_size: int = 8192) -> pyarrow.Table: ...
     def arrow_reader(self, batch_size: int = 8192) -> pyarrow.RecordBatchReader: ...
+    def fetch_arrow_batches(self, batch_size: int = 8192) -> Iterator[pyarrow.RecordBatch]: ...
+
+    # Result Checksum Extension Methods
+    def execute_dbcc(
+        self, command: str, *parameters: Any, tableresults: bool = True
+    ) -> List[DbccResult]: ...
+    def checksum(
+        self,
+        algorithm: str = "sha256",
+        ordered: bool = True,
+        include_row_hashes: bool = False,
+        batch_size: int = 1000,
+    ) -> ResultChecksum: ...
+
+    # Bulk Load Extension Methods
+    def insert_rows(
+        self,
+        table_name: str,
+        columns: Sequence[str],
+        rows: Iterable[Sequence[Any]],
+        max_rows: int = 1000,
+    ) -> int: ...
+    def check_schema_drift(
+        self,
+        table_name: str,
+        source_columns: Union[Mapping[str, Any], Sequence[Any]],
+        add_missing_columns: bool = False,
+    ) -> SchemaDriftReport: ...
+    def write_dataframe(
+        self,
+        table_name: str,
+        df: Any,
+        chunk_size: int = 10000,
+        create_table: bool = False,
+        index: bool = False,
+        **bulkcopy_kwargs: Any,
+    ) -> Dict[str, Any]: ...
 
 # DB-API 2.0 Connection Object
 # https://www.python.org/dev/peps/pep-0249/#connection-objects
@@ -236,6 +792,64 @@ class Connection:
     @timeout.setter
     def timeout(self, value: int) -> None: ...
     @property
+    def workload(self) -> Optional[str]: ...
+    @property
+    def read_only(self) -> bool: ...
+    @property
+    def server(self) -> Optional[str]: ...
+    @property
+    def connect_timings(self) -> Dict[str, Optional[float]]: ...
+    def diagnose_connect(self, timeout: float = 5.0) -> Dict[str, Optional[float]]: ...
+    def workload_group(self) -> Optional[str]: ...
+    def snapshot_isolation_enabled(self) -> bool: ...
+    def read_committed_snapshot_enabled(self) -> bool: ...
+    @property
+    def rstrip_char(self) -> bool: ...
+    @rstrip_char.setter
+    def rstrip_char(self, value: bool) -> None: ...
+    @property
+    def stable_parameter_sizes(self) -> bool: ...
+    @stable_parameter_sizes.setter
+    def stable_parameter_sizes(self, value: bool) -> None: ...
+    @property
+    def auto_parameterize(self) -> bool: ...
+    @auto_parameterize.setter
+    def auto_parameterize(self, value: bool) -> None: ...
+    @property
+    def statement_policy(self) -> Optional[Callable[[str], Optional[bool]]]: ...
+    @statement_policy.setter
+    def statement_policy(self, value: Optional[Callable[[str], Optional[bool]]]) -> None: ...
+    @property
+    def state(self) -> str: ...
+    @property
+    def in_transaction(self) -> bool: ...
+    @property
+    def session_id(self) -> int: ...
+    def usage(self, reset: bool = False) -> Dict[str, int]: ...
+    @property
+    def maintenance(self) -> IndexMaintenance: ...
+    @property
+    def statement_cache_size(self) -> int: ...
+    @statement_cache_size.setter
+    def statement_cache_size(self, value: int) -> None: ...
+    def clear_statement_cache(self) -> None: ...
+    @property
+    def auto_drain_results(self) -> bool: ...
+    @auto_drain_results.setter
+    def auto_drain_results(self, value: bool) -> None: ...
+    @property
+    def diagnose_blocking(self) -> bool: ...
+    @diagnose_blocking.setter
+    def diagnose_blocking(self, value: bool) -> None: ...
+    @property
+    def capture_deadlock_graphs(self) -> bool: ...
+    @capture_deadlock_graphs.setter
+    def capture_deadlock_graphs(self, value: bool) -> None: ...
+    @property
+    def priority(self) -> str: ...
+    @priority.setter
+    def priority(self, value: str) -> None: ...
+    @property
     def autocommit(self) -> bool: ...
     @autocommit.setter
     def autocommit(self, value: bool) -> None: ...
@@ -248,6 +862,9 @@ class Connection:
         attrs_before: Optional[Dict[int, Union[int, str, bytes]]] = None,
         timeout: int = 0,
         native_uuid: Optional[bool] = None,
+        rstrip_char: bool = False,
+        workload: Optional[str] = None,
+        require_row_versioning: Optional[str] = None,
         **kwargs: Any,
     ) -> None: ...
 
@@ -258,11 +875,55 @@ class Connection:
     def close(self) -> None: ...
 
     # Extension Methods
+    def begin_nested(self) -> NestedTransaction: ...
+    def savepoint(self, name: str) -> NestedTransaction: ...
+    def rollback_to(self, name: str) -> None: ...
+    def set_isolation_level(self, level: Union[str, int]) -> None: ...
+    @property
+    def isolation_level(self) -> Optional[str]: ...
+    def create_snapshot(
+        self, snapshot: str, database: Optional[str] = None, directory: Optional[str] = None
+    ) -> str: ...
+    def revert_to_snapshot(
+        self, snapshot: str, disconnect_others: bool = True, timeout: float = 60.0
+    ) -> None: ...
+    def drop_snapshot(self, snapshot: str) -> None: ...
+    def snapshots(self, database: Optional[str] = None) -> List[Dict[str, Any]]: ...
+    def databases(self, include_system: bool = False) -> List[Dict[str, Any]]: ...
+    def catalog(
+        self,
+        databases: Optional[Iterable[str]] = None,
+        kinds: Iterable[str] = ("tables", "columns", "routines"),
+        batch_size: int = 20,
+    ) -> Dict[str, List[Any]]: ...
+    def availability_groups(self) -> List[Dict[str, Any]]: ...
+    def require_primary(
+        self, group: Optional[str] = None, require_healthy: bool = True
+    ) -> Dict[str, Any]: ...
+    def fulltext_population_status(self, catalog: str) -> Dict[str, Any]: ...
+    def wait_for_fulltext_population(
+        self, catalog: str, timeout: Optional[float] = None, poll_interval: float = 1.0
+    ) -> Dict[str, Any]: ...
+    def create_xevent_session(
+        self,
+        name: str,
+        events: Sequence[str],
+        actions: Sequence[str] = (),
+        max_memory_kb: int = 4096,
+        scope: str = "server",
+        keep: bool = False,
+    ) -> XEventSession: ...
+    def attach_xevent_session(self, name: str, scope: str = "server") -> XEventSession: ...
+    def ping(self, timeout: float = 5) -> float: ...
     def setautocommit(self, value: bool = False) -> None: ...
     def setencoding(self, encoding: Optional[str] = None, ctype: Optional[int] = None) -> None: ...
     def getencoding(self) -> Dict[str, Union[str, int]]: ...
     def setdecoding(
-        self, sqltype: int, encoding: Optional[str] = None, ctype: Optional[int] = None
+        self,
+        sqltype: int,
+        encoding: Optional[str] = None,
+        ctype: Optional[int] = None,
+        fallback: Optional[str] = None,
     ) -> None: ...
     def getdecoding(self, sqltype: int) -> Dict[str, Union[str, int]]: ...
     def set_attr(self, attribute: int, value: Union[int, str, bytes, bytearray]) -> None: ...
@@ -271,6 +932,20 @@ class Connection:
     def remove_output_converter(self, sqltype: Union[int, type]) -> None: ...
     def clear_output_converters(self) -> None: ...
     def execute(self, sql: str, *args: Any) -> Cursor: ...
+    def fetch_val(
+        self, sql: str, *args: Any, as_type: Optional[type] = None, default: Any = ...
+    ) -> Any: ...
+    def fetch_row(self, sql: str, *args: Any) -> Optional[Row]: ...
+    def fetch_all(self, sql: str, *args: Any) -> List[Row]: ...
+    def object_id(
+        self, schema: Optional[str], name: str, object_type: Optional[str] = None
+    ) -> Optional[int]: ...
+    def table_exists(self, schema: Optional[str], name: str) -> bool: ...
+    def column_exists(self, schema: Optional[str], table: str, column: str) -> bool: ...
+    def acquire_app_lock(
+        self, name: str, mode: str = "Exclusive", timeout: Optional[float] = None
+    ) -> bool: ...
+    def release_app_lock(self, name: str) -> None: ...
     def batch_execute(
         self,
         statements: List[str],
@@ -278,12 +953,48 @@ class Connection:
         reuse_cursor: Optional[Cursor] = None,
         auto_close: bool = False,
     ) -> Tuple[List[Union[List[Row], int]], Cursor]: ...
+    def load_tables(
+        self,
+        loads: List[TableLoad],
+        order: str = "dependencies",
+        batch_size: int = 1000,
+    ) -> Dict[str, int]: ...
+    def bulk_export_parallel(
+        self,
+        table_name: str,
+        num_streams: int,
+        output_dir: str = ".",
+        file_format: str = "csv",
+        split_column: Optional[str] = None,
+        split: str = "auto",
+        batch_size: int = 10000,
+    ) -> Dict[str, Any]: ...
+    def clone_table(
+        self,
+        source: str,
+        dest: str,
+        with_data: bool = True,
+        replace: bool = True,
+        source_connection: Optional["Connection"] = None,
+    ) -> int: ...
+    def execute_with_progress(
+        self,
+        sql: str,
+        callback: Callable[[ProgressEvent], Any],
+        poll_interval: Optional[float] = 1.0,
+    ) -> List[Tuple[str, str]]: ...
     def getinfo(self, info_type: int) -> Union[str, int, bool, None]: ...
 
     # Context Manager Support
     def __enter__(self) -> "Connection": ...
     def __exit__(self, *args: Any) -> None: ...
 
+# Cancellable Connect
+class ConnectAttempt:
+    cancelled: bool
+    def __init__(self) -> None: ...
+    def cancel_connect(self) -> None: ...
+
 # Module Connection Function
 def connect(
     connection_str: str = "",
@@ -291,6 +1002,14 @@ def connect(
     attrs_before: Optional[Dict[int, Union[int, str, bytes]]] = None,
     timeout: int = 0,
     native_uuid: Optional[bool] = None,
+    rstrip_char: bool = False,
+    workload: Optional[str] = None,
+    require_row_versioning: Optional[str] = None,
+    login_timeout: Optional[int] = None,
+    attempt: Optional[ConnectAttempt] = None,
+    password_file: Optional[Union[str, os.PathLike[str], int]] = None,
+    token_file: Optional[Union[str, os.PathLike[str], int]] = None,
+    read_only: bool = False,
     **kwargs: Any,
 ) -> Connection: ...
 
@@ -332,6 +1051,7 @@ SQL_TXN_READ_UNCOMMITTED: int
 SQL_TXN_READ_COMMITTED: int
 SQL_TXN_REPEATABLE_READ: int
 SQL_TXN_SERIALIZABLE: int
+SQL_TXN_SS_SNAPSHOT: int
 
 # Access Mode Constants
 SQL_MODE_READ_WRITE: int
@@ -7,18 +7,49 @@ Parameter style conversion helpers for mssql-python.
 Supports both qmark (?) and pyformat (%(name)s) parameter styles.
 Includes context-aware scanning for qmark and pyformat detection,
 skipping characters inside bracketed identifiers, string literals,
-quoted identifiers, and SQL comments.
+quoted identifiers, and SQL comments, expansion of ``IN ?`` placeholders
+bound to Python sequences, fitting statements into the parameter limit of a
+request, and rendering of parameters as T-SQL literals.
 
 Reference: https://www.python.org/dev/peps/pep-0249/#paramstyle
 """
 
+import datetime
+import decimal
+import json
+import re
+import uuid
 from typing import Dict, List, Tuple, Any, Union
+from mssql_python.exceptions import TooManyParametersError
 from mssql_python.logging import logger
 
 # Distinctive marker for escaped percent signs during pyformat conversion
 # Uses a unique prefix/suffix that's extremely unlikely to appear in real SQL
 _ESCAPED_PERCENT_MARKER = "__MSSQL_PYFORMAT_ESCAPED_PERCENT_PLACEHOLDER__"
 
+# Sequences longer than this bound to an ``IN ?`` placeholder are sent as a single
+# JSON array parameter (unpacked server-side with OPENJSON) instead of one parameter
+# per value, keeping well below SQL Server's limit of 2100 parameters per request.
+IN_CLAUSE_JSON_THRESHOLD = 1000
+
+# Quick pre-check before the context-aware scan
+_IN_PLACEHOLDER_HINT = re.compile(r"\bIN\s*\?", re.IGNORECASE)
+
+# Parameters SQL Server accepts in one request: 2100, less the statement handle
+# that sp_prepexec passes along with them
+MAX_PARAMETERS = 2099
+
+# Rows of one INSERT ... VALUES table value constructor
+MAX_VALUES_ROWS = 1000
+
+# An explicit IN list made of placeholders only: IN (?, ?, ...)
+_EXPLICIT_IN_LIST_RE = re.compile(r"\bIN\s*\(\s*\?(?:\s*,\s*\?)*\s*\)", re.IGNORECASE)
+
+# INSERT ... VALUES followed by row constructors (checked to be placeholders only)
+_MULTIROW_INSERT_RE = re.compile(
+    r"^(\s*INSERT\b[^?']*?\bVALUES)\s*(\(.*\))\s*;?\s*$", re.IGNORECASE | re.DOTALL
+)
+
 
 def _skip_quoted_context(sql: str, i: int, length: int) -> int:
     """
@@ -177,7 +208,7 @@ def parse_pyformat_params(sql: str) -> List[str]:
     logger.debug(
         "parse_pyformat_params: Starting parse - sql_length=%d, sql_preview=%s",
         len(sql),
-        sql[:100] if len(sql) > 100 else sql,
+        logger.redaction.sql(sql[:100]),
     )
     params = []
     i = 0
@@ -258,7 +289,7 @@ def convert_pyformat_to_qmark(sql: str, param_dict: Dict[str, Any]) -> Tuple[str
     )
     logger.debug(
         "convert_pyformat_to_qmark: SQL preview: %s",
-        sql[:200] if len(sql) > 200 else sql,
+        logger.redaction.sql(sql[:200]),
     )
     logger.debug(
         "convert_pyformat_to_qmark: Parameters provided: %s",
@@ -360,7 +391,7 @@ def convert_pyformat_to_qmark(sql: str, param_dict: Dict[str, Any]) -> Tuple[str
     )
     logger.debug(
         "convert_pyformat_to_qmark: Result SQL preview: %s",
-        rewritten_sql[:200] if len(rewritten_sql) > 200 else rewritten_sql,
+        logger.redaction.sql(rewritten_sql[:200]),
     )
 
     logger.debug(
@@ -486,3 +517,694 @@ def detect_and_convert_parameters(
     raise TypeError(
         f"Parameters must be tuple, list, dict, or None. " f"Got {type(parameters).__name__}"
     )
+
+
+def is_in_list(value: Any) -> bool:
+    """Return True for Python collections that expand into an IN list."""
+    return isinstance(value, (list, tuple, set, frozenset, range))
+
+
+def _qmark_placeholders(sql: str) -> List[Tuple[int, bool]]:
+    """
+    Return (position, follows_in) for every qmark placeholder outside quoted contexts.
+
+    follows_in is True when the placeholder directly follows the IN keyword
+    (``x IN ?`` / ``x NOT IN ?``), ignoring whitespace.
+    """
+    placeholders = []
+    i = 0
+    length = len(sql)
+    while i < length:
+        skipped = _skip_quoted_context(sql, i, length)
+        if skipped >= 0:
+            i = skipped
+            continue
+        if sql[i] == "?":
+            j = i - 1
+            while j >= 0 and sql[j].isspace():
+                j -= 1
+            follows_in = (
+                j >= 1
+                and sql[j - 1 : j + 1].upper() == "IN"
+                and (j < 2 or not (sql[j - 2].isalnum() or sql[j - 2] in "_@#$"))
+            )
+            placeholders.append((i, follows_in))
+        i += 1
+    return placeholders
+
+
+def is_single_in_placeholder(sql: str) -> bool:
+    """Return True if the only qmark placeholder in sql is an ``IN ?`` placeholder."""
+    if not _IN_PLACEHOLDER_HINT.search(sql):
+        return False
+    placeholders = _qmark_placeholders(sql)
+    return len(placeholders) == 1 and placeholders[0][1]
+
+
+def _json_in_list(values: List[Any]) -> Tuple[str, str]:
+    """
+    Build the OPENJSON subquery and JSON array parameter for a long IN list.
+
+    Raises:
+        TypeError: If the values are not all of one supported type.
+    """
+    kinds = {type(v) for v in values if v is not None}
+    if kinds and kinds <= {int} and all(-(2**63) <= v < 2**63 for v in values if v is not None):
+        sql_type, items = "BIGINT", values
+    elif kinds and kinds <= {int, float}:
+        sql_type, items = "FLOAT", values
+    elif kinds and kinds <= {str}:
+        longest = max(len(v) for v in values if v is not None)
+        sql_type = "NVARCHAR(4000)" if longest <= 4000 else "NVARCHAR(MAX)"
+        items = values
+    elif kinds == {decimal.Decimal}:
+        sql_type = "DECIMAL(38, 10)"
+        items = [None if v is None else str(v) for v in values]
+    elif kinds == {uuid.UUID}:
+        sql_type = "UNIQUEIDENTIFIER"
+        items = [None if v is None else str(v) for v in values]
+    elif kinds == {datetime.date}:
+        sql_type = "DATE"
+        items = [None if v is None else v.isoformat() for v in values]
+    elif kinds == {datetime.datetime}:
+        sql_type = "DATETIME2"
+        items = [None if v is None else v.isoformat() for v in values]
+    else:
+        raise TypeError(
+            f"IN lists longer than {IN_CLAUSE_JSON_THRESHOLD} values must contain values of a "
+            f"single type (int, float, str, Decimal, UUID, date or datetime); got "
+            f"{sorted(k.__name__ for k in kinds) or ['only None']}"
+        )
+    subquery = f"(SELECT [value] FROM OPENJSON(?) WITH ([value] {sql_type} '$'))"
+    return subquery, json.dumps(items)
+
+
+def expand_in_clauses(
+    sql: str, parameters: List[Any], json_threshold: int = IN_CLAUSE_JSON_THRESHOLD
+) -> Tuple[str, List[Any]]:
+    """
+    Expand ``IN ?`` placeholders bound to Python sequences into parameter lists.
+
+    Each ``IN ?`` whose parameter is a list, tuple, set, frozenset or range is
+    rewritten to ``IN (?, ?, ...)`` with one parameter per value, so IN lists never
+    have to be built by string formatting. Sequences longer than json_threshold
+    are sent as one JSON array parameter unpacked with OPENJSON (SQL Server 2016+).
+    An empty sequence becomes an empty subquery: ``IN`` matches nothing and
+    ``NOT IN`` matches every row.
+
+    Args:
+        sql: SQL query with qmark placeholders
+        parameters: Positional parameters, one per placeholder
+        json_threshold: Longest sequence expanded into individual parameters
+
+    Returns:
+        Tuple of (rewritten_sql, parameters). Both are returned unchanged when no
+        ``IN ?`` placeholder is bound to a sequence.
+
+    Raises:
+        TypeError: If a long sequence mixes value types (see json_threshold).
+
+    Examples:
+        >>> expand_in_clauses("SELECT * FROM t WHERE id IN ? AND a = ?", [[1, 2], "x"])
+        ("SELECT * FROM t WHERE id IN (?, ?) AND a = ?", [1, 2, "x"])
+    """
+    if not any(is_in_list(p) for p in parameters) or not _IN_PLACEHOLDER_HINT.search(sql):
+        return sql, parameters
+
+    placeholders = _qmark_placeholders(sql)
+    if len(placeholders) != len(parameters):
+        # Let parameter binding report the count mismatch
+        return sql, parameters
+
+    pieces = []
+    expanded: List[Any] = []
+    last = 0
+    for (position, follows_in), value in zip(placeholders, parameters):
+        if not (follows_in and is_in_list(value)):
+            expanded.append(value)
+            continue
+        values = list(value)
+        if not values:
+            replacement = "(SELECT NULL WHERE 1 = 0)"
+        elif len(values) > json_threshold:
+            replacement, json_param = _json_in_list(values)
+            expanded.append(json_param)
+        else:
+            replacement = "(" + ", ".join("?" * len(values)) + ")"
+            expanded.extend(values)
+        pieces.append(sql[last:position])
+        pieces.append(replacement)
+        last = position + 1
+    pieces.append(sql[last:])
+
+    logger.debug(
+        "expand_in_clauses: Expanded IN lists - param_count %d -> %d",
+        len(parameters),
+        len(expanded),
+    )
+    return "".join(pieces), expanded
+
+
+def _collapse_explicit_in_lists(
+    sql: str, parameters: List[Any], json_threshold: int
+) -> Tuple[str, List[Any]]:
+    """Send explicit ``IN (?, ..., ?)`` lists longer than json_threshold as JSON arrays."""
+    index_of = {position: index for index, (position, _) in enumerate(_qmark_placeholders(sql))}
+    pieces = []
+    collapsed: List[Any] = []
+    last = 0
+    next_param = 0
+    for match in _EXPLICIT_IN_LIST_RE.finditer(sql):
+        marks = [match.start() + i for i, char in enumerate(match.group()) if char == "?"]
+        if len(marks) <= json_threshold or any(mark not in index_of for mark in marks):
+            continue
+        first = index_of[marks[0]]
+        values = parameters[first : first + len(marks)]
+        try:
+            subquery, json_param = _json_in_list(values)
+        except TypeError:
+            continue
+        collapsed.extend(parameters[next_param:first])
+        collapsed.append(json_param)
+        next_param = first + len(marks)
+        in_keyword = match.group()[:2]
+        pieces.append(sql[last : match.start()])
+        pieces.append(f"{in_keyword} {subquery}")
+        last = match.end()
+    if not pieces:
+        return sql, parameters
+    pieces.append(sql[last:])
+    collapsed.extend(parameters[next_param:])
+    return "".join(pieces), collapsed
+
+
+def _split_multirow_insert(
+    sql: str, parameters: List[Any], limit: int
+) -> List[Tuple[str, List[Any]]]:
+    """Split INSERT ... VALUES (?, ?), (?, ?), ... into statements within limit."""
+    match = _MULTIROW_INSERT_RE.match(sql)
+    if not match:
+        return []
+    rows = re.sub(r"\s+", "", match.group(2))
+    width = rows.find(")") // 2
+    if width < 1 or len(parameters) % width:
+        return []
+    row = "(" + ",".join("?" * width) + ")"
+    row_count = len(parameters) // width
+    if rows != ",".join([row] * row_count):
+        return []
+    rows_per_statement = min(MAX_VALUES_ROWS, limit // width)
+    if rows_per_statement < 1:
+        return []
+    placeholder = "(" + ", ".join("?" * width) + ")"
+    statements = []
+    for start in range(0, row_count, rows_per_statement):
+        chunk_rows = min(rows_per_statement, row_count - start)
+        statements.append(
+            (
+                f"{match.group(1)} " + ", ".join([placeholder] * chunk_rows),
+                parameters[start * width : (start + chunk_rows) * width],
+            )
+        )
+    return statements
+
+
+def fit_parameter_limit(
+    sql: str,
+    parameters: List[Any],
+    limit: int = MAX_PARAMETERS,
+    json_threshold: int = IN_CLAUSE_JSON_THRESHOLD,
+) -> List[Tuple[str, List[Any]]]:
+    """
+    Rewrite a statement with more parameters than one request takes into ones that fit.
+
+    Two shapes are handled: explicit ``IN (?, ?, ...)`` lists longer than
+    json_threshold are sent as one JSON array parameter (see expand_in_clauses),
+    and a multi-row ``INSERT ... VALUES (?, ?), (?, ?), ...`` is split into several
+    INSERT statements of at most limit parameters and 1000 rows each.
+
+    Args:
+        sql: SQL query with qmark placeholders
+        parameters: Positional parameters, one per placeholder
+        limit: Most parameters a statement may have
+        json_threshold: Longest IN list kept as individual parameters
+
+    Returns:
+        List of (sql, parameters) statements to run in order; the statement
+        itself when it is within limit.
+
+    Raises:
+        TooManyParametersError: If the statement is over limit and has neither shape.
+    """
+    if len(parameters) <= limit:
+        return [(sql, parameters)]
+    collapsed_sql, collapsed = _collapse_explicit_in_lists(sql, parameters, json_threshold)
+    if len(collapsed) <= limit:
+        logger.debug(
+            "fit_parameter_limit: Sent IN lists as JSON - param_count %d -> %d",
+            len(parameters),
+            len(collapsed),
+        )
+        return [(collapsed_sql, collapsed)]
+    statements = _split_multirow_insert(sql, parameters, limit)
+    if statements:
+        logger.debug(
+            "fit_parameter_limit: Split INSERT of %d parameters into %d statements",
+            len(parameters),
+            len(statements),
+        )
+        return statements
+    raise TooManyParametersError(len(parameters), limit)
+
+
+def sql_literal(value: Any) -> str:
+    """
+    Render a parameter value as a T-SQL literal.
+
+    Raises:
+        TypeError: If the value type has no literal form.
+        ValueError: If the value cannot be represented (NaN or infinite numbers).
+
+    Examples:
+        >>> sql_literal("it's")
+        "N'it''s'"
+
+        >>> sql_literal(b"\\x01\\xff")
+        '0x01FF'
+    """
+    if value is None:
+        return "NULL"
+    if isinstance(value, bool):
+        return "1" if value else "0"
+    if isinstance(value, int):
+        return str(value)
+    if isinstance(value, float):
+        if value != value or value in (float("inf"), float("-inf")):
+            raise ValueError(f"{value!r} has no T-SQL literal form")
+        return repr(value)
+    if isinstance(value, decimal.Decimal):
+        if not value.is_finite():
+            raise ValueError(f"{value!r} has no T-SQL literal form")
+        return format(value, "f")
+    if isinstance(value, str):
+        return "N'" + value.replace("'", "''") + "'"
+    if isinstance(value, (bytes, bytearray, memoryview)):
+        return "0x" + bytes(value).hex().upper()
+    if isinstance(value, datetime.datetime):
+        target = "DATETIMEOFFSET" if value.tzinfo is not None else "DATETIME2"
+        return f"CAST('{value.isoformat()}' AS {target})"
+    if isinstance(value, datetime.date):
+        return f"CAST('{value.isoformat()}' AS DATE)"
+    if isinstance(value, datetime.time):
+        return f"CAST('{value.isoformat()}' AS TIME)"
+    if isinstance(value, uuid.UUID):
+        return f"CAST('{value}' AS UNIQUEIDENTIFIER)"
+    raise TypeError(f"Cannot render values of type {type(value).__name__} as a T-SQL literal")
+
+
+def substitute_literals(sql: str, parameters: List[Any]) -> str:
+    """
+    Replace each qmark placeholder in sql with the literal form of its parameter.
+
+    Placeholders inside string literals, quoted identifiers and comments are left
+    alone, as during execution.
+
+    Raises:
+        ValueError: If the number of parameters does not match the placeholders.
+        TypeError: If a parameter has no literal form (see sql_literal).
+    """
+    placeholders = _qmark_placeholders(sql) if "?" in sql else []
+    if len(placeholders) != len(parameters):
+        raise ValueError(
+            f"The SQL contains {len(placeholders)} parameter markers, "
+            f"but {len(parameters)} parameters were supplied"
+        )
+    pieces = []
+    last = 0
+    for (position, _), value in zip(placeholders, parameters):
+        pieces.append(sql[last:position])
+        pieces.append(sql_literal(value))
+        last = position + 1
+    pieces.append(sql[last:])
+    return "".join(pieces)
+
+
+# Query hints accepted by apply_query_hints, matched case-insensitively. Anything else
+# is rejected so that hints can never smuggle arbitrary SQL into a statement.
+_KEYWORD_HINTS = {
+    "RECOMPILE",
+    "OPTIMIZE FOR UNKNOWN",
+    "KEEP PLAN",
+    "KEEPFIXED PLAN",
+    "ROBUST PLAN",
+    "FORCE ORDER",
+    "EXPAND VIEWS",
+    "HASH JOIN",
+    "LOOP JOIN",
+    "MERGE JOIN",
+    "HASH GROUP",
+    "ORDER GROUP",
+    "CONCAT UNION",
+    "HASH UNION",
+    "MERGE UNION",
+    "IGNORE_NONCLUSTERED_COLUMNSTORE_INDEX",
+    "NO_PERFORMANCE_SPOOL",
+    "DISABLE_OPTIMIZED_PLAN_FORCING",
+}
+_NUMERIC_HINT_RE = re.compile(r"^(MAXDOP|FAST|MAXRECURSION|QUERYTRACEON)\s+(\d+)$", re.IGNORECASE)
+_GRANT_HINT_RE = re.compile(
+    r"^(MAX_GRANT_PERCENT|MIN_GRANT_PERCENT)\s*=\s*(\d+(?:\.\d+)?)$", re.IGNORECASE
+)
+_USE_HINT_RE = re.compile(r"^USE\s+HINT\s*\((.*)\)$", re.IGNORECASE | re.DOTALL)
+_USE_HINT_NAME_RE = re.compile(r"^'?([A-Z][A-Z0-9_]*)'?$", re.IGNORECASE)
+
+
+def _normalize_hints(hints: List[str]) -> List[str]:
+    """
+    Validate query hints and return them in canonical T-SQL form.
+
+    USE HINT names may be given as "USE HINT('NAME', ...)" or as the bare name
+    (any name containing an underscore, e.g. "DISABLE_OPTIMIZER_ROWGOAL"); they are
+    combined into a single USE HINT(...) entry.
+
+    Raises:
+        ValueError: If a hint is not recognized.
+    """
+    options = []
+    use_hints = []
+    for hint in hints:
+        if not isinstance(hint, str):
+            raise ValueError(f"Query hints must be strings, got {type(hint).__name__}")
+        text = " ".join(hint.split()).upper()
+        if text in _KEYWORD_HINTS:
+            options.append(text)
+            continue
+        match = _NUMERIC_HINT_RE.match(text)
+        if match:
+            options.append(f"{match.group(1)} {int(match.group(2))}")
+            continue
+        match = _GRANT_HINT_RE.match(text)
+        if match:
+            options.append(f"{match.group(1)} = {match.group(2)}")
+            continue
+        match = _USE_HINT_RE.match(text)
+        names = [part.strip() for part in match.group(1).split(",")] if match else [text]
+        if not match and "_" not in text:
+            names = []
+        parsed = [_USE_HINT_NAME_RE.match(name) for name in names]
+        if not names or not all(parsed):
+            raise ValueError(f"Unsupported query hint: {hint!r}")
+        use_hints.extend(p.group(1) for p in parsed)
+    if use_hints:
+        unique = list(dict.fromkeys(use_hints))
+        options.append("USE HINT(" + ", ".join(f"'{name}'" for name in unique) + ")")
+    return options
+
+
+def _trailing_option_clause(sql: str) -> int:
+    """
+    Return the index of the closing parenthesis of a trailing OPTION (...) clause,
+    or -1 when the statement does not end with one.
+    """
+    end = len(sql) - 1
+    if end < 0 or sql[end] != ")":
+        return -1
+    depth = 0
+    i = 0
+    length = len(sql)
+    open_at = -1
+    last_open = -1
+    while i < length:
+        skipped = _skip_quoted_context(sql, i, length)
+        if skipped >= 0:
+            i = skipped
+            continue
+        if sql[i] == "(":
+            if depth == 0:
+                open_at = i
+            depth += 1
+        elif sql[i] == ")":
+            depth -= 1
+            if depth == 0 and i == end:
+                last_open = open_at
+        i += 1
+    if last_open < 0:
+        return -1
+    if re.search(r"\bOPTION\s*$", sql[:last_open], re.IGNORECASE):
+        return end
+    return -1
+
+
+def apply_query_hints(sql: str, hints: List[str]) -> str:
+    """
+    Append query hints to a statement as an OPTION (...) clause.
+
+    Hints are validated against the supported T-SQL query hints (see
+    _normalize_hints), so only hint syntax, never arbitrary SQL, is added. If the
+    statement already ends with an OPTION clause the hints are merged into it.
+    The hints apply to the last statement of a batch.
+
+    Args:
+        sql: A single SELECT, INSERT, UPDATE, DELETE or MERGE statement.
+        hints: Hints such as "RECOMPILE", "MAXDOP 4", "MAX_GRANT_PERCENT = 10" or
+            "USE HINT('DISABLE_OPTIMIZER_ROWGOAL')".
+
+    Returns:
+        The statement with the OPTION clause.
+
+    Raises:
+        ValueError: If a hint is not supported.
+
+    Examples:
+        >>> apply_query_hints("SELECT * FROM t;", ["recompile", "MAXDOP 2"])
+        'SELECT * FROM t\\nOPTION (RECOMPILE, MAXDOP 2)'
+    """
+    options = _normalize_hints(list(hints))
+    if not options:
+        return sql
+    statement = sql.rstrip()
+    while statement.endswith(";"):
+        statement = statement[:-1].rstrip()
+    closing = _trailing_option_clause(statement)
+    if closing >= 0:
+        return statement[:closing].rstrip() + ", " + ", ".join(options) + ")"
+    # A new line keeps the clause out of a trailing -- comment
+    return statement + "\nOPTION (" + ", ".join(options) + ")"
+
+
+# Tokens of Connection.auto_parameterize: comments, string literals, quoted identifiers,
+# numeric literals, words (with @variables and #temp names), whitespace and other characters
+_LITERAL_TOKEN_RE = re.compile(
+    r"""
+    (?P<comment>--[^\n]*|/\*.*?\*/)
+    | (?P<string>N?'(?:[^']|'')*'?)
+    | (?P<identifier>\[(?:[^\]]|\]\])*\]|"(?:[^"]|"")*")
+    | (?P<number>(?<![\w@#$])(?:0x[0-9A-Fa-f]*|\d+(?:\.\d*)?(?:[eE][+-]?\d+)?))
+    | (?P<word>[\w@#$]+)
+    | (?P<space>\s+)
+    | (?P<other>.)
+    """,
+    re.VERBOSE | re.DOTALL,
+)
+# Statements whose literals are parameterized, by their first word
+_PARAMETERIZED_STATEMENTS = {"SELECT", "INSERT", "UPDATE", "DELETE", "MERGE", "WITH"}
+# Words whose presence leaves the whole batch as it is: other statement kinds, and
+# functions whose arguments must be literals
+_UNPARAMETERIZED_WORDS = {
+    "ALTER",
+    "BACKUP",
+    "BEGIN",
+    "BULK",
+    "CREATE",
+    "DBCC",
+    "DECLARE",
+    "DENY",
+    "DROP",
+    "EXEC",
+    "EXECUTE",
+    "GRANT",
+    "IF",
+    "OPENDATASOURCE",
+    "OPENQUERY",
+    "OPENROWSET",
+    "PRINT",
+    "RAISERROR",
+    "RESTORE",
+    "REVOKE",
+    "THROW",
+    "TRUNCATE",
+    "USE",
+    "WAITFOR",
+    "WHILE",
+}
+# Words before a parenthesis whose arguments must stay literals: type lengths,
+# precisions and scales, IDENTITY seeds, sampling sizes and percentiles
+_LITERAL_ARGUMENT_WORDS = {
+    "BINARY",
+    "CHAR",
+    "DATETIME2",
+    "DATETIMEOFFSET",
+    "DECIMAL",
+    "FLOAT",
+    "IDENTITY",
+    "NCHAR",
+    "NUMERIC",
+    "NVARCHAR",
+    "PERCENTILE_CONT",
+    "PERCENTILE_DISC",
+    "TABLESAMPLE",
+    "TIME",
+    "VARBINARY",
+    "VARCHAR",
+}
+# Clauses whose literals stay as they are up to the end of the enclosing
+# parenthesis: OPTION (...) hints and FOR XML / FOR JSON options such as PATH('')
+_LITERAL_CLAUSE_WORDS = {"OPTION", "FOR"}
+# Words ending a select list at its own parenthesis depth (GROUP BY and ORDER BY
+# are recognized at their BY)
+_SELECT_LIST_END_WORDS = {
+    "EXCEPT",
+    "FOR",
+    "FROM",
+    "HAVING",
+    "INTERSECT",
+    "INTO",
+    "OPTION",
+    "UNION",
+    "WHERE",
+    "WINDOW",
+}
+# Words ending an ORDER BY / GROUP BY list
+_BY_LIST_END_WORDS = {
+    "EXCEPT",
+    "FOR",
+    "FROM",
+    "HAVING",
+    "INTERSECT",
+    "OFFSET",
+    "OPTION",
+    "RANGE",
+    "ROWS",
+    "SELECT",
+    "UNION",
+    "WHERE",
+}
+_BIGINT_MAX = 2**63 - 1
+
+
+class _LiteralInt(int):
+    """
+    An integer literal replaced by parameterize_literals. It is bound as INT, or
+    BIGINT beyond that range, like the literal it replaces, rather than as the
+    narrowest type holding the value: a TINYINT parameter would make 200 + 100
+    overflow where the literal expression does not.
+    """
+
+
+def parameterize_literals(sql: str) -> Union[Tuple[str, List[Any]], None]:
+    """
+    Replace the integer and string literals of a statement with qmark placeholders,
+    so statements differing only in those values share one cached plan.
+
+    Only single SELECT, INSERT, UPDATE, DELETE and MERGE statements (with or
+    without a leading WITH) are rewritten; None is returned, and the statement is
+    to be sent unchanged, for anything else, for SQL that already has placeholders
+    and when no literal qualifies. Literals whose value has to be a constant stay
+    as they are:
+
+    - the select list and ORDER BY / GROUP BY lists, as with the server's forced
+      parameterization: an expression such as LEFT(name, 3) in both the select
+      list and GROUP BY has to stay identical in the two
+    - TOP n and ROWS n PRECEDING / FOLLOWING
+    - type arguments such as VARCHAR(10) or DECIMAL(18, 2), IDENTITY(1, 1),
+      TABLESAMPLE and PERCENTILE_CONT arguments
+    - the OPTION (...) and FOR XML / FOR JSON clauses
+    - column aliases ('name' = expr, expr AS 'name')
+
+    Decimal, float and binary literals, integers beyond BIGINT and strings with
+    non-ASCII characters outside N'...' are kept too, since binding them would
+    change their type or value. Replaced integers are bound as INT or BIGINT.
+
+    Examples:
+        >>> parameterize_literals("SELECT * FROM t WHERE id = 42 AND name = N'x'")
+        ('SELECT * FROM t WHERE id = ? AND name = ?', [42, 'x'])
+    """
+    tokens = [(m.lastgroup, m.group()) for m in _LITERAL_TOKEN_RE.finditer(sql)]
+    significant = [i for i, (kind, _) in enumerate(tokens) if kind not in ("comment", "space")]
+    if not significant:
+        return None
+    first_kind, first_text = tokens[significant[0]]
+    if first_kind != "word" or first_text.upper() not in _PARAMETERIZED_STATEMENTS:
+        return None
+    updates = first_text.upper() in ("UPDATE", "MERGE")
+
+    def word(position: int) -> str:
+        if 0 <= position < len(significant):
+            kind, text = tokens[significant[position]]
+            return text.upper() if kind in ("word", "other") else ""
+        return ""
+
+    replacements: Dict[int, Any] = {}
+    # One entry per open parenthesis: whether its literals must stay
+    literal_parens: List[bool] = []
+    frozen_depth: Union[int, None] = None
+    # Parenthesis depths of the select lists and ORDER BY / GROUP BY lists being read
+    select_depths = set()
+    by_depths = set()
+    for position, index in enumerate(significant):
+        kind, text = tokens[index]
+        depth = len(literal_parens)
+        upper = text.upper()
+        if kind == "other":
+            if text == "?" or (text == ";" and position + 1 < len(significant)):
+                return None
+            if text == "(":
+                literal_parens.append(word(position - 1) in _LITERAL_ARGUMENT_WORDS)
+            elif text == ")" and literal_parens:
+                literal_parens.pop()
+                select_depths.discard(depth)
+                by_depths.discard(depth)
+                if frozen_depth is not None and depth - 1 < frozen_depth:
+                    frozen_depth = None
+            continue
+        if kind == "word":
+            if upper in _UNPARAMETERIZED_WORDS or (upper == "SET" and not updates):
+                return None
+            if upper in _LITERAL_CLAUSE_WORDS and frozen_depth is None:
+                frozen_depth = depth
+            if upper == "SELECT":
+                select_depths.add(depth)
+            elif upper in _SELECT_LIST_END_WORDS:
+                select_depths.discard(depth)
+            if upper == "BY" and word(position - 1) in ("ORDER", "GROUP"):
+                select_depths.discard(depth)
+                by_depths.add(depth)
+            elif upper in _BY_LIST_END_WORDS:
+                by_depths.discard(depth)
+            continue
+        if kind not in ("string", "number") or frozen_depth is not None or any(literal_parens):
+            continue
+        if select_depths or by_depths:
+            continue
+        previous, following = word(position - 1), word(position + 1)
+        if kind == "string":
+            if len(text) < 2 + text.startswith("N") or not text.endswith("'"):
+                return None  # Unterminated literal: leave the error to the server
+            value = text[2 if text.startswith("N") else 1 : -1].replace("''", "'")
+            if previous == "AS" or following == "=":
+                continue
+            if not text.startswith("N") and not value.isascii():
+                continue
+            replacements[index] = value
+        elif text.isdigit():
+            if previous == "TOP" or following in ("PRECEDING", "FOLLOWING"):
+                continue
+            if int(text) > _BIGINT_MAX:
+                continue
+            replacements[index] = _LiteralInt(text)
+    if not replacements or len(replacements) > MAX_PARAMETERS:
+        return None
+    parameters = [replacements[index] for index in sorted(replacements)]
+    rewritten = "".join("?" if i in replacements else text for i, (_, text) in enumerate(tokens))
+    logger.debug("parameterize_literals: Replaced %d literals with parameters", len(parameters))
+    return rewritten, parameters
@@ -6,10 +6,12 @@ This module provides connection pooling functionality for the mssql_python packa
 
 import atexit
 import threading
-from typing import Dict
+from typing import Any, Dict, List, Optional
 
 from mssql_python import ddbc_bindings
+from mssql_python.connection_string_parser import sanitize_connection_string
 from mssql_python.logging import logger
+from mssql_python.statement_limit import STATEMENT_QUEUE_POLICIES, StatementLimiter
 
 
 class PoolingManager:
@@ -25,19 +27,58 @@ class PoolingManager:
     _initialized: bool = False
     _pools_closed: bool = False  # Track if pools have been closed
     _lock: threading.Lock = threading.Lock()
-    _config: Dict[str, int] = {"max_size": 100, "idle_timeout": 600}
+    _config: Dict[str, Any] = {
+        "max_size": 100,
+        "idle_timeout": 600,
+        "min_size": 0,
+        "max_lifetime": 0,
+        "validation_query": None,
+        "max_concurrent_statements": 0,
+        "statement_queue_policy": "wait",
+        "statement_queue_timeout": None,
+    }
+    # Statement limit of each pool, by the connection string the pool is keyed by
+    _statement_limiters: Dict[str, StatementLimiter] = {}
 
     @classmethod
-    def enable(cls, max_size: int = 100, idle_timeout: int = 600) -> None:
+    def enable(
+        cls,
+        max_size: int = 100,
+        idle_timeout: int = 600,
+        min_size: int = 0,
+        max_lifetime: int = 0,
+        validation_query: Optional[str] = None,
+        max_concurrent_statements: int = 0,
+        statement_queue_policy: str = "wait",
+        statement_queue_timeout: Optional[float] = None,
+    ) -> None:
         """
         Enable connection pooling with specified parameters.
 
         Args:
             max_size: Maximum number of connections in the pool (default: 100)
             idle_timeout: Timeout in seconds for idle connections (default: 600)
+            min_size: Connections of a pool kept open past idle_timeout once
+                opened (default: 0); they are not opened in advance
+            max_lifetime: Seconds after which a connection is closed instead of
+                being reused, e.g. to follow failovers or rotate credentials
+                (default: 0, no limit)
+            validation_query: Statement run on a pooled connection before it is
+                handed out, e.g. "SELECT 1"; a connection it fails on is replaced.
+                By default only a dead connection is detected (no round trip).
+            max_concurrent_statements: Statements executing at once on the
+                connections of a pool (default: 0, no limit); an execute beyond
+                it queues or fails per statement_queue_policy
+            statement_queue_policy: "wait" (default) to queue an execute until a
+                statement of the pool finishes, or "fail" to raise OperationalError
+            statement_queue_timeout: Seconds an execute queues before failing with
+                OperationalError (default: None, no limit)
 
         Raises:
-            ValueError: If parameters are invalid (max_size <= 0 or idle_timeout < 0)
+            ValueError: If parameters are invalid (max_size <= 0, idle_timeout < 0,
+                min_size outside 0..max_size, max_lifetime < 0,
+                max_concurrent_statements < 0, an unknown statement_queue_policy
+                or statement_queue_timeout < 0)
         """
         logger.debug(
             "PoolingManager.enable: Attempting to enable pooling - max_size=%d, idle_timeout=%d",
@@ -49,22 +90,49 @@ class PoolingManager:
                 logger.debug("PoolingManager.enable: Pooling already enabled, skipping")
                 return
 
-            if max_size <= 0 or idle_timeout < 0:
+            if (
+                max_size <= 0
+                or idle_timeout < 0
+                or not 0 <= min_size <= max_size
+                or max_lifetime < 0
+            ):
                 logger.error(
-                    "PoolingManager.enable: Invalid parameters - max_size=%d, idle_timeout=%d",
+                    "PoolingManager.enable: Invalid parameters - max_size=%d, idle_timeout=%d, "
+                    "min_size=%d, max_lifetime=%d",
                     max_size,
                     idle_timeout,
+                    min_size,
+                    max_lifetime,
                 )
                 raise ValueError("Invalid pooling parameters")
+            if validation_query is not None and (
+                not isinstance(validation_query, str) or not validation_query.strip()
+            ):
+                raise ValueError("validation_query must be a non-empty string or None")
+            if max_concurrent_statements < 0:
+                raise ValueError("max_concurrent_statements must be non-negative")
+            if statement_queue_policy not in STATEMENT_QUEUE_POLICIES:
+                raise ValueError("statement_queue_policy must be 'wait' or 'fail'")
+            if statement_queue_timeout is not None and statement_queue_timeout < 0:
+                raise ValueError("statement_queue_timeout must be non-negative or None")
 
             logger.info(
                 "PoolingManager.enable: Enabling connection pooling - max_size=%d, idle_timeout=%d seconds",
                 max_size,
                 idle_timeout,
             )
-            ddbc_bindings.enable_pooling(max_size, idle_timeout)
+            ddbc_bindings.enable_pooling(
+                max_size, idle_timeout, min_size, max_lifetime, validation_query or ""
+            )
             cls._config["max_size"] = max_size
             cls._config["idle_timeout"] = idle_timeout
+            cls._config["min_size"] = min_size
+            cls._config["max_lifetime"] = max_lifetime
+            cls._config["validation_query"] = validation_query
+            cls._config["max_concurrent_statements"] = max_concurrent_statements
+            cls._config["statement_queue_policy"] = statement_queue_policy
+            cls._config["statement_queue_timeout"] = statement_queue_timeout
+            cls._statement_limiters = {}
             cls._enabled = True
             cls._initialized = True
             logger.info("PoolingManager.enable: Connection pooling enabled successfully")
@@ -90,6 +158,7 @@ class PoolingManager:
             cls._pools_closed = True
             cls._enabled = False
             cls._initialized = True
+            cls._statement_limiters = {}
 
     @classmethod
     def is_enabled(cls) -> bool:
@@ -111,6 +180,55 @@ class PoolingManager:
         """
         return cls._initialized
 
+    @classmethod
+    def stats(cls) -> List[Dict[str, Any]]:
+        """
+        Return the size and counters of every connection pool, for monitoring.
+
+        There is one pool per connection string. Each entry has the connection
+        string with secrets masked ("connection_string"), the connections open or
+        being opened ("size"), waiting in the pool ("idle") and checked out
+        ("in_use"), and the counts of connections opened ("created"), acquires
+        served from the pool ("reused"), connections closed as dead, invalid, idle
+        or past max_lifetime ("discarded") and acquires refused because the pool
+        was full ("exhausted"). With max_concurrent_statements, entries also have
+        the counters of the statement queue (see StatementLimiter.stats).
+
+        Returns:
+            list of dict: One entry per pool; empty when pooling is disabled.
+        """
+        if not cls._enabled:
+            return []
+        stats = ddbc_bindings.pool_stats()
+        with cls._lock:
+            limiters = dict(cls._statement_limiters)
+        for entry in stats:
+            limiter = limiters.get(entry["connection_string"])
+            if limiter is not None:
+                entry.update(limiter.stats())
+            entry["connection_string"] = sanitize_connection_string(entry["connection_string"])
+        return stats
+
+    @classmethod
+    def statement_limiter(cls, connection_str: str) -> Optional[StatementLimiter]:
+        """
+        Return the statement limit of the pool of connection_str, or None when
+        pooling is disabled or max_concurrent_statements is not set.
+        """
+        with cls._lock:
+            max_concurrent = cls._config["max_concurrent_statements"]
+            if not cls._enabled or not max_concurrent:
+                return None
+            limiter = cls._statement_limiters.get(connection_str)
+            if limiter is None:
+                limiter = StatementLimiter(
+                    max_concurrent,
+                    cls._config["statement_queue_policy"],
+                    cls._config["statement_queue_timeout"],
+                )
+                cls._statement_limiters[connection_str] = limiter
+            return limiter
+
     @classmethod
     def _reset_for_testing(cls) -> None:
         """Reset pooling state - for testing purposes only"""
@@ -118,6 +236,8 @@ class PoolingManager:
             cls._enabled = False
             cls._initialized = False
             cls._pools_closed = False
+            cls._config["max_concurrent_statements"] = 0
+            cls._statement_limiters = {}
 
 
 @atexit.register
@@ -0,0 +1,210 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module implements Cursor.callproc(): stored procedure calls with OUTPUT and
+input/output parameters and the procedure's return code. The call runs as one
+batch that declares a variable per OUTPUT parameter, executes the procedure
+with them, and selects the return code and the variables after the results of the
+procedure, so no driver support for SQL_PARAM_OUTPUT buffers is needed:
+
+    DECLARE @return_value INT, @p1 INT, @p2 NVARCHAR(50) = ?;
+    EXEC @return_value = [dbo].[proc] ?, @p1 OUTPUT, @p2 OUTPUT;
+    SELECT @return_value, @p1, @p2;
+"""
+
+from typing import TYPE_CHECKING, Any, Dict, List, Mapping, Optional, Sequence, Tuple, Union
+
+from mssql_python.bulk_load import _SQL_TYPE_NAME_RE
+from mssql_python.exceptions import ProgrammingError
+from mssql_python.helpers import catalog_of, quote_identifier, quote_multipart_name
+
+if TYPE_CHECKING:
+    from mssql_python.cursor import Cursor
+
+ProcedureParameters = Union[Sequence[Any], Mapping[str, Any]]
+
+_PARAMETERS_QUERY = (
+    "SELECT p.name, TYPE_NAME(p.user_type_id), p.max_length, p.precision, p.scale "
+    "FROM {catalog}sys.parameters AS p "
+    "WHERE p.object_id = OBJECT_ID(?) AND p.parameter_id > 0 "
+    "ORDER BY p.parameter_id"
+)
+# Types declared with a length, counted in bytes (max_length) or in characters
+_BYTE_LENGTH_TYPES = ("binary", "varbinary", "char", "varchar")
+_CHAR_LENGTH_TYPES = ("nchar", "nvarchar")
+_SCALE_TYPES = ("datetime2", "datetimeoffset", "time")
+_PRECISION_TYPES = ("decimal", "numeric")
+
+
+class Output:
+    """
+    An OUTPUT parameter of Cursor.callproc(), passed to the procedure as NULL.
+
+    Args:
+        sql_type: Type of the parameter, e.g. "INT" or "NVARCHAR(50)". When left
+            out, it is looked up in the procedure's metadata (sys.parameters).
+    """
+
+    def __init__(self, sql_type: Optional[str] = None) -> None:
+        if sql_type is not None and not (
+            isinstance(sql_type, str) and _SQL_TYPE_NAME_RE.match(sql_type.strip())
+        ):
+            raise ValueError(f"Invalid SQL type for an OUTPUT parameter: {sql_type!r}")
+        self.sql_type = sql_type.strip() if sql_type else None
+
+    @property
+    def has_input(self) -> bool:
+        """True if the parameter also passes a value into the procedure."""
+        return False
+
+    def __repr__(self) -> str:
+        return f"Output({self.sql_type!r})"
+
+
+class InOut(Output):
+    """An input/output parameter of Cursor.callproc(): value is passed in, then replaced."""
+
+    def __init__(self, value: Any, sql_type: Optional[str] = None) -> None:
+        super().__init__(sql_type)
+        self.value = value
+
+    @property
+    def has_input(self) -> bool:
+        return True
+
+    def __repr__(self) -> str:
+        return f"InOut({self.value!r}, {self.sql_type!r})"
+
+
+def _declared_type(type_name: str, max_length: int, precision: int, scale: int) -> str:
+    """Return the type of a sys.parameters row as written in a declaration."""
+    name = type_name.lower()
+    if name in _BYTE_LENGTH_TYPES + _CHAR_LENGTH_TYPES:
+        if max_length == -1:
+            return f"{type_name}(MAX)"
+        length = max_length // 2 if name in _CHAR_LENGTH_TYPES else max_length
+        return f"{type_name}({length})"
+    if name in _PRECISION_TYPES:
+        return f"{type_name}({precision}, {scale})"
+    if name in _SCALE_TYPES:
+        return f"{type_name}({scale})"
+    return type_name if _SQL_TYPE_NAME_RE.match(type_name) else quote_identifier(type_name)
+
+
+def procedure_parameter_types(cursor: "Cursor", procname: str) -> List[Tuple[str, str]]:
+    """
+    Return (name, declared type) of each parameter of a procedure, in order.
+
+    Temporary procedures (#proc) are looked up in tempdb, and three-part names
+    in the database they name.
+    """
+    catalog, object_name = catalog_of(procname)
+    cursor.execute(_PARAMETERS_QUERY.format(catalog=catalog), object_name)
+    return [(row[0], _declared_type(*row[1:])) for row in cursor.fetchall()]
+
+
+def _parameter_items(parameters: ProcedureParameters) -> List[Tuple[Optional[str], Any]]:
+    if isinstance(parameters, Mapping):
+        items = []
+        for name, value in parameters.items():
+            if not isinstance(name, str) or not name.lstrip("@"):
+                raise ProgrammingError(
+                    driver_error="callproc() parameter names must be non-empty strings",
+                    ddbc_error=f"Invalid parameter name: {name!r}",
+                )
+            items.append(("@" + name.lstrip("@"), value))
+        return items
+    if isinstance(parameters, (str, bytes)):
+        raise ProgrammingError(
+            driver_error="callproc() parameters must be a sequence or a mapping",
+            ddbc_error=f"Invalid parameters: {type(parameters).__name__}",
+        )
+    return [(None, value) for value in parameters]
+
+
+def call_batch(
+    procname: str,
+    parameters: ProcedureParameters,
+    parameter_types: Optional[Sequence[Tuple[str, str]]] = None,
+) -> Tuple[str, List[Any]]:
+    """
+    Return the batch that calls procname, and its parameter values.
+
+    Args:
+        procname: The procedure, a name of up to four parts.
+        parameters: Values in the order of the procedure's parameters, or by
+            parameter name; Output and InOut mark OUTPUT parameters.
+        parameter_types: (name, type) of the procedure's parameters, needed for
+            Output parameters given without a type.
+
+    Raises:
+        ProgrammingError: If the type of an Output parameter is not known.
+    """
+    types_by_name = {name.lower(): sql_type for name, sql_type in parameter_types or ()}
+    declarations = ["@return_value INT"]
+    arguments = []
+    outputs = ["@return_value"]
+    # The markers of the declarations come before those of the arguments
+    declared_values: List[Any] = []
+    values: List[Any] = []
+    for index, (name, value) in enumerate(_parameter_items(parameters)):
+        prefix = f"{name} = " if name else ""
+        if not isinstance(value, Output):
+            arguments.append(prefix + "?")
+            values.append(value)
+            continue
+        sql_type = value.sql_type
+        if sql_type is None and parameter_types is not None:
+            if name is not None:
+                sql_type = types_by_name.get(name.lower())
+            elif index < len(parameter_types):
+                sql_type = parameter_types[index][1]
+        if sql_type is None:
+            raise ProgrammingError(
+                driver_error=f"Cannot determine the type of OUTPUT parameter {name or index + 1}",
+                ddbc_error="Pass the type, e.g. Output('INT'), or check the procedure name",
+            )
+        variable = f"@p{index + 1}"
+        if value.has_input:
+            declarations.append(f"{variable} {sql_type} = ?")
+            declared_values.append(value.value)
+        else:
+            declarations.append(f"{variable} {sql_type}")
+        arguments.append(f"{prefix}{variable} OUTPUT")
+        outputs.append(variable)
+    call = f"EXEC @return_value = {quote_multipart_name(procname)}"
+    if arguments:
+        call += " " + ", ".join(arguments)
+    batch = (
+        f"DECLARE {', '.join(declarations)};\n"
+        f"{call};\n"
+        f"SELECT {', '.join(outputs)};"
+    )
+    return batch, declared_values + values
+
+
+def needs_parameter_types(parameters: ProcedureParameters) -> bool:
+    """Return True if an Output parameter has no type, so it must be looked up."""
+    values = parameters.values() if isinstance(parameters, Mapping) else parameters
+    return any(isinstance(value, Output) and value.sql_type is None for value in values)
+
+
+def call_results(
+    parameters: ProcedureParameters, final_row: Sequence[Any]
+) -> Tuple[Any, Union[List[Any], Dict[str, Any]]]:
+    """
+    Return the return code and the parameters with the values of OUTPUT
+    parameters replaced by their final values, given the row the batch selects.
+    """
+    return_value = final_row[0]
+    outputs = iter(final_row[1:])
+    if isinstance(parameters, Mapping):
+        result: Union[List[Any], Dict[str, Any]] = dict(parameters)
+        keys = list(result)
+    else:
+        result = list(parameters)
+        keys = list(range(len(result)))
+    for key in keys:
+        if isinstance(result[key], Output):
+            result[key] = next(outputs)
+    return return_value, result
@@ -0,0 +1,233 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module drives progress callbacks for long-running statements such as BACKUP,
+RESTORE and DBCC, combining their "N percent processed" informational messages with
+polling of sys.dm_exec_requests.percent_complete on a separate connection.
+"""
+
+import re
+import threading
+from typing import TYPE_CHECKING, Any, Callable, Dict, List, Optional, Tuple
+
+from mssql_python.logging import logger
+
+if TYPE_CHECKING:
+    from mssql_python.connection import Connection
+
+# BACKUP/RESTORE ... WITH STATS = n emit "<n> percent processed." messages
+_PERCENT_MESSAGE_RE = re.compile(r"(\d+(?:\.\d+)?)\s+percent processed", re.IGNORECASE)
+
+_PERCENT_COMPLETE_QUERY = (
+    "SELECT percent_complete FROM sys.dm_exec_requests "
+    "WHERE session_id = ? AND percent_complete > 0"
+)
+
+# Per-thread operator counters of the lightweight query profiling infrastructure,
+# on by default from SQL Server 2019 (LIGHTWEIGHT_QUERY_PROFILING)
+_OPERATOR_PROFILE_QUERY = (
+    "SELECT node_id, physical_operator_name, OBJECT_NAME(object_id, database_id), "
+    "row_count, estimate_row_count, elapsed_time_ms, cpu_time_ms, logical_read_count "
+    "FROM sys.dm_exec_query_profiles WHERE session_id = ? ORDER BY node_id, thread_id"
+)
+
+
+class ProgressEvent:
+    """
+    A progress update passed to the execute_with_progress callback.
+
+    Attributes:
+        percent: Completion percentage (0-100), or None for a message without one.
+        message: The informational message text, for events from the server's
+            messages; None for polled and final events.
+        source: "message" (informational message), "poll" (dm_exec_requests) or
+            "complete" (the statement finished).
+    """
+
+    def __init__(self, percent: Optional[float], message: Optional[str], source: str) -> None:
+        self.percent = percent
+        self.message = message
+        self.source = source
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Return the event as a plain dictionary."""
+        return {"percent": self.percent, "message": self.message, "source": self.source}
+
+    def __repr__(self) -> str:
+        return (
+            f"ProgressEvent(percent={self.percent!r}, message={self.message!r}, "
+            f"source={self.source!r})"
+        )
+
+
+def parse_progress_message(message: str) -> Optional[float]:
+    """Return the percentage in a "N percent processed" message, or None."""
+    match = _PERCENT_MESSAGE_RE.search(message)
+    return float(match.group(1)) if match else None
+
+
+class _ProgressReporter:
+    """Serializes callback invocations and keeps reported percentages monotonic."""
+
+    def __init__(self, callback: Callable[[ProgressEvent], Any]) -> None:
+        self._callback = callback
+        self._lock = threading.Lock()
+        self._last_percent = 0.0
+        self.error: Optional[BaseException] = None
+
+    def report(self, percent: Optional[float], message: Optional[str], source: str) -> None:
+        with self._lock:
+            if percent is not None:
+                if percent < self._last_percent or (
+                    source == "poll" and percent == self._last_percent
+                ):
+                    # Stale poll results or a replayed message: nothing new to report
+                    if message is None:
+                        return
+                    percent = None
+                else:
+                    self._last_percent = percent
+            self._callback(ProgressEvent(percent, message, source))
+
+
+def _poll_percent_complete(
+    connection: "Connection",
+    session_id: int,
+    interval: float,
+    reporter: _ProgressReporter,
+    stop: threading.Event,
+) -> None:
+    """Report percent_complete of session_id every interval seconds until stopped."""
+    try:
+        side = connection._spawn_connection(autocommit=True)
+    except Exception:  # pylint: disable=broad-exception-caught
+        logger.debug("execute_with_progress: Could not open polling connection", exc_info=True)
+        return
+    try:
+        cursor = side.cursor()
+        while not stop.wait(interval):
+            try:
+                rows = cursor.execute(_PERCENT_COMPLETE_QUERY, session_id).fetchall()
+            except Exception:  # pylint: disable=broad-exception-caught
+                # Polling is best effort (e.g. missing VIEW SERVER STATE)
+                logger.debug("execute_with_progress: Polling stopped", exc_info=True)
+                return
+            if rows and not stop.is_set():
+                try:
+                    reporter.report(float(rows[0][0]), None, "poll")
+                except Exception as e:  # pylint: disable=broad-exception-caught
+                    # Re-raised on the caller's thread once the statement finishes
+                    reporter.error = e
+                    return
+    finally:
+        side.close()
+
+
+def operator_progress(monitor: "Connection", session_id: int) -> List[Dict[str, Any]]:
+    """
+    Read the per-operator progress of the statement running on session_id.
+
+    See Cursor.progress. Counters of parallel operators are combined over their
+    threads: rows, CPU time and reads are summed, elapsed time is the longest.
+    """
+    cursor = monitor.cursor()
+    try:
+        rows = cursor.execute(_OPERATOR_PROFILE_QUERY, session_id).fetchall()
+    finally:
+        cursor.close()
+    operators: Dict[int, Dict[str, Any]] = {}
+    for node_id, operator, object_name, count, estimate, elapsed, cpu, reads in rows:
+        entry = operators.get(node_id)
+        if entry is None:
+            operators[node_id] = {
+                "node_id": node_id,
+                "operator": operator,
+                "object_name": object_name,
+                "row_count": count,
+                "estimate_row_count": estimate,
+                "elapsed_time_ms": elapsed,
+                "cpu_time_ms": cpu,
+                "logical_read_count": reads,
+                "threads": 1,
+            }
+            continue
+        entry["row_count"] += count
+        entry["estimate_row_count"] = max(entry["estimate_row_count"], estimate)
+        entry["elapsed_time_ms"] = max(entry["elapsed_time_ms"], elapsed)
+        entry["cpu_time_ms"] += cpu
+        entry["logical_read_count"] += reads
+        entry["threads"] += 1
+    for entry in operators.values():
+        estimate = entry["estimate_row_count"]
+        entry["percent_complete"] = (
+            min(100.0, 100.0 * entry["row_count"] / estimate) if estimate else None
+        )
+    return list(operators.values())
+
+
+def _report_messages(
+    cursor: Any, reporter: _ProgressReporter, messages: List[Tuple[str, str]]
+) -> None:
+    """Collect the cursor's current messages and report any progress they carry."""
+    for record in cursor.messages:
+        messages.append(record)
+        text = record[1] if isinstance(record, tuple) else str(record)
+        reporter.report(parse_progress_message(text), text, "message")
+
+
+def execute_with_progress(
+    connection: "Connection",
+    sql: str,
+    callback: Callable[[ProgressEvent], Any],
+    poll_interval: Optional[float] = 1.0,
+) -> List[Tuple[str, str]]:
+    """
+    Execute a long-running statement, reporting its progress to callback.
+
+    See Connection.execute_with_progress.
+    """
+    if not callable(callback):
+        raise TypeError("callback must be callable")
+    if poll_interval is not None and (
+        isinstance(poll_interval, bool)
+        or not isinstance(poll_interval, (int, float))
+        or poll_interval <= 0
+    ):
+        raise ValueError("poll_interval must be a positive number of seconds or None")
+
+    reporter = _ProgressReporter(callback)
+    cursor = connection.cursor()
+    stop = threading.Event()
+    poller = None
+    messages: List[Tuple[str, str]] = []
+    try:
+        if poll_interval is not None:
+            session_id = cursor.execute("SELECT @@SPID").fetchall()[0][0]
+            poller = threading.Thread(
+                target=_poll_percent_complete,
+                args=(connection, session_id, poll_interval, reporter, stop),
+                name="mssql-progress-poll",
+                daemon=True,
+            )
+            poller.start()
+
+        cursor.execute(sql, use_prepare=False)
+        # Each informational message ends a step of the statement; advancing with
+        # nextset() lets the server continue and delivers the next message.
+        while True:
+            _report_messages(cursor, reporter, messages)
+            if cursor.description:
+                cursor.fetchall()
+            if not cursor.nextset():
+                break
+        _report_messages(cursor, reporter, messages)
+    finally:
+        stop.set()
+        if poller is not None:
+            poller.join()
+        cursor.close()
+
+    if reporter.error is not None:
+        raise reporter.error
+    reporter.report(100.0, None, "complete")
+    return messages
@@ -77,6 +77,7 @@ void Connection::connect(const py::dict& attrs_before) {
     }
     SQLWCHAR* connStrPtr = reinterpretU16stringAsSqlWChar(_connStr);
     SQLRETURN ret;
+    auto started = std::chrono::steady_clock::now();
     {
         // Release the GIL during the blocking ODBC connect call.
         // SQLDriverConnect involves DNS resolution, TCP handshake, TLS negotiation,
@@ -86,8 +87,20 @@ void Connection::connect(const py::dict& attrs_before) {
         ret = SQLDriverConnect_ptr(_dbcHandle->get(), nullptr, connStrPtr, SQL_NTS, nullptr,
                                    0, nullptr, SQL_DRIVER_NOPROMPT);
     }
-    checkError(ret);
+    DriverMetrics& metrics = DriverMetrics::get();
+    metrics.connect_duration.observe(std::chrono::steady_clock::now() - started);
+    try {
+        checkError(ret);
+    } catch (const std::exception& ex) {
+        DriverMetrics::add(metrics.connection_failures);
+        if (std::string(ex.what()).rfind("SQLSTATE:28", 0) == 0) {
+            DriverMetrics::add(metrics.failed_logins);
+        }
+        throw;
+    }
+    DriverMetrics::add(metrics.connections_opened);
     updateLastUsed();
+    _createdAt = _lastUsed;
 }
 
 void Connection::disconnect() {
@@ -166,8 +179,6 @@ void Connection::disconnect() {
     }
 }
 
-// TODO(microsoft): Add an exception class in C++ for error handling,
-// DB spec compliant
 void Connection::checkError(SQLRETURN ret) const {
     if (!SQL_SUCCEEDED(ret)) {
         // Format: "SQLSTATE:XXXXX:<odbc_error_message>" — parsed by _raise_connection_error()
@@ -176,7 +187,7 @@ void Connection::checkError(SQLRETURN ret) const {
         std::string errorMsg = err.ddbcErrorMsg;
         // Only add SQLSTATE prefix if we have a valid 5-character code
         if (sqlState.length() == 5) {
-            ThrowStdException("SQLSTATE:" + sqlState + ":" + errorMsg);
+            throw DriverError(sqlState, "SQLSTATE:" + sqlState + ":" + errorMsg);
         } else {
             // No valid SQLSTATE (e.g., SQL_INVALID_HANDLE) — throw clean error message
             ThrowStdException(errorMsg);
@@ -513,6 +524,36 @@ std::chrono::steady_clock::time_point Connection::lastUsed() const {
     return _lastUsed;
 }
 
+std::chrono::steady_clock::time_point Connection::createdAt() const {
+    return _createdAt;
+}
+
+bool Connection::validate(const std::u16string& query) {
+    if (!_dbcHandle) {
+        ThrowStdException("Connection handle not allocated");
+    }
+    LOG("Validating pooled connection with a validation query");
+    SQLHANDLE stmt = nullptr;
+    SQLRETURN ret = SQLAllocHandle_ptr(SQL_HANDLE_STMT, _dbcHandle->get(), &stmt);
+    if (!SQL_SUCCEEDED(ret)) {
+        LOG("Failed to allocate validation statement (ret=%d). Marking as dead.", ret);
+        return false;
+    }
+    {
+        // The query is a round trip to the server; other threads may run meanwhile
+        py::gil_scoped_release release;
+        ret = SQLExecDirect_ptr(stmt, reinterpretU16stringAsSqlWChar(query), SQL_NTS);
+        // Freeing the statement discards any result set of the query
+        SQLFreeHandle_ptr(SQL_HANDLE_STMT, stmt);
+    }
+    if (!SQL_SUCCEEDED(ret) && ret != SQL_NO_DATA) {
+        LOG("Validation query failed (ret=%d). Marking as dead.", ret);
+        return false;
+    }
+    updateLastUsed();
+    return true;
+}
+
 ConnectionHandle::ConnectionHandle(const std::u16string& connStr, bool usePool,
                                    const py::dict& attrsBefore)
     : _usePool(usePool), _connStr(connStr) {
@@ -574,7 +615,23 @@ SqlHandlePtr ConnectionHandle::allocStatementHandle() {
     if (!_conn) {
         ThrowStdException("Connection object is not initialized");
     }
-    return _conn->allocStatementHandle();
+    SqlHandlePtr stmtHandle = _conn->allocStatementHandle();
+    stmtHandle->usage = _usage;
+    return stmtHandle;
+}
+
+py::dict ConnectionHandle::usage(bool reset) {
+    auto read = [reset](std::atomic<uint64_t>& counter) {
+        return reset ? counter.exchange(0, std::memory_order_relaxed)
+                     : counter.load(std::memory_order_relaxed);
+    };
+    py::dict result;
+    result["statements_executed"] = read(_usage->statements_executed);
+    result["rows_fetched"] = read(_usage->rows_fetched);
+    result["rows_affected"] = read(_usage->rows_affected);
+    result["bytes_sent"] = read(_usage->bytes_sent);
+    result["bytes_received"] = read(_usage->bytes_received);
+    return result;
 }
 
 py::object Connection::getInfo(SQLUSMALLINT infoType) const {
@@ -45,8 +45,11 @@ class Connection {
     bool getAutocommit() const;
     bool isAlive() const;
     bool reset();
+    // Run query on a temporary statement; false if it fails (pool validation).
+    bool validate(const std::u16string& query);
     void updateLastUsed();
     std::chrono::steady_clock::time_point lastUsed() const;
+    std::chrono::steady_clock::time_point createdAt() const;
 
     // Allocate a new statement handle on this connection.
     SqlHandlePtr allocStatementHandle();
@@ -69,6 +72,8 @@ class Connection {
     bool _autocommit = true;
     SqlHandlePtr _dbcHandle;
     std::chrono::steady_clock::time_point _lastUsed;
+    // Set by connect(); pools retire connections older than their max lifetime
+    std::chrono::steady_clock::time_point _createdAt;
     // Per-attribute owned buffers for connect attributes whose pointer the
     // driver may dereference *after* SQLSetConnectAttr returns (deferred
     // attributes, e.g. SQL_COPT_SS_ACCESS_TOKEN). Keyed by attribute ID so
@@ -111,8 +116,12 @@ class ConnectionHandle {
     // Get information about the driver and data source
     py::object getInfo(SQLUSMALLINT infoType) const;
 
+    // Counters of the statements run on this handle; reset zeroes them after reading
+    py::dict usage(bool reset);
+
   private:
     std::shared_ptr<Connection> _conn;
     bool _usePool;
     std::u16string _connStr;
+    std::shared_ptr<ConnectionUsage> _usage = std::make_shared<ConnectionUsage>();
 };
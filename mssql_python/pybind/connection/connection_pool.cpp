@@ -9,8 +9,15 @@
 // Logging uses LOG() macro for all diagnostic output
 #include "logger_bridge.hpp"
 
-ConnectionPool::ConnectionPool(size_t max_size, int idle_timeout_secs)
-    : _max_size(max_size), _idle_timeout_secs(idle_timeout_secs), _current_size(0) {}
+ConnectionPool::ConnectionPool(const ConnectionPoolConfig& config)
+    : _config(config), _current_size(0) {}
+
+bool ConnectionPool::pastLifetime(const std::shared_ptr<Connection>& conn,
+                                  std::chrono::steady_clock::time_point now) const {
+    return _config.max_lifetime_secs > 0 &&
+           std::chrono::duration_cast<std::chrono::seconds>(now - conn->createdAt()).count() >=
+               _config.max_lifetime_secs;
+}
 
 std::shared_ptr<Connection> ConnectionPool::acquire(const std::u16string& connStr,
                                                     const py::dict& attrs_before) {
@@ -23,6 +30,9 @@ std::shared_ptr<Connection> ConnectionPool::acquire(const std::u16string& connSt
         std::lock_guard<std::mutex> lock(_mutex);
         auto now = std::chrono::steady_clock::now();
         size_t before = _pool.size();
+        // Idle connections are kept while only min_size connections are open;
+        // connections past their lifetime are retired regardless
+        size_t open = _current_size;
 
         _pool.erase(std::remove_if(_pool.begin(), _pool.end(),
                                    [&](const std::shared_ptr<Connection>& conn) {
@@ -30,8 +40,11 @@ std::shared_ptr<Connection> ConnectionPool::acquire(const std::u16string& connSt
                                            std::chrono::duration_cast<std::chrono::seconds>(
                                                now - conn->lastUsed())
                                                .count();
-                                       if (idle_time > _idle_timeout_secs) {
+                                       bool idle = idle_time > _config.idle_timeout_secs &&
+                                                   open > _config.min_size;
+                                       if (idle || pastLifetime(conn, now)) {
                                            to_disconnect.push_back(conn);
+                                           if (open > 0) --open;
                                            return true;
                                        }
                                        return false;
@@ -39,6 +52,7 @@ std::shared_ptr<Connection> ConnectionPool::acquire(const std::u16string& connSt
                     _pool.end());
 
         size_t pruned = before - _pool.size();
+        _stats.discarded += pruned;
         // Decrement _current_size eagerly so new slots can be reserved while
         // stale connections are being disconnected (Phase 4).  This means
         // _current_size tracks *reserved capacity* (pooled + checked-out +
@@ -56,7 +70,7 @@ std::shared_ptr<Connection> ConnectionPool::acquire(const std::u16string& connSt
             std::lock_guard<std::mutex> lock(_mutex);
             if (_pool.empty()) {
                 // No more candidates — try to reserve a slot for a new connection.
-                if (_current_size < _max_size) {
+                if (_current_size < _config.max_size) {
                     valid_conn = std::make_shared<Connection>(connStr, true);
                     ++_current_size;
                     needs_connect = true;
@@ -67,6 +81,8 @@ std::shared_ptr<Connection> ConnectionPool::acquire(const std::u16string& connSt
                     // adding a condition-variable retry loop.  This is an
                     // acceptable trade-off: transient "pool full" errors under
                     // heavy contention are rare and callers can retry.
+                    ++_stats.exhausted;
+                    DriverMetrics::add(DriverMetrics::get().pool_exhausted);
                     throw std::runtime_error("ConnectionPool::acquire: pool size limit reached");
                 }
                 break;
@@ -77,20 +93,25 @@ std::shared_ptr<Connection> ConnectionPool::acquire(const std::u16string& connSt
 
         // Validate the candidate outside the mutex.
         try {
-            if (candidate->isAlive() && candidate->reset()) {
+            if (candidate->isAlive() && candidate->reset() &&
+                (_config.validation_query.empty() ||
+                 candidate->validate(_config.validation_query))) {
                 valid_conn = candidate;
+                std::lock_guard<std::mutex> lock(_mutex);
+                ++_stats.reused;
                 break;
             }
         } catch (const std::exception& ex) {
             LOG("Candidate connection validation failed: %s", ex.what());
         }
 
-        // Candidate is dead or reset failed — mark for disconnect and
-        // decrement the pool size.
+        // Candidate is dead, reset failed or the validation query failed —
+        // mark for disconnect and decrement the pool size.
         to_disconnect.push_back(candidate);
         {
             std::lock_guard<std::mutex> lock(_mutex);
             if (_current_size > 0) --_current_size;
+            ++_stats.discarded;
         }
     }
 
@@ -106,6 +127,8 @@ std::shared_ptr<Connection> ConnectionPool::acquire(const std::u16string& connSt
             }
             throw;
         }
+        std::lock_guard<std::mutex> lock(_mutex);
+        ++_stats.created;
     }
 
     // Phase 4: Disconnect expired/bad connections outside lock.
@@ -123,11 +146,13 @@ void ConnectionPool::release(std::shared_ptr<Connection> conn) {
     bool should_disconnect = false;
     {
         std::lock_guard<std::mutex> lock(_mutex);
-        if (_pool.size() < _max_size) {
+        if (_pool.size() < _config.max_size &&
+            !pastLifetime(conn, std::chrono::steady_clock::now())) {
             conn->updateLastUsed();
             _pool.push_back(conn);
         } else {
             should_disconnect = true;
+            ++_stats.discarded;
         }
     }
     // Disconnect outside the mutex to avoid holding it during the
@@ -163,6 +188,14 @@ void ConnectionPool::close() {
     }
 }
 
+ConnectionPoolStats ConnectionPool::stats() {
+    std::lock_guard<std::mutex> lock(_mutex);
+    ConnectionPoolStats stats = _stats;
+    stats.size = _current_size;
+    stats.idle = _pool.size();
+    return stats;
+}
+
 ConnectionPoolManager& ConnectionPoolManager::getInstance() {
     static ConnectionPoolManager manager;
     return manager;
@@ -176,14 +209,19 @@ std::shared_ptr<Connection> ConnectionPoolManager::acquireConnection(const std::
         auto& pool_ref = _pools[connStr];
         if (!pool_ref) {
             LOG("Creating new connection pool");
-            pool_ref = std::make_shared<ConnectionPool>(_default_max_size, _default_idle_secs);
+            pool_ref = std::make_shared<ConnectionPool>(_config);
         }
         pool = pool_ref;
     }
     // Call acquire() outside _manager_mutex.  acquire() may release the GIL
     // during the ODBC connect call; holding _manager_mutex across that would
     // create a mutex/GIL lock-ordering deadlock.
-    return pool->acquire(connStr, attrs_before);
+    DriverMetrics& metrics = DriverMetrics::get();
+    DriverMetrics::add(metrics.pool_acquires);
+    auto started = std::chrono::steady_clock::now();
+    auto conn = pool->acquire(connStr, attrs_before);
+    metrics.pool_acquire_duration.observe(std::chrono::steady_clock::now() - started);
+    return conn;
 }
 
 void ConnectionPoolManager::returnConnection(const std::u16string& conn_str,
@@ -202,10 +240,28 @@ void ConnectionPoolManager::returnConnection(const std::u16string& conn_str,
     }
 }
 
-void ConnectionPoolManager::configure(int max_size, int idle_timeout_secs) {
+void ConnectionPoolManager::configure(const ConnectionPoolConfig& config) {
     std::lock_guard<std::mutex> lock(_manager_mutex);
-    _default_max_size = max_size;
-    _default_idle_secs = idle_timeout_secs;
+    _config = config;
+}
+
+std::vector<std::pair<std::u16string, ConnectionPoolStats>> ConnectionPoolManager::stats() {
+    std::vector<std::shared_ptr<ConnectionPool>> pools;
+    std::vector<std::pair<std::u16string, ConnectionPoolStats>> result;
+    {
+        std::lock_guard<std::mutex> lock(_manager_mutex);
+        for (auto& [conn_str, pool] : _pools) {
+            if (pool) {
+                result.emplace_back(conn_str, ConnectionPoolStats());
+                pools.push_back(pool);
+            }
+        }
+    }
+    // Read each pool's counters under its own mutex only, as acquire() does
+    for (size_t i = 0; i < pools.size(); ++i) {
+        result[i].second = pools[i]->stats();
+    }
+    return result;
 }
 
 void ConnectionPoolManager::closePools() {
@@ -7,17 +7,39 @@
 #pragma once
 #include "connection/connection.h"
 #include <chrono>
+#include <cstdint>
 #include <deque>
 #include <memory>
 #include <mutex>
 #include <string>
 #include <unordered_map>
+#include <utility>
+#include <vector>
+
+// Settings of the pools of all connection strings, see PoolingManager.enable()
+struct ConnectionPoolConfig {
+    size_t max_size = 10;              // Maximum number of connections allowed
+    int idle_timeout_secs = 300;       // Idle time before connections are stale
+    size_t min_size = 0;               // Connections kept open past the idle timeout
+    int max_lifetime_secs = 0;         // Age at which connections are retired; 0: never
+    std::u16string validation_query;   // Run before handing out a pooled connection
+};
+
+// Counters of one pool, see ConnectionPool::stats()
+struct ConnectionPoolStats {
+    size_t size = 0;         // Connections open or being opened (idle + in use)
+    size_t idle = 0;         // Connections waiting in the pool
+    uint64_t created = 0;    // New connections opened
+    uint64_t reused = 0;     // Acquires served by a pooled connection
+    uint64_t discarded = 0;  // Closed as dead, invalid, idle or past their lifetime
+    uint64_t exhausted = 0;  // Acquires refused because the pool was full
+};
 
 // Manages a fixed-size pool of reusable database connections for a
 // single connection string
 class ConnectionPool {
   public:
-    ConnectionPool(size_t max_size, int idle_timeout_secs);
+    explicit ConnectionPool(const ConnectionPoolConfig& config);
 
     // Acquires a connection from the pool or creates a new one if under limit
     std::shared_ptr<Connection> acquire(const std::u16string& connStr,
@@ -29,10 +51,17 @@ class ConnectionPool {
     // Closes all connections in the pool, releasing resources
     void close();
 
+    // Returns the pool's current size and counters
+    ConnectionPoolStats stats();
+
   private:
-    size_t _max_size;        // Maximum number of connections allowed
-    int _idle_timeout_secs;  // Idle time before connections are stale
+    // Whether conn has reached the maximum lifetime at now
+    bool pastLifetime(const std::shared_ptr<Connection>& conn,
+                      std::chrono::steady_clock::time_point now) const;
+
+    ConnectionPoolConfig _config;
     size_t _current_size = 0;
+    ConnectionPoolStats _stats;                     // Counters; size and idle set by stats()
     std::deque<std::shared_ptr<Connection>> _pool;  // Available connections
     std::mutex _mutex;                              // Mutex for thread-safe access
 };
@@ -43,7 +72,7 @@ class ConnectionPoolManager {
     // Returns the singleton instance of the manager
     static ConnectionPoolManager& getInstance();
 
-    void configure(int max_size, int idle_timeout);
+    void configure(const ConnectionPoolConfig& config);
 
     // Gets a connection from the appropriate pool (creates one if none exists)
     std::shared_ptr<Connection> acquireConnection(const std::u16string& conn_str,
@@ -55,6 +84,9 @@ class ConnectionPoolManager {
     // Closes all pools and their connections
     void closePools();
 
+    // Returns the stats of every pool with its connection string
+    std::vector<std::pair<std::u16string, ConnectionPoolStats>> stats();
+
   private:
     ConnectionPoolManager() = default;
     ~ConnectionPoolManager() = default;
@@ -64,8 +96,7 @@ class ConnectionPoolManager {
 
     // Protects access to the _pools map
     std::mutex _manager_mutex;
-    size_t _default_max_size = 10;
-    int _default_idle_secs = 300;
+    ConnectionPoolConfig _config;  // Settings of pools created from now on
 
     // Prevent copying
     ConnectionPoolManager(const ConnectionPoolManager&) = delete;
@@ -0,0 +1,132 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT license.
+
+// PEP 249 exception types of the ddbc_bindings module. Errors the bindings throw as
+// DriverError are raised in Python as the DB-API exception their SQLSTATE class maps
+// onto (ddbc_bindings.OperationalError for 08xxx, IntegrityError for 23xxx, ...),
+// with the SQLSTATE in the exception's ``sqlstate`` attribute. ddbc_bindings.Error
+// derives from RuntimeError, what the bindings raised before, so existing handlers
+// keep working; mssql_python.exceptions still maps the full SQLSTATE for the
+// exceptions raised to applications.
+
+#pragma once
+#include <pybind11/pybind11.h>
+
+#include <array>
+#include <stdexcept>
+#include <string>
+#include <utility>
+
+// An error thrown by the bindings, with the SQLSTATE of the ODBC diagnostic record it
+// was thrown for; empty for errors the bindings detect themselves.
+class DriverError : public std::runtime_error {
+  public:
+    DriverError(std::string sqlState, const std::string& message)
+        : std::runtime_error(message), _sqlState(std::move(sqlState)) {}
+
+    const std::string& sqlState() const { return _sqlState; }
+
+  private:
+    std::string _sqlState;
+};
+
+// The PEP 249 exception an SQLSTATE maps onto. The class (first two characters)
+// decides, except for the ODBC-specific HY and IM states and a few exceptions to
+// their class; errors without a SQLSTATE come from the interface itself.
+inline const char* DbApiExceptionName(const std::string& sqlState) {
+    if (sqlState.size() < 2) {
+        return "InterfaceError";
+    }
+    if (sqlState == "22019" || sqlState == "22025") {
+        return "ProgrammingError";
+    }
+    if (sqlState == "40002") {
+        return "IntegrityError";
+    }
+    if (sqlState == "HYC00" || sqlState == "IM001") {
+        return "NotSupportedError";
+    }
+    if (sqlState == "HY019" || sqlState == "HY020") {
+        return "DataError";
+    }
+    static const std::array<const char*, 8> operationalHy = {
+        "HY000", "HY001", "HY008", "HY013", "HY014", "HY018", "HYT00", "HYT01"};
+    for (const char* state : operationalHy) {
+        if (sqlState == state) {
+            return "OperationalError";
+        }
+    }
+    const std::string cls = sqlState.substr(0, 2);
+    if (cls == "07" || cls == "21" || cls == "24" || cls == "34" || cls == "3C" ||
+        cls == "3D" || cls == "3F" || cls == "42" || cls == "HY") {
+        return "ProgrammingError";
+    }
+    if (cls == "01" || cls == "08" || cls == "25" || cls == "28" || cls == "40" ||
+        cls == "IM") {
+        return "OperationalError";
+    }
+    if (cls == "0A") {
+        return "NotSupportedError";
+    }
+    if (cls == "22") {
+        return "DataError";
+    }
+    if (cls == "23" || cls == "44") {
+        return "IntegrityError";
+    }
+    return "DatabaseError";
+}
+
+// Creates Warning, Error and the Error subclasses of PEP 249 in the module, and
+// registers the translator raising a DriverError as the one its SQLSTATE maps onto.
+inline void RegisterDbApiExceptions(pybind11::module_& m) {
+    namespace py = pybind11;
+    struct Definition {
+        const char* name;
+        const char* base;
+        const char* doc;
+    };
+    static const std::array<Definition, 10> definitions = {{
+        {"Warning", nullptr, "Important warnings, such as data truncation."},
+        {"Error", nullptr, "Base class of the errors raised by the bindings."},
+        {"InterfaceError", "Error", "Errors of the database interface itself."},
+        {"DatabaseError", "Error", "Errors related to the database."},
+        {"DataError", "DatabaseError", "Errors due to the processed data."},
+        {"OperationalError", "DatabaseError", "Errors of the database's operation."},
+        {"IntegrityError", "DatabaseError", "Violations of relational integrity."},
+        {"InternalError", "DatabaseError", "Internal errors of the database."},
+        {"ProgrammingError", "DatabaseError", "Errors in the SQL or its use of the API."},
+        {"NotSupportedError", "DatabaseError", "Use of unsupported database features."},
+    }};
+    const std::string module = py::cast<std::string>(m.attr("__name__"));
+    for (const Definition& definition : definitions) {
+        PyObject* base = definition.base ? m.attr(definition.base).ptr()
+                         : std::string(definition.name) == "Warning" ? PyExc_Exception
+                                                                     : PyExc_RuntimeError;
+        const std::string qualified = module + "." + definition.name;
+        PyObject* type =
+            PyErr_NewExceptionWithDoc(qualified.c_str(), definition.doc, base, nullptr);
+        if (type == nullptr) {
+            throw py::error_already_set();
+        }
+        m.attr(definition.name) = py::reinterpret_steal<py::object>(type);
+        m.attr(definition.name).attr("sqlstate") = py::none();
+    }
+
+    // The module outlives the translator, so holding it keeps the types alive
+    static py::handle bindings = m.inc_ref();
+    py::register_exception_translator([](std::exception_ptr error) {
+        try {
+            if (error) {
+                std::rethrow_exception(error);
+            }
+        } catch (const DriverError& e) {
+            py::object type = bindings.attr(DbApiExceptionName(e.sqlState()));
+            py::object exception = type(e.what());
+            if (!e.sqlState().empty()) {
+                exception.attr("sqlstate") = e.sqlState();
+            }
+            PyErr_SetObject(type.ptr(), exception.ptr());
+        }
+    });
+}
@@ -11,13 +11,15 @@
 #include "utf_utils.h"
 
 
-#include <algorithm>  // std::min
+#include <algorithm>  // std::min, std::max
+#include <atomic>
 #include <cctype>
 #include <cstdint>
 #include <cstring>  // For std::memcpy
 #include <filesystem>
 #include <iomanip>  // std::setw, std::setfill
 #include <iostream>
+#include <limits>  // std::numeric_limits
 #include <utility>  // std::forward
 
 
@@ -36,6 +38,7 @@
 #define SQL_SS_UDT (-151)
 #define SQL_SS_VARIANT (-150)
 #define SQL_CA_SS_VARIANT_TYPE (1215)
+#define SQL_CA_SS_COLUMN_COLLATION (1214)
 #ifndef SQL_C_DATE
 #define SQL_C_DATE (9)
 #endif
@@ -172,8 +175,153 @@ py::object get_uuid_class() {
     }
     return py::module_::import("uuid").attr("UUID");
 }
+
+// mssql_python.temporal is imported on first use: it is part of the package
+// that imports this module
+static py::object precise_datetime_class;
+static py::object precise_time_class;
+
+py::object get_precise_datetime_class() {
+    if (!precise_datetime_class) {
+        precise_datetime_class =
+            py::module_::import("mssql_python.temporal").attr("PreciseDateTime");
+    }
+    return precise_datetime_class;
+}
+
+py::object get_precise_time_class() {
+    if (!precise_time_class) {
+        precise_time_class = py::module_::import("mssql_python.temporal").attr("PreciseTime");
+    }
+    return precise_time_class;
+}
 }  // namespace PythonObjectCache
 
+//-------------------------------------------------------------------------------------------------
+// Temporal values: with temporal_precision = "nanoseconds", DATETIME2, DATETIMEOFFSET and TIME
+// values are returned as mssql_python.temporal.PreciseDateTime / PreciseTime, keeping the digits
+// below the microsecond that datetime.datetime and datetime.time cannot hold.
+//-------------------------------------------------------------------------------------------------
+static std::atomic<bool> g_temporalNanoseconds{false};
+
+void DDBCSetTemporalNanoseconds(bool enabled) {
+    g_temporalNanoseconds.store(enabled, std::memory_order_relaxed);
+}
+
+// A datetime (with tzinfo unless None) from the fields of an ODBC timestamp, fraction in ns
+static py::object MakeDateTimeObject(int year, int month, int day, int hour, int minute,
+                                     int second, SQLUINTEGER fraction,
+                                     const py::object& tzinfo = py::none()) {
+    if (g_temporalNanoseconds.load(std::memory_order_relaxed)) {
+        return PythonObjectCache::get_precise_datetime_class()(
+            year, month, day, hour, minute, second, fraction / 1000, tzinfo,
+            py::arg("nanosecond") = fraction % 1000);
+    }
+    return PythonObjectCache::get_datetime_class()(year, month, day, hour, minute, second,
+                                                   fraction / 1000, tzinfo);
+}
+
+// A time from the fields of an SQL_SS_TIME2_STRUCT, fraction in ns
+static py::object MakeTimeObject(int hour, int minute, int second, SQLUINTEGER fraction) {
+    if (g_temporalNanoseconds.load(std::memory_order_relaxed)) {
+        return PythonObjectCache::get_precise_time_class()(
+            hour, minute, second, fraction / 1000, py::none(),
+            py::arg("nanosecond") = fraction % 1000);
+    }
+    return PythonObjectCache::get_time_class()(hour, minute, second, fraction / 1000);
+}
+
+// The fraction of the second of a datetime parameter in ns, with the nanosecond digits of a
+// PreciseDateTime (or a pandas Timestamp, which has the same attribute) down to the 100 ns
+// precision of SQL Server
+static SQLUINTEGER TemporalFractionNs(const py::handle& value) {
+    SQLUINTEGER fraction = static_cast<SQLUINTEGER>(value.attr("microsecond").cast<int>() * 1000);
+    if (py::hasattr(value, "nanosecond")) {
+        fraction += static_cast<SQLUINTEGER>(value.attr("nanosecond").cast<int>()) / 100 * 100;
+    }
+    return fraction;
+}
+
+//-------------------------------------------------------------------------------------------------
+// Numeric values: DECIMAL, NUMERIC and MONEY are bound and fetched as SQL_NUMERIC_STRUCT, a
+// 128-bit magnitude with sign, precision and scale, so no text formatting or locale is involved.
+//-------------------------------------------------------------------------------------------------
+
+// Set the SQL_C_NUMERIC record recNumber of the application descriptor descAttr
+// (SQL_ATTR_APP_PARAM_DESC or SQL_ATTR_APP_ROW_DESC) to precision and scale. Without this the
+// driver converts with its default precision and a scale of 0. Setting SQL_DESC_TYPE unbinds the
+// record, so the buffers SQLBindCol/SQLBindParameter bound (dataPtr and its length/indicator
+// strLenOrIndPtr, if any) are set again, the data pointer last since setting it runs the
+// driver's consistency check. See
+// https://learn.microsoft.com/en-us/sql/odbc/reference/appendixes/retrieve-numeric-data-sql-numeric-struct-kb222831
+static SQLRETURN SetNumericDescRecord(SQLHSTMT hStmt, SQLINTEGER descAttr, SQLSMALLINT recNumber,
+                                      SQLSMALLINT precision, SQLSMALLINT scale,
+                                      SQLPOINTER dataPtr = nullptr,
+                                      SQLLEN* strLenOrIndPtr = nullptr) {
+    SQLHDESC hDesc = nullptr;
+    SQLRETURN rc = SQLGetStmtAttr_ptr(hStmt, descAttr, &hDesc, 0, NULL);
+    if (!SQL_SUCCEEDED(rc)) {
+        LOG("SetNumericDescRecord: SQLGetStmtAttr failed for record %d - SQLRETURN=%d",
+            recNumber, rc);
+        return rc;
+    }
+    rc = SQLSetDescField_ptr(hDesc, recNumber, SQL_DESC_TYPE, (SQLPOINTER)SQL_C_NUMERIC, 0);
+    if (SQL_SUCCEEDED(rc)) {
+        rc = SQLSetDescField_ptr(hDesc, recNumber, SQL_DESC_PRECISION,
+                                 reinterpret_cast<SQLPOINTER>(static_cast<intptr_t>(precision)), 0);
+    }
+    if (SQL_SUCCEEDED(rc)) {
+        rc = SQLSetDescField_ptr(hDesc, recNumber, SQL_DESC_SCALE,
+                                 reinterpret_cast<SQLPOINTER>(static_cast<intptr_t>(scale)), 0);
+    }
+    if (SQL_SUCCEEDED(rc) && strLenOrIndPtr != nullptr) {
+        rc = SQLSetDescField_ptr(hDesc, recNumber, SQL_DESC_OCTET_LENGTH_PTR, strLenOrIndPtr, 0);
+    }
+    if (SQL_SUCCEEDED(rc) && strLenOrIndPtr != nullptr) {
+        rc = SQLSetDescField_ptr(hDesc, recNumber, SQL_DESC_INDICATOR_PTR, strLenOrIndPtr, 0);
+    }
+    if (SQL_SUCCEEDED(rc) && dataPtr != nullptr) {
+        rc = SQLSetDescField_ptr(hDesc, recNumber, SQL_DESC_DATA_PTR, dataPtr, 0);
+    }
+    if (!SQL_SUCCEEDED(rc)) {
+        LOG("SetNumericDescRecord: SQLSetDescField failed for record %d (precision=%d, "
+            "scale=%d) - SQLRETURN=%d",
+            recNumber, precision, scale, rc);
+    }
+    return rc;
+}
+
+// The decimal.Decimal of an SQL_NUMERIC_STRUCT, exactly: the decimal digits of its
+// little-endian magnitude, with the scale as the negative exponent (so NUMERIC(10, 2) 1.5 is
+// Decimal("1.50"), as the server formats it)
+static py::object NumericStructToDecimal(const SQL_NUMERIC_STRUCT& value) {
+    unsigned char magnitude[SQL_MAX_NUMERIC_LEN];
+    std::memcpy(magnitude, value.val, SQL_MAX_NUMERIC_LEN);
+    // 2^128 has 39 decimal digits
+    unsigned char digits[40];
+    int count = 0;
+    bool remaining = true;
+    while (remaining && count < 40) {
+        // Divide the magnitude by 10 in place, most significant byte first
+        unsigned int remainder = 0;
+        remaining = false;
+        for (int b = SQL_MAX_NUMERIC_LEN - 1; b >= 0; --b) {
+            unsigned int current = (remainder << 8) | magnitude[b];
+            magnitude[b] = static_cast<unsigned char>(current / 10);
+            remainder = current % 10;
+            remaining = remaining || magnitude[b] != 0;
+        }
+        digits[count++] = static_cast<unsigned char>(remainder);
+    }
+    py::tuple digitTuple(count);
+    for (int d = 0; d < count; ++d) {
+        digitTuple[d] = py::int_(digits[count - 1 - d]);
+    }
+    // SQL_NUMERIC_STRUCT sign: 1 positive, 0 negative; Decimal sign: 0 positive, 1 negative
+    return PythonObjectCache::get_decimal_class()(
+        py::make_tuple(value.sign == 1 ? 0 : 1, digitTuple, -static_cast<int>(value.scale)));
+}
+
 //-------------------------------------------------------------------------------------------------
 // Class definitions
 //-------------------------------------------------------------------------------------------------
@@ -390,6 +538,7 @@ SQLEndTranFunc SQLEndTran_ptr = nullptr;
 SQLFreeHandleFunc SQLFreeHandle_ptr = nullptr;
 SQLDisconnectFunc SQLDisconnect_ptr = nullptr;
 SQLFreeStmtFunc SQLFreeStmt_ptr = nullptr;
+SQLCancelFunc SQLCancel_ptr = nullptr;
 
 // Diagnostic APIs
 SQLGetDiagRecFunc SQLGetDiagRec_ptr = nullptr;
@@ -441,6 +590,16 @@ std::string MakeParamMismatchErrorStr(const SQLSMALLINT cType, const int paramIn
     return errorString;
 }
 
+// True for text/binary parameter values, and for file-like objects (anything
+// with read()) bound as data-at-execution, which are read while executing
+static bool IsTextOrBinaryParam(const py::object& param, const ParamInfo& paramInfo) {
+    if (py::isinstance<py::str>(param) || py::isinstance<py::bytearray>(param) ||
+        py::isinstance<py::bytes>(param)) {
+        return true;
+    }
+    return paramInfo.isDAE && py::hasattr(param, "read");
+}
+
 // This function allocates a buffer of ParamType, stores it as a void* in
 // paramBuffers for book-keeping and then returns a ParamType* to the allocated
 // memory. ctorArgs are the arguments to ParamType's constructor used while
@@ -594,8 +753,7 @@ SQLRETURN BindParameters(SqlHandle& handle, SQLHANDLE hStmt, const py::list& par
         // TODO: Add more data types like money, guid, interval, TVPs etc.
         switch (paramInfo.paramCType) {
             case SQL_C_CHAR: {
-                if (!py::isinstance<py::str>(param) && !py::isinstance<py::bytearray>(param) &&
-                    !py::isinstance<py::bytes>(param)) {
+                if (!IsTextOrBinaryParam(param, paramInfo)) {
                     ThrowStdException(MakeParamMismatchErrorStr(paramInfo.paramCType, paramIndex));
                 }
                 if (paramInfo.isDAE) {
@@ -652,8 +810,7 @@ SQLRETURN BindParameters(SqlHandle& handle, SQLHANDLE hStmt, const py::list& par
                 break;
             }
             case SQL_C_BINARY: {
-                if (!py::isinstance<py::str>(param) && !py::isinstance<py::bytearray>(param) &&
-                    !py::isinstance<py::bytes>(param)) {
+                if (!IsTextOrBinaryParam(param, paramInfo)) {
                     ThrowStdException(MakeParamMismatchErrorStr(paramInfo.paramCType, paramIndex));
                 }
                 if (paramInfo.isDAE) {
@@ -687,8 +844,7 @@ SQLRETURN BindParameters(SqlHandle& handle, SQLHANDLE hStmt, const py::list& par
                 break;
             }
             case SQL_C_WCHAR: {
-                if (!py::isinstance<py::str>(param) && !py::isinstance<py::bytearray>(param) &&
-                    !py::isinstance<py::bytes>(param)) {
+                if (!IsTextOrBinaryParam(param, paramInfo)) {
                     ThrowStdException(MakeParamMismatchErrorStr(paramInfo.paramCType, paramIndex));
                 }
                 if (paramInfo.isDAE) {
@@ -873,9 +1029,8 @@ SQLRETURN BindParameters(SqlHandle& handle, SQLHANDLE hStmt, const py::list& par
                 dtoPtr->hour = static_cast<SQLUSMALLINT>(param.attr("hour").cast<int>());
                 dtoPtr->minute = static_cast<SQLUSMALLINT>(param.attr("minute").cast<int>());
                 dtoPtr->second = static_cast<SQLUSMALLINT>(param.attr("second").cast<int>());
-                // SQL server supports in ns, but python datetime supports in µs
-                dtoPtr->fraction =
-                    static_cast<SQLUINTEGER>(param.attr("microsecond").cast<int>() * 1000);
+                // In ns; python datetime supports µs, PreciseDateTime ns
+                dtoPtr->fraction = TemporalFractionNs(param);
 
                 py::object utcoffset = tzinfo.attr("utcoffset")(param);
                 if (utcoffset.is_none()) {
@@ -919,9 +1074,8 @@ SQLRETURN BindParameters(SqlHandle& handle, SQLHANDLE hStmt, const py::list& par
                     static_cast<SQLUSMALLINT>(param.attr("minute").cast<int>());
                 sqlTimestampPtr->second =
                     static_cast<SQLUSMALLINT>(param.attr("second").cast<int>());
-                // SQL server supports in ns, but python datetime supports in µs
-                sqlTimestampPtr->fraction = static_cast<SQLUINTEGER>(
-                    param.attr("microsecond").cast<int>() * 1000);  // Convert µs to ns
+                // In ns; python datetime supports µs, PreciseDateTime ns
+                sqlTimestampPtr->fraction = TemporalFractionNs(param);
                 dataPtr = static_cast<void*>(sqlTimestampPtr);
                 break;
             }
@@ -949,10 +1103,15 @@ SQLRETURN BindParameters(SqlHandle& handle, SQLHANDLE hStmt, const py::list& par
                 break;
             }
             case SQL_C_GUID: {
-                if (!py::isinstance<py::bytes>(param)) {
+                py::bytes uuid_bytes;
+                if (py::isinstance<py::bytes>(param)) {
+                    uuid_bytes = param.cast<py::bytes>();
+                } else if (py::isinstance(param, PythonObjectCache::get_uuid_class())) {
+                    // uuid.UUID objects are bound by their bytes_le, as in BindParameterArray
+                    uuid_bytes = param.attr("bytes_le").cast<py::bytes>();
+                } else {
                     ThrowStdException(MakeParamMismatchErrorStr(paramInfo.paramCType, paramIndex));
                 }
-                py::bytes uuid_bytes = param.cast<py::bytes>();
                 const unsigned char* uuid_data =
                     reinterpret_cast<const unsigned char*>(PyBytes_AS_STRING(uuid_bytes.ptr()));
                 if (PyBytes_GET_SIZE(uuid_bytes.ptr()) != 16) {
@@ -997,50 +1156,16 @@ SQLRETURN BindParameters(SqlHandle& handle, SQLHANDLE hStmt, const py::list& par
                 paramIndex, rc, paramInfo.paramCType, paramInfo.paramSQLType);
             return rc;
         }
-        // Special handling for Numeric type -
-        // https://learn.microsoft.com/en-us/sql/odbc/reference/appendixes/retrieve-numeric-data-sql-numeric-struct-kb222831?view=sql-server-ver16#sql_c_numeric-overview
+        // Precision and scale of a SQL_C_NUMERIC parameter go in its APD record
         if (paramInfo.paramCType == SQL_C_NUMERIC) {
-            SQLHDESC hDesc = nullptr;
-            rc = SQLGetStmtAttr_ptr(hStmt, SQL_ATTR_APP_PARAM_DESC, &hDesc, 0, NULL);
-            if (!SQL_SUCCEEDED(rc)) {
-                LOG("BindParameters: SQLGetStmtAttr(SQL_ATTR_APP_PARAM_DESC) "
-                    "failed for param[%d] - SQLRETURN=%d",
-                    paramIndex, rc);
-                return rc;
-            }
-            rc = SQLSetDescField_ptr(hDesc, 1, SQL_DESC_TYPE, (SQLPOINTER)SQL_C_NUMERIC, 0);
-            if (!SQL_SUCCEEDED(rc)) {
-                LOG("BindParameters: SQLSetDescField(SQL_DESC_TYPE) failed for "
-                    "param[%d] - SQLRETURN=%d",
-                    paramIndex, rc);
-                return rc;
-            }
             SQL_NUMERIC_STRUCT* numericPtr = reinterpret_cast<SQL_NUMERIC_STRUCT*>(dataPtr);
-            rc = SQLSetDescField_ptr(
-                hDesc, 1, SQL_DESC_PRECISION,
-                reinterpret_cast<SQLPOINTER>(static_cast<uintptr_t>(numericPtr->precision)), 0);
+            rc = SetNumericDescRecord(hStmt, SQL_ATTR_APP_PARAM_DESC,
+                                      static_cast<SQLSMALLINT>(paramIndex + 1),
+                                      numericPtr->precision, numericPtr->scale, dataPtr,
+                                      strLenOrIndPtr);
             if (!SQL_SUCCEEDED(rc)) {
-                LOG("BindParameters: SQLSetDescField(SQL_DESC_PRECISION) "
-                    "failed for param[%d] - SQLRETURN=%d",
-                    paramIndex, rc);
-                return rc;
-            }
-
-            rc = SQLSetDescField_ptr(
-                hDesc, 1, SQL_DESC_SCALE,
-                reinterpret_cast<SQLPOINTER>(static_cast<intptr_t>(numericPtr->scale)), 0);
-            if (!SQL_SUCCEEDED(rc)) {
-                LOG("BindParameters: SQLSetDescField(SQL_DESC_SCALE) failed "
-                    "for param[%d] - SQLRETURN=%d",
-                    paramIndex, rc);
-                return rc;
-            }
-
-            rc = SQLSetDescField_ptr(hDesc, 1, SQL_DESC_DATA_PTR,
-                                     reinterpret_cast<SQLPOINTER>(numericPtr), 0);
-            if (!SQL_SUCCEEDED(rc)) {
-                LOG("BindParameters: SQLSetDescField(SQL_DESC_DATA_PTR) failed "
-                    "for param[%d] - SQLRETURN=%d",
+                LOG("BindParameters: Setting the numeric descriptor failed for param[%d] - "
+                    "SQLRETURN=%d",
                     paramIndex, rc);
                 return rc;
             }
@@ -1096,9 +1221,8 @@ static bool is_python_finalizing() {
     }
 }
 
-// TODO: Add more nuanced exception classes
 void ThrowStdException(const std::string& message) {
-    throw std::runtime_error(message);
+    throw DriverError("", message);
 }
 std::string GetLastErrorMessage();
 
@@ -1445,6 +1569,7 @@ DriverHandle LoadDriverOrThrowException() {
     SQLDisconnect_ptr = GetFunctionPointer<SQLDisconnectFunc>(handle, "SQLDisconnect");
     SQLFreeHandle_ptr = GetFunctionPointer<SQLFreeHandleFunc>(handle, "SQLFreeHandle");
     SQLFreeStmt_ptr = GetFunctionPointer<SQLFreeStmtFunc>(handle, "SQLFreeStmt");
+    SQLCancel_ptr = GetFunctionPointer<SQLCancelFunc>(handle, "SQLCancel");
 
     SQLGetDiagRec_ptr = GetFunctionPointer<SQLGetDiagRecFunc>(handle, "SQLGetDiagRecW");
 
@@ -1461,6 +1586,7 @@ DriverHandle LoadDriverOrThrowException() {
                    SQLFetchScroll_ptr && SQLGetData_ptr && SQLNumResultCols_ptr && SQLBindCol_ptr &&
                    SQLDescribeCol_ptr && SQLMoreResults_ptr && SQLColAttribute_ptr &&
                    SQLEndTran_ptr && SQLDisconnect_ptr && SQLFreeHandle_ptr && SQLFreeStmt_ptr &&
+                   SQLCancel_ptr &&
                    SQLGetDiagRec_ptr && SQLGetInfo_ptr && SQLParamData_ptr && SQLPutData_ptr &&
                    SQLTables_ptr && SQLDescribeParam_ptr && SQLGetTypeInfo_ptr &&
                    SQLProcedures_ptr && SQLForeignKeys_ptr && SQLPrimaryKeys_ptr &&
@@ -1780,22 +1906,36 @@ ErrorInfo SQLCheckError_Wrap(SQLSMALLINT handleType, SqlHandlePtr handle, SQLRET
             DriverLoader::getInstance().loadDriver();  // Load the driver
         }
 
-        SQLWCHAR sqlState[6], message[SQL_MAX_MESSAGE_LENGTH_SQLSERVER];
-        SQLINTEGER nativeError;
-        SQLSMALLINT messageLen;
-
-        SQLRETURN diagReturn =
-            SQLGetDiagRec_ptr(handleType, rawHandle, 1, sqlState, &nativeError, message,
-                              SQL_MAX_MESSAGE_LENGTH_SQLSERVER, &messageLen);
+        // A batch can fail with several errors (e.g. multiple RAISERRORs or a
+        // constraint violation followed by the statement abort). The first record
+        // describes the exception; all of them are kept in records.
+        for (SQLSMALLINT recNumber = 1;; recNumber++) {
+            SQLWCHAR sqlState[6] = {0};
+            SQLWCHAR message[SQL_MAX_MESSAGE_LENGTH_SQLSERVER] = {0};
+            SQLINTEGER nativeError = 0;
+            SQLSMALLINT messageLen = 0;
+
+            SQLRETURN diagReturn =
+                SQLGetDiagRec_ptr(handleType, rawHandle, recNumber, sqlState, &nativeError,
+                                  message, SQL_MAX_MESSAGE_LENGTH_SQLSERVER, &messageLen);
+            if (!SQL_SUCCEEDED(diagReturn)) {
+                break;
+            }
 
-        if (SQL_SUCCEEDED(diagReturn)) {
             std::u16string sqlStateUtf16 = dupeSqlWCharAsUtf16Le(sqlState, 5);
             std::u16string messageUtf16 = dupeSqlWCharAsUtf16Le(
                 message, std::min(static_cast<size_t>(messageLen),
                                   static_cast<size_t>(SQL_MAX_MESSAGE_LENGTH_SQLSERVER - 1)));
+            std::string stateStr = utf16LeToUtf8Alloc(std::move(sqlStateUtf16));
+            std::string msgStr = utf16LeToUtf8Alloc(std::move(messageUtf16));
 
-            errorInfo.sqlState = utf16LeToUtf8Alloc(std::move(sqlStateUtf16));
-            errorInfo.ddbcErrorMsg = utf16LeToUtf8Alloc(std::move(messageUtf16));
+            if (recNumber == 1) {
+                errorInfo.sqlState = stateStr;
+                errorInfo.ddbcErrorMsg = msgStr;
+                errorInfo.nativeError = static_cast<int>(nativeError);
+            }
+            errorInfo.records.emplace_back(std::move(stateStr), static_cast<int>(nativeError),
+                                           std::move(msgStr));
         }
     }
     return errorInfo;
@@ -1847,8 +1987,90 @@ py::list SQLGetAllDiagRecords(SqlHandlePtr handle) {
     return records;
 }
 
+// Bytes of a value as counted by DriverMetrics: strings as UTF-16, binary values as
+// is, others not at all
+static uint64_t MetricsValueBytes(PyObject* value) {
+    if (PyUnicode_Check(value)) {
+        return static_cast<uint64_t>(PyUnicode_GET_LENGTH(value)) * sizeof(SQLWCHAR);
+    }
+    if (PyBytes_Check(value)) {
+        return static_cast<uint64_t>(PyBytes_GET_SIZE(value));
+    }
+    if (PyByteArray_Check(value)) {
+        return static_cast<uint64_t>(PyByteArray_GET_SIZE(value));
+    }
+    return 0;
+}
+
+// Bytes of the values of a list (a row, or a column of executemany parameters)
+static uint64_t MetricsListBytes(PyObject* values) {
+    uint64_t bytes = 0;
+    Py_ssize_t size = PyList_GET_SIZE(values);
+    for (Py_ssize_t i = 0; i < size; ++i) {
+        bytes += MetricsValueBytes(PyList_GET_ITEM(values, i));
+    }
+    return bytes;
+}
+
+// Bytes of a statement sent: its SQL text and string/binary parameters
+static uint64_t MetricsSentBytes(const std::u16string& query, const py::list& params) {
+    uint64_t bytes = static_cast<uint64_t>(query.size()) * sizeof(char16_t);
+    for (const auto& param : params) {
+        PyObject* value = param.ptr();
+        bytes += PyList_Check(value) ? MetricsListBytes(value) : MetricsValueBytes(value);
+    }
+    return bytes;
+}
+
+// Records an execution on handle that started at started and returned ret, in the
+// driver metrics and the usage of the handle's connection
+static void RecordStatementMetrics(const SqlHandlePtr& handle,
+                                   std::chrono::steady_clock::time_point started, SQLRETURN ret,
+                                   uint64_t sentBytes) {
+    DriverMetrics& metrics = DriverMetrics::get();
+    metrics.statement_duration.observe(std::chrono::steady_clock::now() - started);
+    DriverMetrics::add(metrics.statements_executed);
+    DriverMetrics::add(metrics.bytes_sent, sentBytes);
+    if (!SQL_SUCCEEDED(ret) && ret != SQL_NO_DATA && ret != SQL_NEED_DATA) {
+        DriverMetrics::add(metrics.statement_errors);
+    }
+    ConnectionUsage* usage = handle ? handle->usage.get() : nullptr;
+    if (!usage) {
+        return;
+    }
+    DriverMetrics::add(usage->statements_executed);
+    DriverMetrics::add(usage->bytes_sent, sentBytes);
+    SQLLEN rowCount = 0;
+    if (SQL_SUCCEEDED(ret) && SQLRowCount_ptr &&
+        SQL_SUCCEEDED(SQLRowCount_ptr(handle->get(), &rowCount)) && rowCount > 0) {
+        DriverMetrics::add(usage->rows_affected, static_cast<uint64_t>(rowCount));
+    }
+}
+
+// Records the rows appended to rows from index first on, fetched from handle
+static void RecordFetchMetrics(const SqlHandlePtr& handle, const py::list& rows, size_t first) {
+    size_t count = rows.size();
+    if (count <= first) {
+        return;
+    }
+    uint64_t bytes = 0;
+    for (size_t i = first; i < count; ++i) {
+        PyObject* row = PyList_GET_ITEM(rows.ptr(), static_cast<Py_ssize_t>(i));
+        if (PyList_Check(row)) {
+            bytes += MetricsListBytes(row);
+        }
+    }
+    DriverMetrics& metrics = DriverMetrics::get();
+    DriverMetrics::add(metrics.rows_fetched, count - first);
+    DriverMetrics::add(metrics.bytes_received, bytes);
+    if (handle && handle->usage) {
+        DriverMetrics::add(handle->usage->rows_fetched, count - first);
+        DriverMetrics::add(handle->usage->bytes_received, bytes);
+    }
+}
+
 // Wrap SQLExecDirect
-SQLRETURN SQLExecDirect_wrap(SqlHandlePtr StatementHandle, const std::u16string& Query) {
+SQLRETURN SQLExecDirect_wrap(SqlHandlePtr StatementHandle, const SqlText& Query) {
     LOG("SQLExecDirect: Executing query directly - statement_handle=%p, "
         "query_length=%zu chars",
         (void*)StatementHandle->get(), Query.length());
@@ -1867,6 +2089,7 @@ SQLRETURN SQLExecDirect_wrap(SqlHandlePtr StatementHandle, const std::u16string&
 
     SQLWCHAR* queryPtr = reinterpretU16stringAsSqlWChar(Query);
     SQLRETURN ret;
+    auto started = std::chrono::steady_clock::now();
     {
         // Release the GIL during the blocking ODBC call so that other Python
         // threads (e.g. asyncio event loop, heartbeat threads) can run while
@@ -1874,6 +2097,7 @@ SQLRETURN SQLExecDirect_wrap(SqlHandlePtr StatementHandle, const std::u16string&
         py::gil_scoped_release release;
         ret = SQLExecDirect_ptr(StatementHandle->get(), queryPtr, SQL_NTS);
     }
+    RecordStatementMetrics(StatementHandle, started, ret, MetricsSentBytes(Query, py::list()));
     if (!SQL_SUCCEEDED(ret)) {
         LOG("SQLExecDirect: Query execution failed - SQLRETURN=%d", ret);
     }
@@ -1914,10 +2138,10 @@ SQLRETURN SQLTables_wrap(SqlHandlePtr StatementHandle, const std::u16string& cat
 // statement and binds the parameters. Otherwise, it executes the query
 // directly. 'usePrepare' parameter can be used to disable the prepare step for
 // queries that might already be prepared in a previous call.
-SQLRETURN SQLExecute_wrap(const SqlHandlePtr statementHandle, const std::u16string& query,
-                          const py::list& params, std::vector<ParamInfo>& paramInfos,
-                          py::list& isStmtPrepared, const bool usePrepare,
-                          const py::dict& encodingSettings) {
+static SQLRETURN SQLExecute_impl(const SqlHandlePtr statementHandle,
+                                 const std::u16string& query, const py::list& params,
+                                 std::vector<ParamInfo>& paramInfos, py::list& isStmtPrepared,
+                                 const bool usePrepare, const py::dict& encodingSettings) {
     LOG("SQLExecute: Executing %s query - statement_handle=%p, "
         "param_count=%zu, query_length=%zu chars",
         (params.size() > 0 ? "parameterized" : "direct"), (void*)statementHandle->get(),
@@ -2124,8 +2348,46 @@ SQLRETURN SQLExecute_wrap(const SqlHandlePtr statementHandle, const std::u16stri
                             return rc;
                         }
                     }
+                } else if (py::hasattr(pyObj, "read")) {
+                    // A file-like object: send what each read() returns until it
+                    // returns nothing, so the value never has to be in memory whole
+                    py::object read = pyObj.attr("read");
+                    size_t totalBytes = 0;
+                    while (true) {
+                        py::object chunk = read(DAE_CHUNK_SIZE);
+                        std::string data;
+                        if (py::isinstance<py::str>(chunk)) {
+                            const std::string encoding =
+                                matchedInfo->paramCType == SQL_C_WCHAR ? "utf-16-le" : charEncoding;
+                            data = chunk.attr("encode")(encoding, "strict").cast<std::string>();
+                        } else if (!chunk.is_none()) {
+                            PyObject* raw = PyBytes_FromObject(chunk.ptr());
+                            if (!raw) {
+                                throw py::error_already_set();
+                            }
+                            data = py::reinterpret_steal<py::bytes>(raw).cast<std::string>();
+                        } else {
+                            ThrowStdException("read() of a stream parameter returned None; "
+                                              "non-blocking streams are not supported");
+                        }
+                        if (data.empty() && totalBytes > 0) {
+                            break;
+                        }
+                        // An empty first read sends an empty (not NULL) value
+                        rc = putData((SQLPOINTER)data.data(), static_cast<SQLLEN>(data.size()));
+                        if (!SQL_SUCCEEDED(rc)) {
+                            LOG("SQLExecute: SQLPutData failed for stream chunk - offset=%zu",
+                                totalBytes);
+                            return rc;
+                        }
+                        if (data.empty()) {
+                            break;
+                        }
+                        totalBytes += data.size();
+                    }
+                    LOG("SQLExecute: DAE stream parameter sent %zu bytes", totalBytes);
                 } else {
-                    ThrowStdException("DAE only supported for str or bytes");
+                    ThrowStdException("DAE only supported for str, bytes or file-like objects");
                 }
             }
             if (!SQL_SUCCEEDED(rc)) {
@@ -2555,8 +2817,7 @@ SQLRETURN BindParameterArray(SqlHandle& handle, SQLHANDLE hStmt, const py::list&
                             tsArray[i].hour = dtObj.attr("hour").cast<SQLUSMALLINT>();
                             tsArray[i].minute = dtObj.attr("minute").cast<SQLUSMALLINT>();
                             tsArray[i].second = dtObj.attr("second").cast<SQLUSMALLINT>();
-                            tsArray[i].fraction = static_cast<SQLUINTEGER>(
-                                dtObj.attr("microsecond").cast<int>() * 1000);  // µs to ns
+                            tsArray[i].fraction = TemporalFractionNs(dtObj);
                             strLenOrIndArray[i] = 0;
                         }
                     }
@@ -2610,10 +2871,8 @@ SQLRETURN BindParameterArray(SqlHandle& handle, SQLHANDLE hStmt, const py::list&
                                 static_cast<SQLUSMALLINT>(param.attr("minute").cast<int>());
                             dtoArray[i].second =
                                 static_cast<SQLUSMALLINT>(param.attr("second").cast<int>());
-                            // SQL server supports in ns, but python datetime
-                            // supports in µs
-                            dtoArray[i].fraction = static_cast<SQLUINTEGER>(
-                                param.attr("microsecond").cast<int>() * 1000);
+                            // In ns; python datetime supports µs, PreciseDateTime ns
+                            dtoArray[i].fraction = TemporalFractionNs(param);
 
                             // Compute and preserve the original UTC offset.
                             py::object utcoffset = tzinfo.attr("utcoffset")(param);
@@ -2781,6 +3040,16 @@ SQLRETURN BindParameterArray(SqlHandle& handle, SQLHANDLE hStmt, const py::list&
                     paramIndex, rc);
                 return rc;
             }
+            if (info.paramCType == SQL_C_NUMERIC) {
+                // Every row is bound at the column's precision and scale (see executemany)
+                rc = SetNumericDescRecord(hStmt, SQL_ATTR_APP_PARAM_DESC,
+                                          static_cast<SQLSMALLINT>(paramIndex + 1),
+                                          static_cast<SQLSMALLINT>(info.columnSize),
+                                          info.decimalDigits, dataPtr, strLenOrIndArray);
+                if (!SQL_SUCCEEDED(rc)) {
+                    return rc;
+                }
+            }
         }
     } catch (...) {
         LOG("BindParameterArray: Exception during binding, cleaning up "
@@ -2794,10 +3063,29 @@ SQLRETURN BindParameterArray(SqlHandle& handle, SQLHANDLE hStmt, const py::list&
     return SQL_SUCCESS;
 }
 
-SQLRETURN SQLExecuteMany_wrap(const SqlHandlePtr statementHandle, const std::u16string& query,
-                              const py::list& columnwise_params,
-                              std::vector<ParamInfo>& paramInfos, size_t paramSetSize,
-                              const py::dict& encodingSettings) {
+SQLRETURN SQLExecute_wrap(const SqlHandlePtr statementHandle, const SqlText& query,
+                          const py::list& params, std::vector<ParamInfo>& paramInfos,
+                          py::list& isStmtPrepared, const bool usePrepare,
+                          const py::dict& encodingSettings) {
+    uint64_t sentBytes = MetricsSentBytes(query, params);
+    auto started = std::chrono::steady_clock::now();
+    SQLRETURN ret;
+    try {
+        ret = SQLExecute_impl(statementHandle, query, params, paramInfos, isStmtPrepared,
+                              usePrepare, encodingSettings);
+    } catch (...) {
+        RecordStatementMetrics(statementHandle, started, SQL_ERROR, sentBytes);
+        throw;
+    }
+    RecordStatementMetrics(statementHandle, started, ret, sentBytes);
+    return ret;
+}
+
+static SQLRETURN SQLExecuteMany_impl(const SqlHandlePtr statementHandle,
+                                     const std::u16string& query,
+                                     const py::list& columnwise_params,
+                                     std::vector<ParamInfo>& paramInfos, size_t paramSetSize,
+                                     const py::dict& encodingSettings) {
     LOG("SQLExecuteMany: Starting batch execution - param_count=%zu, "
         "param_set_size=%zu",
         columnwise_params.size(), paramSetSize);
@@ -2959,6 +3247,24 @@ SQLRETURN SQLExecuteMany_wrap(const SqlHandlePtr statementHandle, const std::u16
     }
 }
 
+SQLRETURN SQLExecuteMany_wrap(const SqlHandlePtr statementHandle, const std::u16string& query,
+                              const py::list& columnwise_params,
+                              std::vector<ParamInfo>& paramInfos, size_t paramSetSize,
+                              const py::dict& encodingSettings) {
+    uint64_t sentBytes = MetricsSentBytes(query, columnwise_params);
+    auto started = std::chrono::steady_clock::now();
+    SQLRETURN ret;
+    try {
+        ret = SQLExecuteMany_impl(statementHandle, query, columnwise_params, paramInfos,
+                                  paramSetSize, encodingSettings);
+    } catch (...) {
+        RecordStatementMetrics(statementHandle, started, SQL_ERROR, sentBytes);
+        throw;
+    }
+    RecordStatementMetrics(statementHandle, started, ret, sentBytes);
+    return ret;
+}
+
 // Wrap SQLNumResultCols
 SQLSMALLINT SQLNumResultCols_wrap(SqlHandlePtr statementHandle) {
     LOG("SQLNumResultCols: Getting number of columns in result set for "
@@ -2971,8 +3277,13 @@ SQLSMALLINT SQLNumResultCols_wrap(SqlHandlePtr statementHandle) {
     }
 
     SQLSMALLINT columnCount;
-    // TODO: Handle the return code
-    SQLNumResultCols_ptr(statementHandle->get(), &columnCount);
+    {
+        // After a prepare without an execute, the driver asks the server for the
+        // result set's metadata
+        py::gil_scoped_release release;
+        // TODO: Handle the return code
+        SQLNumResultCols_ptr(statementHandle->get(), &columnCount);
+    }
     return columnCount;
 }
 
@@ -2986,7 +3297,12 @@ SQLRETURN SQLDescribeCol_wrap(SqlHandlePtr StatementHandle, py::list& ColumnMeta
     }
 
     SQLSMALLINT ColumnCount;
-    SQLRETURN retcode = SQLNumResultCols_ptr(StatementHandle->get(), &ColumnCount);
+    SQLRETURN retcode;
+    {
+        // May be a metadata round trip to the server (prepared, not yet executed)
+        py::gil_scoped_release release;
+        retcode = SQLNumResultCols_ptr(StatementHandle->get(), &ColumnCount);
+    }
     if (!SQL_SUCCEEDED(retcode)) {
         LOG("SQLDescribeCol: Failed to get number of columns - SQLRETURN=%d", retcode);
         return retcode;
@@ -3005,6 +3321,23 @@ SQLRETURN SQLDescribeCol_wrap(SqlHandlePtr StatementHandle, py::list& ColumnMeta
                                      &ColumnSize, &DecimalDigits, &Nullable);
 
         if (SQL_SUCCEEDED(retcode)) {
+            // The collation of narrow character columns tells the code page of
+            // their bytes; None where the driver does not report it
+            py::object collation = py::none();
+            if (DataType == SQL_CHAR || DataType == SQL_VARCHAR || DataType == SQL_LONGVARCHAR) {
+                SQLWCHAR collationName[129];
+                SQLSMALLINT collationBytes = 0;
+                SQLRETURN collationRet =
+                    SQLColAttribute_ptr(StatementHandle->get(), i, SQL_CA_SS_COLUMN_COLLATION,
+                                        collationName, sizeof(collationName), &collationBytes,
+                                        NULL);
+                if (SQL_SUCCEEDED(collationRet) && collationBytes > 0) {
+                    size_t collationChars =
+                        std::min(static_cast<size_t>(collationBytes) / sizeof(SQLWCHAR),
+                                 (sizeof(collationName) / sizeof(SQLWCHAR)) - 1);
+                    collation = py::cast(dupeSqlWCharAsUtf16Le(collationName, collationChars));
+                }
+            }
             // Append a named py::dict to ColumnMetadata
             // TODO: Should we define a struct for this task instead of dict?
             ColumnMetadata.append(
@@ -3012,7 +3345,8 @@ SQLRETURN SQLDescribeCol_wrap(SqlHandlePtr StatementHandle, py::list& ColumnMeta
                              ColumnName, std::min(static_cast<size_t>(NameLength),
                                                   (sizeof(ColumnName) / sizeof(SQLWCHAR)) - 1)),
                          "DataType"_a = DataType, "ColumnSize"_a = ColumnSize,
-                         "DecimalDigits"_a = DecimalDigits, "Nullable"_a = Nullable));
+                         "DecimalDigits"_a = DecimalDigits, "Nullable"_a = Nullable,
+                         "Collation"_a = collation));
         } else {
             return retcode;
         }
@@ -3055,85 +3389,76 @@ SQLRETURN SQLFetch_wrap(SqlHandlePtr StatementHandle) {
     return SQLFetch_ptr(StatementHandle->get());
 }
 
+// Read the next part of a column value with SQLGetData into chunk. Returns the
+// number of data bytes in chunk, not counting the null terminator the driver
+// appends to character data. Sets done once the value is complete (or was
+// already read completely: SQL_NO_DATA) and isNull for a NULL value.
+static size_t ReadLobChunk(SQLHSTMT hStmt, SQLUSMALLINT colIndex, SQLSMALLINT cType, char* chunk,
+                           SQLLEN chunkSize, bool& done, bool& isNull) {
+    SQLLEN actualRead = 0;
+    SQLRETURN ret;
+    {
+        // Release the GIL during blocking SQLGetData LOB streaming
+        py::gil_scoped_release release;
+        ret = SQLGetData_ptr(hStmt, colIndex, cType, chunk, chunkSize, &actualRead);
+    }
+    done = true;
+    isNull = false;
+    if (ret == SQL_NO_DATA) {
+        // The previous part ended exactly at the end of the value
+        return 0;
+    }
+    if (!SQL_SUCCEEDED(ret)) {
+        std::ostringstream oss;
+        oss << "Error fetching LOB for column " << colIndex << ", cType=" << cType
+            << ", SQLGetData return=" << ret;
+        LOG("ReadLobChunk: %s", oss.str().c_str());
+        ThrowStdException(oss.str());
+    }
+    if (actualRead == SQL_NULL_DATA) {
+        isNull = true;
+        return 0;
+    }
+    SQLLEN terminator = 0;
+    if (cType == SQL_C_WCHAR) {
+        terminator = sizeof(SQLWCHAR);
+    } else if (cType == SQL_C_CHAR) {
+        terminator = 1;
+    }
+    SQLLEN available = chunkSize - terminator;
+    if (cType == SQL_C_WCHAR) {
+        // The driver writes whole characters only
+        available -= available % static_cast<SQLLEN>(sizeof(SQLWCHAR));
+    }
+    if (actualRead == SQL_NO_TOTAL || actualRead > available) {
+        // Truncated (01004): the buffer is full and more data follows
+        done = false;
+        return static_cast<size_t>(available);
+    }
+    return static_cast<size_t>(actualRead < 0 ? 0 : actualRead);
+}
+
 // Non-static so it can be called from inline functions in header
 py::object FetchLobColumnData(SQLHSTMT hStmt, SQLUSMALLINT colIndex, SQLSMALLINT cType,
                               bool isWideChar, bool isBinary, const std::string& charEncoding) {
     std::vector<char> buffer;
-    SQLRETURN ret = SQL_SUCCESS_WITH_INFO;
+    std::vector<char> chunk(DAE_CHUNK_SIZE, 0);
     int loopCount = 0;
+    bool done = false;
 
-    while (true) {
+    while (!done) {
         ++loopCount;
-        std::vector<char> chunk(DAE_CHUNK_SIZE, 0);
-        SQLLEN actualRead = 0;
-        {
-            // Release the GIL during blocking SQLGetData LOB streaming
-            py::gil_scoped_release release;
-            ret = SQLGetData_ptr(hStmt, colIndex, cType, chunk.data(), DAE_CHUNK_SIZE, &actualRead);
-        }
-
-        if (ret == SQL_ERROR || !SQL_SUCCEEDED(ret) && ret != SQL_SUCCESS_WITH_INFO) {
-            std::ostringstream oss;
-            oss << "Error fetching LOB for column " << colIndex << ", cType=" << cType
-                << ", loop=" << loopCount << ", SQLGetData return=" << ret;
-            LOG("FetchLobColumnData: %s", oss.str().c_str());
-            ThrowStdException(oss.str());
-        }
-        if (actualRead == SQL_NULL_DATA) {
+        bool isNull = false;
+        size_t bytesRead =
+            ReadLobChunk(hStmt, colIndex, cType, chunk.data(), DAE_CHUNK_SIZE, done, isNull);
+        if (isNull) {
             LOG("FetchLobColumnData: Column %d is NULL at loop %d", colIndex, loopCount);
             return py::none();
         }
-
-        size_t bytesRead = 0;
-        if (actualRead >= 0) {
-            bytesRead = static_cast<size_t>(actualRead);
-            if (bytesRead > DAE_CHUNK_SIZE) {
-                bytesRead = DAE_CHUNK_SIZE;
-            }
-        } else {
-            // fallback: use full buffer size if actualRead is unknown
-            bytesRead = DAE_CHUNK_SIZE;
-        }
-
-        // For character data, trim trailing null terminators
-        if (!isBinary && bytesRead > 0) {
-            if (!isWideChar) {
-                // Narrow characters
-                while (bytesRead > 0 && chunk[bytesRead - 1] == '\0') {
-                    --bytesRead;
-                }
-                if (bytesRead < DAE_CHUNK_SIZE) {
-                    LOG("FetchLobColumnData: Trimmed null terminator from "
-                        "narrow char data - loop=%d",
-                        loopCount);
-                }
-            } else {
-                // Wide characters
-                size_t wcharSize = sizeof(SQLWCHAR);
-                if (bytesRead >= wcharSize && (bytesRead % wcharSize == 0)) {
-                    size_t wcharCount = bytesRead / wcharSize;
-                    std::vector<SQLWCHAR> alignedBuf(wcharCount);
-                    std::memcpy(alignedBuf.data(), chunk.data(), bytesRead);
-                    while (wcharCount > 0 && alignedBuf[wcharCount - 1] == 0) {
-                        --wcharCount;
-                        bytesRead -= wcharSize;
-                    }
-                    if (bytesRead < DAE_CHUNK_SIZE) {
-                        LOG("FetchLobColumnData: Trimmed null terminator from "
-                            "wide char data - loop=%d",
-                            loopCount);
-                    }
-                }
-            }
-        }
         if (bytesRead > 0) {
             buffer.insert(buffer.end(), chunk.begin(), chunk.begin() + bytesRead);
             LOG("FetchLobColumnData: Appended %zu bytes at loop %d", bytesRead, loopCount);
         }
-        if (ret == SQL_SUCCESS) {
-            LOG("FetchLobColumnData: SQL_SUCCESS - no more data at loop %d", loopCount);
-            break;
-        }
     }
     LOG("FetchLobColumnData: Total bytes collected=%zu for column %d", buffer.size(), colIndex);
 
@@ -3173,6 +3498,30 @@ py::object FetchLobColumnData(SQLHSTMT hStmt, SQLUSMALLINT colIndex, SQLSMALLINT
     }
 }
 
+// Read the next part of a column of the current row, for Cursor.fetch_lob_stream.
+// Returns up to chunkSize bytes (UTF-16LE for SQL_C_WCHAR), empty bytes once the
+// whole value has been read, and None for a NULL value.
+py::object SQLGetDataChunk_wrap(SqlHandlePtr StatementHandle, SQLUSMALLINT colIndex,
+                                SQLSMALLINT cType, SQLLEN chunkSize) {
+    if (!SQLGetData_ptr) {
+        LOG("SQLGetDataChunk: Function pointer not initialized, loading driver");
+        DriverLoader::getInstance().loadDriver();  // Load the driver
+    }
+    if (chunkSize < 16) {
+        ThrowStdException("LOB chunk size must be at least 16 bytes");
+    }
+    std::vector<char> chunk(static_cast<size_t>(chunkSize), 0);
+    bool done = false;
+    bool isNull = false;
+    size_t bytesRead = ReadLobChunk(StatementHandle->get(), colIndex, cType, chunk.data(),
+                                    chunkSize, done, isNull);
+    if (isNull) {
+        return py::none();
+    }
+    DriverMetrics::add(DriverMetrics::get().bytes_received, bytesRead);
+    return py::bytes(chunk.data(), bytesRead);
+}
+
 // Helper function to map sql_variant's underlying C type to SQL data type
 // This allows sql_variant to reuse existing fetch logic for each data type
 SQLSMALLINT MapVariantCTypeToSQLType(SQLLEN variantCType) {
@@ -3234,6 +3583,16 @@ static inline bool IsLobOrVariantColumn(SQLSMALLINT dataType, SQLULEN columnSize
             (columnSize == 0 || columnSize == SQL_NO_TOTAL || columnSize > SQL_MAX_LOB_SIZE));
 }
 
+// Buffer size in bytes for one CHAR/VARCHAR value fetched as SQL_C_CHAR, including the
+// null terminator. The driver converts to the client encoding (UTF-8 on Linux/macOS,
+// the ANSI code page on Windows, which may be UTF-8 too), so a value at the declared
+// column length can need up to 4 bytes per character. A smaller buffer truncates
+// such values, and the truncated value then has to be re-read with SQLGetData, which
+// a block cursor (SQL_ATTR_ROW_ARRAY_SIZE > 1) does not allow for bound columns.
+static inline uint64_t NarrowCharFetchBufferSize(SQLULEN columnSize) {
+    return static_cast<uint64_t>(columnSize) * 4 + 1;
+}
+
 // Helper function to retrieve column data
 SQLRETURN SQLGetData_wrap(SqlHandlePtr StatementHandle, SQLUSMALLINT colCount, py::list& row,
                           const std::string& charEncoding = "utf-16le",
@@ -3355,8 +3714,12 @@ SQLRETURN SQLGetData_wrap(SqlHandlePtr StatementHandle, SQLUSMALLINT colCount, p
                         (columnSize + 1) * sizeof(SQLWCHAR);  // +1 for null terminator
                     std::vector<SQLWCHAR> dataBuffer(columnSize + 1);
                     SQLLEN dataLen;
-                    ret = SQLGetData_ptr(hStmt, i, SQL_C_WCHAR, dataBuffer.data(), fetchBufferSize,
-                                         &dataLen);
+                    {
+                        // A value of up to 8000 bytes may span packets still to arrive
+                        py::gil_scoped_release release;
+                        ret = SQLGetData_ptr(hStmt, i, SQL_C_WCHAR, dataBuffer.data(),
+                                             fetchBufferSize, &dataLen);
+                    }
                     if (SQL_SUCCEEDED(ret)) {
                         if (dataLen > 0) {
                             uint64_t numCharsInData = dataLen / sizeof(SQLWCHAR);
@@ -3413,27 +3776,19 @@ SQLRETURN SQLGetData_wrap(SqlHandlePtr StatementHandle, SQLUSMALLINT colCount, p
                                           "fetched as SQL_C_WCHAR");
                     }
                 } else {
-                    // Allocate columnSize * 4 + 1 on ALL platforms (no #if guard).
-                    //
-                    // Why this differs from SQLBindColums / FetchBatchData:
-                    // Those two functions use #if to apply *4 only on Linux/macOS,
-                    // because on Windows with a non-UTF-8 collation (e.g. CP1252)
-                    // each character occupies exactly 1 byte, so *1 suffices and
-                    // saves memory across the entire batch (fetchSize × numCols
-                    // buffers).
-                    //
-                    // SQLGetData_wrap allocates a single temporary buffer per
-                    // column per row, so the over-allocation cost is negligible.
-                    // Using *4 unconditionally here keeps the code simple and
-                    // correct on every platform—including Windows with a UTF-8
-                    // collation where multi-byte chars could otherwise cause
-                    // truncation at the exact column boundary (e.g. CP1252 é in
-                    // VARCHAR(10)).
-                    uint64_t fetchBufferSize = columnSize * 4 + 1 /* null-termination */;
+                    // Same size as the bound buffers of SQLBindColums / FetchBatchData
+                    // (see NarrowCharFetchBufferSize): multi-byte characters would
+                    // otherwise be truncated at the exact column boundary (e.g.
+                    // CP1252 é in VARCHAR(10) with a UTF-8 client code page).
+                    uint64_t fetchBufferSize = NarrowCharFetchBufferSize(columnSize);
                     std::vector<SQLCHAR> dataBuffer(fetchBufferSize);
                     SQLLEN dataLen;
-                    ret = SQLGetData_ptr(hStmt, i, SQL_C_CHAR, dataBuffer.data(), dataBuffer.size(),
-                                         &dataLen);
+                    {
+                        // A value of up to 8000 bytes may span packets still to arrive
+                        py::gil_scoped_release release;
+                        ret = SQLGetData_ptr(hStmt, i, SQL_C_CHAR, dataBuffer.data(),
+                                             dataBuffer.size(), &dataLen);
+                    }
                     if (SQL_SUCCEEDED(ret)) {
                         // columnSize is in chars, dataLen is in bytes
                         if (dataLen > 0) {
@@ -3521,8 +3876,12 @@ SQLRETURN SQLGetData_wrap(SqlHandlePtr StatementHandle, SQLUSMALLINT colCount, p
                         (columnSize + 1) * sizeof(SQLWCHAR);  // +1 for null terminator
                     std::vector<SQLWCHAR> dataBuffer(columnSize + 1);
                     SQLLEN dataLen;
-                    ret = SQLGetData_ptr(hStmt, i, SQL_C_WCHAR, dataBuffer.data(), fetchBufferSize,
-                                         &dataLen);
+                    {
+                        // A value of up to 8000 bytes may span packets still to arrive
+                        py::gil_scoped_release release;
+                        ret = SQLGetData_ptr(hStmt, i, SQL_C_WCHAR, dataBuffer.data(),
+                                             fetchBufferSize, &dataLen);
+                    }
                     if (SQL_SUCCEEDED(ret)) {
                         if (dataLen > 0) {
                             uint64_t numCharsInData = dataLen / sizeof(SQLWCHAR);
@@ -3618,6 +3977,30 @@ SQLRETURN SQLGetData_wrap(SqlHandlePtr StatementHandle, SQLUSMALLINT colCount, p
             }
             case SQL_DECIMAL:
             case SQL_NUMERIC: {
+                if (dataType != SQL_SS_VARIANT) {
+                    // Fetched as SQL_NUMERIC_STRUCT at the column's precision and scale
+                    SQL_NUMERIC_STRUCT numeric = {};
+                    SQLLEN indicator = 0;
+                    ret = SetNumericDescRecord(hStmt, SQL_ATTR_APP_ROW_DESC, i,
+                                               static_cast<SQLSMALLINT>(columnSize),
+                                               decimalDigits);
+                    if (SQL_SUCCEEDED(ret)) {
+                        ret = SQLGetData_ptr(hStmt, i, SQL_ARD_TYPE, &numeric, sizeof(numeric),
+                                             &indicator);
+                    }
+                    if (SQL_SUCCEEDED(ret) && indicator != SQL_NULL_DATA) {
+                        row.append(NumericStructToDecimal(numeric));
+                    } else {
+                        if (!SQL_SUCCEEDED(ret)) {
+                            LOG("SQLGetData: Error retrieving SQL_NUMERIC/DECIMAL for "
+                                "column %d - SQLRETURN=%d",
+                                i, ret);
+                        }
+                        row.append(py::none());
+                    }
+                    break;
+                }
+                // A sql_variant's own precision and scale are not described: read it as text
                 SQLCHAR numericStr[MAX_DIGITS_IN_NUMERIC] = {0};
                 SQLLEN indicator = 0;
 
@@ -3720,8 +4103,7 @@ SQLRETURN SQLGetData_wrap(SqlHandlePtr StatementHandle, SQLUSMALLINT colCount, p
                 SQLLEN indicator = 0;
                 ret = SQLGetData_ptr(hStmt, i, SQL_C_SS_TIME2, &t2, sizeof(t2), &indicator);
                 if (SQL_SUCCEEDED(ret) && indicator != SQL_NULL_DATA) {
-                    row.append(PythonObjectCache::get_time_class()(
-                        t2.hour, t2.minute, t2.second, t2.fraction / 1000));  // ns to µs
+                    row.append(MakeTimeObject(t2.hour, t2.minute, t2.second, t2.fraction));
                 } else {
                     if (!SQL_SUCCEEDED(ret)) {
                         LOG("SQLGetData: Error retrieving SQL_SS_TIME2 for column "
@@ -3739,11 +4121,10 @@ SQLRETURN SQLGetData_wrap(SqlHandlePtr StatementHandle, SQLUSMALLINT colCount, p
                 ret = SQLGetData_ptr(hStmt, i, SQL_C_TYPE_TIMESTAMP, &timestampValue,
                                      sizeof(timestampValue), NULL);
                 if (SQL_SUCCEEDED(ret)) {
-                    row.append(PythonObjectCache::get_datetime_class()(
-                        timestampValue.year, timestampValue.month, timestampValue.day,
-                        timestampValue.hour, timestampValue.minute, timestampValue.second,
-                        timestampValue.fraction / 1000  // Convert back ns to µs
-                        ));
+                    row.append(MakeDateTimeObject(timestampValue.year, timestampValue.month,
+                                                  timestampValue.day, timestampValue.hour,
+                                                  timestampValue.minute, timestampValue.second,
+                                                  timestampValue.fraction));
                 } else {
                     LOG("SQLGetData: Error retrieving SQL_TYPE_TIMESTAMP for "
                         "column %d - SQLRETURN=%d",
@@ -3774,14 +4155,12 @@ SQLRETURN SQLGetData_wrap(SqlHandlePtr StatementHandle, SQLUSMALLINT colCount, p
                             << totalMinutes << " minutes for column " << i;
                         ThrowStdException(oss.str());
                     }
-                    // Convert fraction from ns to µs
-                    int microseconds = dtoValue.fraction / 1000;
                     py::object datetime_module = py::module_::import("datetime");
                     py::object tzinfo = datetime_module.attr("timezone")(
                         datetime_module.attr("timedelta")(py::arg("minutes") = totalMinutes));
-                    py::object py_dt = PythonObjectCache::get_datetime_class()(
+                    py::object py_dt = MakeDateTimeObject(
                         dtoValue.year, dtoValue.month, dtoValue.day, dtoValue.hour, dtoValue.minute,
-                        dtoValue.second, microseconds, tzinfo);
+                        dtoValue.second, dtoValue.fraction, tzinfo);
                     row.append(py_dt);
                 } else {
                     LOG("SQLGetData: Error fetching DATETIMEOFFSET for column "
@@ -3806,8 +4185,12 @@ SQLRETURN SQLGetData_wrap(SqlHandlePtr StatementHandle, SQLUSMALLINT colCount, p
                     // Small VARBINARY, fetch directly
                     std::vector<SQLCHAR> dataBuffer(columnSize);
                     SQLLEN dataLen;
-                    ret = SQLGetData_ptr(hStmt, i, SQL_C_BINARY, dataBuffer.data(), columnSize,
-                                         &dataLen);
+                    {
+                        // A value of up to 8000 bytes may span packets still to arrive
+                        py::gil_scoped_release release;
+                        ret = SQLGetData_ptr(hStmt, i, SQL_C_BINARY, dataBuffer.data(), columnSize,
+                                             &dataLen);
+                    }
 
                     if (SQL_SUCCEEDED(ret)) {
                         if (dataLen > 0) {
@@ -3972,11 +4355,7 @@ SQLRETURN SQLBindColums(SQLHSTMT hStmt, ColumnBuffers& buffers, py::list& column
                         fetchBufferSize * sizeof(SQLWCHAR), buffers.indicators[col - 1].data());
                 } else {
                     // Original narrow-char path
-#if defined(__APPLE__) || defined(__linux__)
-                    uint64_t fetchBufferSize = columnSize * 4 + 1 /*null-terminator*/;
-#else
-                    uint64_t fetchBufferSize = columnSize + 1 /*null-terminator*/;
-#endif
+                    uint64_t fetchBufferSize = NarrowCharFetchBufferSize(columnSize);
                     buffers.charBuffers[col - 1].resize(fetchSize * fetchBufferSize);
                     ret = SQLBindCol_ptr(
                         hStmt, col, SQL_C_CHAR, buffers.charBuffers[col - 1].data(),
@@ -4025,10 +4404,17 @@ SQLRETURN SQLBindColums(SQLHSTMT hStmt, ColumnBuffers& buffers, py::list& column
                 break;
             case SQL_DECIMAL:
             case SQL_NUMERIC:
-                buffers.charBuffers[col - 1].resize(fetchSize * MAX_DIGITS_IN_NUMERIC);
-                ret = SQLBindCol_ptr(hStmt, col, SQL_C_CHAR, buffers.charBuffers[col - 1].data(),
-                                     MAX_DIGITS_IN_NUMERIC * sizeof(SQLCHAR),
+                buffers.numericBuffers[col - 1].resize(fetchSize);
+                ret = SQLBindCol_ptr(hStmt, col, SQL_C_NUMERIC,
+                                     buffers.numericBuffers[col - 1].data(),
+                                     sizeof(SQL_NUMERIC_STRUCT),
                                      buffers.indicators[col - 1].data());
+                if (SQL_SUCCEEDED(ret)) {
+                    ret = SetNumericDescRecord(
+                        hStmt, SQL_ATTR_APP_ROW_DESC, col, static_cast<SQLSMALLINT>(columnSize),
+                        columnMeta["DecimalDigits"].cast<SQLSMALLINT>(),
+                        buffers.numericBuffers[col - 1].data(), buffers.indicators[col - 1].data());
+                }
                 break;
             case SQL_DOUBLE:
             case SQL_FLOAT:
@@ -4159,20 +4545,15 @@ SQLRETURN FetchBatchData(SQLHSTMT hStmt, ColumnBuffers& buffers, py::list& colum
             // units (same as NVARCHAR). +1 for null terminator.
             columnInfos[col].fetchBufferSize = columnInfos[col].processedColumnSize + 1;
         } else {
-            // On Linux/macOS, the ODBC driver returns UTF-8 for SQL_C_CHAR where
-            // each character can be up to 4 bytes. Must match SQLBindColums buffer.
-#if defined(__APPLE__) || defined(__linux__)
+            // SQL_C_CHAR data can take up to 4 bytes per character on every
+            // platform. Must match SQLBindColums buffer.
             if (isCharType) {
-                columnInfos[col].fetchBufferSize = columnInfos[col].processedColumnSize * 4 +
-                                                   1;  // *4 for UTF-8, +1 for null terminator
+                columnInfos[col].fetchBufferSize =
+                    NarrowCharFetchBufferSize(columnInfos[col].processedColumnSize);
             } else {
                 columnInfos[col].fetchBufferSize =
                     columnInfos[col].processedColumnSize + 1;  // +1 for null terminator
             }
-#else
-            columnInfos[col].fetchBufferSize =
-                columnInfos[col].processedColumnSize + 1;  // +1 for null terminator
-#endif
         }
     }
 
@@ -4341,21 +4722,12 @@ SQLRETURN FetchBatchData(SQLHSTMT hStmt, ColumnBuffers& buffers, py::list& colum
                 case SQL_DECIMAL:
                 case SQL_NUMERIC: {
                     try {
-                        SQLLEN decimalDataLen = buffers.indicators[col - 1][i];
-                        const char* rawData = reinterpret_cast<const char*>(
-                            &buffers.charBuffers[col - 1][i * MAX_DIGITS_IN_NUMERIC]);
-
-                        // Always use standard decimal point for Python Decimal
-                        // parsing The decimal separator only affects display
-                        // formatting, not parsing
                         PyObject* decimalObj =
-                            PythonObjectCache::get_decimal_class()(py::str(rawData, decimalDataLen))
+                            NumericStructToDecimal(buffers.numericBuffers[col - 1][i])
                                 .release()
                                 .ptr();
                         PyList_SET_ITEM(row, col - 1, decimalObj);
                     } catch (const py::error_already_set& e) {
-                        // Handle the exception, e.g., log the error and set
-                        // py::none()
                         LOG("Error converting to decimal: {}", e.what());
                         Py_INCREF(Py_None);
                         PyList_SET_ITEM(row, col - 1, Py_None);
@@ -4366,9 +4738,8 @@ SQLRETURN FetchBatchData(SQLHSTMT hStmt, ColumnBuffers& buffers, py::list& colum
                 case SQL_TYPE_TIMESTAMP:
                 case SQL_DATETIME: {
                     const SQL_TIMESTAMP_STRUCT& ts = buffers.timestampBuffers[col - 1][i];
-                    PyObject* datetimeObj = PythonObjectCache::get_datetime_class()(
-                                                ts.year, ts.month, ts.day, ts.hour, ts.minute,
-                                                ts.second, ts.fraction / 1000)
+                    PyObject* datetimeObj = MakeDateTimeObject(ts.year, ts.month, ts.day, ts.hour,
+                                                               ts.minute, ts.second, ts.fraction)
                                                 .release()
                                                 .ptr();
                     PyList_SET_ITEM(row, col - 1, datetimeObj);
@@ -4387,10 +4758,7 @@ SQLRETURN FetchBatchData(SQLHSTMT hStmt, ColumnBuffers& buffers, py::list& colum
                 case SQL_SS_TIME2: {
                     const SQL_SS_TIME2_STRUCT& t2 = buffers.timeBuffers[col - 1][i];
                     PyObject* timeObj =
-                        PythonObjectCache::get_time_class()(t2.hour, t2.minute, t2.second,
-                                                            t2.fraction / 1000)  // ns to µs
-                            .release()
-                            .ptr();
+                        MakeTimeObject(t2.hour, t2.minute, t2.second, t2.fraction).release().ptr();
                     PyList_SET_ITEM(row, col - 1, timeObj);
                     break;
                 }
@@ -4403,11 +4771,9 @@ SQLRETURN FetchBatchData(SQLHSTMT hStmt, ColumnBuffers& buffers, py::list& colum
                         py::object datetime_module = py::module_::import("datetime");
                         py::object tzinfo = datetime_module.attr("timezone")(
                             datetime_module.attr("timedelta")(py::arg("minutes") = totalMinutes));
-                        py::object py_dt = PythonObjectCache::get_datetime_class()(
+                        py::object py_dt = MakeDateTimeObject(
                             dtoValue.year, dtoValue.month, dtoValue.day, dtoValue.hour,
-                            dtoValue.minute, dtoValue.second,
-                            dtoValue.fraction / 1000,  // ns → µs
-                            tzinfo);
+                            dtoValue.minute, dtoValue.second, dtoValue.fraction, tzinfo);
                         PyList_SET_ITEM(row, col - 1, py_dt.release().ptr());
                     } else {
                         Py_INCREF(Py_None);
@@ -4508,7 +4874,7 @@ size_t calculateRowSize(py::list& columnNames, SQLUSMALLINT numCols) {
                 break;
             case SQL_DECIMAL:
             case SQL_NUMERIC:
-                rowSize += MAX_DIGITS_IN_NUMERIC;
+                rowSize += sizeof(SQL_NUMERIC_STRUCT);
                 break;
             case SQL_TIMESTAMP:
             case SQL_TYPE_TIMESTAMP:
@@ -4574,10 +4940,9 @@ size_t calculateRowSize(py::list& columnNames, SQLUSMALLINT numCols) {
 // the result set and populates the provided Python list with the row data. If
 // there are no more rows to fetch, it returns SQL_NO_DATA. If an error occurs
 // during fetching, it throws a runtime error.
-SQLRETURN FetchMany_wrap(SqlHandlePtr StatementHandle, py::list& rows, int fetchSize,
-                         const std::string& charEncoding = "utf-16le",
-                         const std::string& wcharEncoding = "utf-16le",
-                         int charCtype = SQL_C_WCHAR) {
+static SQLRETURN FetchMany_impl(SqlHandlePtr StatementHandle, py::list& rows, int fetchSize,
+                                const std::string& charEncoding,
+                                const std::string& wcharEncoding, int charCtype) {
     // Issue #531: upgrade SQL_C_CHAR + utf-8 to SQL_C_WCHAR on Windows so the
     // driver does lossless UTF-16 conversion instead of returning ACP bytes.
     charCtype = EffectiveCharCtypeForFetch(charCtype, charEncoding);
@@ -4662,6 +5027,17 @@ SQLRETURN FetchMany_wrap(SqlHandlePtr StatementHandle, py::list& rows, int fetch
     return ret;
 }
 
+SQLRETURN FetchMany_wrap(SqlHandlePtr StatementHandle, py::list& rows, int fetchSize,
+                         const std::string& charEncoding = "utf-16le",
+                         const std::string& wcharEncoding = "utf-16le",
+                         int charCtype = SQL_C_WCHAR) {
+    size_t first = rows.size();
+    SQLRETURN ret =
+        FetchMany_impl(StatementHandle, rows, fetchSize, charEncoding, wcharEncoding, charCtype);
+    RecordFetchMetrics(StatementHandle, rows, first);
+    return ret;
+}
+
 // GetDataVar - Progressively fetches variable-length column data using SQLGetData.
 //
 // Calls SQLGetData repeatedly, reallocating the buffer as needed, until all data is retrieved.
@@ -4701,10 +5077,15 @@ SQLRETURN GetDataVar(SQLHSTMT hStmt, SQLUSMALLINT colNumber, SQLSMALLINT cType,
 
     while (true) {
         SQLLEN localInd = 0;
-        SQLRETURN ret = SQLGetData_ptr(
-            hStmt, colNumber, cType, reinterpret_cast<uint8_t*>(dataVec.data() + start),
-            sizeof(T) * (dataVec.size() - start),  // Available buffer size from start position
-            &localInd);
+        SQLRETURN ret;
+        {
+            // Each chunk of a streamed value may wait for the next packets from the server
+            py::gil_scoped_release release;
+            ret = SQLGetData_ptr(
+                hStmt, colNumber, cType, reinterpret_cast<uint8_t*>(dataVec.data() + start),
+                sizeof(T) * (dataVec.size() - start),  // Available buffer size from start position
+                &localInd);
+        }
 
         // Handle NULL data
         if (localInd == SQL_NULL_DATA) {
@@ -4813,6 +5194,7 @@ SQLRETURN FetchArrowBatch_wrap(SqlHandlePtr StatementHandle, py::list& capsules,
 
     std::vector<SQLSMALLINT> dataTypes(numCols);
     std::vector<SQLULEN> columnSizes(numCols);
+    std::vector<SQLSMALLINT> columnScales(numCols, 0);
     std::vector<bool> columnNullable(numCols);
     std::vector<bool> columnVarLen(numCols, false);
     std::vector<int64_t> nullCounts(numCols, 0);
@@ -4831,6 +5213,7 @@ SQLRETURN FetchArrowBatch_wrap(SqlHandlePtr StatementHandle, py::list& capsules,
 
         dataTypes[i] = dataType;
         columnSizes[i] = columnSize;
+        columnScales[i] = colMeta["DecimalDigits"].cast<SQLSMALLINT>();
         columnNullable[i] = (nullable != SQL_NO_NULLS);
 
         if ((dataType == SQL_WVARCHAR || dataType == SQL_WLONGVARCHAR || dataType == SQL_VARCHAR ||
@@ -5122,13 +5505,18 @@ SQLRETURN FetchArrowBatch_wrap(SqlHandlePtr StatementHandle, py::list& capsules,
                         }
                         case SQL_DECIMAL:
                         case SQL_NUMERIC: {
-                            buffers.charBuffers[idxCol].resize(MAX_DIGITS_IN_NUMERIC);
-                            ret = SQLGetData_ptr(hStmt, idxCol + 1, SQL_C_CHAR,
-                                                 buffers.charBuffers[idxCol].data(),
-                                                 MAX_DIGITS_IN_NUMERIC * sizeof(SQLCHAR),
-                                                 buffers.indicators[idxCol].data());
+                            buffers.numericBuffers[idxCol].resize(1);
+                            ret = SetNumericDescRecord(hStmt, SQL_ATTR_APP_ROW_DESC, idxCol + 1,
+                                                       static_cast<SQLSMALLINT>(columnSize),
+                                                       columnScales[idxCol]);
+                            if (SQL_SUCCEEDED(ret)) {
+                                ret = SQLGetData_ptr(hStmt, idxCol + 1, SQL_ARD_TYPE,
+                                                     buffers.numericBuffers[idxCol].data(),
+                                                     sizeof(SQL_NUMERIC_STRUCT),
+                                                     buffers.indicators[idxCol].data());
+                            }
                             if (!SQL_SUCCEEDED(ret)) {
-                                LOG("Error fetching CHAR data for column %d", idxCol + 1);
+                                LOG("Error fetching NUMERIC data for column %d", idxCol + 1);
                                 return ret;
                             }
                             break;
@@ -5294,11 +5682,7 @@ SQLRETURN FetchArrowBatch_wrap(SqlHandlePtr StatementHandle, py::list& capsules,
                     case SQL_VARCHAR:
                     case SQL_LONGVARCHAR: {
                         if (charCtype == SQL_C_CHAR) {
-#if defined(__APPLE__) || defined(__linux__)
-                            uint64_t fetchBufferSize = columnSize * 4 + 1 /*null-terminator*/;
-#else
-                            uint64_t fetchBufferSize = columnSize + 1 /*null-terminator*/;
-#endif
+                            uint64_t fetchBufferSize = NarrowCharFetchBufferSize(columnSize);
                             auto target_vec = &arrowColumnProducer->varData;
                             auto start = arrowColumnProducer->varVal[idxRowArrow];
                             while (target_vec->size() < start + dataLen) {
@@ -5394,22 +5778,18 @@ SQLRETURN FetchArrowBatch_wrap(SqlHandlePtr StatementHandle, py::list& capsules,
                         break;
                     case SQL_DECIMAL:
                     case SQL_NUMERIC: {
-                        // Relies on overloaded operators defined in Int128_t struct
-                        assert(dataLen <= MAX_DIGITS_IN_NUMERIC);
-                        Int128_t decimalValue(0, 0);
-                        auto start = idxRowSql * MAX_DIGITS_IN_NUMERIC;
-                        int sign = 1;
-                        for (SQLULEN idx = start; idx < start + dataLen; idx++) {
-                            char digitChar = buffers.charBuffers[idxCol][idx];
-                            if (digitChar == '-') {
-                                sign = -1;
-                            } else if (digitChar >= '0' && digitChar <= '9') {
-                                decimalValue =
-                                    decimalValue.multiply_by_10() + (uint64_t)(digitChar - '0');
-                            }
+                        // The little-endian magnitude of the struct is the unscaled value
+                        const SQL_NUMERIC_STRUCT& numeric =
+                            buffers.numericBuffers[idxCol][idxRowSql];
+                        uint64_t low = 0;
+                        uint64_t high = 0;
+                        for (int b = 7; b >= 0; --b) {
+                            low = (low << 8) | numeric.val[b];
+                            high = (high << 8) | numeric.val[b + 8];
                         }
+                        Int128_t decimalValue(low, static_cast<int64_t>(high));
                         arrowColumnProducer->decimalVal[idxRowArrow] =
-                            (sign > 0) ? decimalValue : -decimalValue;
+                            (numeric.sign == 1) ? decimalValue : -decimalValue;
                         break;
                     }
                     case SQL_TIMESTAMP:
@@ -5683,6 +6063,8 @@ SQLRETURN FetchArrowBatch_wrap(SqlHandlePtr StatementHandle, py::list& capsules,
 // fetched.
 // @param rows: A Python list that will be populated with the fetched rows of
 // data.
+// @param fetchSizeHint: Rows to pull per SQLFetch call. 0 (default) derives the
+// batch size from the row size.
 //
 // @return SQLRETURN: SQL_SUCCESS if data is fetched successfully,
 //                    SQL_NO_DATA if there are no more rows to fetch,
@@ -5693,10 +6075,10 @@ SQLRETURN FetchArrowBatch_wrap(SqlHandlePtr StatementHandle, py::list& capsules,
 // populates the provided Python list with the row data. If there are no more
 // rows to fetch, it returns SQL_NO_DATA. If an error occurs during fetching, it
 // throws a runtime error.
-SQLRETURN FetchAll_wrap(SqlHandlePtr StatementHandle, py::list& rows,
-                        const std::string& charEncoding = "utf-16le",
-                        const std::string& wcharEncoding = "utf-16le",
-                        int charCtype = SQL_C_WCHAR) {
+static SQLRETURN FetchAll_impl(SqlHandlePtr StatementHandle, py::list& rows,
+                               const std::string& charEncoding,
+                               const std::string& wcharEncoding, int charCtype,
+                               int fetchSizeHint) {
     // Issue #531: upgrade SQL_C_CHAR + utf-8 to SQL_C_WCHAR on Windows so the
     // driver does lossless UTF-16 conversion instead of returning ACP bytes.
     charCtype = EffectiveCharCtypeForFetch(charCtype, charEncoding);
@@ -5789,6 +6171,13 @@ SQLRETURN FetchAll_wrap(SqlHandlePtr StatementHandle, py::list& rows,
     } else {
         fetchSize = 1000;
     }
+    // An explicit per-cursor hint (cursor.internal_fetch_rows or arraysize) overrides
+    // the heuristic, but never binds more rows than fit in memoryLimit
+    if (fetchSizeHint > 0) {
+        size_t maxRows = totalRowSize > 0 ? std::max<size_t>(numRowsInMemLimit, 1)
+                                          : static_cast<size_t>(fetchSizeHint);
+        fetchSize = static_cast<int>(std::min(static_cast<size_t>(fetchSizeHint), maxRows));
+    }
     LOG("FetchAll_wrap: Fetching data in batch sizes of %d", fetchSize);
 
     ColumnBuffers buffers(numCols, fetchSize);
@@ -5823,11 +6212,24 @@ SQLRETURN FetchAll_wrap(SqlHandlePtr StatementHandle, py::list& rows,
     return ret;
 }
 
+SQLRETURN FetchAll_wrap(SqlHandlePtr StatementHandle, py::list& rows,
+                        const std::string& charEncoding = "utf-16le",
+                        const std::string& wcharEncoding = "utf-16le",
+                        int charCtype = SQL_C_WCHAR, int fetchSizeHint = 0) {
+    size_t first = rows.size();
+    SQLRETURN ret =
+        FetchAll_impl(StatementHandle, rows, charEncoding, wcharEncoding, charCtype, fetchSizeHint);
+    RecordFetchMetrics(StatementHandle, rows, first);
+    return ret;
+}
+
 // FetchOne_wrap - Fetches a single row of data from the result set.
 //
 // @param StatementHandle: Handle to the statement from which data is to be
 // fetched.
 // @param row: A Python list that will be populated with the fetched row data.
+// @param fetch: When false, the cursor is already on the row (moved there with
+// SQLFetch) and only its data is read.
 //
 // @return SQLRETURN: SQL_SUCCESS or SQL_SUCCESS_WITH_INFO if data is fetched
 // successfully,
@@ -5839,10 +6241,9 @@ SQLRETURN FetchAll_wrap(SqlHandlePtr StatementHandle, py::list& rows,
 // result set and populates the provided Python list with the row data. If there
 // are no more rows to fetch, it returns SQL_NO_DATA. If an error occurs during
 // fetching, it throws a runtime error.
-SQLRETURN FetchOne_wrap(SqlHandlePtr StatementHandle, py::list& row,
-                        const std::string& charEncoding = "utf-16le",
-                        const std::string& wcharEncoding = "utf-16le",
-                        int charCtype = SQL_C_WCHAR) {
+static SQLRETURN FetchOne_impl(SqlHandlePtr StatementHandle, py::list& row,
+                               const std::string& charEncoding,
+                               const std::string& wcharEncoding, int charCtype, bool fetch) {
     // Issue #531: upgrade SQL_C_CHAR + utf-8 to SQL_C_WCHAR on Windows so the
     // driver does lossless UTF-16 conversion instead of returning ACP bytes.
     charCtype = EffectiveCharCtypeForFetch(charCtype, charEncoding);
@@ -5855,10 +6256,12 @@ SQLRETURN FetchOne_wrap(SqlHandlePtr StatementHandle, py::list& row,
     SQLFreeStmt_ptr(hStmt, SQL_UNBIND);
 
     // Assume hStmt is already allocated and a query has been executed
-    {
+    if (fetch) {
         // Release the GIL during the blocking ODBC fetch
         py::gil_scoped_release release;
         ret = SQLFetch_ptr(hStmt);
+    } else {
+        ret = SQL_SUCCESS;
     }
     if (SQL_SUCCEEDED(ret)) {
         // Retrieve column count
@@ -5875,6 +6278,25 @@ SQLRETURN FetchOne_wrap(SqlHandlePtr StatementHandle, py::list& row,
     return ret;
 }
 
+SQLRETURN FetchOne_wrap(SqlHandlePtr StatementHandle, py::list& row,
+                        const std::string& charEncoding = "utf-16le",
+                        const std::string& wcharEncoding = "utf-16le",
+                        int charCtype = SQL_C_WCHAR, bool fetch = true) {
+    SQLRETURN ret =
+        FetchOne_impl(StatementHandle, row, charEncoding, wcharEncoding, charCtype, fetch);
+    if (SQL_SUCCEEDED(ret)) {
+        uint64_t bytes = MetricsListBytes(row.ptr());
+        DriverMetrics& metrics = DriverMetrics::get();
+        DriverMetrics::add(metrics.rows_fetched);
+        DriverMetrics::add(metrics.bytes_received, bytes);
+        if (StatementHandle->usage) {
+            DriverMetrics::add(StatementHandle->usage->rows_fetched);
+            DriverMetrics::add(StatementHandle->usage->bytes_received, bytes);
+        }
+    }
+    return ret;
+}
+
 // Wrap SQLMoreResults
 SQLRETURN SQLMoreResults_wrap(SqlHandlePtr StatementHandle) {
     LOG("SQLMoreResults_wrap: Check for more results");
@@ -5889,6 +6311,23 @@ SQLRETURN SQLMoreResults_wrap(SqlHandlePtr StatementHandle) {
     return SQLMoreResults_ptr(StatementHandle->get());
 }
 
+// Wrap SQLCancel. Safe to call from another thread while the statement executes:
+// the driver sends an attention to the server and the executing call returns
+// HY008 once the server has acknowledged it.
+SQLRETURN SQLCancel_wrap(SqlHandlePtr StatementHandle) {
+    LOG("SQLCancel_wrap: Cancelling statement");
+    if (!StatementHandle || !StatementHandle->get() || StatementHandle->isImplicitlyFreed()) {
+        return SQL_INVALID_HANDLE;
+    }
+    if (!SQLCancel_ptr) {
+        LOG("SQLCancel_wrap: Function pointer not initialized. Loading the driver.");
+        DriverLoader::getInstance().loadDriver();  // Load the driver
+    }
+
+    py::gil_scoped_release release;
+    return SQLCancel_ptr(StatementHandle->get());
+}
+
 // Wrap SQLFreeHandle
 SQLRETURN SQLFreeHandle_wrap(SQLSMALLINT HandleType, SqlHandlePtr Handle) {
     LOG("SQLFreeHandle_wrap: Free SQL handle type=%d", HandleType);
@@ -5940,9 +6379,81 @@ SQLLEN SQLRowCount_wrap(SqlHandlePtr StatementHandle) {
 }
 
 static std::once_flag pooling_init_flag;
-void enable_pooling(int maxSize, int idleTimeout) {
-    std::call_once(pooling_init_flag,
-                   [&]() { ConnectionPoolManager::getInstance().configure(maxSize, idleTimeout); });
+void enable_pooling(int maxSize, int idleTimeout, int minSize, int maxLifetime,
+                    const std::u16string& validationQuery) {
+    std::call_once(pooling_init_flag, [&]() {
+        ConnectionPoolConfig config;
+        config.max_size = maxSize;
+        config.idle_timeout_secs = idleTimeout;
+        config.min_size = minSize;
+        config.max_lifetime_secs = maxLifetime;
+        config.validation_query = validationQuery;
+        ConnectionPoolManager::getInstance().configure(config);
+    });
+}
+
+// Returns one dict per pool: its connection string, size and counters
+py::list pool_stats() {
+    py::list result;
+    for (const auto& [connStr, stats] : ConnectionPoolManager::getInstance().stats()) {
+        py::dict entry;
+        entry["connection_string"] = connStr;
+        entry["size"] = stats.size;
+        entry["idle"] = stats.idle;
+        entry["in_use"] = stats.size >= stats.idle ? stats.size - stats.idle : 0;
+        entry["created"] = stats.created;
+        entry["reused"] = stats.reused;
+        entry["discarded"] = stats.discarded;
+        entry["exhausted"] = stats.exhausted;
+        result.append(entry);
+    }
+    return result;
+}
+
+// A histogram as {"buckets": [(upper bound, cumulative count), ..., (inf, count)],
+// "sum": seconds, "count": observations}
+static py::dict HistogramToDict(const MetricsHistogram& histogram) {
+    py::list buckets;
+    uint64_t cumulative = 0;
+    for (size_t i = 0; i <= MetricsHistogram::kBounds.size(); ++i) {
+        cumulative += histogram.bucket(i);
+        double bound = i < MetricsHistogram::kBounds.size()
+                           ? MetricsHistogram::kBounds[i]
+                           : std::numeric_limits<double>::infinity();
+        buckets.append(py::make_tuple(bound, cumulative));
+    }
+    py::dict result;
+    result["buckets"] = buckets;
+    result["sum"] = histogram.sum();
+    result["count"] = histogram.count();
+    return result;
+}
+
+// Returns a snapshot of the process-wide driver metrics (see metrics.h)
+py::dict metrics() {
+    const DriverMetrics& m = DriverMetrics::get();
+    auto load = [](const std::atomic<uint64_t>& counter) {
+        return counter.load(std::memory_order_relaxed);
+    };
+    py::dict counters;
+    counters["connections_opened"] = load(m.connections_opened);
+    counters["connection_failures"] = load(m.connection_failures);
+    counters["failed_logins"] = load(m.failed_logins);
+    counters["statements_executed"] = load(m.statements_executed);
+    counters["statement_errors"] = load(m.statement_errors);
+    counters["rows_fetched"] = load(m.rows_fetched);
+    counters["bytes_sent"] = load(m.bytes_sent);
+    counters["bytes_received"] = load(m.bytes_received);
+    counters["pool_acquires"] = load(m.pool_acquires);
+    counters["pool_exhausted"] = load(m.pool_exhausted);
+    py::dict histograms;
+    histograms["connect_duration_seconds"] = HistogramToDict(m.connect_duration);
+    histograms["statement_duration_seconds"] = HistogramToDict(m.statement_duration);
+    histograms["pool_acquire_duration_seconds"] = HistogramToDict(m.pool_acquire_duration);
+    py::dict result;
+    result["counters"] = counters;
+    result["histograms"] = histograms;
+    return result;
 }
 
 // Thread-safe decimal separator setting
@@ -5971,6 +6482,9 @@ PYBIND11_MODULE(ddbc_bindings, m) {
 
     m.attr("SQL_NO_TOTAL") = static_cast<int>(SQL_NO_TOTAL);
 
+    // DB-API exception types, raised for the DriverErrors the bindings throw
+    RegisterDbApiExceptions(m);
+
     // Expose the C++ functions to Python
     m.def("ThrowStdException", &ThrowStdException);
     m.def("GetDriverPathCpp", &GetDriverPathCpp, "Get the path to the ODBC driver");
@@ -5999,7 +6513,9 @@ PYBIND11_MODULE(ddbc_bindings, m) {
     // Define error info class
     py::class_<ErrorInfo>(m, "ErrorInfo")
         .def_readwrite("sqlState", &ErrorInfo::sqlState)
-        .def_readwrite("ddbcErrorMsg", &ErrorInfo::ddbcErrorMsg);
+        .def_readwrite("ddbcErrorMsg", &ErrorInfo::ddbcErrorMsg)
+        .def_readwrite("nativeError", &ErrorInfo::nativeError)
+        .def_readwrite("records", &ErrorInfo::records);
 
     py::class_<SqlHandle, SqlHandlePtr>(m, "SqlHandle")
         .def("free", &SqlHandle::free, "Free the handle")
@@ -6017,8 +6533,14 @@ PYBIND11_MODULE(ddbc_bindings, m) {
         .def("set_attr", &ConnectionHandle::setAttr, py::arg("attribute"), py::arg("value"),
              "Set connection attribute")
         .def("alloc_statement_handle", &ConnectionHandle::allocStatementHandle)
-        .def("get_info", &ConnectionHandle::getInfo, py::arg("info_type"));
-    m.def("enable_pooling", &enable_pooling, "Enable global connection pooling");
+        .def("get_info", &ConnectionHandle::getInfo, py::arg("info_type"))
+        .def("get_usage", &ConnectionHandle::usage, py::arg("reset") = false,
+             "Get the rows and bytes this connection has fetched, affected and sent");
+    m.def("enable_pooling", &enable_pooling, "Enable global connection pooling",
+          py::arg("max_size"), py::arg("idle_timeout"), py::arg("min_size") = 0,
+          py::arg("max_lifetime") = 0, py::arg("validation_query") = std::u16string());
+    m.def("pool_stats", &pool_stats, "Get the size and counters of every connection pool");
+    m.def("metrics", &metrics, "Get the process-wide driver counters and histograms");
     m.def("close_pooling", []() { ConnectionPoolManager::getInstance().closePools(); });
     m.def("DDBCSQLExecDirect", &SQLExecDirect_wrap, "Execute a SQL query directly");
     m.def("DDBCSQLExecute", &SQLExecute_wrap, "Prepare and execute T-SQL statements",
@@ -6035,20 +6557,25 @@ PYBIND11_MODULE(ddbc_bindings, m) {
     m.def("DDBCSQLDescribeCol", &SQLDescribeCol_wrap,
           "Get information about a column in the result set");
     m.def("DDBCSQLGetData", &SQLGetData_wrap, "Retrieve data from the result set");
+    m.def("DDBCSQLGetDataChunk", &SQLGetDataChunk_wrap,
+          "Retrieve the next part of a column of the current row");
     m.def("DDBCSQLMoreResults", &SQLMoreResults_wrap, "Check for more results in the result set");
     m.def("DDBCSQLFetchOne", &FetchOne_wrap, "Fetch one row from the result set",
           py::arg("StatementHandle"), py::arg("row"), py::arg("charEncoding") = "utf-16le",
-          py::arg("wcharEncoding") = "utf-16le", py::arg("charCtype") = SQL_C_WCHAR);
+          py::arg("wcharEncoding") = "utf-16le", py::arg("charCtype") = SQL_C_WCHAR,
+          py::arg("fetch") = true);
     m.def("DDBCSQLFetchMany", &FetchMany_wrap, py::arg("StatementHandle"), py::arg("rows"),
           py::arg("fetchSize"), py::arg("charEncoding") = "utf-16le",
           py::arg("wcharEncoding") = "utf-16le", py::arg("charCtype") = SQL_C_WCHAR,
           "Fetch many rows from the result set");
     m.def("DDBCSQLFetchAll", &FetchAll_wrap, "Fetch all rows from the result set",
           py::arg("StatementHandle"), py::arg("rows"), py::arg("charEncoding") = "utf-16le",
-          py::arg("wcharEncoding") = "utf-16le", py::arg("charCtype") = SQL_C_WCHAR);
+          py::arg("wcharEncoding") = "utf-16le", py::arg("charCtype") = SQL_C_WCHAR,
+          py::arg("fetchSizeHint") = 0);
     m.def("DDBCSQLFetchArrowBatch", &FetchArrowBatch_wrap,
           "Fetch an arrow batch of given length from the result set");
     m.def("DDBCSQLFreeHandle", &SQLFreeHandle_wrap, "Free a handle");
+    m.def("DDBCSQLCancel", &SQLCancel_wrap, "Cancel the statement executing on a handle");
     m.def("DDBCSQLResetStmt", &SQLResetStmt_wrap,
           "Close cursor and unbind params without freeing HSTMT");
     m.def("DDBCSQLCheckError", &SQLCheckError_Wrap, "Check for driver errors");
@@ -6061,6 +6588,8 @@ PYBIND11_MODULE(ddbc_bindings, m) {
     m.def("DDBCSQLFetchScroll", &SQLFetchScroll_wrap,
           "Scroll to a specific position in the result set and optionally "
           "fetch data");
+    m.def("DDBCSetTemporalNanoseconds", &DDBCSetTemporalNanoseconds,
+          "Return DATETIME2, DATETIMEOFFSET and TIME values with nanosecond precision");
     m.def("DDBCSetDecimalSeparator", &DDBCSetDecimalSeparator,
           "Set the decimal separator character");
     m.def(
@@ -13,6 +13,7 @@
 #include <pybind11/pytypes.h>  // Add this line for datetime support
 #include <pybind11/stl.h>
 #include <string>
+#include <tuple>
 #include <vector>
 
 
@@ -35,6 +36,15 @@ using py::literals::operator""_a;
 // Include logger bridge for LOG macros
 #include "logger_bridge.hpp"
 
+// Driver-wide and per-connection counters
+#include "metrics.h"
+
+// PEP 249 exception types and the DriverError translated into them
+#include "dbapi_errors.h"
+
+// SQL text argument accepted as str or UTF-8 bytes
+#include "sql_text.h"
+
 #if defined(__APPLE__) || defined(__linux__)
 #include <dlfcn.h>
 #endif
@@ -113,6 +123,7 @@ typedef SQLRETURN(SQL_API* SQLEndTranFunc)(SQLSMALLINT, SQLHANDLE, SQLSMALLINT);
 typedef SQLRETURN(SQL_API* SQLFreeHandleFunc)(SQLSMALLINT, SQLHANDLE);
 typedef SQLRETURN(SQL_API* SQLDisconnectFunc)(SQLHDBC);
 typedef SQLRETURN(SQL_API* SQLFreeStmtFunc)(SQLHSTMT, SQLUSMALLINT);
+typedef SQLRETURN(SQL_API* SQLCancelFunc)(SQLHSTMT);
 
 // Diagnostic APIs
 typedef SQLRETURN(SQL_API* SQLGetDiagRecFunc)(SQLSMALLINT, SQLHANDLE, SQLSMALLINT, SQLWCHAR*,
@@ -171,6 +182,7 @@ extern SQLEndTranFunc SQLEndTran_ptr;
 extern SQLFreeHandleFunc SQLFreeHandle_ptr;
 extern SQLDisconnectFunc SQLDisconnect_ptr;
 extern SQLFreeStmtFunc SQLFreeStmt_ptr;
+extern SQLCancelFunc SQLCancel_ptr;
 
 // Diagnostic APIs
 extern SQLGetDiagRecFunc SQLGetDiagRec_ptr;
@@ -181,7 +193,7 @@ extern SQLDescribeParamFunc SQLDescribeParam_ptr;
 extern SQLParamDataFunc SQLParamData_ptr;
 extern SQLPutDataFunc SQLPutData_ptr;
 
-// Throws a std::runtime_error with the given message
+// Throws a DriverError without SQLSTATE (raised as ddbc_bindings.InterfaceError)
 void ThrowStdException(const std::string& message);
 
 // Define a platform-agnostic type for the driver handle
@@ -281,6 +293,10 @@ class SqlHandle {
     std::unordered_map<int, DescribedParamInfo> describeCache;
     void clearDescribeCache() { describeCache.clear(); }
 
+    // Usage counters of the connection a statement handle belongs to (null for
+    // other handle types); set by ConnectionHandle::allocStatementHandle()
+    std::shared_ptr<ConnectionUsage> usage;
+
   private:
     SQLSMALLINT _type;
     SQLHANDLE _handle;
@@ -293,6 +309,9 @@ using SqlHandlePtr = std::shared_ptr<SqlHandle>;
 struct ErrorInfo {
     std::string sqlState;
     std::string ddbcErrorMsg;
+    int nativeError = 0;  // SQL Server error number of the first diagnostic record
+    // Every diagnostic record of the failed call as (sqlState, nativeError, message)
+    std::vector<std::tuple<std::string, int, std::string>> records;
 };
 ErrorInfo SQLCheckError_Wrap(SQLSMALLINT handleType, SqlHandlePtr handle, SQLRETURN retcode);
 
@@ -381,13 +400,14 @@ struct ColumnBuffers {
     std::vector<std::vector<SQLGUID>> guidBuffers;
     std::vector<std::vector<SQLLEN>> indicators;
     std::vector<std::vector<DateTimeOffset>> datetimeoffsetBuffers;
+    std::vector<std::vector<SQL_NUMERIC_STRUCT>> numericBuffers;
 
     ColumnBuffers(SQLSMALLINT numCols, int fetchSize)
         : charBuffers(numCols), wcharBuffers(numCols), intBuffers(numCols),
           smallIntBuffers(numCols), realBuffers(numCols), doubleBuffers(numCols),
           timestampBuffers(numCols), bigIntBuffers(numCols), dateBuffers(numCols),
           timeBuffers(numCols), guidBuffers(numCols), datetimeoffsetBuffers(numCols),
-          indicators(numCols, std::vector<SQLLEN>(fetchSize)) {}
+          numericBuffers(numCols), indicators(numCols, std::vector<SQLLEN>(fetchSize)) {}
 };
 
 // Performance: Column processor function type for fast type conversion
@@ -0,0 +1,83 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT license.
+
+// Process-wide driver metrics, read by ddbc_bindings.metrics() (see mssql_python.driver_metrics).
+// Everything is updated with relaxed atomics, so recording never takes a lock and
+// never needs the GIL; a snapshot read while other threads record may be off by the
+// events in flight, which is fine for monitoring.
+
+#pragma once
+#include <array>
+#include <atomic>
+#include <chrono>
+#include <cstddef>
+#include <cstdint>
+
+// A latency histogram with fixed upper bounds in seconds (non-cumulative buckets;
+// the bindings make them cumulative, as Prometheus expects, when read).
+class MetricsHistogram {
+  public:
+    static constexpr std::array<double, 11> kBounds = {0.001, 0.005, 0.01, 0.025, 0.05, 0.1,
+                                                       0.25,  0.5,   1.0,  5.0,   30.0};
+
+    void observe(std::chrono::steady_clock::duration elapsed) {
+        double seconds = std::chrono::duration<double>(elapsed).count();
+        size_t bucket = 0;
+        while (bucket < kBounds.size() && seconds > kBounds[bucket]) {
+            ++bucket;
+        }
+        _buckets[bucket].fetch_add(1, std::memory_order_relaxed);
+        _count.fetch_add(1, std::memory_order_relaxed);
+        _sumMicros.fetch_add(static_cast<uint64_t>(seconds * 1e6), std::memory_order_relaxed);
+    }
+
+    // Observations in bucket i (i == kBounds.size() is the +Inf bucket)
+    uint64_t bucket(size_t i) const { return _buckets[i].load(std::memory_order_relaxed); }
+    uint64_t count() const { return _count.load(std::memory_order_relaxed); }
+    double sum() const {
+        return static_cast<double>(_sumMicros.load(std::memory_order_relaxed)) / 1e6;
+    }
+
+  private:
+    std::array<std::atomic<uint64_t>, kBounds.size() + 1> _buckets{};
+    std::atomic<uint64_t> _count{0};
+    std::atomic<uint64_t> _sumMicros{0};
+};
+
+struct DriverMetrics {
+    std::atomic<uint64_t> connections_opened{0};
+    std::atomic<uint64_t> connection_failures{0};
+    std::atomic<uint64_t> failed_logins{0};  // Connect failures with SQLSTATE class 28
+    std::atomic<uint64_t> statements_executed{0};
+    std::atomic<uint64_t> statement_errors{0};
+    std::atomic<uint64_t> rows_fetched{0};
+    // Measured on the values crossing the bindings, not on TDS packets: SQL text and
+    // string/binary parameters sent, string/binary column values received (text as
+    // UTF-16, as SQL_C_WCHAR transfers it)
+    std::atomic<uint64_t> bytes_sent{0};
+    std::atomic<uint64_t> bytes_received{0};
+    std::atomic<uint64_t> pool_acquires{0};
+    std::atomic<uint64_t> pool_exhausted{0};  // Acquires refused because the pool was full
+    MetricsHistogram connect_duration;
+    MetricsHistogram statement_duration;
+    MetricsHistogram pool_acquire_duration;
+
+    static DriverMetrics& get() {
+        static DriverMetrics metrics;
+        return metrics;
+    }
+
+    static void add(std::atomic<uint64_t>& counter, uint64_t value = 1) {
+        counter.fetch_add(value, std::memory_order_relaxed);
+    }
+};
+
+// Cumulative usage of one connection (Connection.usage() in Python), shared with the
+// statement handles it allocates; counted on the same values as DriverMetrics
+struct ConnectionUsage {
+    std::atomic<uint64_t> statements_executed{0};
+    std::atomic<uint64_t> rows_fetched{0};
+    std::atomic<uint64_t> rows_affected{0};  // Row counts the driver reports for DML
+    std::atomic<uint64_t> bytes_sent{0};
+    std::atomic<uint64_t> bytes_received{0};
+};
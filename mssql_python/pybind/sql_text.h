@@ -0,0 +1,100 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT license.
+
+// SQL text passed to DDBCSQLExecute and DDBCSQLExecDirect. SQL Server only accepts
+// UTF-16 SQL text (TDS batch and RPC requests carry it as UTF-16), so the conversion
+// cannot be skipped, but it is done once, straight into the buffer handed to the
+// driver: a str from its internal representation, without the intermediate UTF-16
+// bytes object of pybind11's std::u16string caster, and bytes as UTF-8, so a large
+// generated script read from a file never has to become a str.
+
+#pragma once
+#include <pybind11/pybind11.h>
+#include <simdutf.h>
+
+#include <string>
+
+#include "dbapi_errors.h"
+
+// UTF-16 SQL text converted from a str or from UTF-8 bytes
+struct SqlText : std::u16string {};
+
+// Converts str to UTF-16 by the width of its code units. Strings that are not valid
+// UTF-16 (lone surrogates) are left to Python's encoder, which raises
+// UnicodeEncodeError as the std::u16string caster does.
+inline void PyUnicodeToUtf16(PyObject* text, std::u16string& out) {
+    const Py_ssize_t length = PyUnicode_GET_LENGTH(text);
+    const void* data = PyUnicode_DATA(text);
+    switch (PyUnicode_KIND(text)) {
+        case PyUnicode_1BYTE_KIND:
+            out.resize(length);
+            simdutf::convert_latin1_to_utf16le(static_cast<const char*>(data), length,
+                                               out.data());
+            return;
+        case PyUnicode_2BYTE_KIND:
+            if (simdutf::validate_utf16le(static_cast<const char16_t*>(data), length)) {
+                out.assign(static_cast<const char16_t*>(data), length);
+                return;
+            }
+            break;
+        default: {
+            const char32_t* units = static_cast<const char32_t*>(data);
+            out.resize(simdutf::utf16_length_from_utf32(units, length));
+            if (simdutf::convert_utf32_to_utf16le(units, length, out.data()) == out.size()) {
+                return;
+            }
+            break;
+        }
+    }
+    pybind11::object encoded = pybind11::reinterpret_steal<pybind11::object>(
+        PyUnicode_AsEncodedString(text, "utf-16-le", "strict"));
+    if (!encoded) {
+        throw pybind11::error_already_set();
+    }
+    out.assign(reinterpret_cast<const char16_t*>(PyBytes_AS_STRING(encoded.ptr())),
+               PyBytes_GET_SIZE(encoded.ptr()) / sizeof(char16_t));
+}
+
+// Converts UTF-8 to UTF-16; invalid UTF-8 raises ddbc_bindings.DataError (22021,
+// character not in repertoire)
+inline void Utf8ToUtf16(const char* data, size_t length, std::u16string& out) {
+    // Every UTF-8 byte yields at most one UTF-16 code unit
+    out.resize(length);
+    simdutf::result result =
+        simdutf::convert_utf8_to_utf16le_with_errors(data, length, out.data());
+    if (result.error != simdutf::error_code::SUCCESS) {
+        throw DriverError("22021", "SQL text is not valid UTF-8 (at byte " +
+                                       std::to_string(result.count) + ")");
+    }
+    out.resize(result.count);
+}
+
+namespace pybind11 {
+namespace detail {
+template <>
+struct type_caster<SqlText> {
+  public:
+    PYBIND11_TYPE_CASTER(SqlText, const_name("str | bytes"));
+
+    bool load(handle source, bool) {
+        PyObject* object = source.ptr();
+        if (PyUnicode_Check(object)) {
+            PyUnicodeToUtf16(object, value);
+            return true;
+        }
+        if (PyBytes_Check(object)) {
+            Utf8ToUtf16(PyBytes_AS_STRING(object), PyBytes_GET_SIZE(object), value);
+            return true;
+        }
+        return false;
+    }
+
+    static handle cast(const SqlText& text, return_value_policy, handle) {
+        int byteOrder = -1;  // little-endian
+        return PyUnicode_DecodeUTF16(reinterpret_cast<const char*>(text.data()),
+                                     static_cast<Py_ssize_t>(text.size() * sizeof(char16_t)),
+                                     nullptr, &byteOrder);
+    }
+};
+}  // namespace detail
+}  // namespace pybind11
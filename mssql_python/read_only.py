@@ -0,0 +1,140 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module implements the client-side checks of read-only connections
+(connect(read_only=True)): statements that obviously modify data, objects or
+permissions are rejected before they are sent to the server.
+"""
+
+from typing import List, Tuple, Union
+
+from mssql_python.exceptions import ProgrammingError
+from mssql_python.parameter_helper import _LITERAL_TOKEN_RE
+
+# Statements rejected wherever they appear
+_WRITE_STATEMENTS = {
+    "BACKUP",
+    "DENY",
+    "GRANT",
+    "KILL",
+    "RECONFIGURE",
+    "RESTORE",
+    "REVOKE",
+    "SHUTDOWN",
+    "UPDATETEXT",
+    "WRITETEXT",
+}
+# Statements rejected unless they only touch temporary tables (#t) or table
+# variables (@t), which reports use for intermediate results
+_TARGETED_WRITES = {"ALTER", "CREATE", "DELETE", "DROP", "INSERT", "MERGE", "TRUNCATE", "UPDATE"}
+# Words between a statement and the name of the object it writes
+_TARGET_PREFIXES = {
+    "ALTER",
+    "EXISTS",
+    "FROM",
+    "IF",
+    "INTO",
+    "OR",
+    "PERCENT",
+    "PROC",
+    "PROCEDURE",
+    "TABLE",
+    "TOP",
+}
+
+
+def _tokens(sql: str) -> List[Tuple[str, str]]:
+    return [
+        (m.lastgroup, m.group())
+        for m in _LITERAL_TOKEN_RE.finditer(sql)
+        if m.lastgroup not in ("comment", "space")
+    ]
+
+
+def _is_temporary(name: str) -> bool:
+    return name.lstrip('["').startswith(("#", "@"))
+
+
+def _targets(tokens: List[Tuple[str, str]], position: int) -> Tuple[List[str], int]:
+    """
+    Return the names of the objects written by the statement whose keyword is at
+    position (several for DROP TABLE a, b), and the position after them.
+    """
+    depth = 0
+    while position < len(tokens):
+        kind, text = tokens[position]
+        if text == "(":
+            depth += 1
+        elif text == ")":
+            depth -= 1
+        elif depth == 0 and not (kind == "word" and text.upper() in _TARGET_PREFIXES):
+            if kind not in ("word", "identifier"):
+                return [], position
+            break
+        position += 1
+    names = []
+    while position < len(tokens) and tokens[position][0] in ("word", "identifier"):
+        # A multi-part name, e.g. dbo.t
+        name = tokens[position][1]
+        position += 1
+        while (
+            position + 1 < len(tokens)
+            and tokens[position][1] == "."
+            and tokens[position + 1][0] in ("word", "identifier")
+        ):
+            name += "." + tokens[position + 1][1]
+            position += 2
+        names.append(name)
+        if position + 1 < len(tokens) and tokens[position][1] == ",":
+            position += 1
+        else:
+            break
+    return names, position
+
+
+def check_read_only(sql: Union[str, bytes]) -> None:
+    """
+    Raise unless sql looks read-only.
+
+    Rejected are BACKUP, GRANT, REVOKE, DENY, RESTORE, KILL and the like, and
+    INSERT, UPDATE, DELETE, MERGE, SELECT ... INTO, TRUNCATE and CREATE, ALTER or
+    DROP of anything other than temporary tables and table variables. This is a
+    guardrail against mistakes, not a security boundary: the text of dynamic SQL
+    and what stored procedures run are not inspected, so the login should still
+    only be granted read permissions.
+
+    Raises:
+        ProgrammingError: If sql contains a statement that writes.
+    """
+    if isinstance(sql, bytes):
+        sql = sql.decode("latin-1")
+    tokens = _tokens(sql)
+    position = 0
+    while position < len(tokens):
+        kind, text = tokens[position]
+        keyword = text.upper() if kind == "word" else ""
+        previous = tokens[position - 1][1].upper() if position else ""
+        following = tokens[position + 1][1].upper() if position + 1 < len(tokens) else ""
+        position += 1
+        if keyword in _WRITE_STATEMENTS:
+            _reject(keyword)
+        # FOR UPDATE declares a cursor, ON DELETE a foreign key action and THEN INSERT
+        # the action of a MERGE, whose target is checked with the MERGE
+        if keyword == "INTO" or (
+            keyword in _TARGETED_WRITES and previous not in ("FOR", "ON", "THEN")
+        ):
+            # MERGE JOIN and MERGE UNION are hints, UPDATE(column) is a trigger function
+            if (keyword == "MERGE" and following in ("JOIN", "UNION")) or (
+                keyword == "UPDATE" and following == "("
+            ):
+                continue
+            names, position = _targets(tokens, position)
+            if not names or not all(_is_temporary(name) for name in names):
+                _reject(f"{keyword} {', '.join(names)}".strip())
+
+
+def _reject(statement: str) -> None:
+    raise ProgrammingError(
+        driver_error=f"{statement} is not allowed on a read-only connection",
+        ddbc_error="The connection was opened with read_only=True",
+    )
@@ -0,0 +1,287 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module waits for a SQL Server to accept logins, e.g. a container that was
+just started. Each attempt checks the TCP port, exchanges a TDS pre-login packet
+and logs in, so the wait can tell a closed port from a server that is still
+starting and from a login that will never succeed.
+"""
+
+import re
+import socket
+import struct
+import time
+from typing import Any, Dict, Optional, Tuple
+
+from mssql_python import db_connection
+from mssql_python.connection import _server_candidates
+from mssql_python.connection_string_parser import _ConnectionStringParser
+from mssql_python.environment import environment_defaults
+from mssql_python.exceptions import (
+    CertificateTrustError,
+    DatabaseError,
+    DatabaseUnavailableError,
+    InvalidCredentialsError,
+    LoginDisabledError,
+    OperationalError,
+)
+from mssql_python.logging import logger
+
+# States reported while waiting, from the least to the most ready
+PORT_CLOSED = "port_closed"
+SERVER_STARTING = "server_starting"
+AUTH_FAILED = "auth_failed"
+READY = "ready"
+
+_DEFAULT_PORT = 1433
+# Upper bounds in seconds for one pre-login exchange and for one login attempt
+_PROBE_TIMEOUT = 5.0
+_LOGIN_TIMEOUT = 30.0
+_PRELOGIN = 0x12
+_TABULAR_RESULT = 0x04
+_PRELOGIN_VERSION = 0x00
+_PRELOGIN_ENCRYPTION = 0x01
+_PRELOGIN_TERMINATOR = 0xFF
+_ENCRYPT_OFF = 0x00
+
+# Login errors of a server that is up but not ready for logins yet: the database
+# is unavailable or recovering (4060, 922, 927, Azure 40613), or setup runs upgrade
+# scripts after a start (18401). Connection errors carry no error number, so the
+# messages are matched.
+_STARTING_MESSAGES = re.compile(
+    r"Cannot open database|script upgrade mode|is being recovered|in the middle of a restore"
+    r"|is not currently available|Failed to open the explicitly specified database"
+    r"|server is in the process of shutting down",
+    re.IGNORECASE,
+)
+# Login failures that retrying does not fix
+_FINAL_LOGIN_ERRORS = (InvalidCredentialsError, LoginDisabledError, CertificateTrustError)
+_AUTH_MESSAGES = re.compile(r"Login failed|password|authentication", re.IGNORECASE)
+
+
+def _tcp_endpoint(connection_str: str) -> Optional[Tuple[str, int]]:
+    """Return (host, port) of a single TCP server, None when it cannot be probed."""
+    params = _ConnectionStringParser(validate_keywords=False)._parse(connection_str)
+    params.update({key.lower(): value for key, value in environment_defaults(params).items()})
+    servers = _server_candidates(params)
+    if len(servers) != 1 or (params.get("encrypt") or "").lower() == "strict":
+        # TDS 8 starts with TLS, so a clear-text pre-login would be refused
+        return None
+    return server_endpoint(servers[0])
+
+
+def server_endpoint(server: str) -> Optional[Tuple[str, int]]:
+    """Return (host, port) of a Server value, None if it is not a TCP address with a port."""
+    server = server.strip()
+    if server.lower().startswith("tcp:"):
+        server = server[4:]
+    elif re.match(r"^(np|lpc|admin):|^\(localdb\)", server, re.IGNORECASE):
+        return None
+    host, _, port = server.partition(",")
+    if "\\" in host and not port:
+        # Named instances find their port through SQL Browser
+        return None
+    host = host.split("\\")[0].strip()
+    if host in (".", "(local)", ""):
+        host = "localhost"
+    try:
+        return host, int(port) if port else _DEFAULT_PORT
+    except ValueError:
+        return None
+
+
+def prelogin_packet(encryption: int = _ENCRYPT_OFF) -> bytes:
+    """Return a TDS PRELOGIN packet offering the VERSION and ENCRYPTION options."""
+    options_length = 2 * 5 + 1
+    version = bytes(6)
+    payload = (
+        struct.pack(">BHH", _PRELOGIN_VERSION, options_length, len(version))
+        + struct.pack(">BHH", _PRELOGIN_ENCRYPTION, options_length + len(version), 1)
+        + bytes([_PRELOGIN_TERMINATOR])
+        + version
+        + bytes([encryption])
+    )
+    return struct.pack(">BBHHBB", _PRELOGIN, 0x01, 8 + len(payload), 0, 1, 0) + payload
+
+
+def prelogin_options(packet: bytes) -> Dict[int, bytes]:
+    """
+    Return the options of a PRELOGIN response by token.
+
+    Raises:
+        ValueError: If packet is not a PRELOGIN response.
+    """
+    if len(packet) < 8 or packet[0] != _TABULAR_RESULT:
+        raise ValueError("Not a TDS pre-login response")
+    payload = packet[8:]
+    options = {}
+    index = 0
+    while index < len(payload) and payload[index] != _PRELOGIN_TERMINATOR:
+        if index + 5 > len(payload):
+            raise ValueError("Truncated TDS pre-login response")
+        token, offset, length = struct.unpack(">BHH", payload[index : index + 5])
+        if offset + length > len(payload):
+            raise ValueError("Truncated TDS pre-login response")
+        options[token] = payload[offset : offset + length]
+        index += 5
+    return options
+
+
+def parse_prelogin_response(packet: bytes) -> Optional[str]:
+    """
+    Return the server version of a PRELOGIN response, e.g. "16.0.4135".
+
+    Raises:
+        ValueError: If packet is not a PRELOGIN response.
+    """
+    version = prelogin_options(packet).get(_PRELOGIN_VERSION, b"")
+    if len(version) < 6:
+        return None
+    major, minor, build = struct.unpack(">BBH", version[:4])
+    return f"{major}.{minor}.{build}"
+
+
+def _recv_exactly(sock: socket.socket, size: int) -> bytes:
+    data = b""
+    while len(data) < size:
+        chunk = sock.recv(size - len(data))
+        if not chunk:
+            raise ConnectionError("Connection closed during the TDS pre-login")
+        data += chunk
+    return data
+
+
+def probe_prelogin(host: str, port: int, timeout: float = 5.0) -> Tuple[str, Optional[str]]:
+    """
+    Check whether a TCP endpoint speaks TDS.
+
+    Returns:
+        tuple: (state, version), where state is PORT_CLOSED when nothing accepts
+            connections on the port, SERVER_STARTING when the port is open but
+            the server does not answer the pre-login yet, and READY (for logins
+            to be tried) with the server version otherwise.
+    """
+    try:
+        sock = socket.create_connection((host, port), timeout=timeout)
+    except OSError as e:
+        logger.debug("wait_for_server: %s,%d is closed: %s", host, port, e)
+        return PORT_CLOSED, None
+    try:
+        sock.settimeout(timeout)
+        sock.sendall(prelogin_packet())
+        header = _recv_exactly(sock, 8)
+        length = struct.unpack(">H", header[2:4])[0]
+        packet = header + _recv_exactly(sock, max(length - 8, 0))
+        return READY, parse_prelogin_response(packet)
+    except (OSError, ValueError) as e:
+        logger.debug("wait_for_server: %s,%d does not answer the pre-login: %s", host, port, e)
+        return SERVER_STARTING, None
+    finally:
+        sock.close()
+
+
+def classify_connect_error(error: BaseException) -> str:
+    """
+    Return why a connection attempt failed: SERVER_STARTING, AUTH_FAILED (for
+    logins that cannot succeed, including untrusted server certificates) or
+    PORT_CLOSED (the server could not be reached at all).
+    """
+    message = str(error)
+    if isinstance(error, DatabaseUnavailableError) or _STARTING_MESSAGES.search(message):
+        return SERVER_STARTING
+    if isinstance(error, _FINAL_LOGIN_ERRORS) or _AUTH_MESSAGES.search(message):
+        return AUTH_FAILED
+    return PORT_CLOSED
+
+
+def wait_for_server(
+    connection_str: str,
+    timeout: float = 60.0,
+    poll_interval: float = 1.0,
+    **connect_kwargs: Any,
+) -> Dict[str, Any]:
+    """
+    Wait until a SQL Server accepts logins with the given connection string.
+
+    Every attempt first checks the server's TCP port and exchanges a TDS
+    pre-login packet with it (skipped for named pipes, named instances without
+    a port, failover lists and Encrypt=strict), then logs in and runs SELECT 1.
+    The server counts as starting while the port is closed, while the pre-login
+    goes unanswered, and while logins fail because the database is recovering
+    (4060) or upgrade scripts run (18401). A login that fails for any other
+    reason, such as a wrong password, ends the wait at once.
+
+    Args:
+        connection_str: The connection string, as for mssql_python.connect().
+        timeout: Seconds to wait in total.
+        poll_interval: Seconds between attempts.
+        **connect_kwargs: Passed to mssql_python.connect() (attrs_before, ...).
+
+    Returns:
+        dict: "state" (READY), "attempts", "elapsed" seconds and "version" (the
+            server's version from the pre-login, or None if not probed).
+
+    Raises:
+        DatabaseError: The login error, for a login that cannot succeed.
+        OperationalError: If the server is not ready after timeout seconds; its
+            message names the last state seen (port_closed or server_starting).
+
+    Example:
+        # docker compose up -d, then:
+        mssql_python.wait_for_server(
+            "Server=localhost,1433;UID=sa;PWD=...;TrustServerCertificate=yes", timeout=120
+        )
+    """
+    if isinstance(poll_interval, bool) or not isinstance(poll_interval, (int, float)):
+        raise ValueError("poll_interval must be a positive number of seconds")
+    if poll_interval <= 0:
+        raise ValueError("poll_interval must be a positive number of seconds")
+    endpoint = _tcp_endpoint(connection_str)
+    start = time.monotonic()
+    deadline = start + timeout
+    attempts = 0
+    state: Optional[str] = None
+    last_error: Optional[BaseException] = None
+    version = None
+    while True:
+        attempts += 1
+        remaining = max(deadline - time.monotonic(), 0.0)
+        probe_timeout = max(min(remaining, _PROBE_TIMEOUT), 0.1)
+        new_state = READY
+        if endpoint is not None:
+            new_state, version = probe_prelogin(endpoint[0], endpoint[1], probe_timeout)
+        if new_state == READY:
+            try:
+                conn = db_connection.connect(
+                    connection_str,
+                    timeout=max(int(min(remaining, _LOGIN_TIMEOUT)), 1),
+                    **connect_kwargs,
+                )
+            except DatabaseError as e:
+                last_error = e
+                new_state = classify_connect_error(e)
+                if new_state == AUTH_FAILED:
+                    logger.error("wait_for_server: Login failed, not retrying: %s", e)
+                    raise
+            else:
+                try:
+                    conn.cursor().execute("SELECT 1").fetchall()
+                finally:
+                    conn.close()
+                elapsed = time.monotonic() - start
+                logger.info("wait_for_server: Server ready after %.1fs", elapsed)
+                return {
+                    "state": READY,
+                    "attempts": attempts,
+                    "elapsed": elapsed,
+                    "version": version,
+                }
+        if new_state != state:
+            logger.info("wait_for_server: %s", new_state)
+            state = new_state
+        if time.monotonic() + poll_interval > deadline:
+            raise OperationalError(
+                driver_error=f"SQL Server is not ready after {timeout}s ({state})",
+                ddbc_error=str(last_error) if last_error else f"Last state: {state}",
+            ) from last_error
+        time.sleep(poll_interval)
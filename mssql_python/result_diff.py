@@ -0,0 +1,164 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module implements diff_results(): a streaming comparison of two result sets
+by key, e.g. a source table and its migrated copy, that reports the inserted,
+deleted and changed keys while holding only one batch of each side in memory.
+"""
+
+from typing import TYPE_CHECKING, Any, Dict, Iterator, List, Optional, Sequence, Tuple, Union
+
+from mssql_python.exceptions import ProgrammingError
+
+if TYPE_CHECKING:
+    from mssql_python.cursor import Cursor
+
+_KINDS = ("inserted", "deleted", "changed")
+
+
+class RowDifference:
+    """
+    A key whose row differs between the two result sets of diff_results().
+
+    Attributes:
+        kind: "deleted" (only in the first result set), "inserted" (only in the
+            second) or "changed" (in both, with different values).
+        key: The values of the key columns.
+        columns: Names of the columns whose values differ, for "changed".
+        row_a: The row of the first result set, or None for "inserted".
+        row_b: The row of the second result set, or None for "deleted".
+    """
+
+    def __init__(
+        self,
+        kind: str,
+        key: Tuple[Any, ...],
+        columns: Optional[List[str]] = None,
+        row_a: Any = None,
+        row_b: Any = None,
+    ) -> None:
+        if kind not in _KINDS:
+            raise ValueError(f"kind must be one of {', '.join(_KINDS)}, got {kind!r}")
+        self.kind = kind
+        self.key = key
+        self.columns = columns or []
+        self.row_a = row_a
+        self.row_b = row_b
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Return the difference as a plain dictionary, without the rows."""
+        return {"kind": self.kind, "key": self.key, "columns": self.columns}
+
+    def __repr__(self) -> str:
+        return f"RowDifference({self.kind!r}, key={self.key!r}, columns={self.columns!r})"
+
+
+def _column_indices(cursor: "Cursor", side: str) -> Dict[str, int]:
+    if not cursor.description:
+        raise ProgrammingError(
+            driver_error=f"{side} has no result set to compare",
+            ddbc_error="diff_results() requires executed queries that return rows",
+        )
+    return {desc[0]: i for i, desc in enumerate(cursor.description)}
+
+
+def _sort_key(key: Tuple[Any, ...]) -> Tuple[Tuple[bool, Any], ...]:
+    # NULL sorts first, as in ORDER BY
+    return tuple((value is not None, value) for value in key)
+
+
+def _keyed_rows(
+    cursor: "Cursor", key_indices: List[int], batch_size: int, side: str
+) -> Iterator[Tuple[Any, Tuple[Any, ...], Any]]:
+    """Yield (sort key, key, row) for each row, checking that keys ascend."""
+    previous = None
+    while True:
+        batch = cursor.fetchmany(batch_size)
+        if not batch:
+            return
+        for row in batch:
+            key = tuple(row[i] for i in key_indices)
+            sort_key = _sort_key(key)
+            if previous is not None and not previous < sort_key:
+                problem = "repeats" if previous == sort_key else "is out of order"
+                raise ProgrammingError(
+                    driver_error=f"Key {key!r} of {side} {problem}",
+                    ddbc_error=(
+                        "diff_results() requires rows ordered by unique key columns "
+                        "(ORDER BY the keys, with a binary collation for string keys)"
+                    ),
+                )
+            previous = sort_key
+            yield sort_key, key, row
+
+
+def diff_results(
+    cursor_a: "Cursor",
+    cursor_b: "Cursor",
+    key_columns: Union[str, Sequence[str]],
+    batch_size: int = 10000,
+) -> Iterator[RowDifference]:
+    """
+    Compare the result sets of two cursors by key, yielding each difference.
+
+    Both queries must return their rows ordered by the key columns, ascending
+    and without duplicate keys: both sides are read in step, batch_size rows
+    at a time, as a merge join, so memory does not grow with the row count.
+    Rows are read from each cursor's connection, so the result sets may come
+    from different servers. The key order is checked as Python compares the
+    values; for string keys, ORDER BY them with a binary collation (e.g.
+    COLLATE Latin1_General_BIN2) so the server's order matches.
+
+    Columns are matched by name; those in only one result set are not compared.
+
+    Args:
+        cursor_a: Cursor with the first (e.g. source) result set.
+        cursor_b: Cursor with the second (e.g. migrated) result set.
+        key_columns: Name, or names, of the columns that identify a row.
+        batch_size: Rows fetched from each cursor at a time.
+
+    Yields:
+        RowDifference: "deleted", "inserted" or "changed", in key order.
+
+    Raises:
+        ProgrammingError: If a cursor has no result set, lacks a key column, or
+            its rows are not ordered by unique keys.
+    """
+    if isinstance(key_columns, str):
+        key_columns = [key_columns]
+    if not key_columns:
+        raise ValueError("key_columns must name at least one column")
+    if batch_size < 1:
+        raise ValueError(f"batch_size must be positive, got {batch_size}")
+    columns_a = _column_indices(cursor_a, "cursor_a")
+    columns_b = _column_indices(cursor_b, "cursor_b")
+    for side, columns in (("cursor_a", columns_a), ("cursor_b", columns_b)):
+        missing = [name for name in key_columns if name not in columns]
+        if missing:
+            raise ProgrammingError(
+                driver_error=f"Key column(s) {', '.join(missing)} not in the result set of {side}",
+                ddbc_error="diff_results() key columns must be in both result sets",
+            )
+    compared = [name for name in columns_a if name in columns_b and name not in key_columns]
+
+    keys_a = [columns_a[name] for name in key_columns]
+    keys_b = [columns_b[name] for name in key_columns]
+    rows_a = _keyed_rows(cursor_a, keys_a, batch_size, "cursor_a")
+    rows_b = _keyed_rows(cursor_b, keys_b, batch_size, "cursor_b")
+    a = next(rows_a, None)
+    b = next(rows_b, None)
+    while a is not None or b is not None:
+        if b is None or (a is not None and a[0] < b[0]):
+            yield RowDifference("deleted", a[1], row_a=a[2])
+            a = next(rows_a, None)
+        elif a is None or b[0] < a[0]:
+            yield RowDifference("inserted", b[1], row_b=b[2])
+            b = next(rows_b, None)
+        else:
+            changed = [
+                name for name in compared if a[2][columns_a[name]] != b[2][columns_b[name]]
+            ]
+            if changed:
+                yield RowDifference("changed", a[1], changed, a[2], b[2])
+            a = next(rows_a, None)
+            b = next(rows_b, None)
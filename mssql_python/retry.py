@@ -0,0 +1,164 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module provides the retry policy cursors apply to statements that fail with
+transient errors such as deadlocks, lock request timeouts and query timeouts, and
+the reconnect-and-replay loop used for statements marked idempotent.
+"""
+
+import random
+import time
+from typing import Any, Callable, Dict, Iterable, Optional
+
+from mssql_python.logging import logger
+
+DEADLOCK_VICTIM = 1205
+LOCK_REQUEST_TIMEOUT = 1222
+# Query timeouts carry no server error number; -2 is the number SqlClient uses
+QUERY_TIMEOUT = -2
+
+DEFAULT_RETRY_ERRORS = frozenset({DEADLOCK_VICTIM, LOCK_REQUEST_TIMEOUT, QUERY_TIMEOUT})
+
+# A deadlock victim's whole transaction is rolled back, so re-running only the
+# failed statement inside a user transaction would lose the earlier work
+_TRANSACTION_ABORTING_ERRORS = frozenset({DEADLOCK_VICTIM})
+
+_TIMEOUT_SQLSTATES = ("HYT00",)
+
+
+def error_number(error: BaseException) -> Optional[int]:
+    """Return the error number of a driver exception, QUERY_TIMEOUT for query timeouts."""
+    if getattr(error, "sqlstate", None) in _TIMEOUT_SQLSTATES:
+        return QUERY_TIMEOUT
+    return getattr(error, "native_error", None)
+
+
+def is_connection_loss(error: BaseException) -> bool:
+    """Return True if error means the session to the server was lost (SQLSTATE class 08)."""
+    return (getattr(error, "sqlstate", None) or "").startswith("08")
+
+
+class RetryPolicy:
+    """
+    Retry policy for statements failing with transient errors.
+
+    Assign one to cursor.retry_policy to have execute() and executemany() re-run a
+    statement that failed with one of the given errors, sleeping between attempts
+    with exponential backoff: backoff, 2 * backoff, 4 * backoff, ... capped at
+    max_backoff, each randomized to between half and all of that value when jitter is
+    on. Every attempt converts and binds the parameters afresh.
+
+    Outside autocommit mode a deadlock (1205) is not retried: the server has rolled
+    back the whole transaction, which the application has to run again.
+
+    Attributes:
+        max_attempts: Total number of attempts, including the first one.
+        errors: Error numbers to retry; QUERY_TIMEOUT (-2) stands for query timeouts.
+        backoff: Delay in seconds before the first retry.
+        max_backoff: Upper bound for the delay in seconds.
+        jitter: Whether delays are randomized to spread out competing clients.
+    """
+
+    def __init__(
+        self,
+        max_attempts: int = 3,
+        errors: Iterable[int] = DEFAULT_RETRY_ERRORS,
+        backoff: float = 0.1,
+        max_backoff: float = 5.0,
+        jitter: bool = True,
+    ) -> None:
+        if isinstance(max_attempts, bool) or not isinstance(max_attempts, int) or max_attempts < 1:
+            raise ValueError("max_attempts must be a positive integer")
+        for name, value in (("backoff", backoff), ("max_backoff", max_backoff)):
+            if isinstance(value, bool) or not isinstance(value, (int, float)) or value < 0:
+                raise ValueError(f"{name} must be a non-negative number of seconds")
+        errors = frozenset(errors)
+        if not all(isinstance(e, int) and not isinstance(e, bool) for e in errors):
+            raise ValueError("errors must be integer error numbers")
+        self.max_attempts = max_attempts
+        self.errors = errors
+        self.backoff = float(backoff)
+        self.max_backoff = float(max_backoff)
+        self.jitter = bool(jitter)
+
+    def is_retryable(self, error: BaseException, in_transaction: bool = False) -> bool:
+        """Return True if a statement failing with error may be run again."""
+        number = error_number(error)
+        if number not in self.errors:
+            return False
+        return not (in_transaction and number in _TRANSACTION_ABORTING_ERRORS)
+
+    def delay(self, attempt: int) -> float:
+        """Return the delay in seconds after the given failed attempt (1-based)."""
+        delay = min(self.backoff * 2 ** (attempt - 1), self.max_backoff)
+        if self.jitter:
+            delay *= random.uniform(0.5, 1.0)
+        return delay
+
+    def call(self, func: Callable[[], Any], in_transaction: bool = False) -> Any:
+        """Call func, retrying it according to this policy, and return its result."""
+        attempt = 1
+        while True:
+            try:
+                return func()
+            except Exception as e:  # pylint: disable=broad-exception-caught
+                if attempt >= self.max_attempts or not self.is_retryable(e, in_transaction):
+                    raise
+                delay = self.delay(attempt)
+                logger.warning(
+                    "Statement failed with error %s, retrying in %.2fs (attempt %d of %d)",
+                    error_number(e),
+                    delay,
+                    attempt + 1,
+                    self.max_attempts,
+                )
+                time.sleep(delay)
+                attempt += 1
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Return the policy settings as a plain dictionary."""
+        return {
+            "max_attempts": self.max_attempts,
+            "errors": sorted(self.errors),
+            "backoff": self.backoff,
+            "max_backoff": self.max_backoff,
+            "jitter": self.jitter,
+        }
+
+    def __repr__(self) -> str:
+        return (
+            f"RetryPolicy(max_attempts={self.max_attempts}, errors={sorted(self.errors)}, "
+            f"backoff={self.backoff}, max_backoff={self.max_backoff}, jitter={self.jitter})"
+        )
+
+
+def call_with_reconnect(
+    func: Callable[[], Any], reconnect: Callable[[], None], policy: Optional[RetryPolicy] = None
+) -> Any:
+    """
+    Call func, reconnecting and calling it again after connection-loss errors.
+
+    Attempts and delays follow policy (reconnection failures count as attempts);
+    without a policy func is replayed once, right after reconnecting.
+    """
+    max_attempts = policy.max_attempts if policy is not None else 2
+    attempt = 1
+    while True:
+        try:
+            if attempt > 1:
+                reconnect()
+            return func()
+        except Exception as e:  # pylint: disable=broad-exception-caught
+            if attempt >= max_attempts or not is_connection_loss(e):
+                raise
+            delay = policy.delay(attempt) if policy is not None else 0.0
+            logger.warning(
+                "Connection lost (SQLSTATE %s), reconnecting in %.2fs (attempt %d of %d)",
+                getattr(e, "sqlstate", None),
+                delay,
+                attempt + 1,
+                max_attempts,
+            )
+            if delay:
+                time.sleep(delay)
+            attempt += 1
@@ -0,0 +1,181 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module provides read-write splitting: a pool of endpoints with one read-write
+server and any number of read-only secondaries, routing read-only work to the
+secondaries and everything else to the read-write endpoint.
+"""
+
+import contextlib
+import contextvars
+import itertools
+import threading
+from typing import TYPE_CHECKING, Any, Iterator, List, Optional, Sequence, Union
+
+from mssql_python import db_connection
+from mssql_python.exceptions import OperationalError
+from mssql_python.logging import logger
+
+if TYPE_CHECKING:
+    from mssql_python.connection import Connection
+    from mssql_python.row import Row
+
+# Set inside RoutingPool.reader() scopes, per thread and per asyncio task
+_reader_scope: contextvars.ContextVar[bool] = contextvars.ContextVar(
+    "mssql_python_reader_scope", default=False
+)
+
+
+class RoutingPool:
+    """
+    Connections to a read-write endpoint and its read-only secondaries.
+
+    Work is routed to a secondary when it is flagged readonly=True or runs inside
+    a ``with pool.reader():`` scope, and to the read-write endpoint otherwise.
+    Secondaries are used in turn; one that cannot be reached is skipped, and when
+    none can, reads fall back to the read-write endpoint unless disabled with
+    fallback_to_writer=False. Each endpoint's connections are pooled by the
+    driver (see mssql_python.pooling), so getting a connection is cheap.
+
+    A secondary may lag behind the primary: work that has to see its own writes
+    should not be routed to it.
+
+    Attributes:
+        writer: Connection string of the read-write endpoint.
+        readers: Connection strings of the read-only endpoints, e.g. replicas or an
+            availability group listener with ApplicationIntent=ReadOnly.
+        fallback_to_writer: Whether reads use the read-write endpoint when no
+            secondary can be reached.
+    """
+
+    def __init__(
+        self,
+        writer: str,
+        readers: Sequence[str] = (),
+        fallback_to_writer: bool = True,
+        **connect_kwargs: Any,
+    ) -> None:
+        """
+        Args:
+            writer: Connection string of the read-write endpoint.
+            readers: Connection strings of the read-only endpoints.
+            fallback_to_writer: Route reads to the writer when no reader is reachable.
+            **connect_kwargs: Passed to mssql_python.connect() for every endpoint
+                (autocommit, timeout, attrs_before, ...); read_only=True makes a
+                pool for reporting credentials whose connections all reject writes.
+        """
+        if not isinstance(writer, str) or not writer:
+            raise ValueError("writer must be a non-empty connection string")
+        if isinstance(readers, str) or not all(isinstance(r, str) and r for r in readers):
+            raise ValueError("readers must be a sequence of non-empty connection strings")
+        self.writer = writer
+        self.readers: List[str] = list(readers)
+        self.fallback_to_writer = bool(fallback_to_writer)
+        self._connect_kwargs = connect_kwargs
+        self._next_reader = itertools.count()
+        self._lock = threading.Lock()
+
+    @contextlib.contextmanager
+    def reader(self) -> Iterator["RoutingPool"]:
+        """
+        Route connect() and execute() calls made in the block to the secondaries.
+
+        Calls passing readonly=False still go to the read-write endpoint. The scope
+        applies to the current thread (or asyncio task) only.
+        """
+        token = _reader_scope.set(True)
+        try:
+            yield self
+        finally:
+            _reader_scope.reset(token)
+
+    @staticmethod
+    def in_reader_scope() -> bool:
+        """Return True inside a reader() block."""
+        return _reader_scope.get()
+
+    def _reader_order(self) -> List[str]:
+        with self._lock:
+            start = next(self._next_reader) % len(self.readers)
+        return self.readers[start:] + self.readers[:start]
+
+    def connect(self, readonly: Optional[bool] = None) -> "Connection":
+        """
+        Open a connection to the endpoint the work is routed to.
+
+        Args:
+            readonly: True for a secondary, False for the read-write endpoint, or
+                None (default) for a secondary only inside a reader() block.
+
+        Returns:
+            Connection: A new connection; close it to return it to the pool.
+
+        Raises:
+            OperationalError: If no endpoint the work may use can be reached, or
+                read-only work has no secondary and fallback_to_writer is off.
+        """
+        if readonly is None:
+            readonly = self.in_reader_scope()
+        if not readonly:
+            return db_connection.connect(self.writer, **self._connect_kwargs)
+
+        last_error: Optional[BaseException] = None
+        for conn_str in self._reader_order() if self.readers else ():
+            try:
+                return db_connection.connect(conn_str, **self._connect_kwargs)
+            except OperationalError as e:
+                logger.warning("RoutingPool: Read-only endpoint unreachable, trying next: %s", e)
+                last_error = e
+        if self.fallback_to_writer:
+            if self.readers:
+                logger.warning("RoutingPool: No read-only endpoint reachable, using the writer")
+            return db_connection.connect(self.writer, **self._connect_kwargs)
+        if not self.readers:
+            raise OperationalError(
+                driver_error="No read-only endpoint is configured",
+                ddbc_error="Pass readers, or fallback_to_writer=True to read from the writer",
+            )
+        raise OperationalError(
+            driver_error="No read-only endpoint could be reached",
+            ddbc_error=str(last_error) if last_error else "",
+        ) from last_error
+
+    def execute(
+        self, sql: str, *params: Any, readonly: Optional[bool] = None
+    ) -> Union[List["Row"], int]:
+        """
+        Run one statement on the endpoint it is routed to and return its result.
+
+        The statement runs on a pooled connection that is committed and returned to
+        the pool afterwards.
+
+        Args:
+            sql: The statement to run.
+            *params: Parameters to bind.
+            readonly: As for connect().
+
+        Returns:
+            list or int: The rows of the statement, or its row count if it returns
+                no rows.
+        """
+        conn = self.connect(readonly=readonly)
+        try:
+            cursor = conn.cursor()
+            try:
+                cursor.execute(sql, *params)
+                result: Union[List["Row"], int] = (
+                    cursor.fetchall() if cursor.description else cursor.rowcount
+                )
+            finally:
+                cursor.close()
+            if not conn.autocommit:
+                conn.commit()
+            return result
+        finally:
+            conn.close()
+
+    def __repr__(self) -> str:
+        return (
+            f"RoutingPool(readers={len(self.readers)}, "
+            f"fallback_to_writer={self.fallback_to_writer})"
+        )
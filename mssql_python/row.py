@@ -16,9 +16,13 @@ class Row:
     A row of data from a cursor fetch operation. Provides both tuple-like indexing
     and attribute access to column values.
 
-    Column attribute access behavior depends on the global 'lowercase' setting:
-    - When enabled: Case-insensitive attribute access
-    - When disabled (default): Case-sensitive attribute access matching original column names
+    Column attribute access behavior depends on the global 'lowercase' and
+    'case_insensitive_columns' settings:
+    - When either is enabled: Case-insensitive attribute access
+    - When both are disabled (default): Case-sensitive attribute access matching
+      original column names
+
+    Which column a duplicated name refers to follows 'duplicate_column_names'.
 
     Example:
         row = cursor.fetchone()
@@ -34,6 +38,7 @@ class Row:
         converter_map=None,
         uuid_str_indices=None,
         column_map_lower=None,
+        transform_map=None,
     ):
         """
         Initialize a Row object with values and pre-built column map.
@@ -48,6 +53,9 @@ class Row:
             column_map_lower: Pre-built lowercase column map for O(1) case-insensitive
                 lookups. Built once per result set in the cursor when lowercase is enabled;
                 None when lowercase is off (the default). Shared across all rows.
+            transform_map: Tuple of (column index, callables) pairs from
+                Cursor.add_column_transform, resolved once per result set. None means
+                no transforms.
         """
         # Apply output converters if available using pre-computed converter map
         if converter_map:
@@ -68,6 +76,9 @@ class Row:
         if uuid_str_indices:
             self._stringify_uuids(uuid_str_indices)
 
+        if transform_map:
+            self._apply_transforms(transform_map)
+
         self._column_map = column_map
         self._cursor = cursor
         # Lowercase map is pre-built once per result set in the cursor and shared
@@ -92,6 +103,21 @@ class Row:
             if v is not None and isinstance(v, _uuid.UUID):
                 vals[i] = str(v).upper()
 
+    def _apply_transforms(self, transform_map):
+        """Apply per-column transforms to self._values in-place, skipping NULLs."""
+        vals = self._values
+        if not isinstance(vals, list):
+            vals = list(vals)
+            self._values = vals
+
+        for i, funcs in transform_map:
+            v = vals[i]
+            if v is None:
+                continue
+            for func in funcs:
+                v = func(v)
+            vals[i] = v
+
     def _apply_output_converters(self, values, cursor):
         """
         Apply output converters to raw values.
@@ -112,11 +138,8 @@ class Row:
             if desc is None or value is None:
                 continue
 
-            # Get SQL type from description
-            sql_type = desc[1]  # type_code is at index 1 in description tuple
-
-            # Try to get a converter for this type
-            converter = cursor.connection.get_output_converter(sql_type)
+            # Try to get a converter for the column's SQL type, then its type_code
+            converter = cursor._column_output_converter(i, desc)
 
             # If no converter found for the SQL type but the value is a string or bytes,
             # try the WVARCHAR converter as a fallback
@@ -0,0 +1,139 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module computes streaming checksums over query results, so two result sets
+(e.g. a source table and its migrated copy) can be reconciled by comparing digests
+instead of materializing and diffing both sides.
+"""
+
+import datetime
+import decimal
+import hashlib
+import uuid
+from typing import Any, Iterable, List, Optional, Sequence
+
+from mssql_python.fips import require_approved_hash
+
+# Values are encoded as a one-byte type tag followed by a length-prefixed payload,
+# so that e.g. the integer 1, the string "1" and NULL all hash differently and
+# adjacent column values can never run together.
+_NULL = b"\x00"
+
+
+def _field(tag: bytes, payload: bytes) -> bytes:
+    return tag + len(payload).to_bytes(4, "big") + payload
+
+
+def encode_value(value: Any) -> bytes:
+    """
+    Return the canonical byte encoding of a fetched value.
+
+    Numeric values are normalized (Decimal('1.10') == Decimal('1.1')) and
+    timezone-aware datetimes are converted to UTC, so the same data read from
+    columns with a different scale or offset produces the same digest.
+
+    Raises:
+        TypeError: If the value type has no canonical encoding.
+    """
+    if value is None:
+        return _NULL
+    if isinstance(value, bool):
+        return _field(b"b", b"1" if value else b"0")
+    if isinstance(value, int):
+        return _field(b"i", str(value).encode("ascii"))
+    if isinstance(value, float):
+        return _field(b"f", repr(value).encode("ascii"))
+    if isinstance(value, decimal.Decimal):
+        normalized = value.normalize() if value.is_finite() else value
+        if normalized.is_zero():
+            normalized = decimal.Decimal(0)
+        return _field(b"d", str(normalized).encode("ascii"))
+    if isinstance(value, str):
+        return _field(b"s", value.encode("utf-8"))
+    if isinstance(value, (bytes, bytearray, memoryview)):
+        return _field(b"x", bytes(value))
+    if isinstance(value, datetime.datetime):
+        if value.tzinfo is not None:
+            value = value.astimezone(datetime.timezone.utc).replace(tzinfo=None)
+        return _field(b"t", value.isoformat().encode("ascii"))
+    if isinstance(value, datetime.date):
+        return _field(b"D", value.isoformat().encode("ascii"))
+    if isinstance(value, datetime.time):
+        return _field(b"T", value.isoformat().encode("ascii"))
+    if isinstance(value, uuid.UUID):
+        return _field(b"u", value.bytes)
+    raise TypeError(f"Cannot compute a checksum for values of type {type(value).__name__}")
+
+
+def hash_row(row: Sequence[Any], algorithm: str = "sha256") -> bytes:
+    """Return the digest of one row's canonical encoding."""
+    require_approved_hash(algorithm)
+    hasher = hashlib.new(algorithm)
+    for value in row:
+        hasher.update(encode_value(value))
+    return hasher.digest()
+
+
+class ResultChecksum:
+    """
+    Accumulates per-row hashes into a digest for a whole result set.
+
+    With ordered=True the digest depends on row order (rows are chained into one
+    running hash). With ordered=False the row digests are summed modulo
+    2**digest_bits, so the same rows in any order produce the same digest; use
+    this when the two sides cannot be read in the same order.
+
+    Attributes:
+        algorithm: The hashlib algorithm name.
+        ordered: Whether the digest depends on row order.
+        row_count: Number of rows seen.
+        row_hashes: Hex digest of every row, if include_row_hashes was set.
+    """
+
+    def __init__(
+        self, algorithm: str = "sha256", ordered: bool = True, include_row_hashes: bool = False
+    ) -> None:
+        # Fail early on unknown (or, in FIPS mode, unapproved) algorithms
+        require_approved_hash(algorithm)
+        self._running = hashlib.new(algorithm)
+        self.algorithm = algorithm
+        self.ordered = ordered
+        self.row_count = 0
+        self.row_hashes: Optional[List[str]] = [] if include_row_hashes else None
+        self._bits = self._running.digest_size * 8
+        self._sum = 0
+
+    def update(self, rows: Iterable[Sequence[Any]]) -> None:
+        """Add rows to the checksum."""
+        for row in rows:
+            row_digest = hash_row(row, self.algorithm)
+            if self.ordered:
+                self._running.update(row_digest)
+            else:
+                self._sum = (self._sum + int.from_bytes(row_digest, "big")) % (1 << self._bits)
+            if self.row_hashes is not None:
+                self.row_hashes.append(row_digest.hex())
+            self.row_count += 1
+
+    @property
+    def digest(self) -> str:
+        """Hex digest of all rows seen so far."""
+        if self.ordered:
+            return self._running.hexdigest()
+        return self._sum.to_bytes(self._bits // 8, "big").hex()
+
+    def __eq__(self, other: Any) -> bool:
+        if not isinstance(other, ResultChecksum):
+            return NotImplemented
+        return (self.algorithm, self.ordered, self.row_count, self.digest) == (
+            other.algorithm,
+            other.ordered,
+            other.row_count,
+            other.digest,
+        )
+
+    def __repr__(self) -> str:
+        return (
+            f"ResultChecksum(algorithm={self.algorithm!r}, ordered={self.ordered}, "
+            f"row_count={self.row_count}, digest={self.digest!r})"
+        )
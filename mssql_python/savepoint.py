@@ -0,0 +1,140 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module provides savepoint-scoped nested transactions on top of the transaction
+a connection runs in manual-commit mode, following the nested transaction model of
+SQLAlchemy's Connection.begin_nested().
+"""
+
+import re
+from typing import TYPE_CHECKING, Any, Dict, Optional
+
+from mssql_python.exceptions import ProgrammingError
+from mssql_python.logging import logger
+
+if TYPE_CHECKING:
+    from mssql_python.connection import Connection
+
+# SAVE TRANSACTION needs an open transaction. In manual-commit mode the driver runs
+# with IMPLICIT_TRANSACTIONS ON, under which BEGIN TRANSACTION would open two nested
+# levels that commit() could not close, so the option is lifted around it.
+_SAVE_SQL = (
+    "IF @@TRANCOUNT = 0 BEGIN "
+    "SET IMPLICIT_TRANSACTIONS OFF; BEGIN TRANSACTION; SET IMPLICIT_TRANSACTIONS ON "
+    "END; SAVE TRANSACTION {name}"
+)
+_ROLLBACK_SQL = "ROLLBACK TRANSACTION {name}"
+# Savepoint names of Connection.savepoint(), within the 32 characters SQL Server keeps
+_SAVEPOINT_NAME_RE = re.compile(r"[A-Za-z_][A-Za-z0-9_]{0,31}")
+
+
+class NestedTransaction:
+    """
+    A savepoint inside the connection's current transaction.
+
+    Returned by Connection.begin_nested(). rollback() undoes the work done since
+    the savepoint while keeping the enclosing transaction open; commit() releases
+    the savepoint, leaving its work to be committed or rolled back with the
+    enclosing transaction. Used as a context manager, the savepoint is released
+    when the block completes and rolled back when it raises.
+
+    Ending a savepoint also ends the savepoints nested inside it, and
+    connection.commit() or connection.rollback() ends all of them.
+
+    Attributes:
+        name: The savepoint name used on the server.
+        is_active: True until the savepoint is released or rolled back.
+    """
+
+    def __init__(self, connection: "Connection", name: str) -> None:
+        self.connection = connection
+        self.name = name
+        self.is_active = True
+
+    def _check_active(self, action: str) -> None:
+        if not self.is_active:
+            raise ProgrammingError(
+                driver_error=f"Cannot {action} a savepoint that is no longer active",
+                ddbc_error=f"Savepoint {self.name} has already been ended",
+            )
+
+    def commit(self) -> None:
+        """Release the savepoint; its work stays part of the enclosing transaction."""
+        self._check_active("release")
+        # SQL Server has no RELEASE SAVEPOINT: savepoints live until the transaction ends
+        self.connection._end_savepoint(self)
+        logger.debug("Savepoint %s released", self.name)
+
+    def rollback(self) -> None:
+        """Roll back the work done since the savepoint."""
+        self._check_active("roll back")
+        # Ended only once rolled back: after a failed ROLLBACK the savepoint stays set
+        self.connection._run_transaction_statement(_ROLLBACK_SQL.format(name=self.name))
+        self.connection._end_savepoint(self)
+        logger.debug("Rolled back to savepoint %s", self.name)
+
+    def __enter__(self) -> "NestedTransaction":
+        return self
+
+    def __exit__(self, exc_type: Any, exc_val: Any, exc_tb: Any) -> None:
+        if not self.is_active:
+            return
+        if exc_type is None:
+            self.commit()
+        else:
+            self.rollback()
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Return the savepoint state as a plain dictionary."""
+        return {"name": self.name, "is_active": self.is_active}
+
+    def __repr__(self) -> str:
+        return f"NestedTransaction(name={self.name!r}, is_active={self.is_active})"
+
+
+def begin_nested(connection: "Connection", name: Optional[str] = None) -> NestedTransaction:
+    """
+    Set a savepoint in the connection's transaction and return it.
+
+    See Connection.begin_nested and Connection.savepoint (with name).
+    """
+    if name is not None and not (isinstance(name, str) and _SAVEPOINT_NAME_RE.fullmatch(name)):
+        raise ProgrammingError(
+            driver_error=f"Invalid savepoint name {name!r}",
+            ddbc_error="Savepoint names are up to 32 letters, digits and underscores",
+        )
+    if connection.autocommit:
+        operation = "begin_nested()" if name is None else "savepoint()"
+        raise ProgrammingError(
+            driver_error=f"{operation} requires autocommit to be off",
+            ddbc_error="Savepoints need a transaction; autocommit is enabled",
+        )
+    if name is None:
+        connection._savepoint_counter += 1
+        name = f"mssql_sp_{connection._savepoint_counter}"
+    savepoint = NestedTransaction(connection, name)
+    connection._run_transaction_statement(_SAVE_SQL.format(name=savepoint.name))
+    connection._savepoints.append(savepoint)
+    logger.debug("Savepoint %s set", savepoint.name)
+    return savepoint
+
+
+def rollback_to(connection: "Connection", name: str) -> None:
+    """
+    Roll back to the most recent active savepoint named name, keeping it set.
+
+    See Connection.rollback_to.
+    """
+    for index in range(len(connection._savepoints) - 1, -1, -1):
+        if connection._savepoints[index].name == name:
+            break
+    else:
+        raise ProgrammingError(
+            driver_error=f"No active savepoint named {name!r}",
+            ddbc_error="Savepoints end with the transaction and when rolled back past",
+        )
+    for ended in connection._savepoints[index + 1 :]:
+        ended.is_active = False
+    del connection._savepoints[index + 1 :]
+    connection._run_transaction_statement(_ROLLBACK_SQL.format(name=name))
+    logger.debug("Rolled back to savepoint %s", name)
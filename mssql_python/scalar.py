@@ -0,0 +1,143 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module checks and converts the single value of Connection.fetch_val(...,
+as_type=...), so callers get the Python type they ask for or a DataError naming
+what the query returned, instead of casting row[0] themselves.
+"""
+
+import datetime
+import decimal
+import uuid
+from typing import Any, Callable, Dict, Optional
+
+from mssql_python.exceptions import DataError
+
+# Default of typed_scalar(): NULL raises DataError
+NO_DEFAULT: Any = object()
+
+
+def _to_int(value: Any) -> Optional[int]:
+    if isinstance(value, bool):
+        return None
+    if isinstance(value, int):
+        return value
+    # DECIMAL(p, 0) and FLOAT columns holding whole numbers
+    if isinstance(value, decimal.Decimal) and value == value.to_integral_value():
+        return int(value)
+    if isinstance(value, float) and value.is_integer():
+        return int(value)
+    return None
+
+
+def _to_float(value: Any) -> Optional[float]:
+    if isinstance(value, (int, float, decimal.Decimal)) and not isinstance(value, bool):
+        return float(value)
+    return None
+
+
+def _to_decimal(value: Any) -> Optional[decimal.Decimal]:
+    if isinstance(value, decimal.Decimal):
+        return value
+    if isinstance(value, int) and not isinstance(value, bool):
+        return decimal.Decimal(value)
+    if isinstance(value, float):
+        # The shortest repr, so 0.1 becomes Decimal("0.1") rather than its binary expansion
+        return decimal.Decimal(repr(value))
+    return None
+
+
+def _to_bool(value: Any) -> Optional[bool]:
+    if isinstance(value, bool):
+        return value
+    if isinstance(value, int) and value in (0, 1):
+        return bool(value)
+    return None
+
+
+def _to_str(value: Any) -> Optional[str]:
+    if isinstance(value, str):
+        return value
+    if isinstance(value, uuid.UUID):
+        return str(value)
+    return None
+
+
+def _to_uuid(value: Any) -> Optional[uuid.UUID]:
+    if isinstance(value, uuid.UUID):
+        return value
+    if isinstance(value, str):
+        try:
+            return uuid.UUID(value)
+        except ValueError:
+            return None
+    return None
+
+
+def _to_bytes(value: Any) -> Optional[bytes]:
+    return bytes(value) if isinstance(value, (bytes, bytearray, memoryview)) else None
+
+
+def _to_date(value: Any) -> Optional[datetime.date]:
+    # datetime is a date subclass, but dropping its time would lose data
+    if isinstance(value, datetime.date) and not isinstance(value, datetime.datetime):
+        return value
+    return None
+
+
+_CONVERTERS: Dict[type, Callable[[Any], Any]] = {
+    int: _to_int,
+    float: _to_float,
+    decimal.Decimal: _to_decimal,
+    bool: _to_bool,
+    str: _to_str,
+    uuid.UUID: _to_uuid,
+    bytes: _to_bytes,
+    datetime.date: _to_date,
+}
+
+
+def convert_scalar(value: Any, as_type: type, sql: str = "") -> Any:
+    """
+    Return value as an as_type, converting only where no information is lost
+    (e.g. Decimal("3") to int, int to Decimal, a UNIQUEIDENTIFIER to str).
+
+    Raises:
+        DataError: If value is not of, or cannot be converted to, as_type.
+    """
+    if not isinstance(as_type, type):
+        raise TypeError(f"as_type must be a type, got {as_type!r}")
+    converter = _CONVERTERS.get(as_type)
+    if converter is not None:
+        converted = converter(value)
+    else:
+        converted = value if isinstance(value, as_type) else None
+    if converted is None:
+        query = f" by {sql!r}" if sql else ""
+        raise DataError(
+            driver_error=(
+                f"Expected a value of type {as_type.__name__}, got "
+                f"{type(value).__name__} {value!r}{query}"
+            ),
+            ddbc_error=f"fetch_val() value is not a {as_type.__name__}",
+        )
+    return converted
+
+
+def typed_scalar(value: Any, as_type: type, default: Any = NO_DEFAULT, sql: str = "") -> Any:
+    """
+    Return the value of a query as an as_type (see convert_scalar), or default
+    if it is NULL (None, also for a query without rows).
+
+    Raises:
+        DataError: If the value cannot be converted, or is NULL without a default.
+    """
+    if value is not None:
+        return convert_scalar(value, as_type, sql)
+    if default is not NO_DEFAULT:
+        return default
+    query = f" by {sql!r}" if sql else ""
+    raise DataError(
+        driver_error=f"Expected a value of type {as_type.__name__}, got NULL or no rows{query}",
+        ddbc_error=f"fetch_val() value is not a {as_type.__name__}",
+    )
@@ -0,0 +1,88 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module reads connection secrets (the password_file and token_file options
+of connect()) from a file or an open file descriptor. The secret is read again
+each time a session is opened, so a Kubernetes secret mount or a Vault agent can
+rotate it without the process restarting.
+"""
+
+import errno
+import os
+from typing import Optional, Tuple, Union
+
+SecretSource = Union[str, "os.PathLike[str]", int, "SecretFile"]
+
+# Bytes read per os.read() call from a file descriptor
+_READ_SIZE = 65536
+
+
+class SecretFile:
+    """
+    A secret stored in a file or behind a file descriptor.
+
+    Paths are opened and read on every read(). Descriptors of regular files are
+    read again from their start; other descriptors (pipes, sockets) can be read
+    only once, so their value is kept.
+    Trailing newlines are dropped, as secret files usually end with one.
+    """
+
+    def __init__(self, source: SecretSource, name: str) -> None:
+        if isinstance(source, bool) or not isinstance(source, (str, os.PathLike, int)):
+            raise TypeError(f"{name} must be a path or a file descriptor, got {source!r}")
+        if isinstance(source, int) and source < 0:
+            raise ValueError(f"{name} must be a valid file descriptor, got {source}")
+        self.source = source
+        self.name = name
+        self._value: Optional[str] = None
+
+    @classmethod
+    def of(cls, source: Optional[SecretSource], name: str) -> Optional["SecretFile"]:
+        """Return source as a SecretFile, None and SecretFile instances unchanged."""
+        if source is None or isinstance(source, SecretFile):
+            return source
+        return cls(source, name)
+
+    def read(self) -> str:
+        """
+        Return the current secret.
+
+        Raises:
+            ValueError: If the file cannot be read or is not UTF-8 text.
+        """
+        if self._value is not None:
+            return self._value
+        try:
+            if isinstance(self.source, int):
+                data, seekable = self._read_descriptor(self.source)
+            else:
+                with open(self.source, "rb") as secret:
+                    data, seekable = secret.read(), True
+            value = data.decode("utf-8").rstrip("\r\n")
+        except OSError as e:
+            raise ValueError(f"Cannot read {self.name} {self.source}: {e.strerror}") from e
+        except UnicodeDecodeError as e:
+            raise ValueError(f"{self.name} {self.source} is not UTF-8 text") from e
+        if not seekable:
+            self._value = value
+        return value
+
+    @staticmethod
+    def _read_descriptor(fd: int) -> Tuple[bytes, bool]:
+        try:
+            os.lseek(fd, 0, os.SEEK_SET)
+            seekable = True
+        except OSError as e:
+            if e.errno != errno.ESPIPE:
+                raise
+            seekable = False
+        chunks = []
+        while True:
+            chunk = os.read(fd, _READ_SIZE)
+            if not chunk:
+                return b"".join(chunks), seekable
+            chunks.append(chunk)
+
+    def __repr__(self) -> str:
+        return f"SecretFile({self.source!r}, {self.name!r})"
+
@@ -0,0 +1,201 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module provides database snapshot helpers: creating a snapshot of a database
+with its sparse files placed next to the database's data files, reverting the
+database to it, and listing and dropping snapshots. Reverting makes them useful
+as fast resettable fixtures for integration tests.
+"""
+
+import ntpath
+import posixpath
+import time
+from typing import TYPE_CHECKING, Any, Dict, List, Optional
+
+from mssql_python.exceptions import OperationalError, ProgrammingError
+from mssql_python.helpers import quote_identifier
+from mssql_python.logging import logger
+
+if TYPE_CHECKING:
+    from mssql_python.connection import Connection
+
+# Seconds to wait for a reverted database to come back online, and between checks
+REVERT_ONLINE_TIMEOUT = 60.0
+_ONLINE_POLL_INTERVAL = 0.5
+
+_DATA_FILES_QUERY = (
+    "SELECT name, physical_name FROM sys.master_files "
+    "WHERE database_id = DB_ID(?) AND type = 0 ORDER BY file_id"
+)
+_SNAPSHOTS_QUERY = (
+    "SELECT s.name, DB_NAME(s.source_database_id), s.create_date FROM sys.databases AS s "
+    "WHERE s.source_database_id IS NOT NULL"
+)
+_STATUS_QUERY = "SELECT CAST(DATABASEPROPERTYEX(?, 'Status') AS nvarchar(60))"
+
+
+def _literal(text: str) -> str:
+    return "N'" + text.replace("'", "''") + "'"
+
+
+def snapshot_file_path(
+    physical_name: str, snapshot: str, logical_name: str, directory: Optional[str] = None
+) -> str:
+    """
+    Return the sparse file path for one data file of a snapshot.
+
+    The file goes into directory, or next to the source data file, and is named
+    <snapshot>_<logical name>.ss. Windows or Linux path rules are inferred from
+    the source file's path, since they follow the server, not the client.
+    """
+    paths = ntpath if "\\" in physical_name or ntpath.splitdrive(physical_name)[0] else posixpath
+    folder = directory if directory is not None else paths.dirname(physical_name)
+    return paths.join(folder, f"{snapshot}_{logical_name}.ss")
+
+
+def create_snapshot_sql(
+    snapshot: str, database: str, files: List[tuple], directory: Optional[str] = None
+) -> str:
+    """Return the CREATE DATABASE ... AS SNAPSHOT OF statement for the data files."""
+    if not files:
+        raise ProgrammingError(
+            driver_error=f"Database {database!r} has no data files to snapshot",
+            ddbc_error="No data files in sys.master_files",
+        )
+    file_specs = ", ".join(
+        f"(NAME = {quote_identifier(logical)}, "
+        f"FILENAME = {_literal(snapshot_file_path(physical, snapshot, logical, directory))})"
+        for logical, physical in files
+    )
+    return (
+        f"CREATE DATABASE {quote_identifier(snapshot)} ON {file_specs} "
+        f"AS SNAPSHOT OF {quote_identifier(database)}"
+    )
+
+
+def _require_autocommit(connection: "Connection", operation: str) -> None:
+    if not connection.autocommit:
+        raise ProgrammingError(
+            driver_error=f"{operation} needs autocommit",
+            ddbc_error="CREATE DATABASE and RESTORE cannot run inside a transaction",
+        )
+
+
+def _fetchall(connection: "Connection", sql: str, *params: Any) -> List[tuple]:
+    cursor = connection.cursor()
+    try:
+        return [tuple(row) for row in cursor.execute(sql, *params).fetchall()]
+    finally:
+        cursor.close()
+
+
+def _run(connection: "Connection", sql: str) -> None:
+    logger.info("snapshots: %s", logger.redaction.sql(sql))
+    cursor = connection.cursor()
+    try:
+        cursor.execute(sql, use_prepare=False)
+        while cursor.nextset():
+            pass
+    finally:
+        cursor.close()
+
+
+def _current_database(connection: "Connection") -> str:
+    return _fetchall(connection, "SELECT DB_NAME()")[0][0]
+
+
+def create_snapshot(
+    connection: "Connection",
+    snapshot: str,
+    database: Optional[str] = None,
+    directory: Optional[str] = None,
+) -> str:
+    """Create a database snapshot; see Connection.create_snapshot."""
+    _require_autocommit(connection, "Creating a database snapshot")
+    database = database or _current_database(connection)
+    files = _fetchall(connection, _DATA_FILES_QUERY, database)
+    sql = create_snapshot_sql(snapshot, database, files, directory)
+    _run(connection, sql)
+    return sql
+
+
+def list_snapshots(
+    connection: "Connection", database: Optional[str] = None
+) -> List[Dict[str, Any]]:
+    """List database snapshots; see Connection.snapshots."""
+    sql = _SNAPSHOTS_QUERY
+    params: tuple = ()
+    if database is not None:
+        sql += " AND s.source_database_id = DB_ID(?)"
+        params = (database,)
+    rows = _fetchall(connection, sql + " ORDER BY s.create_date", *params)
+    return [
+        {"name": name, "database": source, "created": created} for name, source, created in rows
+    ]
+
+
+def _source_database(connection: "Connection", snapshot: str) -> str:
+    for entry in list_snapshots(connection):
+        if entry["name"] == snapshot:
+            return entry["database"]
+    raise ProgrammingError(
+        driver_error=f"Database snapshot {snapshot!r} does not exist",
+        ddbc_error="No snapshot with this name in sys.databases",
+    )
+
+
+def drop_snapshot(connection: "Connection", snapshot: str) -> None:
+    """Drop a database snapshot; see Connection.drop_snapshot."""
+    _require_autocommit(connection, "Dropping a database snapshot")
+    _source_database(connection, snapshot)
+    _run(connection, f"DROP DATABASE {quote_identifier(snapshot)}")
+
+
+def _wait_online(connection: "Connection", database: str, timeout: float) -> None:
+    deadline = time.monotonic() + timeout
+    while True:
+        rows = _fetchall(connection, _STATUS_QUERY, database)
+        status = rows[0][0] if rows else None
+        if status == "ONLINE":
+            return
+        if time.monotonic() >= deadline:
+            raise OperationalError(
+                driver_error=f"Database {database!r} did not come online after the revert",
+                ddbc_error=f"Status after {timeout}s: {status}",
+            )
+        time.sleep(_ONLINE_POLL_INTERVAL)
+
+
+def revert_to_snapshot(
+    connection: "Connection",
+    snapshot: str,
+    disconnect_others: bool = True,
+    timeout: float = REVERT_ONLINE_TIMEOUT,
+) -> None:
+    """Revert a database to a snapshot; see Connection.revert_to_snapshot."""
+    _require_autocommit(connection, "Reverting to a database snapshot")
+    database = _source_database(connection, snapshot)
+    others = [entry["name"] for entry in list_snapshots(connection, database)]
+    if others != [snapshot]:
+        raise ProgrammingError(
+            driver_error=f"Database {database!r} has other snapshots: drop them first",
+            ddbc_error=f"Snapshots of {database}: {', '.join(others)}",
+        )
+    quoted = quote_identifier(database)
+    current = _current_database(connection)
+    # The revert needs the database to itself, including this session
+    _run(connection, "USE [master]")
+    try:
+        if disconnect_others:
+            _run(connection, f"ALTER DATABASE {quoted} SET SINGLE_USER WITH ROLLBACK IMMEDIATE")
+        try:
+            _run(
+                connection,
+                f"RESTORE DATABASE {quoted} FROM DATABASE_SNAPSHOT = {_literal(snapshot)}",
+            )
+        finally:
+            if disconnect_others:
+                _run(connection, f"ALTER DATABASE {quoted} SET MULTI_USER")
+        _wait_online(connection, database, timeout)
+    finally:
+        _run(connection, f"USE {quote_identifier(current)}")
@@ -0,0 +1,106 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+
+Statement size limits and batch splitting of SQL scripts for mssql-python.
+
+SQL Server accepts a batch of at most 65,536 network packets, i.e. 256 MB of
+UTF-16 text with the default packet size of 4096 bytes. Statements are checked
+against that limit before they are sent, so that an oversized statement fails
+with StatementTooLargeError instead of a dropped connection. Scripts too large
+for one batch (e.g. generated seed scripts) are split on GO separator lines,
+the way sqlcmd and SSMS run them, and can be read lazily from a file. Scripts
+and statements may be UTF-8 bytes, which the driver converts to UTF-16 once,
+without them becoming a str.
+"""
+
+import re
+from typing import Any, AnyStr, Iterable, Iterator, Mapping, Optional, Union
+
+from mssql_python.constants import ConstantsDDBC
+from mssql_python.exceptions import StatementTooLargeError
+
+# Number of network packets a batch may span, and the packet size without a
+# PacketSize setting (both in bytes as the server counts them)
+MAX_BATCH_PACKETS = 65536
+DEFAULT_PACKET_SIZE = 4096
+
+# A batch separator: GO alone on its line, with an optional repeat count and a
+# trailing comment; the bytes pattern is for UTF-8 scripts
+_GO_LINE_RE = re.compile(r"^\s*GO(?:\s+(\d+))?\s*(?:--.*)?$", re.IGNORECASE)
+_GO_LINE_BYTES_RE = re.compile(_GO_LINE_RE.pattern.encode(), re.IGNORECASE)
+
+
+def configured_packet_size(
+    params: Mapping[str, str], attrs_before: Optional[Mapping[int, Any]] = None
+) -> int:
+    """
+    Return the packet size a connection asks for: SQL_ATTR_PACKET_SIZE in
+    attrs_before, else PacketSize in the connection string, else the default.
+    """
+    value = (attrs_before or {}).get(ConstantsDDBC.SQL_ATTR_PACKET_SIZE.value)
+    if value is None:
+        value = params.get("PacketSize")
+    try:
+        size = int(value)
+    except (TypeError, ValueError):
+        return DEFAULT_PACKET_SIZE
+    return size if size > 0 else DEFAULT_PACKET_SIZE
+
+
+def batch_size_limit(packet_size: int = DEFAULT_PACKET_SIZE) -> int:
+    """Return the largest batch, in bytes, the server accepts with this packet size."""
+    return MAX_BATCH_PACKETS * packet_size
+
+
+def statement_size(sql: Union[str, bytes]) -> int:
+    """Return the size in bytes of sql, a str or UTF-8 bytes, as sent (UTF-16LE)."""
+    if isinstance(sql, bytes):
+        sql = sql.decode("utf-8", "surrogateescape")
+    return len(sql.encode("utf-16-le", "surrogatepass"))
+
+
+def check_statement_size(sql: Union[str, bytes], packet_size: int = DEFAULT_PACKET_SIZE) -> None:
+    """
+    Raise StatementTooLargeError if sql is larger than the server accepts in a batch.
+
+    A character takes at most 4 bytes in UTF-16 and a UTF-8 byte at most 2, so
+    only statements long enough to exceed the limit are converted to be measured.
+    """
+    limit = batch_size_limit(packet_size)
+    if len(sql) * (2 if isinstance(sql, bytes) else 4) <= limit:
+        return
+    size = statement_size(sql)
+    if size > limit:
+        raise StatementTooLargeError(size, limit)
+
+
+def iter_batches(script: Union[str, bytes, Iterable[AnyStr]]) -> Iterator[AnyStr]:
+    """
+    Yield the batches of a SQL script separated by GO lines.
+
+    script is the script text, as str or UTF-8 bytes, or an iterable of its lines,
+    such as a file opened in text or binary mode, which is read one batch at a
+    time. Batches have the type of the script's lines. ``GO n`` yields the batch
+    n times. Batches that are empty or only whitespace are skipped. GO is
+    recognized on any line of its own, including one inside a multi-line comment
+    or string literal, as with sqlcmd.
+    """
+    lines = script.splitlines() if isinstance(script, (str, bytes)) else script
+    go_line, newline, line_end = _GO_LINE_RE, "\n", "\r\n"
+    batch = []
+    for line in lines:
+        if isinstance(line, bytes) and newline == "\n":
+            go_line, newline, line_end = _GO_LINE_BYTES_RE, b"\n", b"\r\n"
+        match = go_line.match(line)
+        if match is None:
+            batch.append(line.rstrip(line_end))
+            continue
+        text = newline.join(batch)
+        batch = []
+        if text.strip():
+            for _ in range(int(match.group(1) or 1)):
+                yield text
+    text = newline.join(batch)
+    if text.strip():
+        yield text
@@ -0,0 +1,139 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module bounds the statements executing at once on the connections of one
+connection pool (pooling(max_concurrent_statements=...)), so a burst of queries
+queues in the client instead of taking every worker thread of the server.
+"""
+
+import collections
+import threading
+import time
+from typing import Any, Deque, Dict, List, Optional
+
+from mssql_python.exceptions import OperationalError
+from mssql_python.logging import logger
+
+STATEMENT_QUEUE_POLICIES = ("wait", "fail")
+# Priority classes of queued statements, highest first
+PRIORITIES = ("high", "normal", "low")
+
+
+def check_priority(priority: str) -> str:
+    """Return priority if it is one of PRIORITIES, else raise ValueError."""
+    if priority not in PRIORITIES:
+        raise ValueError(f"priority must be 'high', 'normal' or 'low', got {priority!r}")
+    return priority
+
+
+class StatementLimiter:
+    """
+    Admits at most max_concurrent statements at a time. With policy "wait" an
+    execute beyond the limit queues until a statement finishes (or timeout
+    seconds pass); with "fail" it raises OperationalError at once.
+
+    Queued statements are admitted by priority class, then in arrival order, so
+    "high" (e.g. interactive) requests go ahead of "low" (e.g. batch) ones.
+    """
+
+    def __init__(
+        self, max_concurrent: int, policy: str = "wait", timeout: Optional[float] = None
+    ) -> None:
+        if max_concurrent < 1:
+            raise ValueError(f"max_concurrent must be positive, got {max_concurrent}")
+        if policy not in STATEMENT_QUEUE_POLICIES:
+            raise ValueError(f"policy must be 'wait' or 'fail', got {policy!r}")
+        if timeout is not None and timeout < 0:
+            raise ValueError(f"timeout must be non-negative or None, got {timeout}")
+        self.max_concurrent = max_concurrent
+        self.policy = policy
+        self.timeout = timeout
+        self._condition = threading.Condition()
+        self._active = 0
+        # Tokens of the queued statements, one queue per priority class
+        self._waiting: List[Deque[object]] = [collections.deque() for _ in PRIORITIES]
+        self._admitted = 0
+        self._waited = 0
+        self._rejected = 0
+        self._wait_seconds = 0.0
+
+    def acquire(self, priority: str = "normal") -> None:
+        """
+        Take a slot for one statement, waiting per the policy.
+
+        Raises:
+            ValueError: If priority is not "high", "normal" or "low".
+            OperationalError: If the limit is reached with policy "fail", or no
+                slot frees up within timeout seconds.
+        """
+        rank = PRIORITIES.index(check_priority(priority))
+        with self._condition:
+            ahead = self._waiting[: rank + 1]
+            if self._active < self.max_concurrent and not any(ahead):
+                self._active += 1
+                self._admitted += 1
+                return
+            if self.policy == "fail":
+                self._rejected += 1
+                self._raise_rejected("reached")
+            token = object()
+            queue = self._waiting[rank]
+            queue.append(token)
+            started = time.monotonic()
+            try:
+                admitted = self._condition.wait_for(
+                    lambda: self._active < self.max_concurrent
+                    and queue[0] is token
+                    and not any(self._waiting[:rank]),
+                    self.timeout,
+                )
+            finally:
+                queue.remove(token)
+                self._wait_seconds += time.monotonic() - started
+                # The next statement of the queue may be admitted now
+                self._condition.notify_all()
+            self._waited += 1
+            if not admitted:
+                self._rejected += 1
+                self._raise_rejected(f"still reached after waiting {self.timeout} seconds")
+            self._active += 1
+            self._admitted += 1
+
+    def release(self) -> None:
+        """Return the slot taken by acquire()."""
+        with self._condition:
+            if self._active > 0:
+                self._active -= 1
+                self._condition.notify_all()
+
+    def _raise_rejected(self, reason: str) -> None:
+        logger.warning(
+            "Statement rejected: max_concurrent_statements=%d %s", self.max_concurrent, reason
+        )
+        raise OperationalError(
+            driver_error="Too many concurrent statements on the connection pool",
+            ddbc_error=f"max_concurrent_statements ({self.max_concurrent}) {reason}",
+        )
+
+    def stats(self) -> Dict[str, Any]:
+        """
+        Return the limit and its queue counters: statements executing
+        ("statements_active") and queued ("statements_queued") now, and the
+        counts of statements admitted, that had to queue ("statements_waited")
+        or were refused ("statements_rejected"), with the total seconds spent
+        queued ("statement_wait_seconds"). "statements_queued_by_priority" splits
+        the queue by priority class.
+        """
+        with self._condition:
+            return {
+                "max_concurrent_statements": self.max_concurrent,
+                "statements_active": self._active,
+                "statements_queued": sum(len(queue) for queue in self._waiting),
+                "statements_queued_by_priority": {
+                    priority: len(queue) for priority, queue in zip(PRIORITIES, self._waiting)
+                },
+                "statements_admitted": self._admitted,
+                "statements_waited": self._waited,
+                "statements_rejected": self._rejected,
+                "statement_wait_seconds": self._wait_seconds,
+            }
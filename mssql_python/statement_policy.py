@@ -0,0 +1,201 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module implements statement policies (Connection.statement_policy): a check
+run on every statement before it is sent, which blocks statements such as DROP or
+TRUNCATE, or references to other databases, e.g. to sandbox analysts sharing a
+notebook environment.
+"""
+
+import re
+from typing import Callable, Iterable, List, Optional, Pattern, Sequence, Tuple, Union
+
+from mssql_python.exceptions import ProgrammingError
+from mssql_python.parameter_helper import _LITERAL_TOKEN_RE
+from mssql_python.read_only import _tokens
+
+# A statement policy: called with the SQL text, it blocks the statement by raising
+# or by returning False
+PolicyCallback = Callable[[str], Optional[bool]]
+
+# Words after which a name is that of a table, view, procedure or function
+_OBJECT_POSITIONS = {
+    "APPLY",
+    "EXEC",
+    "EXECUTE",
+    "FROM",
+    "INTO",
+    "JOIN",
+    "MERGE",
+    "REFERENCES",
+    "TABLE",
+    "UPDATE",
+    "USING",
+}
+# Functions reading from other servers and databases
+_REMOTE_FUNCTIONS = {"OPENDATASOURCE", "OPENQUERY", "OPENROWSET"}
+
+
+def _name_part(text: str) -> str:
+    if text[:1] == "[":
+        return text[1:-1].replace("]]", "]").lower()
+    if text[:1] == '"':
+        return text[1:-1].replace('""', '"').lower()
+    return text.lower()
+
+
+def _dotted_names(tokens: List[Tuple[str, str]]) -> Iterable[Tuple[int, List[str]]]:
+    """Yield the position and the parts of each name, "" for empty parts as in db..t."""
+    position = 0
+    while position < len(tokens):
+        kind, text = tokens[position]
+        if kind not in ("word", "identifier"):
+            position += 1
+            continue
+        start = position
+        parts = [_name_part(text)]
+        position += 1
+        while position < len(tokens) and tokens[position][1] == ".":
+            position += 1
+            if position < len(tokens) and tokens[position][0] in ("word", "identifier"):
+                parts.append(_name_part(tokens[position][1]))
+                position += 1
+            else:
+                parts.append("")
+        yield start, parts
+
+
+class StatementPolicy:
+    """
+    A statement policy of allowed and denied statements, for
+    Connection.statement_policy.
+
+    A statement is blocked when it contains one of deny_statements, matches one
+    of deny, matches none of allow (when given) or, with deny_cross_database,
+    refers to a database not in databases. Statement words and names are found
+    outside comments, string literals and quoted identifiers; the regular
+    expressions are searched, case-insensitively, in the statement without its
+    comments.
+
+    This is a guardrail, not a security boundary: the text of dynamic SQL and
+    what stored procedures and views run are not inspected, and code with access
+    to the connection can replace its policy. Permissions on the server are the
+    sandbox.
+
+    Example:
+        conn.statement_policy = StatementPolicy(
+            deny_statements=["DROP", "TRUNCATE", "ALTER DATABASE"],
+            deny_cross_database=True,
+            databases=["sales", "tempdb"],
+        )
+    """
+
+    def __init__(
+        self,
+        deny_statements: Iterable[str] = (),
+        deny: Sequence[Union[str, Pattern[str]]] = (),
+        allow: Sequence[Union[str, Pattern[str]]] = (),
+        deny_cross_database: bool = False,
+        databases: Iterable[str] = (),
+    ) -> None:
+        """
+        Args:
+            deny_statements: Statement words, or phrases of words such as
+                "ALTER DATABASE", that block a statement wherever they appear.
+            deny: Regular expressions blocking the statements they match.
+            allow: Regular expressions of which a statement must match one.
+            deny_cross_database: Block three- and four-part names of other
+                databases (such as other_db.dbo.t and server.db.dbo.t), USE of
+                other databases and OPENQUERY, OPENROWSET and OPENDATASOURCE.
+            databases: Databases that may still be referred to with
+                deny_cross_database, e.g. the connection's own database.
+        """
+        self.deny_statements: List[Tuple[str, ...]] = [
+            tuple(word.upper() for word in phrase.split()) for phrase in deny_statements
+        ]
+        if not all(self.deny_statements):
+            raise ValueError("deny_statements cannot contain empty phrases")
+        self.deny = [re.compile(pattern, re.IGNORECASE) for pattern in deny]
+        self.allow = [re.compile(pattern, re.IGNORECASE) for pattern in allow]
+        self.deny_cross_database = bool(deny_cross_database)
+        self.databases = {database.lower() for database in databases}
+
+    def __call__(self, sql: str) -> None:
+        self.check(sql)
+
+    def check(self, sql: Union[str, bytes]) -> None:
+        """
+        Raise unless the policy allows sql.
+
+        Raises:
+            ProgrammingError: If the policy blocks sql, naming the reason.
+        """
+        if isinstance(sql, bytes):
+            sql = sql.decode("latin-1")
+        tokens = _tokens(sql)
+        words = [text.upper() if kind == "word" else "" for kind, text in tokens]
+        for phrase in self.deny_statements:
+            for position in range(len(words) - len(phrase) + 1):
+                if tuple(words[position : position + len(phrase)]) == phrase:
+                    _block(f"{' '.join(phrase)} statements are not allowed")
+        text = "".join(
+            " " if match.lastgroup == "comment" else match.group()
+            for match in _LITERAL_TOKEN_RE.finditer(sql)
+        )
+        for pattern in self.deny:
+            if pattern.search(text):
+                _block(f"Statements matching {pattern.pattern!r} are not allowed")
+        if self.allow and not any(pattern.search(text) for pattern in self.allow):
+            _block("The statement matches none of the allowed patterns")
+        if self.deny_cross_database:
+            reference = self._cross_database_reference(tokens, words)
+            if reference is not None:
+                _block(f"{reference} is not allowed")
+
+    def _cross_database_reference(
+        self, tokens: List[Tuple[str, str]], words: List[str]
+    ) -> Optional[str]:
+        """Describe the first reference to another database or server, if any."""
+        for position, word in enumerate(words):
+            if word in _REMOTE_FUNCTIONS:
+                return word
+            if word == "USE" and position + 1 < len(tokens):
+                database = _name_part(tokens[position + 1][1])
+                if database not in self.databases:
+                    return f"USE of database {database!r}"
+        for position, parts in _dotted_names(tokens):
+            if position > 0 and words[position - 1] in _OBJECT_POSITIONS:
+                # [server.]database.schema.object
+                if len(parts) >= 4:
+                    return f"Reference to linked server {parts[-4]!r}"
+                database = parts[0] if len(parts) == 3 else ""
+            elif len(parts) >= 4 or (len(parts) == 3 and not parts[1]):
+                # database.schema.table.column or database..object; other three-part
+                # names may be schema.table.column
+                database = parts[-4] if len(parts) >= 4 else parts[0]
+            else:
+                continue
+            if database and database not in self.databases:
+                return f"Reference to database {database!r}"
+        return None
+
+
+def check_statement_policy(policy: PolicyCallback, sql: Union[str, bytes]) -> None:
+    """
+    Run a statement policy on sql.
+
+    Raises:
+        ProgrammingError: If the policy returns False; exceptions the policy
+            raises propagate as they are.
+    """
+    if isinstance(sql, bytes):
+        sql = sql.decode("latin-1")
+    if policy(sql) is False:
+        _block("The statement was blocked by the connection's statement policy")
+
+
+def _block(reason: str) -> None:
+    raise ProgrammingError(
+        driver_error=f"Statement blocked by policy: {reason}",
+        ddbc_error="The statement was not sent, see Connection.statement_policy",
+    )
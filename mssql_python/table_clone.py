@@ -0,0 +1,224 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module implements Connection.clone_table(): a copy of a table's structure
+(columns, types, nullability, collations, identity and computed columns),
+scripted from the catalog views, and optionally of its rows, copied server-side
+with INSERT ... SELECT or, from another connection, with bulk copy.
+"""
+
+from typing import TYPE_CHECKING, Any, List, Optional
+
+from mssql_python.exceptions import ProgrammingError
+from mssql_python.helpers import catalog_of, quote_identifier, quote_multipart_name
+from mssql_python.logging import logger
+from mssql_python.procedure import _declared_type
+
+if TYPE_CHECKING:
+    from mssql_python.connection import Connection
+    from mssql_python.cursor import Cursor
+
+_CLONE_COLUMNS_QUERY = (
+    "SELECT c.name, t.name, c.max_length, c.precision, c.scale, c.is_nullable, "
+    "c.collation_name, t.is_user_defined, SCHEMA_NAME(t.schema_id), c.is_identity, "
+    "CONVERT(VARCHAR(40), ic.seed_value), CONVERT(VARCHAR(40), ic.increment_value), "
+    "cc.definition, cc.is_persisted "
+    "FROM {catalog}sys.columns AS c "
+    "JOIN {catalog}sys.types AS t ON c.user_type_id = t.user_type_id "
+    "LEFT JOIN {catalog}sys.identity_columns AS ic "
+    "ON ic.object_id = c.object_id AND ic.column_id = c.column_id "
+    "LEFT JOIN {catalog}sys.computed_columns AS cc "
+    "ON cc.object_id = c.object_id AND cc.column_id = c.column_id "
+    "WHERE c.object_id = OBJECT_ID(?) "
+    "ORDER BY c.column_id"
+)
+# Types whose values the server generates, so rows are copied without them
+_GENERATED_TYPES = ("timestamp", "rowversion")
+
+
+class CloneColumn:
+    """One column of the table to clone, as its definition in CREATE TABLE."""
+
+    def __init__(
+        self,
+        name: str,
+        sql_type: str,
+        is_nullable: bool = True,
+        collation: Optional[str] = None,
+        identity: Optional[str] = None,
+        computed: Optional[str] = None,
+    ) -> None:
+        self.name = name
+        self.sql_type = sql_type
+        self.is_nullable = is_nullable
+        self.collation = collation
+        # "IDENTITY(seed, increment)" / "AS (expression) [PERSISTED]"
+        self.identity = identity
+        self.computed = computed
+
+    @property
+    def is_copied(self) -> bool:
+        """True if the column's values are copied with the rows."""
+        return self.computed is None and self.sql_type.lower() not in _GENERATED_TYPES
+
+    def definition(self) -> str:
+        """Return the column definition, e.g. "[id] int IDENTITY(1, 1) NOT NULL"."""
+        if self.computed is not None:
+            return f"{quote_identifier(self.name)} {self.computed}"
+        parts = [quote_identifier(self.name), self.sql_type]
+        if self.collation:
+            parts.append(f"COLLATE {self.collation}")
+        if self.identity:
+            parts.append(self.identity)
+        parts.append("NULL" if self.is_nullable else "NOT NULL")
+        return " ".join(parts)
+
+    def __repr__(self) -> str:
+        return f"CloneColumn({self.definition()!r})"
+
+
+def clone_columns(cursor: "Cursor", table_name: str) -> List[CloneColumn]:
+    """
+    Return the columns of a table, in order, for cloning it.
+
+    Raises:
+        ProgrammingError: If the table does not exist.
+    """
+    catalog, object_name = catalog_of(table_name)
+    cursor.execute(_CLONE_COLUMNS_QUERY.format(catalog=catalog), object_name)
+    columns = []
+    for row in cursor.fetchall():
+        name, type_name, max_length, precision, scale, is_nullable, collation = row[:7]
+        is_user_defined, type_schema, is_identity, seed, increment, computed, persisted = row[7:]
+        if is_user_defined:
+            sql_type = f"{quote_identifier(type_schema)}.{quote_identifier(type_name)}"
+        else:
+            sql_type = _declared_type(type_name, max_length, precision, scale)
+        if computed is not None:
+            computed = f"AS {computed}" + (" PERSISTED" if persisted else "")
+        columns.append(
+            CloneColumn(
+                name,
+                sql_type,
+                bool(is_nullable),
+                collation,
+                f"IDENTITY({seed}, {increment})" if is_identity else None,
+                computed,
+            )
+        )
+    if not columns:
+        raise ProgrammingError(
+            driver_error=f"Table '{table_name}' was not found",
+            ddbc_error="Cannot clone a table without columns in the catalog",
+        )
+    return columns
+
+
+def clone_statements(
+    source: str, dest: str, columns: List[CloneColumn], with_data: bool = True, replace: bool = True
+) -> List[str]:
+    """Return the statements that (re)create dest with columns and copy the rows of source."""
+    quoted_dest = quote_multipart_name(dest)
+    statements = []
+    if replace:
+        statements.append(f"DROP TABLE IF EXISTS {quoted_dest}")
+    definitions = ",\n    ".join(column.definition() for column in columns)
+    statements.append(f"CREATE TABLE {quoted_dest} (\n    {definitions}\n)")
+    if with_data:
+        column_list = ", ".join(quote_identifier(c.name) for c in columns if c.is_copied)
+        copy = (
+            f"INSERT INTO {quoted_dest} WITH (TABLOCK) ({column_list}) "
+            f"SELECT {column_list} FROM {quote_multipart_name(source)}"
+        )
+        if any(column.identity for column in columns):
+            statements.append(f"SET IDENTITY_INSERT {quoted_dest} ON")
+            statements.extend([copy, f"SET IDENTITY_INSERT {quoted_dest} OFF"])
+        else:
+            statements.append(copy)
+    return statements
+
+
+def clone_table(
+    connection: "Connection",
+    source: str,
+    dest: str,
+    with_data: bool = True,
+    replace: bool = True,
+    source_connection: Optional["Connection"] = None,
+) -> int:
+    """
+    Create dest with the structure of source and, with with_data, copy its rows.
+
+    On one connection everything runs in a single transaction: dropping an
+    existing dest (with replace), creating it and copying the rows server-side,
+    so a failed clone leaves dest as it was and running it again gives the same
+    snapshot. Work already pending on the connection is committed with it.
+    With source_connection, source is read there and its rows are
+    bulk-copied into dest; dest is then created and committed before the copy.
+
+    Indexes, constraints, triggers and permissions are not cloned.
+
+    Returns:
+        int: The number of rows copied.
+
+    Raises:
+        ProgrammingError: If source does not exist, or dest exists without replace.
+    """
+    reader = (source_connection or connection).cursor()
+    cursor = connection.cursor()
+    restore_autocommit = connection.autocommit
+    try:
+        columns = clone_columns(reader, source)
+        if restore_autocommit:
+            connection.autocommit = False
+        local_copy = with_data and source_connection is None
+        rows = 0
+        try:
+            for statement in clone_statements(source, dest, columns, local_copy, replace):
+                cursor.execute(statement)
+                if statement.startswith("INSERT"):
+                    rows = max(cursor.rowcount, 0)
+            connection.commit()
+        except BaseException:
+            try:
+                connection.rollback()
+            except Exception:  # pylint: disable=broad-exception-caught
+                logger.warning("clone_table: Rollback after a failed clone failed", exc_info=True)
+            raise
+        if with_data and source_connection is not None:
+            rows = _bulk_copy_rows(reader, cursor, source, dest, columns)
+        logger.info("clone_table: Cloned %s into %s with %d rows", source, dest, rows)
+        return rows
+    finally:
+        if restore_autocommit:
+            connection.autocommit = True
+        reader.close()
+        cursor.close()
+
+
+def _bulk_copy_rows(
+    reader: "Cursor", cursor: "Cursor", source: str, dest: str, columns: List[CloneColumn]
+) -> int:
+    """Stream the rows of source from reader's connection into dest with bulk copy."""
+    copied = [column.name for column in columns if column.is_copied]
+    reader.execute(
+        f"SELECT {', '.join(quote_identifier(name) for name in copied)} "
+        f"FROM {quote_multipart_name(source)}"
+    )
+
+    def rows() -> Any:
+        while True:
+            batch = reader.fetchmany(10000)
+            if not batch:
+                return
+            yield from batch
+
+    result = cursor.bulkcopy(
+        dest,
+        rows(),
+        column_mappings=copied,
+        keep_identity=any(column.identity for column in columns),
+        keep_nulls=True,
+        table_lock=True,
+    )
+    return int(result.get("rows_copied", 0)) if isinstance(result, dict) else 0
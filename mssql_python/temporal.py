@@ -0,0 +1,199 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module provides datetime and time subclasses that keep the 100-nanosecond
+precision of DATETIME2(7), DATETIMEOFFSET(7) and TIME(7), which Python's
+microsecond-based types truncate. They are returned with
+mssql_python.temporal_precision = "nanoseconds" and bound with full precision
+as parameters.
+"""
+
+import datetime
+from typing import Any, Optional
+
+TEMPORAL_PRECISIONS = ("microseconds", "nanoseconds")
+
+
+def _check_nanosecond(nanosecond: int) -> int:
+    if not isinstance(nanosecond, int) or not 0 <= nanosecond <= 999:
+        raise ValueError(f"nanosecond must be an int in 0..999, got {nanosecond!r}")
+    return nanosecond
+
+
+def _with_nanoseconds(iso: str, nanosecond: int) -> str:
+    """Extend the microseconds of an isoformat() string, before any UTC offset, to 9 digits."""
+    dot = iso.rindex(".")
+    end = dot + 7
+    return f"{iso[:end]}{nanosecond:03d}{iso[end:]}"
+
+
+class PreciseDateTime(datetime.datetime):
+    """
+    A datetime with nanoseconds beyond its microsecond, e.g. a DATETIME2(7) value.
+
+    nanosecond (0-999) holds the digits below the microsecond; fraction is the
+    whole fraction of the second in nanoseconds. Comparisons, hashing and
+    arithmetic are those of datetime, at microsecond precision; results of
+    arithmetic and replace() have no nanoseconds.
+    """
+
+    # pylint: disable=too-many-arguments,too-many-positional-arguments
+    def __new__(
+        cls,
+        year: int,
+        month: int,
+        day: int,
+        hour: int = 0,
+        minute: int = 0,
+        second: int = 0,
+        microsecond: int = 0,
+        tzinfo: Optional[datetime.tzinfo] = None,
+        *,
+        fold: int = 0,
+        nanosecond: int = 0,
+    ) -> "PreciseDateTime":
+        self = super().__new__(
+            cls, year, month, day, hour, minute, second, microsecond, tzinfo, fold=fold
+        )
+        self._nanosecond = _check_nanosecond(nanosecond)
+        return self
+
+    @classmethod
+    def from_datetime(cls, value: datetime.datetime, nanosecond: int = 0) -> "PreciseDateTime":
+        """Return value with nanosecond digits below its microsecond."""
+        return cls(
+            value.year,
+            value.month,
+            value.day,
+            value.hour,
+            value.minute,
+            value.second,
+            value.microsecond,
+            value.tzinfo,
+            fold=value.fold,
+            nanosecond=nanosecond,
+        )
+
+    @property
+    def nanosecond(self) -> int:
+        """Nanoseconds below the microsecond, 0-999."""
+        return self._nanosecond
+
+    @property
+    def fraction(self) -> int:
+        """The fraction of the second in nanoseconds."""
+        return self.microsecond * 1000 + self._nanosecond
+
+    def isoformat(self, sep: str = "T", timespec: str = "auto") -> str:
+        """As datetime.isoformat(), with 9 fractional digits for timespec "nanoseconds"
+        or, with "auto", when nanosecond is set."""
+        if timespec == "nanoseconds" or (timespec == "auto" and self._nanosecond):
+            iso = super().isoformat(sep, "microseconds")
+            return _with_nanoseconds(iso, self._nanosecond)
+        return super().isoformat(sep, timespec)
+
+    def __str__(self) -> str:
+        return self.isoformat(" ")
+
+    def __repr__(self) -> str:
+        base = repr(self.to_datetime())
+        return f"{type(self).__name__}{base[base.index('('):-1]}, nanosecond={self._nanosecond})"
+
+    def __reduce_ex__(self, protocol: Any) -> Any:
+        return (type(self).from_datetime, (self.to_datetime(), self._nanosecond))
+
+    def to_datetime(self) -> datetime.datetime:
+        """Return the value as a plain datetime, truncated to microseconds."""
+        return datetime.datetime(
+            self.year,
+            self.month,
+            self.day,
+            self.hour,
+            self.minute,
+            self.second,
+            self.microsecond,
+            self.tzinfo,
+            fold=self.fold,
+        )
+
+
+class PreciseTime(datetime.time):
+    """
+    A time with nanoseconds beyond its microsecond, e.g. a TIME(7) value.
+
+    nanosecond and fraction are as for PreciseDateTime.
+    """
+
+    # pylint: disable=too-many-arguments,too-many-positional-arguments
+    def __new__(
+        cls,
+        hour: int = 0,
+        minute: int = 0,
+        second: int = 0,
+        microsecond: int = 0,
+        tzinfo: Optional[datetime.tzinfo] = None,
+        *,
+        fold: int = 0,
+        nanosecond: int = 0,
+    ) -> "PreciseTime":
+        self = super().__new__(cls, hour, minute, second, microsecond, tzinfo, fold=fold)
+        self._nanosecond = _check_nanosecond(nanosecond)
+        return self
+
+    @classmethod
+    def from_time(cls, value: datetime.time, nanosecond: int = 0) -> "PreciseTime":
+        """Return value with nanosecond digits below its microsecond."""
+        return cls(
+            value.hour,
+            value.minute,
+            value.second,
+            value.microsecond,
+            value.tzinfo,
+            fold=value.fold,
+            nanosecond=nanosecond,
+        )
+
+    @property
+    def nanosecond(self) -> int:
+        """Nanoseconds below the microsecond, 0-999."""
+        return self._nanosecond
+
+    @property
+    def fraction(self) -> int:
+        """The fraction of the second in nanoseconds."""
+        return self.microsecond * 1000 + self._nanosecond
+
+    def isoformat(self, timespec: str = "auto") -> str:
+        """As time.isoformat(), with 9 fractional digits for timespec "nanoseconds"
+        or, with "auto", when nanosecond is set."""
+        if timespec == "nanoseconds" or (timespec == "auto" and self._nanosecond):
+            return _with_nanoseconds(super().isoformat("microseconds"), self._nanosecond)
+        return super().isoformat(timespec)
+
+    def __str__(self) -> str:
+        return self.isoformat()
+
+    def __repr__(self) -> str:
+        base = repr(self.to_time())
+        return f"{type(self).__name__}{base[base.index('('):-1]}, nanosecond={self._nanosecond})"
+
+    def __reduce_ex__(self, protocol: Any) -> Any:
+        return (type(self).from_time, (self.to_time(), self._nanosecond))
+
+    def to_time(self) -> datetime.time:
+        """Return the value as a plain time, truncated to microseconds."""
+        return datetime.time(
+            self.hour, self.minute, self.second, self.microsecond, self.tzinfo, fold=self.fold
+        )
+
+
+def time_parameter_text(value: datetime.time) -> str:
+    """
+    Return a time parameter as the text it is bound as, with the 7 fractional
+    digits of TIME(7) for a PreciseTime.
+    """
+    if isinstance(value, PreciseTime):
+        iso = value.isoformat("nanoseconds")
+        dot = iso.rindex(".")
+        return iso[: dot + 8] + iso[dot + 10 :]
+    return value.isoformat(timespec="microseconds")
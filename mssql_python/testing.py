@@ -0,0 +1,320 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module provides ephemeral databases for integration tests: a uniquely named
+database created for a test (or a database reset from a snapshot), connections and
+pools bound to it, and teardown that also catches up on databases left behind by
+test runs that crashed.
+"""
+
+import atexit
+import hashlib
+import os
+import re
+import socket
+import threading
+import uuid
+import weakref
+from typing import TYPE_CHECKING, Any, Dict, List, Optional
+
+from mssql_python.exceptions import ProgrammingError
+from mssql_python.helpers import quote_identifier
+from mssql_python.logging import logger
+from mssql_python.routing import RoutingPool
+from mssql_python.snapshots import _source_database, list_snapshots, revert_to_snapshot
+
+if TYPE_CHECKING:
+    from mssql_python.connection import Connection
+
+EPHEMERAL_DATABASE_PREFIX = "mssql_python_test_"
+# Seconds after which a leftover ephemeral database counts as orphaned even if
+# its owner cannot be checked (another host, or Windows)
+ORPHAN_MAX_AGE = 24 * 3600.0
+
+_PREFIX_RE = re.compile(r"^[A-Za-z_][A-Za-z0-9_]{0,63}$")
+_EPHEMERAL_QUERY = (
+    "SELECT name, DATEDIFF(second, create_date, GETDATE()) FROM sys.databases "
+    "WHERE source_database_id IS NULL AND name LIKE ? ESCAPE '\\'"
+)
+
+# Ephemeral databases of this process that are not torn down yet, by name
+_live: Dict[str, "EphemeralDatabase"] = {}
+_live_lock = threading.Lock()
+
+
+def _host_tag() -> str:
+    return hashlib.sha256(socket.gethostname().encode("utf-8")).hexdigest()[:8]
+
+
+def _name_re(prefix: str) -> "re.Pattern[str]":
+    return re.compile(rf"^{re.escape(prefix)}([0-9a-f]{{8}})_([0-9]+)_[0-9a-f]{{8}}$")
+
+
+def _validate_prefix(prefix: str) -> str:
+    if not isinstance(prefix, str) or not _PREFIX_RE.match(prefix):
+        raise ValueError(f"Invalid ephemeral database prefix: {prefix!r}")
+    return prefix
+
+
+def ephemeral_database_name(prefix: str = EPHEMERAL_DATABASE_PREFIX) -> str:
+    """
+    Return a new unique database name: <prefix><host>_<pid>_<random>.
+
+    The host and process id of the creator are part of the name, so that leftover
+    databases of crashed processes can be recognized (see find_orphaned_databases).
+    """
+    return f"{_validate_prefix(prefix)}{_host_tag()}_{os.getpid()}_{uuid.uuid4().hex[:8]}"
+
+
+def _process_alive(pid: int) -> bool:
+    if pid == os.getpid():
+        return True
+    if os.name == "nt":
+        # os.kill() terminates processes on Windows; there the age decides
+        return True
+    try:
+        os.kill(pid, 0)
+    except ProcessLookupError:
+        return False
+    except PermissionError:
+        return True
+    return True
+
+
+def find_orphaned_databases(
+    connection: "Connection",
+    prefix: str = EPHEMERAL_DATABASE_PREFIX,
+    max_age: float = ORPHAN_MAX_AGE,
+) -> List[str]:
+    """
+    Return the ephemeral databases whose creator is gone.
+
+    A database is orphaned when the process that created it on this host no
+    longer runs, or when it is older than max_age seconds. Databases of this
+    process that are still in use are never returned.
+    """
+    pattern = _name_re(_validate_prefix(prefix))
+    cursor = connection.cursor()
+    try:
+        rows = cursor.execute(_EPHEMERAL_QUERY, prefix.replace("_", "\\_") + "%").fetchall()
+    finally:
+        cursor.close()
+    host = _host_tag()
+    with _live_lock:
+        live = set(_live.keys())
+    orphans = []
+    for name, age in (tuple(row) for row in rows):
+        match = pattern.match(name)
+        if match is None or name in live:
+            continue
+        dead = match.group(1) == host and not _process_alive(int(match.group(2)))
+        if dead or (age is not None and age >= max_age):
+            orphans.append(name)
+    return orphans
+
+
+def _run(connection: "Connection", sql: str) -> None:
+    logger.info("testing: %s", logger.redaction.sql(sql))
+    cursor = connection.cursor()
+    try:
+        cursor.execute(sql, use_prepare=False)
+    finally:
+        cursor.close()
+
+
+def _drop_database(connection: "Connection", name: str) -> None:
+    """Drop a database with its snapshots, disconnecting the sessions using it."""
+    for entry in list_snapshots(connection, name):
+        _run(connection, f"DROP DATABASE {quote_identifier(entry['name'])}")
+    quoted = quote_identifier(name)
+    try:
+        _run(connection, f"ALTER DATABASE {quoted} SET SINGLE_USER WITH ROLLBACK IMMEDIATE")
+    except Exception as e:  # pylint: disable=broad-exception-caught
+        # e.g. Azure SQL Database, which has no SINGLE_USER
+        logger.debug("testing: Could not disconnect the sessions of %s: %s", name, e)
+    _run(connection, f"DROP DATABASE {quoted}")
+
+
+def drop_orphaned_databases(
+    connection: "Connection",
+    prefix: str = EPHEMERAL_DATABASE_PREFIX,
+    max_age: float = ORPHAN_MAX_AGE,
+) -> List[str]:
+    """
+    Drop the ephemeral databases find_orphaned_databases() reports, and return
+    the names of those dropped. Databases that cannot be dropped are logged and
+    skipped. Needs autocommit.
+    """
+    dropped = []
+    for name in find_orphaned_databases(connection, prefix, max_age):
+        try:
+            _drop_database(connection, name)
+        except Exception as e:  # pylint: disable=broad-exception-caught
+            logger.warning("testing: Could not drop orphaned database %s: %s", name, e)
+        else:
+            dropped.append(name)
+    return dropped
+
+
+class EphemeralDatabase:
+    """
+    A database that exists for the duration of a test.
+
+    By default a new, uniquely named database is created and dropped on close().
+    With snapshot, the snapshot's source database is reverted to it instead, both
+    when the database is set up and when it is torn down, so every test starts
+    from the snapshot's contents. Teardown disconnects all sessions using the
+    database, including pooled ones. Databases left behind by processes that
+    are killed are dropped by later runs (drop_orphans), and those of processes
+    exiting without close() are dropped at interpreter exit.
+
+    Creating and dropping databases needs CREATE DATABASE and ALTER ANY DATABASE
+    permission; this is done on a separate autocommit session opened from the
+    connection.
+
+    Attributes:
+        name: Name of the database, set once it is created.
+        snapshot: Snapshot the database is reset from, or None.
+
+    Example:
+        @pytest.fixture
+        def database(conn_str):
+            admin = mssql_python.connect(conn_str)
+            try:
+                with EphemeralDatabase(admin) as db:
+                    yield db
+            finally:
+                admin.close()
+
+        def test_orders(database):
+            conn = database.connect()
+            ...
+    """
+
+    def __init__(
+        self,
+        connection: "Connection",
+        snapshot: Optional[str] = None,
+        prefix: str = EPHEMERAL_DATABASE_PREFIX,
+        drop_orphans: bool = True,
+        max_orphan_age: float = ORPHAN_MAX_AGE,
+    ) -> None:
+        self.snapshot = snapshot
+        self.name: Optional[str] = None
+        self._prefix = _validate_prefix(prefix)
+        self._drop_orphans = drop_orphans
+        self._max_orphan_age = max_orphan_age
+        self._admin = connection._spawn_connection(autocommit=True)
+        self._connections: "weakref.WeakSet[Connection]" = weakref.WeakSet()
+        self._lock = threading.Lock()
+        self._closed = False
+
+    def create(self) -> "EphemeralDatabase":
+        """Create (or reset) the database; called by the with statement."""
+        with self._lock:
+            if self.name is not None:
+                return self
+            if self.snapshot is not None:
+                database = _source_database(self._admin, self.snapshot)
+                revert_to_snapshot(self._admin, self.snapshot)
+                self.name = database
+                return self
+            if self._drop_orphans:
+                for name in drop_orphaned_databases(
+                    self._admin, self._prefix, self._max_orphan_age
+                ):
+                    logger.info("testing: Dropped orphaned database %s", name)
+            name = ephemeral_database_name(self._prefix)
+            _run(self._admin, f"CREATE DATABASE {quote_identifier(name)}")
+            self.name = name
+            with _live_lock:
+                _live[name] = self
+            return self
+
+    def _require_database(self) -> str:
+        if self._closed or self.name is None:
+            raise ProgrammingError(
+                driver_error="Ephemeral database is not available",
+                ddbc_error="Call create() first; the database is dropped after close()",
+            )
+        return self.name
+
+    @property
+    def connection_string(self) -> str:
+        """
+        Connection string for the database, for code that connects itself.
+
+        Access tokens passed through attrs_before are not part of it.
+        """
+        return self._admin._spawn_connection_string(self._require_database())
+
+    def connect(self, autocommit: bool = False) -> "Connection":
+        """Open a connection to the database; it is closed on close() at the latest."""
+        conn = self._admin._spawn_connection(autocommit, database=self._require_database())
+        self._connections.add(conn)
+        return conn
+
+    def pool(self, **connect_kwargs: Any) -> RoutingPool:
+        """
+        Return a pool of connections to the database.
+
+        Args:
+            **connect_kwargs: Passed to mssql_python.connect() (autocommit,
+                attrs_before, ...).
+        """
+        return RoutingPool(self.connection_string, **connect_kwargs)
+
+    def close(self) -> None:
+        """
+        Tear the database down: drop it, or revert it to the snapshot again.
+
+        Connections opened with connect() are closed first; other sessions using
+        the database are disconnected and their transactions rolled back.
+        """
+        with self._lock:
+            if self._closed:
+                return
+            self._closed = True
+            try:
+                for conn in list(self._connections):
+                    if not conn.closed:
+                        conn.close()
+                if self.name is None:
+                    return
+                if self.snapshot is not None:
+                    revert_to_snapshot(self._admin, self.snapshot)
+                else:
+                    _drop_database(self._admin, self.name)
+            finally:
+                with _live_lock:
+                    if self.name is not None and _live.get(self.name) is self:
+                        del _live[self.name]
+                self._admin.close()
+
+    def __enter__(self) -> "EphemeralDatabase":
+        return self.create()
+
+    def __exit__(self, *args: Any) -> None:
+        self.close()
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Return the database's settings as a plain dictionary."""
+        return {"name": self.name, "snapshot": self.snapshot, "closed": self._closed}
+
+    def __repr__(self) -> str:
+        return (
+            f"EphemeralDatabase(name={self.name!r}, snapshot={self.snapshot!r}, "
+            f"closed={self._closed!r})"
+        )
+
+
+@atexit.register
+def _drop_live_databases() -> None:
+    """Tear down the ephemeral databases a process exits without closing."""
+    with _live_lock:
+        databases = list(_live.values())
+    for database in databases:
+        try:
+            database.close()
+        except Exception as e:  # pylint: disable=broad-exception-caught
+            logger.warning("testing: Could not drop %s at exit: %s", database.name, e)
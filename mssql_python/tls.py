@@ -0,0 +1,241 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module implements server certificate pinning (ServerCertificateHash): the
+server's TLS certificate is fetched with a TDS pre-login handshake and compared
+with the pinned SHA-256 fingerprints, and the matching certificate is handed to
+the ODBC driver (and to py-core for bulk copy) as ServerCertificate, which then
+only accepts a server presenting exactly that certificate.
+"""
+
+import atexit
+import hashlib
+import os
+import shutil
+import socket
+import ssl
+import struct
+import tempfile
+import threading
+from typing import Dict, List, Optional, Tuple
+
+from mssql_python.constants import _KEY_SERVER_CERTIFICATE_HASH
+from mssql_python.exceptions import CertificateTrustError
+from mssql_python.fips import restrict_tls_context
+from mssql_python.logging import logger
+from mssql_python.readiness import (
+    _PRELOGIN,
+    _PRELOGIN_ENCRYPTION,
+    _recv_exactly,
+    prelogin_options,
+    prelogin_packet,
+    server_endpoint,
+)
+
+# PRELOGIN encryption values (MS-TDS 2.2.6.5)
+_ENCRYPT_ON = 0x01
+_ENCRYPT_NOT_SUP = 0x02
+_TDS_HEADER = struct.Struct(">BBHHBB")
+_END_OF_MESSAGE = 0x01
+_MAX_PACKET_PAYLOAD = 4096 - _TDS_HEADER.size
+# Seconds a certificate fetch may take
+CERTIFICATE_FETCH_TIMEOUT = 10.0
+
+# PEM files of pinned certificates that matched, by (host, port)
+_pinned_files: Dict[Tuple[str, int], str] = {}
+_pinned_lock = threading.Lock()
+_pin_directory: Optional[str] = None
+
+
+def normalize_fingerprint(pin: str) -> str:
+    """Return a SHA-256 fingerprint as 64 uppercase hex digits ("sha256:" and ":" allowed)."""
+    value = pin.strip()
+    if value.lower().startswith("sha256:"):
+        value = value[len("sha256:") :]
+    value = value.replace(":", "").replace(" ", "").upper()
+    if len(value) != 64 or any(c not in "0123456789ABCDEF" for c in value):
+        raise ValueError(f"Invalid SHA-256 certificate fingerprint: {pin!r}")
+    return value
+
+
+def parse_certificate_pins(value: str) -> List[str]:
+    """Return the fingerprints of a comma-separated ServerCertificateHash value."""
+    pins = [normalize_fingerprint(pin) for pin in value.split(",") if pin.strip()]
+    if not pins:
+        raise ValueError("ServerCertificateHash needs at least one SHA-256 fingerprint")
+    return pins
+
+
+def certificate_fingerprint(certificate: bytes) -> str:
+    """Return the SHA-256 fingerprint of a DER certificate as 64 uppercase hex digits."""
+    return hashlib.sha256(certificate).hexdigest().upper()
+
+
+def _tls_context() -> ssl.SSLContext:
+    # Trust is decided by the pin, not by a CA chain or the host name
+    context = ssl.SSLContext(ssl.PROTOCOL_TLS_CLIENT)
+    context.check_hostname = False
+    context.verify_mode = ssl.CERT_NONE
+    return restrict_tls_context(context)
+
+
+def _send_tds(sock: socket.socket, packet_type: int, data: bytes) -> None:
+    chunks = [data[i : i + _MAX_PACKET_PAYLOAD] for i in range(0, len(data), _MAX_PACKET_PAYLOAD)]
+    for index, chunk in enumerate(chunks or [b""]):
+        status = _END_OF_MESSAGE if index == len(chunks) - 1 else 0
+        length = _TDS_HEADER.size + len(chunk)
+        sock.sendall(_TDS_HEADER.pack(packet_type, status, length, 0, index + 1, 0) + chunk)
+
+
+def _recv_tds(sock: socket.socket) -> Tuple[bytes, bytes]:
+    """Return (first header, payload) of the next TDS message."""
+    first_header = None
+    payload = b""
+    while True:
+        header = _recv_exactly(sock, _TDS_HEADER.size)
+        first_header = first_header or header
+        length = struct.unpack(">H", header[2:4])[0]
+        payload += _recv_exactly(sock, max(length - _TDS_HEADER.size, 0))
+        if header[1] & _END_OF_MESSAGE:
+            return first_header, payload
+
+
+def _prelogin_encryption(sock: socket.socket) -> Optional[int]:
+    """Send a PRELOGIN offering encryption; return the server's ENCRYPTION value, if any."""
+    _send_tds(sock, _PRELOGIN, prelogin_packet(_ENCRYPT_ON)[_TDS_HEADER.size :])
+    header, payload = _recv_tds(sock)
+    encryption = prelogin_options(header + payload).get(_PRELOGIN_ENCRYPTION, b"")
+    return encryption[0] if encryption else None
+
+
+def _tls_in_tds(sock: socket.socket, host: str) -> bytes:
+    """Run the TLS handshake inside TDS PRELOGIN packets; return the server certificate."""
+    incoming, outgoing = ssl.MemoryBIO(), ssl.MemoryBIO()
+    tls = _tls_context().wrap_bio(incoming, outgoing, server_hostname=host)
+    while True:
+        try:
+            tls.do_handshake()
+            break
+        except ssl.SSLWantReadError:
+            pending = outgoing.read()
+            if pending:
+                _send_tds(sock, _PRELOGIN, pending)
+            incoming.write(_recv_tds(sock)[1])
+    return tls.getpeercert(binary_form=True)
+
+
+def _handshake_in_tds(sock: socket.socket, host: str) -> bytes:
+    """Run the pre-login and its TLS handshake; return the server certificate."""
+    if _prelogin_encryption(sock) == _ENCRYPT_NOT_SUP:
+        raise CertificateTrustError(
+            "Server does not support encryption",
+            f"{host} answered the pre-login with ENCRYPT_NOT_SUP",
+            "certificate pinning needs a server with TLS enabled",
+        )
+    return _tls_in_tds(sock, host)
+
+
+def fetch_server_certificate(
+    host: str, port: int, strict: bool = False, timeout: float = CERTIFICATE_FETCH_TIMEOUT
+) -> bytes:
+    """
+    Return the DER certificate a server presents, without validating it.
+
+    With strict (Encrypt=strict, TDS 8) the connection starts with TLS; otherwise
+    the handshake runs inside the TDS pre-login exchange.
+    """
+    with socket.create_connection((host, port), timeout=timeout) as sock:
+        sock.settimeout(timeout)
+        if strict:
+            context = _tls_context()
+            context.set_alpn_protocols(["tds/8.0"])
+            with context.wrap_socket(sock, server_hostname=host) as tls:
+                return tls.getpeercert(binary_form=True)
+        return _handshake_in_tds(sock, host)
+
+
+def _pin_file(fingerprint: str, certificate: bytes) -> str:
+    global _pin_directory  # pylint: disable=global-statement
+    if _pin_directory is None:
+        _pin_directory = tempfile.mkdtemp(prefix="mssql_python_pins_")
+        atexit.register(shutil.rmtree, _pin_directory, True)
+    path = os.path.join(_pin_directory, f"{fingerprint}.pem")
+    with open(path, "w", encoding="ascii") as pem:
+        pem.write(ssl.DER_cert_to_PEM_cert(certificate))
+    return path
+
+
+def pinned_certificate_file(
+    server: str, pins: List[str], strict: bool = False, timeout: float = CERTIFICATE_FETCH_TIMEOUT
+) -> str:
+    """
+    Return a PEM file with the certificate of server, after checking it against pins.
+
+    The file is remembered per server until forget_pinned_certificate().
+
+    Raises:
+        CertificateTrustError: If the server's certificate matches no pin, or
+            the server is not a TCP address whose certificate can be fetched.
+    """
+    endpoint = server_endpoint(server)
+    if endpoint is None:
+        raise CertificateTrustError(
+            "Certificate pinning needs a TCP server address",
+            f"Cannot fetch the certificate of {server}",
+            "use Server=host or host,port (named instances need their port)",
+        )
+    with _pinned_lock:
+        if endpoint in _pinned_files:
+            return _pinned_files[endpoint]
+    try:
+        certificate = fetch_server_certificate(endpoint[0], endpoint[1], strict, timeout)
+    except (OSError, ssl.SSLError, ValueError) as e:
+        raise CertificateTrustError(
+            "Could not fetch the server certificate",
+            f"{server}: {e}",
+            "check that the server is reachable and has TLS enabled",
+        ) from e
+    fingerprint = certificate_fingerprint(certificate)
+    if fingerprint not in pins:
+        raise CertificateTrustError(
+            "Server certificate does not match ServerCertificateHash",
+            f"{server} presented a certificate with SHA-256 fingerprint {fingerprint}",
+            f"if the certificate was renewed, add {fingerprint} to ServerCertificateHash",
+        )
+    logger.info("tls: Certificate of %s matches pin %s", server, fingerprint)
+    path = _pin_file(fingerprint, certificate)
+    with _pinned_lock:
+        _pinned_files[endpoint] = path
+    return path
+
+
+def forget_pinned_certificate(server: str) -> None:
+    """Drop the remembered certificate of server, e.g. after connecting failed."""
+    endpoint = server_endpoint(server)
+    with _pinned_lock:
+        _pinned_files.pop(endpoint, None)
+
+
+
+def pin_server_certificate(params: Dict[str, str], server: str) -> Dict[str, str]:
+    """
+    Return parsed connection string params for server with ServerCertificateHash
+    replaced by ServerCertificate=<file of the matching certificate>.
+
+    Raises:
+        ValueError: If the pins are malformed, encryption is optional, or
+            ServerCertificate is set as well.
+        CertificateTrustError: See pinned_certificate_file().
+    """
+    key = _KEY_SERVER_CERTIFICATE_HASH.lower()
+    if key not in params:
+        return params
+    pins = parse_certificate_pins(params[key])
+    encrypt = params.get("encrypt", "yes").strip().lower()
+    if encrypt in ("no", "false", "optional"):
+        raise ValueError("ServerCertificateHash needs Encrypt=yes or Encrypt=strict")
+    if "servercertificate" in params:
+        raise ValueError("ServerCertificateHash and ServerCertificate cannot both be set")
+    params = {name: value for name, value in params.items() if name != key}
+    params["servercertificate"] = pinned_certificate_file(server, pins, encrypt == "strict")
+    return params
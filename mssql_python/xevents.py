@@ -0,0 +1,306 @@
+"""
+Copyright (c) Microsoft Corporation.
+Licensed under the MIT license.
+This module consumes Extended Events sessions: it creates or attaches to a session
+with a ring_buffer target and turns the events the target holds into dicts by
+polling it on a dedicated connection.
+"""
+
+import datetime
+import hashlib
+import re
+import threading
+import xml.etree.ElementTree as ET
+from typing import TYPE_CHECKING, Any, Dict, Iterator, List, Optional, Sequence, Set
+
+from mssql_python.exceptions import ProgrammingError
+from mssql_python.logging import logger
+
+if TYPE_CHECKING:
+    from mssql_python.connection import Connection
+
+_SESSION_NAME_RE = re.compile(r"^[A-Za-z_][A-Za-z0-9_#$@]{0,127}$")
+# package.name, e.g. sqlserver.rpc_completed or sqlos.wait_info
+_QUALIFIED_NAME_RE = re.compile(r"^[A-Za-z_][A-Za-z0-9_]*\.[A-Za-z_][A-Za-z0-9_]*$")
+
+# Server sessions (SQL Server) and database sessions (Azure SQL Database)
+_SCOPES = {
+    "server": ("SERVER", "sys.dm_xe_sessions", "sys.dm_xe_session_targets"),
+    "database": (
+        "DATABASE",
+        "sys.dm_xe_database_sessions",
+        "sys.dm_xe_database_session_targets",
+    ),
+}
+_TARGET_QUERY = (
+    "SELECT CAST(t.target_data AS nvarchar(max)) FROM {sessions} AS s "
+    "JOIN {targets} AS t ON t.event_session_address = s.address "
+    "WHERE s.name = ? AND t.target_name = 'ring_buffer'"
+)
+
+
+def _validate_scope(scope: str) -> str:
+    if scope not in _SCOPES:
+        raise ValueError(f"scope must be one of {sorted(_SCOPES)}, got {scope!r}")
+    return scope
+
+
+def _validate_session_name(name: str) -> str:
+    if not isinstance(name, str) or not _SESSION_NAME_RE.match(name):
+        raise ValueError(f"Invalid event session name: {name!r}")
+    return name
+
+
+def _validate_qualified_names(names: Sequence[str], kind: str) -> List[str]:
+    if isinstance(names, str):
+        names = [names]
+    names = list(names)
+    for name in names:
+        if not isinstance(name, str) or not _QUALIFIED_NAME_RE.match(name):
+            raise ValueError(f"Invalid {kind} name (expected package.name): {name!r}")
+    return names
+
+
+def create_session_sql(
+    name: str,
+    events: Sequence[str],
+    actions: Sequence[str] = (),
+    max_memory_kb: int = 4096,
+    scope: str = "server",
+) -> str:
+    """Return the CREATE EVENT SESSION statement for a ring_buffer session."""
+    on = _SCOPES[_validate_scope(scope)][0]
+    events = _validate_qualified_names(events, "event")
+    if not events:
+        raise ValueError("At least one event is required")
+    actions = _validate_qualified_names(actions, "action")
+    if isinstance(max_memory_kb, bool) or not isinstance(max_memory_kb, int) or max_memory_kb <= 0:
+        raise ValueError("max_memory_kb must be a positive integer")
+    action_clause = f" (ACTION ({', '.join(actions)}))" if actions else ""
+    event_clauses = ", ".join(f"ADD EVENT {event}{action_clause}" for event in events)
+    return (
+        f"CREATE EVENT SESSION [{_validate_session_name(name)}] ON {on} {event_clauses} "
+        f"ADD TARGET package0.ring_buffer (SET max_memory = {max_memory_kb}) "
+        "WITH (EVENT_RETENTION_MODE = ALLOW_SINGLE_EVENT_LOSS, MAX_DISPATCH_LATENCY = 1 SECONDS)"
+    )
+
+
+def _field_value(field: ET.Element) -> Any:
+    """Return a data or action field's value: its text, or its XML for XML fields."""
+    value = field.find("value")
+    if value is None:
+        return None
+    children = list(value)
+    if children:
+        # e.g. the <deadlock> graph of xml_deadlock_report
+        return "".join(ET.tostring(child, encoding="unicode") for child in children)
+    return value.text
+
+
+def _parse_timestamp(text: Optional[str]) -> Optional[datetime.datetime]:
+    if not text:
+        return None
+    try:
+        return datetime.datetime.fromisoformat(text.replace("Z", "+00:00"))
+    except ValueError:
+        return None
+
+
+def parse_ring_buffer(target_data: str) -> List[Dict[str, Any]]:
+    """
+    Parse the XML of a ring_buffer target into event dicts, oldest first.
+
+    Each event has "name", "timestamp" (an aware UTC datetime, or None), "data"
+    and "actions" (field name to value; XML-typed fields such as a deadlock
+    graph are returned as XML strings).
+    """
+    if not target_data:
+        return []
+    root = ET.fromstring(target_data)
+    events = []
+    for element in root.iter("event"):
+        events.append(
+            {
+                "name": element.get("name"),
+                "timestamp": _parse_timestamp(element.get("timestamp")),
+                "data": {f.get("name"): _field_value(f) for f in element.findall("data")},
+                "actions": {f.get("name"): _field_value(f) for f in element.findall("action")},
+            }
+        )
+    return events
+
+
+class XEventSession:
+    """
+    An Extended Events session with a ring_buffer target, read as Python dicts.
+
+    The session is polled on a dedicated autocommit connection opened from the
+    connection it was created with. Each read() returns the events added to the
+    ring buffer since the previous read; events the buffer drops between reads
+    (because it is full) are not seen. Reading the target needs VIEW SERVER STATE
+    (or VIEW DATABASE STATE for database-scoped sessions).
+
+    Attributes:
+        name: Name of the event session.
+        scope: "server" for SQL Server, "database" for Azure SQL Database.
+        owned: Whether close() drops the session, for sessions created without
+            keep=True.
+    """
+
+    def __init__(
+        self, connection: "Connection", name: str, scope: str = "server", owned: bool = False
+    ) -> None:
+        self.name = _validate_session_name(name)
+        self.scope = _validate_scope(scope)
+        self.owned = owned
+        self._connection = connection._spawn_connection(autocommit=True)
+        self._lock = threading.Lock()
+        self._last_timestamp: Optional[datetime.datetime] = None
+        # Digests of the returned events stamped _last_timestamp
+        self._seen_at_last: Set[str] = set()
+        self._closed = False
+
+    @classmethod
+    def create(
+        cls,
+        connection: "Connection",
+        name: str,
+        events: Sequence[str],
+        actions: Sequence[str] = (),
+        max_memory_kb: int = 4096,
+        scope: str = "server",
+        keep: bool = False,
+    ) -> "XEventSession":
+        """Create and start a session; see Connection.create_xevent_session."""
+        sql = create_session_sql(name, events, actions, max_memory_kb, scope)
+        session = cls(connection, name, scope, owned=not keep)
+        on = _SCOPES[scope][0]
+        try:
+            cursor = session._connection.cursor()
+            try:
+                cursor.execute(sql, use_prepare=False)
+                try:
+                    cursor.execute(
+                        f"ALTER EVENT SESSION [{name}] ON {on} STATE = START", use_prepare=False
+                    )
+                except Exception:
+                    # Do not leave the created, never started session behind
+                    try:
+                        cursor.execute(f"DROP EVENT SESSION [{name}] ON {on}", use_prepare=False)
+                    except Exception as e:  # pylint: disable=broad-exception-caught
+                        logger.warning("XEventSession: Could not drop %s: %s", name, e)
+                    raise
+            finally:
+                cursor.close()
+        except Exception:
+            session._connection.close()
+            raise
+        return session
+
+    def _target_data(self) -> Optional[str]:
+        _, sessions, targets = _SCOPES[self.scope]
+        cursor = self._connection.cursor()
+        try:
+            rows = cursor.execute(
+                _TARGET_QUERY.format(sessions=sessions, targets=targets), self.name
+            ).fetchall()
+        finally:
+            cursor.close()
+        return rows[0][0] if rows else None
+
+    def read(self) -> List[Dict[str, Any]]:
+        """
+        Return the events added to the ring buffer since the previous read.
+
+        Raises:
+            ProgrammingError: If the session is closed, or is not running with a
+                ring_buffer target.
+        """
+        with self._lock:
+            if self._closed:
+                raise ProgrammingError(
+                    driver_error="Event session is closed",
+                    ddbc_error="Event session is closed",
+                )
+            target_data = self._target_data()
+            if target_data is None:
+                raise ProgrammingError(
+                    driver_error=f"Event session {self.name!r} is not running",
+                    ddbc_error="No started session with a ring_buffer target has this name",
+                )
+            return self._new_events(target_data)
+
+    def _new_events(self, target_data: str) -> List[Dict[str, Any]]:
+        """Drop the events of target_data returned by earlier reads."""
+        events = [
+            (event, hashlib.sha256(repr(event).encode("utf-8")).hexdigest())
+            for event in parse_ring_buffer(target_data)
+        ]
+        last = self._last_timestamp
+        new_events = [
+            event
+            for event, digest in events
+            if last is None
+            or event["timestamp"] is None
+            or event["timestamp"] > last
+            or (event["timestamp"] == last and digest not in self._seen_at_last)
+        ]
+        timestamps = [event["timestamp"] for event, _ in events if event["timestamp"] is not None]
+        if timestamps:
+            self._last_timestamp = max(timestamps)
+            self._seen_at_last = {
+                digest for event, digest in events if event["timestamp"] == self._last_timestamp
+            }
+        return new_events
+
+    def stream(
+        self, poll_interval: float = 1.0, stop: Optional[threading.Event] = None
+    ) -> Iterator[Dict[str, Any]]:
+        """
+        Yield events as they arrive, polling every poll_interval seconds.
+
+        Runs until the stop event is set (checked between polls), or until the
+        caller stops iterating.
+        """
+        if isinstance(poll_interval, bool) or not isinstance(poll_interval, (int, float)):
+            raise ValueError("poll_interval must be a positive number of seconds")
+        if poll_interval <= 0:
+            raise ValueError("poll_interval must be a positive number of seconds")
+        stop = stop or threading.Event()
+        while not stop.is_set():
+            yield from self.read()
+            stop.wait(poll_interval)
+
+    def close(self) -> None:
+        """Stop reading; a session created here without keep=True is dropped."""
+        with self._lock:
+            if self._closed:
+                return
+            self._closed = True
+            try:
+                if self.owned:
+                    cursor = self._connection.cursor()
+                    try:
+                        cursor.execute(
+                            f"DROP EVENT SESSION [{self.name}] ON {_SCOPES[self.scope][0]}",
+                            use_prepare=False,
+                        )
+                    except Exception as e:  # pylint: disable=broad-exception-caught
+                        logger.warning("XEventSession: Could not drop %s: %s", self.name, e)
+                    finally:
+                        cursor.close()
+            finally:
+                self._connection.close()
+
+    def __enter__(self) -> "XEventSession":
+        return self
+
+    def __exit__(self, *args: Any) -> None:
+        self.close()
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Return the session's settings as a plain dictionary."""
+        return {"name": self.name, "scope": self.scope, "owned": self.owned}
+
+    def __repr__(self) -> str:
+        return f"XEventSession(name={self.name!r}, scope={self.scope!r}, owned={self.owned!r})"
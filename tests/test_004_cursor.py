@@ -14661,12 +14661,10 @@ def test_tables_error_handling(cursor):
         pass
 
 
-def test_callproc_not_supported_error(cursor):
-    """Test callproc NotSupportedError (Lines 2413-2421)."""
-
-    # This should always raise NotSupportedError (lines 2417-2420)
-    with pytest.raises(mssql_python.NotSupportedError, match="callproc.*is not yet implemented"):
-        cursor.callproc("test_proc")
+def test_callproc_missing_procedure(cursor):
+    """Test callproc of a procedure that does not exist raises a database error."""
+    with pytest.raises(mssql_python.DatabaseError, match="test_proc_that_does_not_exist"):
+        cursor.callproc("test_proc_that_does_not_exist")
 
 
 def test_setoutputsize_no_op(cursor):
@@ -16910,14 +16908,20 @@ def test_map_sql_type_decimal_outside_money_returns_numeric():
     assert isinstance(format(val, "f"), str)
 
 
-def test_map_sql_type_decimal_in_money_returns_varchar():
-    """_map_sql_type returns SQL_VARCHAR for Decimal within MONEY range."""
+def test_map_sql_type_decimal_in_money_returns_numeric(monkeypatch):
+    """_map_sql_type binds a Decimal within MONEY range as SQL_NUMERIC_STRUCT too."""
+    import types
+    from mssql_python import ddbc_bindings
+
+    monkeypatch.setattr(ddbc_bindings, "NumericData", types.SimpleNamespace)
     cur = _make_bare_cursor()
     val = decimal.Decimal("100.50")
     dummy_row = [val]
-    sql_type, c_type, _, _, _ = cur._map_sql_type(val, dummy_row, 0)
-    assert sql_type == _C.SQL_VARCHAR.value
-    assert c_type == _C.SQL_C_CHAR.value
+    sql_type, c_type, precision, scale, _ = cur._map_sql_type(val, dummy_row, 0)
+    assert sql_type == _C.SQL_NUMERIC.value
+    assert c_type == _C.SQL_C_NUMERIC.value
+    assert (precision, scale) == (5, 2)
+    assert dummy_row[0].val == (10050).to_bytes(16, "little")
 
 
 def test_executemany_numeric_override_needed():
@@ -16953,6 +16957,7 @@ def test_executemany_numeric_override_needed():
 
 def test_executemany_decimal_numeric_override_coverage(monkeypatch):
     """Call the real executemany method to cover the GH-609 override lines."""
+    import types
     from unittest.mock import MagicMock
     from mssql_python import ddbc_bindings
     from mssql_python.cursor import Cursor
@@ -16967,6 +16972,10 @@ def test_executemany_decimal_numeric_override_coverage(monkeypatch):
     cur._connection = MagicMock()
     cur._connection._encoding = "utf-8"
     cur._connection._conn = MagicMock()
+    cur._connection._packet_size = 4096
+    cur._connection._read_only = False
+    cur._connection._statement_policy = None
+    cur._retry_policy = None
     captured = {}
 
     def fake_sql_execute_many(hstmt, op, col_params, param_types, row_count, enc):
@@ -16980,6 +16989,7 @@ def test_executemany_decimal_numeric_override_coverage(monkeypatch):
     monkeypatch.setattr(ddbc_bindings, "SQLExecuteMany", fake_sql_execute_many)
     monkeypatch.setattr(ddbc_bindings, "DDBCSQLGetAllDiagRecords", lambda h: [])
     monkeypatch.setattr(ddbc_bindings, "DDBCSQLRowCount", lambda h: 2)
+    monkeypatch.setattr(ddbc_bindings, "NumericData", types.SimpleNamespace)
     data = [
         (decimal.Decimal("100.50"),),
         (decimal.Decimal("999999999999999999.123456"),),
@@ -16988,10 +16998,12 @@ def test_executemany_decimal_numeric_override_coverage(monkeypatch):
     pt = captured["parameters_type"]
     assert len(pt) == 1
     assert pt[0].paramSQLType == _C.SQL_NUMERIC.value
-    assert pt[0].paramCType == _C.SQL_C_CHAR.value
+    # Every row is bound as SQL_NUMERIC_STRUCT at a precision and scale fitting all rows
+    assert pt[0].paramCType == _C.SQL_C_NUMERIC.value
+    assert (pt[0].columnSize, pt[0].decimalDigits) == (24, 6)
     col_values = captured["columnwise_params"][0]
-    for val in col_values:
-        assert val is None or isinstance(val, str)
+    assert [(val.precision, val.scale) for val in col_values] == [(24, 6), (24, 6)]
+    assert col_values[0].val == (100500000).to_bytes(16, "little")
 
 
 # ---------------------------------------------------------
@@ -303,6 +303,19 @@ def test_arrow_reader(cursor: mssql_python.Cursor):
     assert sum(len(b) for b in batches) == 11
 
 
+def test_fetch_arrow_batches(cursor: mssql_python.Cursor):
+    cursor.execute("select top 11 1 a from sys.objects")
+    batches = list(cursor.fetch_arrow_batches(4))
+    assert all(type(b) is pa.RecordBatch for b in batches)
+    assert [len(b) for b in batches] == [4, 4, 3]
+    cursor.execute("select top 8 1 a from sys.objects")
+    assert [len(b) for b in cursor.fetch_arrow_batches(4)] == [4, 4]
+    cursor.execute("select 1 a where 1 = 0")
+    assert list(cursor.fetch_arrow_batches()) == []
+    with pytest.raises(ValueError):
+        cursor.fetch_arrow_batches(0)
+
+
 def test_arrow_long_string(cursor: mssql_python.Cursor):
     "Make sure resizing the data buffer works"
     long_string = "A" * 100000  # 100k characters
@@ -693,3 +693,58 @@ def test_pooling_state_consistency(conn_str):
     assert PoolingManager.is_initialized(), "Should remain initialized after disable call"
 
     print("Pooling state consistency verified")
+
+
+# =============================================================================
+# Pool Options and Statistics Tests
+# =============================================================================
+
+
+@pytest.mark.parametrize(
+    "options",
+    [
+        {"max_size": 2, "min_size": 3},
+        {"min_size": -1},
+        {"max_lifetime": -5},
+        {"validation_query": "  "},
+    ],
+)
+def test_pool_option_validation(options):
+    """Invalid min_size, max_lifetime and validation_query are rejected up front."""
+    PoolingManager._reset_for_testing()
+    with pytest.raises(ValueError):
+        pooling(**options)
+    assert not PoolingManager.is_enabled()
+    assert mssql_python.pool_stats() == []
+
+
+def test_pool_stats_max_lifetime_and_validation_query(conn_str):
+    """pool_stats() counts reuse, and connections past max_lifetime are replaced.
+
+    Run in a subprocess so these pooling() options are the first in the process.
+    """
+    _run_in_subprocess(
+        """
+        import os, time
+        from mssql_python import connect, pooling, pool_stats
+
+        conn_str = os.environ["DB_CONNECTION_STRING"]
+        pooling(max_size=2, idle_timeout=600, max_lifetime=2, validation_query="SELECT 1")
+
+        for _ in range(2):
+            conn = connect(conn_str)
+            conn.cursor().execute("SELECT 1").fetchall()
+            conn.close()
+        (stats,) = pool_stats()
+        assert (stats["created"], stats["reused"]) == (1, 1), stats
+        assert (stats["size"], stats["idle"], stats["in_use"]) == (1, 1, 0), stats
+        assert "pwd=***" in stats["connection_string"].lower() or "pwd" not in conn_str.lower()
+
+        time.sleep(3)
+        conn = connect(conn_str)
+        (stats,) = pool_stats()
+        conn.close()
+        assert (stats["created"], stats["discarded"], stats["in_use"]) == (2, 1, 1), stats
+        """,
+        conn_str,
+    )
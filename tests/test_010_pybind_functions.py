@@ -109,6 +109,30 @@ class TestUtilityFunctions:
         with pytest.raises(RuntimeError):
             ddbc.ThrowStdException("Test exception message")
 
+    def test_throw_std_exception_is_interface_error(self):
+        """ThrowStdException raises the InterfaceError of the DB-API hierarchy."""
+        with pytest.raises(ddbc.InterfaceError) as info:
+            ddbc.ThrowStdException("Test exception message")
+        assert isinstance(info.value, ddbc.Error)
+        assert info.value.sqlstate is None
+        assert str(info.value) == "Test exception message"
+
+    def test_dbapi_exception_hierarchy(self):
+        """The bindings define the PEP 249 exception tree, rooted at RuntimeError."""
+        for name in (
+            "DataError",
+            "OperationalError",
+            "IntegrityError",
+            "InternalError",
+            "ProgrammingError",
+            "NotSupportedError",
+        ):
+            assert issubclass(getattr(ddbc, name), ddbc.DatabaseError)
+        assert issubclass(ddbc.InterfaceError, ddbc.Error)
+        assert issubclass(ddbc.DatabaseError, ddbc.Error)
+        assert issubclass(ddbc.Error, RuntimeError)
+        assert not issubclass(ddbc.Warning, ddbc.Error)
+
 
 @pytest.mark.skipif(not DDBC_AVAILABLE, reason="ddbc_bindings not available")
 class TestDataStructures:
@@ -511,6 +535,7 @@ class TestAdditionalPybindFunctions:
             "enable_pooling",
             "close_pooling",
             "DDBCSetDecimalSeparator",
+            "DDBCSetTemporalNanoseconds",
             "DDBCSQLExecDirect",
             "DDBCSQLExecute",
             "DDBCSQLRowCount",
@@ -518,6 +543,7 @@ class TestAdditionalPybindFunctions:
             "DDBCSQLNumResultCols",
             "DDBCSQLDescribeCol",
             "DDBCSQLGetData",
+            "DDBCSQLGetDataChunk",
             "DDBCSQLMoreResults",
             "DDBCSQLFetchOne",
             "DDBCSQLFetchMany",
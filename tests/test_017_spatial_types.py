@@ -304,8 +304,8 @@ def test_geography_output_converter(cursor, db_connection):
             return None
         return b"CONVERTED:" + value
 
-    # _build_converter_map looks up by desc[1] (Python type), not SQL type code.
-    # Register by `bytes` since that's what spatial columns report in description.
+    # Converters registered by the Python type in description (bytes for spatial
+    # columns) apply as well as ones registered by SQL type code.
     db_connection.add_output_converter(bytes, geography_converter)
 
     try:
@@ -210,3 +210,105 @@ def test_commit_does_not_block_other_python_threads(conn_str):
         f"Heartbeat thread was starved across cursor.execute+commit. "
         f"Got {ticks_during} ticks, expected >= {expected_min_ticks}."
     )
+
+
+# ============================================================================
+# Result sets: a batch that pauses between result sets blocks in SQLMoreResults,
+# and a large value arrives over many SQLGetData calls. Both run with the GIL
+# released, so a heartbeat keeps ticking across them.
+# ============================================================================
+
+
+def _ticks_during(work, heartbeat_interval=0.05):
+    """Return (ticks, seconds) a heartbeat thread made while work() ran on another thread."""
+    stop_event = threading.Event()
+    tick_count = [0]
+    errors = []
+
+    def heartbeat():
+        while not stop_event.is_set():
+            tick_count[0] += 1
+            time.sleep(heartbeat_interval)
+
+    def run():
+        try:
+            work()
+        except Exception as exc:
+            errors.append(str(exc))
+
+    hb = threading.Thread(target=heartbeat, daemon=True)
+    worker = threading.Thread(target=run, daemon=True)
+    hb.start()
+    time.sleep(0.1)
+    ticks_before = tick_count[0]
+    start = time.perf_counter()
+    worker.start()
+    worker.join(timeout=WAITFOR_SECONDS + 30)
+    elapsed = time.perf_counter() - start
+    ticks_after = tick_count[0]
+    stop_event.set()
+    hb.join(timeout=5)
+
+    assert not worker.is_alive(), "Worker thread did not finish in time"
+    assert not errors, f"Worker thread error: {errors}"
+    return ticks_after - ticks_before, elapsed
+
+
+@pytest.mark.stress  # Heartbeat tick counts flake under CI CPU contention (macOS Py3.14)
+def test_nextset_does_not_block_other_python_threads(conn_str):
+    """
+    ``SELECT 1; WAITFOR ...; SELECT 2`` returns the first result set right away;
+    moving to the second waits in SQLMoreResults for the WAITFOR to finish.
+    """
+    mssql_python.pooling(enabled=False)
+    conn = connect(conn_str)
+    cursor = conn.cursor()
+    cursor.execute(f"SELECT 1; {WAITFOR_SQL}; SELECT 2")
+    assert cursor.fetchone()[0] == 1
+
+    def work():
+        assert cursor.nextset()
+        assert cursor.fetchone()[0] == 2
+
+    try:
+        ticks_during, _ = _ticks_during(work)
+    finally:
+        conn.close()
+
+    expected_min_ticks = int(WAITFOR_SECONDS / 0.05 * 0.4)
+    print(f"\n[HEARTBEAT] ticks during nextset: {ticks_during} (expected >= {expected_min_ticks})")
+    assert ticks_during >= expected_min_ticks, (
+        f"Heartbeat thread was starved during cursor.nextset(). "
+        f"Got {ticks_during} ticks, expected >= {expected_min_ticks}."
+    )
+
+
+@pytest.mark.stress  # Heartbeat tick counts flake under CI CPU contention (macOS Py3.14)
+def test_large_value_fetch_does_not_block_other_python_threads(conn_str):
+    """
+    A 100 MB NVARCHAR(MAX) value is streamed with SQLGetData chunk by chunk; the
+    heartbeat keeps ticking for most of the transfer.
+    """
+    mssql_python.pooling(enabled=False)
+    conn = connect(conn_str)
+    cursor = conn.cursor()
+
+    def work():
+        cursor.execute("SELECT REPLICATE(CAST(N'x' AS nvarchar(max)), 50000000)")
+        assert len(cursor.fetchone()[0]) == 50000000
+
+    try:
+        ticks_during, elapsed = _ticks_during(work)
+    finally:
+        conn.close()
+
+    # Decoding the value into a str holds the GIL, so allow for a larger share
+    expected_min_ticks = int(elapsed / 0.05 * 0.25)
+    print(
+        f"\n[HEARTBEAT] ticks during a {elapsed:.2f}s fetch: {ticks_during} "
+        f"(expected >= {expected_min_ticks})"
+    )
+    assert ticks_during >= expected_min_ticks, (
+        f"Heartbeat thread was starved while fetching a large value. "
+        f"Got {ticks_during} ticks, expected >= {expected_min_ticks}."
+    )
@@ -0,0 +1,678 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for the bulk load helpers in mssql_python.bulk_load."""
+
+import datetime
+import decimal
+
+import pytest
+
+from mssql_python import SchemaDriftError, ProgrammingError
+from mssql_python.bulk_load import (
+    BulkCopyOptions,
+    TargetColumn,
+    ColumnTypeMismatch,
+    TableLoad,
+    compare_schemas,
+    order_table_loads,
+    load_tables,
+    prepare_idempotent_load,
+    insert_statements,
+    odbc_bulk_copy,
+    report_row_progress,
+    normalize_order_hints,
+    _normalize_source_columns,
+    _column_definition,
+)
+from mssql_python.cursor import Cursor
+from mssql_python.helpers import quote_identifier, split_multipart_name, quote_multipart_name
+
+
+# ---------------------------------------------------------------------------
+# Identifier helpers
+# ---------------------------------------------------------------------------
+
+
+def test_quote_identifier_escapes_closing_bracket():
+    assert quote_identifier("Order Details") == "[Order Details]"
+    assert quote_identifier("a]b") == "[a]]b]"
+
+
+@pytest.mark.parametrize("bad", ["", None, 5, "x" * 129])
+def test_quote_identifier_rejects_invalid(bad):
+    with pytest.raises(ValueError):
+        quote_identifier(bad)
+
+
+def test_split_multipart_name():
+    assert split_multipart_name("dbo.Users") == ("dbo", "Users")
+    assert split_multipart_name("[my db].[dbo].[a.b]") == ("my db", "dbo", "a.b")
+    assert split_multipart_name('"sch"."t""x"') == ("sch", 't"x')
+    assert split_multipart_name("[a]]b]") == ("a]b",)
+
+
+@pytest.mark.parametrize("bad", ["[unclosed", "a.b.c.d.e", "dbo.", "[a]x"])
+def test_split_multipart_name_rejects_invalid(bad):
+    with pytest.raises(ValueError):
+        split_multipart_name(bad)
+
+
+def test_quote_multipart_name():
+    assert quote_multipart_name("dbo.Users") == "[dbo].[Users]"
+    assert quote_multipart_name("[dbo].[My]]Table]") == "[dbo].[My]]Table]"
+
+
+# ---------------------------------------------------------------------------
+# Schema comparison (no database required)
+# ---------------------------------------------------------------------------
+
+TARGET = [
+    TargetColumn("id", "int", is_nullable=False, is_identity=True),
+    TargetColumn("name", "nvarchar", is_nullable=False),
+    TargetColumn("created", "datetime2", is_nullable=False, has_default=True),
+    TargetColumn("score", "decimal"),
+]
+
+
+def test_normalize_source_columns_shapes():
+    assert _normalize_source_columns(["a", "b"]) == [("a", None), ("b", None)]
+    assert _normalize_source_columns({"a": int}) == [("a", int)]
+    description = [("a", str, None, 50, 50, 0, True)]
+    assert _normalize_source_columns(description) == [("a", str)]
+
+
+def test_normalize_source_columns_rejects_duplicates_and_bad_specs():
+    with pytest.raises(ValueError):
+        _normalize_source_columns(["a", "A"])
+    with pytest.raises(TypeError):
+        _normalize_source_columns("abc")
+    with pytest.raises(TypeError):
+        _normalize_source_columns([1, 2])
+
+
+def test_compare_schemas_matching_source_is_compatible():
+    report = compare_schemas("dbo.T", {"NAME": str, "score": decimal.Decimal}, TARGET)
+    assert report.is_compatible
+    assert report.missing_columns == []
+    assert report.extra_columns == ["id", "created"]
+    assert report.unfillable_columns == []
+    report.raise_if_incompatible()
+
+
+def test_compare_schemas_reports_missing_and_unfillable_columns():
+    report = compare_schemas("dbo.T", ["score", "email"], TARGET)
+    assert report.has_drift
+    assert not report.is_compatible
+    assert report.missing_columns == ["email"]
+    assert report.unfillable_columns == ["name"]
+
+
+def test_compare_schemas_reports_type_mismatch():
+    report = compare_schemas("dbo.T", {"name": str, "score": datetime.date}, TARGET)
+    assert report.type_mismatches == [ColumnTypeMismatch("score", datetime.date, "decimal")]
+    assert not report.is_compatible
+
+
+def test_compare_schemas_accepts_sql_type_names():
+    report = compare_schemas("dbo.T", {"name": "NVARCHAR(50)", "score": "varbinary"}, TARGET)
+    assert [m.name for m in report.type_mismatches] == ["score"]
+
+
+def test_raise_if_incompatible_attaches_report():
+    report = compare_schemas("dbo.T", ["email"], TARGET)
+    with pytest.raises(SchemaDriftError) as exc_info:
+        report.raise_if_incompatible()
+    assert exc_info.value.report is report
+    assert isinstance(exc_info.value, ProgrammingError)
+    assert "email" in str(exc_info.value)
+
+
+def test_report_to_dict():
+    report = compare_schemas("dbo.T", {"name": bytes}, TARGET)
+    data = report.to_dict()
+    assert data["table_name"] == "dbo.T"
+    assert data["type_mismatches"] == [
+        {"name": "name", "source_type": "bytes", "target_type": "nvarchar"}
+    ]
+
+
+def test_column_definition_inference():
+    assert _column_definition(int) == "BIGINT"
+    assert _column_definition(bool) == "BIT"
+    assert _column_definition(datetime.datetime) == "DATETIME2(7)"
+    assert _column_definition("nvarchar(100)") == "nvarchar(100)"
+    assert _column_definition("int; DROP TABLE x") is None
+    assert _column_definition(None) is None
+
+
+# ---------------------------------------------------------------------------
+# Multi-table load ordering and transaction handling (no database required)
+# ---------------------------------------------------------------------------
+
+
+def _names(loads):
+    return [load.table_name for load in loads]
+
+
+def test_order_table_loads_puts_parents_first():
+    loads = [TableLoad("lines", []), TableLoad("orders", []), TableLoad("customers", [])]
+    # lines -> orders -> customers
+    ordered = order_table_loads(loads, [(0, 1), (1, 2)])
+    assert _names(ordered) == ["customers", "orders", "lines"]
+
+
+def test_order_table_loads_keeps_unrelated_input_order():
+    loads = [TableLoad("b", []), TableLoad("a", []), TableLoad("c", [])]
+    assert _names(order_table_loads(loads, [])) == ["b", "a", "c"]
+    assert _names(order_table_loads(loads, [(0, 0)])) == ["b", "a", "c"]
+
+
+def test_order_table_loads_rejects_cycles():
+    loads = [TableLoad("a", []), TableLoad("b", [])]
+    with pytest.raises(ProgrammingError):
+        order_table_loads(loads, [(0, 1), (1, 0)])
+
+
+class _FakeConnection:
+    def __init__(self):
+        self.autocommit = True
+        self.in_transaction = False
+        self.events = []
+
+    def commit(self):
+        self.events.append("commit")
+
+    def rollback(self):
+        self.events.append("rollback")
+
+
+class _FakeCursor:
+    def __init__(self, fail_on=None, fail_execute_on=None):
+        self.connection = _FakeConnection()
+        self.fail_on = fail_on
+        self.fail_execute_on = fail_execute_on
+        self.statements = []
+
+    def execute(self, sql, *params):
+        if self.fail_execute_on and self.fail_execute_on in sql:
+            raise OSError("statement failed")
+        self.statements.append(sql)
+
+    def executemany(self, sql, rows):
+        if self.fail_on and self.fail_on in sql:
+            raise RuntimeError("insert failed")
+        self.statements.append((sql, len(rows)))
+
+
+def test_load_tables_commits_and_restores_autocommit():
+    cursor = _FakeCursor()
+    result = load_tables(
+        cursor,
+        [TableLoad("dbo.a", [(1, "x"), (2, "y"), (3, "z")], columns=["id", "v"])],
+        batch_size=2,
+    )
+    assert result == {"dbo.a": 3}
+    assert cursor.statements == [
+        ("INSERT INTO [dbo].[a] ([id], [v]) VALUES (?, ?)", 2),
+        ("INSERT INTO [dbo].[a] ([id], [v]) VALUES (?, ?)", 1),
+    ]
+    assert cursor.connection.events == ["commit"]
+    assert cursor.connection.autocommit is True
+
+
+def test_load_tables_rolls_back_everything_on_failure():
+    cursor = _FakeCursor(fail_on="[b]")
+    loads = [TableLoad("a", [(1,)]), TableLoad("b", [(2,)])]
+    with pytest.raises(RuntimeError):
+        load_tables(cursor, loads, order="given")
+    assert cursor.connection.events == ["rollback"]
+    assert cursor.connection.autocommit is True
+
+
+def test_load_tables_identity_insert_is_wrapped():
+    cursor = _FakeCursor()
+    load_tables(cursor, [TableLoad("t", [(1,)], keep_identity=True)])
+    assert cursor.statements[0] == "SET IDENTITY_INSERT [t] ON"
+    assert cursor.statements[-1] == "SET IDENTITY_INSERT [t] OFF"
+
+
+def test_load_tables_identity_insert_failure_keeps_insert_error():
+    cursor = _FakeCursor(fail_on="INSERT", fail_execute_on="OFF")
+    with pytest.raises(RuntimeError, match="insert failed"):
+        load_tables(cursor, [TableLoad("t", [(1,)], keep_identity=True)])
+    assert cursor.statements == ["SET IDENTITY_INSERT [t] ON"]
+
+
+def test_load_tables_refuses_open_transaction():
+    cursor = _FakeCursor()
+    cursor.connection.autocommit = False
+    cursor.connection.in_transaction = True
+    with pytest.raises(ProgrammingError):
+        load_tables(cursor, [TableLoad("t", [(1,)])])
+    assert cursor.statements == []
+    assert cursor.connection.events == []
+
+
+def test_load_tables_validates_arguments():
+    with pytest.raises(ValueError):
+        load_tables(_FakeCursor(), [], order="random")
+    with pytest.raises(ValueError):
+        load_tables(_FakeCursor(), [], batch_size=0)
+    with pytest.raises(TypeError):
+        load_tables(_FakeCursor(), [("t", [])])
+
+
+class _MetadataCursor(_FakeCursor):
+    """Fake cursor that answers the sys.columns query and reports a DELETE rowcount."""
+
+    def __init__(self, columns, deleted=0):
+        super().__init__()
+        self.columns = columns
+        self.rowcount = deleted
+
+    def execute(self, sql, *params):
+        self.statements.append((sql, params))
+
+    def fetchall(self):
+        return [(name, "int", 4, 10, 0, True, False, False, False) for name in self.columns]
+
+
+def test_prepare_idempotent_load_stamps_rows_and_clears_previous_attempt():
+    cursor = _MetadataCursor(["id", "name", "load_id"], deleted=2)
+    rows, mappings, removed = prepare_idempotent_load(
+        cursor, "dbo.t", [(1, "a"), (2, "b")], None, "run-7"
+    )
+    assert list(rows) == [(1, "a", "run-7"), (2, "b", "run-7")]
+    assert mappings == ["id", "name", "load_id"]
+    assert removed == 2
+    assert cursor.statements[-1] == ("DELETE FROM [dbo].[t] WHERE [load_id] = ?", ("run-7",))
+    assert cursor.connection.events == []
+
+
+def test_prepare_idempotent_load_commits_only_the_delete():
+    cursor = _MetadataCursor(["id", "load_id"], deleted=3)
+    cursor.connection.autocommit = False
+    _, _, removed = prepare_idempotent_load(cursor, "t", [(1,)], None, "run-8")
+    assert removed == 3
+    assert cursor.statements[-1] == ("DELETE FROM [t] WHERE [load_id] = ?", ("run-8",))
+    assert cursor.connection.events == ["commit"]
+
+
+def test_prepare_idempotent_load_refuses_open_transaction():
+    cursor = _MetadataCursor(["id", "load_id"])
+    cursor.connection.autocommit = False
+    cursor.connection.in_transaction = True
+    with pytest.raises(ProgrammingError, match="transaction open"):
+        prepare_idempotent_load(cursor, "t", [(1,)], None, "run-9")
+    # Nothing is deleted or committed on the caller's behalf
+    assert cursor.statements == []
+    assert cursor.connection.events == []
+
+
+def test_prepare_idempotent_load_extends_column_mappings():
+    cursor = _MetadataCursor(["a", "b", "LoadKey"])
+    _, mappings, _ = prepare_idempotent_load(cursor, "t", [(1,)], ["b"], 5, "loadkey")
+    assert mappings == ["b", "loadkey"]
+
+    rows, mappings, _ = prepare_idempotent_load(cursor, "t", [(1, 2, 3)], [(2, "a")], 5, "LoadKey")
+    assert mappings == [(2, "a"), (3, "LoadKey")]
+    assert list(rows) == [(1, 2, 3, 5)]
+
+
+def test_prepare_idempotent_load_validates_input():
+    cursor = _MetadataCursor(["id", "load_id"])
+    with pytest.raises(ProgrammingError):
+        prepare_idempotent_load(cursor, "t", [(1,)], None, 1, "batch")
+    with pytest.raises(ValueError):
+        prepare_idempotent_load(cursor, "t", [(1,)], ["load_id"], 1)
+    with pytest.raises(TypeError):
+        prepare_idempotent_load(cursor, "t", [[1]], None, 1)
+
+
+# ---------------------------------------------------------------------------
+# Integration tests (require DB_CONNECTION_STRING)
+# ---------------------------------------------------------------------------
+
+
+@pytest.fixture
+def drift_table(cursor):
+    table_name = "mssql_python_drift_test"
+    cursor.execute(f"IF OBJECT_ID('{table_name}', 'U') IS NOT NULL DROP TABLE {table_name}")
+    cursor.execute(
+        f"CREATE TABLE {table_name} ("
+        "id INT IDENTITY(1,1) PRIMARY KEY, "
+        "name NVARCHAR(50) NOT NULL, "
+        "created DATETIME2 NOT NULL DEFAULT SYSDATETIME(), "
+        "score DECIMAL(10, 2) NULL)"
+    )
+    cursor.connection.commit()
+    yield table_name
+    cursor.execute(f"IF OBJECT_ID('{table_name}', 'U') IS NOT NULL DROP TABLE {table_name}")
+    cursor.connection.commit()
+
+
+def test_check_schema_drift_against_table(cursor, drift_table):
+    report = cursor.check_schema_drift(drift_table, {"name": str, "score": float})
+    assert report.is_compatible
+    assert sorted(report.extra_columns) == ["created", "id"]
+
+    report = cursor.check_schema_drift(drift_table, ["score"])
+    assert report.unfillable_columns == ["name"]
+
+
+def test_check_schema_drift_adds_missing_columns(cursor, drift_table):
+    report = cursor.check_schema_drift(
+        drift_table, {"name": str, "email": str}, add_missing_columns=True
+    )
+    assert report.added_columns == ["email"]
+    assert report.missing_columns == []
+
+    cursor.execute(
+        "SELECT is_nullable FROM sys.columns WHERE object_id = OBJECT_ID(?) AND name = 'email'",
+        drift_table,
+    )
+    assert cursor.fetchval() == 1
+
+
+def test_check_schema_drift_unknown_table(cursor):
+    with pytest.raises(ProgrammingError):
+        cursor.check_schema_drift("dbo.mssql_python_no_such_table", ["a"])
+
+
+def test_bulkcopy_refuses_drifted_source(cursor, drift_table):
+    pytest.importorskip("mssql_py_core", exc_type=ImportError)
+    with pytest.raises(SchemaDriftError) as exc_info:
+        cursor.bulkcopy(drift_table, [(1.5,)], source_columns=["score"])
+    assert exc_info.value.report.unfillable_columns == ["name"]
+
+
+@pytest.fixture
+def parent_child_tables(cursor):
+    drop = (
+        "IF OBJECT_ID('mssql_python_load_child', 'U') IS NOT NULL "
+        "DROP TABLE mssql_python_load_child; "
+        "IF OBJECT_ID('mssql_python_load_parent', 'U') IS NOT NULL "
+        "DROP TABLE mssql_python_load_parent"
+    )
+    cursor.execute(drop)
+    cursor.execute("CREATE TABLE mssql_python_load_parent (id INT PRIMARY KEY, name NVARCHAR(20))")
+    cursor.execute(
+        "CREATE TABLE mssql_python_load_child (id INT PRIMARY KEY, "
+        "parent_id INT NOT NULL REFERENCES mssql_python_load_parent(id))"
+    )
+    cursor.connection.commit()
+    yield "mssql_python_load_parent", "mssql_python_load_child"
+    cursor.execute(drop)
+    cursor.connection.commit()
+
+
+def test_load_tables_orders_by_foreign_keys(db_connection, parent_child_tables):
+    parent, child = parent_child_tables
+    result = db_connection.load_tables(
+        [
+            TableLoad(child, [(10, 1), (11, 2)]),
+            TableLoad(parent, [(1, "a"), (2, "b")]),
+        ]
+    )
+    assert list(result) == [parent, child]
+    assert db_connection.execute(f"SELECT COUNT(*) FROM {child}").fetchval() == 2
+
+
+def test_load_tables_failure_rolls_back_all_tables(db_connection, parent_child_tables):
+    parent, child = parent_child_tables
+    with pytest.raises(Exception):
+        db_connection.load_tables(
+            [
+                TableLoad(parent, [(1, "a")]),
+                # parent_id 99 violates the foreign key
+                TableLoad(child, [(10, 99)]),
+            ]
+        )
+    assert db_connection.execute(f"SELECT COUNT(*) FROM {parent}").fetchval() == 0
+
+
+def test_bulkcopy_with_load_id_is_retry_safe(cursor):
+    pytest.importorskip("mssql_py_core", exc_type=ImportError)
+    table_name = "mssql_python_load_id_test"
+    cursor.execute(f"IF OBJECT_ID('{table_name}', 'U') IS NOT NULL DROP TABLE {table_name}")
+    cursor.execute(f"CREATE TABLE {table_name} (id INT, name NVARCHAR(20), load_id INT)")
+    cursor.connection.commit()
+    try:
+        data = [(1, "a"), (2, "b"), (3, "c")]
+        first = cursor.bulkcopy(table_name, data, load_id=42)
+        assert first["rows_replaced"] == 0
+        # A retry of the same load replaces the previous rows instead of duplicating them
+        second = cursor.bulkcopy(table_name, data, load_id=42)
+        assert second["rows_replaced"] == 3
+
+        cursor.execute(f"SELECT COUNT(*), MIN(load_id) FROM {table_name}")
+        assert tuple(cursor.fetchone()) == (3, 42)
+    finally:
+        cursor.execute(f"DROP TABLE {table_name}")
+        cursor.connection.commit()
+
+
+# ---------------------------------------------------------------------------
+# Multi-row INSERT statements
+# ---------------------------------------------------------------------------
+
+
+def test_insert_statements_chunk_by_rows():
+    statements = list(insert_statements("dbo.T", ["a", "b"], ((i, -i) for i in range(2500))))
+    assert [len(params) // 2 for _, params in statements] == [1000, 1000, 500]
+    assert statements[0][0] == statements[1][0]
+    assert statements[0][0].startswith("INSERT INTO [dbo].[T] ([a], [b]) VALUES (?, ?), (?, ?)")
+    assert statements[2][0].count("(?, ?)") == 500
+    assert statements[2][1][:4] == [2000, -2000, 2001, -2001]
+
+
+def test_insert_statements_respect_parameter_limit():
+    columns = [f"c{i}" for i in range(30)]
+    statements = list(insert_statements("T", columns, [tuple(range(30))] * 150, max_rows=500))
+    # 2099 // 30 = 69 rows per statement
+    assert [len(params) for _, params in statements] == [69 * 30, 69 * 30, 12 * 30]
+    assert all(len(params) <= 2099 for _, params in statements)
+
+
+@pytest.mark.parametrize(
+    "columns, rows, max_rows",
+    [
+        ([], [(1,)], 10),
+        ("ab", [(1,)], 10),
+        (["a"], [(1,)], 0),
+        (["a"], [(1,)], 1001),
+        (["a", "b"], [(1, 2), (3,)], 10),
+        (["c"] * 2100, [], 10),
+    ],
+)
+def test_insert_statements_reject_invalid_input(columns, rows, max_rows):
+    with pytest.raises(ValueError):
+        list(insert_statements("T", columns, rows, max_rows))
+
+
+def test_insert_rows_into_table(cursor):
+    cursor.execute("CREATE TABLE #insert_rows (id INT, name NVARCHAR(20))")
+    rows = [(i, f"row {i}") for i in range(2345)]
+    assert cursor.insert_rows("#insert_rows", ["id", "name"], rows) == 2345
+    cursor.execute("SELECT COUNT(*), SUM(id), MAX(name) FROM #insert_rows")
+    assert tuple(cursor.fetchone()) == (2345, sum(range(2345)), "row 999")
+
+
+# ---------------------------------------------------------------------------
+# ODBC bulk copy engine
+# ---------------------------------------------------------------------------
+
+
+class _RecordingSession:
+    """A spawned session that records the statements and commits of a load."""
+
+    def __init__(self, fail_on_chunk=None):
+        self.log = []
+        self.fail_on_chunk = fail_on_chunk
+        self.timeout = None
+        self.closed = False
+
+    def cursor(self):
+        return self
+
+    def execute(self, sql, *params):
+        self.log.append(("execute", sql))
+
+    def executemany(self, sql, rows):
+        chunks = sum(1 for entry in self.log if entry[0] == "executemany")
+        if chunks == self.fail_on_chunk:
+            raise RuntimeError("insert failed")
+        self.log.append(("executemany", sql, len(rows)))
+
+    def commit(self):
+        self.log.append(("commit",))
+
+    def rollback(self):
+        self.log.append(("rollback",))
+
+    def close(self):
+        self.closed = True
+
+
+class _SpawningConnection:
+    def __init__(self, session):
+        self.session = session
+
+    def _spawn_connection(self, autocommit=False):
+        assert autocommit is False
+        return self.session
+
+
+def test_odbc_bulk_copy_commits_each_batch():
+    session = _RecordingSession()
+    rows = [(i, f"n{i}") for i in range(2500)]
+    result = odbc_bulk_copy(_SpawningConnection(session), "dbo.T", rows, batch_size=1200)
+    assert (result["rows_copied"], result["batch_count"]) == (2500, 3)
+    steps = [entry[0] if entry[0] == "commit" else entry[2] for entry in session.log]
+    assert steps == [1000, 200, "commit", 1000, 200, "commit", 100, "commit"]
+    assert session.log[0][1] == "INSERT INTO [dbo].[T] VALUES (?, ?)"
+    assert session.closed and session.timeout == 30
+
+
+def test_odbc_bulk_copy_options():
+    session = _RecordingSession()
+    odbc_bulk_copy(
+        _SpawningConnection(session),
+        "T",
+        [(1, "x", "y")],
+        column_mappings=[(2, "b"), (0, "a")],
+        keep_identity=True,
+        table_lock=True,
+    )
+    assert session.log[0] == ("execute", "SET IDENTITY_INSERT [T] ON")
+    insert = "INSERT INTO [T] WITH (TABLOCK) ([b], [a]) VALUES (?, ?)"
+    assert session.log[1] == ("executemany", insert, 1)
+    assert session.log[-1] == ("commit",)
+
+
+def test_odbc_bulk_copy_rolls_back_the_failed_batch():
+    session = _RecordingSession(fail_on_chunk=1)
+    with pytest.raises(RuntimeError):
+        odbc_bulk_copy(_SpawningConnection(session), "T", [(i,) for i in range(1500)])
+    assert session.log[-1] == ("rollback",) and session.closed
+    with pytest.raises(TypeError):
+        odbc_bulk_copy(_SpawningConnection(_RecordingSession()), "T", [[1, 2]])
+
+
+def test_odbc_bulk_copy_consumes_rows_lazily():
+    produced = []
+
+    def rows():
+        for i in range(2500):
+            produced.append(i)
+            yield (i,)
+
+    class _Session(_RecordingSession):
+        def executemany(self, sql, chunk):
+            # Rows are sent as they are produced, not read up front
+            assert len(produced) <= sum(e[2] for e in self.log if e[0] == "executemany") + 1000
+            super().executemany(sql, chunk)
+
+    result = odbc_bulk_copy(_SpawningConnection(_Session()), "T", rows())
+    assert result["rows_copied"] == 2500
+
+
+def test_report_row_progress():
+    calls = []
+    assert list(report_row_progress(range(25), calls.append, 10)) == list(range(25))
+    assert calls == [10, 20, 25]
+    calls.clear()
+    list(report_row_progress(range(20), calls.append, 10))
+    assert calls == [10, 20]
+    calls.clear()
+    list(report_row_progress([], calls.append, 10))
+    assert calls == [0]
+
+
+def test_bulk_copy_options():
+    options = BulkCopyOptions()
+    assert options.batch_size == 0 and options.timeout == 30 and options.order is None
+    assert repr(options) == "BulkCopyOptions()"
+    options = BulkCopyOptions(batch_size=500, table_lock=True, order=["id", ("name", "desc")])
+    assert options.order == [("id", "ASC"), ("name", "DESC")]
+    assert repr(options) == (
+        "BulkCopyOptions(batch_size=500, table_lock=True, "
+        "order=[('id', 'ASC'), ('name', 'DESC')])"
+    )
+    assert options.to_dict()["keep_nulls"] is False
+
+
+def test_normalize_order_hints():
+    assert normalize_order_hints(None) is None
+    assert normalize_order_hints([]) is None
+    assert normalize_order_hints("id") == [("id", "ASC")]
+    with pytest.raises(ValueError):
+        normalize_order_hints([("id", "UP")])
+    with pytest.raises(ValueError):
+        normalize_order_hints([""])
+
+
+def test_bulkcopy_options_conflicts_with_arguments():
+    # Options are resolved before the cursor is touched
+    with pytest.raises(ValueError, match="batch_size"):
+        Cursor.bulkcopy(object(), "t", [], batch_size=10, options=BulkCopyOptions())
+    with pytest.raises(TypeError):
+        Cursor.bulkcopy(object(), "t", [], options={"batch_size": 10})
+
+
+def test_bulkcopy_through_the_driver(cursor):
+    table_name = "mssql_python_odbc_bulkcopy_test"
+    cursor.execute(f"IF OBJECT_ID('{table_name}', 'U') IS NOT NULL DROP TABLE {table_name}")
+    cursor.execute(f"CREATE TABLE {table_name} (id INT IDENTITY, name NVARCHAR(20))")
+    cursor.connection.commit()
+    try:
+        rows = [(i + 100, f"row {i}") for i in range(1500)]
+        result = cursor.bulkcopy(
+            table_name, rows, batch_size=1000, keep_identity=True, engine="odbc"
+        )
+        assert (result["rows_copied"], result["batch_count"]) == (1500, 2)
+        cursor.execute(f"SELECT COUNT(*), MIN(id) FROM {table_name}")
+        assert tuple(cursor.fetchone()) == (1500, 100)
+        with pytest.raises(ValueError):
+            cursor.bulkcopy(table_name, rows, engine="bcp")
+        # A generator is streamed, with progress reported along the way
+        seen = []
+        result = cursor.bulkcopy(
+            table_name,
+            ((f"gen {i}",) for i in range(2500)),
+            column_mappings=["name"],
+            engine="odbc",
+            progress=seen.append,
+            progress_every=1000,
+        )
+        assert result["rows_copied"] == 2500 and seen == [1000, 2000, 2500]
+        # The same load options, built once
+        options = BulkCopyOptions(batch_size=100, order=["name"])
+        result = cursor.bulkcopy(
+            table_name, [("opt",)] * 250, column_mappings=["name"], engine="odbc", options=options
+        )
+        assert (result["rows_copied"], result["batch_count"]) == (250, 3)
+    finally:
+        cursor.execute(f"DROP TABLE {table_name}")
+        cursor.connection.commit()
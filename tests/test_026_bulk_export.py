@@ -0,0 +1,82 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for parallel table export (mssql_python.bulk_export)."""
+
+import csv
+
+import pytest
+
+from mssql_python.bulk_export import range_predicates, partition_predicates, bulk_export_parallel
+
+
+def test_range_predicates_cover_all_values():
+    predicates = range_predicates("id", [100, 200, 200, None, 300])
+    assert predicates == [
+        ("([id] < ? OR [id] IS NULL)", [100]),
+        ("[id] >= ? AND [id] < ?", [100, 200]),
+        ("[id] >= ? AND [id] < ?", [200, 300]),
+        ("[id] >= ?", [300]),
+    ]
+
+
+def test_range_predicates_without_boundaries_selects_everything():
+    assert range_predicates("id", []) == [("1 = 1", [])]
+
+
+def test_partition_predicates_round_robin():
+    predicates = partition_predicates("pf_sales", "sale date", 5, 2)
+    assert predicates == [
+        ("$PARTITION.[pf_sales]([sale date]) IN (?, ?, ?)", [1, 3, 5]),
+        ("$PARTITION.[pf_sales]([sale date]) IN (?, ?)", [2, 4]),
+    ]
+    # Never more streams than partitions
+    assert len(partition_predicates("pf", "c", 2, 8)) == 2
+
+
+@pytest.mark.parametrize(
+    "kwargs",
+    [
+        {"num_streams": 0},
+        {"num_streams": True},
+        {"file_format": "xlsx"},
+        {"split": "hash"},
+        {"batch_size": 0},
+    ],
+)
+def test_bulk_export_parallel_validates_arguments(kwargs):
+    arguments = {"table_name": "dbo.t", "num_streams": 2}
+    arguments.update(kwargs)
+    with pytest.raises(ValueError):
+        bulk_export_parallel(None, **arguments)
+
+
+def test_bulk_export_parallel_writes_one_file_per_stream(db_connection, tmp_path):
+    table_name = "mssql_python_parallel_export"
+    cursor = db_connection.cursor()
+    cursor.execute(f"IF OBJECT_ID('{table_name}', 'U') IS NOT NULL DROP TABLE {table_name}")
+    cursor.execute(f"CREATE TABLE {table_name} (id INT PRIMARY KEY CLUSTERED, v NVARCHAR(10))")
+    cursor.executemany(
+        f"INSERT INTO {table_name} VALUES (?, ?)", [(i, f"v{i}") for i in range(1, 101)]
+    )
+    db_connection.commit()
+    try:
+        try:
+            result = db_connection.bulk_export_parallel(table_name, 4, output_dir=str(tmp_path))
+        except Exception as e:  # pylint: disable=broad-exception-caught
+            if "snapshot" in str(e).lower():
+                pytest.skip("ALLOW_SNAPSHOT_ISOLATION is not enabled on the test database")
+            raise
+
+        assert result["rows_exported"] == 100
+        assert len(result["files"]) == 4
+        exported = []
+        for path in result["files"]:
+            with open(path, newline="", encoding="utf-8") as handle:
+                rows = list(csv.reader(handle))
+            assert rows[0] == ["id", "v"]
+            exported.extend(int(row[0]) for row in rows[1:])
+        assert sorted(exported) == list(range(1, 101))
+    finally:
+        cursor.execute(f"DROP TABLE {table_name}")
+        db_connection.commit()
@@ -0,0 +1,89 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for streaming result checksums (mssql_python.row_hash and Cursor.checksum)."""
+
+import datetime
+import decimal
+import uuid
+
+import pytest
+
+from mssql_python.row_hash import ResultChecksum, encode_value, hash_row
+
+
+def test_encode_value_distinguishes_types():
+    encodings = {encode_value(v) for v in (None, 1, True, "1", b"1", 1.0, decimal.Decimal(1))}
+    assert len(encodings) == 7
+
+
+def test_encode_value_normalizes_equivalent_values():
+    assert encode_value(decimal.Decimal("1.10")) == encode_value(decimal.Decimal("1.1"))
+    assert encode_value(decimal.Decimal("0.000")) == encode_value(decimal.Decimal("0"))
+    utc = datetime.datetime(2024, 1, 1, 12, 0, tzinfo=datetime.timezone.utc)
+    plus_two = utc.astimezone(datetime.timezone(datetime.timedelta(hours=2)))
+    assert encode_value(utc) == encode_value(plus_two)
+    assert encode_value(bytearray(b"ab")) == encode_value(b"ab")
+
+
+def test_encode_value_rejects_unknown_types():
+    with pytest.raises(TypeError):
+        encode_value(object())
+
+
+def test_hash_row_fields_do_not_run_together():
+    assert hash_row(("ab", "c")) != hash_row(("a", "bc"))
+    assert hash_row((None, "")) != hash_row(("", None))
+
+
+def test_ordered_and_unordered_digests():
+    rows = [(1, "a"), (2, "b"), (3, uuid.UUID(int=3))]
+    forward, backward = ResultChecksum(), ResultChecksum()
+    forward.update(rows)
+    backward.update(reversed(rows))
+    assert forward.row_count == 3
+    assert forward.digest != backward.digest
+
+    forward, backward = ResultChecksum(ordered=False), ResultChecksum(ordered=False)
+    forward.update(rows)
+    backward.update(reversed(rows))
+    assert forward == backward
+
+
+def test_checksum_is_incremental_and_keeps_row_hashes():
+    whole = ResultChecksum("md5", include_row_hashes=True)
+    whole.update([(1,), (2,)])
+    pieces = ResultChecksum("md5")
+    pieces.update([(1,)])
+    pieces.update([(2,)])
+    assert whole.digest == pieces.digest
+    assert whole.row_hashes == [hash_row((1,), "md5").hex(), hash_row((2,), "md5").hex()]
+
+
+def test_unknown_algorithm_is_rejected():
+    with pytest.raises(ValueError):
+        ResultChecksum("no-such-hash")
+
+
+def test_cursor_checksum_matches_across_queries(cursor):
+    query = (
+        "SELECT v, CAST(v AS DECIMAL(10, 2)) AS d, CAST(v AS NVARCHAR(10)) AS s "
+        "FROM (VALUES (1), (2), (3)) AS t(v) ORDER BY v"
+    )
+    first = cursor.execute(query).checksum(batch_size=2)
+    second = cursor.execute(query).checksum()
+    assert first.row_count == 3
+    assert first == second
+
+    unordered = cursor.execute(query.replace("ORDER BY v", "ORDER BY v DESC")).checksum(
+        ordered=False
+    )
+    assert unordered.digest == cursor.execute(query).checksum(ordered=False).digest
+
+
+def test_cursor_checksum_requires_result_set(cursor):
+    from mssql_python import ProgrammingError
+
+    cursor.execute("DECLARE @x INT = 1")
+    with pytest.raises(ProgrammingError):
+        cursor.checksum()
@@ -0,0 +1,264 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""
+Tests for the pending-results guard: executing on a non-MARS connection while
+another cursor still has unread results raises a descriptive InterfaceError.
+"""
+
+import pytest
+
+from mssql_python import InterfaceError
+from mssql_python import cursor as cursor_module
+from mssql_python.constants import ConstantsDDBC as ddbc_sql_const
+from mssql_python.cursor import Cursor
+from mssql_python.exceptions import raise_exception, PENDING_RESULTS_MESSAGE
+
+
+class _FakeConnection:
+    def __init__(self, mars_enabled=False, auto_drain=False):
+        self._mars_enabled = mars_enabled
+        self._auto_drain_results = auto_drain
+        self._cursors = set()
+
+
+class _UnboundCursor(Cursor):
+    """Cursor without a statement handle; only the pending-results state is set."""
+
+    def __del__(self):
+        pass
+
+    def _soft_reset_cursor(self):
+        self.was_reset = True
+        self._results_pending = False
+
+
+def _fake_cursor(connection, pending=False, closed=False):
+    cursor = _UnboundCursor.__new__(_UnboundCursor)
+    cursor._connection = connection
+    cursor._results_pending = pending
+    cursor.closed = closed
+    cursor._row_fetched_ahead = False
+    connection._cursors.add(cursor)
+    return cursor
+
+
+def test_guard_raises_when_other_cursor_has_pending_results():
+    connection = _FakeConnection()
+    _fake_cursor(connection, pending=True)
+    current = _fake_cursor(connection)
+    with pytest.raises(InterfaceError, match="call fetchall\\(\\) or nextset\\(\\)"):
+        current._check_pending_results()
+
+
+def test_guard_ignores_own_closed_and_drained_cursors():
+    connection = _FakeConnection()
+    _fake_cursor(connection, pending=True, closed=True)
+    _fake_cursor(connection, pending=False)
+    current = _fake_cursor(connection, pending=True)
+    current._check_pending_results()
+
+
+class _ScriptedResult:
+    """Server cursor over scripted rows; records which rows had their data read."""
+
+    def __init__(self, rows):
+        self.rows = list(rows)
+        self.position = -1
+        self.read = []
+
+    def fetch(self, hstmt):
+        if self.position + 1 >= len(self.rows):
+            self.position = len(self.rows)
+            return ddbc_sql_const.SQL_NO_DATA.value
+        self.position += 1
+        return ddbc_sql_const.SQL_SUCCESS.value
+
+    def fetch_one(self, hstmt, row_data, *args, fetch=True):
+        if fetch and self.fetch(hstmt) == ddbc_sql_const.SQL_NO_DATA.value:
+            return ddbc_sql_const.SQL_NO_DATA.value
+        self.read.append(self.rows[self.position])
+        row_data.extend(self.rows[self.position])
+        return ddbc_sql_const.SQL_SUCCESS.value
+
+    def fetch_many(self, hstmt, rows, size, *args):
+        target = len(rows) + size
+        while len(rows) < target and self.fetch(hstmt) != ddbc_sql_const.SQL_NO_DATA.value:
+            self.read.append(self.rows[self.position])
+            rows.append(list(self.rows[self.position]))
+        return ddbc_sql_const.SQL_SUCCESS.value
+
+
+def _fetching_cursor(connection, monkeypatch, rows):
+    """Pending cursor that has returned its first row of [1] + rows."""
+    cursor = _fake_cursor(connection, pending=True)
+    cursor.hstmt = object()
+    cursor.messages = []
+    cursor.description = [("v", int, None, 10, 10, 0, False)]
+    cursor.arraysize = 1
+    cursor._internal_fetch_rows = 0
+    cursor._has_result_set = True
+    cursor._next_row_index = 1
+    cursor._rownumber = 0
+    cursor._skip_increment_for_next_fetch = False
+    cursor._lob_reader = None
+    cursor._get_decoding_settings = lambda sql_type: {}
+    cursor._get_column_and_converter_maps = lambda: ({"v": 0}, None, {"v": 0})
+    cursor._uuid_str_indices = None
+    cursor._cached_transform_map = None
+    result = _ScriptedResult([[1]] + rows)
+    result.position = 0
+    cursor.result = result
+
+    monkeypatch.setattr(cursor_module.ddbc_bindings, "DDBCSQLFetch", result.fetch)
+    monkeypatch.setattr(cursor_module.ddbc_bindings, "DDBCSQLFetchOne", result.fetch_one)
+    monkeypatch.setattr(cursor_module.ddbc_bindings, "DDBCSQLFetchMany", result.fetch_many)
+    monkeypatch.setattr(cursor_module.ddbc_bindings, "DDBCSQLGetAllDiagRecords", lambda h: [])
+    return cursor
+
+
+def test_guard_passes_when_other_cursor_returned_its_last_row(monkeypatch):
+    connection = _FakeConnection()
+    other = _fetching_cursor(connection, monkeypatch, [])
+    _fake_cursor(connection)._check_pending_results()
+    assert not other._results_pending
+    assert other.fetchone() is None
+
+
+def test_guard_moves_to_next_row_without_reading_it(monkeypatch):
+    connection = _FakeConnection()
+    other = _fetching_cursor(connection, monkeypatch, [[2], [3]])
+    current = _fake_cursor(connection)
+    with pytest.raises(InterfaceError):
+        current._check_pending_results()
+    assert other._results_pending
+    # The row's data is read and converted by the cursor's own next fetch
+    assert other.result.position == 1 and other.result.read == []
+    assert other.fetchone()[0] == 2
+    assert other.rownumber == 1
+    assert other.fetchone()[0] == 3
+    current._check_pending_results()
+    assert not other._results_pending
+
+
+def test_fetchmany_starts_with_row_moved_to(monkeypatch):
+    connection = _FakeConnection()
+    other = _fetching_cursor(connection, monkeypatch, [[2], [3], [4]])
+    with pytest.raises(InterfaceError):
+        _fake_cursor(connection)._check_pending_results()
+    assert [row[0] for row in other.fetchmany(2)] == [2, 3]
+    assert [row[0] for row in other.fetchmany(5)] == [4]
+    assert not other._results_pending
+
+
+def test_guard_leaves_streaming_cursor_alone(monkeypatch):
+    connection = _FakeConnection()
+    other = _fetching_cursor(connection, monkeypatch, [])
+    stream = other._lob_reader = object()
+    with pytest.raises(InterfaceError):
+        _fake_cursor(connection)._check_pending_results()
+    assert other._lob_reader is stream
+    assert other.result.position == 0
+
+
+def test_guard_is_disabled_with_mars():
+    connection = _FakeConnection(mars_enabled=True)
+    _fake_cursor(connection, pending=True)
+    _fake_cursor(connection)._check_pending_results()
+
+
+def test_auto_drain_discards_other_cursor_results_with_warning():
+    connection = _FakeConnection(auto_drain=True)
+    other = _fake_cursor(connection, pending=True)
+    other.description = [("v", int, None, 10, 10, 0, False)]
+    with pytest.warns(Warning, match="Discarding unread results"):
+        _fake_cursor(connection)._check_pending_results()
+    assert other.was_reset
+    assert other.description is None
+    assert not other._results_pending
+
+
+def test_driver_busy_error_is_translated():
+    with pytest.raises(InterfaceError) as exc_info:
+        raise_exception(
+            "HY000",
+            "[Microsoft][ODBC Driver 18 for SQL Server]Connection is busy with results "
+            "for another command",
+        )
+    assert PENDING_RESULTS_MESSAGE in str(exc_info.value)
+
+
+def test_execute_while_other_cursor_has_unread_rows(db_connection):
+    first = db_connection.cursor()
+    second = db_connection.cursor()
+    try:
+        first.execute("SELECT v FROM (VALUES (1), (2), (3)) AS t(v)")
+        first.fetchone()
+        with pytest.raises(InterfaceError, match="enable MARS"):
+            second.execute("SELECT 1")
+
+        # Draining the first cursor frees the connection
+        first.fetchall()
+        assert second.execute("SELECT 1").fetchall()[0][0] == 1
+    finally:
+        first.close()
+        second.close()
+
+
+def test_fetchval_of_single_row_frees_connection(db_connection):
+    first = db_connection.cursor()
+    second = db_connection.cursor()
+    try:
+        assert first.execute("SELECT 7").fetchval() == 7
+        assert second.execute("SELECT 1").fetchval() == 1
+        assert first.fetchone() is None
+    finally:
+        first.close()
+        second.close()
+
+
+def test_arrow_batch_after_guard_moved_to_next_row(db_connection):
+    pytest.importorskip("pyarrow")
+    first = db_connection.cursor()
+    second = db_connection.cursor()
+    try:
+        first.execute("SELECT v FROM (VALUES (1), (2), (3)) AS t(v)")
+        assert first.fetchval() == 1
+        with pytest.raises(InterfaceError):
+            second.execute("SELECT 1")
+        assert first.arrow_batch(10).column(0).to_pylist() == [2, 3]
+    finally:
+        first.close()
+        second.close()
+
+
+def test_fetchmany_to_end_frees_connection(db_connection):
+    first = db_connection.cursor()
+    second = db_connection.cursor()
+    try:
+        first.execute("SELECT v FROM (VALUES (1), (2)) AS t(v)")
+        assert len(first.fetchmany(5)) == 2
+        assert second.execute("SELECT 1").fetchall()[0][0] == 1
+    finally:
+        first.close()
+        second.close()
+
+
+def test_auto_drain_results_property(db_connection):
+    assert db_connection.auto_drain_results is False
+    with pytest.raises(TypeError):
+        db_connection.auto_drain_results = "yes"
+
+    first = db_connection.cursor()
+    second = db_connection.cursor()
+    db_connection.auto_drain_results = True
+    try:
+        first.execute("SELECT v FROM (VALUES (1), (2), (3)) AS t(v)")
+        first.fetchone()
+        with pytest.warns(Warning):
+            assert second.execute("SELECT 42").fetchall()[0][0] == 42
+        assert first.description is None
+    finally:
+        db_connection.auto_drain_results = False
+        first.close()
+        second.close()
@@ -0,0 +1,85 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for cursor.internal_fetch_rows and arraysize-driven fetch batching."""
+
+import pytest
+
+from mssql_python import ddbc_bindings
+from mssql_python.cursor import Cursor
+
+
+class _DetachedCursor(Cursor):
+    """Cursor with only the attributes the batching helpers need."""
+
+    def __init__(self):  # pylint: disable=super-init-not-called
+        self._internal_fetch_rows = None
+        self.arraysize = 1
+        self.closed = True
+
+
+def test_internal_fetch_rows_validation():
+    cursor = _DetachedCursor()
+    assert cursor.internal_fetch_rows is None
+    cursor.internal_fetch_rows = 500
+    assert cursor.internal_fetch_rows == 500
+    cursor.internal_fetch_rows = None
+    for bad in (0, -1, 1.5, True, "10"):
+        with pytest.raises(ValueError):
+            cursor.internal_fetch_rows = bad
+
+
+def test_fetchall_batch_rows_resolution():
+    cursor = _DetachedCursor()
+    # Default arraysize of 1 leaves the choice to the driver layer
+    assert cursor._fetchall_batch_rows() == 0
+    cursor.arraysize = 250
+    assert cursor._fetchall_batch_rows() == 250
+    cursor.internal_fetch_rows = 4000
+    assert cursor._fetchall_batch_rows() == 4000
+
+
+ROWS_QUERY = "SELECT TOP 7 v FROM (VALUES (1),(2),(3),(4),(5),(6),(7),(8)) AS t(v) ORDER BY v"
+
+
+def test_fetchmany_pulls_in_internal_fetch_rows_chunks(cursor, monkeypatch):
+    requested = []
+    original = ddbc_bindings.DDBCSQLFetchMany
+
+    def recording_fetch_many(hstmt, rows, size, *args):
+        requested.append(size)
+        return original(hstmt, rows, size, *args)
+
+    monkeypatch.setattr(ddbc_bindings, "DDBCSQLFetchMany", recording_fetch_many)
+    cursor.internal_fetch_rows = 2
+    try:
+        cursor.execute(ROWS_QUERY)
+        rows = cursor.fetchmany(5)
+        assert [row[0] for row in rows] == [1, 2, 3, 4, 5]
+        assert requested == [2, 2, 1]
+
+        requested.clear()
+        rows = cursor.fetchmany(5)
+        assert [row[0] for row in rows] == [6, 7]
+        # Stops after the first short chunk
+        assert requested == [2, 2]
+    finally:
+        cursor.internal_fetch_rows = None
+
+
+def test_fetchall_passes_fetch_size_hint(cursor, monkeypatch):
+    hints = []
+    original = ddbc_bindings.DDBCSQLFetchAll
+
+    def recording_fetch_all(hstmt, rows, *args):
+        hints.append(args[-1])
+        return original(hstmt, rows, *args)
+
+    monkeypatch.setattr(ddbc_bindings, "DDBCSQLFetchAll", recording_fetch_all)
+    cursor.arraysize = 3
+    try:
+        cursor.execute(ROWS_QUERY)
+        assert [row[0] for row in cursor.fetchall()] == [1, 2, 3, 4, 5, 6, 7]
+        assert hints == [3]
+    finally:
+        cursor.arraysize = 1
@@ -0,0 +1,85 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for Cursor.fetchbatches and its double-buffered prefetch mode."""
+
+import threading
+
+import pytest
+
+from mssql_python.cursor import Cursor
+
+
+class _ScriptedCursor(Cursor):
+    """Cursor whose fetchmany serves rows from a list and records the fetching thread."""
+
+    def __init__(self, rows, fail_after=None):  # pylint: disable=super-init-not-called
+        self._rows = list(rows)
+        self._fail_after = fail_after
+        self.fetch_threads = []
+        self.arraysize = 1
+        self.closed = False
+
+    def __del__(self):
+        pass
+
+    def fetchmany(self, size=None):
+        self.fetch_threads.append(threading.current_thread())
+        if self._fail_after is not None and len(self.fetch_threads) > self._fail_after:
+            raise RuntimeError("network error")
+        batch, self._rows = self._rows[:size], self._rows[size:]
+        return batch
+
+
+@pytest.mark.parametrize("prefetch", [True, False])
+def test_fetchbatches_yields_all_rows(prefetch):
+    cursor = _ScriptedCursor(range(7))
+    batches = list(cursor.fetchbatches(3, prefetch=prefetch))
+    assert batches == [[0, 1, 2], [3, 4, 5], [6]]
+
+
+def test_fetchbatches_prefetch_runs_on_worker_thread():
+    cursor = _ScriptedCursor(range(6))
+    assert list(cursor.fetchbatches(3)) == [[0, 1, 2], [3, 4, 5]]
+    assert all(thread is not threading.current_thread() for thread in cursor.fetch_threads)
+    # The trailing empty fetch detects the end of a result set that divides evenly
+    assert len(cursor.fetch_threads) == 3
+
+
+def test_fetchbatches_propagates_worker_errors():
+    cursor = _ScriptedCursor(range(10), fail_after=1)
+    batches = cursor.fetchbatches(2)
+    assert next(batches) == [0, 1]
+    with pytest.raises(RuntimeError, match="network error"):
+        next(batches)
+
+
+def test_fetchbatches_early_close_waits_for_inflight_fetch():
+    cursor = _ScriptedCursor(range(10))
+    batches = cursor.fetchbatches(2)
+    assert next(batches) == [0, 1]
+    batches.close()
+    # The prefetched batch was consumed from the cursor, nothing else is fetched
+    assert len(cursor.fetch_threads) == 2
+    assert cursor._rows == list(range(4, 10))
+
+
+def test_fetchbatches_defaults_and_validation():
+    cursor = _ScriptedCursor(range(1500))
+    assert [len(b) for b in cursor.fetchbatches(prefetch=False)] == [1000, 500]
+    cursor.arraysize = 50
+    for bad in (0, -1, 2.5, True):
+        with pytest.raises(ValueError):
+            cursor.fetchbatches(bad)
+
+
+def test_fetchbatches_against_server(cursor):
+    cursor.execute(
+        "SELECT TOP 2500 ROW_NUMBER() OVER (ORDER BY (SELECT NULL)) AS n "
+        "FROM sys.all_objects a CROSS JOIN sys.all_objects b"
+    )
+    values = []
+    for batch in cursor.fetchbatches(1000):
+        assert len(batch) <= 1000
+        values.extend(row[0] for row in batch)
+    assert values == list(range(1, 2501))
@@ -0,0 +1,128 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for per-column result transforms (Cursor.add_column_transform)."""
+
+import pytest
+
+from mssql_python import ProgrammingError
+from mssql_python.cursor import Cursor, COLUMN_TRANSFORMS
+from mssql_python.row import Row
+
+
+class _DescribedCursor(Cursor):
+    """Cursor with a fixed description and no statement handle."""
+
+    def __init__(self, description):  # pylint: disable=super-init-not-called
+        self.description = description
+        self._connection = None
+        self._char_column_indices = ()
+        self._narrow_char_collations = ()
+        self.closed = False
+        self._column_transforms = []
+        self._cached_transform_map = None
+
+    def __del__(self):
+        pass
+
+
+DESCRIPTION = [("code", str, None, 10, 10, 0, True), ("qty", int, None, 10, 10, 0, True)]
+
+
+def test_named_transforms_only_touch_strings():
+    assert COLUMN_TRANSFORMS["rstrip"]("ab   ") == "ab"
+    assert COLUMN_TRANSFORMS["rstrip"]("ab\t") == "ab\t"
+    assert COLUMN_TRANSFORMS["upper"]("ab") == "AB"
+    assert COLUMN_TRANSFORMS["strip"](5) == 5
+
+
+def test_transform_map_resolves_names_and_indices_in_order():
+    cursor = _DescribedCursor(DESCRIPTION)
+
+    def double(value):
+        return value * 2
+
+    cursor.add_column_transform("code", "rstrip")
+    cursor.add_column_transform(0, "upper")
+    cursor.add_column_transform(1, double)
+    cursor.add_column_transform("missing", "lower")
+    cursor.add_column_transform(7, "lower")
+    assert cursor._cached_transform_map == (
+        (0, (COLUMN_TRANSFORMS["rstrip"], COLUMN_TRANSFORMS["upper"])),
+        (1, (double,)),
+    )
+
+    row = Row(["ab  ", 3], {"code": 0, "qty": 1}, transform_map=cursor._cached_transform_map)
+    assert tuple(row) == ("AB", 6)
+    row = Row([None, None], {"code": 0, "qty": 1}, transform_map=cursor._cached_transform_map)
+    assert tuple(row) == (None, None)
+
+    cursor.clear_column_transforms()
+    assert cursor._build_transform_map() is None
+
+
+@pytest.mark.parametrize(
+    "column, transform",
+    [("code", "titlecase"), ("code", 42), (-1, "upper"), (True, "upper"), (1.5, "upper")],
+)
+def test_add_column_transform_validation(column, transform):
+    with pytest.raises(ProgrammingError):
+        _DescribedCursor(DESCRIPTION).add_column_transform(column, transform)
+
+
+def test_column_transforms_on_fetched_rows(cursor):
+    cursor.add_column_transform("code", "rstrip")
+    cursor.add_column_transform("name", "upper")
+    try:
+        query = (
+            "SELECT CAST(v AS CHAR(6)) AS code, CAST(v AS NCHAR(5)) AS padded, "
+            "N'x' + v AS name FROM (VALUES ('a'), ('bc'), (NULL)) AS t(v) ORDER BY v DESC"
+        )
+        cursor.execute(query)
+        assert [tuple(row) for row in cursor.fetchall()] == [
+            ("bc", "bc   ", "XBC"),
+            ("a", "a    ", "XA"),
+            (None, None, None),
+        ]
+        cursor.execute(query)
+        assert cursor.fetchone().code == "bc"
+        assert cursor.fetchmany(1)[0].code == "a"
+        cursor.fetchall()
+    finally:
+        cursor.clear_column_transforms()
+
+
+class _PaddingConnection:
+    _rstrip_char = True
+
+
+def test_rstrip_char_targets_fixed_length_columns_first():
+    cursor = _DescribedCursor(None)
+    cursor._connection = _PaddingConnection()
+    cursor._initialize_description(
+        [
+            {"ColumnName": "c", "DataType": 1, "ColumnSize": 5, "DecimalDigits": 0, "Nullable": 1},
+            {"ColumnName": "v", "DataType": 12, "ColumnSize": 5, "DecimalDigits": 0, "Nullable": 1},
+            {"ColumnName": "n", "DataType": -8, "ColumnSize": 5, "DecimalDigits": 0, "Nullable": 1},
+        ]
+    )
+    assert cursor._char_column_indices == (0, 2)
+    cursor.add_column_transform("c", "upper")
+    row = Row(["a  ", "b  ", "c  "], {}, transform_map=cursor._cached_transform_map)
+    assert tuple(row) == ("A", "b  ", "c")
+
+
+def test_rstrip_char_connection_option(db_connection):
+    assert db_connection.rstrip_char is False
+    with pytest.raises(TypeError):
+        db_connection.rstrip_char = 1
+
+    cursor = db_connection.cursor()
+    query = "SELECT CAST('ab' AS CHAR(5)), CAST(N'cd' AS NCHAR(4)), CAST('ef  ' AS VARCHAR(5))"
+    try:
+        assert tuple(cursor.execute(query).fetchall()[0]) == ("ab   ", "cd  ", "ef  ")
+        db_connection.rstrip_char = True
+        assert tuple(cursor.execute(query).fetchall()[0]) == ("ab", "cd", "ef  ")
+    finally:
+        db_connection.rstrip_char = False
+        cursor.close()
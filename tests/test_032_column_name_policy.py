@@ -0,0 +1,83 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for the duplicate_column_names and case_insensitive_columns settings."""
+
+import pytest
+
+import mssql_python
+from mssql_python.cursor import Cursor
+from mssql_python.row import Row
+
+
+def _metadata(*names):
+    return [
+        {"ColumnName": name, "DataType": 4, "ColumnSize": 10, "DecimalDigits": 0, "Nullable": 1}
+        for name in names
+    ]
+
+
+class _DescribedCursor(Cursor):
+    """Cursor without a statement handle, used to build descriptions and column maps."""
+
+    def __init__(self):  # pylint: disable=super-init-not-called
+        self.description = None
+        self._char_column_indices = ()
+
+    def __del__(self):
+        pass
+
+
+@pytest.fixture
+def restore_name_settings():
+    saved = (mssql_python.duplicate_column_names, mssql_python.case_insensitive_columns)
+    yield
+    mssql_python.duplicate_column_names, mssql_python.case_insensitive_columns = saved
+
+
+def test_settings_validation(restore_name_settings):
+    assert mssql_python.duplicate_column_names == "last"
+    assert mssql_python.case_insensitive_columns is False
+    with pytest.raises(ValueError):
+        mssql_python.duplicate_column_names = "error"
+    with pytest.raises(ValueError):
+        mssql_python.case_insensitive_columns = "yes"
+
+
+@pytest.mark.parametrize("policy, expected", [("last", 2), ("first", 0)])
+def test_duplicate_names_resolve_by_policy(restore_name_settings, policy, expected):
+    mssql_python.duplicate_column_names = policy
+    cursor = _DescribedCursor()
+    cursor._initialize_description(_metadata("id", "name", "ID"))
+    column_map, column_map_lower = cursor._build_column_maps()
+    assert column_map["id"] == 0 and column_map["ID"] == 2
+    assert column_map_lower is None
+
+    mssql_python.case_insensitive_columns = True
+    _, column_map_lower = cursor._build_column_maps()
+    assert column_map_lower["id"] == expected
+
+
+def test_suffix_policy_renames_duplicates(restore_name_settings):
+    mssql_python.duplicate_column_names = "suffix"
+    cursor = _DescribedCursor()
+    cursor._initialize_description(_metadata("id", "ID", "id_1", "id", "", ""))
+    assert [desc[0] for desc in cursor.description] == ["id", "ID_2", "id_1", "id_3", "", ""]
+
+
+def test_case_insensitive_row_access_keeps_original_names(restore_name_settings):
+    mssql_python.case_insensitive_columns = True
+    cursor = _DescribedCursor()
+    cursor._initialize_description(_metadata("CustomerId"))
+    column_map, column_map_lower = cursor._build_column_maps()
+    row = Row([7], column_map, column_map_lower=column_map_lower)
+    assert row.customerid == row["CUSTOMERID"] == row.CustomerId == 7
+    assert cursor.description[0][0] == "CustomerId"
+
+
+def test_suffix_policy_against_server(cursor, restore_name_settings):
+    mssql_python.duplicate_column_names = "suffix"
+    cursor.execute("SELECT 1 AS id, 2 AS id, 3 AS Id")
+    row = cursor.fetchall()[0]
+    assert [desc[0] for desc in cursor.description] == ["id", "id_1", "Id_2"]
+    assert (row.id, row.id_1, row.Id_2) == (1, 2, 3)
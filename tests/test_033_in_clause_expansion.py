@@ -0,0 +1,72 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for expansion of ``IN ?`` placeholders bound to Python sequences."""
+
+import json
+import uuid
+
+import pytest
+
+from mssql_python.parameter_helper import expand_in_clauses, is_single_in_placeholder
+
+
+def test_expands_sequences_after_in():
+    sql, params = expand_in_clauses(
+        "SELECT * FROM t WHERE a = ? AND id IN ? AND b NOT IN ?", ["x", [1, 2, 3], (4,)]
+    )
+    assert sql == "SELECT * FROM t WHERE a = ? AND id IN (?, ?, ?) AND b NOT IN (?)"
+    assert params == ["x", 1, 2, 3, 4]
+
+
+def test_ignores_quoted_and_non_in_placeholders():
+    query = "SELECT '? IN ?' AS [in ?], v FROM t WHERE v = ? -- IN ?\n"
+    assert expand_in_clauses(query, [[1, 2]]) == (query, [[1, 2]])
+    query = "SELECT * FROM t JOIN u ON t.id IN ?"
+    assert expand_in_clauses(query, [[1]])[0] == "SELECT * FROM t JOIN u ON t.id IN (?)"
+    # Identifiers ending in "in" are not the IN keyword
+    query = "SELECT * FROM t WHERE login ?"
+    assert expand_in_clauses(query, [[1]]) == (query, [[1]])
+
+
+def test_empty_sequence_matches_nothing():
+    sql, params = expand_in_clauses("SELECT 1 WHERE 1 IN ?", [set()])
+    assert sql == "SELECT 1 WHERE 1 IN (SELECT NULL WHERE 1 = 0)"
+    assert params == []
+
+
+def test_long_sequences_use_a_single_json_parameter():
+    sql, params = expand_in_clauses("SELECT * FROM t WHERE id IN ?", [range(5)], json_threshold=3)
+    assert sql == (
+        "SELECT * FROM t WHERE id IN (SELECT [value] FROM OPENJSON(?) WITH ([value] BIGINT '$'))"
+    )
+    assert json.loads(params[0]) == [0, 1, 2, 3, 4]
+
+    ids = [uuid.UUID(int=i) for i in range(4)]
+    sql, params = expand_in_clauses("SELECT 1 WHERE ? IN ?", ["a", ids], json_threshold=3)
+    assert "UNIQUEIDENTIFIER" in sql
+    assert params[0] == "a" and json.loads(params[1]) == [str(u) for u in ids]
+
+    with pytest.raises(TypeError):
+        expand_in_clauses("SELECT 1 WHERE 1 IN ?", [[1, "a", 2, 3]], json_threshold=3)
+
+
+def test_is_single_in_placeholder():
+    assert is_single_in_placeholder("SELECT * FROM t WHERE id IN ?")
+    assert not is_single_in_placeholder("SELECT * FROM t WHERE id IN ? AND a = ?")
+    assert not is_single_in_placeholder("SELECT * FROM t WHERE id = ?")
+
+
+def test_execute_with_in_lists(cursor):
+    query = "SELECT v FROM (VALUES (1), (2), (3), (4)) AS t(v) WHERE v IN ? ORDER BY v"
+    assert [row[0] for row in cursor.execute(query, [2, 4]).fetchall()] == [2, 4]
+    assert [row[0] for row in cursor.execute(query, ([1, 3],)).fetchall()] == [1, 3]
+    assert [row[0] for row in cursor.execute(query, (3,)).fetchall()] == [3]
+    assert cursor.execute(query, []).fetchall() == []
+
+    query = "SELECT v FROM (VALUES (1), (2), (3)) AS t(v) WHERE v NOT IN ? AND v > ?"
+    assert [row[0] for row in cursor.execute(query, {1}, 0).fetchall()] == [2, 3]
+
+    many = list(range(5000))
+    query = "SELECT COUNT(*) FROM sys.all_objects WHERE object_id IN ? OR 1 IN ?"
+    assert cursor.execute(query, many, many).fetchall()[0][0] > 0
@@ -0,0 +1,81 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for literal rendering of parameters (Cursor.mogrify)."""
+
+import datetime
+import decimal
+import uuid
+
+import pytest
+
+from mssql_python import ProgrammingError
+from mssql_python.cursor import Cursor
+from mssql_python.parameter_helper import sql_literal, substitute_literals
+
+
+class _OpenCursor(Cursor):
+    """Cursor without a connection; mogrify never touches the server."""
+
+    def __init__(self):  # pylint: disable=super-init-not-called
+        self.closed = False
+
+    def __del__(self):
+        pass
+
+
+@pytest.mark.parametrize(
+    "value, literal",
+    [
+        (None, "NULL"),
+        (True, "1"),
+        (-42, "-42"),
+        (1.5, "1.5"),
+        (decimal.Decimal("1E+3"), "1000"),
+        ("it's", "N'it''s'"),
+        (b"\x00\xab", "0x00AB"),
+        (b"", "0x"),
+        (datetime.date(2024, 2, 29), "CAST('2024-02-29' AS DATE)"),
+        (datetime.time(13, 5, 0, 250), "CAST('13:05:00.000250' AS TIME)"),
+        (datetime.datetime(2024, 1, 2, 3, 4, 5), "CAST('2024-01-02T03:04:05' AS DATETIME2)"),
+        (
+            datetime.datetime(2024, 1, 2, tzinfo=datetime.timezone.utc),
+            "CAST('2024-01-02T00:00:00+00:00' AS DATETIMEOFFSET)",
+        ),
+        (uuid.UUID(int=1), "CAST('00000000-0000-0000-0000-000000000001' AS UNIQUEIDENTIFIER)"),
+    ],
+)
+def test_sql_literal(value, literal):
+    assert sql_literal(value) == literal
+
+
+def test_sql_literal_rejects_unrepresentable_values():
+    with pytest.raises(ValueError):
+        sql_literal(float("nan"))
+    with pytest.raises(TypeError):
+        sql_literal(object())
+
+
+def test_substitute_literals_skips_quoted_markers():
+    sql = "SELECT '?' AS [q?], ? -- ?\n, ?"
+    assert substitute_literals(sql, [1, "x"]) == "SELECT '?' AS [q?], 1 -- ?\n, N'x'"
+    with pytest.raises(ValueError):
+        substitute_literals("SELECT ?, ?", [1])
+
+
+def test_mogrify_accepts_execute_parameter_styles():
+    cursor = _OpenCursor()
+    assert cursor.mogrify("SELECT ?, ?", 1, "a") == "SELECT 1, N'a'"
+    assert cursor.mogrify("SELECT ?, ?", (1, "a")) == "SELECT 1, N'a'"
+    assert cursor.mogrify("SELECT %(x)s, %(x)s", {"x": None}) == "SELECT NULL, NULL"
+    assert cursor.mogrify("SELECT 1 WHERE 2 IN ?", [1, 2]) == "SELECT 1 WHERE 2 IN (1, 2)"
+    assert cursor.mogrify("SELECT 1") == "SELECT 1"
+    with pytest.raises(ProgrammingError):
+        cursor.mogrify("SELECT ?, ?", 1)
+
+
+def test_mogrified_statement_runs_like_parameterized_one(cursor):
+    params = ("O'Neil", decimal.Decimal("12.50"), b"\x01\x02", datetime.date(2024, 5, 1), None)
+    query = "SELECT ?, ?, ?, ?, ?"
+    expected = tuple(cursor.execute(query, params).fetchall()[0])
+    assert tuple(cursor.execute(cursor.mogrify(query, params)).fetchall()[0]) == expected
@@ -0,0 +1,71 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for execution plan capture (execute(..., capture_plan=...) and cursor.plan)."""
+
+import pytest
+
+from mssql_python import ProgrammingError
+from mssql_python.cursor import Cursor
+
+QUERY = "SELECT v FROM (VALUES (1), (2), (3)) AS t(v) WHERE v > ?"
+
+
+class _OpenCursor(Cursor):
+    """Cursor without a connection, for argument validation."""
+
+    def __init__(self):  # pylint: disable=super-init-not-called
+        self.closed = False
+        self.description = None
+        self._retry_policy = None
+        self._capture_wait_stats = False
+        self._measuring_waits = False
+
+    def __del__(self):
+        pass
+
+
+def test_invalid_capture_plan_is_rejected():
+    with pytest.raises(ProgrammingError):
+        _OpenCursor().execute("SELECT 1", capture_plan="live")
+
+
+def test_plan_result_set_detection():
+    cursor = _OpenCursor()
+    assert not cursor._is_plan_result_set()
+    cursor.description = [("Microsoft SQL Server 2005 XML Showplan", str) + (None,) * 5]
+    assert cursor._is_plan_result_set()
+    cursor.description = [("v", int) + (None,) * 5]
+    assert not cursor._is_plan_result_set()
+
+
+def test_estimated_plan_does_not_run_the_statement(cursor):
+    cursor.execute(QUERY, 1, capture_plan="estimated")
+    assert cursor.plan is not None and "<ShowPlanXML" in cursor.plan
+    assert cursor.description is None
+
+    # SHOWPLAN_XML is switched off again
+    assert [row[0] for row in cursor.execute(QUERY, 1).fetchall()] == [2, 3]
+    assert cursor.plan is None
+
+
+def test_actual_plan_for_row_returning_statement(cursor):
+    cursor.execute(QUERY, 1, capture_plan="actual")
+    assert [row[0] for row in cursor.fetchall()] == [2, 3]
+    assert cursor.nextset() is False
+    assert "<ShowPlanXML" in cursor.plan
+    assert "RunTimeInformation" in cursor.plan
+
+    # STATISTICS XML is switched off again: no extra result set
+    cursor.execute(QUERY, 1).fetchall()
+    assert cursor.nextset() is False
+
+
+def test_actual_plan_for_statement_without_rows(cursor):
+    cursor.execute("CREATE TABLE #plan_capture (v INT)")
+    try:
+        cursor.execute("INSERT INTO #plan_capture VALUES (?), (?)", 1, 2, capture_plan="actual")
+        assert cursor.rowcount == 2
+        assert "<ShowPlanXML" in cursor.plan
+    finally:
+        cursor.execute("DROP TABLE #plan_capture")
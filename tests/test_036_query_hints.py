@@ -0,0 +1,91 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for per-execution query hints (execute(..., hints=[...]))."""
+
+import pytest
+
+from mssql_python import ProgrammingError
+from mssql_python.cursor import Cursor
+from mssql_python.parameter_helper import apply_query_hints
+
+
+def test_hints_are_normalized_into_one_option_clause():
+    sql = apply_query_hints(
+        "SELECT * FROM t -- trailing comment\n;",
+        ["recompile", "maxdop   4", "max_grant_percent=12.5", "DISABLE_OPTIMIZER_ROWGOAL"],
+    )
+    assert sql == (
+        "SELECT * FROM t -- trailing comment\n"
+        "OPTION (RECOMPILE, MAXDOP 4, MAX_GRANT_PERCENT = 12.5, "
+        "USE HINT('DISABLE_OPTIMIZER_ROWGOAL'))"
+    )
+
+
+def test_use_hint_names_are_combined():
+    sql = apply_query_hints(
+        "SELECT 1",
+        [
+            "USE HINT('ENABLE_QUERY_OPTIMIZER_HOTFIXES', 'DISABLE_OPTIMIZER_ROWGOAL')",
+            "ENABLE_QUERY_OPTIMIZER_HOTFIXES",
+        ],
+    )
+    assert sql == (
+        "SELECT 1\nOPTION (USE HINT('ENABLE_QUERY_OPTIMIZER_HOTFIXES', "
+        "'DISABLE_OPTIMIZER_ROWGOAL'))"
+    )
+
+
+def test_hints_merge_into_existing_option_clause():
+    sql = apply_query_hints("SELECT * FROM t WHERE v IN (1, 2) OPTION (FAST 10)", ["RECOMPILE"])
+    assert sql == "SELECT * FROM t WHERE v IN (1, 2) OPTION (FAST 10, RECOMPILE)"
+    sql = apply_query_hints("SELECT * FROM t WHERE v IN (1, 2)", ["RECOMPILE"])
+    assert sql == "SELECT * FROM t WHERE v IN (1, 2)\nOPTION (RECOMPILE)"
+
+
+@pytest.mark.parametrize(
+    "hint",
+    [
+        "RECOMPILE); DROP TABLE t; --",
+        "MAXDOP -1",
+        "USE HINT('x''); DROP TABLE t')",
+        "TABLE HINT(t, INDEX(0))",
+        "NOLOCK",
+        42,
+    ],
+)
+def test_unsupported_hints_are_rejected(hint):
+    with pytest.raises(ValueError):
+        apply_query_hints("SELECT 1", [hint])
+
+
+class _UnsentCursor(Cursor):
+    """Cursor that fails if a statement gets past execute()'s checks."""
+
+    def __init__(self):  # pylint: disable=super-init-not-called
+        self._connection = type(
+            "_Connection",
+            (),
+            {"_auto_parameterize": False, "_read_only": False, "_statement_policy": None},
+        )()
+        self._retry_policy = None
+        self._capture_wait_stats = False
+
+    def __del__(self):
+        pass
+
+    def _execute_capturing_plan(self, *args):
+        raise AssertionError("statement was sent")
+
+
+def test_execute_rejects_unsupported_hint_before_sending():
+    with pytest.raises(ProgrammingError):
+        _UnsentCursor().execute("SELECT 1", hints=["NOLOCK"], capture_plan="estimated")
+
+
+def test_execute_with_hints(cursor):
+    query = "SELECT v FROM (VALUES (1), (2), (3)) AS t(v) WHERE v > ? ORDER BY v"
+    rows = cursor.execute(query, 1, hints=["RECOMPILE", "MAXDOP 1"]).fetchall()
+    assert [row[0] for row in rows] == [2, 3]
+    with pytest.raises(ProgrammingError):
+        cursor.execute(query, 1, hints=["NOLOCK"])
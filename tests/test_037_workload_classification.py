@@ -0,0 +1,44 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for workload tagging used by Resource Governor classifier functions."""
+
+import pytest
+
+from mssql_python import connect
+from mssql_python.connection import Connection, _application_name, _validate_workload
+
+
+def test_application_name_carries_workload_tag():
+    assert _application_name(None) == "MSSQL-Python"
+    assert _application_name("reporting") == "MSSQL-Python (reporting)"
+
+
+@pytest.mark.parametrize("workload", ["", "   ", "a;APP=x", "{x}", "x" * 200, 5])
+def test_invalid_workload_is_rejected(workload):
+    with pytest.raises(ValueError):
+        _validate_workload(workload)
+
+
+def test_workload_is_part_of_connection_string():
+    connection = Connection.__new__(Connection)
+    connection._workload = "nightly-etl"
+    conn_str, params = connection._construct_connection_string("Server=localhost")
+    assert params["APP"] == "MSSQL-Python (nightly-etl)"
+    assert "APP={MSSQL-Python (nightly-etl)}" in conn_str
+
+
+def test_workload_tagged_session(conn_str):
+    connection = connect(conn_str, workload="reporting")
+    try:
+        assert connection.workload == "reporting"
+        cursor = connection.cursor()
+        assert cursor.execute("SELECT APP_NAME()").fetchall()[0][0] == "MSSQL-Python (reporting)"
+        cursor.close()
+        try:
+            group = connection.workload_group()
+        except Exception:  # pylint: disable=broad-exception-caught
+            pytest.skip("VIEW SERVER STATE is required to read the workload group")
+        assert group is None or isinstance(group, str)
+    finally:
+        connection.close()
@@ -0,0 +1,57 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for progress reporting of long-running statements (execute_with_progress)."""
+
+import pytest
+
+from mssql_python import ProgressEvent
+from mssql_python.progress import _ProgressReporter, parse_progress_message
+
+
+def test_parse_progress_message():
+    assert (
+        parse_progress_message("[Microsoft][ODBC Driver 18][SQL Server]10 percent processed.")
+        == 10.0
+    )
+    assert parse_progress_message("Processed 360 pages for database 'x'") is None
+
+
+def test_reporter_keeps_percentages_monotonic():
+    events = []
+    reporter = _ProgressReporter(events.append)
+    reporter.report(20.0, None, "poll")
+    reporter.report(20.0, None, "poll")  # unchanged poll result is dropped
+    reporter.report(10.0, "10 percent processed.", "message")  # late message keeps its text
+    reporter.report(None, "Processed 8 pages.", "message")
+    reporter.report(100.0, None, "complete")
+    assert [event.to_dict() for event in events] == [
+        {"percent": 20.0, "message": None, "source": "poll"},
+        {"percent": None, "message": "10 percent processed.", "source": "message"},
+        {"percent": None, "message": "Processed 8 pages.", "source": "message"},
+        {"percent": 100.0, "message": None, "source": "complete"},
+    ]
+    assert isinstance(events[0], ProgressEvent)
+
+
+@pytest.mark.parametrize("kwargs", [{"callback": None}, {"poll_interval": 0}])
+def test_execute_with_progress_validates_arguments(kwargs):
+    from mssql_python.progress import execute_with_progress
+
+    arguments = {"callback": lambda event: None, "poll_interval": 1.0}
+    arguments.update(kwargs)
+    with pytest.raises((TypeError, ValueError)):
+        execute_with_progress(None, "SELECT 1", **arguments)
+
+
+def test_execute_with_progress_reports_messages(db_connection):
+    events = []
+    messages = db_connection.execute_with_progress(
+        "PRINT '50 percent processed.'; SELECT 1; PRINT 'done'",
+        events.append,
+        poll_interval=None,
+    )
+    assert [event.source for event in events] == ["message", "message", "complete"]
+    assert events[0].percent == 50.0
+    assert events[-1].percent == 100.0
+    assert any("done" in message[1] for message in messages)
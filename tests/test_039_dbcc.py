@@ -0,0 +1,61 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for DBCC output capture (Cursor.execute_dbcc)."""
+
+import pytest
+
+from mssql_python import ProgrammingError
+from mssql_python.dbcc import message_text, with_tableresults
+
+
+@pytest.mark.parametrize(
+    "command, expected",
+    [
+        ("DBCC CHECKDB(N'db');", "DBCC CHECKDB(N'db') WITH TABLERESULTS"),
+        (
+            "dbcc checktable('t') WITH NO_INFOMSGS",
+            "dbcc checktable('t') WITH NO_INFOMSGS, TABLERESULTS",
+        ),
+        ("DBCC CHECKDB WITH TABLERESULTS", "DBCC CHECKDB WITH TABLERESULTS"),
+        ("DBCC SQLPERF(LOGSPACE)", "DBCC SQLPERF(LOGSPACE)"),
+        ("SELECT 1", "SELECT 1"),
+    ],
+)
+def test_with_tableresults(command, expected):
+    assert with_tableresults(command) == expected
+
+
+def test_message_text_strips_driver_prefix():
+    message = "[Microsoft][ODBC Driver 18 for SQL Server][SQL Server]DBCC execution completed."
+    assert message_text(message) == "DBCC execution completed."
+
+
+def test_execute_dbcc_rejects_other_statements(cursor):
+    with pytest.raises(ProgrammingError):
+        cursor.execute_dbcc("SELECT 1")
+
+
+def test_execute_dbcc_result_set_and_messages(cursor):
+    results = cursor.execute_dbcc("DBCC SQLPERF(LOGSPACE)")
+    assert "Database Name" in results[0].columns
+    assert len(results[0]) > 0
+    assert results[-1].is_messages
+    assert any("DBCC execution completed" in row.message for row in results[-1].rows)
+    # Nothing is left pending on the cursor
+    assert cursor.execute("SELECT 1").fetchall()[0][0] == 1
+
+
+def test_execute_dbcc_message_only_output(cursor):
+    results = cursor.execute_dbcc("DBCC CHECKCATALOG", tableresults=False)
+    assert len(results) == 1 and results[0].is_messages
+    assert results[0].columns == ["message"]
+    assert any("DBCC execution completed" in row[0] for row in results[0].rows)
+
+
+def test_execute_dbcc_option_rows(cursor):
+    results = cursor.execute_dbcc("DBCC USEROPTIONS")
+    assert results[0].columns == ["Set Option", "Value"]
+    options = {row[0] for row in results[0].rows}
+    assert "textsize" in options
+    assert results[0].to_dict()["rows"][0].keys() == {"Set Option", "Value"}
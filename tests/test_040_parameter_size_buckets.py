@@ -0,0 +1,88 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""
+Tests for stable_parameter_sizes: string and binary parameters declared with
+bucketed sizes so sp_executesql/sp_prepexec declarations stay plan-cache friendly.
+"""
+
+import pytest
+
+from mssql_python.constants import ConstantsDDBC
+from mssql_python.cursor import Cursor, _bucket_parameter_size
+
+
+class _FakeConnection:
+    def __init__(self, stable=False):
+        self._stable_parameter_sizes = stable
+
+
+class _UnboundCursor(Cursor):
+    """Cursor with only the attributes parameter type inference needs."""
+
+    def __init__(self, connection):  # pylint: disable=super-init-not-called
+        self._connection = connection
+        self._inputsizes = None
+
+    def __del__(self):
+        pass
+
+
+class _ParamInfo:
+    """Stand-in for ddbc_bindings.ParamInfo."""
+
+
+def _param_info(connection, value):
+    cursor = _UnboundCursor(connection)
+    return cursor._create_parameter_types_list(value, _ParamInfo, [value], 0)
+
+
+def _column_size(connection, value):
+    return _param_info(connection, value).columnSize
+
+
+@pytest.mark.parametrize(
+    "size, expected", [(1, 64), (64, 64), (65, 256), (300, 1024), (1025, 4000), (4000, 4000)]
+)
+def test_string_sizes_round_up_to_bucket(size, expected):
+    assert _bucket_parameter_size(ConstantsDDBC.SQL_WVARCHAR.value, size) == expected
+    assert _bucket_parameter_size(ConstantsDDBC.SQL_VARCHAR.value, size) == expected
+
+
+def test_binary_and_other_types():
+    assert _bucket_parameter_size(ConstantsDDBC.SQL_VARBINARY.value, 5000) == 8000
+    assert _bucket_parameter_size(ConstantsDDBC.SQL_INTEGER.value, 4) == 4
+
+
+def test_inferred_sizes_are_bucketed_only_when_enabled():
+    assert _column_size(_FakeConnection(), "Smith") == 5
+    assert _column_size(_FakeConnection(stable=True), "Smith") == 64
+    assert _column_size(_FakeConnection(stable=True), "Johnson") == 64
+    assert _column_size(_FakeConnection(stable=True), b"\x00" * 100) == 256
+
+
+def test_large_values_keep_data_at_execution_binding():
+    info = _param_info(_FakeConnection(stable=True), "x" * 5000)
+    assert info.isDAE
+    assert info.columnSize == 0
+
+
+def test_stable_parameter_sizes_property(db_connection):
+    assert db_connection.stable_parameter_sizes is False
+    with pytest.raises(TypeError):
+        db_connection.stable_parameter_sizes = 1
+
+
+def test_stable_parameter_sizes_against_server(db_connection):
+    cursor = db_connection.cursor()
+    db_connection.stable_parameter_sizes = True
+    try:
+        for name in ("Smith", "Johnson", "Ng"):
+            cursor.execute("SELECT ?, SQL_VARIANT_PROPERTY(?, 'MaxLength')", name, name)
+            value, max_length = cursor.fetchall()[0]
+            assert value == name
+            # nvarchar(64) reports its maximum length in bytes
+            assert max_length == 128
+    finally:
+        db_connection.stable_parameter_sizes = False
+        cursor.close()
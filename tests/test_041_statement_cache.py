@@ -0,0 +1,166 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""
+Tests for the connection statement cache: prepared statement handles reused across
+cursors and executions of identical SQL, with an LRU limit and stale invalidation.
+"""
+
+import pickle
+from collections import OrderedDict
+
+import pytest
+
+from mssql_python import ProgrammingError, ddbc_bindings
+from mssql_python.connection import Connection
+from mssql_python.cursor import Cursor
+from mssql_python.exceptions import raise_exception
+
+
+class _FakeHandle:
+    def __init__(self, name):
+        self.name = name
+        self.freed = False
+
+    def free(self):
+        self.freed = True
+
+
+class _FakeSqlConnection:
+    def __init__(self):
+        self.allocated = 0
+
+    def alloc_statement_handle(self):
+        self.allocated += 1
+        return _FakeHandle(f"new-{self.allocated}")
+
+
+def _connection(size):
+    connection = Connection.__new__(Connection)
+    connection._closed = False
+    connection._conn = _FakeSqlConnection()
+    connection._statement_cache = OrderedDict()
+    connection.statement_cache_size = size
+    return connection
+
+
+class _PreparedCursor(Cursor):
+    """Cursor holding a fake handle prepared for sql."""
+
+    def __init__(self, connection, sql=None):  # pylint: disable=super-init-not-called
+        self._connection = connection
+        self.hstmt = _FakeHandle(sql)
+        self.is_stmt_prepared = [sql is not None]
+        self._prepared_sql = sql
+        self._timeout = 0
+
+    def __del__(self):
+        pass
+
+
+@pytest.fixture(autouse=True)
+def _reset_stmt_succeeds(monkeypatch):
+    monkeypatch.setattr(ddbc_bindings, "DDBCSQLResetStmt", lambda hstmt: 0, raising=False)
+
+
+def test_switching_sql_caches_handle_for_other_cursors():
+    connection = _connection(4)
+    first = _PreparedCursor(connection, "SELECT ?")
+    handle = first.hstmt
+    assert first._swap_statement_handle("SELECT ?, ?", reuse=True) is False
+    assert first.hstmt.name == "new-1"
+    assert not handle.freed
+
+    second = _PreparedCursor(connection)
+    assert second._swap_statement_handle("SELECT ?", reuse=True) is True
+    assert second.hstmt is handle
+    assert second.is_stmt_prepared == [True]
+    assert second._prepared_sql == "SELECT ?"
+    assert "SELECT ?" not in connection._statement_cache
+
+
+def test_cache_disabled_by_default():
+    connection = _connection(0)
+    cursor = _PreparedCursor(connection, "SELECT ?")
+    handle = cursor.hstmt
+    assert cursor._swap_statement_handle("SELECT 1", reuse=False) is False
+    assert cursor.hstmt is handle
+    assert not connection._statement_cache
+
+
+def test_lru_limit_frees_oldest_handles():
+    connection = _connection(2)
+    handles = [_FakeHandle(n) for n in range(3)]
+    for n, handle in enumerate(handles):
+        assert connection._cache_statement(f"SELECT {n}, ?", handle, 0)
+    assert handles[0].freed
+    assert list(connection._statement_cache) == ["SELECT 1, ?", "SELECT 2, ?"]
+
+    connection.statement_cache_size = 1
+    assert handles[1].freed and not handles[2].freed
+    connection.clear_statement_cache()
+    assert handles[2].freed and not connection._statement_cache
+
+
+def test_statement_cache_size_validation():
+    connection = _connection(0)
+    for bad in (-1, 1.5, True, "8"):
+        with pytest.raises(ValueError):
+            connection.statement_cache_size = bad
+
+
+def test_close_hands_prepared_handle_to_connection():
+    connection = _connection(4)
+    cursor = _PreparedCursor(connection, "SELECT ?")
+    handle = cursor.hstmt
+    cursor.closed = False
+    cursor.messages = []
+    cursor._rownumber = -1
+    cursor.close()
+    assert connection._statement_cache["SELECT ?"] == (handle, 0)
+    assert not handle.freed
+
+
+def test_stale_statement_errors_clear_cache():
+    connection = _connection(4)
+    handle = _FakeHandle("cached")
+    connection._cache_statement("SELECT ?", handle, 0)
+    cursor = _PreparedCursor(connection)
+    with pytest.raises(ProgrammingError) as exc_info:
+        raise_exception("42S02", "Invalid object name 'dbo.t'.", 208)
+    assert cursor._invalidate_stale_statements(exc_info.value)
+    assert handle.freed and not connection._statement_cache
+
+    connection._cache_statement("SELECT ?", _FakeHandle("cached"), 0)
+    with pytest.raises(ProgrammingError) as exc_info:
+        raise_exception("42000", "Incorrect syntax near 'x'.", 102)
+    assert not cursor._invalidate_stale_statements(exc_info.value)
+    assert connection._statement_cache
+
+
+def test_native_error_survives_pickling():
+    with pytest.raises(ProgrammingError) as exc_info:
+        raise_exception("42S02", "Invalid object name 'dbo.t'.", 208)
+    assert exc_info.value.native_error == 208
+    assert pickle.loads(pickle.dumps(exc_info.value)).native_error == 208
+
+
+def test_statement_cache_against_server(db_connection):
+    db_connection.statement_cache_size = 8
+    try:
+        first = db_connection.cursor()
+        assert first.execute("SELECT ? + 1", 1).fetchall()[0][0] == 2
+        handle = first.hstmt
+        first.close()
+        assert "SELECT ? + 1" in db_connection._statement_cache
+
+        second = db_connection.cursor()
+        assert second.execute("SELECT ? + 1", 41).fetchall()[0][0] == 42
+        assert second.hstmt is handle
+        # Moving on to other SQL hands the prepared handle back to the cache
+        second.execute("SELECT 1").fetchall()
+        assert "SELECT ? + 1" in db_connection._statement_cache
+        second.close()
+    finally:
+        db_connection.statement_cache_size = 0
+    assert not db_connection._statement_cache
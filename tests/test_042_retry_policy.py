@@ -0,0 +1,191 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for RetryPolicy and the cursor-level retry of transient errors."""
+
+import pytest
+
+from mssql_python import OperationalError, RetryPolicy, connect
+from mssql_python import retry
+from mssql_python.cursor import Cursor
+from mssql_python.exceptions import raise_exception
+
+
+def _error(sqlstate, native_error):
+    try:
+        raise_exception(sqlstate, "[Microsoft][SQL Server]transient failure", native_error)
+    except Exception as e:  # pylint: disable=broad-exception-caught
+        return e
+
+
+DEADLOCK = _error("40001", 1205)
+LOCK_TIMEOUT = _error("HY000", 1222)
+QUERY_TIMEOUT = _error("HYT00", 0)
+
+
+@pytest.fixture(autouse=True)
+def _no_sleep(monkeypatch):
+    delays = []
+    monkeypatch.setattr(retry.time, "sleep", delays.append)
+    return delays
+
+
+def test_policy_validation():
+    for kwargs in (
+        {"max_attempts": 0},
+        {"max_attempts": True},
+        {"backoff": -1},
+        {"max_backoff": "1"},
+        {"errors": ["1205"]},
+    ):
+        with pytest.raises(ValueError):
+            RetryPolicy(**kwargs)
+
+
+def test_default_errors_and_timeout_number():
+    policy = RetryPolicy()
+    assert policy.errors == {1205, 1222, -2}
+    assert retry.error_number(QUERY_TIMEOUT) == retry.QUERY_TIMEOUT
+    assert all(policy.is_retryable(e) for e in (DEADLOCK, LOCK_TIMEOUT, QUERY_TIMEOUT))
+    assert not policy.is_retryable(_error("23000", 2627))
+    assert not policy.is_retryable(ValueError("not a driver error"))
+
+
+def test_deadlock_not_retried_inside_transaction():
+    policy = RetryPolicy()
+    assert not policy.is_retryable(DEADLOCK, in_transaction=True)
+    assert policy.is_retryable(LOCK_TIMEOUT, in_transaction=True)
+
+
+def test_exponential_backoff_is_capped():
+    policy = RetryPolicy(backoff=0.5, max_backoff=3.0, jitter=False)
+    assert [policy.delay(n) for n in (1, 2, 3, 4)] == [0.5, 1.0, 2.0, 3.0]
+    jittered = RetryPolicy(backoff=1.0).delay(1)
+    assert 0.5 <= jittered <= 1.0
+
+
+def test_call_retries_until_success(_no_sleep):
+    failures = [DEADLOCK, LOCK_TIMEOUT]
+
+    def flaky():
+        if failures:
+            raise failures.pop(0)
+        return "done"
+
+    policy = RetryPolicy(max_attempts=3, backoff=0.1, jitter=False)
+    assert policy.call(flaky) == "done"
+    assert _no_sleep == [0.1, 0.2]
+
+
+def test_call_gives_up_after_max_attempts():
+    attempts = []
+
+    def always_deadlocked():
+        attempts.append(1)
+        raise DEADLOCK
+
+    with pytest.raises(OperationalError):
+        RetryPolicy(max_attempts=2).call(always_deadlocked)
+    assert len(attempts) == 2
+
+
+class _PlanCapturingCursor(Cursor):
+    """Cursor whose execution (via capture_plan) fails with scripted errors first."""
+
+    def __init__(self, failures, autocommit=True):  # pylint: disable=super-init-not-called
+        self._failures = list(failures)
+        self._connection = type(
+            "_Connection",
+            (),
+            {
+                "autocommit": autocommit,
+                "_auto_parameterize": False,
+                "_read_only": False,
+                "_statement_policy": None,
+            },
+        )()
+        self._retry_policy = None
+        self._retrying = False
+        self._capture_wait_stats = False
+        self._measuring_waits = False
+        self.calls = []
+
+    def __del__(self):
+        pass
+
+    def _execute_capturing_plan(self, operation, parameters, *args):
+        self.calls.append((operation, parameters))
+        if self._failures:
+            raise self._failures.pop(0)
+        return self
+
+
+def test_cursor_execute_retries_with_same_parameters():
+    cursor = _PlanCapturingCursor([DEADLOCK, QUERY_TIMEOUT])
+    cursor.retry_policy = RetryPolicy(max_attempts=3)
+    assert cursor.execute("UPDATE t SET v = ?", 7, capture_plan="estimated") is cursor
+    assert cursor.calls == [("UPDATE t SET v = ?", (7,))] * 3
+    assert cursor._retrying is False
+
+
+def test_cursor_retries_repeat_only_the_execution():
+    cursor = _PlanCapturingCursor([DEADLOCK])
+    checked = []
+    cursor._connection._statement_policy = checked.append
+    cursor.retry_policy = RetryPolicy(max_attempts=2)
+    cursor.execute("SELECT 1", capture_plan="estimated", hints=["RECOMPILE"])
+    assert checked == ["SELECT 1"]
+    assert cursor.calls == [("SELECT 1\nOPTION (RECOMPILE)", ())] * 2
+
+
+def test_cursor_without_policy_or_in_transaction_raises():
+    cursor = _PlanCapturingCursor([DEADLOCK])
+    with pytest.raises(OperationalError):
+        cursor.execute("SELECT 1", capture_plan="estimated")
+    assert len(cursor.calls) == 1
+
+    cursor = _PlanCapturingCursor([DEADLOCK], autocommit=False)
+    cursor.retry_policy = RetryPolicy()
+    with pytest.raises(OperationalError):
+        cursor.execute("SELECT 1", capture_plan="estimated")
+    assert len(cursor.calls) == 1
+
+    with pytest.raises(TypeError):
+        cursor.retry_policy = 3
+
+
+def test_lock_timeout_is_retried_against_server(conn_str):
+    class _RecordingPolicy(RetryPolicy):
+        def __init__(self):
+            super().__init__(max_attempts=2)
+            self.delays = []
+
+        def delay(self, attempt):
+            self.delays.append(attempt)
+            return 0
+
+    holder = connect(conn_str, autocommit=False)
+    waiter = connect(conn_str, autocommit=True)
+    try:
+        holder_cursor = holder.cursor()
+        holder_cursor.execute("CREATE TABLE ##retry_policy_test (id INT PRIMARY KEY, v INT)")
+        holder.commit()
+        holder_cursor.execute("INSERT INTO ##retry_policy_test VALUES (1, 0)")
+
+        cursor = waiter.cursor()
+        cursor.execute("SET LOCK_TIMEOUT 100")
+        policy = _RecordingPolicy()
+        cursor.retry_policy = policy
+        with pytest.raises(Exception) as exc_info:
+            cursor.execute("SELECT v FROM ##retry_policy_test WHERE id = ?", 1)
+        assert exc_info.value.native_error == 1222
+        assert policy.delays == [1]
+
+        holder.rollback()
+        assert cursor.execute("SELECT COUNT(*) FROM ##retry_policy_test").fetchall()[0][0] == 0
+    finally:
+        holder.rollback()
+        holder.cursor().execute("DROP TABLE IF EXISTS ##retry_policy_test")
+        holder.commit()
+        waiter.close()
+        holder.close()
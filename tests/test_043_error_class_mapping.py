@@ -0,0 +1,87 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for registering exception classes for native SQL Server error numbers."""
+
+import pytest
+
+from mssql_python import (
+    IntegrityError,
+    OperationalError,
+    register_error_class,
+    unregister_error_class,
+)
+from mssql_python.exceptions import raise_exception
+
+DUPLICATE_KEY_MESSAGE = (
+    "[Microsoft][ODBC Driver 18 for SQL Server][SQL Server]Violation of PRIMARY KEY "
+    "constraint 'PK_t'. Cannot insert duplicate key in object 'dbo.t'."
+)
+
+
+class DuplicateKeyError(IntegrityError):
+    """Application exception for unique index and constraint violations."""
+
+
+@pytest.fixture(autouse=True)
+def _clear_registrations():
+    yield
+    unregister_error_class([2601, 2627, 1205])
+
+
+def test_registered_class_is_raised_with_error_details():
+    register_error_class([2601, 2627], DuplicateKeyError)
+    with pytest.raises(DuplicateKeyError) as exc_info:
+        raise_exception("23000", DUPLICATE_KEY_MESSAGE, 2627)
+    error = exc_info.value
+    assert error.native_error == 2627
+    assert error.sqlstate == "23000"
+    assert error.driver_error == "Integrity constraint violation"
+    assert "Cannot insert duplicate key" in error.ddbc_error
+
+
+def test_unregistered_numbers_keep_default_mapping():
+    register_error_class(2627, DuplicateKeyError)
+    with pytest.raises(IntegrityError) as exc_info:
+        raise_exception("23000", "Cannot insert the value NULL", 515)
+    assert type(exc_info.value) is IntegrityError
+
+    unregister_error_class(2627)
+    with pytest.raises(IntegrityError) as exc_info:
+        raise_exception("23000", DUPLICATE_KEY_MESSAGE, 2627)
+    assert type(exc_info.value) is IntegrityError
+
+
+def test_later_registration_replaces_earlier():
+    class DeadlockError(OperationalError):
+        pass
+
+    class RetryableError(OperationalError):
+        pass
+
+    register_error_class(1205, DeadlockError)
+    register_error_class(1205, RetryableError)
+    with pytest.raises(RetryableError):
+        raise_exception("40001", "Transaction was deadlocked", 1205)
+
+
+def test_registration_validation():
+    with pytest.raises(TypeError):
+        register_error_class(2627, ValueError)
+    with pytest.raises(TypeError):
+        register_error_class(2627, DuplicateKeyError("x", "y"))
+    for bad in ([], ["2627"], [True], 26.27):
+        with pytest.raises((ValueError, TypeError)):
+            register_error_class(bad, DuplicateKeyError)
+
+
+def test_duplicate_key_raises_registered_class(cursor):
+    register_error_class([2601, 2627], DuplicateKeyError)
+    cursor.execute("CREATE TABLE #error_class_test (id INT PRIMARY KEY)")
+    try:
+        cursor.execute("INSERT INTO #error_class_test VALUES (?)", 1)
+        with pytest.raises(DuplicateKeyError) as exc_info:
+            cursor.execute("INSERT INTO #error_class_test VALUES (?)", 1)
+        assert exc_info.value.native_error == 2627
+    finally:
+        cursor.execute("DROP TABLE #error_class_test")
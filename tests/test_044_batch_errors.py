@@ -0,0 +1,123 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for collecting every error of a failed batch into exc.errors."""
+
+import pickle
+
+import pytest
+
+from mssql_python import DatabaseError, IntegrityError, ddbc_bindings
+from mssql_python import cursor as cursor_module
+from mssql_python.constants import ConstantsDDBC
+from mssql_python.cursor import Cursor
+from mssql_python.exceptions import raise_exception
+
+PK_VIOLATION = ("23000", 2627, "Violation of PRIMARY KEY constraint 'PK_t'.")
+TERMINATED = ("01000", 3621, "The statement has been terminated.")
+
+
+def test_all_records_are_kept_on_exception():
+    with pytest.raises(IntegrityError) as exc_info:
+        raise_exception(PK_VIOLATION[0], PK_VIOLATION[2], 2627, [PK_VIOLATION, TERMINATED])
+    assert exc_info.value.errors == [PK_VIOLATION, TERMINATED]
+    assert pickle.loads(pickle.dumps(exc_info.value)).errors == [PK_VIOLATION, TERMINATED]
+
+
+def test_single_error_without_records():
+    with pytest.raises(DatabaseError) as exc_info:
+        raise_exception("42S02", "Invalid object name 'dbo.t'.", 208)
+    assert exc_info.value.errors == [("42S02", 208, "Invalid object name 'dbo.t'.")]
+    assert DatabaseError("driver", "ddbc").errors == []
+
+
+class _DiagInfo:
+    def __init__(self, records):
+        self.records = records
+
+
+class _FailedBatchCursor(Cursor):
+    """Cursor with a statement handle whose remaining results are scripted."""
+
+    def __init__(self):  # pylint: disable=super-init-not-called
+        self.hstmt = object()
+
+    def __del__(self):
+        pass
+
+
+def test_remaining_batch_errors_are_collected(monkeypatch):
+    second = ("42000", 50000, "second failure")
+    results = [
+        ConstantsDDBC.SQL_ERROR.value,
+        ConstantsDDBC.SQL_SUCCESS.value,
+        ConstantsDDBC.SQL_NO_DATA.value,
+    ]
+    monkeypatch.setattr(ddbc_bindings, "DDBCSQLMoreResults", lambda hstmt: results.pop(0))
+    monkeypatch.setattr(
+        ddbc_bindings, "DDBCSQLCheckError", lambda *args: _DiagInfo([second]), raising=False
+    )
+    with pytest.raises(IntegrityError) as exc_info:
+        raise_exception(PK_VIOLATION[0], PK_VIOLATION[2], 2627, [PK_VIOLATION])
+    _FailedBatchCursor()._collect_batch_errors(exc_info.value)
+    assert exc_info.value.errors == [PK_VIOLATION, second]
+    assert not results
+
+
+def test_collection_stops_when_results_cannot_be_reached(monkeypatch):
+    calls = []
+
+    def more_results(hstmt):
+        calls.append(hstmt)
+        return ConstantsDDBC.SQL_ERROR.value
+
+    monkeypatch.setattr(ddbc_bindings, "DDBCSQLMoreResults", more_results)
+    monkeypatch.setattr(
+        ddbc_bindings,
+        "DDBCSQLCheckError",
+        lambda *args: _DiagInfo([("HY010", 0, "Function sequence error")]),
+        raising=False,
+    )
+    with pytest.raises(IntegrityError) as exc_info:
+        raise_exception(PK_VIOLATION[0], PK_VIOLATION[2], 2627, [PK_VIOLATION])
+    _FailedBatchCursor()._collect_batch_errors(exc_info.value)
+    assert exc_info.value.errors == [PK_VIOLATION]
+    assert len(calls) == 1
+
+    with pytest.raises(DatabaseError) as exc_info:
+        raise_exception("08S01", "Communication link failure", 10054)
+    _FailedBatchCursor()._collect_batch_errors(exc_info.value)
+    assert len(calls) == 1
+
+
+def test_collection_is_bounded(monkeypatch):
+    calls = []
+
+    def more_results(hstmt):
+        calls.append(hstmt)
+        return ConstantsDDBC.SQL_ERROR.value
+
+    monkeypatch.setattr(ddbc_bindings, "DDBCSQLMoreResults", more_results)
+    monkeypatch.setattr(
+        ddbc_bindings, "DDBCSQLCheckError", lambda *args: _DiagInfo([TERMINATED]), raising=False
+    )
+    monkeypatch.setattr(cursor_module, "_MAX_BATCH_RESULTS", 5)
+    with pytest.raises(IntegrityError) as exc_info:
+        raise_exception(PK_VIOLATION[0], PK_VIOLATION[2], 2627, [PK_VIOLATION])
+    _FailedBatchCursor()._collect_batch_errors(exc_info.value)
+    assert len(calls) == 5
+    assert exc_info.value.errors == [PK_VIOLATION] + [TERMINATED] * 5
+
+
+def test_batch_errors_against_server(cursor):
+    with pytest.raises(DatabaseError) as exc_info:
+        cursor.execute(
+            "RAISERROR('first failure', 16, 1); "
+            "SELECT 1; "
+            "RAISERROR('second failure', 16, 1);"
+        )
+    messages = [message for _, native, message in exc_info.value.errors if native == 50000]
+    assert len(messages) == 2
+    assert "first failure" in messages[0] and "second failure" in messages[1]
+    # The cursor is usable again after the failed batch
+    assert cursor.execute("SELECT 42").fetchall()[0][0] == 42
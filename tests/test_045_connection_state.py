@@ -0,0 +1,226 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for connection.state and connection.in_transaction."""
+
+import threading
+import weakref
+
+from mssql_python import Error, OperationalError, connect
+from mssql_python.connection import Connection
+from mssql_python.exceptions import raise_exception
+
+
+class _FakeSqlConnection:
+    def __init__(self, autocommit):
+        self.autocommit = autocommit
+
+    def get_autocommit(self):
+        return self.autocommit
+
+    def set_autocommit(self, value):
+        self.autocommit = value
+
+    def commit(self):
+        pass
+
+    def rollback(self):
+        pass
+
+
+class _TrancountCursor:
+    """Cursor answering SELECT @@TRANCOUNT with the connection's scripted count."""
+
+    def __init__(self, connection):
+        self._connection = connection
+        self.closed = False
+
+    def execute(self, sql):
+        assert sql == "SELECT @@TRANCOUNT"
+        self._connection.trancount_queries += 1
+        return self
+
+    def fetchall(self):
+        return [(self._connection.trancount,)]
+
+    def close(self):
+        self.closed = True
+
+
+class _StateConnection(Connection):
+    """Connection with only the state tracking attributes and a scripted server."""
+
+    def __init__(self, autocommit=False):  # pylint: disable=super-init-not-called
+        self._closed = False
+        self._connecting = False
+        self._broken = False
+        self._transaction_open = False
+        self._transaction_state_stale = False
+        self._active_executions = 0
+        self._executing_cursors = weakref.WeakSet()
+        self._state_lock = threading.Condition()
+        self._savepoints = []
+        self._cursors = set()
+        self._conn = _FakeSqlConnection(autocommit)
+        self.trancount = 0
+        self.trancount_queries = 0
+
+    def __del__(self):
+        pass
+
+    def cursor(self):
+        return _TrancountCursor(self)
+
+    def run_statement(self, trancount):
+        self._begin_execution()
+        self.trancount = trancount
+        self._end_execution()
+
+
+def _connection(autocommit=False):
+    return _StateConnection(autocommit)
+
+
+def _link_failure():
+    try:
+        raise_exception("08S01", "Communication link failure", 10054)
+    except OperationalError as e:
+        return e
+
+
+def test_execution_opens_transaction_until_commit():
+    connection = _connection()
+    assert connection.state == "idle"
+    connection._begin_execution()
+    assert connection.state == "executing"
+    connection.trancount = 1
+    connection._end_execution()
+    assert connection.state == "in_transaction"
+    assert connection.in_transaction
+    connection.commit()
+    assert connection.state == "idle"
+    assert not connection.in_transaction
+
+
+def test_rollback_and_autocommit_end_transaction():
+    connection = _connection()
+    connection.run_statement(trancount=1)
+    connection.rollback()
+    assert not connection.in_transaction
+
+    connection.run_statement(trancount=1)
+    connection.setautocommit(True)
+    assert not connection.in_transaction
+
+
+def test_autocommit_execution_stays_idle():
+    connection = _connection(autocommit=True)
+    connection.run_statement(trancount=0)
+    assert connection.state == "idle"
+
+
+def test_server_transaction_count_is_checked_once_after_execution():
+    connection = _connection(autocommit=True)
+    # e.g. a stored procedure that left its transaction open
+    connection.run_statement(trancount=1)
+    assert connection.in_transaction
+    assert connection.in_transaction
+    assert connection.trancount_queries == 1
+
+    # e.g. an explicit COMMIT in manual-commit mode
+    connection = _connection()
+    connection.run_statement(trancount=0)
+    assert not connection.in_transaction
+
+
+def test_last_known_state_while_results_are_pending():
+    connection = _connection()
+    pending = type("_Pending", (), {"_results_pending": True, "closed": False})()
+    connection._cursors.add(pending)
+    connection.run_statement(trancount=0)
+    assert connection.in_transaction
+    assert connection.trancount_queries == 0
+
+
+def test_connection_errors_mark_connection_broken():
+    connection = _connection()
+    connection._begin_execution()
+    connection._end_execution(_link_failure())
+    assert connection.state == "broken"
+    assert connection.trancount_queries == 0
+
+    connection = _connection()
+    connection._begin_execution()
+    connection.trancount = 1
+    connection._end_execution(OperationalError("Timeout expired", ""))
+    assert connection.state == "in_transaction"
+
+
+def test_connecting_and_closed_states():
+    connection = _connection()
+    connection._connecting = True
+    assert connection.state == "connecting"
+    connection._closed = True
+    assert connection.state == "closed"
+    assert not connection.in_transaction
+
+
+def test_state_against_server(db_connection):
+    assert db_connection.autocommit is False
+    db_connection.commit()
+    assert db_connection.state == "idle"
+    cursor = db_connection.cursor()
+    try:
+        # Statements that touch no table do not open an implicit transaction
+        cursor.execute("SELECT 1").fetchall()
+        assert db_connection.state == "idle"
+        cursor.execute("CREATE TABLE #state_test (id INT)")
+        assert db_connection.in_transaction
+        assert db_connection.state == "in_transaction"
+    finally:
+        db_connection.rollback()
+        cursor.close()
+    assert db_connection.state == "idle"
+
+
+def test_begin_transaction_detected_in_autocommit(conn_str):
+    connection = connect(conn_str, autocommit=True)
+    try:
+        cursor = connection.cursor()
+        cursor.execute("BEGIN TRANSACTION")
+        assert connection.in_transaction
+        cursor.execute("COMMIT TRANSACTION")
+        assert not connection.in_transaction
+    finally:
+        connection.close()
+
+
+def test_procedure_left_transaction_detected(conn_str):
+    connection = connect(conn_str, autocommit=True)
+    try:
+        cursor = connection.cursor()
+        cursor.execute("CREATE PROCEDURE #leave_open AS BEGIN TRANSACTION")
+        try:
+            cursor.execute("EXEC #leave_open")
+        except Error:
+            pass  # error 266: the transaction count changed across the EXECUTE
+        assert connection.in_transaction
+        cursor.execute("ROLLBACK TRANSACTION")
+        assert not connection.in_transaction
+    finally:
+        connection.close()
+
+
+def test_state_while_results_are_pending(conn_str):
+    connection = connect(conn_str)
+    try:
+        cursor = connection.cursor()
+        cursor.execute("SELECT name FROM sys.objects")
+        # The pending result set is not disturbed; the last known state is returned
+        assert connection.state == "in_transaction"
+        assert connection.in_transaction
+        assert cursor.fetchall()
+        connection.rollback()
+        assert not connection.in_transaction
+    finally:
+        connection.close()
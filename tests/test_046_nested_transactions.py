@@ -0,0 +1,150 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for connection.begin_nested() savepoints."""
+
+import threading
+
+import pytest
+
+from mssql_python import NestedTransaction, ProgrammingError
+from mssql_python.connection import Connection
+
+
+class _FakeSqlConnection:
+    def __init__(self, autocommit):
+        self.autocommit = autocommit
+
+    def get_autocommit(self):
+        return self.autocommit
+
+    def set_autocommit(self, value):
+        self.autocommit = value
+
+    def commit(self):
+        pass
+
+    def rollback(self):
+        pass
+
+
+class _RecordingConnection(Connection):
+    """Connection recording the transaction statements it would send."""
+
+    def __init__(self, autocommit=False):  # pylint: disable=super-init-not-called
+        self._closed = False
+        self._broken = False
+        self._transaction_open = False
+        self._transaction_state_stale = False
+        self._state_lock = threading.Lock()
+        self._savepoints = []
+        self._savepoint_counter = 0
+        self._conn = _FakeSqlConnection(autocommit)
+        self.statements = []
+
+    def __del__(self):
+        pass
+
+    def _run_transaction_statement(self, sql):
+        self.statements.append(sql)
+
+
+def test_context_manager_releases_on_success():
+    connection = _RecordingConnection()
+    with connection.begin_nested() as savepoint:
+        assert isinstance(savepoint, NestedTransaction)
+        assert savepoint.is_active
+    assert not savepoint.is_active
+    assert len(connection.statements) == 1
+    assert connection.statements[0].endswith("SAVE TRANSACTION mssql_sp_1")
+
+
+def test_context_manager_rolls_back_on_error():
+    connection = _RecordingConnection()
+    with pytest.raises(ValueError):
+        with connection.begin_nested():
+            raise ValueError("boom")
+    assert connection.statements[-1] == "ROLLBACK TRANSACTION mssql_sp_1"
+    assert connection._savepoints == []
+
+
+def test_ending_outer_savepoint_ends_inner_ones():
+    connection = _RecordingConnection()
+    outer = connection.begin_nested()
+    inner = connection.begin_nested()
+    assert inner.name == "mssql_sp_2"
+    outer.rollback()
+    assert not inner.is_active
+    with pytest.raises(ProgrammingError):
+        inner.commit()
+    # Leaving the block of an ended savepoint sends nothing more
+    sent = len(connection.statements)
+    with inner:
+        pass
+    assert len(connection.statements) == sent
+
+
+def test_failed_rollback_keeps_the_savepoint():
+    connection = _RecordingConnection()
+    savepoint = connection.begin_nested()
+
+    def fail(sql):
+        raise ProgrammingError("Cannot roll back mssql_sp_1", "")
+
+    connection._run_transaction_statement = fail
+    with pytest.raises(ProgrammingError):
+        savepoint.rollback()
+    assert savepoint.is_active
+    assert connection._savepoints == [savepoint]
+
+
+def test_transaction_end_discards_savepoints():
+    connection = _RecordingConnection()
+    savepoint = connection.begin_nested()
+    connection.commit()
+    assert not savepoint.is_active
+    savepoint = connection.begin_nested()
+    connection.rollback()
+    assert not savepoint.is_active
+
+
+def test_requires_autocommit_off():
+    connection = _RecordingConnection(autocommit=True)
+    with pytest.raises(ProgrammingError):
+        connection.begin_nested()
+    assert connection.statements == []
+
+
+def test_nested_rollback_keeps_outer_work(db_connection):
+    db_connection.autocommit = False
+    cursor = db_connection.cursor()
+    try:
+        cursor.execute("CREATE TABLE #nested_test (id INT)")
+        cursor.execute("INSERT INTO #nested_test VALUES (1)")
+        with pytest.raises(RuntimeError):
+            with db_connection.begin_nested():
+                cursor.execute("INSERT INTO #nested_test VALUES (2)")
+                raise RuntimeError("undo the inner insert")
+        with db_connection.begin_nested():
+            cursor.execute("INSERT INTO #nested_test VALUES (3)")
+        rows = cursor.execute("SELECT id FROM #nested_test ORDER BY id").fetchall()
+        assert [row[0] for row in rows] == [1, 3]
+        assert db_connection.in_transaction
+    finally:
+        db_connection.rollback()
+        cursor.close()
+
+
+def test_savepoint_starts_transaction(db_connection):
+    db_connection.autocommit = False
+    db_connection.commit()
+    cursor = db_connection.cursor()
+    try:
+        savepoint = db_connection.begin_nested()
+        assert cursor.execute("SELECT @@TRANCOUNT").fetchall()[0][0] == 1
+        savepoint.commit()
+        db_connection.commit()
+        assert cursor.execute("SELECT @@TRANCOUNT").fetchall()[0][0] == 0
+    finally:
+        db_connection.rollback()
+        cursor.close()
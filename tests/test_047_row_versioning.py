@@ -0,0 +1,76 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for snapshot isolation / RCSI detection and require_row_versioning."""
+
+import pytest
+
+from mssql_python import OperationalError, connect
+from mssql_python.connection import Connection, _validate_row_versioning
+
+
+class _VersioningConnection(Connection):
+    """Connection reporting scripted row versioning options."""
+
+    def __init__(self, requirement, **state):  # pylint: disable=super-init-not-called
+        self._require_row_versioning = requirement
+        self._state = dict(
+            {"database": "app", "snapshot_isolation": False, "read_committed_snapshot": False},
+            **state,
+        )
+        self.closed_by_check = False
+
+    def __del__(self):
+        pass
+
+    def _row_versioning_state(self):
+        return dict(self._state)
+
+    def close(self):
+        self.closed_by_check = True
+
+
+@pytest.mark.parametrize("requirement", ["rcsi", "SNAPSHOT", "", 1])
+def test_invalid_requirement_is_rejected(requirement):
+    with pytest.raises(ValueError):
+        _validate_row_versioning(requirement)
+
+
+def test_helpers_read_row_versioning_state():
+    connection = _VersioningConnection(None, read_committed_snapshot=True)
+    assert connection.read_committed_snapshot_enabled()
+    assert not connection.snapshot_isolation_enabled()
+
+
+def test_met_requirement_keeps_connection_open():
+    connection = _VersioningConnection("read_committed_snapshot", read_committed_snapshot=True)
+    connection._check_row_versioning()
+    assert not connection.closed_by_check
+
+
+def test_missing_options_fail_fast_and_close():
+    connection = _VersioningConnection("both", read_committed_snapshot=True)
+    with pytest.raises(OperationalError) as exc_info:
+        connection._check_row_versioning()
+    assert "ALLOW_SNAPSHOT_ISOLATION" in str(exc_info.value)
+    assert "READ_COMMITTED_SNAPSHOT" not in str(exc_info.value)
+    assert connection.closed_by_check
+
+
+def test_requirement_against_server(conn_str):
+    connection = connect(conn_str)
+    try:
+        rcsi = connection.read_committed_snapshot_enabled()
+        snapshot = connection.snapshot_isolation_enabled()
+        assert isinstance(rcsi, bool) and isinstance(snapshot, bool)
+    finally:
+        connection.close()
+
+    if rcsi:
+        connect(conn_str, require_row_versioning="read_committed_snapshot").close()
+    else:
+        with pytest.raises(OperationalError):
+            connect(conn_str, require_row_versioning="read_committed_snapshot")
+    if not (rcsi and snapshot):
+        with pytest.raises(OperationalError):
+            connect(conn_str, require_row_versioning="both")
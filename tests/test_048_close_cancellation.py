@@ -0,0 +1,138 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for cancelling executing statements when a connection is closed."""
+
+import threading
+import time
+import weakref
+
+import pytest
+
+from mssql_python import connect
+from mssql_python import connection as connection_module
+from mssql_python.connection import Connection
+from mssql_python.cursor import Cursor
+
+
+class _Statement:
+    """Statement handle blocked in SQLExecute until the fake SQLCancel is called."""
+
+    def __init__(self):
+        self.cancelled = threading.Event()
+
+
+class _ExecutingCursor(Cursor):
+    def __init__(self):  # pylint: disable=super-init-not-called
+        self.hstmt = _Statement()
+
+    def __del__(self):
+        pass
+
+
+class _CancellingConnection(Connection):
+    """Connection with only the execution tracking used by _cancel_executions()."""
+
+    def __init__(self):  # pylint: disable=super-init-not-called
+        self._closed = True
+        self._active_executions = 0
+        self._executing_cursors = weakref.WeakSet()
+        self._state_lock = threading.Condition()
+
+    def __del__(self):
+        pass
+
+    def run(self, cursor, acknowledge=True):
+        """Execute on a background thread until cursor is cancelled."""
+
+        started = threading.Event()
+
+        def execute():
+            self._begin_execution(cursor)
+            started.set()
+            if acknowledge:
+                cursor.hstmt.cancelled.wait(5)
+            else:
+                time.sleep(0.5)
+            self._end_execution(None, cursor)
+
+        thread = threading.Thread(target=execute, daemon=True)
+        thread.start()
+        started.wait(5)
+        return thread
+
+
+@pytest.fixture
+def cancels(monkeypatch):
+    cancelled = []
+
+    def cancel(hstmt):
+        cancelled.append(hstmt)
+        hstmt.cancelled.set()
+        return 0
+
+    monkeypatch.setattr(connection_module.ddbc_bindings, "DDBCSQLCancel", cancel, raising=False)
+    return cancelled
+
+
+def test_close_cancels_and_waits_for_executing_statements(cancels):
+    connection = _CancellingConnection()
+    cursor = _ExecutingCursor()
+    thread = connection.run(cursor)
+    connection._cancel_executions()
+    assert cancels == [cursor.hstmt]
+    assert connection._active_executions == 0
+    thread.join(1)
+    assert not thread.is_alive()
+
+
+def test_wait_for_acknowledgment_is_bounded(cancels):
+    connection = _CancellingConnection()
+    cursor = _ExecutingCursor()
+    thread = connection.run(cursor, acknowledge=False)
+    started = time.monotonic()
+    connection._cancel_executions(timeout=0.05)
+    assert time.monotonic() - started < 0.4
+    assert cancels == [cursor.hstmt]
+    thread.join()
+
+
+def test_no_cancel_without_executing_statements(cancels):
+    connection = _CancellingConnection()
+    connection._cancel_executions()
+    assert cancels == []
+
+
+def test_statement_of_closing_thread_is_not_waited_for(cancels):
+    connection = _CancellingConnection()
+    cursor = _ExecutingCursor()
+    connection._begin_execution(cursor)
+    started = time.monotonic()
+    connection._cancel_executions(timeout=1)
+    assert time.monotonic() - started < 0.5
+    assert cancels == []
+    connection._end_execution(None, cursor)
+
+
+def test_close_stops_statement_running_on_another_thread(conn_str):
+    connection = connect(conn_str, autocommit=True)
+    cursor = connection.cursor()
+    errors = []
+
+    def run():
+        try:
+            cursor.execute("WAITFOR DELAY '00:01:00'")
+        except Exception as e:  # pylint: disable=broad-exception-caught
+            errors.append(e)
+
+    thread = threading.Thread(target=run, daemon=True)
+    thread.start()
+    deadline = time.monotonic() + 5
+    while not connection._executing_cursors and time.monotonic() < deadline:
+        time.sleep(0.05)
+    started = time.monotonic()
+    connection.close()
+    thread.join(10)
+    assert not thread.is_alive()
+    assert time.monotonic() - started < 10
+    assert errors
@@ -0,0 +1,87 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for connection.ping()."""
+
+import pytest
+
+from mssql_python import InterfaceError, OperationalError, PingError, connect
+from mssql_python.connection import Connection
+
+
+class _ProbeCursor:
+    def __init__(self, connection, error):
+        self.connection = connection
+        self._error = error
+        self._timeout = 0
+        self.applied_timeout = None
+        self.closed = False
+
+    def _set_timeout(self):
+        self.applied_timeout = self._timeout
+
+    def execute(self, sql, use_prepare=True):
+        assert sql == "SELECT 1" and not use_prepare
+        # As recorded at the end of every execution with autocommit off
+        self.connection._transaction_open = True
+        self.connection._transaction_state_stale = True
+        if self._error is not None:
+            raise self._error
+        return self
+
+    def fetchall(self):
+        return [(1,)]
+
+    def close(self):
+        self.closed = True
+
+
+class _ProbeConnection(Connection):
+    """Connection whose probe cursor answers or fails as scripted."""
+
+    def __init__(self, error=None):  # pylint: disable=super-init-not-called
+        self._closed = False
+        self._transaction_open = False
+        self._transaction_state_stale = False
+        self.probe = _ProbeCursor(self, error)
+
+    def __del__(self):
+        pass
+
+    def cursor(self):
+        return self.probe
+
+
+def test_ping_returns_round_trip_time():
+    connection = _ProbeConnection()
+    assert connection.ping(timeout=0.5) >= 0
+    assert connection.probe.applied_timeout == 1
+    assert connection.probe.closed
+    assert not connection.in_transaction
+
+
+def test_ping_failure_raises_ping_error():
+    cause = OperationalError("Timeout expired", "[Microsoft][ODBC Driver]Query timeout expired")
+    connection = _ProbeConnection(cause)
+    with pytest.raises(PingError) as exc_info:
+        connection.ping()
+    assert isinstance(exc_info.value, OperationalError)
+    assert exc_info.value.__cause__ is cause
+    assert connection.probe.closed
+
+
+@pytest.mark.parametrize("timeout", [0, -1, True, "5"])
+def test_invalid_timeout_is_rejected(timeout):
+    with pytest.raises(ValueError):
+        _ProbeConnection().ping(timeout=timeout)
+
+
+def test_ping_against_server(conn_str):
+    connection = connect(conn_str)
+    try:
+        assert connection.ping() < 5
+        assert not connection.in_transaction
+    finally:
+        connection.close()
+    with pytest.raises(InterfaceError):
+        connection.ping()
@@ -0,0 +1,153 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for reconnect-and-replay of statements executed with idempotent=True."""
+
+import pytest
+
+from mssql_python import OperationalError, ProgrammingError, RetryPolicy, connect
+from mssql_python import retry
+from mssql_python.cursor import Cursor
+from mssql_python.exceptions import raise_exception
+
+
+def _error(sqlstate, message, native_error=0):
+    try:
+        raise_exception(sqlstate, message, native_error)
+    except Exception as e:  # pylint: disable=broad-exception-caught
+        return e
+
+
+LINK_FAILURE = _error("08S01", "Communication link failure", 10054)
+LOGIN_FAILURE = _error("08001", "TCP Provider: No connection could be made")
+SYNTAX_ERROR = _error("42000", "Incorrect syntax near 'FROM'.", 102)
+
+
+@pytest.fixture(autouse=True)
+def _no_sleep(monkeypatch):
+    delays = []
+    monkeypatch.setattr(retry.time, "sleep", delays.append)
+    return delays
+
+
+def _flaky(failures, calls):
+    def run():
+        calls.append("execute")
+        if failures:
+            raise failures.pop(0)
+        return "done"
+
+    return run
+
+
+def test_connection_loss_is_replayed_once_without_policy():
+    calls = []
+    run = _flaky([LINK_FAILURE], calls)
+    assert retry.call_with_reconnect(run, lambda: calls.append("reconnect")) == "done"
+    assert calls == ["execute", "reconnect", "execute"]
+
+    calls = []
+    with pytest.raises(OperationalError):
+        retry.call_with_reconnect(
+            _flaky([LINK_FAILURE, LINK_FAILURE], calls), lambda: calls.append("reconnect")
+        )
+    assert calls == ["execute", "reconnect", "execute"]
+
+
+def test_other_errors_are_not_replayed():
+    calls = []
+    with pytest.raises(ProgrammingError):
+        retry.call_with_reconnect(_flaky([SYNTAX_ERROR], calls), lambda: calls.append("x"))
+    assert calls == ["execute"]
+
+
+def test_policy_controls_attempts_and_backoff(_no_sleep):
+    calls = []
+    reconnects = [LOGIN_FAILURE]
+
+    def reconnect():
+        calls.append("reconnect")
+        if reconnects:
+            raise reconnects.pop(0)
+
+    policy = RetryPolicy(max_attempts=3, backoff=0.5, jitter=False)
+    assert retry.call_with_reconnect(_flaky([LINK_FAILURE], calls), reconnect, policy) == "done"
+    # The failed reconnection counts as the second attempt
+    assert calls == ["execute", "reconnect", "reconnect", "execute"]
+    assert _no_sleep == [0.5, 1.0]
+
+
+class _ReplayingCursor(Cursor):
+    """Cursor whose execution (via capture_plan) fails with scripted errors first."""
+
+    def __init__(self, failures, transaction_open=False):  # pylint: disable=super-init-not-called
+        self._failures = list(failures)
+        self.events = []
+        events = self.events
+        self._connection = type(
+            "_Connection",
+            (),
+            {
+                "_transaction_open": transaction_open,
+                "_reconnect": lambda _self: events.append("reconnect"),
+                "_auto_parameterize": False,
+                "_read_only": False,
+                "_statement_policy": None,
+            },
+        )()
+        self._retry_policy = None
+        self._retrying = False
+        self._replaying = False
+        self._capture_wait_stats = False
+        self._measuring_waits = False
+
+    def __del__(self):
+        pass
+
+    def _reset_cursor(self):
+        self.events.append("new handle")
+
+    def _execute_capturing_plan(self, operation, parameters, *args):
+        self.events.append(("execute", operation, parameters))
+        if self._failures:
+            raise self._failures.pop(0)
+        return self
+
+
+def test_idempotent_execute_reconnects_and_replays():
+    cursor = _ReplayingCursor([LINK_FAILURE])
+    assert cursor.execute("SELECT ?", 1, capture_plan="estimated", idempotent=True) is cursor
+    executed = ("execute", "SELECT ?", (1,))
+    assert cursor.events == [executed, "reconnect", "new handle", executed]
+    assert cursor._replaying is False
+
+
+def test_statements_not_marked_idempotent_are_not_replayed():
+    cursor = _ReplayingCursor([LINK_FAILURE])
+    with pytest.raises(OperationalError):
+        cursor.execute("SELECT 1", capture_plan="estimated")
+    assert "reconnect" not in cursor.events
+
+
+def test_no_replay_inside_open_transaction():
+    cursor = _ReplayingCursor([LINK_FAILURE], transaction_open=True)
+    with pytest.raises(OperationalError):
+        cursor.execute("SELECT 1", capture_plan="estimated", idempotent=True)
+    assert "reconnect" not in cursor.events
+
+
+def test_killed_session_is_replaced(conn_str):
+    connection = connect(conn_str, autocommit=True)
+    killer = connect(conn_str, autocommit=True)
+    try:
+        cursor = connection.cursor()
+        spid = cursor.execute("SELECT @@SPID").fetchall()[0][0]
+        try:
+            killer.cursor().execute(f"KILL {int(spid)}")
+        except Exception:  # pylint: disable=broad-exception-caught
+            pytest.skip("ALTER ANY CONNECTION is required to kill a session")
+        rows = cursor.execute("SELECT @@SPID", idempotent=True).fetchall()
+        assert rows and connection.state == "idle"
+    finally:
+        killer.close()
+        connection.close()
@@ -0,0 +1,130 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for RoutingPool read-write splitting."""
+
+import threading
+
+import pytest
+
+from mssql_python import OperationalError, RoutingPool
+from mssql_python import routing
+
+WRITER = "Server=primary;Database=app"
+READERS = ["Server=replica1;Database=app", "Server=replica2;Database=app"]
+
+
+class _FakeConnection:
+    def __init__(self, conn_str):
+        self.conn_str = conn_str
+
+
+@pytest.fixture
+def opened(monkeypatch):
+    """Record each connection string connected to; "down" servers are unreachable."""
+    calls = []
+
+    def connect(conn_str, **kwargs):
+        calls.append(conn_str)
+        if "down" in conn_str:
+            raise OperationalError("Login timeout expired", "")
+        return _FakeConnection(conn_str)
+
+    monkeypatch.setattr(routing.db_connection, "connect", connect)
+    return calls
+
+
+def test_writes_go_to_writer_and_reads_rotate_over_readers(opened):
+    pool = RoutingPool(WRITER, READERS)
+    assert pool.connect().conn_str == WRITER
+    assert [pool.connect(readonly=True).conn_str for _ in range(3)] == [
+        READERS[0],
+        READERS[1],
+        READERS[0],
+    ]
+
+
+def test_reader_scope_routes_to_readers(opened):
+    pool = RoutingPool(WRITER, READERS)
+    with pool.reader():
+        assert pool.in_reader_scope()
+        assert pool.connect().conn_str in READERS
+        assert pool.connect(readonly=False).conn_str == WRITER
+    assert not pool.in_reader_scope()
+    assert pool.connect().conn_str == WRITER
+
+
+def test_reader_scope_is_per_thread(opened):
+    pool = RoutingPool(WRITER, READERS)
+    seen = []
+    with pool.reader():
+        thread = threading.Thread(target=lambda: seen.append(pool.connect().conn_str))
+        thread.start()
+        thread.join()
+    assert seen == [WRITER]
+
+
+def test_unreachable_readers_are_skipped_then_writer_used(opened):
+    pool = RoutingPool(WRITER, ["Server=down1", READERS[0]])
+    assert pool.connect(readonly=True).conn_str == READERS[0]
+
+    pool = RoutingPool(WRITER, ["Server=down1", "Server=down2"])
+    assert pool.connect(readonly=True).conn_str == WRITER
+    assert opened[-3:] == ["Server=down1", "Server=down2", WRITER]
+
+    pool = RoutingPool(WRITER, ["Server=down1"], fallback_to_writer=False)
+    with pytest.raises(OperationalError):
+        pool.connect(readonly=True)
+
+
+def test_without_readers_everything_goes_to_writer(opened):
+    assert RoutingPool(WRITER).connect(readonly=True).conn_str == WRITER
+    with pytest.raises(OperationalError, match="No read-only endpoint is configured"):
+        RoutingPool(WRITER, fallback_to_writer=False).connect(readonly=True)
+    assert opened == [WRITER]
+
+
+class _FailingCursor:
+    def __init__(self, events):
+        self.events = events
+
+    def execute(self, sql, *params):
+        raise OperationalError("Invalid object name 'missing'", "")
+
+    def close(self):
+        self.events.append("cursor closed")
+
+
+def test_execute_closes_the_cursor_on_failure(monkeypatch):
+    events = []
+
+    class _Connection(_FakeConnection):
+        def cursor(self):
+            return _FailingCursor(events)
+
+        def close(self):
+            events.append("connection closed")
+
+    monkeypatch.setattr(
+        routing.db_connection, "connect", lambda conn_str, **kwargs: _Connection(conn_str)
+    )
+    with pytest.raises(OperationalError):
+        RoutingPool(WRITER).execute("SELECT * FROM missing")
+    assert events == ["cursor closed", "connection closed"]
+
+
+@pytest.mark.parametrize("readers", ["Server=replica1", [""], [None]])
+def test_invalid_endpoints_are_rejected(readers):
+    with pytest.raises(ValueError):
+        RoutingPool(WRITER, readers)
+    with pytest.raises(ValueError):
+        RoutingPool("")
+
+
+def test_execute_against_server(conn_str):
+    # The same server stands in for both endpoints
+    pool = RoutingPool(conn_str, [conn_str])
+    with pool.reader():
+        rows = pool.execute("SELECT ?", 42)
+    assert rows[0][0] == 42
+    assert pool.execute("DECLARE @t TABLE (id INT); INSERT INTO @t VALUES (1)") in (1, -1)
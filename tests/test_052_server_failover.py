@@ -0,0 +1,79 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for Server=a|b|c failover lists in the connection string."""
+
+import pytest
+
+from mssql_python import OperationalError, connect
+from mssql_python import connection as connection_module
+from mssql_python.connection import Connection, _server_candidates
+from mssql_python.connection_string_parser import _ConnectionStringParser
+
+
+def test_server_list_is_tried_in_priority_order():
+    params = {"server": "dc1-sql | dc2-sql,1433|tcp:dc3-sql"}
+    assert _server_candidates(params) == ["dc1-sql", "dc2-sql,1433", "tcp:dc3-sql"]
+    assert _server_candidates({"server": "single"}) == ["single"]
+
+
+def test_random_order_shuffles(monkeypatch):
+    monkeypatch.setattr(connection_module.random, "shuffle", lambda servers: servers.reverse())
+    params = {"server": "a|b|c", "serverorder": "Random"}
+    assert _server_candidates(params) == ["c", "b", "a"]
+    with pytest.raises(ValueError):
+        _server_candidates({"server": "a|b", "serverorder": "fastest"})
+
+
+def _connection(conn_str):
+    connection = Connection.__new__(Connection)
+    connection.connection_str, _ = connection._construct_connection_string(conn_str)
+    connection._pooling = False
+    connection._attrs_before = {}
+    return connection
+
+
+@pytest.fixture
+def attempts(monkeypatch):
+    """Record the Server of every connect attempt; servers named down-* refuse."""
+    servers = []
+
+    class _FakeSqlConnection:
+        def __init__(self, conn_str, pooling, attrs_before):
+            params = _ConnectionStringParser(validate_keywords=False)._parse(conn_str)
+            servers.append(params["server"])
+            assert "serverorder" not in params
+            if params["server"].startswith("down-"):
+                raise RuntimeError("[Microsoft][ODBC Driver 18]Login timeout expired")
+
+    monkeypatch.setattr(connection_module.ddbc_bindings, "Connection", _FakeSqlConnection)
+    return servers
+
+
+def test_failover_to_next_server(attempts):
+    connection = _connection("Server=down-dc1|dc2-sql;Database=app")
+    connection._conn = connection._open_session()
+    assert attempts == ["down-dc1", "dc2-sql"]
+    assert connection.server == "dc2-sql"
+
+
+def test_error_of_last_server_is_raised(attempts):
+    connection = _connection("Server=down-dc1|down-dc2;ServerOrder=priority")
+    with pytest.raises(OperationalError):
+        connection._open_session()
+    assert attempts == ["down-dc1", "down-dc2"]
+
+
+def test_unreachable_first_server_against_real_server(conn_str):
+    params = _ConnectionStringParser(validate_keywords=False)._parse(conn_str)
+    server = params.get("server")
+    if not server:
+        pytest.skip("Connection string has no Server keyword")
+    params["server"] = f"unreachable.invalid|{server}"
+    failover = ";".join(f"{key}={{{value}}}" for key, value in params.items())
+    connection = connect(failover, timeout=5)
+    try:
+        assert connection.server == server
+        assert connection.cursor().execute("SELECT 1").fetchall()[0][0] == 1
+    finally:
+        connection.close()
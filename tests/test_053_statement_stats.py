@@ -0,0 +1,97 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for cursor.stats() query hash and plan handle exposure."""
+
+from mssql_python import connect
+from mssql_python.cursor import Cursor
+from mssql_python.exceptions import raise_exception
+
+PLAN = (
+    '<ShowPlanXML><BatchSequence><Batch><Statements>'
+    '<StmtSimple StatementText="SELECT 1" QueryHash="0x8A4B1AA1E9A7BD2C" '
+    'QueryPlanHash="0x1C3F0B2E8D7A6E5F"/>'
+    '<StmtSimple StatementText="SELECT 2" QueryHash="0x0000000000000002" '
+    'QueryPlanHash="0x0000000000000003"/>'
+    "</Statements></Batch></BatchSequence></ShowPlanXML>"
+)
+PLAN_HANDLE = "0x06000500" + "AB" * 36
+
+
+class _LookupCursor:
+    def __init__(self, rows, error=None):
+        self.rows = rows
+        self.error = error
+        self.executed = []
+        self.closed = False
+
+    def execute(self, sql, *params):
+        self.executed.append((sql, params))
+        if self.error:
+            raise self.error
+        return self
+
+    def fetchall(self):
+        return self.rows
+
+    def close(self):
+        self.closed = True
+
+
+class _PlanCursor(Cursor):
+    """Cursor with a captured plan whose lookups run on the given cursor."""
+
+    def __init__(self, plan, lookup):  # pylint: disable=super-init-not-called
+        self.plan = plan
+        self._connection = type("_Connection", (), {"cursor": lambda _self: lookup})()
+
+    def __del__(self):
+        pass
+
+
+def test_without_captured_plan_nothing_is_reported():
+    lookup = _LookupCursor([])
+    stats = _PlanCursor(None, lookup).stats()
+    assert stats == {"query_hash": None, "query_plan_hash": None, "plan_handle": None}
+    assert lookup.executed == []
+
+
+def test_hashes_of_first_statement_and_cached_plan_handle():
+    lookup = _LookupCursor([(PLAN_HANDLE,)])
+    stats = _PlanCursor(PLAN, lookup).stats()
+    assert stats == {
+        "query_hash": "0x8A4B1AA1E9A7BD2C",
+        "query_plan_hash": "0x1C3F0B2E8D7A6E5F",
+        "plan_handle": PLAN_HANDLE,
+    }
+    assert lookup.executed[0][1] == ("0x8A4B1AA1E9A7BD2C", "0x1C3F0B2E8D7A6E5F")
+    assert lookup.closed
+
+
+def test_uncached_or_unreadable_plan_handle_is_none():
+    assert _PlanCursor(PLAN, _LookupCursor([])).stats()["plan_handle"] is None
+
+    try:
+        raise_exception("42000", "VIEW SERVER STATE permission was denied", 300)
+    except Exception as e:  # pylint: disable=broad-exception-caught
+        denied = e
+    lookup = _LookupCursor([], error=denied)
+    stats = _PlanCursor(PLAN, lookup).stats()
+    assert stats["query_hash"] == "0x8A4B1AA1E9A7BD2C"
+    assert stats["plan_handle"] is None
+    assert lookup.closed
+
+
+def test_stats_after_actual_plan(conn_str):
+    connection = connect(conn_str, autocommit=True)
+    try:
+        cursor = connection.cursor()
+        cursor.execute("SELECT name FROM sys.objects WHERE object_id = ?", 3, capture_plan="actual")
+        cursor.fetchall()
+        cursor.nextset()
+        stats = cursor.stats()
+        assert stats["query_hash"].startswith("0x")
+        assert stats["query_plan_hash"].startswith("0x")
+        assert stats["plan_handle"] is None or stats["plan_handle"].startswith("0x")
+    finally:
+        connection.close()
@@ -0,0 +1,113 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for per-statement session wait statistics (capture_wait_stats)."""
+
+import pytest
+
+from mssql_python import ProgrammingError, connect
+from mssql_python.cursor import Cursor
+
+
+class _WaitingCursor(Cursor):
+    """Cursor whose wait statistics snapshots come from a script."""
+
+    def __init__(self, snapshots, mars=True):  # pylint: disable=super-init-not-called
+        self._snapshots = list(snapshots)
+        self.events = []
+        self._connection = type(
+            "_Connection",
+            (),
+            {
+                "_mars_enabled": mars,
+                "_auto_parameterize": False,
+                "_read_only": False,
+                "_statement_policy": None,
+            },
+        )()
+        self.closed = False
+        self._results_pending = False
+        self._retry_policy = None
+        self._capture_wait_stats = True
+        self._measuring_waits = False
+        self._wait_baseline = None
+        self._wait_delta = None
+
+    def __del__(self):
+        pass
+
+    def _session_wait_stats(self):
+        self.events.append("snapshot")
+        return self._snapshots.pop(0)
+
+    def _execute_capturing_plan(self, operation, parameters, *args):
+        self.events.append(("execute", operation))
+        return self
+
+
+BEFORE = {"LCK_M_S": (1, 100, 0), "SOS_SCHEDULER_YIELD": (5, 2, 2)}
+AFTER = {"LCK_M_S": (3, 5100, 1), "SOS_SCHEDULER_YIELD": (5, 2, 2), "PAGEIOLATCH_SH": (4, 30, 0)}
+
+
+def test_delta_reports_increased_waits_longest_first():
+    cursor = _WaitingCursor([BEFORE, AFTER])
+    cursor.execute("SELECT 1", capture_plan="estimated")
+    assert cursor.events == ["snapshot", ("execute", "SELECT 1")]
+    stats = cursor.wait_stats()
+    assert list(stats) == ["LCK_M_S", "PAGEIOLATCH_SH"]
+    assert stats["LCK_M_S"] == {
+        "waiting_tasks_count": 2,
+        "wait_time_ms": 5000,
+        "signal_wait_time_ms": 1,
+    }
+    # The delta is computed once
+    assert cursor.wait_stats() is stats
+    assert cursor.events.count("snapshot") == 2
+
+
+def test_measured_execution_keeps_hints():
+    cursor = _WaitingCursor([BEFORE, AFTER])
+    cursor.execute("SELECT 1", capture_plan="estimated", hints=["RECOMPILE"])
+    assert cursor.events == ["snapshot", ("execute", "SELECT 1\nOPTION (RECOMPILE)")]
+
+
+def test_disabled_or_unreadable_statistics_give_none():
+    cursor = _WaitingCursor([])
+    cursor._capture_wait_stats = False
+    cursor.execute("SELECT 1", capture_plan="estimated")
+    assert cursor.wait_stats() is None
+    assert cursor.events == [("execute", "SELECT 1")]
+
+    cursor = _WaitingCursor([None])
+    cursor.execute("SELECT 1", capture_plan="estimated")
+    assert cursor.wait_stats() is None
+
+
+def test_unread_rows_without_mars_are_rejected():
+    cursor = _WaitingCursor([BEFORE, AFTER], mars=False)
+    cursor.execute("SELECT 1", capture_plan="estimated")
+    cursor._results_pending = True
+    with pytest.raises(ProgrammingError):
+        cursor.wait_stats()
+    cursor._results_pending = False
+    assert "LCK_M_S" in cursor.wait_stats()
+
+
+def test_capture_wait_stats_must_be_bool():
+    cursor = _WaitingCursor([])
+    with pytest.raises(TypeError):
+        cursor.capture_wait_stats = 1
+
+
+def test_waitfor_delay_is_reported(conn_str):
+    connection = connect(conn_str, autocommit=True)
+    try:
+        cursor = connection.cursor()
+        cursor.capture_wait_stats = True
+        cursor.execute("WAITFOR DELAY '00:00:00.200'")
+        stats = cursor.wait_stats()
+        if stats is None:
+            pytest.skip("VIEW SERVER STATE is required to read session wait statistics")
+        assert stats["WAITFOR"]["wait_time_ms"] >= 150
+    finally:
+        connection.close()
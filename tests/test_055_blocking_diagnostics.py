@@ -0,0 +1,168 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for blocking chain diagnostics attached to timeout errors."""
+
+import pickle
+import threading
+import weakref
+
+import pytest
+
+from mssql_python import OperationalError, connect
+from mssql_python import diagnostics
+from mssql_python.connection import Connection
+from mssql_python.exceptions import raise_exception
+
+
+def _error(sqlstate, message):
+    try:
+        raise_exception(sqlstate, message)
+    except Exception as e:  # pylint: disable=broad-exception-caught
+        return e
+
+
+# session 60 waits on 55, which waits on the idle head blocker 52
+KEY = "KEY: 5:72057594043498496 (8194443284a0)"
+CHAIN_ROWS = [
+    (52, None, None, None, None, "sleeping", "etl", "host1", "loader", 1, "UPDATE t"),
+    (55, 52, "LCK_M_X", 4200, KEY, "suspended", "app", "host2", "api", 1, "UPDATE t"),
+    (60, 55, "LCK_M_S", 3100, KEY, "suspended", "app", "host3", "api", 0, "SELECT v FROM t"),
+]
+
+
+class _ChainCursor:
+    def __init__(self, rows, error=None):
+        self.rows = rows
+        self.error = error
+        self._timeout = 0
+        self.closed = False
+
+    def _set_timeout(self):
+        pass
+
+    def execute(self, sql, use_prepare=True):
+        if self.error:
+            raise self.error
+        return self
+
+    def fetchall(self):
+        return self.rows
+
+    def close(self):
+        self.closed = True
+
+
+class _ChainConnection:
+    def __init__(self, cursor):
+        self._cursor = cursor
+
+    def cursor(self):
+        return self._cursor
+
+
+def test_chain_marks_head_blockers():
+    cursor = _ChainCursor(CHAIN_ROWS)
+    chain = diagnostics.capture_blocking_chain(_ChainConnection(cursor))
+    assert [entry["session_id"] for entry in chain] == [52, 55, 60]
+    assert [entry["head_blocker"] for entry in chain] == [True, False, False]
+    assert chain[2]["blocking_session_id"] == 55
+    assert chain[2]["wait_resource"] == KEY
+    assert cursor._timeout == diagnostics.DIAGNOSTIC_QUERY_TIMEOUT
+    assert cursor.closed
+
+
+def test_unreadable_chain_is_none():
+    denied = _error("42000", "VIEW SERVER STATE permission was denied")
+    cursor = _ChainCursor([], error=denied)
+    assert diagnostics.capture_blocking_chain(_ChainConnection(cursor)) is None
+    assert cursor.closed
+
+
+class _ExecutionConnection(Connection):
+    """Connection with only the execution tracking used by _end_execution()."""
+
+    def __init__(self, diagnose):  # pylint: disable=super-init-not-called
+        self._closed = False
+        self._closing = False
+        self._conn = None
+        self._active_executions = 1
+        self._executing_cursors = weakref.WeakSet()
+        self._state_lock = threading.Condition()
+        self._diagnose_blocking = diagnose
+
+    def __del__(self):
+        pass
+
+
+@pytest.fixture
+def captured(monkeypatch):
+    calls = []
+
+    def capture(connection):
+        calls.append(connection)
+        return [{"session_id": 52, "head_blocker": True}]
+
+    monkeypatch.setattr(diagnostics, "capture_blocking_chain", capture)
+    return calls
+
+
+def test_timeout_errors_get_the_blocking_chain(captured):
+    connection = _ExecutionConnection(diagnose=True)
+    timeout = _error("HYT00", "Query timeout expired")
+    connection._end_execution(timeout)
+    assert timeout.blocking_chain == [{"session_id": 52, "head_blocker": True}]
+    assert captured == [connection]
+    # The chain survives pickling, e.g. across worker processes
+    assert pickle.loads(pickle.dumps(timeout)).blocking_chain == timeout.blocking_chain
+
+
+def test_other_errors_and_disabled_diagnostics_are_not_diagnosed(captured):
+    connection = _ExecutionConnection(diagnose=True)
+    syntax = _error("42000", "Incorrect syntax near 'FROM'.")
+    connection._end_execution(syntax)
+    assert syntax.blocking_chain is None
+
+    connection = _ExecutionConnection(diagnose=False)
+    timeout = _error("HYT00", "Query timeout expired")
+    connection._end_execution(timeout)
+    assert timeout.blocking_chain is None
+
+    connection = _ExecutionConnection(diagnose=True)
+    connection._closing = True
+    cancelled = _error("HY008", "Operation canceled")
+    connection._end_execution(cancelled)
+    assert cancelled.blocking_chain is None
+    assert captured == []
+
+
+def test_diagnose_blocking_must_be_bool():
+    connection = _ExecutionConnection(diagnose=False)
+    with pytest.raises(TypeError):
+        connection.diagnose_blocking = "yes"
+
+
+def test_lock_timeout_reports_blocker(conn_str):
+    setup = connect(conn_str, autocommit=True)
+    blocker = connect(conn_str)
+    victim = connect(conn_str, autocommit=True)
+    try:
+        setup.cursor().execute("CREATE TABLE ##blocking_diagnostics (id INT)")
+        blocker_cursor = blocker.cursor()
+        blocker_cursor.execute("INSERT INTO ##blocking_diagnostics VALUES (1)")
+        blocker_spid = blocker_cursor.execute("SELECT @@SPID").fetchall()[0][0]
+
+        victim.diagnose_blocking = True
+        victim.timeout = 1
+        with pytest.raises(OperationalError) as exc_info:
+            victim.cursor().execute("SELECT id FROM ##blocking_diagnostics")
+        chain = exc_info.value.blocking_chain
+        if not chain:
+            pytest.skip("VIEW SERVER STATE is required to see the blocking session")
+        assert any(e["session_id"] == blocker_spid and e["head_blocker"] for e in chain)
+    finally:
+        victim.close()
+        blocker.rollback()
+        blocker.close()
+        setup.cursor().execute("DROP TABLE IF EXISTS ##blocking_diagnostics")
+        setup.close()
@@ -0,0 +1,129 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for per-operator progress of running statements (cursor.progress())."""
+
+import threading
+import weakref
+
+import pytest
+
+from mssql_python import DatabaseError, ProgrammingError, connect
+from mssql_python.cursor import Cursor
+from mssql_python.progress import operator_progress
+
+# A parallel scan (node 1, two threads) feeding a gather streams (node 0)
+PROFILE_ROWS = [
+    (0, "Parallelism", None, 500, 2000, 900, 10, 0),
+    (1, "Clustered Index Scan", "orders", 300, 1000, 850, 400, 120),
+    (1, "Clustered Index Scan", "orders", 400, 1000, 870, 420, 130),
+    (2, "Constant Scan", None, 0, 0, 1, 0, 0),
+]
+
+
+class _MonitorCursor:
+    def __init__(self, rows):
+        self.rows = rows
+        self.executed = []
+        self.closed = False
+
+    def execute(self, sql, *params):
+        self.executed.append(params)
+        return self
+
+    def fetchall(self):
+        return self.rows
+
+    def close(self):
+        self.closed = True
+
+
+class _Monitor:
+    def __init__(self, rows):
+        self.last_cursor = _MonitorCursor(rows)
+
+    def cursor(self):
+        return self.last_cursor
+
+
+def test_operator_counters_are_combined_over_threads():
+    monitor = _Monitor(PROFILE_ROWS)
+    operators = operator_progress(monitor, 57)
+    assert monitor.last_cursor.executed == [(57,)]
+    assert monitor.last_cursor.closed
+    assert [op["node_id"] for op in operators] == [0, 1, 2]
+    scan = operators[1]
+    assert scan["operator"] == "Clustered Index Scan"
+    assert scan["object_name"] == "orders"
+    assert scan["row_count"] == 700
+    assert scan["estimate_row_count"] == 1000
+    assert scan["percent_complete"] == 70.0
+    assert scan["elapsed_time_ms"] == 870
+    assert scan["cpu_time_ms"] == 820
+    assert scan["logical_read_count"] == 250
+    assert scan["threads"] == 2
+    assert operators[0]["percent_complete"] == 25.0
+    assert operators[2]["percent_complete"] is None
+
+
+class _ProgressConnection:
+    def __init__(self, monitor, session_id):
+        self._executing_cursors = weakref.WeakSet()
+        self._session_id = session_id
+        self._monitor_lock = threading.Lock()
+        self.monitor = monitor
+
+    def _monitoring_connection(self):
+        return self.monitor
+
+
+class _RunningCursor(Cursor):
+    def __init__(self, connection):  # pylint: disable=super-init-not-called
+        self._connection = connection
+        self.closed = False
+        self._results_pending = False
+
+    def __del__(self):
+        pass
+
+
+def test_progress_of_running_statement():
+    connection = _ProgressConnection(_Monitor(PROFILE_ROWS), session_id=57)
+    cursor = _RunningCursor(connection)
+    assert cursor.progress() == []
+
+    connection._executing_cursors.add(cursor)
+    assert len(cursor.progress()) == 3
+    connection._executing_cursors.discard(cursor)
+
+    # Rows still being fetched: the statement has not finished on the server
+    cursor._results_pending = True
+    assert len(cursor.progress()) == 3
+
+
+def test_unknown_session_is_rejected():
+    connection = _ProgressConnection(_Monitor([]), session_id=None)
+    cursor = _RunningCursor(connection)
+    connection._executing_cursors.add(cursor)
+    with pytest.raises(ProgrammingError):
+        cursor.progress()
+
+
+def test_progress_while_fetching(conn_str):
+    connection = connect(conn_str, autocommit=True)
+    try:
+        session_id = connection.session_id
+        assert session_id > 0 and connection.session_id == session_id
+        cursor = connection.cursor()
+        cursor.execute("SELECT a.object_id FROM sys.all_objects a CROSS JOIN sys.all_objects b")
+        cursor.fetchone()
+        try:
+            operators = cursor.progress()
+        except DatabaseError:
+            pytest.skip("VIEW SERVER STATE is required to read query profiles")
+        assert all(op["row_count"] >= 0 for op in operators)
+        cursor.close()
+        assert connection._monitor is not None
+    finally:
+        connection.close()
+    assert connection._monitor is None
@@ -0,0 +1,182 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for the Extended Events session consumer."""
+
+import datetime
+import threading
+
+import pytest
+
+from mssql_python import DatabaseError, ProgrammingError, XEventSession, connect
+from mssql_python.xevents import create_session_sql, parse_ring_buffer
+
+RPC = (
+    '<event name="rpc_completed" package="sqlserver" timestamp="{ts}">'
+    '<data name="duration"><value>{duration}</value></data>'
+    '<data name="object_name"><value>sp_executesql</value></data>'
+    '<action name="sql_text" package="sqlserver"><value>SELECT 1</value></action>'
+    "</event>"
+)
+DEADLOCK = (
+    '<event name="xml_deadlock_report" package="sqlserver" timestamp="2026-10-14T08:00:03.000Z">'
+    '<data name="xml_report"><type name="xml" package="package0"/>'
+    '<value><deadlock><victim-list><victimProcess id="process1"/></victim-list></deadlock>'
+    "</value></data></event>"
+)
+
+
+def _buffer(*events):
+    return f'<RingBufferTarget eventCount="{len(events)}">{"".join(events)}</RingBufferTarget>'
+
+
+def _rpc(second, duration=10):
+    return RPC.format(ts=f"2026-10-14T08:00:0{second}.000Z", duration=duration)
+
+
+def test_ring_buffer_events_are_parsed():
+    rpc, deadlock = parse_ring_buffer(_buffer(_rpc(1, 1500), DEADLOCK))
+    assert rpc["name"] == "rpc_completed"
+    utc = datetime.timezone.utc
+    assert rpc["timestamp"] == datetime.datetime(2026, 10, 14, 8, 0, 1, tzinfo=utc)
+    assert rpc["data"] == {"duration": "1500", "object_name": "sp_executesql"}
+    assert rpc["actions"] == {"sql_text": "SELECT 1"}
+    assert deadlock["data"]["xml_report"].startswith("<deadlock><victim-list>")
+    assert parse_ring_buffer("") == []
+
+
+def test_create_statement():
+    sql = create_session_sql(
+        "app_rpc", ["sqlserver.rpc_completed"], actions=["sqlserver.sql_text"], max_memory_kb=1024
+    )
+    assert sql.startswith("CREATE EVENT SESSION [app_rpc] ON SERVER ")
+    assert "ADD EVENT sqlserver.rpc_completed (ACTION (sqlserver.sql_text))" in sql
+    assert "package0.ring_buffer (SET max_memory = 1024)" in sql
+    assert " ON DATABASE " in create_session_sql("s", "sqlos.wait_info", scope="database")
+
+
+@pytest.mark.parametrize(
+    "name, events, actions",
+    [
+        ("bad name", ["sqlserver.rpc_completed"], ()),
+        ("s]; DROP", ["sqlserver.rpc_completed"], ()),
+        ("s", [], ()),
+        ("s", ["rpc_completed"], ()),
+        ("s", ["sqlserver.rpc_completed"], ["sqlserver.sql_text); --"]),
+    ],
+)
+def test_invalid_names_are_rejected(name, events, actions):
+    with pytest.raises(ValueError):
+        create_session_sql(name, events, actions)
+
+
+class _PolledSession(XEventSession):
+    """Session whose ring buffer contents come from a script."""
+
+    def __init__(self, buffers):  # pylint: disable=super-init-not-called
+        self.name = "app_rpc"
+        self.scope = "server"
+        self.owned = False
+        self._buffers = list(buffers)
+        self._lock = threading.Lock()
+        self._last_timestamp = None
+        self._seen_at_last = set()
+        self._closed = False
+
+    def _target_data(self):
+        return self._buffers.pop(0)
+
+
+def test_reads_return_only_new_events():
+    session = _PolledSession(
+        [
+            _buffer(_rpc(1), _rpc(2, 20)),
+            # A second event in the same millisecond as the last one returned
+            _buffer(_rpc(1), _rpc(2, 20), _rpc(2, 30)),
+            # The oldest event was evicted from the full buffer
+            _buffer(_rpc(2, 20), _rpc(2, 30), _rpc(3)),
+            _buffer(_rpc(2, 20), _rpc(2, 30), _rpc(3)),
+        ]
+    )
+    assert [e["data"]["duration"] for e in session.read()] == ["10", "20"]
+    assert [e["data"]["duration"] for e in session.read()] == ["30"]
+    assert [e["timestamp"].second for e in session.read()] == [3]
+    assert session.read() == []
+
+
+def test_stream_stops_with_event_and_rejects_bad_interval():
+    session = _PolledSession([_buffer(_rpc(1)), _buffer(_rpc(1), _rpc(2))])
+    stop = threading.Event()
+    seen = []
+    for event in session.stream(poll_interval=0.01, stop=stop):
+        seen.append(event["timestamp"].second)
+        if len(seen) == 2:
+            stop.set()
+    assert seen == [1, 2]
+    with pytest.raises(ValueError):
+        next(session.stream(poll_interval=0))
+
+
+def test_stopped_or_closed_sessions_cannot_be_read():
+    session = _PolledSession([None])
+    with pytest.raises(ProgrammingError):
+        session.read()
+    session._closed = True
+    with pytest.raises(ProgrammingError):
+        session.read()
+
+
+class _SessionCursor:
+    def __init__(self, executed, fail_on):
+        self.executed = executed
+        self.fail_on = fail_on
+
+    def execute(self, sql, use_prepare=True):
+        self.executed.append(sql.split(" ON ")[0])
+        if self.fail_on in sql:
+            raise DatabaseError("The event session could not be started", "")
+
+    def close(self):
+        pass
+
+
+class _SessionConnection:
+    def __init__(self, fail_on):
+        self.executed = []
+        self.fail_on = fail_on
+        self.closed = False
+
+    def _spawn_connection(self, autocommit=False):
+        return self
+
+    def cursor(self):
+        return _SessionCursor(self.executed, self.fail_on)
+
+    def close(self):
+        self.closed = True
+
+
+def test_session_that_cannot_start_is_dropped():
+    connection = _SessionConnection(fail_on="STATE = START")
+    with pytest.raises(DatabaseError, match="could not be started"):
+        XEventSession.create(connection, "app_rpc", ["sqlserver.rpc_completed"], keep=True)
+    assert connection.executed == [
+        "CREATE EVENT SESSION [app_rpc]",
+        "ALTER EVENT SESSION [app_rpc]",
+        "DROP EVENT SESSION [app_rpc]",
+    ]
+    assert connection.closed
+
+
+def test_attach_system_health(conn_str):
+    connection = connect(conn_str)
+    try:
+        try:
+            session = connection.attach_xevent_session("system_health")
+            events = session.read()
+        except (DatabaseError, ProgrammingError):
+            pytest.skip("system_health ring buffer is not readable on this server")
+        assert all(event["name"] for event in events)
+        session.close()
+    finally:
+        connection.close()
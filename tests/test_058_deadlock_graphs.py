@@ -0,0 +1,163 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for deadlock graphs attached to deadlock victim errors."""
+
+import pickle
+import threading
+import weakref
+
+import pytest
+
+from mssql_python import DatabaseError, connect
+from mssql_python import diagnostics
+from mssql_python.connection import Connection
+from mssql_python.exceptions import raise_exception
+
+GRAPH = '<deadlock><victim-list><victimProcess id="process2"/></victim-list></deadlock>'
+
+
+def _error(sqlstate, message, native_error=0):
+    try:
+        raise_exception(sqlstate, message, native_error)
+    except Exception as e:  # pylint: disable=broad-exception-caught
+        return e
+
+
+def _deadlock():
+    return _error("40001", "Transaction was deadlocked ... chosen as the deadlock victim.", 1205)
+
+
+class _GraphCursor:
+    def __init__(self, results):
+        self.results = list(results)
+        self.executed = []
+        self._timeout = 0
+        self.closed = False
+
+    def _set_timeout(self):
+        pass
+
+    def execute(self, sql, *params, use_prepare=True):
+        self.executed.append(params)
+        self.rows = self.results.pop(0) if self.results else []
+        return self
+
+    def fetchall(self):
+        return self.rows
+
+    def close(self):
+        self.closed = True
+
+
+class _GraphConnection:
+    def __init__(self, results, session_id=57):
+        self.last_cursor = _GraphCursor(results)
+        self._session_id = session_id
+
+    @property
+    def session_id(self):
+        if self._session_id is None:
+            raise _error("08S01", "Communication link failure")
+        return self._session_id
+
+    def cursor(self):
+        return self.last_cursor
+
+
+@pytest.fixture
+def sleeps(monkeypatch):
+    calls = []
+    monkeypatch.setattr(diagnostics.time, "sleep", calls.append)
+    return calls
+
+
+def test_graph_is_awaited_until_it_reaches_system_health(sleeps):
+    connection = _GraphConnection([[], [(None,)], [(GRAPH,)]])
+    assert diagnostics.capture_deadlock_graph(connection) == GRAPH
+    assert connection.last_cursor.executed == [(57,)] * 3
+    assert len(sleeps) == 2
+    assert connection.last_cursor.closed
+
+
+def test_missing_graph_or_session_gives_none(sleeps):
+    connection = _GraphConnection([])
+    assert diagnostics.capture_deadlock_graph(connection, wait=0) is None
+    assert diagnostics.capture_deadlock_graph(_GraphConnection([], session_id=None)) is None
+
+
+class _ExecutionConnection(Connection):
+    """Connection with only the execution tracking used by _end_execution()."""
+
+    def __init__(self, capture):  # pylint: disable=super-init-not-called
+        self._closed = False
+        self._closing = False
+        self._conn = None
+        self._active_executions = 1
+        self._executing_cursors = weakref.WeakSet()
+        self._state_lock = threading.Condition()
+        self._diagnose_blocking = False
+        self._capture_deadlock_graphs = capture
+
+    def __del__(self):
+        pass
+
+
+def test_deadlock_victims_get_the_graph(monkeypatch):
+    monkeypatch.setattr(diagnostics, "capture_deadlock_graph", lambda connection: GRAPH)
+    error = _deadlock()
+    _ExecutionConnection(capture=True)._end_execution(error)
+    assert error.deadlock_graph == GRAPH
+    assert pickle.loads(pickle.dumps(error)).deadlock_graph == GRAPH
+
+    error = _deadlock()
+    _ExecutionConnection(capture=False)._end_execution(error)
+    assert error.deadlock_graph is None
+
+
+def test_capture_deadlock_graphs_must_be_bool():
+    with pytest.raises(TypeError):
+        _ExecutionConnection(capture=False).capture_deadlock_graphs = 1
+
+
+def test_deadlock_against_server(conn_str):
+    setup = connect(conn_str, autocommit=True)
+    first, second = connect(conn_str), connect(conn_str)
+    setup.cursor().execute(
+        "CREATE TABLE ##deadlock_graphs (id INT PRIMARY KEY, v INT); "
+        "INSERT INTO ##deadlock_graphs VALUES (1, 0), (2, 0)"
+    )
+    errors = []
+    barrier = threading.Barrier(2)
+
+    def update(connection, order):
+        connection.capture_deadlock_graphs = True
+        cursor = connection.cursor()
+        try:
+            cursor.execute("UPDATE ##deadlock_graphs SET v = 1 WHERE id = ?", order[0])
+            barrier.wait(5)
+            cursor.execute("UPDATE ##deadlock_graphs SET v = 1 WHERE id = ?", order[1])
+        except DatabaseError as e:
+            errors.append(e)
+        finally:
+            connection.rollback()
+
+    threads = [
+        threading.Thread(target=update, args=(first, (1, 2))),
+        threading.Thread(target=update, args=(second, (2, 1))),
+    ]
+    try:
+        for thread in threads:
+            thread.start()
+        for thread in threads:
+            thread.join(30)
+        victims = [e for e in errors if e.native_error == 1205]
+        assert victims
+        if victims[0].deadlock_graph is None:
+            pytest.skip("system_health deadlock graphs are not readable on this server")
+        assert victims[0].deadlock_graph.startswith("<deadlock")
+    finally:
+        first.close()
+        second.close()
+        setup.cursor().execute("DROP TABLE IF EXISTS ##deadlock_graphs")
+        setup.close()
@@ -0,0 +1,107 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for full-text search condition builders and catalog status helpers."""
+
+import pytest
+
+from mssql_python import OperationalError, ProgrammingError
+from mssql_python import fulltext
+
+
+@pytest.mark.parametrize(
+    "text, prefix, expected",
+    [
+        ("sql server", False, '"sql server"'),
+        ("data", True, '"data*"'),
+        ('say "hi"', False, '"say ""hi"""'),
+        ("a* OR b", False, '"a OR b"'),
+        ("  NEAR(x)  AND  ", False, '"NEAR(x) AND"'),
+    ],
+)
+def test_phrase_quotes_user_input(text, prefix, expected):
+    assert fulltext.phrase(text, prefix) == expected
+
+
+@pytest.mark.parametrize("text", ["", "  ", "***", None])
+def test_phrase_without_words_is_rejected(text):
+    with pytest.raises(ValueError):
+        fulltext.phrase(text)
+
+
+def test_contains_condition():
+    assert fulltext.contains_condition("sql") == '"sql"'
+    assert fulltext.contains_condition(["sql", "server"]) == '"sql" AND "server"'
+    assert fulltext.contains_condition(["sq", "serv"], "or", prefix=True) == '"sq*" OR "serv*"'
+    assert (
+        fulltext.contains_condition(["a", "b"], "OR", exclude=["beta"])
+        == '("a" OR "b") AND NOT "beta"'
+    )
+    with pytest.raises(ValueError):
+        fulltext.contains_condition(["a"], "NEAR")
+    with pytest.raises(ValueError):
+        fulltext.contains_condition([])
+
+
+def test_near_condition():
+    assert fulltext.near_condition(["sql", "server"]) == 'NEAR(("sql", "server"), MAX, FALSE)'
+    assert fulltext.near_condition(["a", "b"], 3, ordered=True) == 'NEAR(("a", "b"), 3, TRUE)'
+    for terms, distance in [(["only"], None), ("ab", None), (["a", "b"], -1)]:
+        with pytest.raises(ValueError):
+            fulltext.near_condition(terms, distance)
+
+
+def test_contains_predicate():
+    assert fulltext.contains_predicate() == "CONTAINS(*, ?)"
+    assert fulltext.contains_predicate("body") == "CONTAINS([body], ?)"
+    assert (
+        fulltext.contains_predicate(["title", "bo]dy"], "freetext", language=1033)
+        == "FREETEXT(([title], [bo]]dy]), ?, LANGUAGE 1033)"
+    )
+    assert fulltext.contains_predicate(language="English").endswith(", LANGUAGE 'English')")
+    for kwargs in [{"function": "MATCH"}, {"language": "x'; --"}, {"columns": []}]:
+        with pytest.raises(ValueError):
+            fulltext.contains_predicate(**kwargs)
+
+
+class _StatusCursor:
+    def __init__(self, statuses):
+        self.statuses = statuses
+
+    def execute(self, sql, *params):
+        self.row = (self.statuses.pop(0), 1200, 1000000)
+        return self
+
+    def fetchall(self):
+        return [self.row]
+
+    def close(self):
+        pass
+
+
+class _StatusConnection:
+    def __init__(self, statuses):
+        self._cursor = _StatusCursor(list(statuses))
+
+    def cursor(self):
+        return self._cursor
+
+
+def test_population_status_and_wait(monkeypatch):
+    monkeypatch.setattr(fulltext.time, "sleep", lambda seconds: None)
+    status = fulltext.population_status(_StatusConnection([6]), "docs")
+    assert status["description"] == "incremental population in progress"
+    assert not status["idle"] and status["item_count"] == 1200
+
+    status = fulltext.wait_for_population(_StatusConnection([1, 7, 0]), "docs")
+    assert status["idle"]
+
+    with pytest.raises(OperationalError):
+        fulltext.wait_for_population(_StatusConnection([1, 1]), "docs", timeout=0)
+    with pytest.raises(ProgrammingError):
+        fulltext.population_status(_StatusConnection([None]), "missing")
+
+
+def test_missing_catalog_against_server(db_connection):
+    with pytest.raises(ProgrammingError):
+        db_connection.fulltext_population_status("mssql_python_no_such_catalog")
@@ -0,0 +1,148 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for the index maintenance helpers (connection.maintenance)."""
+
+import threading
+import warnings
+
+import pytest
+
+from mssql_python import IndexMaintenance, ProgrammingError
+from mssql_python.maintenance import rebuild_index_sql, server_capabilities
+
+ENTERPRISE_2022 = server_capabilities(16, 3)
+ENTERPRISE_2016 = server_capabilities(13, 3)
+STANDARD_2022 = server_capabilities(16, 2)
+AZURE = server_capabilities(12, 5)
+
+
+def test_capabilities_follow_edition_and_version():
+    assert ENTERPRISE_2022["resumable"] and ENTERPRISE_2022["online_columnstore"]
+    assert ENTERPRISE_2016["online"] and not ENTERPRISE_2016["resumable"]
+    assert not STANDARD_2022["online"] and not STANDARD_2022["resumable"]
+    # Azure SQL Database reports an old ProductMajorVersion but has every feature
+    assert AZURE["resumable"] and AZURE["online_columnstore"]
+
+
+def test_resumable_online_rebuild():
+    sql = rebuild_index_sql("dbo.orders", "ix_date", ENTERPRISE_2022, maxdop=4, max_duration=60)
+    assert sql == (
+        "ALTER INDEX [ix_date] ON [dbo].[orders] REBUILD "
+        "WITH (ONLINE = ON, RESUMABLE = ON, MAX_DURATION = 60 MINUTES, MAXDOP = 4)"
+    )
+
+
+def test_options_are_dropped_where_unsupported():
+    with pytest.warns(Warning, match="Resumable"):
+        sql = rebuild_index_sql("orders", "ix_date", ENTERPRISE_2016)
+    assert sql.endswith("REBUILD WITH (ONLINE = ON)")
+    with pytest.warns(Warning, match="offline"):
+        sql = rebuild_index_sql("orders", "ix_date", STANDARD_2022)
+    assert sql == "ALTER INDEX [ix_date] ON [orders] REBUILD"
+    with warnings.catch_warnings():
+        warnings.simplefilter("error")
+        # Resumable is never possible for ALL or columnstore, so no warning
+        assert rebuild_index_sql("orders", "all", ENTERPRISE_2022).endswith(
+            "ALL ON [orders] REBUILD WITH (ONLINE = ON)"
+        )
+        sql = rebuild_index_sql("orders", "cci", ENTERPRISE_2022, columnstore=True)
+        assert sql.endswith("REBUILD WITH (ONLINE = ON)")
+    with pytest.warns(Warning):
+        rebuild_index_sql("orders", "cci", ENTERPRISE_2016, columnstore=True)
+
+
+@pytest.mark.parametrize("kwargs", [{"maxdop": 0}, {"max_duration": "60"}, {"maxdop": True}])
+def test_invalid_options_are_rejected(kwargs):
+    with pytest.raises(ValueError):
+        rebuild_index_sql("orders", "ix_date", ENTERPRISE_2022, **kwargs)
+
+
+class _MaintenanceCursor:
+    def __init__(self, connection):
+        self._connection = connection
+
+    def execute(self, sql, *params, use_prepare=True):
+        self._connection.executed.append(sql)
+        self.rows = self._connection.results.pop(0) if sql.startswith("SELECT") else []
+        return self
+
+    def fetchall(self):
+        return self.rows
+
+    def close(self):
+        pass
+
+
+class _MaintenanceConnection:
+    def __init__(self, results, autocommit=True):
+        self.results = list(results)
+        self.autocommit = autocommit
+        self.executed = []
+        self._monitor_lock = threading.Lock()
+        self.monitor = _MaintenanceMonitor(self)
+
+    def cursor(self):
+        return _MaintenanceCursor(self)
+
+    def _monitoring_connection(self):
+        return self.monitor
+
+
+class _MaintenanceMonitor:
+    def __init__(self, connection):
+        self._connection = connection
+
+    def cursor(self):
+        self._connection.executed.append("monitor:")
+        return _MaintenanceCursor(self._connection)
+
+
+def test_rebuild_checks_index_and_autocommit():
+    connection = _MaintenanceConnection([[(16, 3)], [(2,)]])
+    sql = IndexMaintenance(connection).rebuild_index("dbo.orders", "ix_date")
+    assert connection.executed[-1] == sql
+    assert "RESUMABLE = ON" in sql
+
+    connection = _MaintenanceConnection([[(16, 3)], [(2,)]], autocommit=False)
+    with pytest.raises(ProgrammingError):
+        IndexMaintenance(connection).rebuild_index("dbo.orders", "ix_date")
+
+    connection = _MaintenanceConnection([[(16, 3)], []])
+    with pytest.raises(ProgrammingError):
+        IndexMaintenance(connection).rebuild_index("dbo.orders", "missing")
+
+
+def test_resumable_operations_are_controlled_on_the_monitor():
+    row = ("dbo.orders", "ix_date", "PAUSED", 42.5, None, None, 3.0, 1000, "ALTER INDEX ...")
+    connection = _MaintenanceConnection([[row]])
+    maintenance = IndexMaintenance(connection)
+    operations = maintenance.resumable_operations("dbo.orders")
+    assert operations[0]["state"] == "PAUSED" and operations[0]["percent_complete"] == 42.5
+    assert connection.executed[-1].endswith("WHERE object_id = OBJECT_ID(?)")
+
+    maintenance.pause("dbo.orders", "ix_date")
+    maintenance.abort("dbo.orders", "ix_date")
+    assert connection.executed[-4:] == [
+        "monitor:",
+        "ALTER INDEX [ix_date] ON [dbo].[orders] PAUSE",
+        "monitor:",
+        "ALTER INDEX [ix_date] ON [dbo].[orders] ABORT",
+    ]
+    sql = maintenance.resume("dbo.orders", "ix_date", maxdop=2)
+    assert sql == "ALTER INDEX [ix_date] ON [dbo].[orders] RESUME WITH (MAXDOP = 2)"
+
+
+def test_rebuild_and_reorganize_against_server(db_connection):
+    cursor = db_connection.cursor()
+    cursor.execute("CREATE TABLE #maintenance (id INT PRIMARY KEY, v INT INDEX ix_v)")
+    try:
+        maintenance = db_connection.maintenance
+        assert maintenance is db_connection.maintenance
+        with warnings.catch_warnings():
+            warnings.simplefilter("ignore")
+            sql = maintenance.rebuild_index("#maintenance", "ix_v", resumable=False)
+        assert sql.startswith("ALTER INDEX [ix_v] ON [#maintenance] REBUILD")
+        assert maintenance.reorganize_index("#maintenance").endswith("REORGANIZE")
+    finally:
+        cursor.execute("DROP TABLE #maintenance")
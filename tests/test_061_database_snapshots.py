@@ -0,0 +1,125 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for the database snapshot helpers (Connection.create_snapshot and friends)."""
+
+import uuid
+
+import pytest
+
+from mssql_python import ProgrammingError, connect
+from mssql_python.snapshots import (
+    create_snapshot_sql,
+    revert_to_snapshot,
+    snapshot_file_path,
+)
+
+
+def test_sparse_files_follow_the_server_path_style():
+    assert snapshot_file_path(r"C:\Data\app.mdf", "app_ss", "app") == r"C:\Data\app_ss_app.ss"
+    assert (
+        snapshot_file_path("/var/opt/mssql/data/app.mdf", "app_ss", "app")
+        == "/var/opt/mssql/data/app_ss_app.ss"
+    )
+    assert snapshot_file_path(r"C:\Data\app.mdf", "s", "app", r"D:\Snaps") == r"D:\Snaps\s_app.ss"
+    assert snapshot_file_path("/data/app.mdf", "s", "app", "/snaps") == "/snaps/s_app.ss"
+
+
+def test_create_snapshot_sql_quotes_names_and_paths():
+    files = [("app", r"C:\Data\app.mdf"), ("app_2", r"C:\O'Brien\app_2.ndf")]
+    assert create_snapshot_sql("app]ss", "app", files) == (
+        r"CREATE DATABASE [app]]ss] ON (NAME = [app], FILENAME = N'C:\Data\app]ss_app.ss'), "
+        r"(NAME = [app_2], FILENAME = N'C:\O''Brien\app]ss_app_2.ss') AS SNAPSHOT OF [app]"
+    )
+    with pytest.raises(ProgrammingError):
+        create_snapshot_sql("app_ss", "missing", [])
+
+
+class _SnapshotCursor:
+    def __init__(self, connection):
+        self._connection = connection
+
+    def execute(self, sql, *params, use_prepare=True):
+        self._connection.executed.append(sql)
+        self.rows = self._connection.results.pop(0) if sql.startswith("SELECT") else []
+        return self
+
+    def fetchall(self):
+        return self.rows
+
+    def nextset(self):
+        return False
+
+    def close(self):
+        pass
+
+
+class _SnapshotConnection:
+    def __init__(self, results, autocommit=True):
+        self.results = list(results)
+        self.autocommit = autocommit
+        self.executed = []
+
+    def cursor(self):
+        return _SnapshotCursor(self)
+
+
+def test_revert_switches_database_and_waits_until_online():
+    snapshot = ("app_ss", "app", None)
+    connection = _SnapshotConnection(
+        [[snapshot], [snapshot], [("app",)], [("RESTORING",)], [("ONLINE",)]]
+    )
+    revert_to_snapshot(connection, "app_ss")
+    statements = [sql for sql in connection.executed if not sql.startswith("SELECT")]
+    assert statements == [
+        "USE [master]",
+        "ALTER DATABASE [app] SET SINGLE_USER WITH ROLLBACK IMMEDIATE",
+        "RESTORE DATABASE [app] FROM DATABASE_SNAPSHOT = N'app_ss'",
+        "ALTER DATABASE [app] SET MULTI_USER",
+        "USE [app]",
+    ]
+
+
+def test_revert_refuses_with_other_snapshots_or_without_autocommit():
+    snapshot = ("app_ss", "app", None)
+    connection = _SnapshotConnection([[snapshot], [snapshot, ("app_ss2", "app", None)]])
+    with pytest.raises(ProgrammingError, match="other snapshots"):
+        revert_to_snapshot(connection, "app_ss")
+    assert all(sql.startswith("SELECT") for sql in connection.executed)
+
+    with pytest.raises(ProgrammingError, match="does not exist"):
+        revert_to_snapshot(_SnapshotConnection([[snapshot]]), "other_ss")
+    with pytest.raises(ProgrammingError, match="autocommit"):
+        revert_to_snapshot(_SnapshotConnection([], autocommit=False), "app_ss")
+
+
+def test_snapshot_create_revert_and_drop(conn_str):
+    conn = connect(conn_str, autocommit=True)
+    database = f"snapshot_test_{uuid.uuid4().hex[:8]}"
+    snapshot = f"{database}_ss"
+    try:
+        try:
+            conn.cursor().execute(f"CREATE DATABASE [{database}]")
+        except Exception as e:  # pylint: disable=broad-exception-caught
+            pytest.skip(f"Cannot create a database here: {e}")
+        try:
+            conn.create_snapshot(snapshot, database)
+        except Exception as e:  # pylint: disable=broad-exception-caught
+            pytest.skip(f"Database snapshots are not available here: {e}")
+        assert [entry["name"] for entry in conn.snapshots(database)] == [snapshot]
+
+        conn.cursor().execute(f"CREATE TABLE [{database}].dbo.changed (id INT)")
+        conn.revert_to_snapshot(snapshot)
+        cursor = conn.cursor()
+        cursor.execute(f"SELECT OBJECT_ID('[{database}].dbo.changed')")
+        assert cursor.fetchone()[0] is None
+
+        conn.drop_snapshot(snapshot)
+        assert conn.snapshots(database) == []
+        with pytest.raises(ProgrammingError):
+            conn.drop_snapshot(database)
+    finally:
+        cursor = conn.cursor()
+        cursor.execute(f"IF DB_ID('{snapshot}') IS NOT NULL DROP DATABASE [{snapshot}]")
+        cursor.execute(f"IF DB_ID('{database}') IS NOT NULL DROP DATABASE [{database}]")
+        conn.close()
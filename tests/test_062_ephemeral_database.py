@@ -0,0 +1,135 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for ephemeral test databases (mssql_python.testing)."""
+
+import os
+import subprocess
+import sys
+
+import pytest
+
+from mssql_python import EphemeralDatabase, ProgrammingError, connect, testing
+from mssql_python.testing import ephemeral_database_name, find_orphaned_databases
+
+
+def test_names_are_unique_and_carry_the_owner():
+    name = ephemeral_database_name()
+    assert name.startswith("mssql_python_test_") and f"_{os.getpid()}_" in name
+    assert name != ephemeral_database_name()
+    assert ephemeral_database_name("ci_").startswith("ci_")
+    for prefix in ("", "1abc", "a-b", "a]"):
+        with pytest.raises(ValueError):
+            ephemeral_database_name(prefix)
+
+
+class _AdminCursor:
+    def __init__(self, connection):
+        self._connection = connection
+
+    def execute(self, sql, *params, use_prepare=True):
+        self._connection.executed.append(sql)
+        self.rows = self._connection.results.pop(0) if sql.startswith("SELECT") else []
+        return self
+
+    def fetchall(self):
+        return self.rows
+
+    def close(self):
+        pass
+
+
+class _AdminConnection:
+    def __init__(self, results=()):
+        self.results = list(results)
+        self.executed = []
+        self.autocommit = True
+        self.closed = False
+        self.spawned = []
+
+    def cursor(self):
+        return _AdminCursor(self)
+
+    def _spawn_connection(self, autocommit=False, database=None):
+        if database is None:
+            return self
+        conn = _AdminConnection()
+        self.spawned.append((conn, database))
+        return conn
+
+    def _spawn_connection_string(self, database=None):
+        return f"Server=test;Database={database}"
+
+    def close(self):
+        self.closed = True
+
+
+def _dead_pid():
+    process = subprocess.Popen([sys.executable, "-c", "pass"])
+    process.wait()
+    return process.pid
+
+
+def test_orphans_are_databases_of_dead_processes_or_old_ones():
+    if os.name == "nt":
+        pytest.skip("Process liveness is not checked on Windows")
+    host = testing._host_tag()
+    dead = f"mssql_python_test_{host}_{_dead_pid()}_0123abcd"
+    mine = f"mssql_python_test_{host}_{os.getpid()}_0123abcd"
+    remote_young = "mssql_python_test_ffffffff_1_0123abcd"
+    remote_old = "mssql_python_test_ffffffff_2_0123abcd"
+    unrelated = "mssql_python_test_backup"
+    rows = [(dead, 10), (mine, 10), (remote_young, 10), (remote_old, 90000), (unrelated, 90000)]
+    orphans = find_orphaned_databases(_AdminConnection([rows]))
+    assert orphans == [dead, remote_old]
+
+    # A database still in use by this process is never an orphan, whatever its age
+    testing._live[remote_old] = object()
+    try:
+        orphans = find_orphaned_databases(_AdminConnection([rows]), max_age=0)
+        assert orphans == [dead, mine, remote_young]
+    finally:
+        del testing._live[remote_old]
+
+
+def test_lifecycle_creates_binds_and_drops():
+    admin = _AdminConnection([[], [], []])
+    ephemeral = EphemeralDatabase(admin)
+    with pytest.raises(ProgrammingError):
+        ephemeral.connect()
+    with ephemeral as db:
+        assert db.name in testing._live
+        assert admin.executed[-1] == f"CREATE DATABASE [{db.name}]"
+        conn = db.connect()
+        assert admin.spawned == [(conn, db.name)]
+        assert db.connection_string.endswith(f"Database={db.name}")
+        assert db.pool().writer == db.connection_string
+    assert conn.closed and admin.closed
+    assert db.name not in testing._live
+    assert admin.executed[-2:] == [
+        f"ALTER DATABASE [{db.name}] SET SINGLE_USER WITH ROLLBACK IMMEDIATE",
+        f"DROP DATABASE [{db.name}]",
+    ]
+    with pytest.raises(ProgrammingError):
+        db.connect()
+
+
+def test_ephemeral_database_against_server(conn_str):
+    admin = connect(conn_str)
+    try:
+        try:
+            db = EphemeralDatabase(admin).create()
+        except Exception as e:  # pylint: disable=broad-exception-caught
+            pytest.skip(f"Cannot create a database here: {e}")
+        with db:
+            conn = db.connect()
+            cursor = conn.cursor()
+            cursor.execute("SELECT DB_NAME()")
+            assert cursor.fetchone()[0] == db.name
+            pooled = db.pool(autocommit=True).connect()
+            pooled.cursor().execute("CREATE TABLE t (id INT)")
+        cursor = admin.cursor()
+        cursor.execute("SELECT DB_ID(?)", db.name)
+        assert cursor.fetchone()[0] is None
+    finally:
+        admin.close()
@@ -0,0 +1,121 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for LocalDB and SQL Express discovery (mssql_python.discovery)."""
+
+import pytest
+
+from mssql_python import InterfaceError, OperationalError, discovery, local_instances
+from mssql_python import connection as connection_module
+from mssql_python.connection import Connection
+from mssql_python.connection_string_parser import _ConnectionStringParser
+
+PIPE = r"np:\\.\pipe\LOCALDB#1A2B3C4D\tsql\query"
+RUNNING = f"""Name:               MSSQLLocalDB
+Version:            15.0.4153.1
+Shared name:
+Owner:              CONTOSO\\dev
+Auto-create:        Yes
+State:              Running
+Last start time:    10/14/2026 09:12:44
+Instance pipe name: {PIPE}
+"""
+STOPPED = RUNNING.replace("Running", "Stopped").replace(PIPE, "")
+
+
+def test_localdb_server_names_are_recognized():
+    assert discovery.localdb_instance(r"(localdb)\MSSQLLocalDB") == "MSSQLLocalDB"
+    assert discovery.localdb_instance(r" (LocalDB)\ProjectsV13 ") == "ProjectsV13"
+    for server in (r".\SQLEXPRESS", "localhost", r"(localdb)\\", "tcp:(localdb)\\x"):
+        assert discovery.localdb_instance(server) is None
+
+
+def test_pipe_name_is_read_from_info_output():
+    assert discovery.parse_localdb_pipe(RUNNING) == PIPE
+    assert discovery.parse_localdb_pipe(STOPPED) is None
+
+
+@pytest.fixture
+def sqllocaldb(monkeypatch):
+    """Pretend to run on Windows, with a stopped MSSQLLocalDB instance."""
+    calls = []
+    state = {"running": False}
+
+    def run(*args):
+        calls.append(args)
+        if args[0] == "start":
+            state["running"] = True
+            return ""
+        if len(args) == 1:
+            return "MSSQLLocalDB\nProjectsV13\n"
+        return RUNNING if state["running"] else STOPPED
+
+    monkeypatch.setattr(discovery, "_is_windows", lambda: True)
+    monkeypatch.setattr(discovery, "_sqllocaldb", lambda: r"C:\Tools\SqlLocalDB.exe")
+    monkeypatch.setattr(discovery, "_run_sqllocaldb", run)
+    monkeypatch.setattr(discovery, "_pipes", {})
+    return calls
+
+
+def test_stopped_instance_is_started_and_its_pipe_remembered(sqllocaldb):
+    server = r"(localdb)\MSSQLLocalDB"
+    assert discovery.resolve_localdb(server) == PIPE
+    info = ("info", "MSSQLLocalDB")
+    assert sqllocaldb == [info, ("start", "MSSQLLocalDB"), info]
+    assert discovery.resolve_localdb(r"(localdb)\mssqllocaldb") == PIPE
+    assert len(sqllocaldb) == 3
+
+    discovery.forget_localdb_pipe(server)
+    assert discovery.resolve_localdb(server) == PIPE
+    assert len(sqllocaldb) == 4
+    assert discovery.resolve_localdb("dc1-sql") == "dc1-sql"
+
+
+def test_instance_that_does_not_start_raises(sqllocaldb, monkeypatch):
+    monkeypatch.setattr(discovery, "_run_sqllocaldb", lambda *args: STOPPED)
+    with pytest.raises(OperationalError):
+        discovery.resolve_localdb(r"(localdb)\MSSQLLocalDB")
+
+
+def test_localdb_needs_windows(monkeypatch):
+    monkeypatch.setattr(discovery, "_is_windows", lambda: False)
+    with pytest.raises(InterfaceError, match="only available on Windows"):
+        discovery.resolve_localdb(r"(localdb)\MSSQLLocalDB")
+    assert local_instances() == []
+
+
+def test_local_instances_lists_localdb_and_registered_instances(sqllocaldb, monkeypatch):
+    registered = [
+        discovery._instance_entry("MSSQLSERVER", "Developer Edition (64-bit)"),
+        discovery._instance_entry("SQLEXPRESS", "Express Edition (64-bit)"),
+        discovery._instance_entry("REPORTING", "Standard Edition (64-bit)"),
+    ]
+    monkeypatch.setattr(discovery, "_registry_instances", lambda: registered)
+    instances = local_instances()
+    assert [(i["server"], i["kind"]) for i in instances] == [
+        (r"(localdb)\MSSQLLocalDB", "localdb"),
+        (r"(localdb)\ProjectsV13", "localdb"),
+        ("localhost", "default"),
+        (r".\SQLEXPRESS", "express"),
+        (r".\REPORTING", "named"),
+    ]
+
+
+def test_connection_uses_the_instance_pipe(sqllocaldb, monkeypatch):
+    servers = []
+
+    class _FakeSqlConnection:
+        def __init__(self, conn_str, pooling, attrs_before):
+            params = _ConnectionStringParser(validate_keywords=False)._parse(conn_str)
+            servers.append(params["server"])
+
+    monkeypatch.setattr(connection_module.ddbc_bindings, "Connection", _FakeSqlConnection)
+    connection = Connection.__new__(Connection)
+    connection.connection_str, _ = connection._construct_connection_string(
+        r"Server=(localdb)\MSSQLLocalDB;Trusted_Connection=yes"
+    )
+    connection._pooling = False
+    connection._attrs_before = {}
+    connection._conn = connection._open_session()
+    assert servers == [PIPE]
+    assert connection.server == r"(localdb)\MSSQLLocalDB"
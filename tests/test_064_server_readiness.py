@@ -0,0 +1,164 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for wait_for_server() and its TDS pre-login probe."""
+
+import socket
+import struct
+import threading
+
+import pytest
+
+from mssql_python import OperationalError, wait_for_server
+from mssql_python import readiness
+from mssql_python.readiness import (
+    AUTH_FAILED,
+    PORT_CLOSED,
+    READY,
+    SERVER_STARTING,
+    classify_connect_error,
+    parse_prelogin_response,
+    prelogin_packet,
+    probe_prelogin,
+)
+
+# VERSION 16.0.4135 and ENCRYPTION off, as SQL Server 2022 answers
+RESPONSE_PAYLOAD = (
+    struct.pack(">BHH", 0x00, 11, 6)
+    + struct.pack(">BHH", 0x01, 17, 1)
+    + b"\xff"
+    + struct.pack(">BBHH", 16, 0, 4135, 0)
+    + b"\x00"
+)
+RESPONSE = (
+    struct.pack(">BBHHBB", 0x04, 0x01, 8 + len(RESPONSE_PAYLOAD), 0, 1, 0) + RESPONSE_PAYLOAD
+)
+
+
+def test_prelogin_packet_layout():
+    packet = prelogin_packet()
+    assert packet[0] == 0x12 and packet[1] == 0x01
+    assert struct.unpack(">H", packet[2:4])[0] == len(packet) == 26
+    assert packet[8:13] == struct.pack(">BHH", 0x00, 11, 6)
+    assert packet[18] == 0xFF
+
+
+def test_prelogin_response_version():
+    assert parse_prelogin_response(RESPONSE) == "16.0.4135"
+    with pytest.raises(ValueError):
+        parse_prelogin_response(b"HTTP/1.1 400 Bad Request\r\n")
+
+
+@pytest.mark.parametrize(
+    "conn_str, endpoint",
+    [
+        ("Server=db;UID=sa", ("db", 1433)),
+        ("Server=tcp:db.example.com,14330", ("db.example.com", 14330)),
+        ("Server=.;Trusted_Connection=yes", ("localhost", 1433)),
+        ("Server=db\\SQLEXPRESS,1500", ("db", 1500)),
+        ("Server=db\\SQLEXPRESS", None),
+        ("Server=np:\\\\.\\pipe\\sql\\query", None),
+        ("Server=a|b", None),
+        ("Server=db;Encrypt=strict", None),
+    ],
+)
+def test_probed_endpoint(conn_str, endpoint):
+    assert readiness._tcp_endpoint(conn_str) == endpoint
+
+
+def _classify(message):
+    return classify_connect_error(OperationalError("Connection failed", message))
+
+
+def test_login_errors_are_classified():
+    assert _classify('[SQL Server]Cannot open database "app" requested by the login.') == (
+        SERVER_STARTING
+    )
+    assert _classify("Login failed for user 'sa'. Reason: Server is in script upgrade mode.") == (
+        SERVER_STARTING
+    )
+    assert _classify("[SQL Server]Login failed for user 'sa'.") == AUTH_FAILED
+    assert _classify("TCP Provider: No connection could be made") == PORT_CLOSED
+
+
+def _listen(handler):
+    server = socket.socket()
+    server.bind(("127.0.0.1", 0))
+    server.listen(1)
+
+    def serve():
+        client, _ = server.accept()
+        with client:
+            handler(client)
+        server.close()
+
+    threading.Thread(target=serve, daemon=True).start()
+    return server.getsockname()[1]
+
+
+def test_probe_tells_closed_starting_and_ready_apart():
+    closed = socket.socket()
+    closed.bind(("127.0.0.1", 0))
+    port = closed.getsockname()[1]
+    closed.close()
+    assert probe_prelogin("127.0.0.1", port, timeout=1) == (PORT_CLOSED, None)
+
+    port = _listen(lambda client: None)
+    assert probe_prelogin("127.0.0.1", port, timeout=1) == (SERVER_STARTING, None)
+
+    def answer(client):
+        client.recv(26)
+        client.sendall(RESPONSE)
+
+    port = _listen(answer)
+    assert probe_prelogin("127.0.0.1", port, timeout=1) == (READY, "16.0.4135")
+
+
+class _Connection:
+    def cursor(self):
+        return self
+
+    def execute(self, sql):
+        return self
+
+    def fetchall(self):
+        return [(1,)]
+
+    def close(self):
+        pass
+
+
+@pytest.fixture
+def logins(monkeypatch):
+    """Make connect() fail with the queued errors, then succeed."""
+    errors = []
+
+    def connect(conn_str, timeout=0, **kwargs):
+        if errors:
+            raise errors.pop(0)
+        return _Connection()
+
+    monkeypatch.setattr(readiness.db_connection, "connect", connect)
+    monkeypatch.setattr(readiness, "_tcp_endpoint", lambda conn_str: None)
+    return errors
+
+
+def test_wait_retries_while_the_server_starts(logins):
+    logins.extend([OperationalError("x", 'Cannot open database "master"')] * 2)
+    result = wait_for_server("Server=db", timeout=10, poll_interval=0.01)
+    assert result["state"] == READY and result["attempts"] == 3
+
+
+def test_wait_stops_at_auth_failure_and_timeout(logins):
+    logins.append(OperationalError("x", "Login failed for user 'sa'."))
+    with pytest.raises(OperationalError, match="Login failed"):
+        wait_for_server("Server=db", timeout=10, poll_interval=0.01)
+
+    logins.extend([OperationalError("x", 'Cannot open database "master"')] * 1000)
+    with pytest.raises(OperationalError, match="server_starting"):
+        wait_for_server("Server=db", timeout=0.1, poll_interval=0.01)
+
+
+def test_wait_for_real_server(conn_str):
+    result = wait_for_server(conn_str, timeout=30)
+    assert result["state"] == READY and result["attempts"] >= 1
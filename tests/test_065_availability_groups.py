@@ -0,0 +1,118 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for Always On availability group health (Connection.availability_groups)."""
+
+import pytest
+
+from mssql_python import OperationalError, ProgrammingError
+from mssql_python.availability import availability_groups, require_primary
+
+GROUPS = [("ag_orders", "SQL1", "HEALTHY")]
+REPLICAS = [
+    (
+        "ag_orders",
+        "SQL1",
+        1,
+        "PRIMARY",
+        "ONLINE",
+        "CONNECTED",
+        "HEALTHY",
+        "SYNCHRONOUS_COMMIT",
+        "AUTOMATIC",
+        "NO",
+    ),
+    (
+        "ag_orders",
+        "SQL2",
+        0,
+        "SECONDARY",
+        None,
+        "CONNECTED",
+        "HEALTHY",
+        "SYNCHRONOUS_COMMIT",
+        "AUTOMATIC",
+        "READ_ONLY",
+    ),
+]
+DATABASES = [
+    ("ag_orders", "SQL1", "orders", "SYNCHRONIZED", "HEALTHY", 0, None, 0, 0, None),
+    ("ag_orders", "SQL2", "orders", "SYNCHRONIZED", "HEALTHY", 0, None, 12, 40, None),
+]
+
+
+class _HadrCursor:
+    def __init__(self, results):
+        self._results = results
+
+    def execute(self, sql):
+        self.rows = self._results.pop(0)
+        return self
+
+    def fetchall(self):
+        return self.rows
+
+    def close(self):
+        pass
+
+
+class _HadrConnection:
+    def __init__(self, *results):
+        self.results = list(results)
+
+    def cursor(self):
+        return _HadrCursor(self.results)
+
+
+def _primary(health="HEALTHY", local=1):
+    replicas = [REPLICAS[0][:2] + (local,) + REPLICAS[0][3:], REPLICAS[1]]
+    return _HadrConnection([(1,)], [("ag_orders", "SQL1", health)], replicas, DATABASES)
+
+
+def test_groups_replicas_and_databases():
+    groups = availability_groups(_HadrConnection([(1,)], GROUPS, REPLICAS, DATABASES))
+    assert [group["name"] for group in groups] == ["ag_orders"]
+    group = groups[0]
+    assert group["local_role"] == "PRIMARY" and group["primary_replica"] == "SQL1"
+    primary, secondary = group["replicas"]
+    assert primary["is_local"] is True and not primary["readable_secondary"]
+    assert secondary["readable_secondary"] and secondary["role"] == "SECONDARY"
+    assert secondary["databases"] == [
+        {
+            "database": "orders",
+            "synchronization_state": "SYNCHRONIZED",
+            "synchronization_health": "HEALTHY",
+            "is_suspended": False,
+            "suspend_reason": None,
+            "log_send_queue_kb": 12,
+            "redo_queue_kb": 40,
+            "last_commit_time": None,
+        }
+    ]
+
+
+def test_servers_without_always_on_have_no_groups():
+    assert availability_groups(_HadrConnection([(0,)])) == []
+    with pytest.raises(ProgrammingError):
+        require_primary(_HadrConnection([(0,)]))
+
+
+def test_require_primary_gates_on_role_and_health():
+    assert require_primary(_primary(), "AG_ORDERS")["name"] == "ag_orders"
+    with pytest.raises(OperationalError, match="not the primary"):
+        require_primary(_primary(local=0))
+    with pytest.raises(OperationalError, match="not healthy"):
+        require_primary(_primary(health="PARTIALLY_HEALTHY"))
+    assert require_primary(_primary(health="PARTIALLY_HEALTHY"), require_healthy=False)
+    with pytest.raises(ProgrammingError):
+        require_primary(_primary(), "ag_other")
+
+
+def test_availability_groups_against_server(db_connection):
+    try:
+        groups = db_connection.availability_groups()
+    except Exception as e:  # pylint: disable=broad-exception-caught
+        pytest.skip(f"HADR DMVs are not readable here: {e}")
+    for group in groups:
+        assert group["local_role"] in ("PRIMARY", "SECONDARY", "RESOLVING", None)
+        assert all("readable_secondary" in replica for replica in group["replicas"])
@@ -0,0 +1,110 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for login failure triage: LoginError subclasses with remediation hints."""
+
+import pickle
+from unittest.mock import patch
+
+import pytest
+
+from mssql_python import (
+    CertificateTrustError,
+    DatabaseUnavailableError,
+    InvalidCredentialsError,
+    LoginDisabledError,
+    LoginError,
+    OperationalError,
+    ServerUnreachableError,
+    connect,
+)
+from mssql_python.exceptions import login_error
+
+SQL = "[Microsoft][ODBC Driver 18 for SQL Server][SQL Server]"
+
+
+@pytest.mark.parametrize(
+    "sqlstate, message, exception_class, hint",
+    [
+        ("28000", f"{SQL}Login failed for user 'app'.", InvalidCredentialsError, "UID/PWD"),
+        (
+            "28000",
+            f"{SQL}Login failed for user 'app'. Reason: The account is disabled.",
+            LoginDisabledError,
+            "ENABLE",
+        ),
+        (
+            "28000",
+            f"{SQL}Login failed for user 'app'. Reason: The password of the account has expired.",
+            LoginDisabledError,
+            "password",
+        ),
+        (
+            "42000",
+            f'{SQL}Cannot open database "orders" requested by the login. The login failed.',
+            DatabaseUnavailableError,
+            "exists",
+        ),
+        (
+            "42000",
+            f"{SQL}Cannot open server 'prod' requested by the login. Client with IP address "
+            "'203.0.113.7' is not allowed to access the server.",
+            ServerUnreachableError,
+            "firewall rule",
+        ),
+        (
+            "08001",
+            "[Microsoft][ODBC Driver 18 for SQL Server]SSL Provider: [error:0A000086:SSL "
+            "routines::certificate verify failed:unable to get local issuer certificate]",
+            CertificateTrustError,
+            "TrustServerCertificate",
+        ),
+        (
+            "HYT00",
+            "[Microsoft][ODBC Driver 18 for SQL Server]Login timeout expired",
+            ServerUnreachableError,
+            "1433",
+        ),
+    ],
+)
+def test_login_failures_are_told_apart(sqlstate, message, exception_class, hint):
+    error = login_error(sqlstate, message)
+    assert type(error) is exception_class
+    assert isinstance(error, LoginError) and isinstance(error, OperationalError)
+    assert hint in error.hint and error.hint in str(error)
+    assert error.sqlstate == sqlstate
+
+
+def test_unrecognized_errors():
+    error = login_error("28000", "[SQL Server]Some new reason")
+    assert type(error) is LoginError and error.hint is None
+    assert login_error("IM002", "Data source name not found") is None
+
+
+def test_hint_survives_pickling():
+    error = login_error("28000", f"{SQL}Login failed for user 'app'.")
+    copy = pickle.loads(pickle.dumps(error))
+    assert type(copy) is InvalidCredentialsError
+    assert copy.hint == error.hint and str(copy) == str(error)
+
+
+def test_connect_raises_the_triaged_error():
+    with patch(
+        "mssql_python.connection.ddbc_bindings.Connection",
+        side_effect=RuntimeError(
+            "SQLSTATE:08001:[Microsoft][ODBC Driver 18 for SQL Server]SSL Provider: "
+            "The certificate chain was issued by an authority that is not trusted."
+        ),
+    ):
+        with pytest.raises(CertificateTrustError) as exc_info:
+            connect("Server=testserver;Database=mydb;Trusted_Connection=yes;")
+    assert "ServerCertificate" in exc_info.value.hint
+
+
+def test_wrong_password_against_server(conn_str):
+    if "pwd=" not in conn_str.lower():
+        pytest.skip("Connection string has no password")
+    params = [part for part in conn_str.split(";") if not part.lower().startswith("pwd=")]
+    with pytest.raises(LoginError) as exc_info:
+        connect(";".join(params + ["PWD=not-the-password"]))
+    assert exc_info.value.hint
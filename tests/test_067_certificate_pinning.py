@@ -0,0 +1,197 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for TLS options: ServerCertificateHash pinning and HostnameInCertificate."""
+
+import shutil
+import socket
+import ssl
+import struct
+import subprocess
+import threading
+
+import pytest
+
+import mssql_python.connection as connection_module
+from mssql_python import CertificateTrustError, connect
+from mssql_python import tls
+from mssql_python.connection import Connection
+from mssql_python.connection_string_parser import _ConnectionStringParser
+from mssql_python.helpers import connstr_to_pycore_params
+from mssql_python.tls import (
+    certificate_fingerprint,
+    fetch_server_certificate,
+    parse_certificate_pins,
+    pin_server_certificate,
+)
+
+FINGERPRINT = "AB" * 32
+
+
+@pytest.fixture(scope="module")
+def certificate(tmp_path_factory):
+    """A self-signed certificate and key for sql.contoso.com, as (cert file, key file)."""
+    if shutil.which("openssl") is None:
+        pytest.skip("openssl is not installed")
+    directory = tmp_path_factory.mktemp("certificate")
+    cert_file, key_file = str(directory / "cert.pem"), str(directory / "key.pem")
+    subprocess.run(
+        ["openssl", "req", "-x509", "-newkey", "rsa:2048", "-nodes", "-days", "1"]
+        + ["-subj", "/CN=sql.contoso.com", "-keyout", key_file, "-out", cert_file],
+        check=True,
+        capture_output=True,
+    )
+    return cert_file, key_file
+
+
+@pytest.fixture(autouse=True)
+def _no_remembered_pins():
+    tls._pinned_files.clear()
+    yield
+    tls._pinned_files.clear()
+
+
+def _der(cert_file):
+    with open(cert_file, encoding="ascii") as pem:
+        return ssl.PEM_cert_to_DER_cert(pem.read())
+
+
+def _tds(packet_type, payload):
+    return struct.pack(">BBHHBB", packet_type, 0x01, 8 + len(payload), 0, 1, 0) + payload
+
+
+def _read_tds(client):
+    header = client.recv(8, socket.MSG_WAITALL)
+    length = struct.unpack(">H", header[2:4])[0]
+    return client.recv(length - 8, socket.MSG_WAITALL)
+
+
+def _serve_tds_tls(cert_file, key_file, encryption=0x01):
+    """Listen once as a SQL Server doing the TLS handshake inside PRELOGIN packets."""
+    server = socket.socket()
+    server.bind(("127.0.0.1", 0))
+    server.listen(1)
+    context = ssl.SSLContext(ssl.PROTOCOL_TLS_SERVER)
+    context.load_cert_chain(cert_file, key_file)
+
+    def serve():
+        client, _ = server.accept()
+        with client:
+            _read_tds(client)
+            payload = struct.pack(">BHH", 0x01, 6, 1) + b"\xff" + bytes([encryption])
+            client.sendall(_tds(0x04, payload))
+            incoming, outgoing = ssl.MemoryBIO(), ssl.MemoryBIO()
+            session = context.wrap_bio(incoming, outgoing, server_side=True)
+            try:
+                while True:
+                    incoming.write(_read_tds(client))
+                    try:
+                        session.do_handshake()
+                    except ssl.SSLWantReadError:
+                        pass
+                    if outgoing.pending:
+                        client.sendall(_tds(0x12, outgoing.read()))
+            except (OSError, ssl.SSLError, struct.error):
+                pass
+        server.close()
+
+    threading.Thread(target=serve, daemon=True).start()
+    return server.getsockname()[1]
+
+
+def test_pins_are_normalized():
+    spaced = ":".join(["ab"] * 32)
+    assert parse_certificate_pins(f"sha256:{spaced}, {'cd' * 32}") == [FINGERPRINT, "CD" * 32]
+    assert certificate_fingerprint(b"") == (
+        "E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855"
+    )
+    for value in ("", "sha1:ab", "ZZ" * 32):
+        with pytest.raises(ValueError):
+            parse_certificate_pins(value)
+
+
+def test_tls_keywords_are_normalized():
+    connection = Connection.__new__(Connection)
+    conn_str, params = connection._construct_connection_string(
+        "Server=dr-listener;Encrypt=yes",
+        trust_server_certificate=False,
+        hostname_in_certificate="sql.contoso.com",
+        server_certificate_hash=FINGERPRINT,
+    )
+    assert params["TrustServerCertificate"] == "no"
+    assert params["HostnameInCertificate"] == "sql.contoso.com"
+    assert params["ServerCertificateHash"] == FINGERPRINT
+    pycore = connstr_to_pycore_params(
+        _ConnectionStringParser(validate_keywords=False)._parse(conn_str)
+    )
+    assert pycore["host_name_in_certificate"] == "sql.contoso.com"
+    assert "servercertificatehash" not in pycore
+
+
+def test_certificate_fetched_through_the_prelogin(certificate):
+    port = _serve_tds_tls(*certificate)
+    assert fetch_server_certificate("127.0.0.1", port, timeout=5) == _der(certificate[0])
+
+
+def test_server_without_encryption_cannot_be_pinned(certificate):
+    port = _serve_tds_tls(*certificate, encryption=0x02)
+    with pytest.raises(CertificateTrustError, match="does not support encryption"):
+        fetch_server_certificate("127.0.0.1", port, timeout=5)
+
+
+def test_pin_mismatch_names_the_actual_fingerprint(certificate):
+    port = _serve_tds_tls(*certificate)
+    params = {"server": f"127.0.0.1,{port}", "servercertificatehash": FINGERPRINT}
+    with pytest.raises(CertificateTrustError) as exc_info:
+        pin_server_certificate(params, params["server"])
+    assert certificate_fingerprint(_der(certificate[0])) in exc_info.value.hint
+
+
+@pytest.mark.parametrize(
+    "params, message",
+    [
+        ({"encrypt": "optional"}, "Encrypt=yes"),
+        ({"servercertificate": "/certs/sql.pem"}, "cannot both be set"),
+    ],
+)
+def test_pinning_conflicts(params, message):
+    params = dict(params, server="db", servercertificatehash=FINGERPRINT)
+    with pytest.raises(ValueError, match=message):
+        pin_server_certificate(params, "db")
+
+
+def test_connection_passes_the_pinned_certificate(certificate, monkeypatch):
+    der = _der(certificate[0])
+    fetches = []
+    monkeypatch.setattr(
+        tls, "fetch_server_certificate", lambda *args: fetches.append(args) or der
+    )
+    conn_strs = []
+
+    class _FakeSqlConnection:
+        def __init__(self, conn_str, pooling, attrs_before):
+            conn_strs.append(_ConnectionStringParser(validate_keywords=False)._parse(conn_str))
+
+    monkeypatch.setattr(connection_module.ddbc_bindings, "Connection", _FakeSqlConnection)
+    connection = Connection.__new__(Connection)
+    connection.connection_str, _ = connection._construct_connection_string(
+        "Server=dr-listener,14330;UID=app;PWD=x",
+        server_certificate_hash=f"{FINGERPRINT},{certificate_fingerprint(der)}",
+    )
+    connection._pooling = False
+    connection._attrs_before = {}
+    for _ in range(2):
+        connection._open_session()
+    assert fetches == [("dr-listener", 14330, False, tls.CERTIFICATE_FETCH_TIMEOUT)]
+    params = conn_strs[-1]
+    assert "servercertificatehash" not in params
+    with open(params["servercertificate"], encoding="ascii") as pem:
+        assert ssl.PEM_cert_to_DER_cert(pem.read()) == der
+    assert conn_strs[0] == params
+
+
+def test_wrong_pin_against_server(conn_str):
+    if "servercertificate" in conn_str.lower() or "encrypt=no" in conn_str.lower():
+        pytest.skip("Connection string already controls the certificate")
+    with pytest.raises(CertificateTrustError):
+        connect(conn_str, server_certificate_hash=FINGERPRINT, encrypt="yes")
@@ -0,0 +1,74 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for FIPS mode (mssql_python.fips_mode)."""
+
+import ssl
+
+import pytest
+
+import mssql_python
+from mssql_python import NotSupportedError, connect
+from mssql_python import fips
+from mssql_python.helpers import Settings, _settings
+from mssql_python.row_hash import ResultChecksum, hash_row
+
+
+@pytest.fixture
+def fips_on(monkeypatch):
+    """Turn FIPS mode on with both crypto providers reporting FIPS mode."""
+    monkeypatch.setattr(fips, "_system_fips_enabled", lambda: True)
+    monkeypatch.setattr(fips, "_openssl_fips_enabled", lambda: True)
+    monkeypatch.setattr(_settings, "fips_mode", True)
+    return monkeypatch
+
+
+def test_environment_variable_turns_fips_mode_on(monkeypatch):
+    monkeypatch.setenv("MSSQL_PYTHON_FIPS_MODE", "1")
+    assert Settings().fips_mode is True
+    monkeypatch.setenv("MSSQL_PYTHON_FIPS_MODE", "")
+    assert Settings().fips_mode is False
+
+
+def test_turning_fips_mode_on_checks_the_providers(monkeypatch):
+    monkeypatch.setattr(fips, "_system_fips_enabled", lambda: True)
+    monkeypatch.setattr(fips, "_openssl_fips_enabled", lambda: False)
+    monkeypatch.setattr(_settings, "fips_mode", False)
+    with pytest.raises(NotSupportedError, match="openssl"):
+        mssql_python.fips_mode = True
+    assert mssql_python.fips_mode is False
+    monkeypatch.setattr(fips, "_openssl_fips_enabled", lambda: True)
+    mssql_python.fips_mode = True
+    assert fips.fips_status() == {"fips_mode": True, "system": True, "openssl": True}
+    with pytest.raises(ValueError):
+        mssql_python.fips_mode = "yes"
+
+
+def test_only_approved_hashes(fips_on):
+    assert len(hash_row([1, "a"], "sha384")) == 48
+    assert ResultChecksum("SHA3-256").algorithm == "SHA3-256"
+    for algorithm in ("md5", "sha1", "blake2b"):
+        with pytest.raises(NotSupportedError, match="not FIPS-approved"):
+            ResultChecksum(algorithm)
+    fips_on.setattr(_settings, "fips_mode", False)
+    assert len(hash_row([1], "md5")) == 16
+
+
+def test_encryption_cannot_be_turned_off(fips_on):
+    for encrypt in ("no", "Optional"):
+        with pytest.raises(NotSupportedError, match="not allowed in FIPS mode"):
+            connect(f"Server=db;Encrypt={encrypt};Trusted_Connection=yes")
+
+
+def test_connections_need_fips_providers(fips_on):
+    fips_on.setattr(fips, "_system_fips_enabled", lambda: False)
+    with pytest.raises(NotSupportedError, match="system"):
+        connect("Server=db;Trusted_Connection=yes")
+
+
+def test_tls_contexts_are_restricted(fips_on):
+    context = fips.restrict_tls_context(ssl.SSLContext(ssl.PROTOCOL_TLS_CLIENT))
+    assert context.minimum_version == ssl.TLSVersion.TLSv1_2
+    names = {cipher["name"] for cipher in context.get_ciphers()}
+    assert names and not any("CBC" in name or "SHA1" in name for name in names)
+    assert all("GCM" in name or name.startswith("TLS_") for name in names)
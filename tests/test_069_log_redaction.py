@@ -0,0 +1,95 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for the log redaction policy (configure_redaction)."""
+
+import logging
+
+import pytest
+
+from mssql_python import RedactionPolicy, configure_redaction
+from mssql_python.helpers import sanitize_connection_string
+from mssql_python.logging import logger
+
+
+class _Records(logging.Handler):
+    def __init__(self):
+        super().__init__(logging.DEBUG)
+        self.messages = []
+
+    def emit(self, record):
+        self.messages.append(record.getMessage())
+
+
+@pytest.fixture
+def records(monkeypatch):
+    """Enable DEBUG logging into a list of messages, restoring the policy afterwards."""
+    handler = _Records()
+    level = logger._logger.level
+    monkeypatch.setattr(logger, "redaction", logger.redaction)
+    monkeypatch.setattr(logger, "_cached_level", logging.DEBUG)
+    monkeypatch.setattr(logger, "_is_debug_enabled", True)
+    logger._logger.setLevel(logging.DEBUG)
+    logger._logger.addHandler(handler)
+    yield handler.messages
+    logger._logger.removeHandler(handler)
+    logger._logger.setLevel(level)
+
+
+def test_default_policy():
+    policy = RedactionPolicy()
+    assert policy.to_dict() == {
+        "masked_keys": ["password", "pwd"],
+        "log_sql": True,
+        "parameters": "length",
+    }
+    assert policy.sql("SELECT 1") == "SELECT 1"
+    assert policy.parameters_text(["secret", 42, None, b"\x00\x01"]) == (
+        "(<str len=6>, <int>, <NULL>, <bytes len=2>)"
+    )
+
+
+def test_policy_options():
+    policy = RedactionPolicy(masked_keys=["UID"], log_sql=False, parameters="omit")
+    assert policy.masks("uid") and policy.masks("PWD") and not policy.masks("Server")
+    assert policy.sql("SELECT * FROM t WHERE ssn = ?") == "<SQL redacted, 29 characters>"
+    assert policy.parameters_text([1]) is None
+    values = RedactionPolicy(parameters="values")
+    assert values.parameters_text({"id": 7, "name": "x"}) == "(id=7, name='x')"
+    with pytest.raises(ValueError):
+        RedactionPolicy(parameters="hash")
+    with pytest.raises(TypeError):
+        RedactionPolicy(masked_keys="UID")
+
+
+def test_free_text_is_scrubbed():
+    policy = RedactionPolicy(masked_keys=["uid"])
+    message = "connecting with server=db;UID=app; pwd={a;b}}c};Database=x"
+    assert policy.scrub(message) == "connecting with server=db;UID=***; pwd=***;Database=x"
+    assert policy.scrub("password = hunter2, retrying") == "password = ***"
+
+
+def test_masked_keys_in_connection_strings(monkeypatch):
+    monkeypatch.setattr(logger, "redaction", logger.redaction)
+    conn_str = "Server=db;UID=app;PWD=secret;Database=sales"
+    assert "app" in sanitize_connection_string(conn_str)
+    configure_redaction(masked_keys=["uid", "database"])
+    sanitized = sanitize_connection_string(conn_str)
+    assert "app" not in sanitized and "sales" not in sanitized and "secret" not in sanitized
+    assert "Server=db" in sanitized
+
+
+def test_py_core_messages_are_scrubbed(records):
+    logger.py_core_log(logging.INFO, "Bulk copy connection: user_name=sa;password=Pa55;")
+    assert records[-1] == "Bulk copy connection: user_name=sa;password=***;"
+
+
+def test_execute_logs_no_parameter_values(records, db_connection):
+    configure_redaction(log_sql=False)
+    cursor = db_connection.cursor()
+    try:
+        cursor.execute("SELECT ? AS ssn", "123-45-6789").fetchall()
+    finally:
+        cursor.close()
+    assert not any("123-45-6789" in message or "AS ssn" in message for message in records)
+    assert any("<str len=11>" in message for message in records)
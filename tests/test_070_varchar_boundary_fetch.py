@@ -0,0 +1,78 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""
+Tests for fetching VARCHAR values at their declared length with bound column buffers.
+
+Characters that take more bytes in the client encoding than in the column's
+collation (CP1252 é becomes two UTF-8 bytes) used to overflow the bound SQL_C_CHAR
+buffer at the exact column length; the re-read with SQLGetData then failed on the
+block cursor used by fetchmany()/fetchall().
+"""
+
+import pytest
+
+from mssql_python import SQL_CHAR, SQL_WCHAR
+
+UTF8_COLLATION = "Latin1_General_100_CI_AS_SC_UTF8"
+ROWS = 5
+
+
+@pytest.fixture(params=[SQL_CHAR, SQL_WCHAR], ids=["sql_c_char", "sql_c_wchar"])
+def boundary_cursor(request, db_connection):
+    """A cursor over a result of ROWS rows of VARCHAR values filled to their length."""
+    ctype = request.param
+    encoding = "utf-8" if ctype == SQL_CHAR else "utf-16le"
+    db_connection.setdecoding(SQL_CHAR, encoding=encoding, ctype=ctype)
+    cursor = db_connection.cursor()
+    cursor.execute(
+        "CREATE TABLE #boundary (id int, latin VARCHAR(10) COLLATE Latin1_General_CI_AS, "
+        "wide VARCHAR(8000) COLLATE Latin1_General_CI_AS)"
+    )
+    cursor.executemany(
+        "INSERT INTO #boundary VALUES (?, ?, ?)",
+        [(i, "é" * 10, "é" * 8000) for i in range(ROWS)],
+    )
+    yield cursor
+    cursor.execute("DROP TABLE #boundary")
+    cursor.close()
+    db_connection.setdecoding(SQL_CHAR, encoding="utf-16le", ctype=SQL_WCHAR)
+
+
+def _select(cursor):
+    return cursor.execute("SELECT id, latin, wide FROM #boundary ORDER BY id")
+
+
+def _check(rows):
+    assert [row[0] for row in rows] == list(range(len(rows)))
+    assert all(row[1] == "é" * 10 and row[2] == "é" * 8000 for row in rows)
+
+
+def test_fetchone_at_column_length(boundary_cursor):
+    _select(boundary_cursor)
+    _check([boundary_cursor.fetchone() for _ in range(ROWS)])
+    assert boundary_cursor.fetchone() is None
+
+
+def test_fetchmany_at_column_length(boundary_cursor):
+    _select(boundary_cursor)
+    rows = boundary_cursor.fetchmany(3) + boundary_cursor.fetchmany(3)
+    assert len(rows) == ROWS
+    _check(rows)
+
+
+def test_fetchall_at_column_length(boundary_cursor):
+    rows = _select(boundary_cursor).fetchall()
+    assert len(rows) == ROWS
+    _check(rows)
+
+
+def test_utf8_collation_at_column_length(cursor):
+    try:
+        cursor.execute(
+            f"SELECT CAST(REPLICATE(N'€', 4) COLLATE {UTF8_COLLATION} AS VARCHAR(12)) "
+            "FROM (VALUES (1), (2), (3)) AS v(n)"
+        )
+    except Exception as e:  # pylint: disable=broad-exception-caught
+        pytest.skip(f"UTF-8 collations are not supported: {e}")
+    assert [row[0] for row in cursor.fetchall()] == ["€" * 4] * 3
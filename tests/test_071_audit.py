@@ -0,0 +1,107 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for the audit event stream (enable_audit)."""
+
+import json
+import threading
+import time
+from types import SimpleNamespace
+
+import pytest
+
+from mssql_python import AuditStream, ProgrammingError, disable_audit, enable_audit
+from mssql_python import audit
+from mssql_python.audit import normalize_sql
+
+
+@pytest.fixture
+def events():
+    """Audit into a list of events, stopping the stream afterwards."""
+    received = []
+    stream = enable_audit(received.append)
+    yield received, stream
+    disable_audit()
+
+
+def _cursor(conn_str="Server=db,1433;Database=sales;UID=app;PWD=secret;APP=MSSQL-Python"):
+    connection = SimpleNamespace(connection_str=conn_str, _session_id=None)
+    return SimpleNamespace(_connection=connection, rowcount=3)
+
+
+def test_sql_is_normalized():
+    sql = "SELECT [it's], \"2024\" FROM t1 -- all rows\nWHERE id = 42 /* x */ AND n = N'o''k'"
+    assert normalize_sql(sql) == "SELECT [it's], \"2024\" FROM t1 WHERE id = ? AND n = ?"
+    assert normalize_sql("  SET @v2 = 0x1F +\t1.5e3  ") == "SET @v2 = ? + ?"
+
+
+def test_event_fields(events):
+    received, stream = events
+    audit.record(_cursor(), "execute", "UPDATE t SET n = 1 WHERE id = ?", time.perf_counter())
+    assert stream.flush(5)
+    event = received[0]
+    assert event.sql == "UPDATE t SET n = ? WHERE id = ?"
+    assert (event.principal, event.server, event.database) == ("app", "db,1433", "sales")
+    assert event.application == "MSSQL-Python" and event.rowcount == 3
+    assert event.parameter_sets == 1 and event.error is None and event.duration >= 0
+    assert event.timestamp.tzinfo is not None
+    assert "secret" not in repr(event.to_dict())
+
+
+def test_failed_statement(events):
+    received, stream = events
+    error = ProgrammingError("Invalid object name 'nope'", "")
+    error.sqlstate = "42S02"
+    audit.record(_cursor(), "executemany", "INSERT nope VALUES (?)", 0.0, error, 10)
+    stream.flush(5)
+    event = received[0]
+    assert (event.error, event.sqlstate, event.rowcount) == ("ProgrammingError", "42S02", None)
+    assert event.parameter_sets == 10
+
+
+def test_full_queue_drops_events():
+    release = threading.Event()
+    stream = enable_audit(lambda event: release.wait(5), max_queue=1)
+    try:
+        for _ in range(5):
+            audit.record(_cursor(), "execute", "SELECT 1", time.perf_counter())
+        assert stream.dropped >= 3
+    finally:
+        release.set()
+        disable_audit()
+    assert stream.delivered + stream.dropped == 5
+
+
+def test_json_lines_file(tmp_path):
+    path = tmp_path / "audit.jsonl"
+    enable_audit(str(path), normalize=False)
+    audit.record(_cursor("Server=db;Trusted_Connection=yes"), "execute", "SELECT 1", 0.0)
+    disable_audit()
+    record = json.loads(path.read_text(encoding="utf-8"))
+    assert record["sql"] == "SELECT 1" and record["principal"]
+    assert record["timestamp"].endswith("+00:00")
+
+
+def test_invalid_targets():
+    with pytest.raises(TypeError):
+        AuditStream(42)
+    with pytest.raises(ValueError):
+        AuditStream(print, max_queue=0)
+
+
+def test_executed_statements_are_audited(events, db_connection):
+    received, stream = events
+    cursor = db_connection.cursor()
+    try:
+        cursor.execute("SELECT ? AS n WHERE 1 = 1", 5).fetchall()
+        cursor.execute("CREATE TABLE #audit (n int)")
+        cursor.executemany("INSERT INTO #audit VALUES (?)", [(1,), (2,)])
+        with pytest.raises(ProgrammingError):
+            cursor.execute("SELECT * FROM missing_audit_table")
+    finally:
+        cursor.close()
+    stream.flush(5)
+    statements = [(e.operation, e.sql, e.rowcount, e.error) for e in received]
+    assert ("execute", "SELECT ? AS n WHERE ? = ?", -1, None) in statements
+    assert ("executemany", "INSERT INTO #audit VALUES (?)", 2, None) in statements
+    assert statements[-1][1:] == ("SELECT * FROM missing_audit_table", None, "ProgrammingError")
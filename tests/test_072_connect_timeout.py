@@ -0,0 +1,112 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for login_timeout and cancellable connects (ConnectAttempt)."""
+
+import threading
+import time
+
+import pytest
+
+import mssql_python.connection as connection_module
+from mssql_python import (
+    ConnectAttempt,
+    OperationalError,
+    SQL_ATTR_CONNECTION_TIMEOUT,
+    SQL_ATTR_LOGIN_TIMEOUT,
+    ServerUnreachableError,
+    connect,
+)
+from mssql_python.connection import Connection
+
+
+class _HungConnection:
+    """A driver connection whose connect blocks until released."""
+
+    release = None
+    closed = []
+
+    def __init__(self, conn_str, pooling, attrs_before):
+        self.release.wait(10)
+
+    def close(self):
+        self.closed.append(self)
+
+
+@pytest.fixture
+def hung_driver(monkeypatch):
+    """Make driver connects hang until the returned event is set."""
+    release = threading.Event()
+    monkeypatch.setattr(_HungConnection, "release", release)
+    monkeypatch.setattr(_HungConnection, "closed", [])
+    monkeypatch.setattr(connection_module.ddbc_bindings, "Connection", _HungConnection)
+    yield release
+    release.set()
+
+
+def _connection(attrs_before=None, attempt=None):
+    connection = Connection.__new__(Connection)
+    connection.connection_str = "Driver={ODBC Driver 18 for SQL Server};Server=db"
+    connection._pooling = False
+    connection._attrs_before = attrs_before or {}
+    connection._connect_attempt = attempt
+    return connection
+
+
+def test_login_timeout_sets_the_driver_attributes(monkeypatch):
+    seen = []
+
+    def fail(conn_str, pooling, attrs_before):
+        seen.append(dict(attrs_before))
+        raise RuntimeError("SQLSTATE:HYT00:[Microsoft][ODBC Driver 18] Login timeout expired")
+
+    monkeypatch.setattr(connection_module.ddbc_bindings, "Connection", fail)
+    with pytest.raises(ServerUnreachableError):
+        connect("Server=db;UID=u;PWD=p", login_timeout=7)
+    assert seen[0][SQL_ATTR_LOGIN_TIMEOUT] == 7 and seen[0][SQL_ATTR_CONNECTION_TIMEOUT] == 7
+    attrs = {SQL_ATTR_LOGIN_TIMEOUT: 3}
+    with pytest.raises(ServerUnreachableError):
+        connect("Server=db;UID=u;PWD=p", attrs_before=attrs, login_timeout=7)
+    assert seen[1][SQL_ATTR_LOGIN_TIMEOUT] == 3 and attrs == {SQL_ATTR_LOGIN_TIMEOUT: 3}
+    for value, error in (("7", TypeError), (-1, ValueError)):
+        with pytest.raises(error):
+            connect("Server=db;UID=u;PWD=p", login_timeout=value)
+
+
+def test_cancel_connect_from_another_thread(hung_driver):
+    attempt = ConnectAttempt()
+    threading.Timer(0.2, attempt.cancel_connect).start()
+    started = time.monotonic()
+    with pytest.raises(OperationalError) as exc_info:
+        _connection(attempt=attempt)._open_session()
+    assert exc_info.value.sqlstate == "HY008" and time.monotonic() - started < 5
+    assert attempt.cancelled
+    # The connection the driver opens after all is closed, not leaked
+    hung_driver.set()
+    deadline = time.monotonic() + 5
+    while not _HungConnection.closed and time.monotonic() < deadline:
+        time.sleep(0.01)
+    assert len(_HungConnection.closed) == 1
+
+
+def test_cancelled_attempt_fails_right_away(hung_driver):
+    attempt = ConnectAttempt()
+    attempt.cancel_connect()
+    with pytest.raises(OperationalError, match="cancel_connect"):
+        _connection(attempt=attempt)._open_session()
+
+
+def test_hung_connect_is_given_up_after_the_login_timeout(hung_driver, monkeypatch):
+    monkeypatch.setattr(connection_module, "LOGIN_TIMEOUT_GRACE", 0.0)
+    started = time.monotonic()
+    with pytest.raises(ServerUnreachableError, match="did not return within 1 seconds"):
+        _connection({SQL_ATTR_LOGIN_TIMEOUT: 1})._open_session()
+    assert time.monotonic() - started < 5
+
+
+def test_connect_with_login_timeout(conn_str):
+    conn = connect(conn_str, login_timeout=30, attempt=ConnectAttempt())
+    try:
+        assert conn.cursor().execute("SELECT 1").fetchone()[0] == 1
+    finally:
+        conn.close()
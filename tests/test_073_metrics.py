@@ -0,0 +1,65 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for the driver metrics registry (metrics and metrics_text)."""
+
+import pytest
+
+import mssql_python
+from mssql_python import driver_metrics, metrics, metrics_text
+
+
+@pytest.fixture
+def snapshot(monkeypatch):
+    """Make the bindings report a fixed metrics snapshot."""
+    data = {
+        "counters": {"statements_executed": 12, "failed_logins": 1},
+        "histograms": {
+            "statement_duration_seconds": {
+                "buckets": [(0.005, 4), (0.25, 11), (float("inf"), 12)],
+                "sum": 1.5,
+                "count": 12,
+            }
+        },
+    }
+    monkeypatch.setattr(driver_metrics.ddbc_bindings, "metrics", lambda: data, raising=False)
+    return data
+
+
+def test_metrics_returns_the_bindings_snapshot(snapshot):
+    assert metrics() is snapshot
+    assert mssql_python.metrics is metrics
+
+
+def test_prometheus_text(snapshot):
+    lines = metrics_text().splitlines()
+    assert "# TYPE mssql_python_statements_executed_total counter" in lines
+    assert "mssql_python_statements_executed_total 12" in lines
+    assert "mssql_python_failed_logins_total 1" in lines
+    assert "# TYPE mssql_python_statement_duration_seconds histogram" in lines
+    assert 'mssql_python_statement_duration_seconds_bucket{le="0.005"} 4' in lines
+    assert 'mssql_python_statement_duration_seconds_bucket{le="+Inf"} 12' in lines
+    assert "mssql_python_statement_duration_seconds_sum 1.5" in lines
+    assert lines[-1] == "mssql_python_statement_duration_seconds_count 12"
+    assert metrics_text().endswith("\n")
+
+
+def test_counters_follow_statements(db_connection):
+    before = metrics()
+    cursor = db_connection.cursor()
+    try:
+        assert len(cursor.execute("SELECT name FROM sys.objects").fetchall()) > 0
+        with pytest.raises(mssql_python.ProgrammingError):
+            cursor.execute("SELECT * FROM missing_metrics_table")
+    finally:
+        cursor.close()
+    after = metrics()
+    counters = {
+        name: after["counters"][name] - before["counters"][name] for name in before["counters"]
+    }
+    assert counters["statements_executed"] >= 2 and counters["statement_errors"] >= 1
+    assert counters["rows_fetched"] > 0
+    assert counters["bytes_sent"] > 0 and counters["bytes_received"] > 0
+    statements = after["histograms"]["statement_duration_seconds"]
+    assert statements["count"] - before["histograms"]["statement_duration_seconds"]["count"] >= 2
+    assert statements["buckets"][-1] == (float("inf"), statements["count"])
@@ -0,0 +1,74 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for per-connection usage accounting (Connection.usage)."""
+
+from mssql_python import connect
+from mssql_python.connection import Connection, _USAGE_COUNTERS
+
+
+class _DriverConnection:
+    """A driver connection reporting fixed usage counters."""
+
+    def __init__(self, rows):
+        self.counters = dict.fromkeys(_USAGE_COUNTERS, 0)
+        self.counters["rows_fetched"] = rows
+        self.counters["statements_executed"] = 1
+
+    def get_usage(self, reset):
+        usage = dict(self.counters)
+        if reset:
+            self.counters = dict.fromkeys(_USAGE_COUNTERS, 0)
+        return usage
+
+    def close(self):
+        pass
+
+
+def _connection(driver):
+    connection = Connection.__new__(Connection)
+    connection._conn = driver
+    connection._closed = False
+    connection._retired_usage = dict.fromkeys(_USAGE_COUNTERS, 0)
+    return connection
+
+
+def test_usage_survives_reconnects_and_close():
+    connection = _connection(_DriverConnection(rows=5))
+    assert connection.usage()["rows_fetched"] == 5
+    # A reconnect replaces the driver connection; the old session's usage is kept
+    connection._retire_usage()
+    connection._conn = _DriverConnection(rows=2)
+    expected = {**dict.fromkeys(_USAGE_COUNTERS, 0), "rows_fetched": 7, "statements_executed": 2}
+    assert connection.usage() == expected
+    # close() keeps the final counts too
+    connection._retire_usage()
+    connection._conn, connection._closed = None, True
+    assert connection.usage() == expected
+
+
+def test_usage_reset():
+    connection = _connection(_DriverConnection(rows=4))
+    connection._retired_usage["bytes_sent"] = 10
+    first = connection.usage(reset=True)
+    assert (first["rows_fetched"], first["bytes_sent"]) == (4, 10)
+    assert connection.usage() == dict.fromkeys(_USAGE_COUNTERS, 0)
+
+
+def test_usage_counts_statements(conn_str):
+    conn = connect(conn_str)
+    try:
+        cursor = conn.cursor()
+        cursor.execute("CREATE TABLE #usage (id int, name nvarchar(20))")
+        cursor.executemany("INSERT INTO #usage VALUES (?, ?)", [(1, "ab"), (2, "cd"), (3, "ef")])
+        assert len(cursor.execute("SELECT name FROM #usage").fetchall()) == 3
+        usage = conn.usage(reset=True)
+        assert usage["statements_executed"] >= 3
+        assert usage["rows_affected"] == 3 and usage["rows_fetched"] == 3
+        assert usage["bytes_received"] == 3 * 2 * 2  # Two UTF-16 characters per row
+        assert usage["bytes_sent"] > 0
+        cursor.execute("UPDATE #usage SET id = id + 1")
+        assert conn.usage()["rows_affected"] == 3
+    finally:
+        conn.close()
+    assert conn.usage()["rows_affected"] == 3
@@ -0,0 +1,74 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for statements over the parameter limit of a request (fit_parameter_limit)."""
+
+import json
+import pickle
+
+import pytest
+
+from mssql_python import TooManyParametersError
+from mssql_python.parameter_helper import MAX_PARAMETERS, fit_parameter_limit
+
+
+def _placeholders(count):
+    return ", ".join("?" * count)
+
+
+def test_statements_within_the_limit_are_unchanged():
+    sql = f"SELECT * FROM t WHERE id IN ({_placeholders(5)})"
+    assert fit_parameter_limit(sql, list(range(5))) == [(sql, list(range(5)))]
+
+
+def test_long_explicit_in_list_becomes_json():
+    sql = f"SELECT * FROM t WHERE a = ? AND id NOT IN ({_placeholders(3000)}) AND '?' <> ?"
+    params = ["x"] + list(range(3000)) + ["y"]
+    [(rewritten, rewritten_params)] = fit_parameter_limit(sql, params)
+    assert "IN (SELECT [value] FROM OPENJSON(?) WITH ([value] BIGINT '$'))" in rewritten
+    assert rewritten.endswith("AND '?' <> ?")
+    assert rewritten_params[0] == "x" and rewritten_params[2] == "y"
+    assert json.loads(rewritten_params[1]) == list(range(3000))
+
+
+def test_multirow_insert_is_split():
+    rows = 1500
+    sql = "INSERT INTO dbo.t (a, b, c) VALUES " + ",\n".join(["(?,?, ?)"] * rows)
+    params = list(range(rows * 3))
+    statements = fit_parameter_limit(sql, params)
+    # 2099 // 3 = 699 rows per statement
+    assert [len(p) // 3 for _, p in statements] == [699, 699, 102]
+    assert statements[0][0] == "INSERT INTO dbo.t (a, b, c) VALUES " + ", ".join(
+        ["(?, ?, ?)"] * 699
+    )
+    assert [value for _, p in statements for value in p] == params
+
+
+def test_other_statements_raise_a_clear_error():
+    sql = "SELECT " + _placeholders(2200)
+    with pytest.raises(TooManyParametersError) as exc_info:
+        fit_parameter_limit(sql, list(range(2200)))
+    error = exc_info.value
+    assert (error.parameter_count, error.limit) == (2200, MAX_PARAMETERS)
+    assert "insert_rows" in str(error)
+    copy = pickle.loads(pickle.dumps(error))
+    assert (copy.parameter_count, copy.limit, str(copy)) == (2200, MAX_PARAMETERS, str(error))
+    # Mixed-type IN lists cannot go as JSON
+    mixed = [1, "a"] * 1100
+    with pytest.raises(TooManyParametersError):
+        fit_parameter_limit(f"SELECT 1 WHERE 1 IN ({_placeholders(2200)})", mixed)
+
+
+def test_execute_over_the_limit(cursor):
+    cursor.execute("CREATE TABLE #params (a int, b int)")
+    rows = 1200
+    sql = "INSERT INTO #params VALUES " + ", ".join(["(?, ?)"] * rows)
+    cursor.execute(sql, [value for i in range(rows) for value in (i, -i)])
+    assert cursor.rowcount == rows
+    ids = list(range(3000))
+    cursor.execute(f"SELECT COUNT(*) FROM #params WHERE a IN ({_placeholders(3000)})", ids)
+    assert cursor.fetchone()[0] == rows
+    with pytest.raises(TooManyParametersError):
+        cursor.execute("SELECT " + _placeholders(2200), list(range(2200)))
+    with pytest.raises(TooManyParametersError):
+        cursor.executemany("SELECT " + _placeholders(2200), [tuple(range(2200))])
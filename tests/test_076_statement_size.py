@@ -0,0 +1,118 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for the batch size limit, GO-separated scripts and UTF-8 SQL text (sql_script)."""
+
+import io
+import pickle
+
+import pytest
+
+from mssql_python import ProgrammingError, StatementTooLargeError
+from mssql_python.constants import ConstantsDDBC
+from mssql_python.sql_script import (
+    DEFAULT_PACKET_SIZE,
+    batch_size_limit,
+    check_statement_size,
+    configured_packet_size,
+    iter_batches,
+    statement_size,
+)
+
+
+def test_batch_size_limit():
+    assert batch_size_limit() == 65536 * 4096
+    assert batch_size_limit(8000) == 65536 * 8000
+
+
+def test_statement_size_counts_utf16_bytes():
+    assert statement_size("SELECT 1") == 16
+    # Characters outside the BMP take a surrogate pair
+    assert statement_size("\U0001f600") == 4
+
+
+def test_statement_size_of_utf8_bytes():
+    assert statement_size("SELECT N'é'".encode("utf-8")) == statement_size("SELECT N'é'")
+    # A UTF-8 byte takes at most two bytes in UTF-16
+    check_statement_size(b"x" * 32768, packet_size=1)
+    with pytest.raises(StatementTooLargeError):
+        check_statement_size(b"x" * 32769, packet_size=1)
+
+
+def test_check_statement_size():
+    check_statement_size("SELECT 1")
+    # A 1-byte packet size gives a 65536-byte limit
+    check_statement_size("x" * 32768, packet_size=1)
+    with pytest.raises(StatementTooLargeError) as info:
+        check_statement_size("x" * 32769, packet_size=1)
+    assert (info.value.size, info.value.limit) == (65538, 65536)
+    assert "65536 bytes" in str(info.value)
+    copy = pickle.loads(pickle.dumps(info.value))
+    assert (copy.size, copy.limit) == (65538, 65536)
+
+
+def test_configured_packet_size():
+    assert configured_packet_size({}) == DEFAULT_PACKET_SIZE
+    assert configured_packet_size({"PacketSize": "8000"}) == 8000
+    attrs = {ConstantsDDBC.SQL_ATTR_PACKET_SIZE.value: 16384}
+    assert configured_packet_size({"PacketSize": "8000"}, attrs) == 16384
+    assert configured_packet_size({"PacketSize": "big"}) == DEFAULT_PACKET_SIZE
+
+
+def test_iter_batches():
+    script = "CREATE TABLE t (a INT)\ngo\n\nGO\nINSERT t VALUES (1)\nGO 3 -- thrice\nSELECT 1"
+    assert list(iter_batches(script)) == [
+        "CREATE TABLE t (a INT)",
+        "INSERT t VALUES (1)",
+        "INSERT t VALUES (1)",
+        "INSERT t VALUES (1)",
+        "SELECT 1",
+    ]
+    # GO inside a line, or starting a longer word, does not separate batches
+    assert list(iter_batches("SELECT 'GO'\nGOTO done")) == ["SELECT 'GO'\nGOTO done"]
+
+
+def test_iter_batches_reads_lines_lazily():
+    lines = io.StringIO("SELECT 1\r\nGO\r\nSELECT 2\r\n")
+    batches = iter_batches(lines)
+    assert next(batches) == "SELECT 1"
+    assert lines.readline() == "SELECT 2\r\n"
+
+
+def test_iter_batches_of_utf8_scripts():
+    script = "SELECT N'é'\r\nGO\r\nSELECT 2".encode("utf-8")
+    assert list(iter_batches(script)) == ["SELECT N'é'".encode("utf-8"), b"SELECT 2"]
+    assert list(iter_batches(io.BytesIO(script))) == list(iter_batches(script))
+
+
+def test_execute_script(cursor):
+    table_name = "#mssql_python_script_test"
+    script = (
+        f"CREATE TABLE {table_name} (a INT)\n"
+        "GO\n"
+        f"INSERT {table_name} VALUES (1), (2)\n"
+        "GO 2\n"
+        f"SELECT COUNT(*) FROM {table_name}\n"
+    )
+    assert cursor.execute_script(script) == 4
+    assert cursor.fetchone()[0] == 4
+    assert cursor.rowcount >= 4
+
+
+def test_execute_rejects_oversized_statement(cursor):
+    limit = batch_size_limit(cursor.connection._packet_size)
+    sql = "SELECT 1 --" + "x" * (limit // 2)
+    with pytest.raises(StatementTooLargeError):
+        cursor.execute(sql)
+    cursor.execute("SELECT 1")
+    assert cursor.fetchone()[0] == 1
+
+
+def test_execute_utf8_bytes(cursor):
+    cursor.execute("SELECT N'é', 1".encode("utf-8"))
+    assert tuple(cursor.fetchone()) == ("é", 1)
+    with pytest.raises(ProgrammingError):
+        cursor.execute(b"SELECT ?", 1)
+    script = io.BytesIO("SELECT 1\nGO\nSELECT N'日本'\n".encode("utf-8"))
+    assert cursor.execute_script(script) == 2
+    assert cursor.fetchone()[0] == "日本"
@@ -0,0 +1,108 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for the SQL and C types parameters of each Python type are bound as (_map_sql_type)."""
+
+import datetime
+import decimal
+import uuid
+
+import pytest
+
+from mssql_python.constants import ConstantsDDBC as _C
+from mssql_python.cursor import MAX_INLINE_CHAR, Cursor
+
+
+def _bind(value):
+    """Return (sql_type, c_type, column_size, decimal_digits, is_dae) of value."""
+    cursor = Cursor.__new__(Cursor)
+    cursor._inputsizes = None
+    return cursor._map_sql_type(value, [value], 0)
+
+
+@pytest.mark.parametrize(
+    "value, sql_type, c_type",
+    [
+        (None, _C.SQL_UNKNOWN_TYPE, _C.SQL_C_DEFAULT),
+        (True, _C.SQL_BIT, _C.SQL_C_BIT),
+        (255, _C.SQL_TINYINT, _C.SQL_C_TINYINT),
+        (-1, _C.SQL_SMALLINT, _C.SQL_C_SHORT),
+        (32768, _C.SQL_INTEGER, _C.SQL_C_LONG),
+        (-2147483648, _C.SQL_INTEGER, _C.SQL_C_LONG),
+        (2147483648, _C.SQL_BIGINT, _C.SQL_C_SBIGINT),
+        (1.5, _C.SQL_DOUBLE, _C.SQL_C_DOUBLE),
+        (decimal.Decimal("1e30"), _C.SQL_NUMERIC, _C.SQL_C_NUMERIC),
+        ("abc", _C.SQL_VARCHAR, _C.SQL_C_CHAR),
+        ("日本", _C.SQL_WVARCHAR, _C.SQL_C_WCHAR),
+        (b"\x00\x01", _C.SQL_VARBINARY, _C.SQL_C_BINARY),
+        (bytearray(b"\x00"), _C.SQL_VARBINARY, _C.SQL_C_BINARY),
+        (datetime.date(2024, 2, 29), _C.SQL_DATE, _C.SQL_C_TYPE_DATE),
+        (datetime.time(23, 59, 59, 999999), _C.SQL_TYPE_TIME, _C.SQL_C_CHAR),
+        (datetime.datetime(2024, 2, 29, 12), _C.SQL_TIMESTAMP, _C.SQL_C_TYPE_TIMESTAMP),
+        (
+            datetime.datetime(2024, 2, 29, 12, tzinfo=datetime.timezone.utc),
+            _C.SQL_DATETIMEOFFSET,
+            _C.SQL_C_SS_TIMESTAMPOFFSET,
+        ),
+        (uuid.UUID(int=1), _C.SQL_GUID, _C.SQL_C_GUID),
+    ],
+)
+def test_python_types_bind_as(value, sql_type, c_type):
+    bound_sql_type, bound_c_type, _, _, is_dae = _bind(value)
+    assert (bound_sql_type, bound_c_type) == (sql_type.value, c_type.value)
+    assert is_dae is False
+
+
+@pytest.mark.parametrize(
+    "value, column_size, is_dae",
+    [
+        ("x" * MAX_INLINE_CHAR, MAX_INLINE_CHAR, False),
+        ("x" * (MAX_INLINE_CHAR + 1), 0, True),
+        # A character outside the BMP takes two UTF-16 code units of the limit
+        ("\U0001f600" * (MAX_INLINE_CHAR // 2), MAX_INLINE_CHAR, False),
+        ("\U0001f600" * (MAX_INLINE_CHAR // 2) + "x", 0, True),
+        (b"\xff" * 8000, 8000, False),
+        (b"\xff" * 8001, 0, True),
+        # Empty values still bind a column size of 1
+        (b"", 1, False),
+    ],
+)
+def test_max_length_boundaries(value, column_size, is_dae):
+    _, _, bound_size, _, bound_dae = _bind(value)
+    assert (bound_size, bound_dae) == (column_size, is_dae)
+
+
+def test_unsupported_types_are_rejected():
+    with pytest.raises(TypeError):
+        _bind(object())
+    with pytest.raises(ValueError):
+        _bind(decimal.Decimal("1" * 39))
+
+
+@pytest.mark.parametrize(
+    "column_type, value",
+    [
+        ("BIT", True),
+        ("BIGINT", -(2**63)),
+        ("FLOAT", 1.25),
+        ("DECIMAL(38, 10)", decimal.Decimal("1234567890123456789012345678.0123456789")),
+        ("NVARCHAR(4000)", "日" * 4000),
+        ("NVARCHAR(MAX)", "日" * 4001),
+        ("VARBINARY(8000)", b"\x01" * 8000),
+        ("VARBINARY(MAX)", b"\x01" * 8001),
+        ("DATE", datetime.date(9999, 12, 31)),
+        ("TIME(6)", datetime.time(23, 59, 59, 999999)),
+        ("DATETIME2(6)", datetime.datetime(2024, 2, 29, 12, 30, 1, 123456)),
+        ("UNIQUEIDENTIFIER", uuid.UUID("12345678-1234-5678-1234-567812345678")),
+    ],
+)
+def test_round_trip_with_nulls(cursor, column_type, value):
+    cursor.execute(f"CREATE TABLE #bind_types (v {column_type} NULL)")
+    try:
+        cursor.execute("INSERT INTO #bind_types (v) VALUES (?), (?)", value, None)
+        cursor.execute("SELECT v FROM #bind_types WHERE v = ?", value)
+        assert cursor.fetchone()[0] == value
+        cursor.execute("SELECT COUNT(*) FROM #bind_types WHERE v IS NULL")
+        assert cursor.fetchone()[0] == 1
+    finally:
+        cursor.execute("DROP TABLE #bind_types")
@@ -0,0 +1,115 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for the per-phase connect timings (connect_timings, diagnose_connect)."""
+
+import socket
+import struct
+import threading
+
+import pytest
+
+from mssql_python import InterfaceError
+from mssql_python import connection as connection_module
+from mssql_python.connect_timing import NETWORK_PHASES, probe_network_phases, total_seconds
+from mssql_python.connection import Connection
+from mssql_python.readiness import prelogin_packet
+from mssql_python.tls import _ENCRYPT_NOT_SUP
+
+
+@pytest.fixture
+def prelogin_server():
+    """A TCP server answering each pre-login with ENCRYPT_NOT_SUP, then closing."""
+    listener = socket.create_server(("127.0.0.1", 0))
+    response = bytes([0x04]) + prelogin_packet(_ENCRYPT_NOT_SUP)[1:]
+
+    def serve():
+        while True:
+            try:
+                client, _ = listener.accept()
+            except OSError:
+                return
+            with client:
+                header = client.recv(8)
+                length = struct.unpack(">H", header[2:4])[0]
+                received = len(header)
+                while received < length:
+                    received += len(client.recv(length - received))
+                client.sendall(response)
+
+    threading.Thread(target=serve, daemon=True).start()
+    yield "tcp:127.0.0.1,%d" % listener.getsockname()[1]
+    listener.close()
+
+
+def test_probe_without_encryption(prelogin_server):
+    phases = probe_network_phases(prelogin_server)
+    assert list(phases) == list(NETWORK_PHASES)
+    assert all(phases[phase] >= 0 for phase in ("dns", "tcp", "prelogin"))
+    assert phases["tls"] is None
+
+
+def test_probe_stops_at_the_failed_phase():
+    unused = socket.create_server(("127.0.0.1", 0))
+    port = unused.getsockname()[1]
+    unused.close()
+    phases = probe_network_phases(f"127.0.0.1,{port}", timeout=1)
+    assert phases["dns"] is not None
+    assert phases["tcp"] is phases["prelogin"] is phases["tls"] is None
+
+
+def test_probe_of_servers_without_a_port():
+    for server in (r"np:\\.\pipe\sql\query", "(localdb)\\MSSQLLocalDB", "host\\instance"):
+        assert probe_network_phases(server) == dict.fromkeys(NETWORK_PHASES)
+
+
+def test_total_seconds():
+    assert total_seconds({"token": None, "connect": 0.5, "session": 0.25, "total": 9}) == 0.75
+
+
+def _connection(server, connect=0.5):
+    conn = Connection.__new__(Connection)
+    conn._closed = False
+    conn._active_server = server
+    conn._strict_encryption = False
+    conn._connect_timings = {"token": None, "connect": connect, "session": 0.1, "total": 0.6}
+    return conn
+
+
+def test_diagnose_connect_derives_login(monkeypatch):
+    probed = {"dns": 0.01, "tcp": 0.02, "prelogin": 0.03, "tls": 0.04}
+    monkeypatch.setattr(connection_module, "probe_network_phases", lambda *args: dict(probed))
+    diagnosis = _connection("tcp:host,1433").diagnose_connect()
+    assert list(diagnosis) == [
+        "token",
+        "dns",
+        "tcp",
+        "prelogin",
+        "tls",
+        "login",
+        "session",
+        "total",
+    ]
+    assert diagnosis["login"] == pytest.approx(0.4)
+    assert (diagnosis["session"], diagnosis["total"]) == (0.1, 0.6)
+    # Network phases measured now may exceed the connect they break down
+    assert _connection("tcp:host,1433", connect=0.05).diagnose_connect()["login"] == 0.0
+
+
+def test_diagnose_connect_without_probe():
+    diagnosis = _connection(r"np:\\.\pipe\sql\query").diagnose_connect()
+    assert diagnosis["dns"] is diagnosis["login"] is None
+    conn = _connection("host")
+    conn._closed = True
+    with pytest.raises(InterfaceError):
+        conn.diagnose_connect()
+
+
+def test_connect_timings(db_connection):
+    timings = db_connection.connect_timings
+    assert timings["connect"] > 0
+    assert timings["total"] == pytest.approx(total_seconds(timings))
+    diagnosis = db_connection.diagnose_connect()
+    assert diagnosis["total"] == timings["total"]
+    if diagnosis["login"] is not None:
+        assert 0 <= diagnosis["login"] <= timings["connect"]
@@ -0,0 +1,138 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for the asyncio API (mssql_python.aio)."""
+
+import asyncio
+import threading
+import time
+
+import pytest
+
+from mssql_python import aio
+
+
+class _BlockingCursor:
+    """A Cursor stand-in whose execute() blocks until released."""
+
+    def __init__(self):
+        self.closed = False
+        self.arraysize = 1
+        self.hstmt = None
+        self.release = threading.Event()
+        self.threads = []
+        self.rows = list(range(2500))
+
+    def execute(self, operation, *parameters):
+        self.threads.append(threading.current_thread().name)
+        self.release.wait(5)
+        return self
+
+    def fetchmany(self, size=None):
+        rows, self.rows = self.rows[:size], self.rows[size:]
+        return rows
+
+    def close(self):
+        self.closed = True
+
+
+class _Connection:
+    closed = False
+
+    def __init__(self, cursor):
+        self._cursor = cursor
+
+    def cursor(self):
+        return self._cursor
+
+
+def test_calls_run_off_the_event_loop():
+    blocking = _BlockingCursor()
+    cursor = aio.AsyncConnection(_Connection(blocking)).cursor()
+
+    async def main():
+        execution = asyncio.ensure_future(cursor.execute("SELECT 1"))
+        # The loop keeps running while the statement blocks a pool thread
+        await asyncio.sleep(0.05)
+        assert not execution.done()
+        blocking.release.set()
+        assert await execution is cursor
+
+    asyncio.run(main())
+    assert blocking.threads[0].startswith("mssql-python-aio")
+
+
+def test_calls_of_a_connection_run_one_at_a_time():
+    blocking = _BlockingCursor()
+    cursor = aio.AsyncConnection(_Connection(blocking)).cursor()
+
+    async def main():
+        first = asyncio.ensure_future(cursor.execute("SELECT 1"))
+        second = asyncio.ensure_future(cursor.execute("SELECT 2"))
+        await asyncio.sleep(0.05)
+        assert len(blocking.threads) == 1
+        blocking.release.set()
+        await asyncio.gather(first, second)
+
+    asyncio.run(main())
+    assert len(blocking.threads) == 2
+
+
+def test_cancelling_cancels_the_statement(monkeypatch):
+    blocking = _BlockingCursor()
+    cursor = aio.AsyncConnection(_Connection(blocking)).cursor()
+    cancelled = []
+    monkeypatch.setattr(aio.ddbc_bindings, "DDBCSQLCancel", cancelled.append, raising=False)
+
+    async def main():
+        execution = asyncio.ensure_future(cursor.execute("WAITFOR DELAY '00:01'"))
+        await asyncio.sleep(0.05)
+        asyncio.get_running_loop().call_later(0.05, blocking.release.set)
+        execution.cancel()
+        started = time.monotonic()
+        with pytest.raises(asyncio.CancelledError):
+            await execution
+        # The cancelled call was waited for before the cursor is released
+        assert time.monotonic() - started >= 0.04
+
+    asyncio.run(main())
+    assert cancelled == [None]
+
+
+def test_async_iteration_fetches_batches():
+    blocking = _BlockingCursor()
+    blocking.release.set()
+
+    async def main():
+        async with aio.AsyncConnection(_Connection(blocking)).cursor() as cursor:
+            return [row async for row in cursor]
+
+    assert asyncio.run(main()) == list(range(2500))
+    assert blocking.closed
+
+
+def test_round_trip(conn_str):
+    async def main():
+        async with await aio.connect(conn_str) as conn:
+            cursor = await conn.execute("SELECT ? + 1 AS value", 41)
+            assert (await cursor.fetchone()).value == 42
+            await cursor.execute("SELECT v FROM (VALUES (1), (2), (3)) AS t (v) ORDER BY v")
+            assert [row[0] async for row in cursor] == [1, 2, 3]
+            await conn.rollback()
+        assert conn.closed
+
+    asyncio.run(main())
+
+
+def test_concurrent_connections(conn_str):
+    async def query(seconds):
+        async with await aio.connect(conn_str) as conn:
+            cursor = await conn.execute(f"WAITFOR DELAY '00:00:0{seconds}'; SELECT {seconds}")
+            return (await cursor.fetchone())[0]
+
+    async def main():
+        started = time.monotonic()
+        assert await asyncio.gather(query(1), query(1), query(1)) == [1, 1, 1]
+        return time.monotonic() - started
+
+    assert asyncio.run(main()) < 2.5
@@ -0,0 +1,77 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for connection string defaults from MSSQL_* environment variables."""
+
+import pytest
+
+from mssql_python import environment_defaults
+from mssql_python.connection import Connection
+from mssql_python.readiness import _tcp_endpoint
+
+_ENVIRON = {
+    "MSSQL_HOST": "db.internal",
+    "MSSQL_PORT": "14330",
+    "MSSQL_DATABASE": "orders",
+    "MSSQL_USER": "app",
+    "MSSQL_PASSWORD": " secret ",
+    "MSSQL_ENCRYPT": "true",
+    "MSSQL_TRUST_SERVER_CERTIFICATE": "False",
+    "MSSQL_APPLICATION_INTENT": "",
+}
+
+
+def test_defaults_from_the_environment():
+    assert environment_defaults(environ=_ENVIRON) == {
+        "Server": "db.internal,14330",
+        "Database": "orders",
+        "Encrypt": "yes",
+        "TrustServerCertificate": "no",
+        "UID": "app",
+        # Passwords are taken verbatim
+        "PWD": " secret ",
+    }
+
+
+def test_connection_string_keywords_win():
+    params = {"server": "other", "ENCRYPT": "strict"}
+    defaults = environment_defaults(params, _ENVIRON)
+    assert "Server" not in defaults and "Encrypt" not in defaults
+    assert defaults["Database"] == "orders"
+
+
+@pytest.mark.parametrize("keyword", ["UID", "PWD", "Authentication", "Trusted_Connection"])
+def test_credentials_apply_together(keyword):
+    defaults = environment_defaults({keyword: "x"}, _ENVIRON)
+    assert "UID" not in defaults and "PWD" not in defaults
+
+
+def test_password_file(tmp_path):
+    secret = tmp_path / "password"
+    secret.write_text("p@ss;word\n", encoding="utf-8")
+    environ = {"MSSQL_USER": "app", "MSSQL_PASSWORD_FILE": str(secret)}
+    assert environment_defaults(environ=environ)["PWD"] == "p@ss;word"
+    with pytest.raises(ValueError, match="not both"):
+        environment_defaults(environ=dict(environ, MSSQL_PASSWORD="x"))
+    with pytest.raises(ValueError, match="Cannot read MSSQL_PASSWORD_FILE"):
+        environment_defaults(environ={"MSSQL_PASSWORD_FILE": str(tmp_path / "missing")})
+
+
+@pytest.mark.parametrize("port", ["http", "0", "70000"])
+def test_invalid_port(port):
+    with pytest.raises(ValueError, match="MSSQL_PORT"):
+        environment_defaults(environ={"MSSQL_HOST": "db", "MSSQL_PORT": port})
+
+
+def test_connection_string_is_completed(monkeypatch):
+    for name, value in _ENVIRON.items():
+        monkeypatch.setenv(name, value)
+    conn = Connection.__new__(Connection)
+    conn_str, params = conn._construct_connection_string("Database=sales", encrypt="strict")
+    assert (params["Server"], params["Database"], params["Encrypt"]) == (
+        "db.internal,14330",
+        "sales",
+        "strict",
+    )
+    assert "UID=app" in conn_str
+    assert _tcp_endpoint("Database=sales") == ("db.internal", 14330)
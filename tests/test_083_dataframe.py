@@ -0,0 +1,122 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for bulk copying pandas DataFrames (Cursor.write_dataframe)."""
+
+import datetime
+import decimal
+
+import pytest
+
+from mssql_python.dataframe import (
+    _value_sql_type,
+    create_table_statement,
+    sql_type_for_dtype,
+)
+
+
+@pytest.mark.parametrize(
+    "dtype, sql_type",
+    [
+        ("bool", "BIT"),
+        ("boolean", "BIT"),
+        ("int8", "SMALLINT"),
+        ("uint8", "TINYINT"),
+        ("int32", "INT"),
+        ("Int64", "BIGINT"),
+        ("UInt64", "DECIMAL(20, 0)"),
+        ("float32", "REAL"),
+        ("Float64", "FLOAT"),
+        ("datetime64[ns]", "DATETIME2(7)"),
+        ("datetime64[us, Europe/Paris]", "DATETIMEOFFSET(7)"),
+        ("object", None),
+        ("string[pyarrow]", None),
+        ("category", None),
+    ],
+)
+def test_sql_type_for_dtype(dtype, sql_type):
+    assert sql_type_for_dtype(dtype) == sql_type
+
+
+def test_unsupported_dtype():
+    with pytest.raises(TypeError, match="timedelta64"):
+        sql_type_for_dtype("timedelta64[ns]")
+
+
+def test_object_value_types():
+    assert _value_sql_type(["a", "abc"]) == "NVARCHAR(3)"
+    assert _value_sql_type(["x" * 4001]) == "NVARCHAR(MAX)"
+    assert _value_sql_type([]) == "NVARCHAR(1)"
+    assert _value_sql_type([decimal.Decimal("1.5")]) == "DECIMAL(38, 10)"
+    # datetime is a date subclass, so the first matching Python type decides
+    assert _value_sql_type([datetime.datetime(2024, 1, 1)]) == "DATETIME2(7)"
+    with pytest.raises(TypeError, match="one type, got date, datetime"):
+        _value_sql_type([datetime.datetime(2024, 1, 1), datetime.date(2024, 1, 1)])
+
+
+def test_create_table_statement():
+    assert create_table_statement("dbo.scores", [("name", "NVARCHAR(5)"), ("x]", "INT")]) == (
+        "CREATE TABLE [dbo].[scores] ([name] NVARCHAR(5) NULL, [x]]] INT NULL)"
+    )
+
+
+def test_dataframe_columns_and_rows():
+    pd = pytest.importorskip("pandas")
+    from mssql_python.dataframe import dataframe_columns, dataframe_rows
+
+    df = pd.DataFrame(
+        {
+            "id": pd.array([1, None, 3], dtype="Int64"),
+            "score": [1.5, float("nan"), 2.0],
+            "at": pd.to_datetime(["2024-01-01", None, "2024-01-03"]),
+            "tier": pd.Categorical(["gold", None, "gold"]),
+            "name": ["a", None, "abcd"],
+        }
+    )
+    assert dataframe_columns(df) == [
+        ("id", "BIGINT"),
+        ("score", "FLOAT"),
+        ("at", "DATETIME2(7)"),
+        ("tier", "NVARCHAR(4)"),
+        ("name", "NVARCHAR(4)"),
+    ]
+    rows = list(dataframe_rows(df, chunk_size=2))
+    assert rows[1] == (None, None, None, None, None)
+    assert rows[2] == (3, 2.0, datetime.datetime(2024, 1, 3), "gold", "abcd")
+    assert type(rows[2][0]) is int
+
+
+def test_duplicate_column_names():
+    pd = pytest.importorskip("pandas")
+    from mssql_python.dataframe import dataframe_columns
+
+    with pytest.raises(ValueError, match="unique"):
+        dataframe_columns(pd.DataFrame([[1, 2]], columns=["Id", "ID"]))
+
+
+def test_write_dataframe(cursor, db_connection):
+    pd = pytest.importorskip("pandas")
+    df = pd.DataFrame(
+        {
+            "id": pd.array([1, 2, None], dtype="Int64"),
+            "name": ["one", "two", None],
+            "at": pd.to_datetime(["2024-01-01 10:00", None, "2024-01-03"]),
+        }
+    ).set_index("id")
+    # A permanent table: bulkcopy() may load it over another connection
+    table = "dbo.write_dataframe_test"
+    try:
+        cursor.execute(f"IF OBJECT_ID('{table}') IS NOT NULL DROP TABLE {table}")
+        cursor.write_dataframe(table, df, chunk_size=2, create_table=True, index=True)
+        cursor.execute(f"SELECT id, name, at FROM {table} ORDER BY name")
+        rows = [tuple(row) for row in cursor.fetchall()]
+        assert rows == [
+            (None, None, datetime.datetime(2024, 1, 3)),
+            (1, "one", datetime.datetime(2024, 1, 1, 10)),
+            (2, "two", None),
+        ]
+        with pytest.raises(ValueError, match="chunk_size"):
+            cursor.write_dataframe(table, df, chunk_size=0)
+    finally:
+        cursor.execute(f"IF OBJECT_ID('{table}') IS NOT NULL DROP TABLE {table}")
+        db_connection.commit()
@@ -0,0 +1,126 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for the password_file and token_file connect options (secret_file)."""
+
+import os
+import struct
+
+import pytest
+
+from mssql_python import connect
+from mssql_python import connection as connection_module
+from mssql_python.constants import ConstantsDDBC
+from mssql_python.secret_file import SecretFile
+
+_ACCESS_TOKEN = ConstantsDDBC.SQL_COPT_SS_ACCESS_TOKEN.value
+
+
+class _FakeDriverConnection:
+    """Stands in for ddbc_bindings.Connection, recording what each connect was given."""
+
+    opened = []
+
+    def __init__(self, conn_str, pooling, attrs_before):
+        self.opened.append((conn_str, dict(attrs_before)))
+        self._autocommit = False
+
+    def set_autocommit(self, value):
+        self._autocommit = value
+
+    def get_autocommit(self):
+        return self._autocommit
+
+    def close(self):
+        pass
+
+
+@pytest.fixture
+def fake_driver(monkeypatch):
+    monkeypatch.setattr(_FakeDriverConnection, "opened", [])
+    monkeypatch.setattr(connection_module.ddbc_bindings, "Connection", _FakeDriverConnection)
+    return _FakeDriverConnection.opened
+
+
+def test_path_is_read_again(tmp_path):
+    path = tmp_path / "password"
+    path.write_text("first\n", encoding="utf-8")
+    secret = SecretFile(path, "password_file")
+    assert secret.read() == "first"
+    path.write_text("second", encoding="utf-8")
+    assert secret.read() == "second"
+
+
+def test_descriptor_is_read_from_its_start(tmp_path):
+    path = tmp_path / "token"
+    path.write_text("first", encoding="utf-8")
+    fd = os.open(path, os.O_RDWR)
+    try:
+        secret = SecretFile(fd, "token_file")
+        assert secret.read() == "first"
+        os.ftruncate(fd, 0)
+        os.pwrite(fd, b"second\n", 0)
+        assert secret.read() == "second"
+    finally:
+        os.close(fd)
+
+
+def test_pipe_is_read_once():
+    read_end, write_end = os.pipe()
+    try:
+        os.write(write_end, b"s3cret\r\n")
+        os.close(write_end)
+        secret = SecretFile(read_end, "password_file")
+        assert secret.read() == secret.read() == "s3cret"
+    finally:
+        os.close(read_end)
+
+
+def test_invalid_sources(tmp_path):
+    with pytest.raises(TypeError, match="path or a file descriptor"):
+        SecretFile(True, "password_file")
+    with pytest.raises(ValueError, match="Cannot read token_file"):
+        SecretFile(tmp_path / "missing", "token_file").read()
+    (tmp_path / "binary").write_bytes(b"\xff\xfe")
+    with pytest.raises(ValueError, match="not UTF-8"):
+        SecretFile(tmp_path / "binary", "password_file").read()
+
+
+@pytest.mark.parametrize(
+    "connection_str, options, message",
+    [
+        ("Server=db;UID=u;PWD=p", {"password_file": "x"}, "PWD or password_file"),
+        ("Server=db", {"password_file": "x", "token_file": "y"}, "not both"),
+        ("Server=db;UID=u", {"token_file": "y"}, "combined with UID"),
+        ("Server=db", {"token_file": "y", "attrs_before": {_ACCESS_TOKEN: b"t"}}, "not both"),
+    ],
+)
+def test_conflicting_credentials(fake_driver, connection_str, options, message):
+    with pytest.raises(ValueError, match=message):
+        connect(connection_str, **options)
+    assert not fake_driver
+
+
+def test_password_is_read_again_on_reconnect(fake_driver, tmp_path):
+    path = tmp_path / "password"
+    path.write_text("first", encoding="utf-8")
+    conn = connect("Server=db;UID=app", password_file=str(path))
+    path.write_text("rotated", encoding="utf-8")
+    conn._reconnect()
+    assert "PWD=first;" in fake_driver[0][0]
+    assert "PWD=rotated;" in fake_driver[1][0]
+
+
+def test_token_is_sent_as_access_token(fake_driver, tmp_path):
+    path = tmp_path / "token"
+    path.write_text("eyJ0eXAi\n", encoding="utf-8")
+    conn = connect("Server=db", token_file=path)
+    path.write_text("eyJhbGci", encoding="utf-8")
+    conn._reconnect()
+    for (conn_str, attrs_before), token in zip(fake_driver, ("eyJ0eXAi", "eyJhbGci")):
+        encoded = token.encode("utf-16-le")
+        assert attrs_before[_ACCESS_TOKEN] == struct.pack("<I", len(encoded)) + encoded
+        assert "PWD" not in conn_str
+    path.write_text("", encoding="utf-8")
+    with pytest.raises(ValueError, match="is empty"):
+        conn._reconnect()
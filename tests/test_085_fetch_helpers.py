@@ -0,0 +1,139 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for the single-call query helpers (fetch_val, fetch_row, fetch_all)."""
+
+import asyncio
+import datetime
+import decimal
+import uuid
+
+import pytest
+
+from mssql_python import DataError, aio
+from mssql_python.connection import Connection
+
+
+class _Cursor:
+    """A Cursor stand-in returning fixed rows."""
+
+    def __init__(self, rows, error=None):
+        self.rows = rows
+        self.error = error
+        self.executed = None
+        self.closed = False
+
+    def execute(self, sql, *params):
+        if self.error:
+            raise self.error
+        self.executed = (sql, params)
+        return self
+
+    def fetchval(self):
+        return self.rows[0][0] if self.rows else None
+
+    def fetchone(self):
+        return self.rows[0] if self.rows else None
+
+    def fetchall(self):
+        return list(self.rows)
+
+    def close(self):
+        self.closed = True
+
+
+def _connection(cursor):
+    conn = Connection.__new__(Connection)
+    conn.cursor = lambda: cursor
+    return conn
+
+
+@pytest.mark.parametrize(
+    "method, expected",
+    [("fetch_val", 1), ("fetch_row", (1, "a")), ("fetch_all", [(1, "a"), (2, "b")])],
+)
+def test_helpers_fetch_and_close(method, expected):
+    cursor = _Cursor([(1, "a"), (2, "b")])
+    assert getattr(_connection(cursor), method)("SELECT ?", 5) == expected
+    assert cursor.executed == ("SELECT ?", (5,))
+    assert cursor.closed
+
+
+def test_cursor_is_closed_when_the_query_fails():
+    cursor = _Cursor([], error=RuntimeError("boom"))
+    with pytest.raises(RuntimeError, match="boom"):
+        _connection(cursor).fetch_row("SELECT 1")
+    assert cursor.closed
+
+
+def test_no_rows():
+    conn = _connection(_Cursor([]))
+    assert conn.fetch_val("SELECT 1 WHERE 0 = 1") is None
+    assert conn.fetch_row("SELECT 1 WHERE 0 = 1") is None
+
+
+def test_helpers_against_the_server(db_connection):
+    assert db_connection.fetch_val("SELECT ? * 2", 21) == 42
+    row = db_connection.fetch_row("SELECT 1 AS id, N'one' AS name UNION ALL SELECT 2, N'two'")
+    assert (row.id, row.name) == (1, "one")
+    rows = db_connection.fetch_all("SELECT v FROM (VALUES (3), (1), (2)) AS t (v) ORDER BY v")
+    assert [row.v for row in rows] == [1, 2, 3]
+    assert db_connection.fetch_val("DECLARE @x INT = 1") is None
+
+
+def test_async_helpers(conn_str):
+    async def main():
+        async with await aio.connect(conn_str) as conn:
+            assert await conn.fetch_val("SELECT ? + 1", 41) == 42
+            assert (await conn.fetch_row("SELECT 7 AS seven")).seven == 7
+            assert len(await conn.fetch_all("SELECT 1 UNION ALL SELECT 2")) == 2
+
+    asyncio.run(main())
+
+
+@pytest.mark.parametrize(
+    "value, as_type, expected",
+    [
+        (decimal.Decimal("3"), int, 3),
+        (4.0, int, 4),
+        (5, decimal.Decimal, decimal.Decimal(5)),
+        (0.1, decimal.Decimal, decimal.Decimal("0.1")),
+        (1, bool, True),
+        (uuid.UUID(int=1), str, "00000000-0000-0000-0000-000000000001"),
+        ("00000000-0000-0000-0000-000000000001", uuid.UUID, uuid.UUID(int=1)),
+        (datetime.date(2024, 1, 2), datetime.date, datetime.date(2024, 1, 2)),
+    ],
+)
+def test_typed_values_are_converted(value, as_type, expected):
+    result = _connection(_Cursor([(value,)])).fetch_val("SELECT x", as_type=as_type)
+    assert result == expected and type(result) is as_type
+
+
+@pytest.mark.parametrize(
+    "value, as_type",
+    [
+        (decimal.Decimal("3.5"), int),
+        (True, int),
+        ("12", int),
+        (2, bool),
+        (datetime.datetime(2024, 1, 2, 3), datetime.date),
+        (b"\x00", str),
+    ],
+)
+def test_typed_value_mismatch(value, as_type):
+    with pytest.raises(DataError, match=f"Expected a value of type {as_type.__name__}"):
+        _connection(_Cursor([(value,)])).fetch_val("SELECT x", as_type=as_type)
+
+
+def test_typed_null():
+    conn = _connection(_Cursor([(None,)]))
+    with pytest.raises(DataError, match="got NULL or no rows by 'SELECT MAX"):
+        conn.fetch_val("SELECT MAX(id) FROM t", as_type=int)
+    assert conn.fetch_val("SELECT MAX(id) FROM t", as_type=int, default=0) == 0
+    assert _connection(_Cursor([])).fetch_val("SELECT 1", as_type=str, default=None) is None
+
+
+def test_typed_value_from_the_server(db_connection):
+    assert db_connection.fetch_val("SELECT CAST(7 AS DECIMAL(5, 0))", as_type=int) == 7
+    with pytest.raises(DataError, match="Expected a value of type int, got str"):
+        db_connection.fetch_val("SELECT N'seven'", as_type=int)
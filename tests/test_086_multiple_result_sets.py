@@ -0,0 +1,83 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for batches and procedures that return several results (Cursor.nextset)."""
+
+import pytest
+
+
+def _results(cursor):
+    """Read every result of the current execution as ("rows", rows) or ("count", n)."""
+    results = []
+    while True:
+        if cursor.description:
+            results.append(("rows", [tuple(row) for row in cursor.fetchall()]))
+        else:
+            results.append(("count", cursor.rowcount))
+        if not cursor.nextset():
+            return results
+
+
+def test_row_counts_are_interleaved_with_result_sets(cursor, db_connection):
+    cursor.execute("CREATE TABLE #multi_results (id INT)")
+    try:
+        cursor.execute(
+            "INSERT INTO #multi_results VALUES (1), (2), (3);"
+            "SELECT id FROM #multi_results ORDER BY id;"
+            "UPDATE #multi_results SET id = id * 10 WHERE id > 1;"
+            "SELECT COUNT(*) FROM #multi_results WHERE id > 5;"
+            "DELETE FROM #multi_results;"
+        )
+        assert _results(cursor) == [
+            ("count", 3),
+            ("rows", [(1,), (2,), (3,)]),
+            ("count", 2),
+            ("rows", [(2,)]),
+            ("count", 3),
+        ]
+        assert cursor.description is None
+    finally:
+        cursor.execute("DROP TABLE #multi_results")
+        db_connection.commit()
+
+
+def test_procedure_with_several_result_sets(cursor, db_connection):
+    cursor.execute(
+        "CREATE PROCEDURE #multi_results_proc AS BEGIN "
+        "SET NOCOUNT ON; "
+        "SELECT 1 AS a; "
+        "PRINT 'between'; "
+        "SELECT 'x' AS b, 'y' AS c UNION ALL SELECT 'z', 'w'; "
+        "SELECT TOP 0 1 AS empty; "
+        "END"
+    )
+    try:
+        cursor.execute("EXEC #multi_results_proc")
+        assert cursor.description[0][0] == "a"
+        assert cursor.fetchall()[0].a == 1
+        assert cursor.nextset() is True
+        assert [column[0] for column in cursor.description] == ["b", "c"]
+        assert any("between" in message for _, message in cursor.messages)
+        assert [tuple(row) for row in cursor.fetchall()] == [("x", "y"), ("z", "w")]
+        assert cursor.nextset() is True
+        assert cursor.fetchall() == []
+        assert cursor.nextset() is False
+    finally:
+        cursor.execute("DROP PROCEDURE #multi_results_proc")
+        db_connection.commit()
+
+
+def test_unread_rows_are_skipped(cursor):
+    cursor.execute("SELECT 1 UNION ALL SELECT 2; SELECT 3")
+    assert cursor.fetchone()[0] == 1
+    assert cursor.nextset() is True
+    assert cursor.fetchall()[0][0] == 3
+    assert cursor.rowcount == 1
+
+
+def test_error_in_a_later_statement(cursor):
+    cursor.execute("SELECT 1; SELECT 1 / 0")
+    assert cursor.fetchone()[0] == 1
+    with pytest.raises(Exception, match="[Dd]ivide by zero"):
+        cursor.nextset()
+        cursor.fetchall()
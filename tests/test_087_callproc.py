@@ -0,0 +1,94 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for stored procedure calls with OUTPUT parameters (Cursor.callproc)."""
+
+import decimal
+
+import pytest
+
+from mssql_python import InOut, Output, ProgrammingError
+from mssql_python.procedure import _declared_type, call_batch, call_results
+
+
+def test_call_batch_binds_inputs_and_declares_outputs():
+    batch, values = call_batch("dbo.add_one", [5, Output("INT"), InOut("a", "NVARCHAR(10)")])
+    assert batch == (
+        "DECLARE @return_value INT, @p2 INT, @p3 NVARCHAR(10) = ?;\n"
+        "EXEC @return_value = [dbo].[add_one] ?, @p2 OUTPUT, @p3 OUTPUT;\n"
+        "SELECT @return_value, @p2, @p3;"
+    )
+    # The declaration's marker comes first in the batch
+    assert values == ["a", 5]
+
+
+def test_call_batch_by_name_with_looked_up_types():
+    batch, values = call_batch(
+        "#proc", {"@a": 1, "b": Output()}, [("@a", "INT"), ("@b", "DECIMAL(10, 2)")]
+    )
+    assert "@p2 DECIMAL(10, 2)" in batch
+    assert "EXEC @return_value = [#proc] @a = ?, @b = @p2 OUTPUT;" in batch
+    assert values == [1]
+
+
+def test_output_without_a_type():
+    with pytest.raises(ProgrammingError, match="type of OUTPUT parameter 1"):
+        call_batch("proc", [Output()], [])
+    with pytest.raises(ValueError, match="Invalid SQL type"):
+        Output("INT; DROP TABLE t")
+
+
+@pytest.mark.parametrize(
+    "row, declared",
+    [
+        (("nvarchar", 100, 0, 0), "nvarchar(50)"),
+        (("varbinary", -1, 0, 0), "varbinary(MAX)"),
+        (("decimal", 9, 12, 3), "decimal(12, 3)"),
+        (("datetime2", 8, 27, 7), "datetime2(7)"),
+        (("int", 4, 10, 0), "int"),
+    ],
+)
+def test_declared_types(row, declared):
+    assert _declared_type(*row) == declared
+
+
+def test_call_results_replace_outputs():
+    parameters = [1, Output("INT"), InOut(2)]
+    assert call_results(parameters, (7, 10, 20)) == (7, [1, 10, 20])
+    assert call_results({"a": Output("INT")}, (0, None)) == (0, {"a": None})
+
+
+@pytest.fixture
+def procedure(cursor, db_connection):
+    cursor.execute(
+        "CREATE PROCEDURE #callproc_test @a INT, @total INT OUTPUT, @label NVARCHAR(20) OUTPUT, "
+        "@ratio DECIMAL(6, 2) = NULL OUTPUT AS BEGIN "
+        "SELECT @a AS a; "
+        "PRINT 'computing'; "
+        "SET @total = @a + 1; "
+        "SET @label = @label + N'!'; "
+        "SET @ratio = @a / 4.0; "
+        "SELECT 'x' AS b UNION ALL SELECT 'y'; "
+        "RETURN 3; "
+        "END"
+    )
+    db_connection.commit()
+    yield "#callproc_test"
+    cursor.execute("DROP PROCEDURE #callproc_test")
+    db_connection.commit()
+
+
+def test_callproc_returns_outputs(cursor, procedure):
+    result = cursor.callproc(procedure, [5, Output(), InOut("hi"), Output()])
+    assert result == [5, 6, "hi!", decimal.Decimal("1.25")]
+    assert cursor.return_value == 3
+    assert [[tuple(row) for row in rows] for rows in cursor.result_sets] == [
+        [(5,)],
+        [("x",), ("y",)],
+    ]
+    assert any("computing" in message for _, message in cursor.messages)
+
+
+def test_callproc_by_name(cursor, procedure):
+    result = cursor.callproc(procedure, {"a": 1, "total": Output("BIGINT"), "label": None})
+    assert result == {"a": 1, "total": 2, "label": None}
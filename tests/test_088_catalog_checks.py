@@ -0,0 +1,52 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for the object_id, table_exists and column_exists connection helpers."""
+
+import pytest
+
+from mssql_python.connection import Connection, _object_name
+
+
+@pytest.mark.parametrize(
+    "schema, name, quoted",
+    [
+        ("dbo", "orders", "[dbo].[orders]"),
+        (None, "orders", "[orders]"),
+        ("sales.eu", "odd]name", "[sales.eu].[odd]]name]"),
+        (None, "#staging", "tempdb..[#staging]"),
+        ("dbo", "#staging", "tempdb.[dbo].[#staging]"),
+    ],
+)
+def test_object_names_are_quoted(schema, name, quoted):
+    assert _object_name(schema, name) == quoted
+
+
+def test_lookups_are_single_parameterized_probes():
+    queries = []
+    conn = Connection.__new__(Connection)
+    conn.fetch_val = lambda sql, *args: queries.append((sql, args)) or 42
+    assert conn.table_exists("dbo", "my table") is True
+    assert conn.object_id(None, "orders") == 42
+    assert conn.column_exists("dbo", "orders", "col]x") is True
+    assert queries == [
+        ("SELECT OBJECT_ID(?, ?)", ("[dbo].[my table]", "U")),
+        ("SELECT OBJECT_ID(?)", ("[orders]",)),
+        ("SELECT COL_LENGTH(?, ?)", ("[dbo].[orders]", "col]x")),
+    ]
+    with pytest.raises(ValueError, match="object_type"):
+        conn.object_id("dbo", "orders", "U'; --")
+
+
+def test_checks_against_the_server(cursor, db_connection):
+    cursor.execute("CREATE TABLE [#catalog check] ([odd.column] INT)")
+    try:
+        assert db_connection.table_exists(None, "#catalog check")
+        assert db_connection.column_exists(None, "#catalog check", "odd.column")
+        assert not db_connection.column_exists(None, "#catalog check", "missing")
+        assert db_connection.object_id("sys", "objects", "V") is not None
+        assert not db_connection.table_exists("sys", "objects")
+        assert not db_connection.table_exists("dbo", "no_such_table_here")
+    finally:
+        cursor.execute("DROP TABLE [#catalog check]")
+        db_connection.commit()
@@ -0,0 +1,86 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for application locks (Connection.acquire_app_lock / release_app_lock)."""
+
+import pytest
+
+from mssql_python import DatabaseError, OperationalError, connect
+from mssql_python.connection import Connection
+from mssql_python.retry import DEADLOCK_VICTIM
+
+
+class _Cursor:
+    """A Cursor stand-in whose procedure calls return a fixed return code."""
+
+    def __init__(self, return_value):
+        self.return_value = return_value
+        self.calls = []
+        self.closed = False
+
+    def callproc(self, procname, parameters):
+        self.calls.append((procname, parameters))
+
+    def close(self):
+        self.closed = True
+
+
+def _connection(cursor):
+    conn = Connection.__new__(Connection)
+    conn.cursor = lambda: cursor
+    return conn
+
+
+@pytest.mark.parametrize("return_value, granted", [(0, True), (1, True), (-1, False)])
+def test_acquire_return_codes(return_value, granted):
+    cursor = _Cursor(return_value)
+    assert _connection(cursor).acquire_app_lock("jobs", "shared", timeout=1.5) is granted
+    assert cursor.calls == [
+        (
+            "sp_getapplock",
+            {"Resource": "jobs", "LockMode": "Shared", "LockOwner": "Session", "LockTimeout": 1500},
+        )
+    ]
+    assert cursor.closed
+
+
+def test_acquire_errors():
+    with pytest.raises(OperationalError, match="deadlock victim") as error:
+        _connection(_Cursor(-3)).acquire_app_lock("jobs")
+    assert error.value.native_error == DEADLOCK_VICTIM
+    with pytest.raises(OperationalError, match="canceled"):
+        _connection(_Cursor(-2)).acquire_app_lock("jobs", timeout=0)
+
+
+@pytest.mark.parametrize(
+    "kwargs",
+    [{"name": ""}, {"name": "x" * 256}, {"mode": "Everything"}, {"timeout": -1}, {"timeout": True}],
+)
+def test_acquire_validates_arguments(kwargs):
+    arguments = {"name": "jobs", **kwargs}
+    with pytest.raises(ValueError):
+        _connection(_Cursor(0)).acquire_app_lock(**arguments)
+
+
+def test_release():
+    cursor = _Cursor(0)
+    _connection(cursor).release_app_lock("jobs")
+    assert cursor.calls == [("sp_releaseapplock", {"Resource": "jobs", "LockOwner": "Session"})]
+    with pytest.raises(DatabaseError, match="not held"):
+        _connection(_Cursor(-999)).release_app_lock("jobs")
+
+
+def test_app_lock_between_connections(db_connection, conn_str):
+    other = connect(conn_str)
+    try:
+        assert db_connection.acquire_app_lock("mssql_python_test_lock", timeout=5)
+        assert not other.acquire_app_lock("mssql_python_test_lock", timeout=0)
+        # Shared locks are compatible with each other, not with the exclusive one
+        assert not other.acquire_app_lock("mssql_python_test_lock", "Shared", timeout=0.1)
+        db_connection.release_app_lock("mssql_python_test_lock")
+        assert other.acquire_app_lock("mssql_python_test_lock", timeout=5)
+        other.release_app_lock("mssql_python_test_lock")
+        with pytest.raises(DatabaseError):
+            other.release_app_lock("mssql_python_test_lock")
+    finally:
+        other.close()
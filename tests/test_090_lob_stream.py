@@ -0,0 +1,98 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for streaming LOB reads (Cursor.fetch_lob_stream)."""
+
+import shutil
+
+import pytest
+
+from mssql_python import ProgrammingError
+from mssql_python.lob_stream import LobBinaryReader, LobReader, LobTextReader
+
+
+def _reader(chunks, check=lambda: None):
+    chunks = list(chunks)
+    first = chunks.pop(0)
+    return LobReader(lambda: chunks.pop(0) if chunks else b"", check, first)
+
+
+def test_binary_reader_pulls_chunks_on_demand():
+    stream = LobBinaryReader(_reader([b"abc", b"defg", b"h"]), 16)
+    assert stream.read(2) == b"ab"
+    assert stream.read() == b"cdefgh"
+    assert stream.read() == b""
+    assert not stream.is_null
+
+
+def test_text_reader_decodes_across_chunk_boundaries():
+    data = "héllo wörld €".encode("utf-16-le")
+    # Chunks splitting a UTF-16 code unit
+    stream = LobTextReader(_reader([data[:3], data[3:8], data[8:]]), 16)
+    assert stream.read() == "héllo wörld €"
+
+
+def test_null_and_empty_values():
+    null = LobBinaryReader(LobReader(lambda: b"", lambda: None, None), 16)
+    assert null.is_null and null.read() == b""
+    empty = LobTextReader(LobReader(lambda: b"", lambda: None, b""), 16)
+    assert not empty.is_null and empty.read() == ""
+
+
+def test_stale_stream_raises():
+    def check():
+        raise ProgrammingError(driver_error="no longer current", ddbc_error="")
+
+    stream = LobBinaryReader(_reader([b"ab", b"cd"], check), 16)
+    # The first chunk came with the row
+    assert stream.read(2) == b"ab"
+    with pytest.raises(ProgrammingError, match="no longer current"):
+        stream.read()
+
+
+@pytest.mark.parametrize(
+    "expression, unit",
+    [
+        ("CAST(REPLICATE(CAST('x' AS VARCHAR(MAX)), ?) AS VARBINARY(MAX))", b"x"),
+        ("REPLICATE(CAST('a' AS VARCHAR(MAX)), ?)", "a"),
+        ("REPLICATE(CAST(N'é' AS NVARCHAR(MAX)), ?)", "é"),
+    ],
+)
+@pytest.mark.parametrize("length", [4095, 4096, 4097, 8191, 8192, 8193, 200000])
+def test_lengths_around_chunk_boundaries(cursor, expression, unit, length):
+    cursor.execute(f"SELECT {expression}", length)
+    assert cursor.fetch_lob_stream(0, chunk_size=4096).read() == unit * length
+    assert cursor.fetch_lob_stream(0) is None
+    # Values fetched whole go through the same chunked reads
+    cursor.execute(f"SELECT {expression}", length)
+    assert cursor.fetchval() == unit * length
+
+
+def test_stream_rows_from_the_server(cursor, tmp_path):
+    cursor.execute(
+        "SELECT v FROM (VALUES (CAST(0x0102 AS VARBINARY(MAX))), (NULL), (0x)) AS t (v)"
+    )
+    first = cursor.fetch_lob_stream(0)
+    with open(tmp_path / "out.bin", "wb") as out:
+        shutil.copyfileobj(first, out)
+    assert (tmp_path / "out.bin").read_bytes() == b"\x01\x02"
+    assert cursor.fetch_lob_stream(0).is_null
+    assert cursor.fetch_lob_stream(0).read() == b""
+    assert cursor.fetch_lob_stream(0) is None
+
+
+def test_stream_is_invalidated_by_the_next_fetch(cursor):
+    cursor.execute("SELECT REPLICATE(CAST('a' AS VARCHAR(MAX)), 100000) UNION ALL SELECT 'b'")
+    stream = cursor.fetch_lob_stream(0, chunk_size=1024)
+    stream.read(10)
+    cursor.fetchone()
+    with pytest.raises(ProgrammingError, match="no longer current"):
+        stream.read()
+
+
+def test_fetch_lob_stream_rejects_other_columns(cursor):
+    cursor.execute("SELECT 1 AS n")
+    with pytest.raises(ProgrammingError, match="not a character or binary column"):
+        cursor.fetch_lob_stream(0)
+    with pytest.raises(ProgrammingError, match="Invalid column index"):
+        cursor.fetch_lob_stream(1)
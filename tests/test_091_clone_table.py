@@ -0,0 +1,124 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for cloning tables (Connection.clone_table)."""
+
+import pytest
+
+from mssql_python import ProgrammingError
+from mssql_python.helpers import catalog_of
+from mssql_python.table_clone import CloneColumn, clone_columns, clone_statements
+
+
+class _Cursor:
+    """A Cursor stand-in returning fixed catalog rows."""
+
+    def __init__(self, rows):
+        self.rows = rows
+        self.executed = None
+
+    def execute(self, sql, *params):
+        self.executed = (sql, params)
+
+    def fetchall(self):
+        return self.rows
+
+
+def _row(name, type_name, max_length=4, precision=10, scale=0, nullable=True, **extra):
+    return (
+        name,
+        type_name,
+        max_length,
+        precision,
+        scale,
+        nullable,
+        extra.get("collation"),
+        extra.get("user_defined", False),
+        "dbo",
+        extra.get("identity", False),
+        "100" if extra.get("identity") else None,
+        "5" if extra.get("identity") else None,
+        extra.get("computed"),
+        extra.get("persisted"),
+    )
+
+
+def test_columns_are_scripted_from_the_catalog():
+    cursor = _Cursor(
+        [
+            _row("id", "bigint", 8, 19, nullable=False, identity=True),
+            _row("name", "nvarchar", 200, 0, collation="Latin1_General_CI_AS"),
+            _row("price", "decimal", 9, 12, 2),
+            _row("total", "decimal", 17, 38, 2, computed="([price]*(2))", persisted=True),
+            _row("code", "Sku", 10, 0, user_defined=True),
+            _row("version", "timestamp", 8, 0, nullable=False),
+        ]
+    )
+    columns = clone_columns(cursor, "#source")
+    assert "FROM tempdb.sys.columns" in cursor.executed[0]
+    assert cursor.executed[1] == ("tempdb..[#source]",)
+    assert [column.definition() for column in columns] == [
+        "[id] bigint IDENTITY(100, 5) NOT NULL",
+        "[name] nvarchar(100) COLLATE Latin1_General_CI_AS NULL",
+        "[price] decimal(12, 2) NULL",
+        "[total] AS ([price]*(2)) PERSISTED",
+        "[code] [dbo].[Sku] NULL",
+        "[version] timestamp NOT NULL",
+    ]
+    copied = [column.name for column in columns if column.is_copied]
+    assert copied == ["id", "name", "price", "code"]
+
+
+def test_missing_table():
+    with pytest.raises(ProgrammingError, match="Table 'dbo.nope' was not found"):
+        clone_columns(_Cursor([]), "dbo.nope")
+
+
+def test_clone_statements():
+    columns = [CloneColumn("id", "int", False, identity="IDENTITY(1, 1)"), CloneColumn("v", "int")]
+    assert clone_statements("src", "dbo.dst", columns) == [
+        "DROP TABLE IF EXISTS [dbo].[dst]",
+        "CREATE TABLE [dbo].[dst] (\n    [id] int IDENTITY(1, 1) NOT NULL,\n    [v] int NULL\n)",
+        "SET IDENTITY_INSERT [dbo].[dst] ON",
+        "INSERT INTO [dbo].[dst] WITH (TABLOCK) ([id], [v]) SELECT [id], [v] FROM [src]",
+        "SET IDENTITY_INSERT [dbo].[dst] OFF",
+    ]
+    assert clone_statements("src", "dst", columns[1:], with_data=False, replace=False) == [
+        "CREATE TABLE [dst] (\n    [v] int NULL\n)"
+    ]
+
+
+@pytest.mark.parametrize(
+    "name, expected",
+    [
+        ("dbo.t", ("", "dbo.t")),
+        ("other.dbo.t", ("[other].", "other.dbo.t")),
+        ("#t", ("tempdb.", "tempdb..[#t]")),
+    ],
+)
+def test_catalog_of(name, expected):
+    assert catalog_of(name) == expected
+
+
+def test_clone_table_against_the_server(cursor, db_connection):
+    cursor.execute(
+        "CREATE TABLE #clone_source (id INT IDENTITY(10, 2) NOT NULL, "
+        "name NVARCHAR(20) COLLATE Latin1_General_BIN NOT NULL, doubled AS (id * 2))"
+    )
+    cursor.execute("INSERT INTO #clone_source (name) VALUES (N'a'), (N'b')")
+    db_connection.commit()
+    try:
+        assert db_connection.clone_table("#clone_source", "#clone_dest") == 2
+        # Running it again replaces the earlier snapshot
+        assert db_connection.clone_table("#clone_source", "#clone_dest") == 2
+        rows = cursor.execute("SELECT id, name, doubled FROM #clone_dest ORDER BY id").fetchall()
+        assert [tuple(row) for row in rows] == [(10, "a", 20), (12, "b", 24)]
+        cursor.execute("INSERT INTO #clone_dest (name) VALUES (N'c')")
+        assert cursor.execute("SELECT MAX(id) FROM #clone_dest").fetchval() == 14
+        with pytest.raises(ProgrammingError):
+            db_connection.clone_table("#clone_source", "#clone_dest", replace=False)
+        assert db_connection.clone_table("#clone_source", "#clone_empty", with_data=False) == 0
+        assert cursor.execute("SELECT COUNT(*) FROM #clone_empty").fetchval() == 0
+    finally:
+        cursor.execute("DROP TABLE IF EXISTS #clone_source, #clone_dest, #clone_empty")
+        db_connection.commit()
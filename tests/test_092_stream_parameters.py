@@ -0,0 +1,89 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for file-like parameters, streamed to the server while executing."""
+
+import hashlib
+import io
+
+import pytest
+
+from mssql_python.constants import ConstantsDDBC as _C
+from mssql_python.cursor import Cursor
+
+
+def _bind(value):
+    """Return (sql_type, c_type, column_size, decimal_digits, is_dae) of value."""
+    cursor = Cursor.__new__(Cursor)
+    cursor._inputsizes = None
+    return cursor._map_sql_type(value, [value], 0)
+
+
+class _Reader:
+    """A minimal file-like object: only read()."""
+
+    def __init__(self, data, chunk=1000):
+        self.data = data
+        self.chunk = chunk
+        self.reads = 0
+
+    def read(self, size=-1):
+        self.reads += 1
+        size = min(size, self.chunk) if size >= 0 else len(self.data)
+        part, self.data = self.data[:size], self.data[size:]
+        return part
+
+
+@pytest.mark.parametrize(
+    "value, sql_type, c_type",
+    [
+        (io.BytesIO(b"abc"), _C.SQL_VARBINARY, _C.SQL_C_BINARY),
+        (_Reader(b"abc"), _C.SQL_VARBINARY, _C.SQL_C_BINARY),
+        (io.StringIO("abc"), _C.SQL_WVARCHAR, _C.SQL_C_WCHAR),
+    ],
+)
+def test_streams_are_bound_as_data_at_execution(value, sql_type, c_type):
+    assert _bind(value) == (sql_type.value, c_type.value, 0, 0, True)
+
+
+def test_other_values_are_not_streams():
+    # bytes-like values have no read(); memoryview stays unsupported as before
+    with pytest.raises(TypeError, match="Unsupported parameter type"):
+        _bind(memoryview(b"abc"))
+
+
+def test_binary_stream_upload(cursor, db_connection, tmp_path):
+    payload = bytes(range(256)) * 40000 + b"tail"  # ~10 MB, not a multiple of a chunk
+    path = tmp_path / "payload.bin"
+    path.write_bytes(payload)
+    cursor.execute("CREATE TABLE #uploads (id INT, data VARBINARY(MAX))")
+    try:
+        with open(path, "rb") as stream:
+            cursor.execute("INSERT INTO #uploads VALUES (?, ?)", 1, stream)
+        reader = _Reader(payload, chunk=3000)
+        cursor.execute("INSERT INTO #uploads VALUES (?, ?)", 2, reader)
+        assert reader.reads > 1
+        cursor.execute("INSERT INTO #uploads VALUES (?, ?)", 3, io.BytesIO(b""))
+        rows = cursor.execute(
+            "SELECT id, DATALENGTH(data), HASHBYTES('SHA2_256', data) FROM #uploads ORDER BY id"
+        ).fetchall()
+        digest = hashlib.sha256(payload).digest()
+        assert [tuple(row) for row in rows] == [
+            (1, len(payload), digest),
+            (2, len(payload), digest),
+            (3, 0, hashlib.sha256(b"").digest()),
+        ]
+    finally:
+        cursor.execute("DROP TABLE #uploads")
+        db_connection.commit()
+
+
+def test_text_stream_upload(cursor, db_connection):
+    text = "snowman ☃ and a long line " * 5000
+    cursor.execute("CREATE TABLE #text_uploads (body NVARCHAR(MAX))")
+    try:
+        cursor.execute("INSERT INTO #text_uploads VALUES (?)", io.StringIO(text))
+        assert cursor.execute("SELECT body FROM #text_uploads").fetchval() == text
+    finally:
+        cursor.execute("DROP TABLE #text_uploads")
+        db_connection.commit()
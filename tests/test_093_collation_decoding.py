@@ -0,0 +1,97 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for collation-aware decoding of narrow (VARCHAR) character data."""
+
+import threading
+
+import pytest
+
+import mssql_python
+from mssql_python import ProgrammingError
+from mssql_python.collation import collation_code_page, collation_codec, narrow_char_decoder
+from mssql_python.connection import Connection
+
+
+@pytest.mark.parametrize(
+    "collation, code_page",
+    [
+        ("Latin1_General_CI_AS", 1252),
+        ("SQL_Latin1_General_CP1_CI_AS", 1252),
+        ("SQL_Latin1_General_CP850_BIN", 850),
+        ("SQL_Latin1_General_CP1251_CI_AS", 1251),
+        ("Cyrillic_General_CI_AS", 1251),
+        ("Polish_100_CI_AS", 1250),
+        ("Greek_CS_AS_KS_WS", 1253),
+        ("Japanese_XJIS_140_CI_AS", 932),
+        ("Chinese_PRC_CI_AS", 936),
+        ("Latin1_General_100_CI_AS_SC_UTF8", 65001),
+        ("French_CI_AS", 1252),
+        (None, None),
+    ],
+)
+def test_collation_code_page(collation, code_page):
+    assert collation_code_page(collation) == code_page
+
+
+def test_collation_codec():
+    assert collation_codec("Latin1_General_CI_AS") == "cp1252"
+    assert collation_codec("Latin1_General_100_CI_AS_SC_UTF8") == "utf-8"
+    assert collation_codec(None) is None
+
+
+def test_decoder_uses_the_collation_then_the_fallback():
+    decode = narrow_char_decoder("Cyrillic_General_CI_AS", None)
+    assert decode("привет".encode("cp1251")) == "привет"
+    assert decode("already text") == "already text"
+
+    utf8 = narrow_char_decoder("Latin1_General_100_CI_AS_SC_UTF8", "cp1252")
+    assert utf8(b"caf\xe9") == "café"
+    assert narrow_char_decoder(None, "ascii")(b"caf\xe9") == "caf�"
+    assert narrow_char_decoder(None, None)(b"caf\xe9") == b"caf\xe9"
+
+
+def _connection():
+    conn = Connection.__new__(Connection)
+    conn._closed = False
+    conn._encoding_lock = threading.RLock()
+    conn._char_fallback_encoding = None
+    conn._decoding_settings = {
+        mssql_python.SQL_CHAR: {"encoding": "utf-16le", "ctype": mssql_python.SQL_WCHAR},
+    }
+    return conn
+
+
+def test_setdecoding_fallback():
+    conn = _connection()
+    conn.setdecoding(mssql_python.SQL_CHAR, "utf-8", mssql_python.SQL_CHAR, fallback="CP1252")
+    assert conn.getdecoding(mssql_python.SQL_CHAR) == {
+        "encoding": "utf-8",
+        "ctype": mssql_python.SQL_CHAR,
+        "fallback": "cp1252",
+    }
+    conn.setdecoding(mssql_python.SQL_CHAR)
+    assert "fallback" not in conn.getdecoding(mssql_python.SQL_CHAR)
+
+
+@pytest.mark.parametrize(
+    "sqltype, fallback",
+    [(mssql_python.SQL_WCHAR, "cp1252"), (mssql_python.SQL_CHAR, "no-such-codec")],
+)
+def test_setdecoding_invalid_fallback(sqltype, fallback):
+    with pytest.raises(ProgrammingError):
+        _connection().setdecoding(sqltype, fallback=fallback)
+
+
+def test_varchar_decoded_with_the_column_collation(db_connection):
+    db_connection.setdecoding(mssql_python.SQL_CHAR, "utf-8", mssql_python.SQL_CHAR)
+    try:
+        cursor = db_connection.cursor()
+        cursor.execute(
+            "SELECT CAST(N'éééééééééé' AS VARCHAR(10)) COLLATE Latin1_General_CI_AS, "
+            "CAST(N'Ж' AS VARCHAR(10)) COLLATE Cyrillic_General_CI_AS"
+        )
+        assert tuple(cursor.fetchone()) == ("éééééééééé", "Ж")
+        cursor.close()
+    finally:
+        db_connection.setdecoding(mssql_python.SQL_CHAR)
@@ -0,0 +1,105 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for diff_results(), the streaming comparison of two result sets."""
+
+import decimal
+
+import pytest
+
+from mssql_python import ProgrammingError, RowDifference, diff_results
+
+
+class _Cursor:
+    """A Cursor stand-in serving fixed rows in batches."""
+
+    def __init__(self, columns, rows):
+        self.description = [(name, None, None, None, None, None, True) for name in columns]
+        self.rows = list(rows)
+        self.batches = []
+
+    def fetchmany(self, size):
+        batch, self.rows = self.rows[:size], self.rows[size:]
+        self.batches.append(len(batch))
+        return batch
+
+
+def _diff(rows_a, rows_b, key="id", columns=("id", "name"), columns_b=None, **kwargs):
+    cursor_a = _Cursor(columns, rows_a)
+    cursor_b = _Cursor(columns_b or columns, rows_b)
+    return [d.to_dict() for d in diff_results(cursor_a, cursor_b, key, **kwargs)]
+
+
+def test_inserted_deleted_and_changed_keys():
+    rows_a = [(1, "a"), (2, "b"), (3, "c"), (5, "e")]
+    rows_b = [(0, "z"), (2, "b"), (3, "C"), (4, "d")]
+    assert _diff(rows_a, rows_b, batch_size=2) == [
+        {"kind": "inserted", "key": (0,), "columns": []},
+        {"kind": "deleted", "key": (1,), "columns": []},
+        {"kind": "changed", "key": (3,), "columns": ["name"]},
+        {"kind": "inserted", "key": (4,), "columns": []},
+        {"kind": "deleted", "key": (5,), "columns": []},
+    ]
+
+
+def test_identical_results_have_no_differences():
+    rows = [(i, str(i)) for i in range(25)]
+    cursor_a, cursor_b = _Cursor(("id", "name"), rows), _Cursor(("id", "name"), rows)
+    assert list(diff_results(cursor_a, cursor_b, ["id"], batch_size=10)) == []
+    assert cursor_a.batches == [10, 10, 5, 0]
+
+
+def test_composite_keys_with_nulls_and_columns_matched_by_name():
+    rows_a = [(None, 1, decimal.Decimal("1.10"), "x"), ("a", 1, decimal.Decimal("2"), "y")]
+    rows_b = [("y", decimal.Decimal("1.1"), None, 1), ("z", decimal.Decimal("2"), "a", 1)]
+    differences = _diff(
+        rows_a,
+        rows_b,
+        key=["region", "id"],
+        columns=("region", "id", "amount", "only_a"),
+        columns_b=("only_b", "amount", "region", "id"),
+    )
+    assert differences == []
+
+
+def test_changed_rows_are_returned():
+    (difference,) = diff_results(
+        _Cursor(("id", "name"), [(1, "a")]), _Cursor(("id", "name"), [(1, "b")]), "id"
+    )
+    assert isinstance(difference, RowDifference)
+    assert (difference.row_a, difference.row_b) == ((1, "a"), (1, "b"))
+
+
+@pytest.mark.parametrize(
+    "rows, problem", [([(2, "b"), (1, "a")], "out of order"), ([(1, "a"), (1, "b")], "repeats")]
+)
+def test_unordered_or_duplicate_keys(rows, problem):
+    with pytest.raises(ProgrammingError, match=problem):
+        _diff(rows, [])
+
+
+def test_missing_key_column_or_result_set():
+    with pytest.raises(ProgrammingError, match="Key column"):
+        _diff([], [], key="code")
+    cursor = _Cursor(("id",), [])
+    cursor.description = None
+    with pytest.raises(ProgrammingError, match="no result set"):
+        list(diff_results(cursor, _Cursor(("id",), []), "id"))
+
+
+def test_diff_against_the_server(db_connection):
+    cursor_a, cursor_b = db_connection.cursor(), db_connection.cursor()
+    try:
+        cursor_a.execute(
+            "SELECT id, name FROM (VALUES (1, N'one'), (2, N'two'), (3, N'three')) "
+            "AS t (id, name) ORDER BY id"
+        )
+        cursor_b.execute(
+            "SELECT id, name FROM (VALUES (2, N'TWO'), (3, N'three'), (4, N'four')) "
+            "AS t (id, name) ORDER BY id"
+        )
+        differences = [(d.kind, d.key) for d in diff_results(cursor_a, cursor_b, "id")]
+        assert differences == [("deleted", (1,)), ("changed", (2,)), ("inserted", (4,))]
+    finally:
+        cursor_a.close()
+        cursor_b.close()
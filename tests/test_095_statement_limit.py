@@ -0,0 +1,174 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for the per-pool statement limit (pooling(max_concurrent_statements=...))."""
+
+import threading
+import time
+import weakref
+
+import pytest
+
+from mssql_python import OperationalError
+from mssql_python.connection import Connection
+from mssql_python.pooling import PoolingManager, ddbc_bindings
+from mssql_python.statement_limit import StatementLimiter
+
+
+@pytest.fixture
+def pooling_manager(monkeypatch):
+    """PoolingManager with the driver pool calls stubbed out."""
+    monkeypatch.setattr(ddbc_bindings, "enable_pooling", lambda *a: None)
+    monkeypatch.setattr(
+        ddbc_bindings,
+        "pool_stats",
+        lambda: [{"connection_string": "Server=a;PWD=secret", "size": 1}],
+    )
+    PoolingManager._reset_for_testing()
+    yield PoolingManager
+    PoolingManager._reset_for_testing()
+
+
+def test_fail_policy_rejects_beyond_the_limit():
+    limiter = StatementLimiter(2, "fail")
+    limiter.acquire()
+    limiter.acquire()
+    with pytest.raises(OperationalError, match="Too many concurrent statements"):
+        limiter.acquire()
+    limiter.release()
+    limiter.acquire()
+    stats = limiter.stats()
+    assert (stats["statements_active"], stats["statements_admitted"]) == (2, 3)
+    assert stats["statements_rejected"] == 1
+
+
+def test_wait_policy_queues_until_a_statement_finishes():
+    limiter = StatementLimiter(1)
+    limiter.acquire()
+    admitted = threading.Event()
+
+    def execute():
+        limiter.acquire()
+        admitted.set()
+
+    worker = threading.Thread(target=execute)
+    worker.start()
+    deadline = time.monotonic() + 5
+    while limiter.stats()["statements_queued"] != 1 and time.monotonic() < deadline:
+        time.sleep(0.01)
+    assert not admitted.is_set()
+    limiter.release()
+    worker.join(5)
+    assert admitted.is_set()
+    stats = limiter.stats()
+    assert (stats["statements_active"], stats["statements_queued"]) == (1, 0)
+    assert stats["statements_waited"] == 1 and stats["statement_wait_seconds"] > 0
+
+
+def test_wait_timeout():
+    limiter = StatementLimiter(1, timeout=0.05)
+    limiter.acquire()
+    with pytest.raises(OperationalError, match="after waiting 0.05 seconds"):
+        limiter.acquire()
+    assert limiter.stats()["statements_rejected"] == 1
+
+
+@pytest.mark.parametrize(
+    "args", [(0,), (1, "drop"), (1, "wait", -1)], ids=["limit", "policy", "timeout"]
+)
+def test_invalid_limiter_options(args):
+    with pytest.raises(ValueError):
+        StatementLimiter(*args)
+
+
+def test_limiters_are_per_pool_and_reported_in_stats(pooling_manager):
+    assert pooling_manager.statement_limiter("Server=a") is None
+    pooling_manager.enable(max_concurrent_statements=3, statement_queue_policy="fail")
+    limiter = pooling_manager.statement_limiter("Server=a;PWD=secret")
+    assert limiter is pooling_manager.statement_limiter("Server=a;PWD=secret")
+    assert limiter is not pooling_manager.statement_limiter("Server=b")
+    assert (limiter.max_concurrent, limiter.policy) == (3, "fail")
+    limiter.acquire()
+    (entry,) = pooling_manager.stats()
+    assert entry["statements_active"] == 1 and entry["max_concurrent_statements"] == 3
+    assert "secret" not in entry["connection_string"]
+
+
+@pytest.mark.parametrize(
+    "options",
+    [
+        {"max_concurrent_statements": -1},
+        {"statement_queue_policy": "drop"},
+        {"statement_queue_timeout": -1},
+    ],
+)
+def test_invalid_pool_options(pooling_manager, options):
+    with pytest.raises(ValueError):
+        pooling_manager.enable(**options)
+    assert not pooling_manager.is_enabled()
+
+
+def test_executions_take_a_slot_of_the_pool():
+    conn = Connection.__new__(Connection)
+    conn._state_lock = threading.Condition()
+    conn._active_executions = 0
+    conn._executing_cursors = weakref.WeakSet()
+    conn._closed, conn._conn = True, None
+    conn._statement_limiter = StatementLimiter(1, "fail")
+    conn._begin_execution()
+    with pytest.raises(OperationalError):
+        conn._begin_execution()
+    assert conn._active_executions == 1
+    conn._end_execution()
+    conn._begin_execution()
+    assert conn._statement_limiter.stats()["statements_active"] == 1
+
+
+def test_queued_statements_are_admitted_by_priority():
+    limiter = StatementLimiter(1)
+    limiter.acquire()
+    order = []
+
+    def execute(priority):
+        limiter.acquire(priority)
+        order.append(priority)
+        limiter.release()
+
+    workers = []
+    for priority in ("low", "normal", "low", "high"):
+        worker = threading.Thread(target=execute, args=(priority,))
+        worker.start()
+        workers.append(worker)
+        deadline = time.monotonic() + 5
+        while limiter.stats()["statements_queued"] < len(workers) and time.monotonic() < deadline:
+            time.sleep(0.01)
+    assert limiter.stats()["statements_queued_by_priority"] == {"high": 1, "normal": 1, "low": 2}
+    limiter.release()
+    for worker in workers:
+        worker.join(5)
+    assert order == ["high", "normal", "low", "low"]
+
+
+def test_priority_of_cursor_then_connection():
+    conn = Connection.__new__(Connection)
+    conn._state_lock = threading.Condition()
+    conn._active_executions = 0
+    conn._executing_cursors = weakref.WeakSet()
+    conn._priority = "low"
+    priorities = []
+    conn._statement_limiter = type(
+        "Limiter", (), {"acquire": lambda self, priority: priorities.append(priority)}
+    )()
+
+    class _Cursor:
+        priority = None
+
+    cursor = _Cursor()
+    conn._begin_execution(cursor)
+    cursor.priority = "high"
+    conn._begin_execution(cursor)
+    assert priorities == ["low", "high"]
+    with pytest.raises(ValueError):
+        conn.priority = "urgent"
+    with pytest.raises(ValueError):
+        StatementLimiter(1).acquire("urgent")
@@ -0,0 +1,59 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for output converters registered by SQL type code (e.g. -151 for CLR UDTs)."""
+
+import threading
+
+from mssql_python.constants import ConstantsDDBC
+from mssql_python.cursor import Cursor
+
+SQL_SS_UDT = ConstantsDDBC.SQL_SS_UDT.value
+
+
+class _Connection:
+    def __init__(self, converters):
+        self._output_converters = converters
+        self._converters_lock = threading.Lock()
+
+    def get_output_converter(self, sqltype):
+        return self._output_converters.get(sqltype)
+
+
+def _cursor(converters, sql_types, type_codes):
+    cursor = Cursor.__new__(Cursor)
+    cursor._connection = _Connection(converters)
+    cursor._column_sql_types = tuple(sql_types)
+    cursor.description = [(f"c{i}", t, None, 0, 0, 0, True) for i, t in enumerate(type_codes)]
+    return cursor
+
+
+def test_sql_type_code_wins_over_python_type():
+    by_code, by_type = (lambda v: ("udt", v)), (lambda v: ("bytes", v))
+    cursor = _cursor(
+        {SQL_SS_UDT: by_code, bytes: by_type},
+        [SQL_SS_UDT, ConstantsDDBC.SQL_VARBINARY.value, ConstantsDDBC.SQL_INTEGER.value],
+        [bytes, bytes, int],
+    )
+    assert cursor._build_converter_map() == [by_code, by_type, None]
+
+
+def test_wvarchar_fallback_still_applies():
+    fallback = lambda v: v  # noqa: E731
+    cursor = _cursor(
+        {ConstantsDDBC.SQL_WVARCHAR.value: fallback}, [ConstantsDDBC.SQL_VARCHAR.value], [str]
+    )
+    assert cursor._build_converter_map() == [fallback]
+
+
+def test_udt_converter_receives_raw_bytes(cursor, db_connection):
+    db_connection.add_output_converter(SQL_SS_UDT, lambda value: ("hierarchyid", value))
+    try:
+        row = cursor.execute(
+            "SELECT hierarchyid::Parse('/1/') AS node, CAST(0x01 AS VARBINARY(1)) AS raw"
+        ).fetchone()
+        kind, value = row.node
+        assert kind == "hierarchyid" and isinstance(value, bytes)
+        assert row.raw == b"\x01"
+    finally:
+        db_connection.remove_output_converter(SQL_SS_UDT)
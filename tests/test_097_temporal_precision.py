@@ -0,0 +1,94 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for nanosecond temporal precision (temporal_precision, PreciseDateTime, PreciseTime)."""
+
+import datetime
+import pickle
+
+import pytest
+
+import mssql_python
+from mssql_python.constants import ConstantsDDBC
+from mssql_python.cursor import Cursor
+from mssql_python.temporal import PreciseDateTime, PreciseTime, time_parameter_text
+
+UTC_MINUS_5 = datetime.timezone(datetime.timedelta(hours=-5))
+
+
+def test_precise_datetime():
+    value = PreciseDateTime(2024, 1, 2, 3, 4, 5, 123456, nanosecond=700)
+    assert isinstance(value, datetime.datetime)
+    assert (value.nanosecond, value.fraction) == (700, 123456700)
+    assert value.isoformat() == "2024-01-02T03:04:05.123456700"
+    assert str(value) == "2024-01-02 03:04:05.123456700"
+    assert value.isoformat(timespec="seconds") == "2024-01-02T03:04:05"
+    assert value == datetime.datetime(2024, 1, 2, 3, 4, 5, 123456)
+    assert repr(value) == "PreciseDateTime(2024, 1, 2, 3, 4, 5, 123456, nanosecond=700)"
+    restored = pickle.loads(pickle.dumps(value))
+    assert type(restored) is PreciseDateTime and restored.nanosecond == 700
+
+    aware = PreciseDateTime.from_datetime(datetime.datetime(2024, 1, 2, tzinfo=UTC_MINUS_5), 5)
+    assert aware.isoformat() == "2024-01-02T00:00:00.000000005-05:00"
+    assert aware.to_datetime() == datetime.datetime(2024, 1, 2, tzinfo=UTC_MINUS_5)
+
+
+def test_precise_time():
+    value = PreciseTime(1, 2, 3, 4, nanosecond=500)
+    assert isinstance(value, datetime.time)
+    assert value.isoformat() == "01:02:03.000004500"
+    assert PreciseTime.from_time(datetime.time(1, 2)).isoformat() == "01:02:00"
+    assert pickle.loads(pickle.dumps(value)).nanosecond == 500
+    assert time_parameter_text(value) == "01:02:03.0000045"
+    assert time_parameter_text(datetime.time(1, 2, 3)) == "01:02:03.000000"
+
+
+@pytest.mark.parametrize("nanosecond", [-1, 1000, 1.5])
+def test_nanosecond_range(nanosecond):
+    with pytest.raises(ValueError):
+        PreciseDateTime(2024, 1, 1, nanosecond=nanosecond)
+    with pytest.raises(ValueError):
+        PreciseTime(nanosecond=nanosecond)
+
+
+def test_precise_parameters_are_bound_with_seven_digits():
+    cursor = Cursor.__new__(Cursor)
+    precise = PreciseDateTime(2024, 1, 2, nanosecond=100)
+    assert cursor._map_sql_type(precise, [precise], 0)[2:4] == (27, 7)
+    plain = datetime.datetime(2024, 1, 2)
+    assert cursor._map_sql_type(plain, [plain], 0)[2:4] == (26, 6)
+    time_value = PreciseTime(nanosecond=100)
+    sql_type, c_type, _, digits, _ = cursor._map_sql_type(time_value, [time_value], 0)
+    assert (sql_type, c_type, digits) == (
+        ConstantsDDBC.SQL_TYPE_TIME.value,
+        ConstantsDDBC.SQL_C_CHAR.value,
+        7,
+    )
+
+
+def test_temporal_precision_setting():
+    assert mssql_python.temporal_precision == "microseconds"
+    with pytest.raises(ValueError):
+        mssql_python.temporal_precision = "picoseconds"
+
+
+def test_nanoseconds_round_trip(cursor):
+    mssql_python.temporal_precision = "nanoseconds"
+    try:
+        row = cursor.execute(
+            "SELECT CAST('2024-01-02 03:04:05.1234567' AS DATETIME2(7)), "
+            "CAST('2024-01-02 03:04:05.1234567 -05:00' AS DATETIMEOFFSET(7)), "
+            "CAST('03:04:05.1234567' AS TIME(7)), CAST(? AS DATETIME2(7)), CAST(? AS TIME(7))",
+            PreciseDateTime(2024, 1, 2, 3, 4, 5, 123456, nanosecond=700),
+            PreciseTime(3, 4, 5, 123456, nanosecond=700),
+        ).fetchone()
+        datetime2, offset, time7, datetime2_param, time_param = row
+        assert isinstance(datetime2, PreciseDateTime) and datetime2.fraction == 123456700
+        assert offset.nanosecond == 700 and offset.utcoffset() == datetime.timedelta(hours=-5)
+        assert isinstance(time7, PreciseTime) and time7.fraction == 123456700
+        assert datetime2_param.fraction == 123456700
+        assert time_param.fraction == 123456700
+    finally:
+        mssql_python.temporal_precision = "microseconds"
+    plain = cursor.execute("SELECT CAST('03:04:05.1234567' AS TIME(7))").fetchval()
+    assert type(plain) is datetime.time and plain.microsecond == 123456
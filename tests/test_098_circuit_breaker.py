@@ -0,0 +1,163 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for the per-server circuit breakers (enable_circuit_breaker)."""
+
+import threading
+
+import pytest
+
+import mssql_python
+from mssql_python import OperationalError, ProgrammingError
+from mssql_python.circuit_breaker import CircuitBreaker, endpoint_breaker, is_connection_failure
+from mssql_python.connection import Connection
+
+
+@pytest.fixture
+def breakers():
+    mssql_python.enable_circuit_breaker(failure_threshold=2, reset_timeout=60)
+    yield
+    mssql_python.disable_circuit_breaker()
+
+
+def _error(cls, sqlstate):
+    error = cls(driver_error="failed", ddbc_error="failed")
+    error.sqlstate = sqlstate
+    return error
+
+
+def _connection(server="db1"):
+    conn = Connection.__new__(Connection)
+    conn._active_server = server
+    conn._state_lock = threading.Condition()
+    conn._active_executions = 0
+    conn._executing_cursors = set()
+    conn._closed = True
+    conn._conn = None
+    conn._attach_diagnostics = lambda error: None
+    return conn
+
+
+@pytest.mark.parametrize(
+    "sqlstate, connecting, expected",
+    [
+        ("08S01", False, True),
+        ("08001", True, True),
+        ("HYT00", True, True),
+        ("HYT00", False, False),
+        ("HYT01", False, True),
+        ("42000", False, False),
+        (None, True, False),
+    ],
+)
+def test_connection_failures(sqlstate, connecting, expected):
+    assert is_connection_failure(sqlstate, connecting) is expected
+
+
+def test_circuit_opens_after_consecutive_failures():
+    breaker = CircuitBreaker("db1", failure_threshold=3, reset_timeout=60)
+    breaker.record_failure()
+    breaker.record_failure()
+    breaker.record_success()
+    breaker.record_failure()
+    breaker.record_failure()
+    assert breaker.state == "closed"
+    breaker.record_failure()
+    assert breaker.state == "open"
+    with pytest.raises(OperationalError, match="Circuit breaker open for server 'db1'"):
+        breaker.allow()
+    stats = breaker.stats()
+    assert stats["times_opened"] == 1 and stats["requests_rejected"] == 1
+    assert 0 < stats["seconds_until_probe"] <= 60
+
+
+def test_half_open_probes():
+    breaker = CircuitBreaker("db1", failure_threshold=1, reset_timeout=0, half_open_probes=2)
+    breaker.record_failure()
+    assert breaker.state == "half_open"
+    breaker.allow()
+    breaker.allow()
+    with pytest.raises(OperationalError):
+        breaker.allow()
+    breaker.abandon()
+    breaker.allow()
+    breaker.record_success()
+    assert breaker.state == "closed" and breaker.stats()["probes"] == 3
+
+    breaker.record_failure()
+    breaker.allow()
+    breaker.record_failure()
+    assert breaker.stats()["times_opened"] == 3
+
+
+@pytest.mark.parametrize(
+    "kwargs",
+    [{"failure_threshold": 0}, {"reset_timeout": -1}, {"half_open_probes": 0}],
+)
+def test_invalid_settings(kwargs):
+    with pytest.raises(ValueError):
+        mssql_python.enable_circuit_breaker(**kwargs)
+    assert endpoint_breaker("db1") is None
+
+
+def test_breakers_are_per_server(breakers):
+    assert endpoint_breaker("DB1 ") is endpoint_breaker("db1")
+    assert endpoint_breaker("db2") is not endpoint_breaker("db1")
+    assert {entry["endpoint"] for entry in mssql_python.circuit_breaker_stats()} == {"DB1", "db2"}
+    mssql_python.disable_circuit_breaker()
+    assert endpoint_breaker("db1") is None
+    assert mssql_python.circuit_breaker_stats() == []
+
+
+def test_statements_fail_fast_once_open(breakers):
+    conn = _connection()
+    for _ in range(2):
+        conn._begin_execution()
+        conn._end_execution(_error(OperationalError, "08S01"))
+    with pytest.raises(OperationalError, match="Circuit breaker open"):
+        conn._begin_execution()
+    assert conn._active_executions == 0
+    # Other servers are not affected
+    other = _connection("db2")
+    other._begin_execution()
+    other._end_execution()
+
+
+def test_server_errors_do_not_open_the_circuit(breakers):
+    conn = _connection()
+    for _ in range(3):
+        conn._begin_execution()
+        conn._end_execution(_error(ProgrammingError, "42S02"))
+        conn._begin_execution()
+        conn._end_execution(_error(OperationalError, "HYT00"))
+    assert endpoint_breaker("db1").state == "closed"
+
+
+def test_connect_skips_a_server_with_an_open_circuit(breakers, monkeypatch):
+    endpoint_breaker("primary").record_failure()
+    endpoint_breaker("primary").record_failure()
+    conn = Connection.__new__(Connection)
+    conn.connection_str = "Server=primary|secondary;Database=db"
+    conn._pooling = False
+    attempts = []
+    monkeypatch.setattr(conn, "_connect_driver", lambda conn_str: attempts.append(conn_str) or 1)
+    assert conn._open_session() == 1
+    assert conn._active_server == "secondary"
+    assert len(attempts) == 1 and "secondary" in attempts[0].lower()
+
+
+def test_connect_failures_open_the_circuit(breakers, monkeypatch):
+    conn = Connection.__new__(Connection)
+    conn.connection_str = "Server=primary;Database=db"
+    conn._pooling = False
+
+    def fail(conn_str):
+        raise RuntimeError("SQLSTATE:08001:TCP Provider: No connection could be made")
+
+    monkeypatch.setattr(conn, "_connect_driver", fail)
+    for _ in range(2):
+        with pytest.raises(OperationalError):
+            conn._open_session()
+    assert endpoint_breaker("primary").state == "open"
+    with pytest.raises(OperationalError, match="Circuit breaker open"):
+        conn._open_session()
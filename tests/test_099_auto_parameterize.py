@@ -0,0 +1,150 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for automatic parameterization of literals (Connection.auto_parameterize)."""
+
+import pytest
+
+from mssql_python.connection import Connection
+from mssql_python.constants import ConstantsDDBC as ddbc_sql_const
+from mssql_python.cursor import Cursor
+from mssql_python.parameter_helper import parameterize_literals
+
+
+@pytest.mark.parametrize(
+    "sql, expected",
+    [
+        (
+            "SELECT * FROM t WHERE id = 42 AND name = N'it''s'",
+            ("SELECT * FROM t WHERE id = ? AND name = ?", [42, "it's"]),
+        ),
+        (
+            "UPDATE t SET a = 5 WHERE b IN (1, 2);",
+            ("UPDATE t SET a = ? WHERE b IN (?, ?);", [5, 1, 2]),
+        ),
+        (
+            "INSERT INTO t (a, b) VALUES (-1, 'x')",
+            ("INSERT INTO t (a, b) VALUES (-?, ?)", [1, "x"]),
+        ),
+        (
+            "WITH c AS (SELECT a FROM t WHERE b = 3) DELETE FROM c",
+            ("WITH c AS (SELECT a FROM t WHERE b = ?) DELETE FROM c", [3]),
+        ),
+        (
+            "SELECT a FROM [t 1] /* 9 */ WHERE \"c2\" = 2 -- 'x'\n",
+            ('SELECT a FROM [t 1] /* 9 */ WHERE "c2" = ? -- \'x\'\n', [2]),
+        ),
+    ],
+)
+def test_literals_become_parameters(sql, expected):
+    assert parameterize_literals(sql) == expected
+
+
+@pytest.mark.parametrize(
+    "sql, expected",
+    [
+        ("SELECT TOP 10 a FROM t ORDER BY 1, 2", None),
+        ("SELECT CAST(a AS DECIMAL(18, 2)), CONVERT(VARCHAR(10), b) FROM t", None),
+        ("SELECT SUM(a) OVER (ORDER BY d ROWS 2 PRECEDING) FROM t", None),
+        ("SELECT a FROM t OPTION (MAXDOP 1)", None),
+        ("SELECT 'x' = a, b AS 'y' FROM t", None),
+        ("SELECT 1.5, 1e3, 0x01, 99999999999999999999, 'é'", None),
+        (
+            "SELECT STUFF((SELECT ',' + n FROM t FOR XML PATH('')), 1, 1, '') WHERE k = 3",
+            ("SELECT STUFF((SELECT ',' + n FROM t FOR XML PATH('')), 1, 1, '') WHERE k = ?", [3]),
+        ),
+        (
+            "SELECT LEFT(name, 3), COUNT(*) FROM t WHERE k = 'a' GROUP BY LEFT(name, 3)",
+            ("SELECT LEFT(name, 3), COUNT(*) FROM t WHERE k = ? GROUP BY LEFT(name, 3)", ["a"]),
+        ),
+        (
+            "SELECT CASE WHEN a > 5 THEN 'hi' ELSE 'lo' END FROM t "
+            "GROUP BY CASE WHEN a > 5 THEN 'hi' ELSE 'lo' END ORDER BY 1",
+            None,
+        ),
+        ("SELECT 200 + 100", None),
+        (
+            "SELECT a FROM t WHERE b IN (SELECT c FROM u WHERE d = 4) ORDER BY a, 2",
+            ("SELECT a FROM t WHERE b IN (SELECT c FROM u WHERE d = ?) ORDER BY a, 2", [4]),
+        ),
+        (
+            "SELECT a FROM t GROUP BY a HAVING COUNT(*) > 1 ORDER BY a OFFSET 5 ROWS",
+            ("SELECT a FROM t GROUP BY a HAVING COUNT(*) > ? ORDER BY a OFFSET ? ROWS", [1, 5]),
+        ),
+    ],
+)
+def test_literals_that_must_stay_constant(sql, expected):
+    assert parameterize_literals(sql) == expected
+
+
+@pytest.mark.parametrize(
+    "sql",
+    [
+        "EXEC sp_who 1",
+        "SET NOCOUNT ON; SELECT 1",
+        "SELECT 1; SELECT 2",
+        "DECLARE @x INT = 1 SELECT @x",
+        "SELECT * FROM OPENROWSET('a', 'b', 'c')",
+        "SELECT a FROM t WHERE b = ? AND c = 1",
+        "SELECT 'unterminated",
+        "SELECT a FROM t",
+    ],
+)
+def test_statements_left_unchanged(sql):
+    assert parameterize_literals(sql) is None
+
+
+def test_integers_bind_as_int_or_bigint():
+    _, parameters = parameterize_literals("SELECT a FROM t WHERE b = 200 AND c = 3000000000")
+    cursor = Cursor.__new__(Cursor)
+    assert [cursor._map_sql_type(value, [value], i)[0] for i, value in enumerate(parameters)] == [
+        ddbc_sql_const.SQL_INTEGER.value,
+        ddbc_sql_const.SQL_BIGINT.value,
+    ]
+    assert cursor._map_sql_type(200, [200], 0)[0] == ddbc_sql_const.SQL_TINYINT.value
+
+
+def test_execute_sends_parameters(monkeypatch):
+    conn = Connection.__new__(Connection)
+    conn._auto_parameterize = True
+    conn._read_only = False
+    conn._statement_policy = None
+    cursor = Cursor.__new__(Cursor)
+    cursor._connection = conn
+    cursor._retry_policy = None
+    cursor._capture_wait_stats = cursor._measuring_waits = False
+    calls = []
+
+    def capture(self, operation, parameters, capture_plan, use_prepare, reset_cursor):
+        calls.append((operation, parameters))
+
+    monkeypatch.setattr(Cursor, "_execute_capturing_plan", capture)
+    cursor.execute("SELECT a FROM t WHERE id = 7", capture_plan="estimated")
+    cursor.execute("SELECT a FROM t WHERE id = ?", 8, capture_plan="estimated")
+    conn.auto_parameterize = False
+    cursor.execute("SELECT a FROM t WHERE id = 9", capture_plan="estimated")
+    assert calls == [
+        ("SELECT a FROM t WHERE id = ?", (7,)),
+        ("SELECT a FROM t WHERE id = ?", (8,)),
+        ("SELECT a FROM t WHERE id = 9", ()),
+    ]
+    with pytest.raises(TypeError):
+        conn.auto_parameterize = 1
+
+
+def test_auto_parameterize_against_the_server(db_connection):
+    db_connection.auto_parameterize = True
+    try:
+        cursor = db_connection.cursor()
+        row = cursor.execute(
+            "SELECT n, s FROM (VALUES (42, N'it''s')) AS v (n, s) WHERE n = 40 + 2 AND s = N'it''s'"
+        ).fetchone()
+        assert (row.n, row.s) == (42, "it's")
+        assert cursor.execute("SELECT COUNT(*) FROM sys.objects WHERE 200 + 100 = 300").fetchval()
+        cursor.execute(
+            "SELECT LEFT(name, 3), COUNT(*) FROM sys.objects WHERE type = 'U' "
+            "GROUP BY LEFT(name, 3)"
+        ).fetchall()
+        assert cursor.execute("SELECT CAST('abc' AS VARCHAR(2))").fetchval() == "ab"
+    finally:
+        db_connection.auto_parameterize = False
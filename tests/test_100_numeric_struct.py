@@ -0,0 +1,112 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for binding and fetching DECIMAL and NUMERIC values as SQL_NUMERIC_STRUCT."""
+
+import types
+from decimal import Decimal
+
+import pytest
+
+from mssql_python import ddbc_bindings
+from mssql_python.constants import ConstantsDDBC
+from mssql_python.cursor import Cursor, _numeric_column_shape
+
+
+@pytest.fixture
+def bare_cursor(monkeypatch):
+    monkeypatch.setattr(ddbc_bindings, "NumericData", types.SimpleNamespace)
+    return Cursor.__new__(Cursor)
+
+
+@pytest.mark.parametrize(
+    "value, precision, scale, unscaled",
+    [
+        (Decimal("12.34"), 4, 2, 1234),
+        (Decimal("0.00012"), 5, 5, 12),
+        (Decimal("3E+4"), 5, 0, 30000),
+        (Decimal("-99999999999999999999999999999999999999"), 38, 0, 10**38 - 1),
+    ],
+)
+def test_numeric_data_is_exact(bare_cursor, value, precision, scale, unscaled):
+    numeric = bare_cursor._get_numeric_data(value)
+    assert (numeric.precision, numeric.scale) == (precision, scale)
+    assert numeric.sign == (0 if value < 0 else 1)
+    assert numeric.val == unscaled.to_bytes(16, "little")
+
+
+def test_numeric_data_at_bound_scale(bare_cursor):
+    assert bare_cursor._get_numeric_data(Decimal("1.5"), 10, 4).val == (15000).to_bytes(
+        16, "little"
+    )
+    # Extra decimal places are rounded half-even
+    assert bare_cursor._get_numeric_data(Decimal("2.345"), 10, 2).val == (234).to_bytes(
+        16, "little"
+    )
+    assert bare_cursor._get_numeric_data(Decimal("2.355"), 10, 2).val == (236).to_bytes(
+        16, "little"
+    )
+
+
+def test_numeric_data_out_of_range(bare_cursor):
+    with pytest.raises(ValueError):
+        bare_cursor._get_numeric_data(Decimal("1" * 39))
+    # Rounding to the bound scale can add a digit
+    with pytest.raises(ValueError, match="does not fit in precision 3, scale 2"):
+        bare_cursor._get_numeric_data(Decimal("9.999"), 3, 2)
+    with pytest.raises(ValueError, match="does not fit in precision 4"):
+        bare_cursor._get_numeric_data(Decimal("-123.45"), 4, 2)
+    assert bare_cursor._get_numeric_data(Decimal("9.994"), 3, 2).val == (999).to_bytes(
+        16, "little"
+    )
+
+
+def test_money_range_decimal_binds_as_numeric(bare_cursor):
+    value = Decimal("922337203685477.5807")
+    sql_type, c_type, precision, scale, _ = bare_cursor._map_sql_type(value, [value], 0)
+    assert sql_type == ConstantsDDBC.SQL_NUMERIC.value
+    assert c_type == ConstantsDDBC.SQL_C_NUMERIC.value
+    assert (precision, scale) == (19, 4)
+
+
+@pytest.mark.parametrize(
+    "column, shape",
+    [
+        ([Decimal("1.5"), None, Decimal("-123.25")], (5, 2)),
+        ([Decimal("0.005")], (3, 3)),
+        ([None], (1, 0)),
+        ([Decimal("1"), 2], None),
+        ([Decimal("NaN")], None),
+        ([Decimal("1" * 30), Decimal("0." + "1" * 10)], None),
+    ],
+)
+def test_numeric_column_shape(column, shape):
+    assert _numeric_column_shape(column) == shape
+
+
+def test_decimal_round_trip(cursor, db_connection):
+    values = [
+        Decimal("1234567890123456789012345678.0123456789"),
+        Decimal("-0.0000000001"),
+        Decimal("0E-10"),
+    ]
+    row = cursor.execute(
+        "SELECT CAST(? AS DECIMAL(38, 10)), CAST(? AS DECIMAL(38, 10)), "
+        "CAST(? AS DECIMAL(38, 10)), CAST(? AS MONEY), CAST(? AS SMALLMONEY)",
+        *values,
+        Decimal("-922337203685477.5808"),
+        Decimal("214748.3647"),
+    ).fetchone()
+    assert list(row[:3]) == values
+    assert row[3] == Decimal("-922337203685477.5808")
+    assert row[4] == Decimal("214748.3647")
+
+    cursor.execute("CREATE TABLE #numeric_struct (d DECIMAL(38, 10), m MONEY)")
+    try:
+        rows = [(value, Decimal("12.3456")) for value in values] + [(None, None)]
+        cursor.executemany("INSERT INTO #numeric_struct VALUES (?, ?)", rows)
+        fetched = cursor.execute("SELECT d, m FROM #numeric_struct").fetchall()
+        assert sorted((tuple(r) for r in fetched), key=str) == sorted(rows, key=str)
+    finally:
+        cursor.execute("DROP TABLE #numeric_struct")
+        db_connection.commit()
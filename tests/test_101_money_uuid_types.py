@@ -0,0 +1,70 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for the MONEY, SMALLMONEY and UNIQUEIDENTIFIER type mappings."""
+
+import uuid
+from decimal import Decimal
+
+import pytest
+
+import mssql_python
+from mssql_python.constants import ConstantsDDBC
+from mssql_python.cursor import Cursor, _guid_parameter
+from mssql_python.exceptions import ProgrammingError
+
+_ID = uuid.UUID("12345678-1234-5678-9abc-def012345678")
+
+
+def test_money_input_sizes_are_decimal():
+    cursor = Cursor.__new__(Cursor)
+    cursor.setinputsizes(
+        [mssql_python.SQL_MONEY, (mssql_python.SQL_SMALLMONEY, 5, 2), mssql_python.SQL_GUID]
+    )
+    assert cursor._inputsizes == [
+        (ConstantsDDBC.SQL_DECIMAL.value, 19, 4),
+        (ConstantsDDBC.SQL_DECIMAL.value, 10, 4),
+        (ConstantsDDBC.SQL_GUID.value, 0, 0),
+    ]
+
+
+@pytest.mark.parametrize(
+    "value",
+    [_ID, str(_ID), str(_ID).upper(), "{" + str(_ID) + "}", _ID.hex, _ID.bytes_le],
+)
+def test_guid_parameter_forms(value):
+    assert _guid_parameter(value) == _ID.bytes_le
+
+
+def test_guid_parameter_rejects_other_strings():
+    with pytest.raises(ProgrammingError):
+        _guid_parameter("not-a-uuid")
+    assert _guid_parameter(None) is None
+
+
+def test_money_and_uuid_round_trip(cursor):
+    cursor.execute(
+        "SELECT CAST(? AS MONEY), CAST(? AS SMALLMONEY), CAST(? AS UNIQUEIDENTIFIER)",
+        Decimal("-922337203685477.5808"),
+        Decimal("1.25"),
+        _ID,
+    )
+    money, smallmoney, guid = cursor.fetchone()
+    assert money == Decimal("-922337203685477.5808")
+    assert smallmoney == Decimal("1.2500") and smallmoney.as_tuple().exponent == -4
+    assert guid == _ID
+    assert [d[1] for d in cursor.description] == [Decimal, Decimal, uuid.UUID]
+
+    cursor.setinputsizes([mssql_python.SQL_MONEY, mssql_python.SQL_GUID])
+    row = cursor.execute("SELECT ?, ?", Decimal("12.3456"), str(_ID)).fetchone()
+    assert row[0] == Decimal("12.3456")
+    assert row[1] == _ID
+
+
+def test_uuid_as_str_per_connection(conn_str):
+    conn = mssql_python.connect(conn_str, native_uuid=False)
+    try:
+        value = conn.cursor().execute("SELECT CAST(? AS UNIQUEIDENTIFIER)", _ID).fetchval()
+        assert value == str(_ID).upper()
+    finally:
+        conn.close()
@@ -0,0 +1,95 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for read-only connections (connect(read_only=True))."""
+
+import re
+from unittest.mock import MagicMock
+
+import pytest
+
+import mssql_python
+from mssql_python.connection import Connection
+from mssql_python.exceptions import ProgrammingError
+from mssql_python.read_only import check_read_only
+
+
+@pytest.mark.parametrize(
+    "sql",
+    [
+        "SELECT * FROM t WHERE note = 'DELETE FROM t' -- DROP TABLE t",
+        "SELECT [update], [delete] FROM [insert]",
+        "INSERT INTO #totals SELECT region, SUM(amount) FROM sales GROUP BY region",
+        "SELECT a INTO #t FROM x; UPDATE #t SET a = a + 1; DROP TABLE IF EXISTS #t",
+        "CREATE TABLE #t (id INT REFERENCES parent (id) ON DELETE CASCADE)",
+        "DECLARE @t TABLE (a INT); INSERT @t VALUES (1); DELETE TOP (1) FROM @t",
+        "MERGE #t AS tgt USING src ON tgt.id = src.id WHEN MATCHED THEN UPDATE SET a = 1;",
+        "DECLARE c CURSOR FOR SELECT a FROM t FOR UPDATE; FETCH NEXT FROM c INTO @a, @b",
+        "SELECT * FROM a INNER MERGE JOIN b ON a.id = b.id OPTION (MERGE UNION)",
+        "EXEC dbo.monthly_report @month = 5",
+    ],
+)
+def test_reads_are_allowed(sql):
+    check_read_only(sql)
+
+
+@pytest.mark.parametrize(
+    "sql, statement",
+    [
+        ("DELETE FROM dbo.orders WHERE id = 1", "DELETE dbo.orders"),
+        ("update [orders] set a = 1", "UPDATE [orders]"),
+        ("INSERT orders VALUES (1)", "INSERT orders"),
+        ("SELECT * INTO orders_copy FROM orders", "INTO orders_copy"),
+        ("WITH c AS (SELECT * FROM t) DELETE FROM c", "DELETE c"),
+        ("DROP TABLE #scratch, orders", "DROP #scratch, orders"),
+        ("CREATE OR ALTER VIEW v AS SELECT 1 AS a", "CREATE VIEW"),
+        ("TRUNCATE TABLE orders", "TRUNCATE orders"),
+        ("GRANT SELECT ON orders TO reporting", "GRANT"),
+        ("ALTER DATABASE current SET READ_ONLY", "ALTER DATABASE"),
+        (b"DELETE FROM orders", "DELETE orders"),
+    ],
+)
+def test_writes_are_rejected(sql, statement):
+    with pytest.raises(ProgrammingError, match=re.escape(f"{statement} is not allowed")):
+        check_read_only(sql)
+
+
+@pytest.fixture
+def offline_connection(monkeypatch):
+    monkeypatch.setattr(Connection, "_open_session", lambda self: MagicMock())
+    return Connection
+
+
+def test_read_only_connection(offline_connection):
+    conn = offline_connection("Server=reports;Database=sales", read_only=True)
+    assert conn.read_only
+    assert "ApplicationIntent=ReadOnly" in conn.connection_str
+    assert "applicationintent=ReadOnly" in conn._spawn_connection_string()
+    with pytest.raises(ProgrammingError):
+        conn.cursor().execute("DELETE FROM orders")
+    with pytest.raises(ProgrammingError):
+        conn.cursor().executemany("INSERT INTO orders VALUES (?)", [(1,), (2,)])
+    with pytest.raises(ProgrammingError, match="Bulk copy"):
+        conn.cursor().bulkcopy("orders", [(1,)])
+    assert not offline_connection("Server=reports;Database=sales").read_only
+
+
+def test_read_only_conflicting_intent(offline_connection):
+    assert offline_connection("Server=s;ApplicationIntent=readonly", read_only=True).read_only
+    with pytest.raises(ValueError, match="conflicts with read_only"):
+        offline_connection("Server=s;ApplicationIntent=ReadWrite", read_only=True)
+    with pytest.raises(ValueError):
+        offline_connection("Server=s", read_only="yes")
+
+
+def test_read_only_round_trip(conn_str):
+    conn = mssql_python.connect(conn_str, read_only=True)
+    try:
+        cursor = conn.cursor()
+        assert cursor.execute("SELECT 1").fetchval() == 1
+        cursor.execute("CREATE TABLE #t (a INT); INSERT INTO #t VALUES (1)")
+        assert cursor.execute("SELECT COUNT(*) FROM #t").fetchval() == 1
+        with pytest.raises(ProgrammingError):
+            cursor.execute("CREATE TABLE read_only_probe (a INT)")
+    finally:
+        conn.close()
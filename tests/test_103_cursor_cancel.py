@@ -0,0 +1,110 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for Cursor.settimeout() and Cursor.cancel()."""
+
+import threading
+import time
+import weakref
+from unittest.mock import MagicMock
+
+import pytest
+
+from mssql_python import ddbc_bindings
+from mssql_python.constants import ConstantsDDBC
+from mssql_python.cursor import Cursor
+from mssql_python.exceptions import OperationalError
+
+
+@pytest.fixture
+def bare_cursor():
+    cursor = Cursor.__new__(Cursor)
+    cursor.closed = False
+    cursor.hstmt = MagicMock()
+    cursor._timeout = 0
+    cursor._results_pending = False
+    cursor._connection = MagicMock()
+    cursor._connection._state_lock = threading.Condition()
+    cursor._connection._executing_cursors = weakref.WeakSet()
+    return cursor
+
+
+def test_settimeout_sets_the_statement_attribute(bare_cursor, monkeypatch):
+    calls = []
+    monkeypatch.setattr(
+        ddbc_bindings, "DDBCSQLSetStmtAttr", lambda *args: calls.append(args[1:]) or 0
+    )
+    bare_cursor.settimeout(2.1)
+    assert bare_cursor.timeout == 3
+    bare_cursor.timeout = None
+    assert bare_cursor.timeout == 0
+    query_timeout = ConstantsDDBC.SQL_ATTR_QUERY_TIMEOUT.value
+    assert calls == [(query_timeout, 3), (query_timeout, 0)]
+
+
+@pytest.mark.parametrize("value, error", [(-1, ValueError), ("5", TypeError), (True, TypeError)])
+def test_settimeout_rejects_invalid_values(bare_cursor, value, error):
+    with pytest.raises(error):
+        bare_cursor.settimeout(value)
+
+
+def test_cancel_sends_attention_while_executing(bare_cursor, monkeypatch):
+    cancelled = []
+    monkeypatch.setattr(ddbc_bindings, "DDBCSQLCancel", lambda h: cancelled.append(h) or 0)
+    bare_cursor.cancel()
+    assert cancelled == []
+    bare_cursor._connection._executing_cursors.add(bare_cursor)
+    bare_cursor.cancel()
+    assert cancelled == [bare_cursor.hstmt]
+
+
+def test_cancel_after_the_statement_finished(bare_cursor, monkeypatch):
+    invalid_handle = ConstantsDDBC.SQL_INVALID_HANDLE.value
+    monkeypatch.setattr(ddbc_bindings, "DDBCSQLCancel", lambda h: invalid_handle)
+    bare_cursor._results_pending = True
+    bare_cursor.cancel()
+    bare_cursor.closed = True
+    bare_cursor.cancel()
+
+
+def test_handle_freed_during_cancel_waits_for_it(bare_cursor, monkeypatch):
+    events = []
+    bare_cursor.hstmt.free.side_effect = lambda: events.append("free")
+    bare_cursor._connection._executing_cursors.add(bare_cursor)
+    # The statement ends on its own thread, which frees the handle
+    freeing = threading.Thread(target=lambda: bare_cursor._detach_statement_handle().free())
+
+    def cancel(hstmt):
+        freeing.start()
+        freeing.join(0.2)
+        events.append("cancel")
+        return 0
+
+    monkeypatch.setattr(ddbc_bindings, "DDBCSQLCancel", cancel)
+    bare_cursor.cancel()
+    freeing.join()
+    assert events == ["cancel", "free"]
+    assert bare_cursor.hstmt is None
+    bare_cursor.cancel()
+    assert events == ["cancel", "free"]
+
+
+def test_cancel_from_another_thread(cursor):
+    timer = threading.Timer(1.0, cursor.cancel)
+    started = time.monotonic()
+    timer.start()
+    try:
+        with pytest.raises(OperationalError):
+            cursor.execute("WAITFOR DELAY '00:00:30'")
+    finally:
+        timer.cancel()
+    assert time.monotonic() - started < 15
+    assert cursor.execute("SELECT 1").fetchval() == 1
+
+
+def test_query_timeout(cursor):
+    cursor.settimeout(1)
+    with pytest.raises(OperationalError):
+        cursor.execute("WAITFOR DELAY '00:00:10'")
+    cursor.settimeout(0)
+    assert cursor.execute("SELECT 1").fetchval() == 1
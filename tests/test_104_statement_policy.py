@@ -0,0 +1,102 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for statement policies (Connection.statement_policy)."""
+
+import re
+from unittest.mock import MagicMock
+
+import pytest
+
+from mssql_python import StatementPolicy, check_statement_policy
+from mssql_python.connection import Connection
+from mssql_python.exceptions import ProgrammingError
+
+_SANDBOX = StatementPolicy(
+    deny_statements=["DROP", "truncate", "ALTER DATABASE"],
+    deny=[r"\bxp_cmdshell\b"],
+    deny_cross_database=True,
+    databases=["sales", "tempdb"],
+)
+
+
+@pytest.mark.parametrize(
+    "sql",
+    [
+        "SELECT * FROM dbo.t WHERE note = 'DROP TABLE t'",
+        "SELECT [drop] FROM t -- TRUNCATE TABLE t",
+        "SELECT s.t.col FROM s.t",
+        "SELECT * FROM [Sales].dbo.orders JOIN tempdb..#scratch ON 1 = 1",
+        "ALTER TABLE #t ADD c INT",
+        "USE sales",
+    ],
+)
+def test_allowed_statements(sql):
+    _SANDBOX(sql)
+
+
+@pytest.mark.parametrize(
+    "sql, reason",
+    [
+        ("DROP TABLE t", "DROP statements"),
+        ("truncate table t", "TRUNCATE statements"),
+        ("ALTER  DATABASE current SET READ_ONLY", "ALTER DATABASE statements"),
+        ("EXEC master..xp_cmdshell 'dir'", "Statements matching"),
+        ("SELECT * FROM hr.dbo.salaries", "Reference to database 'hr'"),
+        ("SELECT * FROM t JOIN [hr].[dbo].[salaries] s ON 1 = 1", "Reference to database 'hr'"),
+        ("SELECT * FROM hr..salaries", "Reference to database 'hr'"),
+        ("SELECT hr.dbo.salaries.amount FROM t", "Reference to database 'hr'"),
+        ("EXEC hr.dbo.raise_salaries", "Reference to database 'hr'"),
+        ("SELECT * FROM srv.sales.dbo.t", "Reference to linked server 'srv'"),
+        ("USE hr", "USE of database 'hr'"),
+        ("SELECT * FROM OPENQUERY(srv, 'SELECT 1')", "OPENQUERY"),
+        (b"DROP TABLE t", "DROP statements"),
+    ],
+)
+def test_blocked_statements(sql, reason):
+    with pytest.raises(ProgrammingError, match=f"Statement blocked by policy: {reason}"):
+        _SANDBOX(sql)
+
+
+def test_allow_patterns():
+    policy = StatementPolicy(allow=[r"^\s*(SELECT|WITH)\b"])
+    policy("  select 1")
+    policy("/* report */ WITH c AS (SELECT 1 AS a) SELECT a FROM c")
+    with pytest.raises(ProgrammingError, match="none of the allowed patterns"):
+        policy("DELETE FROM t")
+    with pytest.raises(ValueError):
+        StatementPolicy(deny_statements=[" "])
+
+
+def test_callback_returning_false():
+    seen = []
+    check_statement_policy(lambda sql: seen.append(sql), b"SELECT 1")
+    assert seen == ["SELECT 1"]
+    with pytest.raises(ProgrammingError, match=re.escape("statement policy")):
+        check_statement_policy(lambda sql: "DELETE" not in sql.upper(), "delete from t")
+
+
+def test_connection_statement_policy(monkeypatch):
+    monkeypatch.setattr(Connection, "_open_session", lambda self: MagicMock())
+    conn = Connection("Server=s;Database=sales")
+    assert conn.statement_policy is None
+    conn.statement_policy = _SANDBOX
+    with pytest.raises(ProgrammingError, match="DROP"):
+        conn.cursor().execute("DROP TABLE orders")
+    with pytest.raises(ProgrammingError, match="'hr'"):
+        conn.cursor().executemany("INSERT INTO hr.dbo.t VALUES (?)", [(1,), (2,)])
+    with pytest.raises(TypeError):
+        conn.statement_policy = "DROP"
+    conn.statement_policy = None
+    assert conn.statement_policy is None
+
+
+def test_statement_policy_round_trip(conn):
+    conn.statement_policy = StatementPolicy(deny_statements=["DROP"])
+    try:
+        cursor = conn.cursor()
+        assert cursor.execute("SELECT 1").fetchval() == 1
+        with pytest.raises(ProgrammingError):
+            cursor.execute("DROP TABLE IF EXISTS statement_policy_probe")
+    finally:
+        conn.statement_policy = None
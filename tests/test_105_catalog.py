@@ -0,0 +1,126 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for cross-database metadata (Connection.databases and Connection.catalog)."""
+
+import datetime
+
+import pytest
+
+from mssql_python import ProgrammingError
+from mssql_python.catalog import catalog, catalog_sql, list_databases
+
+_CREATED = datetime.datetime(2024, 1, 2)
+DATABASES = [
+    ("archive", 7, "OFFLINE", 0, 0, 160, "Latin1_General_CI_AS", "FULL", None, _CREATED),
+    ("master", 1, "ONLINE", 1, 0, 160, "Latin1_General_CI_AS", "SIMPLE", None, _CREATED),
+    ("sales", 5, "ONLINE", 1, 0, 160, "Latin1_General_CI_AS", "FULL", None, _CREATED),
+    ("sales_snap", 8, "ONLINE", 1, 1, 160, "Latin1_General_CI_AS", "FULL", "sales", _CREATED),
+]
+
+
+class _CatalogCursor:
+    def __init__(self, connection):
+        self.connection = connection
+
+    def execute(self, sql, use_prepare=True):
+        self.connection.executed.append(sql)
+        self.results = list(self.connection.results.pop(0))
+        return self
+
+    def fetchall(self):
+        return self.results[0]
+
+    def nextset(self):
+        self.results.pop(0)
+        return bool(self.results)
+
+    def close(self):
+        pass
+
+
+class _CatalogConnection:
+    def __init__(self, *results):
+        # Each execute() gets the next entry: a list of result sets
+        self.results = list(results)
+        self.executed = []
+
+    def cursor(self):
+        return _CatalogCursor(self)
+
+
+def test_list_databases():
+    conn = _CatalogConnection([DATABASES])
+    databases = list_databases(conn)
+    assert [entry["name"] for entry in databases] == ["archive", "sales", "sales_snap"]
+    archive, sales, snapshot = databases
+    assert not archive["accessible"] and sales["accessible"]
+    assert snapshot["snapshot_of"] == "sales" and snapshot["is_read_only"] is True
+    conn = _CatalogConnection([DATABASES])
+    assert "master" in [entry["name"] for entry in list_databases(conn, include_system=True)]
+
+
+def test_catalog_sql_batches_databases():
+    sql = catalog_sql(["sales", "o'brien]"], ["tables", "routines"])
+    assert sql.count("UNION ALL") == 2
+    assert "FROM [o'brien]]].sys.objects" in sql and "N'o''brien]'" in sql
+    assert sql.count(";") == 2
+
+
+def test_catalog_reads_batches_and_skips_offline():
+    conn = _CatalogConnection(
+        [[(3, "sales")]],
+        [DATABASES],
+        [
+            [("sales", "dbo", "orders", "USER_TABLE", 10, _CREATED, _CREATED)],
+            [
+                ("sales", "dbo", "orders", "total", 2, "money", 8, 19, 4, 1, 0, 0),
+                ("sales", "dbo", "orders", "id", 1, "int", 4, 10, 0, 0, 1, 0),
+            ],
+        ],
+        [
+            [("sales_snap", "dbo", "orders", "USER_TABLE", 9, _CREATED, _CREATED)],
+            [],
+        ],
+    )
+    result = catalog(conn, kinds=["tables", "columns"], batch_size=1)
+    assert len(conn.executed) == 4
+    assert result["skipped"] == ["archive"]
+    assert [(t["database"], t["row_count"]) for t in result["tables"]] == [
+        ("sales", 10),
+        ("sales_snap", 9),
+    ]
+    identity, total = result["columns"]
+    assert (identity["name"], identity["ordinal"], identity["is_identity"]) == ("id", 1, True)
+    assert total["table"] == "orders" and total["nullable"] is True
+
+
+def test_catalog_named_databases():
+    routine = ("master", "dbo", "p", "SQL_STORED_PROCEDURE", _CREATED, _CREATED)
+    conn = _CatalogConnection([[(3, "sales")]], [DATABASES], [[routine]])
+    result = catalog(conn, databases=["master"], kinds=["routines"])
+    assert "[master].sys.objects" in conn.executed[-1]
+    assert result["routines"][0]["database"] == "master"
+    conn = _CatalogConnection([[(3, "sales")]], [DATABASES])
+    with pytest.raises(ProgrammingError, match="do not exist: missing"):
+        catalog(conn, databases=["sales", "missing"])
+    with pytest.raises(ProgrammingError, match="Unknown catalog kinds: indexes"):
+        catalog(_CatalogConnection(), kinds=["indexes"])
+
+
+def test_catalog_azure_sql_database():
+    view = ("appdb", "dbo", "t", "VIEW", None, _CREATED, _CREATED)
+    conn = _CatalogConnection([[(5, "appdb")]], [[view]])
+    assert catalog(conn, kinds=["tables"])["tables"][0]["type"] == "VIEW"
+    conn = _CatalogConnection([[(5, "appdb")]])
+    with pytest.raises(ProgrammingError, match="current database"):
+        catalog(conn, databases=["other"])
+
+
+def test_catalog_round_trip(conn):
+    current = conn.cursor().execute("SELECT DB_NAME()").fetchval()
+    assert current in [entry["name"] for entry in conn.databases(include_system=True)]
+    result = conn.catalog(databases=[current, "master"], kinds=["tables", "columns"])
+    assert result["skipped"] == []
+    assert {entry["database"] for entry in result["tables"]} <= {current, "master"}
+    assert all(entry["ordinal"] >= 1 for entry in result["columns"])
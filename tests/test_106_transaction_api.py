@@ -0,0 +1,139 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for named savepoints and Connection.set_isolation_level()."""
+
+import threading
+
+import pytest
+
+import mssql_python
+from mssql_python import InterfaceError, ProgrammingError
+from mssql_python.constants import ConstantsDDBC
+from mssql_python.connection import Connection
+
+
+class _FakeSqlConnection:
+    def __init__(self, autocommit):
+        self.autocommit = autocommit
+        self.attributes = []
+
+    def get_autocommit(self):
+        return self.autocommit
+
+    def set_attr(self, attribute, value):
+        self.attributes.append((attribute, value))
+
+
+class _RecordingConnection(Connection):
+    """Connection recording the transaction statements and attributes it would send."""
+
+    def __init__(self, autocommit=False):  # pylint: disable=super-init-not-called
+        self._closed = False
+        self._broken = False
+        self._transaction_open = False
+        self._transaction_state_stale = False
+        self._state_lock = threading.Lock()
+        self._savepoints = []
+        self._savepoint_counter = 0
+        self._isolation_level = None
+        self._conn = _FakeSqlConnection(autocommit)
+        self.statements = []
+
+    def __del__(self):
+        pass
+
+    def _run_transaction_statement(self, sql):
+        self.statements.append(sql)
+
+
+def test_rollback_to_named_savepoint():
+    connection = _RecordingConnection()
+    first = connection.savepoint("before_import")
+    inner = connection.savepoint("batch_1")
+    connection.rollback_to("before_import")
+    assert connection.statements[0].endswith("SAVE TRANSACTION before_import")
+    assert connection.statements[-1] == "ROLLBACK TRANSACTION before_import"
+    assert first.is_active and not inner.is_active
+    # The savepoint stays set and can be rolled back to again
+    connection.rollback_to("before_import")
+    with pytest.raises(ProgrammingError, match="No active savepoint named 'batch_1'"):
+        connection.rollback_to("batch_1")
+
+
+def test_rollback_to_most_recent_savepoint_of_a_name():
+    connection = _RecordingConnection()
+    outer = connection.savepoint("step")
+    inner = connection.savepoint("step")
+    connection.rollback_to("step")
+    assert outer.is_active and inner.is_active
+    assert connection._savepoints == [outer, inner]
+
+
+@pytest.mark.parametrize("name", ["", "1st", "has space", "x" * 33, "semi;colon", 5])
+def test_invalid_savepoint_names(name):
+    with pytest.raises(ProgrammingError):
+        _RecordingConnection().savepoint(name)
+
+
+def test_savepoint_requires_manual_commit():
+    with pytest.raises(ProgrammingError, match="savepoint\\(\\) requires autocommit"):
+        _RecordingConnection(autocommit=True).savepoint("sp")
+
+
+@pytest.mark.parametrize(
+    "level, name, attribute, value",
+    [
+        ("snapshot", "SNAPSHOT", "SQL_COPT_SS_TXN_ISOLATION", "SQL_TXN_SS_SNAPSHOT"),
+        ("read_committed", "READ COMMITTED", "SQL_ATTR_TXN_ISOLATION", "SQL_TXN_READ_COMMITTED"),
+        (
+            mssql_python.SQL_TXN_SERIALIZABLE,
+            "SERIALIZABLE",
+            "SQL_ATTR_TXN_ISOLATION",
+            "SQL_TXN_SERIALIZABLE",
+        ),
+    ],
+)
+def test_set_isolation_level(level, name, attribute, value):
+    connection = _RecordingConnection()
+    assert connection.isolation_level is None
+    connection.set_isolation_level(level)
+    assert connection.isolation_level == name
+    assert connection._conn.attributes == [
+        (getattr(ConstantsDDBC, attribute).value, getattr(ConstantsDDBC, value).value)
+    ]
+
+
+def test_set_isolation_level_errors():
+    connection = _RecordingConnection()
+    with pytest.raises(ValueError, match="Unknown isolation level"):
+        connection.set_isolation_level("chaos")
+    with pytest.raises(ValueError):
+        connection.set_isolation_level(True)
+    connection._transaction_open = True
+    with pytest.raises(ProgrammingError, match="transaction is open"):
+        connection.set_isolation_level("SNAPSHOT")
+    connection._closed = True
+    with pytest.raises(InterfaceError):
+        connection.set_isolation_level("SNAPSHOT")
+    assert connection._conn.attributes == []
+
+
+def test_isolation_level_and_savepoints_round_trip(conn):
+    conn.autocommit = False
+    try:
+        conn.set_isolation_level("REPEATABLE READ")
+        cursor = conn.cursor()
+        level = cursor.execute(
+            "SELECT transaction_isolation_level FROM sys.dm_exec_sessions WHERE session_id = @@SPID"
+        ).fetchval()
+        assert level == 3
+        cursor.execute("CREATE TABLE #t (a INT)")
+        conn.savepoint("filled")
+        cursor.execute("INSERT INTO #t VALUES (1)")
+        conn.rollback_to("filled")
+        assert cursor.execute("SELECT COUNT(*) FROM #t").fetchval() == 0
+        conn.rollback()
+    finally:
+        conn.set_isolation_level("READ COMMITTED")
+        conn.autocommit = True
@@ -0,0 +1,100 @@
+# Copyright (c) Microsoft Corporation.
+# Licensed under the MIT License.
+
+"""Tests for result column lineage (Cursor.extended_description)."""
+
+import threading
+
+import pytest
+
+from mssql_python import ProgrammingError
+from mssql_python.cursor import Cursor
+from mssql_python.lineage import column_sources, describe_statement
+
+SOURCES = [
+    (1, "id", None, "sales", "dbo", "orders", "id", 0, 1, None, None),
+    (2, "total", None, None, None, None, None, 1, 0, None, None),
+]
+
+
+class _DescribeCursor:
+    def __init__(self, connection):
+        self.connection = connection
+
+    def execute(self, sql, *params):
+        self.connection.executed.append((sql, params))
+        return self
+
+    def fetchall(self):
+        return self.connection.rows
+
+    def close(self):
+        pass
+
+
+class _DescribeConnection:
+    def __init__(self, rows):
+        self.rows = rows
+        self.executed = []
+        self._mars_enabled = False
+        self._cursors = []
+        self._monitor_lock = threading.Lock()
+
+    def cursor(self):
+        return _DescribeCursor(self)
+
+
+def test_describe_statement_names_parameter_markers():
+    sql, declaration = describe_statement(
+        "SELECT a, '?' AS [q?] FROM t WHERE id = ? AND name LIKE ? -- ?"
+    )
+    assert sql == "SELECT a, '?' AS [q?] FROM t WHERE id = @P1 AND name LIKE @P2 -- ?"
+    assert declaration == "@P1 nvarchar(4000), @P2 nvarchar(4000)"
+    assert describe_statement("SELECT 1") == ("SELECT 1", None)
+
+
+def test_column_sources():
+    conn = _DescribeConnection(SOURCES)
+    identifier, total = column_sources(conn, "SELECT id, qty * price AS total FROM v WHERE id = ?")
+    assert conn.executed[0][1][0].endswith("WHERE id = @P1")
+    assert identifier["source_table"] == "orders" and identifier["is_identity"] is True
+    assert total["source_column"] is None and total["is_computed"] is True
+
+
+def test_column_sources_describe_error():
+    error_row = (None,) * 9 + (208, "Invalid object name 'missing'.")
+    with pytest.raises(ProgrammingError, match="Invalid object name 'missing'"):
+        column_sources(_DescribeConnection([error_row]), "SELECT * FROM missing")
+
+
+def test_extended_description():
+    cursor = Cursor.__new__(Cursor)
+    cursor.closed = False
+    cursor._connection = _DescribeConnection(SOURCES)
+    cursor.last_executed_stmt = "SELECT id, qty * price AS total FROM v"
+    cursor.description = [
+        ("id", int, None, 10, 10, 0, False),
+        ("total", float, None, 53, 53, 0, True),
+    ]
+    identifier, total = cursor.extended_description()
+    assert identifier["name"] == "id" and identifier["null_ok"] is False
+    assert (identifier["source_database"], identifier["source_column"]) == ("sales", "id")
+    assert total["type_code"] is float and total["source_table"] is None
+    cursor._result_set_number = 1
+    with pytest.raises(ProgrammingError, match="first result set"):
+        cursor.extended_description()
+    cursor.description = None
+    with pytest.raises(ProgrammingError, match="No result set"):
+        cursor.extended_description()
+
+
+def test_extended_description_round_trip(cursor):
+    cursor.execute(
+        "SELECT o.name AS object_name, o.object_id + 1 AS next_id "
+        "FROM sys.objects AS o WHERE o.object_id = ?",
+        1,
+    )
+    name, next_id = cursor.extended_description()
+    assert name["source_column"] == "name" and name["source_table"] is not None
+    assert next_id["source_column"] is None
+    cursor.fetchall()